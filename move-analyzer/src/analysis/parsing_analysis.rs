@@ -368,7 +368,7 @@ impl<'a> ParsingAnalysisContext<'a> {
                 {
                     mod_defs.call_infos.insert(
                         last_chain_symbol_loc(chain),
-                        CallInfo::new(/* do_call */ false, &v.value),
+                        CallInfo::new(/* do_call */ false, v),
                     );
                 };
             }
@@ -404,6 +404,11 @@ impl<'a> ParsingAnalysisContext<'a> {
                 self.exp_symbols(e2);
             }
             E::Loop(e) => self.exp_symbols(e),
+            E::For(b, e1, e2) => {
+                self.bind_symbols(b, false);
+                self.exp_symbols(e1);
+                self.exp_symbols(e2);
+            }
             E::Labeled(_, e) => self.exp_symbols(e),
             E::Block(seq) => self.seq_symbols(seq),
             E::Lambda(sp!(_, bindings), to, e) => {
@@ -461,7 +466,7 @@ impl<'a> ParsingAnalysisContext<'a> {
                 {
                     mod_defs
                         .call_infos
-                        .insert(name.loc, CallInfo::new(/* do_call */ true, &v.value));
+                        .insert(name.loc, CallInfo::new(/* do_call */ true, v));
                 };
             }
             E::Index(e, v) => {
@@ -703,7 +708,7 @@ impl<'a> ParsingAnalysisContext<'a> {
                 self.chain_symbols(chain);
             }
             T::Ref(_, t) => self.type_symbols(t),
-            T::Fun(v, t) => {
+            T::Fun(v, t, _) => {
                 v.iter().for_each(|t| self.type_symbols(t));
                 self.type_symbols(t);
             }
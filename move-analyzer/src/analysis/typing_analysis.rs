@@ -1105,6 +1105,8 @@ impl TypingVisitorContext for TypingAnalysisContext<'_> {
                     true
                 }
                 TE::Unit { .. }
+                | TE::PackClosure(_)
+                | TE::CallClosure(_, _)
                 | TE::Builtin(_, _)
                 | TE::Vector(_, _, _, _)
                 | TE::IfElse(_, _, _)
@@ -1207,7 +1209,7 @@ impl TypingVisitorContext for TypingAnalysisContext<'_> {
             // All of these cases just need to recur, which the visitor will handle for us.
             N::Type_::Unit
             | N::Type_::Ref(_, _)
-            | N::Type_::Fun(_, _)
+            | N::Type_::Fun(_, _, _)
             | N::Type_::Var(_)
             | N::Type_::Anything
             | N::Type_::UnresolvedError => false,
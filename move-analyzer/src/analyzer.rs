@@ -7,10 +7,13 @@ use anyhow::Result;
 use crossbeam::channel::{bounded, select};
 use lsp_server::{Connection, Message, Notification, Request, Response};
 use lsp_types::{
-    notification::Notification as _, request::Request as _, CompletionOptions, Diagnostic,
-    HoverProviderCapability, InlayHintOptions, InlayHintServerCapabilities, OneOf, SaveOptions,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
-    TypeDefinitionProviderCapability, WorkDoneProgressOptions,
+    notification::Notification as _, request::Request as _, CallHierarchyServerCapability,
+    CodeActionOptions, CodeActionProviderCapability, CompletionOptions, Diagnostic,
+    HoverProviderCapability, InlayHintOptions, InlayHintServerCapabilities, OneOf, RenameOptions,
+    SaveOptions, SemanticTokensFullOptions, SemanticTokensOptions,
+    SemanticTokensServerCapabilities, SignatureHelpOptions, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextDocumentSyncOptions, TypeDefinitionProviderCapability,
+    WorkDoneProgressOptions,
 };
 use move_compiler::linters::LintLevel;
 use move_package::source_package::parsed_manifest::Dependencies;
@@ -21,8 +24,9 @@ use std::{
 };
 
 use crate::{
-    completions::on_completion_request, context::Context, inlay_hints, symbols,
-    vfs::on_text_document_sync_notification,
+    call_hierarchy, code_action, completions::on_completion_request, context::Context, formatting,
+    inlay_hints, rename, semantic_tokens, signature_help::on_signature_help_request, symbols,
+    vfs::on_text_document_sync_notification, workspace_symbols,
 };
 use url::Url;
 use vfs::{impls::memory::MemoryFS, VfsPath};
@@ -97,6 +101,15 @@ pub fn run(implicit_deps: Dependencies) {
             },
             completion_item: None,
         }),
+        // The server shows the signature of the called function when the cursor is inside
+        // the argument list, either at its beginning or after an argument separator.
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+            retrigger_characters: None,
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
         definition_provider: Some(OneOf::Left(true)),
         type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
         references_provider: Some(OneOf::Left(true)),
@@ -109,6 +122,45 @@ pub fn run(implicit_deps: Dependencies) {
                 resolve_provider: None,
             },
         ))),
+        // The server checks if the symbol under the cursor can be renamed before the user is
+        // asked to provide a new name.
+        rename_provider: Some(OneOf::Right(RenameOptions {
+            prepare_provider: Some(true),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        })),
+        // Edits of code actions migrating files to Move 2024 are expensive to compute so they
+        // are computed only when the action is resolved.
+        code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![
+                lsp_types::CodeActionKind::QUICKFIX,
+                lsp_types::CodeActionKind::new(code_action::MIGRATE_KIND),
+            ]),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+            resolve_provider: Some(true),
+        })),
+        // Whole files are formatted using options from the package manifest rather than the ones
+        // provided by the editor.
+        document_formatting_provider: Some(OneOf::Left(true)),
+        // Calls are collected from all packages loaded by the server.
+        call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+        // Symbols are searched for in all packages loaded by the server (including their
+        // dependencies).
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        // Identifiers are classified based on their resolved definitions.
+        semantic_tokens_provider: Some(SemanticTokensServerCapabilities::SemanticTokensOptions(
+            SemanticTokensOptions {
+                work_done_progress_options: WorkDoneProgressOptions {
+                    work_done_progress: None,
+                },
+                legend: semantic_tokens::legend(),
+                range: Some(true),
+                full: Some(SemanticTokensFullOptions::Bool(true)),
+            },
+        )),
         ..Default::default()
     })
     .expect("could not serialize server capabilities");
@@ -310,6 +362,9 @@ fn on_request(
             pkg_dependencies,
             implicit_deps,
         ),
+        lsp_types::request::SignatureHelpRequest::METHOD => {
+            on_signature_help_request(context, request);
+        }
         lsp_types::request::GotoDefinition::METHOD => {
             symbols::on_go_to_def_request(context, request);
         }
@@ -328,6 +383,44 @@ fn on_request(
         lsp_types::request::InlayHintRequest::METHOD => {
             inlay_hints::on_inlay_hint_request(context, request);
         }
+        lsp_types::request::PrepareRenameRequest::METHOD => {
+            rename::on_prepare_rename_request(context, request);
+        }
+        lsp_types::request::Rename::METHOD => {
+            rename::on_rename_request(context, request);
+        }
+        lsp_types::request::CodeActionRequest::METHOD => {
+            code_action::on_code_action_request(context, request);
+        }
+        lsp_types::request::CodeActionResolveRequest::METHOD => {
+            code_action::on_code_action_resolve_request(
+                context,
+                request,
+                ide_files_root.clone(),
+                implicit_deps,
+            );
+        }
+        lsp_types::request::CallHierarchyPrepare::METHOD => {
+            call_hierarchy::on_prepare_call_hierarchy_request(context, request);
+        }
+        lsp_types::request::CallHierarchyIncomingCalls::METHOD => {
+            call_hierarchy::on_incoming_calls_request(context, request);
+        }
+        lsp_types::request::CallHierarchyOutgoingCalls::METHOD => {
+            call_hierarchy::on_outgoing_calls_request(context, request);
+        }
+        lsp_types::request::WorkspaceSymbolRequest::METHOD => {
+            workspace_symbols::on_workspace_symbol_request(context, request);
+        }
+        lsp_types::request::SemanticTokensFullRequest::METHOD => {
+            semantic_tokens::on_semantic_tokens_full_request(context, request);
+        }
+        lsp_types::request::SemanticTokensRangeRequest::METHOD => {
+            semantic_tokens::on_semantic_tokens_range_request(context, request);
+        }
+        lsp_types::request::Formatting::METHOD => {
+            formatting::on_formatting_request(context, request, ide_files_root.clone());
+        }
         lsp_types::request::Shutdown::METHOD => {
             eprintln!("Shutdown request received");
             let response =
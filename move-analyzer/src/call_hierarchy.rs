@@ -0,0 +1,237 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the call hierarchy language server commands. The call graph is built
+//! from call sites recorded during symbolication (`CallInfo` entries in `ModuleDefs`), and calls
+//! are attributed to the function whose definition encloses the call site.
+//!
+//! Incoming calls are collected from all packages currently loaded by the language server (and
+//! not only from the package containing the function) so that, for example, callers of a
+//! function defined in a dependency can be found in any of the packages opened in the IDE. Calls
+//! located in bodies of macros are not included as macro bodies are not type checked until they
+//! are expanded.
+
+use crate::{
+    context::Context,
+    symbols::{DefInfo, SymbolicatorRunner, Symbols},
+};
+use lsp_server::Request;
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
+    Position, Range, SymbolKind, SymbolTag,
+};
+use move_ir_types::location::*;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+use url::Url;
+
+/// Handles prepare call hierarchy request of the language server
+pub fn on_prepare_call_hierarchy_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<CallHierarchyPrepareParams>(request.params.clone())
+        .expect("could not deserialize prepare call hierarchy request");
+
+    let path = parameters
+        .text_document_position_params
+        .text_document
+        .uri
+        .to_file_path()
+        .unwrap();
+    let pos = parameters.text_document_position_params.position;
+
+    let symbols_map = context.symbols.lock().unwrap();
+    let items = prepare_call_hierarchy(&symbols_map, &path, pos);
+    send_call_hierarchy_response(context, request, items);
+}
+
+/// Computes call hierarchy items for a function used or defined at a given position.
+pub(crate) fn prepare_call_hierarchy(
+    symbols_map: &BTreeMap<PathBuf, Symbols>,
+    path: &Path,
+    pos: Position,
+) -> Option<Vec<CallHierarchyItem>> {
+    let symbols = symbols_map.get(&SymbolicatorRunner::root_dir(path)?)?;
+    let use_def = symbols
+        .line_uses(path, pos.line)
+        .into_iter()
+        .find(|u| pos.character >= u.col_start() && pos.character <= u.col_end())?;
+    call_hierarchy_item(symbols, &use_def.def_loc()).map(|item| vec![item])
+}
+
+/// Handles incoming calls request of the language server
+pub fn on_incoming_calls_request(context: &Context, request: &Request) {
+    let parameters =
+        serde_json::from_value::<CallHierarchyIncomingCallsParams>(request.params.clone())
+            .expect("could not deserialize incoming calls request");
+
+    let symbols_map = context.symbols.lock().unwrap();
+    let calls = incoming_calls(&symbols_map, &parameters.item);
+    send_call_hierarchy_response(context, request, calls);
+}
+
+/// Computes calls of a function represented by a given call hierarchy item.
+pub(crate) fn incoming_calls(
+    symbols_map: &BTreeMap<PathBuf, Symbols>,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let callee_loc = function_loc(symbols_map, item)?;
+    // callers (along with the symbols where they were found and call sites), the same call site
+    // can be found in symbols of more than one package if the caller is defined in a dependency
+    let mut callers: BTreeMap<Loc, (&Symbols, BTreeSet<Loc>)> = BTreeMap::new();
+    for symbols in symbols_map.values() {
+        for mod_defs in symbols.file_mods.values().flatten() {
+            for (call_loc, call_info) in &mod_defs.call_infos {
+                if call_info.def_loc != Some(callee_loc) {
+                    continue;
+                }
+                let Some(caller_loc) = mod_defs.enclosing_function(call_loc) else {
+                    continue;
+                };
+                callers
+                    .entry(caller_loc)
+                    .or_insert_with(|| (symbols, BTreeSet::new()))
+                    .1
+                    .insert(*call_loc);
+            }
+        }
+    }
+    let calls = callers
+        .into_iter()
+        .filter_map(|(caller_loc, (symbols, call_locs))| {
+            Some(CallHierarchyIncomingCall {
+                from: call_hierarchy_item(symbols, &caller_loc)?,
+                from_ranges: ranges(symbols, &call_locs),
+            })
+        })
+        .collect::<Vec<_>>();
+    Some(calls)
+}
+
+/// Handles outgoing calls request of the language server
+pub fn on_outgoing_calls_request(context: &Context, request: &Request) {
+    let parameters =
+        serde_json::from_value::<CallHierarchyOutgoingCallsParams>(request.params.clone())
+            .expect("could not deserialize outgoing calls request");
+
+    let symbols_map = context.symbols.lock().unwrap();
+    let calls = outgoing_calls(&symbols_map, &parameters.item);
+    send_call_hierarchy_response(context, request, calls);
+}
+
+/// Computes calls made by a function represented by a given call hierarchy item.
+pub(crate) fn outgoing_calls(
+    symbols_map: &BTreeMap<PathBuf, Symbols>,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let caller_loc = function_loc(symbols_map, item)?;
+    let path = item.uri.to_file_path().ok()?;
+    let (symbols, mod_defs) = symbols_map.values().find_map(|symbols| {
+        let mod_defs = symbols.file_mods.get(&path)?.iter().find(|mod_defs| {
+            mod_defs
+                .functions
+                .values()
+                .any(|fun_def| fun_def.name_loc == caller_loc)
+        })?;
+        Some((symbols, mod_defs))
+    })?;
+    let mut callees: BTreeMap<Loc, BTreeSet<Loc>> = BTreeMap::new();
+    for (call_loc, call_info) in &mod_defs.call_infos {
+        let Some(callee_loc) = call_info.def_loc else {
+            continue;
+        };
+        if mod_defs.enclosing_function(call_loc) == Some(caller_loc) {
+            callees.entry(callee_loc).or_default().insert(*call_loc);
+        }
+    }
+    let calls = callees
+        .into_iter()
+        .filter_map(|(callee_loc, call_locs)| {
+            Some(CallHierarchyOutgoingCall {
+                to: call_hierarchy_item(symbols, &callee_loc)?,
+                from_ranges: ranges(symbols, &call_locs),
+            })
+        })
+        .collect::<Vec<_>>();
+    Some(calls)
+}
+
+fn send_call_hierarchy_response<T: Serialize>(
+    context: &Context,
+    request: &Request,
+    result: Option<Vec<T>>,
+) {
+    eprintln!(
+        "about to send call hierarchy response with {} item(s)",
+        result.as_ref().map_or(0, |items| items.len())
+    );
+    let response = lsp_server::Response::new_ok(request.id.clone(), result);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send call hierarchy response: {:?}", err);
+    }
+}
+
+/// Creates call hierarchy item for a function defined at a given location.
+fn call_hierarchy_item(symbols: &Symbols, def_loc: &Loc) -> Option<CallHierarchyItem> {
+    let Some(DefInfo::Function(mod_ident, visibility, _, name, ..)) = symbols.def_info(def_loc)
+    else {
+        return None;
+    };
+    let path = symbols
+        .files
+        .file_name_mapping()
+        .get(&def_loc.file_hash())?;
+    let range = symbols.files.lsp_range_opt(def_loc)?;
+    let deprecated = symbols
+        .file_mods
+        .get(path)
+        .into_iter()
+        .flatten()
+        .any(|mod_defs| mod_defs.deprecated.contains(def_loc));
+    let visibility = visibility.to_string();
+    Some(CallHierarchyItem {
+        name: name.to_string(),
+        kind: SymbolKind::FUNCTION,
+        tags: deprecated.then(|| vec![SymbolTag::DEPRECATED]),
+        detail: Some(if visibility.is_empty() {
+            mod_ident.to_string()
+        } else {
+            format!("{visibility} {mod_ident}")
+        }),
+        uri: Url::from_file_path(path).ok()?,
+        range,
+        selection_range: range,
+        data: None,
+    })
+}
+
+/// Finds location of the function represented by a given call hierarchy item.
+fn function_loc(symbols_map: &BTreeMap<PathBuf, Symbols>, item: &CallHierarchyItem) -> Option<Loc> {
+    let path = item.uri.to_file_path().ok()?;
+    symbols_map
+        .values()
+        .find_map(|symbols| function_loc_in_file(symbols, &path, item.selection_range))
+}
+
+fn function_loc_in_file(symbols: &Symbols, path: &Path, range: Range) -> Option<Loc> {
+    symbols
+        .file_mods
+        .get(path)?
+        .iter()
+        .flat_map(|mod_defs| mod_defs.functions.values())
+        .map(|fun_def| fun_def.name_loc)
+        .find(|loc| symbols.files.lsp_range_opt(loc) == Some(range))
+}
+
+fn ranges(symbols: &Symbols, locs: &BTreeSet<Loc>) -> Vec<Range> {
+    locs.iter()
+        .filter_map(|loc| symbols.files.lsp_range_opt(loc))
+        .collect()
+}
@@ -0,0 +1,321 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the code action language server command (along with its resolve
+//! companion). Three kinds of code actions are offered:
+//! - quick fixes computed by the compiler (e.g., by linters) - these are attached to the
+//!   diagnostics sent to the client (see `DiagnosticData`) so that they can be turned into code
+//!   actions without re-compiling the package
+//! - suppression of a warning by adding the appropriate `#[allow(...)]` attribute to the
+//!   enclosing module member (or the module itself)
+//! - migration of a file in a legacy package to Move 2024 - this one requires compiling the whole
+//!   package in migration mode so the actual edit is only computed when the action is resolved
+//!
+//! Note that migrating a single file does not update the package manifest - once all files have
+//! been migrated, the edition in `Move.toml` has to be set to Move 2024 for the package to build.
+
+use crate::{
+    context::Context,
+    diagnostics::DiagnosticData,
+    symbols::{SymbolicatorRunner, Symbols},
+    utils::lsp_position_to_loc,
+};
+use anyhow::{anyhow, Result};
+use lsp_server::{Request, RequestId};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Diagnostic, Position, Range,
+    TextEdit, WorkspaceEdit,
+};
+use move_compiler::editions::{Edition, Flavor};
+use move_package::{
+    compilation::build_plan::BuildPlan,
+    source_package::{
+        manifest_parser::parse_move_manifest_from_file, parsed_manifest::Dependencies,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+use tempfile::tempdir;
+use url::Url;
+use vfs::{
+    impls::{memory::MemoryFS, overlay::OverlayFS, physical::PhysicalFS},
+    VfsPath,
+};
+
+/// Kind of the code action migrating a file to Move 2024
+pub const MIGRATE_KIND: &str = "source.migrate";
+
+/// Data attached to the (unresolved) migration code action
+#[derive(Debug, Serialize, Deserialize)]
+struct MigrationData {
+    /// File to be migrated
+    migrate: PathBuf,
+}
+
+/// Handles code action request of the language server
+pub fn on_code_action_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<CodeActionParams>(request.params.clone())
+        .expect("could not deserialize code action request");
+
+    let path = parameters.text_document.uri.to_file_path().unwrap();
+    let only = parameters.context.only.as_ref();
+    let mut actions = vec![];
+
+    if kind_requested(only, &CodeActionKind::QUICKFIX) {
+        let symbols_map = context.symbols.lock().unwrap();
+        let symbols = SymbolicatorRunner::root_dir(&path).and_then(|p| symbols_map.get(&p));
+        for diag in &parameters.context.diagnostics {
+            diagnostic_actions(
+                &mut actions,
+                symbols,
+                &parameters.text_document.uri,
+                &path,
+                diag,
+            );
+        }
+    }
+    if kind_requested(only, &CodeActionKind::new(MIGRATE_KIND)) && needs_migration(&path) {
+        actions.push(CodeAction {
+            title: "Migrate file to Move 2024".to_string(),
+            kind: Some(CodeActionKind::new(MIGRATE_KIND)),
+            data: serde_json::to_value(MigrationData {
+                migrate: path.clone(),
+            })
+            .ok(),
+            ..Default::default()
+        });
+    }
+
+    eprintln!("about to send {} code action(s)", actions.len());
+    let actions = actions
+        .into_iter()
+        .map(CodeActionOrCommand::CodeAction)
+        .collect::<Vec<_>>();
+    send_code_action_response(context, request.id.clone(), Ok(actions));
+}
+
+/// Handles code action resolve request of the language server (only migration actions need to be
+/// resolved as edits for all other actions are computed eagerly)
+pub fn on_code_action_resolve_request(
+    context: &Context,
+    request: &Request,
+    ide_files_root: VfsPath,
+    implicit_deps: Dependencies,
+) {
+    let mut action = serde_json::from_value::<CodeAction>(request.params.clone())
+        .expect("could not deserialize code action resolve request");
+
+    let result = match action
+        .data
+        .clone()
+        .and_then(|data| serde_json::from_value::<MigrationData>(data).ok())
+    {
+        Some(MigrationData { migrate }) => migration_edit(ide_files_root, &migrate, implicit_deps)
+            .map(|edit| {
+                let uri = Url::from_file_path(&migrate).unwrap();
+                action.edit = Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(uri, edit.into_iter().collect())])),
+                    ..Default::default()
+                });
+                action
+            }),
+        None => Ok(action),
+    };
+    send_code_action_response(context, request.id.clone(), result);
+}
+
+fn send_code_action_response<T: Serialize>(context: &Context, id: RequestId, result: Result<T>) {
+    let response = match result {
+        Ok(value) => lsp_server::Response::new_ok(id, value),
+        Err(err) => {
+            eprintln!("code action failed: {err}");
+            lsp_server::Response::new_err(
+                id,
+                lsp_server::ErrorCode::RequestFailed as i32,
+                err.to_string(),
+            )
+        }
+    };
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send code action response: {:?}", err);
+    }
+}
+
+/// Checks if a given kind of code actions was requested by the client (code action kinds are
+/// hierarchical so requesting `source` includes `source.migrate`).
+pub(crate) fn kind_requested(only: Option<&Vec<CodeActionKind>>, kind: &CodeActionKind) -> bool {
+    let Some(only) = only else {
+        return true;
+    };
+    only.iter().any(|requested| {
+        kind.as_str() == requested.as_str()
+            || kind
+                .as_str()
+                .strip_prefix(requested.as_str())
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
+/// Computes quick fixes for a given diagnostic.
+pub(crate) fn diagnostic_actions(
+    actions: &mut Vec<CodeAction>,
+    symbols: Option<&Symbols>,
+    uri: &Url,
+    path: &Path,
+    diag: &Diagnostic,
+) {
+    let Some(data) = diag
+        .data
+        .clone()
+        .and_then(|data| serde_json::from_value::<DiagnosticData>(data).ok())
+    else {
+        return;
+    };
+    let mk_action = |title: String, edits: Vec<TextEdit>, is_preferred: bool| CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        diagnostics: Some(vec![diag.clone()]),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..Default::default()
+        }),
+        is_preferred: Some(is_preferred),
+        ..Default::default()
+    };
+    for (idx, fix) in data.fixes.into_iter().enumerate() {
+        actions.push(mk_action(fix.title, fix.edits, idx == 0));
+    }
+    if let Some(attr) = data.filter_attribute {
+        if let Some(edit) = symbols.and_then(|s| suppression_edit(s, path, diag.range.start, &attr))
+        {
+            actions.push(mk_action(
+                format!("Suppress warning with '#[{attr}]'"),
+                vec![edit],
+                false,
+            ));
+        }
+    }
+}
+
+/// Computes an edit adding an attribute (e.g., `allow(lint(name))`) to the module member (or the
+/// module itself) enclosing a given position. If the member already has an `allow` attribute, the
+/// new filter is added to it instead as duplicate attributes are not allowed.
+pub(crate) fn suppression_edit(
+    symbols: &Symbols,
+    path: &Path,
+    pos: Position,
+    attr: &str,
+) -> Option<TextEdit> {
+    let fhash = symbols.file_hash(path)?;
+    let loc = lsp_position_to_loc(&symbols.files, fhash, &pos)?;
+    let (_, source) = symbols.files.get(&fhash)?;
+
+    // a file may contain more than one module so pick the closest of their enclosing members
+    let member_loc = symbols
+        .file_mods
+        .get(path)?
+        .iter()
+        .filter_map(|mod_defs| mod_defs.enclosing_member(&loc))
+        .max_by_key(|loc| loc.start())?;
+
+    let member_line_start = source[..member_loc.start() as usize]
+        .rfind('\n')
+        .map_or(0, |idx| idx + 1);
+    let member_line = source[..member_line_start].matches('\n').count() as u32;
+
+    // look for an existing `allow` attribute among attributes and comments preceding the member
+    let (attr_name, filter) = attr.split_once('(')?;
+    let filter = filter.strip_suffix(')')?;
+    let attr_start = format!("{attr_name}(");
+    for (idx, line) in source[..member_line_start].lines().rev().enumerate() {
+        let trimmed = line.trim_start();
+        if !trimmed.starts_with("#[") && !trimmed.starts_with("//") && !trimmed.is_empty() {
+            break;
+        }
+        if !trimmed.starts_with("#[") {
+            continue;
+        }
+        if let Some(col) = line.find(&attr_start) {
+            let line_no = member_line - 1 - idx as u32;
+            let character = line[..col + attr_start.len()].chars().count() as u32;
+            let pos = Position::new(line_no, character);
+            return Some(TextEdit::new(Range::new(pos, pos), format!("{filter}, ")));
+        }
+    }
+
+    let indent = source[member_line_start..]
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect::<String>();
+    let pos = Position::new(member_line, 0);
+    Some(TextEdit::new(
+        Range::new(pos, pos),
+        format!("{indent}#[{attr}]\n"),
+    ))
+}
+
+/// Checks if the package containing a given file is a legacy one and can be migrated to Move 2024.
+fn needs_migration(path: &Path) -> bool {
+    SymbolicatorRunner::root_dir(path)
+        .and_then(|pkg_path| parse_move_manifest_from_file(&pkg_path).ok())
+        .is_some_and(|manifest| {
+            manifest
+                .package
+                .edition
+                .is_none_or(|edition| edition == Edition::LEGACY)
+        })
+}
+
+/// Computes the edit migrating a given file to Move 2024 (if the file needs any changes). The
+/// package is compiled in the migration mode using the content of files opened in the IDE.
+pub(crate) fn migration_edit(
+    ide_files_root: VfsPath,
+    path: &Path,
+    implicit_deps: Dependencies,
+) -> Result<Option<TextEdit>> {
+    let pkg_path = SymbolicatorRunner::root_dir(path)
+        .ok_or_else(|| anyhow!("package root not found for {:?}", path))?;
+    let build_config = move_package::BuildConfig {
+        test_mode: true,
+        dev_mode: true,
+        install_dir: Some(tempdir()?.path().to_path_buf()),
+        default_flavor: Some(Flavor::Bos),
+        skip_fetch_latest_git_deps: true,
+        implicit_dependencies: implicit_deps,
+        ..Default::default()
+    };
+    // resolution graph diagnostics are only needed for CLI commands so ignore them by passing a
+    // vector as the writer
+    let resolution_graph =
+        build_config.resolution_graph_for_package(&pkg_path, None, &mut Vec::new())?;
+    let overlay_fs_root = VfsPath::new(OverlayFS::new(&[
+        VfsPath::new(MemoryFS::new()),
+        ide_files_root,
+        VfsPath::new(PhysicalFS::new("/")),
+    ]));
+    let build_plan = BuildPlan::create(&resolution_graph)?.set_compiler_vfs_root(overlay_fs_root);
+    let Some(mut migration) = build_plan.migrate(&mut Vec::new())? else {
+        return Ok(None);
+    };
+    let Some((original, migrated)) = migration.migrated_file(path) else {
+        return Ok(None);
+    };
+    // replace the whole content of the file
+    let end_line = original.matches('\n').count() as u32;
+    let end_character = original
+        .rsplit('\n')
+        .next()
+        .map_or(0, |last_line| last_line.chars().count()) as u32;
+    Ok(Some(TextEdit::new(
+        Range::new(Position::new(0, 0), Position::new(end_line, end_character)),
+        migrated,
+    )))
+}
@@ -115,7 +115,7 @@ pub fn call_completion_item(
 }
 
 fn lambda_snippet(sp!(_, ty): &Type, snippet_idx: &mut i32) -> Option<String> {
-    if let Type_::Fun(vec, _) = ty {
+    if let Type_::Fun(vec, _, _) = ty {
         let arg_snippets = vec
             .iter()
             .map(|_| {
@@ -4,30 +4,50 @@
 
 use crate::utils::{loc_end_to_lsp_position_opt, loc_start_to_lsp_position_opt};
 use codespan_reporting::diagnostic::Severity;
-use lsp_types::{Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, Range};
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, NumberOrString, Range,
+    TextEdit,
+};
 use move_command_line_common::files::FileHash;
-use move_compiler::shared::files::MappedFiles;
-use move_ir_types::location::Loc;
+use move_compiler::{
+    diagnostics::{Diagnostic as CompilerDiagnostic, Diagnostics as CompilerDiagnostics},
+    shared::files::MappedFiles,
+};
+use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, path::PathBuf};
 use url::Url;
 
-/// Converts diagnostics from the codespan format to the format understood by the language server.
+/// Additional information attached to a diagnostic sent to the client which is used to compute
+/// code actions for this diagnostic.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DiagnosticData {
+    /// Fixes that can be applied to resolve the diagnostic
+    pub fixes: Vec<QuickFix>,
+    /// Contents of the attribute that would suppress the diagnostic, if any
+    pub filter_attribute: Option<String>,
+}
+
+/// A fix that can be applied to the file containing the diagnostic.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct QuickFix {
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Converts diagnostics from the compiler format to the format understood by the language server.
 pub fn lsp_diagnostics(
-    diagnostics: &Vec<(
-        codespan_reporting::diagnostic::Severity,
-        &'static str,
-        (Loc, String),
-        Vec<(Loc, String)>,
-        Vec<String>,
-    )>,
+    diagnostics: CompilerDiagnostics,
     files: &MappedFiles,
 ) -> BTreeMap<PathBuf, Vec<Diagnostic>> {
     let mut lsp_diagnostics = BTreeMap::new();
-    for (s, _, (loc, msg), labels, notes) in diagnostics {
+    for diag in diagnostics.into_vec() {
+        let loc = diag.primary_loc();
         let fpath = files.file_path(&loc.file_hash());
-        if let Some(start) = loc_start_to_lsp_position_opt(files, loc) {
-            if let Some(end) = loc_end_to_lsp_position_opt(files, loc) {
+        if let Some(start) = loc_start_to_lsp_position_opt(files, &loc) {
+            if let Some(end) = loc_end_to_lsp_position_opt(files, &loc) {
                 let range = Range::new(start, end);
+                let labels = diag.secondary_labels();
+                let notes = diag.notes();
                 let related_info_opt = if labels.is_empty() && notes.is_empty() {
                     None
                 } else {
@@ -60,24 +80,66 @@ pub fn lsp_diagnostics(
                             .collect(),
                     )
                 };
+                let (code, _) = diag.info().clone().render();
                 lsp_diagnostics
                     .entry(fpath.to_path_buf())
                     .or_insert_with(Vec::new)
-                    .push(Diagnostic::new(
-                        range,
-                        Some(severity(*s)),
-                        None,
-                        None,
-                        msg.to_string(),
-                        related_info_opt,
-                        None,
-                    ));
+                    .push(Diagnostic {
+                        code: Some(NumberOrString::String(code)),
+                        data: diagnostic_data(&diag, files),
+                        ..Diagnostic::new(
+                            range,
+                            Some(severity(diag.info().severity().into_codespan_severity())),
+                            None,
+                            None,
+                            diag.primary_msg().to_string(),
+                            related_info_opt,
+                            None,
+                        )
+                    });
             }
         }
     }
     lsp_diagnostics
 }
 
+/// Computes data attached to a diagnostic, if there is any. Fixes containing edits outside of the
+/// file where the diagnostic is located are not included.
+fn diagnostic_data(diag: &CompilerDiagnostic, files: &MappedFiles) -> Option<serde_json::Value> {
+    let fhash = diag.primary_loc().file_hash();
+    let fixes = diag
+        .fixes()
+        .iter()
+        .filter_map(|fix| {
+            let edits = fix
+                .edits
+                .iter()
+                .map(|(loc, new_text)| {
+                    if loc.file_hash() != fhash {
+                        return None;
+                    }
+                    let start = loc_start_to_lsp_position_opt(files, loc)?;
+                    let end = loc_end_to_lsp_position_opt(files, loc)?;
+                    Some(TextEdit::new(Range::new(start, end), new_text.clone()))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(QuickFix {
+                title: fix.title.clone(),
+                edits,
+            })
+        })
+        .collect::<Vec<_>>();
+    let filter_attribute = diag.filter_attribute().map(|attr| attr.to_string());
+    if fixes.is_empty() && filter_attribute.is_none() {
+        return None;
+    }
+    serde_json::to_value(DiagnosticData {
+        fixes,
+        filter_attribute,
+    })
+    .ok()
+}
+
 /// Produces empty diagnostics in the format understood by the language server for all files that
 /// the language server is aware of.
 pub fn lsp_empty_diagnostics(
@@ -0,0 +1,87 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the document formatting language server command. Files are formatted
+//! using the compiler's source formatter with the options specified in the `[format]` section of
+//! the package manifest (if any) - editor-provided options (e.g., tab size) are ignored so that
+//! formatting in the IDE is consistent with the `move fmt` command.
+
+use crate::{context::Context, symbols::SymbolicatorRunner};
+use anyhow::{anyhow, Result};
+use lsp_server::Request;
+use lsp_types::{DocumentFormattingParams, Position, Range, TextEdit};
+use move_compiler::formatter::format_source;
+use move_package::source_package::manifest_parser::parse_move_manifest_from_file;
+use std::path::Path;
+use vfs::VfsPath;
+
+/// Handles formatting request of the language server
+pub fn on_formatting_request(context: &Context, request: &Request, ide_files_root: VfsPath) {
+    let parameters = serde_json::from_value::<DocumentFormattingParams>(request.params.clone())
+        .expect("could not deserialize formatting request");
+
+    let path = parameters.text_document.uri.to_file_path().unwrap();
+    let response = match formatting_edits(&ide_files_root, &path) {
+        Ok(edits) => {
+            eprintln!("about to send {} formatting edit(s)", edits.len());
+            lsp_server::Response::new_ok(request.id.clone(), edits)
+        }
+        Err(err) => {
+            eprintln!("formatting failed: {err}");
+            lsp_server::Response::new_err(
+                request.id.clone(),
+                lsp_server::ErrorCode::RequestFailed as i32,
+                err.to_string(),
+            )
+        }
+    };
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send formatting response: {:?}", err);
+    }
+}
+
+/// Computes edits formatting a given file (replacing its whole content) using the content of the
+/// file opened in the IDE, if available.
+fn formatting_edits(ide_files_root: &VfsPath, path: &Path) -> Result<Vec<TextEdit>> {
+    let source = match ide_files_root
+        .join(path.to_string_lossy())
+        .and_then(|vfs_path| vfs_path.read_to_string())
+    {
+        Ok(source) => source,
+        Err(_) => std::fs::read_to_string(path)?,
+    };
+
+    let manifest = SymbolicatorRunner::root_dir(path)
+        .map(|pkg_path| parse_move_manifest_from_file(&pkg_path))
+        .transpose()?;
+    let edition = manifest
+        .as_ref()
+        .and_then(|manifest| manifest.package.edition)
+        .unwrap_or_default();
+    let config = manifest
+        .and_then(|manifest| manifest.format)
+        .unwrap_or_default();
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("invalid file path {:?}", path))?
+        .to_string_lossy();
+    let formatted = format_source(&file_name, &source, edition, &config)?;
+    if formatted == source {
+        return Ok(vec![]);
+    }
+    let end_line = source.matches('\n').count() as u32;
+    let end_character = source
+        .rsplit('\n')
+        .next()
+        .map_or(0, |last_line| last_line.chars().count()) as u32;
+    Ok(vec![TextEdit::new(
+        Range::new(Position::new(0, 0), Position::new(end_line, end_character)),
+        formatted,
+    )])
+}
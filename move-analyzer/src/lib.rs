@@ -8,11 +8,21 @@ extern crate move_ir_types;
 
 pub mod analysis;
 pub mod analyzer;
+pub mod call_hierarchy;
+pub mod code_action;
 pub mod compiler_info;
 pub mod completions;
 pub mod context;
 pub mod diagnostics;
+pub mod formatting;
 pub mod inlay_hints;
+pub mod rename;
+pub mod semantic_tokens;
+pub mod signature_help;
 pub mod symbols;
 pub mod utils;
 pub mod vfs;
+pub mod workspace_symbols;
+
+#[cfg(test)]
+mod unit_tests;
@@ -0,0 +1,526 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the rename language server command (as well as its prepare-rename
+//! companion) on top of the symbolication information computed in the `symbols` module.
+//!
+//! A rename is computed by locating the definition of the identifier under the cursor and then
+//! rewriting every reference to this definition recorded in the `references` map. References
+//! whose source text differs from the definition's name (e.g., uses of `use ... as ...` aliases,
+//! or method calls going through a `use fun` alias) are left untouched as they refer to the
+//! definition under a different name. Field/local punning in packs and unpacks (`S { f }`) is
+//! expanded to its explicit form (`S { f: g }`) whenever only one of the two names changes.
+//!
+//! Only definitions located in the package being edited can be renamed - definitions located in
+//! dependencies are rejected as there is no way to update all their uses.
+//!
+//! Columns of positions computed from symbolication information are counted in characters while
+//! the LSP counts them in UTF-16 code units, so they are converted when received and sent.
+
+use crate::{
+    context::Context,
+    symbols::{DefInfo, MemberDefInfo, SymbolicatorRunner, Symbols, UseLoc},
+    utils::{char_col_to_utf16, utf16_col_to_char},
+};
+use lsp_server::{Request, RequestId};
+use lsp_types::{
+    Position, PrepareRenameResponse, Range, RenameParams, TextDocumentPositionParams, TextEdit,
+    WorkspaceEdit,
+};
+use move_command_line_common::files::FileHash;
+use move_compiler::{
+    naming::ast::{BuiltinFunction_, BuiltinTypeName_},
+    parser::{ast::Var, keywords::KEYWORDS},
+};
+use move_ir_types::location::*;
+use move_symbol_pool::Symbol;
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+use url::Url;
+
+/// Kinds of definitions that can be renamed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RenameKind {
+    Local,
+    Function,
+    Datatype,
+    Variant,
+    Field,
+    Const,
+}
+
+/// Edits in a single file: (line, start column) -> (end column, replacement text)
+type FileEdits = BTreeMap<(u32, u32), (u32, String)>;
+
+/// Definition to be renamed along with the range of the identifier under the cursor
+struct RenameTarget {
+    def_loc: Loc,
+    name: Symbol,
+    kind: RenameKind,
+    range: Range,
+}
+
+/// Handles prepare-rename request of the language server
+pub fn on_prepare_rename_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<TextDocumentPositionParams>(request.params.clone())
+        .expect("could not deserialize prepare rename request");
+
+    let fpath = parameters.text_document.uri.to_file_path().unwrap();
+    let symbols_map = &context.symbols.lock().unwrap();
+    let result = prepare_rename(symbols_map, &fpath, &parameters.position);
+    send_rename_response(context, request.id.clone(), result);
+}
+
+/// Computes the range (and the current name) of the identifier at a given position if it can be
+/// renamed.
+pub(crate) fn prepare_rename(
+    symbols_map: &BTreeMap<PathBuf, Symbols>,
+    fpath: &Path,
+    position: &Position,
+) -> Result<PrepareRenameResponse, String> {
+    let (pkg_path, symbols) = package_symbols(symbols_map, fpath)?;
+    let target = rename_target(symbols, &pkg_path, fpath, position)?;
+    Ok(PrepareRenameResponse::RangeWithPlaceholder {
+        range: target.range,
+        placeholder: target.name.to_string(),
+    })
+}
+
+/// Handles rename request of the language server
+pub fn on_rename_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<RenameParams>(request.params.clone())
+        .expect("could not deserialize rename request");
+
+    let fpath = parameters
+        .text_document_position
+        .text_document
+        .uri
+        .to_file_path()
+        .unwrap();
+    let position = parameters.text_document_position.position;
+    let symbols_map = &context.symbols.lock().unwrap();
+    let result = rename(symbols_map, &fpath, &position, &parameters.new_name);
+    send_rename_response(context, request.id.clone(), result);
+}
+
+/// Computes edits renaming the identifier at a given position to a new name.
+pub(crate) fn rename(
+    symbols_map: &BTreeMap<PathBuf, Symbols>,
+    fpath: &Path,
+    position: &Position,
+    new_name: &str,
+) -> Result<WorkspaceEdit, String> {
+    let (pkg_path, symbols) = package_symbols(symbols_map, fpath)?;
+    let target = rename_target(symbols, &pkg_path, fpath, position)?;
+    rename_edits(symbols, &target, new_name)
+}
+
+fn package_symbols<'a>(
+    symbols_map: &'a BTreeMap<PathBuf, Symbols>,
+    fpath: &Path,
+) -> Result<(PathBuf, &'a Symbols), String> {
+    SymbolicatorRunner::root_dir(fpath)
+        .and_then(|pkg_path| symbols_map.get(&pkg_path).map(|s| (pkg_path, s)))
+        .ok_or_else(|| "no symbols available for this file".to_string())
+}
+
+fn send_rename_response<T: serde::Serialize>(
+    context: &Context,
+    id: RequestId,
+    result: Result<T, String>,
+) {
+    let response = match result {
+        Ok(value) => lsp_server::Response::new_ok(id, value),
+        Err(msg) => {
+            eprintln!("rename rejected: {msg}");
+            lsp_server::Response::new_err(id, lsp_server::ErrorCode::RequestFailed as i32, msg)
+        }
+    };
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send rename response: {:?}", err);
+    }
+}
+
+/// Finds the definition of the identifier at the given position and checks if it can be renamed.
+fn rename_target(
+    symbols: &Symbols,
+    pkg_path: &Path,
+    fpath: &Path,
+    position: &Position,
+) -> Result<RenameTarget, String> {
+    let use_fhash = symbols
+        .file_hash(fpath)
+        .ok_or_else(|| "no symbols available for this file".to_string())?;
+    let line = line_text(symbols, use_fhash, position.line).unwrap_or_default();
+    let col = utf16_col_to_char(&line, position.character);
+    let Some(use_def) = symbols
+        .line_uses(fpath, position.line)
+        .into_iter()
+        .find(|u| col >= u.col_start() && col <= u.col_end())
+    else {
+        return Err("no symbol to rename at this position".to_string());
+    };
+    let def_loc = use_def.def_loc();
+    let (name, kind) = match symbols.def_info(&def_loc) {
+        Some(DefInfo::Local(name, ..)) => (*name, RenameKind::Local),
+        Some(DefInfo::Function(_, _, _, name, ..)) => (*name, RenameKind::Function),
+        Some(DefInfo::Struct(_, name, ..)) | Some(DefInfo::Enum(_, name, ..)) => {
+            (*name, RenameKind::Datatype)
+        }
+        Some(DefInfo::Variant(_, _, name, ..)) => (*name, RenameKind::Variant),
+        Some(DefInfo::Field(_, _, name, ..)) => (*name, RenameKind::Field),
+        Some(DefInfo::Const(_, name, ..)) => (*name, RenameKind::Const),
+        Some(DefInfo::Module(..)) => return Err("modules cannot be renamed".to_string()),
+        Some(DefInfo::Type(_)) | None => {
+            return Err("this symbol cannot be renamed".to_string());
+        }
+    };
+    if kind == RenameKind::Field && name.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err("positional fields cannot be renamed".to_string());
+    }
+    if !defined_in_package(symbols, pkg_path, &def_loc) {
+        return Err(format!(
+            "'{name}' is defined in a dependency and cannot be renamed"
+        ));
+    }
+    let start = Position {
+        line: position.line,
+        character: use_def.col_start(),
+    };
+    if source_text(symbols, use_fhash, start, use_def.col_end()).as_deref() != Some(name.as_str()) {
+        return Err(format!(
+            "cannot rename an alias - rename '{name}' at its definition instead"
+        ));
+    }
+    let range = Range {
+        start: Position {
+            line: position.line,
+            character: char_col_to_utf16(&line, use_def.col_start()),
+        },
+        end: Position {
+            line: position.line,
+            character: char_col_to_utf16(&line, use_def.col_end()),
+        },
+    };
+    Ok(RenameTarget {
+        def_loc,
+        name,
+        kind,
+        range,
+    })
+}
+
+/// Computes all edits required to rename the target to the new name.
+fn rename_edits(
+    symbols: &Symbols,
+    target: &RenameTarget,
+    new_name: &str,
+) -> Result<WorkspaceEdit, String> {
+    check_new_name(target, new_name)?;
+    if new_name == target.name.as_str() {
+        return Ok(WorkspaceEdit::default());
+    }
+    check_name_conflict(symbols, target, new_name)?;
+
+    let empty = BTreeSet::new();
+    let refs = symbols.references.get(&target.def_loc).unwrap_or(&empty);
+    let puns = punned_locs(symbols, target, refs);
+
+    // edits keyed on their start position so that an edit for a reference (which may need to
+    // expand punning) replaces the one for the definition if the two are at the same location
+    let mut edits: BTreeMap<FileHash, FileEdits> = BTreeMap::new();
+    let mut add_edit = |fhash, start: Position, col_end: u32, new_text: String| {
+        edits
+            .entry(fhash)
+            .or_default()
+            .insert((start.line, start.character), (col_end, new_text));
+    };
+
+    // the definition itself is normally included in the references but make sure it's renamed
+    // even if it is not
+    if let Some(def_start) = symbols.files.file_start_position_opt(&target.def_loc) {
+        let start: Position = def_start.position().into();
+        add_edit(
+            def_start.file_hash(),
+            start,
+            start.character + target.name.len() as u32,
+            new_name.to_string(),
+        );
+    }
+    for use_loc in refs {
+        let text = source_text(symbols, use_loc.fhash(), use_loc.start(), use_loc.col_end());
+        if text.as_deref() != Some(target.name.as_str()) {
+            // a use via an alias
+            continue;
+        }
+        let new_text = if puns.contains(use_loc) {
+            match target.kind {
+                RenameKind::Field => format!("{new_name}: {}", target.name),
+                _ => format!("{}: {new_name}", target.name),
+            }
+        } else {
+            new_name.to_string()
+        };
+        add_edit(
+            use_loc.fhash(),
+            use_loc.start(),
+            use_loc.col_end(),
+            new_text,
+        );
+    }
+
+    let mut changes = HashMap::new();
+    for (fhash, file_edits) in edits {
+        let Ok(uri) = Url::from_file_path(symbols.files.file_path(&fhash)) else {
+            continue;
+        };
+        let text_edits = file_edits
+            .into_iter()
+            .map(|((line, col_start), (col_end, new_text))| {
+                let text = line_text(symbols, fhash, line).unwrap_or_default();
+                TextEdit {
+                    range: Range {
+                        start: Position {
+                            line,
+                            character: char_col_to_utf16(&text, col_start),
+                        },
+                        end: Position {
+                            line,
+                            character: char_col_to_utf16(&text, col_end),
+                        },
+                    },
+                    new_text,
+                }
+            })
+            .collect::<Vec<_>>();
+        changes.insert(uri, text_edits);
+    }
+    Ok(WorkspaceEdit {
+        changes: Some(changes),
+        document_changes: None,
+        change_annotations: None,
+    })
+}
+
+/// Returns locations of the target's references that are shared with a field (when renaming
+/// a local) or with a local (when renaming a field), that is locations where field name
+/// punning is used.
+fn punned_locs(
+    symbols: &Symbols,
+    target: &RenameTarget,
+    refs: &BTreeSet<UseLoc>,
+) -> BTreeSet<UseLoc> {
+    let partner_is_field = match target.kind {
+        RenameKind::Local => true,
+        RenameKind::Field => false,
+        _ => return BTreeSet::new(),
+    };
+    symbols
+        .references
+        .iter()
+        .filter(|(def_loc, _)| {
+            matches!(
+                (partner_is_field, symbols.def_info(def_loc)),
+                (true, Some(DefInfo::Field(..))) | (false, Some(DefInfo::Local(..)))
+            )
+        })
+        .flat_map(|(_, uses)| uses.intersection(refs).copied())
+        .collect()
+}
+
+/// Checks if a given definition is located in the package rooted at `pkg_path` (rather than in
+/// one of its dependencies).
+fn defined_in_package(symbols: &Symbols, pkg_path: &Path, def_loc: &Loc) -> bool {
+    let def_path = symbols.files.file_path(&def_loc.file_hash());
+    let Some(def_pkg_path) = SymbolicatorRunner::root_dir(def_path) else {
+        return false;
+    };
+    let canonical = |p: &Path| dunce::canonicalize(p).unwrap_or_else(|_| p.to_path_buf());
+    canonical(&def_pkg_path) == canonical(pkg_path)
+}
+
+/// Retrieves source text of a given line.
+fn line_text(symbols: &Symbols, fhash: FileHash, line: u32) -> Option<String> {
+    let (_, content) = symbols.files.get(&fhash)?;
+    content.lines().nth(line as usize).map(str::to_string)
+}
+
+/// Retrieves source text of an identifier located on a single line (columns are counted in
+/// characters).
+fn source_text(
+    symbols: &Symbols,
+    fhash: FileHash,
+    start: Position,
+    col_end: u32,
+) -> Option<String> {
+    let line = line_text(symbols, fhash, start.line)?;
+    let text = line
+        .chars()
+        .skip(start.character as usize)
+        .take(col_end.checked_sub(start.character)? as usize)
+        .collect::<String>();
+    Some(text)
+}
+
+/// Checks if the new name is a valid name for the definition being renamed.
+fn check_new_name(target: &RenameTarget, new_name: &str) -> Result<(), String> {
+    let invalid = |reason: &str| Err(format!("invalid name '{new_name}': {reason}"));
+    let is_syntax_ident = Var::is_syntax_identifier_name(target.name);
+    let ident = if is_syntax_ident && target.kind == RenameKind::Local {
+        match new_name.strip_prefix('$') {
+            Some(rest) => rest,
+            None => return invalid("'macro' parameter names must start with '$'"),
+        }
+    } else {
+        new_name
+    };
+    let mut chars = ident.chars();
+    let valid_ident = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid_ident {
+        return invalid("not a valid identifier");
+    }
+    if KEYWORDS.contains(&ident) {
+        return invalid("reserved keyword");
+    }
+    let new_sym = Symbol::from(new_name);
+    match target.kind {
+        RenameKind::Local => {
+            if !is_syntax_ident && !Var::is_valid_name(new_sym) {
+                return invalid("local variable names must start with 'a'..'z' or '_'");
+            }
+        }
+        RenameKind::Function => {
+            if new_name.starts_with('_') {
+                return invalid("function names cannot start with '_'");
+            }
+        }
+        RenameKind::Datatype | RenameKind::Const => {
+            if !new_name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return invalid("struct, enum and constant names must start with 'A'..'Z'");
+            }
+        }
+        RenameKind::Variant | RenameKind::Field => (),
+    }
+    if target.kind != RenameKind::Local
+        && (BuiltinFunction_::all_names().contains(&new_sym)
+            || BuiltinTypeName_::all_names().contains(&new_sym)
+            || new_name == "Self")
+    {
+        return invalid("restricted name");
+    }
+    Ok(())
+}
+
+/// Checks if the new name is already used by another definition in the same namespace.
+fn check_name_conflict(
+    symbols: &Symbols,
+    target: &RenameTarget,
+    new_name: &str,
+) -> Result<(), String> {
+    let new_sym = Symbol::from(new_name);
+    let conflict = || {
+        Err(format!(
+            "cannot rename '{}' to '{new_name}' as this name is already in use",
+            target.name
+        ))
+    };
+    let fhash = target.def_loc.file_hash();
+    if target.kind == RenameKind::Local {
+        return if local_defined_in_member(symbols, &target.def_loc, new_sym) {
+            conflict()
+        } else {
+            Ok(())
+        };
+    }
+    let mod_ident = match symbols.def_info(&target.def_loc) {
+        Some(DefInfo::Function(mod_ident, ..))
+        | Some(DefInfo::Struct(mod_ident, ..))
+        | Some(DefInfo::Enum(mod_ident, ..))
+        | Some(DefInfo::Variant(mod_ident, ..))
+        | Some(DefInfo::Field(mod_ident, ..))
+        | Some(DefInfo::Const(mod_ident, ..)) => *mod_ident,
+        _ => return Ok(()),
+    };
+    let Some(mod_defs) = symbols.mod_defs(&fhash, mod_ident) else {
+        return Ok(());
+    };
+    let taken = match symbols.def_info(&target.def_loc) {
+        Some(DefInfo::Function(..)) => mod_defs.functions.contains_key(&new_sym),
+        Some(DefInfo::Struct(..)) | Some(DefInfo::Enum(..)) => {
+            mod_defs.structs.contains_key(&new_sym) || mod_defs.enums.contains_key(&new_sym)
+        }
+        Some(DefInfo::Const(..)) => mod_defs.constants.contains_key(&new_sym),
+        Some(DefInfo::Variant(_, enum_name, ..)) => {
+            mod_defs
+                .enums
+                .get(enum_name)
+                .is_some_and(|enum_def| match &enum_def.info {
+                    MemberDefInfo::Enum { variants_info } => variants_info.contains_key(&new_sym),
+                    _ => false,
+                })
+        }
+        Some(DefInfo::Field(_, datatype_name, ..)) => {
+            let in_struct = mod_defs
+                .structs
+                .get(datatype_name)
+                .is_some_and(|struct_def| match &struct_def.info {
+                    MemberDefInfo::Struct { field_defs, .. } => {
+                        field_defs.iter().any(|f| f.name == new_sym)
+                    }
+                    _ => false,
+                });
+            let in_variant =
+                mod_defs
+                    .enums
+                    .get(datatype_name)
+                    .is_some_and(|enum_def| match &enum_def.info {
+                        MemberDefInfo::Enum { variants_info } => {
+                            variants_info.values().any(|(_, field_defs, _)| {
+                                field_defs.iter().any(|f| f.loc == target.def_loc)
+                                    && field_defs.iter().any(|f| f.name == new_sym)
+                            })
+                        }
+                        _ => false,
+                    });
+            in_struct || in_variant
+        }
+        _ => false,
+    };
+    if taken {
+        conflict()
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks if a local named `name` is defined in the module member (e.g., a function) defining
+/// the local at `def_loc`. This is conservative as the two locals may never be in scope at the
+/// same time, but if they are then renaming could change which of them some uses refer to.
+fn local_defined_in_member(symbols: &Symbols, def_loc: &Loc, name: Symbol) -> bool {
+    let fhash = def_loc.file_hash();
+    let Some(mod_defs) = symbols.file_mods.get(symbols.files.file_path(&fhash)) else {
+        return false;
+    };
+    let enclosing_member = |loc: &Loc| {
+        mod_defs
+            .iter()
+            .filter_map(|mod_defs| mod_defs.enclosing_member(loc))
+            .max_by_key(|loc| loc.start())
+    };
+    let member_loc = enclosing_member(def_loc);
+    symbols.def_info.iter().any(|(loc, info)| {
+        matches!(info, DefInfo::Local(local_name, ..) if *local_name == name)
+            && loc.file_hash() == fhash
+            && enclosing_member(loc) == member_loc
+    })
+}
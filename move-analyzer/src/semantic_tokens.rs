@@ -0,0 +1,266 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the semantic tokens language server commands (for both whole files and
+//! ranges within files). Identifiers are classified based on the kind of their (resolved)
+//! definitions so that editors can tell apart, for example, enum variants from structs, constants
+//! from locals, or method calls from regular function calls, which is impossible to do reliably
+//! using regular expressions only.
+
+use crate::{
+    context::Context,
+    symbols::{DefInfo, FunType, SymbolicatorRunner, Symbols},
+};
+use lsp_server::Request;
+use lsp_types::{
+    Range, SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokens,
+    SemanticTokensLegend, SemanticTokensParams, SemanticTokensRangeParams,
+    SemanticTokensRangeResult, SemanticTokensResult,
+};
+use move_compiler::naming::ast::Type_;
+use move_ir_types::location::Loc;
+use serde::Serialize;
+use std::{collections::BTreeSet, path::Path};
+
+/// Token types (their position in this list is used as a token type identifier)
+const TOKEN_TYPES: &[SemanticTokenType] = &[
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::STRUCT,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::METHOD,
+    SemanticTokenType::MACRO,
+];
+
+/// Modifier of mutable locals
+const MUTABLE: SemanticTokenModifier = SemanticTokenModifier::new("mutable");
+/// Modifier of definitions only available in test mode
+const TEST_ONLY: SemanticTokenModifier = SemanticTokenModifier::new("testOnly");
+
+/// Token modifiers (their position in this list is used as a bit in a token modifiers set)
+const TOKEN_MODIFIERS: &[SemanticTokenModifier] = &[
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEPRECATED,
+    MUTABLE,
+    TEST_ONLY,
+];
+
+/// Legend describing token types and modifiers used by the server
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// Handles semantic tokens request for a whole file
+pub fn on_semantic_tokens_full_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<SemanticTokensParams>(request.params.clone())
+        .expect("could not deserialize semantic tokens request");
+    let path = parameters.text_document.uri.to_file_path().unwrap();
+    let result = semantic_tokens(context, &path, None).map(SemanticTokensResult::Tokens);
+    send_semantic_tokens_response(context, request, result);
+}
+
+/// Handles semantic tokens request for a range within a file
+pub fn on_semantic_tokens_range_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<SemanticTokensRangeParams>(request.params.clone())
+        .expect("could not deserialize semantic tokens range request");
+    let path = parameters.text_document.uri.to_file_path().unwrap();
+    let result = semantic_tokens(context, &path, Some(parameters.range))
+        .map(SemanticTokensRangeResult::Tokens);
+    send_semantic_tokens_response(context, request, result);
+}
+
+fn send_semantic_tokens_response<T: Serialize>(
+    context: &Context,
+    request: &Request,
+    result: Option<T>,
+) {
+    eprintln!(
+        "about to send semantic tokens response (tokens found: {})",
+        result.is_some()
+    );
+    let response = lsp_server::Response::new_ok(request.id.clone(), result);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send semantic tokens response: {:?}", err);
+    }
+}
+
+fn semantic_tokens(context: &Context, path: &Path, range: Option<Range>) -> Option<SemanticTokens> {
+    let pkg_path = SymbolicatorRunner::root_dir(path)?;
+    let symbols_map = context.symbols.lock().unwrap();
+    semantic_tokens_with_symbols(symbols_map.get(&pkg_path)?, path, range)
+}
+
+/// Computes (delta-encoded) semantic tokens for a file, optionally restricted to a given range.
+pub(crate) fn semantic_tokens_with_symbols(
+    symbols: &Symbols,
+    path: &Path,
+    range: Option<Range>,
+) -> Option<SemanticTokens> {
+    let use_defs = symbols.file_use_defs.get(path)?;
+    let fhash = symbols.file_hash(path)?;
+    let (_, source) = symbols.files.get(&fhash)?;
+    let lines = source.lines().collect::<Vec<_>>();
+    let params = parameter_locs(symbols, path);
+
+    let mut data = vec![];
+    let (mut prev_line, mut prev_start, mut prev_end) = (0, 0, 0);
+    for (line, uses) in use_defs.iter() {
+        if range.is_some_and(|r| *line < r.start.line || *line > r.end.line) {
+            continue;
+        }
+        let line_text = lines.get(*line as usize).copied().unwrap_or_default();
+        for use_def in uses {
+            let (col_start, col_end) = (use_def.col_start(), use_def.col_end());
+            if range.is_some_and(|r| {
+                (*line == r.start.line && col_end <= r.start.character)
+                    || (*line == r.end.line && col_start >= r.end.character)
+            }) {
+                continue;
+            }
+            // tokens must not overlap
+            if *line == prev_line && !data.is_empty() && col_start < prev_end {
+                continue;
+            }
+            let def_loc = use_def.def_loc();
+            let Some(def_info) = symbols.def_info(&def_loc) else {
+                continue;
+            };
+            let after_dot = char_before(line_text, col_start) == Some('.');
+            let Some((token_type, mut modifiers)) =
+                classify(def_info, &def_loc, &params, after_dot)
+            else {
+                continue;
+            };
+            if def_loc.file_hash() == fhash
+                && def_loc_start(symbols, &def_loc) == (*line, col_start)
+            {
+                modifiers.push(SemanticTokenModifier::DECLARATION);
+            }
+            if let Some(mod_defs) = symbols
+                .files
+                .file_name_mapping()
+                .get(&def_loc.file_hash())
+                .and_then(|def_path| symbols.file_mods.get(def_path))
+            {
+                for mdef in mod_defs {
+                    if mdef.deprecated.contains(&def_loc) {
+                        modifiers.push(SemanticTokenModifier::DEPRECATED);
+                    }
+                    if mdef.test_only.contains(&def_loc) {
+                        modifiers.push(TEST_ONLY);
+                    }
+                }
+            }
+
+            let delta_line = *line - prev_line;
+            let delta_start = if delta_line == 0 {
+                col_start - prev_start
+            } else {
+                col_start
+            };
+            data.push(SemanticToken {
+                delta_line,
+                delta_start,
+                length: col_end - col_start,
+                token_type: TOKEN_TYPES.iter().position(|t| *t == token_type).unwrap() as u32,
+                token_modifiers_bitset: TOKEN_MODIFIERS
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, m)| modifiers.contains(*m))
+                    .fold(0, |bits, (idx, _)| bits | (1 << idx)),
+            });
+            (prev_line, prev_start, prev_end) = (*line, col_start, col_end);
+        }
+    }
+    Some(SemanticTokens {
+        result_id: None,
+        data,
+    })
+}
+
+/// Classifies an identifier based on the information about its definition.
+fn classify(
+    def_info: &DefInfo,
+    def_loc: &Loc,
+    params: &BTreeSet<Loc>,
+    after_dot: bool,
+) -> Option<(SemanticTokenType, Vec<SemanticTokenModifier>)> {
+    let mut modifiers = vec![];
+    let token_type = match def_info {
+        DefInfo::Module(..) => SemanticTokenType::NAMESPACE,
+        DefInfo::Struct(..) => SemanticTokenType::STRUCT,
+        DefInfo::Enum(..) => SemanticTokenType::ENUM,
+        DefInfo::Variant(..) => SemanticTokenType::ENUM_MEMBER,
+        DefInfo::Field(..) => SemanticTokenType::PROPERTY,
+        DefInfo::Const(..) => {
+            modifiers.push(SemanticTokenModifier::READONLY);
+            SemanticTokenType::VARIABLE
+        }
+        DefInfo::Function(_, _, FunType::Macro, ..) => SemanticTokenType::MACRO,
+        DefInfo::Function(..) if after_dot => SemanticTokenType::METHOD,
+        DefInfo::Function(..) => SemanticTokenType::FUNCTION,
+        DefInfo::Local(_, _, _, is_mut, _) => {
+            if *is_mut {
+                modifiers.push(MUTABLE);
+            }
+            if params.contains(def_loc) {
+                SemanticTokenType::PARAMETER
+            } else {
+                SemanticTokenType::VARIABLE
+            }
+        }
+        DefInfo::Type(t) => match &t.value {
+            Type_::Param(_) => SemanticTokenType::TYPE_PARAMETER,
+            _ => return None,
+        },
+    };
+    Some((token_type, modifiers))
+}
+
+/// Collects locations of parameters of all functions defined in a given file.
+fn parameter_locs(symbols: &Symbols, path: &Path) -> BTreeSet<Loc> {
+    let mut params = BTreeSet::new();
+    for mod_defs in symbols.file_mods.get(path).into_iter().flatten() {
+        for fun_def in mod_defs.functions().values() {
+            if let Some(DefInfo::Function(_, _, _, _, _, arg_names, ..)) =
+                symbols.def_info(&fun_def.name_loc)
+            {
+                params.extend(arg_names.iter().map(|n| n.loc));
+            }
+        }
+    }
+    params
+}
+
+/// Returns position (line and column) where a definition starts.
+fn def_loc_start(symbols: &Symbols, def_loc: &Loc) -> (u32, u32) {
+    symbols
+        .files
+        .start_position_opt(def_loc)
+        .map_or((u32::MAX, u32::MAX), |pos| {
+            (pos.line_offset() as u32, pos.column_offset() as u32)
+        })
+}
+
+/// Returns the first non-whitespace character preceding a given column in a line.
+fn char_before(line: &str, col: u32) -> Option<char> {
+    let end = line
+        .char_indices()
+        .nth(col as usize)
+        .map_or(line.len(), |(idx, _)| idx);
+    line[..end].chars().rev().find(|c| !c.is_whitespace())
+}
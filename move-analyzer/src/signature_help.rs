@@ -0,0 +1,167 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the signature help language server command. It relies on the call site
+//! information (`CallInfo`) gathered during parsing analysis and on the resolved call targets
+//! filled in during typing analysis, so it works uniformly for regular function calls, method
+//! calls (including those going through `use fun` aliases), and `macro` function invocations.
+
+use crate::{
+    context::Context,
+    symbols::{
+        fun_type_to_ide_string, ret_type_to_ide_str, type_args_to_ide_string, type_to_ide_string,
+        CallInfo, DefInfo, SymbolicatorRunner, Symbols,
+    },
+    utils::lsp_position_to_loc,
+};
+use lsp_server::Request;
+use lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, Position,
+    SignatureHelp, SignatureHelpParams, SignatureInformation,
+};
+use std::path::Path;
+
+/// Handles signature help request of the language server
+pub fn on_signature_help_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<SignatureHelpParams>(request.params.clone())
+        .expect("could not deserialize signature help request");
+
+    let path = parameters
+        .text_document_position_params
+        .text_document
+        .uri
+        .to_file_path()
+        .unwrap();
+    let pos = parameters.text_document_position_params.position;
+
+    let help = signature_help(context, &path, pos);
+    eprintln!(
+        "about to send signature help response (signature found: {})",
+        help.is_some()
+    );
+    let response = lsp_server::Response::new_ok(request.id.clone(), help);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send signature help response: {:?}", err);
+    }
+}
+
+/// Computes signature help for a given position in a file using the symbols computed during the
+/// last compilation of the package containing the file. Symbols are re-computed by the
+/// symbolicator whenever a file changes, so they do not need to be re-computed here.
+fn signature_help(context: &Context, path: &Path, pos: Position) -> Option<SignatureHelp> {
+    let Some(pkg_path) = SymbolicatorRunner::root_dir(path) else {
+        eprintln!(
+            "failed signature help for {:?} (package root not found)",
+            path
+        );
+        return None;
+    };
+    let symbols_map = context.symbols.lock().unwrap();
+    let symbols = symbols_map.get(&pkg_path)?;
+    signature_help_with_symbols(symbols, path, pos)
+}
+
+/// Computes signature help for a given position in a file using the symbols provided as argument.
+pub fn signature_help_with_symbols(
+    symbols: &Symbols,
+    path: &Path,
+    pos: Position,
+) -> Option<SignatureHelp> {
+    let fhash = symbols.file_hash(path)?;
+    let cursor_offset = lsp_position_to_loc(&symbols.files, fhash, &pos)?.start() as usize;
+    let (_, source) = symbols.files.get(&fhash)?;
+
+    // find the innermost call whose argument list contains the cursor
+    let call_info = symbols
+        .file_mods
+        .get(path)?
+        .iter()
+        .flat_map(|mod_defs| mod_defs.call_infos.values())
+        .filter(|info| inside_args(info, cursor_offset, &source))
+        .min_by_key(|info| info.args_loc.end() - info.args_loc.start())?;
+
+    let def_info = symbols.def_info(&call_info.def_loc?)?;
+    let DefInfo::Function(_, _, fun_type, name, type_args, arg_names, arg_types, ret_type, doc) =
+        def_info
+    else {
+        return None;
+    };
+
+    let mut label = format!(
+        "{}fun {}{}(",
+        fun_type_to_ide_string(fun_type),
+        name,
+        type_args_to_ide_string(type_args, /* separate_lines */ false, /* verbose */ false)
+    );
+    let mut parameters = vec![];
+    for (idx, (arg_name, arg_type)) in arg_names.iter().zip(arg_types).enumerate() {
+        if idx > 0 {
+            label.push_str(", ");
+        }
+        let param_start = utf16_len(&label);
+        label.push_str(&format!(
+            "{}: {}",
+            arg_name.value,
+            type_to_ide_string(arg_type, /* verbose */ false)
+        ));
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([param_start, utf16_len(&label)]),
+            documentation: None,
+        });
+    }
+    label.push(')');
+    label.push_str(&ret_type_to_ide_str(ret_type, /* verbose */ false));
+
+    // for method calls the receiver is not a part of the argument list
+    let receiver_offset = if call_info.dot_call { 1 } else { 0 };
+    let active_parameter = (active_arg(call_info, cursor_offset, &source) + receiver_offset) as u32;
+
+    let signature = SignatureInformation {
+        label,
+        documentation: doc.as_ref().map(|d| {
+            Documentation::MarkupContent(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: d.clone(),
+            })
+        }),
+        parameters: Some(parameters),
+        active_parameter: Some(active_parameter),
+    };
+    Some(SignatureHelp {
+        signatures: vec![signature],
+        active_signature: Some(0),
+        active_parameter: Some(active_parameter),
+    })
+}
+
+/// Checks if the cursor is located between the parentheses delimiting the call's argument list
+/// (the closing one may be missing if the call is still being typed).
+fn inside_args(info: &CallInfo, cursor_offset: usize, source: &str) -> bool {
+    let start = info.args_loc.start() as usize;
+    let end = info.args_loc.end() as usize;
+    if cursor_offset <= start || cursor_offset > end {
+        return false;
+    }
+    cursor_offset < end || !source[..end].ends_with(')')
+}
+
+/// Computes the index of the argument at the cursor position. The cursor is considered to be at
+/// the argument following a given argument only if there is a comma separating the two.
+fn active_arg(info: &CallInfo, cursor_offset: usize, source: &str) -> usize {
+    for (idx, arg_loc) in info.arg_locs.iter().enumerate() {
+        let arg_end = arg_loc.end() as usize;
+        if cursor_offset <= arg_end || !source[arg_end..cursor_offset].contains(',') {
+            return idx;
+        }
+    }
+    info.arg_locs.len()
+}
+
+fn utf16_len(s: &str) -> u32 {
+    s.encode_utf16().count() as u32
+}
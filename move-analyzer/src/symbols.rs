@@ -99,8 +99,8 @@ use move_compiler::{
     naming::ast::{DatatypeTypeParameter, StructFields, Type, TypeName_, Type_, VariantFields},
     parser::ast::{self as P, DocComment},
     shared::{
-        files::MappedFiles, unique_map::UniqueMap, Identifier, Name, NamedAddressMap,
-        NamedAddressMaps,
+        files::MappedFiles, known_attributes::DeprecationAttribute, unique_map::UniqueMap,
+        Identifier, Name, NamedAddressMap, NamedAddressMaps,
     },
     typing::{
         ast::{Exp, ExpListItem, ModuleDefinition, SequenceItem, SequenceItem_, UnannotatedExp_},
@@ -432,6 +432,8 @@ impl Ord for LocalDef {
 pub struct CallInfo {
     /// Is it a dot call?
     pub dot_call: bool,
+    /// Location of the argument list (including parentheses)
+    pub args_loc: Loc,
     /// Locations of arguments
     pub arg_locs: Vec<Loc>,
     /// Definition of function being called (as an Option as its computed after
@@ -440,10 +442,11 @@ pub struct CallInfo {
 }
 
 impl CallInfo {
-    pub fn new(dot_call: bool, args: &[P::Exp]) -> Self {
+    pub fn new(dot_call: bool, args: &Spanned<Vec<P::Exp>>) -> Self {
         Self {
             dot_call,
-            arg_locs: args.iter().map(|e| e.loc).collect(),
+            args_loc: args.loc,
+            arg_locs: args.value.iter().map(|e| e.loc).collect(),
             def_loc: None,
         }
     }
@@ -499,6 +502,12 @@ pub struct ModuleDefs {
     pub untyped_defs: BTreeSet<Loc>,
     /// Information about calls in this module
     pub call_infos: BTreeMap<Loc, CallInfo>,
+    /// Name locations of deprecated definitions (including the module itself and members of a
+    /// deprecated module)
+    pub deprecated: BTreeSet<Loc>,
+    /// Name locations of definitions only available in test mode (including the module itself
+    /// and members of a test-only module)
+    pub test_only: BTreeSet<Loc>,
 }
 
 #[derive(Clone, Debug)]
@@ -690,6 +699,20 @@ pub struct SymbolicatorRunner {
     mtx_cvar: Arc<(Mutex<RunnerState>, Condvar)>,
 }
 
+impl UseLoc {
+    pub fn fhash(&self) -> FileHash {
+        self.fhash
+    }
+
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    pub fn col_end(&self) -> u32 {
+        self.col_end
+    }
+}
+
 impl ModuleDefs {
     pub fn functions(&self) -> &BTreeMap<Symbol, MemberDef> {
         &self.functions
@@ -710,6 +733,35 @@ impl ModuleDefs {
     pub fn ident(&self) -> &ModuleIdent_ {
         &self.ident
     }
+
+    /// Finds the member (or the module itself) enclosing a given location and returns the location
+    /// of its name. Only locations of member names are recorded, so the enclosing member is the
+    /// one whose name precedes the location most closely.
+    pub fn enclosing_member(&self, loc: &Loc) -> Option<Loc> {
+        std::iter::once(self.name_loc)
+            .chain(
+                self.structs
+                    .values()
+                    .chain(self.enums.values())
+                    .chain(self.constants.values())
+                    .chain(self.functions.values())
+                    .map(|member| member.name_loc),
+            )
+            .filter(|member_loc| {
+                member_loc.file_hash() == loc.file_hash() && member_loc.start() <= loc.start()
+            })
+            .max_by_key(|member_loc| member_loc.start())
+    }
+
+    /// Finds the function enclosing a given location (see `enclosing_member`) and returns the
+    /// location of its name.
+    pub fn enclosing_function(&self, loc: &Loc) -> Option<Loc> {
+        let member_loc = self.enclosing_member(loc)?;
+        self.functions
+            .values()
+            .any(|fun_def| fun_def.name_loc == member_loc)
+            .then_some(member_loc)
+    }
 }
 
 impl fmt::Display for DefInfo {
@@ -1126,11 +1178,18 @@ pub fn type_to_ide_string(sp!(_, t): &Type, verbose: bool) -> String {
                 }
             }
         },
-        Type_::Fun(args, ret) => {
+        Type_::Fun(args, ret, abilities) => {
+            let abilities = if abilities.is_empty() {
+                String::new()
+            } else {
+                let abilities = abilities.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+                format!(" has {}", abilities.join(" + "))
+            };
             format!(
-                "|{}| -> {}",
+                "|{}| -> {}{}",
                 type_list_to_ide_string(args, /* separate_lines */ false, verbose),
-                type_to_ide_string(ret, verbose)
+                type_to_ide_string(ret, verbose),
+                abilities
             )
         }
         Type_::Anything => "_".to_string(),
@@ -1257,6 +1316,12 @@ fn ast_value_to_ide_string(sp!(_, val): &Value) -> String {
         V::U64(u) => format!("{}", u),
         V::U128(u) => format!("{}", u),
         V::U256(u) => format!("{}", u),
+        V::I8(i) => format!("{}", i),
+        V::I16(i) => format!("{}", i),
+        V::I32(i) => format!("{}", i),
+        V::I64(i) => format!("{}", i),
+        V::I128(i) => format!("{}", i),
+        V::I256(i) => format!("{}", i),
         V::Bool(b) => format!("{}", b),
         V::Bytearray(vec) => format!(
             "[{}]",
@@ -1324,7 +1389,7 @@ pub fn mod_ident_to_ide_string(
     }
 }
 
-fn fun_type_to_ide_string(fun_type: &FunType) -> String {
+pub fn fun_type_to_ide_string(fun_type: &FunType) -> String {
     match fun_type {
         FunType::Entry => "entry ",
         FunType::Macro => "macro ",
@@ -1754,6 +1819,10 @@ impl UseDefMap {
         self.0
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = (&u32, &BTreeSet<UseDef>)> {
+        self.0.iter()
+    }
+
     pub fn count(&self) -> usize {
         self.0.len()
     }
@@ -2125,8 +2194,7 @@ pub fn get_compiled_pkg(
 
     let mut ide_diagnostics = lsp_empty_diagnostics(mapped_files.file_name_mapping());
     if let Some((compiler_diagnostics, failure)) = diagnostics {
-        let lsp_diagnostics =
-            lsp_diagnostics(&compiler_diagnostics.into_codespan_format(), &mapped_files);
+        let lsp_diagnostics = lsp_diagnostics(compiler_diagnostics, &mapped_files);
         // start with empty diagnostics for all files and replace them with actual diagnostics
         // only for files that have failures/warnings so that diagnostics for all other files
         // (that no longer have failures/warnings) are reset
@@ -2836,6 +2904,11 @@ pub fn ignored_function(name: Symbol) -> bool {
     name == UNIT_TEST_POISON_FUN_NAME
 }
 
+/// Checks if a definition is annotated with the `#[deprecated]` attribute.
+fn is_deprecated(attrs: &E::Attributes) -> bool {
+    attrs.contains_key_(&DeprecationAttribute.into())
+}
+
 /// Get symbols for outer definitions in the module (functions, structs, and consts)
 fn get_mod_outer_defs(
     loc: &Loc,
@@ -2852,10 +2925,22 @@ fn get_mod_outer_defs(
     let mut enums = BTreeMap::new();
     let mut constants = BTreeMap::new();
     let mut functions = BTreeMap::new();
+    let mut deprecated = BTreeSet::new();
+    let mut test_only = BTreeSet::new();
+    let mut record_attrs = |name_loc: Loc, attrs: &E::Attributes| {
+        if is_deprecated(&mod_def.attributes) || is_deprecated(attrs) {
+            deprecated.insert(name_loc);
+        }
+        if mod_def.attributes.is_test_or_test_only() || attrs.is_test_or_test_only() {
+            test_only.insert(name_loc);
+        }
+    };
+    record_attrs(*loc, &mod_def.attributes);
 
     let fhash = loc.file_hash();
     let mut positional = false;
     for (name_loc, name, def) in &mod_def.structs {
+        record_attrs(name_loc, &def.attributes);
         // process struct fields first
         let mut field_defs = vec![];
         let mut field_types = vec![];
@@ -2907,6 +2992,7 @@ fn get_mod_outer_defs(
     }
 
     for (name_loc, name, def) in &mod_def.enums {
+        record_attrs(name_loc, &def.attributes);
         // process variants
         let mut variants_info = BTreeMap::new();
         let mut def_info_variants = vec![];
@@ -2970,6 +3056,7 @@ fn get_mod_outer_defs(
     }
 
     for (name_loc, name, c) in &mod_def.constants {
+        record_attrs(name_loc, &c.attributes);
         constants.insert(
             *name,
             MemberDef {
@@ -2994,6 +3081,7 @@ fn get_mod_outer_defs(
         if ignored_function(*name) {
             continue;
         }
+        record_attrs(name_loc, &fun.attributes);
         let fun_type = if fun.entry.is_some() {
             FunType::Entry
         } else if fun.macro_.is_some() {
@@ -3056,6 +3144,8 @@ fn get_mod_outer_defs(
         functions,
         untyped_defs: BTreeSet::new(),
         call_infos: BTreeMap::new(),
+        deprecated,
+        test_only,
     };
 
     // insert use of the module name in the definition itself
@@ -0,0 +1,116 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TestPackage;
+use crate::call_hierarchy::{incoming_calls, outgoing_calls, prepare_call_hierarchy};
+use lsp_types::{CallHierarchyItem, SymbolKind};
+
+const LIB: &str = r#"module test::lib {
+    public fun leaf(): u64 { 1 }
+
+    public fun middle(): u64 {
+        leaf() + leaf()
+    }
+}
+"#;
+
+const APP: &str = r#"module test::app {
+    use test::lib;
+
+    public fun entry(): u64 {
+        lib::middle() + helper()
+    }
+
+    fun helper(): u64 { lib::leaf() }
+
+    macro fun in_macro(): u64 { lib::leaf() }
+
+    public fun no_calls(): u64 { in_macro!() }
+}
+"#;
+
+fn package() -> TestPackage {
+    TestPackage::new(&[("lib.move", LIB), ("app.move", APP)])
+}
+
+/// Prepares a call hierarchy item for the function at the `nth` occurrence of `marker`.
+fn item(pkg: &TestPackage, file: &str, marker: &str, nth: usize) -> CallHierarchyItem {
+    let pos = pkg.position(file, marker, nth, 0);
+    let mut items = prepare_call_hierarchy(&pkg.symbols_map(), &pkg.file(file), pos)
+        .unwrap_or_else(|| panic!("no call hierarchy item for '{marker}'"));
+    assert_eq!(items.len(), 1);
+    items.pop().unwrap()
+}
+
+#[test]
+fn prepare_item() {
+    let pkg = package();
+    // both definitions and uses resolve to the function definition
+    let def = item(&pkg, "lib.move", "middle", 0);
+    let usage = item(&pkg, "app.move", "middle", 0);
+    assert_eq!(def, usage);
+    assert_eq!(def.name, "middle");
+    assert_eq!(def.kind, SymbolKind::FUNCTION);
+    assert_eq!(def.detail.as_deref(), Some("public test::lib"));
+    assert_eq!(def.uri.to_file_path().unwrap(), pkg.file("lib.move"));
+    assert_eq!(
+        def.selection_range.start,
+        pkg.position("lib.move", "middle", 0, 0)
+    );
+
+    let helper = item(&pkg, "app.move", "helper", 0);
+    assert_eq!(helper.detail.as_deref(), Some("test::app"));
+
+    // positions not on functions have no call hierarchy
+    let pos = pkg.position("app.move", "u64", 0, 0);
+    assert!(prepare_call_hierarchy(&pkg.symbols_map(), &pkg.file("app.move"), pos).is_none());
+}
+
+#[test]
+fn incoming() {
+    let pkg = package();
+    let symbols_map = pkg.symbols_map();
+    let leaf = item(&pkg, "lib.move", "leaf", 0);
+    let mut calls = incoming_calls(&symbols_map, &leaf).unwrap();
+    calls.sort_by(|c1, c2| c1.from.name.cmp(&c2.from.name));
+    // calls in macro bodies are not included
+    let callers = calls
+        .iter()
+        .map(|c| c.from.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(callers, ["helper", "middle"]);
+    assert_eq!(calls[0].from_ranges.len(), 1);
+    assert_eq!(
+        calls[1]
+            .from_ranges
+            .iter()
+            .map(|r| r.start)
+            .collect::<Vec<_>>(),
+        [
+            pkg.position("lib.move", "leaf", 1, 0),
+            pkg.position("lib.move", "leaf", 2, 0)
+        ]
+    );
+
+    let entry = item(&pkg, "app.move", "entry", 0);
+    assert!(incoming_calls(&symbols_map, &entry).unwrap().is_empty());
+}
+
+#[test]
+fn outgoing() {
+    let pkg = package();
+    let symbols_map = pkg.symbols_map();
+    let entry = item(&pkg, "app.move", "entry", 0);
+    let mut calls = outgoing_calls(&symbols_map, &entry).unwrap();
+    calls.sort_by(|c1, c2| c1.to.name.cmp(&c2.to.name));
+    let callees = calls.iter().map(|c| c.to.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(callees, ["helper", "middle"]);
+    assert_eq!(
+        calls[1].from_ranges[0].start,
+        pkg.position("app.move", "middle", 0, 0)
+    );
+
+    let leaf = item(&pkg, "lib.move", "leaf", 0);
+    assert!(outgoing_calls(&symbols_map, &leaf).unwrap().is_empty());
+}
@@ -0,0 +1,213 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TestPackage;
+use crate::code_action::{diagnostic_actions, kind_requested, migration_edit, suppression_edit};
+use lsp_types::{CodeAction, CodeActionKind, Position, TextEdit};
+use move_compiler::linters::LintLevel;
+use std::{collections::BTreeMap, fs};
+use url::Url;
+use vfs::{impls::memory::MemoryFS, VfsPath};
+
+const CONDITIONALS: &str = r#"module test::m {
+    public fun same(a: bool, b: bool): bool {
+        if (a || b) true else false
+    }
+
+    public fun negated(a: u64, b: u64): bool {
+        if (a == b) false else true
+    }
+
+    public fun atomic(c: bool): bool {
+        if (c) true else false
+    }
+}
+"#;
+
+/// Applies edits (which must not overlap) to a given ASCII source.
+fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+    let offset = |pos: &Position| {
+        let line_start: usize = source
+            .split_inclusive('\n')
+            .take(pos.line as usize)
+            .map(str::len)
+            .sum();
+        line_start + pos.character as usize
+    };
+    let mut edits = edits.iter().collect::<Vec<_>>();
+    edits.sort_by_key(|edit| std::cmp::Reverse(offset(&edit.range.start)));
+    let mut result = source.to_string();
+    for edit in edits {
+        result.replace_range(
+            offset(&edit.range.start)..offset(&edit.range.end),
+            &edit.new_text,
+        );
+    }
+    result
+}
+
+/// Computes quick fixes for all diagnostics reported for file `m.move`.
+fn quick_fixes(pkg: &TestPackage) -> Vec<CodeAction> {
+    let path = pkg.file("m.move");
+    let uri = Url::from_file_path(&path).unwrap();
+    let mut actions = vec![];
+    for diag in pkg.diagnostics.get(&path).into_iter().flatten() {
+        diagnostic_actions(&mut actions, Some(&pkg.symbols), &uri, &path, diag);
+    }
+    actions
+}
+
+fn action_edits(action: &CodeAction) -> &[TextEdit] {
+    let changes = action.edit.as_ref().unwrap().changes.as_ref().unwrap();
+    changes.values().next().unwrap()
+}
+
+#[test]
+fn requested_kinds() {
+    let migrate = CodeActionKind::new("source.migrate");
+    assert!(kind_requested(None, &CodeActionKind::QUICKFIX));
+    assert!(kind_requested(
+        Some(&vec![CodeActionKind::SOURCE]),
+        &migrate
+    ));
+    assert!(kind_requested(Some(&vec![migrate.clone()]), &migrate));
+    assert!(!kind_requested(
+        Some(&vec![CodeActionKind::QUICKFIX]),
+        &migrate
+    ));
+    assert!(!kind_requested(
+        Some(&vec![CodeActionKind::new("source.mig")]),
+        &migrate
+    ));
+}
+
+#[test]
+fn unnecessary_conditional_fixes() {
+    let pkg = TestPackage::with_options(
+        &[("m.move", CONDITIONALS)],
+        Some("2024.beta"),
+        LintLevel::All,
+    );
+    let fixes = quick_fixes(&pkg)
+        .into_iter()
+        .filter(|action| action.is_preferred == Some(true))
+        .collect::<Vec<_>>();
+    assert_eq!(fixes.len(), 3);
+
+    let titles = fixes.iter().map(|a| a.title.as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        [
+            "Replace with the condition",
+            "Replace with the negated condition",
+            "Replace with the condition",
+        ]
+    );
+
+    let fixed = apply_edits(
+        CONDITIONALS,
+        &fixes
+            .iter()
+            .flat_map(|a| action_edits(a).to_vec())
+            .collect::<Vec<_>>(),
+    );
+    assert!(fixed.contains("        (a || b)\n"), "{fixed}");
+    assert!(fixed.contains("        !(a == b)\n"), "{fixed}");
+    assert!(fixed.contains("        c\n"), "{fixed}");
+}
+
+#[test]
+fn unnecessary_conditional_suppression() {
+    let pkg = TestPackage::with_options(
+        &[("m.move", CONDITIONALS)],
+        Some("2024.beta"),
+        LintLevel::All,
+    );
+    let suppressions = quick_fixes(&pkg)
+        .into_iter()
+        .filter(|action| action.is_preferred == Some(false))
+        .collect::<Vec<_>>();
+    assert_eq!(suppressions.len(), 3);
+    for (member, line) in [("same", 1), ("negated", 5), ("atomic", 9)] {
+        let suppression = suppressions
+            .iter()
+            .find(|action| action_edits(action)[0].range.start.line == line)
+            .unwrap_or_else(|| panic!("no suppression for '{member}'"));
+        assert_eq!(
+            suppression.title,
+            "Suppress warning with '#[allow(lint(unnecessary_conditional))]'"
+        );
+        let fixed = apply_edits(CONDITIONALS, action_edits(suppression));
+        let expected =
+            format!("    #[allow(lint(unnecessary_conditional))]\n    public fun {member}(");
+        assert!(fixed.contains(&expected), "{fixed}");
+    }
+}
+
+#[test]
+fn suppression_added_to_member() {
+    let source = r#"module test::m {
+    const C: u64 = 0;
+
+    /// Doc comment
+    public fun f(): u64 {
+        C
+    }
+}
+"#;
+    let pkg = TestPackage::new(&[("m.move", source)]);
+    let path = pkg.file("m.move");
+    let pos = pkg.position("m.move", "C\n", 0, 0);
+    let edit = suppression_edit(&pkg.symbols, &path, pos, "allow(unused)").unwrap();
+    assert_eq!(edit.range.start, Position::new(4, 0));
+    assert_eq!(edit.new_text, "    #[allow(unused)]\n");
+
+    // positions before any member belong to the module
+    let pos = pkg.position("m.move", "m {", 0, 2);
+    let edit = suppression_edit(&pkg.symbols, &path, pos, "allow(unused)").unwrap();
+    assert_eq!(edit.range.start, Position::new(0, 0));
+    assert_eq!(edit.new_text, "#[allow(unused)]\n");
+}
+
+#[test]
+fn suppression_merged_into_existing_allow() {
+    let source = r#"module test::m {
+    #[allow(unused_variable)]
+    // comment
+    public fun f(x: u64) {
+        if (true) ()
+    }
+}
+"#;
+    let pkg = TestPackage::new(&[("m.move", source)]);
+    let path = pkg.file("m.move");
+    let pos = pkg.position("m.move", "if", 0, 0);
+    let edit = suppression_edit(&pkg.symbols, &path, pos, "allow(lint(constant_if))").unwrap();
+    assert_eq!(edit.range.start, Position::new(1, 12));
+    assert_eq!(edit.new_text, "lint(constant_if), ");
+    let fixed = apply_edits(source, &[edit]);
+    assert!(fixed.contains("#[allow(lint(constant_if), unused_variable)]"));
+}
+
+#[test]
+fn legacy_package_migration() {
+    let source = r#"module test::m {
+    struct S has drop { f: u64 }
+
+    public fun f(s: S): u64 {
+        s.f
+    }
+}
+"#;
+    let pkg = TestPackage::with_options(&[("m.move", source)], None, LintLevel::None);
+    let path = pkg.file("m.move");
+    let edit = migration_edit(VfsPath::new(MemoryFS::new()), &path, BTreeMap::new())
+        .unwrap()
+        .expect("file should need migration");
+    assert_eq!(edit.range.start, Position::new(0, 0));
+    assert_eq!(edit.range.end, Position::new(7, 0));
+    assert!(edit.new_text.contains("public struct S has drop"));
+    // the file on disk is left unchanged
+    assert_eq!(fs::read_to_string(&path).unwrap(), source);
+}
@@ -0,0 +1,102 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+mod call_hierarchy_tests;
+mod code_action_tests;
+mod rename_tests;
+mod semantic_tokens_tests;
+mod signature_help_tests;
+mod workspace_symbols_tests;
+
+use crate::symbols::{get_symbols, Symbols};
+use lsp_types::{Diagnostic, Position};
+use move_compiler::linters::LintLevel;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use tempfile::TempDir;
+use vfs::{impls::memory::MemoryFS, VfsPath};
+
+/// A package written to a temporary directory along with the symbols computed for it
+pub(crate) struct TestPackage {
+    _dir: TempDir,
+    pub path: PathBuf,
+    pub symbols: Symbols,
+    pub diagnostics: BTreeMap<PathBuf, Vec<Diagnostic>>,
+}
+
+impl TestPackage {
+    /// Creates a package named `test` with the given source files, all of them in the `sources`
+    /// directory, and computes its symbols.
+    pub fn new(files: &[(&str, &str)]) -> Self {
+        Self::with_options(files, Some("2024.beta"), LintLevel::None)
+    }
+
+    /// Creates a package with a given edition (or none for a legacy package) and computes its
+    /// symbols and diagnostics at a given lint level.
+    pub fn with_options(files: &[(&str, &str)], edition: Option<&str>, lint: LintLevel) -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dunce::canonicalize(dir.path()).unwrap();
+        fs::write(
+            path.join("Move.toml"),
+            format!(
+                "[package]\nname = \"test\"\n{}\n[addresses]\ntest = \"0x42\"\n",
+                edition.map_or(String::new(), |e| format!("edition = \"{e}\"\n")),
+            ),
+        )
+        .unwrap();
+        fs::create_dir(path.join("sources")).unwrap();
+        for (name, contents) in files {
+            fs::write(path.join("sources").join(name), contents).unwrap();
+        }
+        let (symbols, diagnostics) = get_symbols(
+            Arc::new(Mutex::new(BTreeMap::new())),
+            VfsPath::new(MemoryFS::new()),
+            &path,
+            None,
+            lint,
+            None,
+            BTreeMap::new(),
+        )
+        .unwrap();
+        let symbols = symbols.unwrap_or_else(|| panic!("no symbols computed: {diagnostics:?}"));
+        Self {
+            _dir: dir,
+            path,
+            symbols,
+            diagnostics,
+        }
+    }
+
+    /// Returns symbols of the package keyed by its path, as they are stored by the language server.
+    pub fn symbols_map(&self) -> BTreeMap<PathBuf, Symbols> {
+        BTreeMap::from([(self.path.clone(), self.symbols.clone())])
+    }
+
+    pub fn file(&self, name: &str) -> PathBuf {
+        self.path.join("sources").join(name)
+    }
+
+    /// Returns the position of the `nth` (0-based) occurrence of `marker` in a file, moved right
+    /// by `shift` characters. Columns are counted in UTF-16 code units, as in the LSP.
+    pub fn position(&self, name: &str, marker: &str, nth: usize, shift: usize) -> Position {
+        let contents = fs::read_to_string(self.file(name)).unwrap();
+        let (offset, _) = contents
+            .match_indices(marker)
+            .nth(nth)
+            .unwrap_or_else(|| panic!("marker '{marker}' not found"));
+        let offset = contents[offset..]
+            .char_indices()
+            .nth(shift)
+            .map_or(contents.len(), |(idx, _)| offset + idx);
+        let line_start = contents[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+        Position {
+            line: contents[..line_start].matches('\n').count() as u32,
+            character: contents[line_start..offset].encode_utf16().count() as u32,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TestPackage;
+use crate::rename::{prepare_rename, rename};
+use lsp_types::{Position, PrepareRenameResponse, Range, TextEdit};
+use std::path::PathBuf;
+
+const LIB: &str = r#"module test::lib {
+    public struct Point has drop { x: u64, y: u64 }
+
+    public fun make(x: u64): Point { Point { x, y: 0 } }
+
+    public fun helper(p: &Point): u64 { p.x + p.y }
+
+    public fun other(): u64 { 0 }
+}
+"#;
+
+const APP: &str = r#"module test::app {
+    use test::lib::{Self, helper as h};
+
+    public fun run(a: u64): u64 {
+        let p = lib::make(a);
+        lib::helper(&p) + h(&p)
+    }
+
+    public fun emoji(b: u64): u64 {
+        let a = /* 😀 é */ b + 1;
+        a
+    }
+}
+"#;
+
+fn package() -> TestPackage {
+    TestPackage::new(&[("lib.move", LIB), ("app.move", APP)])
+}
+
+/// Renames the identifier at the `nth` occurrence of `marker` and returns the edits per file
+/// (sorted by position).
+fn rename_at(
+    pkg: &TestPackage,
+    file: &str,
+    marker: &str,
+    nth: usize,
+    new_name: &str,
+) -> Result<Vec<(PathBuf, Vec<TextEdit>)>, String> {
+    let pos = pkg.position(file, marker, nth, 0);
+    let edit = rename(&pkg.symbols_map(), &pkg.file(file), &pos, new_name)?;
+    let mut changes = edit
+        .changes
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(uri, mut edits)| {
+            edits.sort_by_key(|e| (e.range.start.line, e.range.start.character));
+            (uri.to_file_path().unwrap(), edits)
+        })
+        .collect::<Vec<_>>();
+    changes.sort_by(|(p1, _), (p2, _)| p1.cmp(p2));
+    Ok(changes)
+}
+
+fn edit_at(
+    pkg: &TestPackage,
+    file: &str,
+    marker: &str,
+    nth: usize,
+    len: u32,
+    text: &str,
+) -> TextEdit {
+    let start = pkg.position(file, marker, nth, 0);
+    let end = Position::new(start.line, start.character + len);
+    TextEdit::new(Range::new(start, end), text.to_string())
+}
+
+#[test]
+fn rename_across_modules() {
+    let pkg = package();
+    // renaming at a use site in another module renames the definition and all uses (including the
+    // one in the aliased import), except for uses via an alias
+    let changes = rename_at(&pkg, "app.move", "helper(&p)", 0, "compute").unwrap();
+    let expected = vec![
+        (
+            pkg.file("app.move"),
+            vec![
+                edit_at(&pkg, "app.move", "helper as", 0, 6, "compute"),
+                edit_at(&pkg, "app.move", "helper(&p)", 0, 6, "compute"),
+            ],
+        ),
+        (
+            pkg.file("lib.move"),
+            vec![edit_at(&pkg, "lib.move", "helper", 0, 6, "compute")],
+        ),
+    ];
+    assert_eq!(changes, expected);
+}
+
+#[test]
+fn rename_expands_punning() {
+    let pkg = package();
+    let changes = rename_at(&pkg, "lib.move", "x: u64)", 0, "first").unwrap();
+    let (path, edits) = &changes[0];
+    assert_eq!(path, &pkg.file("lib.move"));
+    assert_eq!(
+        edits,
+        &vec![
+            edit_at(&pkg, "lib.move", "x: u64)", 0, 1, "first"),
+            edit_at(&pkg, "lib.move", "x, y", 0, 1, "x: first"),
+        ]
+    );
+}
+
+#[test]
+fn conflicting_renames_rejected() {
+    let pkg = package();
+    let err = rename_at(&pkg, "lib.move", "helper", 0, "other").unwrap_err();
+    assert!(err.contains("already in use"), "{err}");
+    let err = rename_at(&pkg, "lib.move", "y: u64", 0, "x").unwrap_err();
+    assert!(err.contains("already in use"), "{err}");
+
+    // a local defined in the same function
+    let err = rename_at(&pkg, "app.move", "p =", 0, "a").unwrap_err();
+    assert!(err.contains("already in use"), "{err}");
+    let err = rename_at(&pkg, "app.move", "b: u64", 0, "a").unwrap_err();
+    assert!(err.contains("already in use"), "{err}");
+    // a local with the same name in another function is not a conflict
+    assert!(rename_at(&pkg, "app.move", "b: u64", 0, "p").is_ok());
+}
+
+#[test]
+fn invalid_renames_rejected() {
+    let pkg = package();
+    let err = rename_at(&pkg, "app.move", "h(&p)", 0, "g").unwrap_err();
+    assert!(err.contains("alias"), "{err}");
+    let err = rename_at(&pkg, "lib.move", "Point", 0, "point").unwrap_err();
+    assert!(err.contains("invalid name"), "{err}");
+    let err = rename_at(&pkg, "lib.move", "make", 0, "let").unwrap_err();
+    assert!(err.contains("reserved keyword"), "{err}");
+    let err = rename_at(&pkg, "app.move", "lib::make", 0, "m").unwrap_err();
+    assert!(err.contains("cannot be renamed"), "{err}");
+}
+
+#[test]
+fn rename_on_non_ascii_line() {
+    let pkg = package();
+    // columns are counted in UTF-16 code units, in which the emoji takes two and 'é' one
+    let use_pos = pkg.position("app.move", "b + 1", 0, 0);
+    let line = APP.lines().nth(use_pos.line as usize).unwrap();
+    let prefix = &line[..line.find("b + 1").unwrap()];
+    assert_eq!(use_pos.character, prefix.encode_utf16().count() as u32);
+    assert_ne!(use_pos.character, prefix.chars().count() as u32);
+
+    let PrepareRenameResponse::RangeWithPlaceholder { range, placeholder } =
+        prepare_rename(&pkg.symbols_map(), &pkg.file("app.move"), &use_pos).unwrap()
+    else {
+        panic!("unexpected prepare rename response");
+    };
+    assert_eq!(placeholder, "b");
+    assert_eq!(
+        range,
+        Range::new(use_pos, Position::new(use_pos.line, use_pos.character + 1))
+    );
+
+    let changes = rename_at(&pkg, "app.move", "b + 1", 0, "base").unwrap();
+    assert_eq!(
+        changes,
+        vec![(
+            pkg.file("app.move"),
+            vec![
+                edit_at(&pkg, "app.move", "b: u64", 0, 1, "base"),
+                edit_at(&pkg, "app.move", "b + 1", 0, 1, "base"),
+            ]
+        )]
+    );
+}
@@ -0,0 +1,156 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TestPackage;
+use crate::semantic_tokens::{legend, semantic_tokens_with_symbols};
+use lsp_types::{Position, Range, SemanticTokenModifier, SemanticTokenType};
+
+const SOURCE: &str = r#"module test::m {
+    const LIMIT: u64 = 10;
+
+    public struct S has drop { v: u64 }
+
+    public enum E has drop { A, B(u64) }
+
+    public fun get(s: &S): u64 { s.v }
+
+    #[deprecated]
+    public fun old(): u64 { LIMIT }
+
+    #[test_only]
+    public fun helper(): E { E::A }
+
+    macro fun twice($x: u64): u64 { $x + $x }
+
+    fun calls<T: drop>(s: S, _t: T): u64 {
+        let mut total = s.get();
+        total = total + get(&s) + old();
+        twice!(total)
+    }
+}
+"#;
+
+/// A decoded semantic token
+#[derive(Debug)]
+struct Token {
+    line: u32,
+    start: u32,
+    length: u32,
+    token_type: SemanticTokenType,
+    modifiers: Vec<SemanticTokenModifier>,
+}
+
+fn tokens(pkg: &TestPackage, range: Option<Range>) -> Vec<Token> {
+    let legend = legend();
+    let data = semantic_tokens_with_symbols(&pkg.symbols, &pkg.file("m.move"), range)
+        .unwrap()
+        .data;
+    let (mut line, mut start) = (0, 0);
+    data.into_iter()
+        .map(|t| {
+            if t.delta_line == 0 {
+                start += t.delta_start;
+            } else {
+                line += t.delta_line;
+                start = t.delta_start;
+            }
+            Token {
+                line,
+                start,
+                length: t.length,
+                token_type: legend.token_types[t.token_type as usize].clone(),
+                modifiers: legend
+                    .token_modifiers
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| t.token_modifiers_bitset & (1 << idx) != 0)
+                    .map(|(_, m)| m.clone())
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Finds the token starting at the `nth` occurrence of `marker`.
+fn token_at<'a>(pkg: &TestPackage, tokens: &'a [Token], marker: &str, nth: usize) -> &'a Token {
+    let Position { line, character } = pkg.position("m.move", marker, nth, 0);
+    tokens
+        .iter()
+        .find(|t| t.line == line && t.start == character)
+        .unwrap_or_else(|| panic!("no token for '{marker}' ({nth})"))
+}
+
+#[test]
+fn token_types() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+    let tokens = tokens(&pkg, None);
+    let expected = [
+        ("m {", 0, SemanticTokenType::NAMESPACE),
+        ("S has", 0, SemanticTokenType::STRUCT),
+        ("E has", 0, SemanticTokenType::ENUM),
+        ("A,", 0, SemanticTokenType::ENUM_MEMBER),
+        ("v: u64", 0, SemanticTokenType::PROPERTY),
+        ("v }", 0, SemanticTokenType::PROPERTY),
+        ("s: &S", 0, SemanticTokenType::PARAMETER),
+        ("T: drop", 0, SemanticTokenType::TYPE_PARAMETER),
+        ("twice!", 0, SemanticTokenType::MACRO),
+        ("get()", 0, SemanticTokenType::METHOD),
+        ("get(&s)", 0, SemanticTokenType::FUNCTION),
+        ("total =", 0, SemanticTokenType::VARIABLE),
+    ];
+    for (marker, nth, token_type) in expected {
+        assert_eq!(
+            token_at(&pkg, &tokens, marker, nth).token_type,
+            token_type,
+            "wrong type of '{marker}'"
+        );
+    }
+    let token = token_at(&pkg, &tokens, "twice!", 0);
+    assert_eq!(token.length, "twice".len() as u32);
+}
+
+#[test]
+fn token_modifiers() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+    let tokens = tokens(&pkg, None);
+
+    let limit = token_at(&pkg, &tokens, "LIMIT", 0);
+    assert_eq!(limit.token_type, SemanticTokenType::VARIABLE);
+    assert!(limit.modifiers.contains(&SemanticTokenModifier::READONLY));
+    assert!(limit
+        .modifiers
+        .contains(&SemanticTokenModifier::DECLARATION));
+    let limit_use = token_at(&pkg, &tokens, "LIMIT", 1);
+    assert!(!limit_use
+        .modifiers
+        .contains(&SemanticTokenModifier::DECLARATION));
+
+    let total = token_at(&pkg, &tokens, "total =", 0);
+    assert!(total
+        .modifiers
+        .contains(&SemanticTokenModifier::new("mutable")));
+
+    let old = token_at(&pkg, &tokens, "old()", 1);
+    assert!(old.modifiers.contains(&SemanticTokenModifier::DEPRECATED));
+
+    let helper = token_at(&pkg, &tokens, "helper", 0);
+    assert!(helper
+        .modifiers
+        .contains(&SemanticTokenModifier::new("testOnly")));
+}
+
+#[test]
+fn range_tokens() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+    let start = pkg.position("m.move", "fun calls", 0, 0);
+    let end = pkg.position("m.move", "twice!(total)", 0, 0);
+    let tokens = tokens(&pkg, Some(Range::new(start, end)));
+    assert!(!tokens.is_empty());
+    assert!(tokens
+        .iter()
+        .all(|t| (t.line, t.start) >= (start.line, start.character)
+            && (t.line, t.start) < (end.line, end.character)));
+    // tokens are delta-encoded relative to the start of the file rather than the range
+    assert_eq!(tokens[0].line, start.line);
+}
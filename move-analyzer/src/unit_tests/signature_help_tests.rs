@@ -0,0 +1,105 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TestPackage;
+use crate::signature_help::signature_help_with_symbols;
+use lsp_types::{ParameterLabel, SignatureHelp};
+
+const SOURCE: &str = r#"module test::m {
+    public struct S has drop { v: u64 }
+
+    /// Adds two numbers
+    public fun add(x: u64, y: u64): u64 { x + y }
+
+    public fun get(s: &S, offset: u64): u64 { s.v + offset }
+
+    macro fun twice($f: || -> u64): u64 { $f() + $f() }
+
+    fun calls(s: S): u64 {
+        let a = add(1, add(2, 3));
+        let b = s.get(a);
+        let c = twice!(|| 7);
+        a + b + c
+    }
+}
+"#;
+
+fn help_at(pkg: &TestPackage, marker: &str, nth: usize, shift: usize) -> Option<SignatureHelp> {
+    let pos = pkg.position("m.move", marker, nth, shift);
+    signature_help_with_symbols(&pkg.symbols, &pkg.file("m.move"), pos)
+}
+
+fn label_and_active(help: &SignatureHelp) -> (&str, u32) {
+    let signature = &help.signatures[0];
+    (&signature.label, help.active_parameter.unwrap())
+}
+
+#[test]
+fn function_call() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+
+    let help = help_at(&pkg, "add(1", 0, 4).unwrap();
+    assert_eq!(label_and_active(&help), ("fun add(x: u64, y: u64): u64", 0));
+    let signature = &help.signatures[0];
+    let Some(params) = &signature.parameters else {
+        panic!("no parameters");
+    };
+    let labels = params
+        .iter()
+        .map(|p| match p.label {
+            ParameterLabel::LabelOffsets([start, end]) => {
+                &signature.label[start as usize..end as usize]
+            }
+            ParameterLabel::Simple(_) => panic!("unexpected simple label"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(labels, vec!["x: u64", "y: u64"]);
+    assert!(signature.documentation.is_some());
+
+    // after the separator the second parameter is active
+    let help = help_at(&pkg, "add(1, ", 0, 7).unwrap();
+    assert_eq!(label_and_active(&help).1, 1);
+}
+
+#[test]
+fn nested_call() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+    // the innermost call is selected
+    let help = help_at(&pkg, "add(2", 0, 4).unwrap();
+    assert_eq!(label_and_active(&help).1, 0);
+    let help = help_at(&pkg, "add(2, ", 0, 7).unwrap();
+    assert_eq!(label_and_active(&help).1, 1);
+    // and the outer one once the cursor leaves it
+    let help = help_at(&pkg, "3))", 0, 2).unwrap();
+    assert_eq!(label_and_active(&help).1, 1);
+}
+
+#[test]
+fn method_call() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+    // the receiver is the first parameter
+    let help = help_at(&pkg, "get(a)", 0, 4).unwrap();
+    assert_eq!(
+        label_and_active(&help),
+        ("fun get(s: &S, offset: u64): u64", 1)
+    );
+}
+
+#[test]
+fn macro_call() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+    let help = help_at(&pkg, "twice!(", 0, 7).unwrap();
+    assert_eq!(
+        label_and_active(&help),
+        ("macro fun twice($f: || -> u64): u64", 0)
+    );
+}
+
+#[test]
+fn outside_of_call() {
+    let pkg = TestPackage::new(&[("m.move", SOURCE)]);
+    assert!(help_at(&pkg, "let a", 0, 0).is_none());
+    // on the called function's name
+    assert!(help_at(&pkg, "add(1", 0, 1).is_none());
+}
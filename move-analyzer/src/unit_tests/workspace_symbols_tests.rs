@@ -0,0 +1,80 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::TestPackage;
+use crate::workspace_symbols::workspace_symbols;
+use lsp_types::{SymbolKind, SymbolTag};
+
+const SOURCE: &str = r#"module test::shapes {
+    const MAX_SIDES: u64 = 8;
+
+    public struct Shape has drop { sides: u64 }
+
+    public enum ShapeKind has drop { Square, Triangle }
+
+    public fun shape(): Shape { Shape { sides: 4 } }
+
+    public fun make_shape_kind(): ShapeKind { ShapeKind::Square }
+
+    #[deprecated]
+    public fun sides(s: &Shape): u64 { s.sides }
+
+    public fun max_sides(): u64 { MAX_SIDES }
+}
+"#;
+
+fn names(pkg: &TestPackage, query: &str) -> Vec<String> {
+    workspace_symbols(&pkg.symbols_map(), query)
+        .into_iter()
+        .map(|s| s.name)
+        .collect()
+}
+
+#[test]
+fn ranking() {
+    let pkg = TestPackage::new(&[("shapes.move", SOURCE)]);
+    // exact matches (case insensitive) first, then prefix, substring and fuzzy matches with
+    // shorter names first
+    assert_eq!(
+        names(&pkg, "shape"),
+        ["Shape", "shape", "shapes", "ShapeKind", "make_shape_kind"]
+    );
+    assert_eq!(names(&pkg, "mxsd"), ["MAX_SIDES", "max_sides"]);
+    assert!(names(&pkg, "circle").is_empty());
+}
+
+#[test]
+fn symbol_information() {
+    let pkg = TestPackage::new(&[("shapes.move", SOURCE)]);
+    let symbols = workspace_symbols(&pkg.symbols_map(), "s");
+    let find = |name: &str| symbols.iter().find(|s| s.name == name).unwrap();
+
+    let module = find("shapes");
+    assert_eq!(module.kind, SymbolKind::MODULE);
+    assert_eq!(module.container_name, None);
+
+    let kinds = [
+        ("Shape", SymbolKind::STRUCT),
+        ("ShapeKind", SymbolKind::ENUM),
+        ("shape", SymbolKind::FUNCTION),
+        ("MAX_SIDES", SymbolKind::CONSTANT),
+    ];
+    for (name, kind) in kinds {
+        let symbol = find(name);
+        assert_eq!(symbol.kind, kind, "wrong kind of '{name}'");
+        assert_eq!(symbol.container_name.as_deref(), Some("test::shapes"));
+    }
+
+    let sides = find("sides");
+    assert_eq!(sides.tags, Some(vec![SymbolTag::DEPRECATED]));
+    assert_eq!(
+        sides.location.uri.to_file_path().unwrap(),
+        pkg.file("shapes.move")
+    );
+    assert_eq!(
+        sides.location.range.start,
+        pkg.position("shapes.move", "sides(", 0, 0)
+    );
+    assert_eq!(find("shape").tags, None);
+}
@@ -47,6 +47,30 @@ pub fn loc_end_to_lsp_position_opt(files: &MappedFiles, loc: &Loc) -> Option<Pos
     Some(result)
 }
 
+/// Converts a column counted in characters (as columns of positions computed from compiler
+/// locations are) to a column counted in UTF-16 code units (as columns of LSP positions are).
+pub fn char_col_to_utf16(line: &str, col: u32) -> u32 {
+    line.chars()
+        .take(col as usize)
+        .map(|c| c.len_utf16() as u32)
+        .sum()
+}
+
+/// Converts a column counted in UTF-16 code units to a column counted in characters (a column
+/// pointing inside of a character is rounded up to the next character).
+pub fn utf16_col_to_char(line: &str, col: u32) -> u32 {
+    let mut utf16_col = 0;
+    let mut char_col = 0;
+    for c in line.chars() {
+        if utf16_col >= col {
+            break;
+        }
+        utf16_col += c.len_utf16() as u32;
+        char_col += 1;
+    }
+    char_col
+}
+
 pub fn lsp_position_to_loc(
     files: &MappedFiles,
     file_hash: FileHash,
@@ -0,0 +1,174 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements the workspace symbol language server command. Module-level definitions
+//! (modules, structs, enums, functions and constants) of all packages currently loaded by the
+//! language server, including their dependencies, are searched using fuzzy matching: a
+//! definition matches if all characters of the query appear in its name in the same order
+//! (ignoring case). Matches are ranked so that exact matches come first, followed by prefix
+//! matches, substring matches, and finally the remaining fuzzy matches.
+
+use crate::{context::Context, symbols::Symbols};
+use lsp_server::Request;
+use lsp_types::{
+    Location, SymbolInformation, SymbolKind, SymbolTag, WorkspaceSymbolParams,
+    WorkspaceSymbolResponse,
+};
+use move_compiler::shared::Identifier;
+use move_ir_types::location::*;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+use url::Url;
+
+/// Maximum number of symbols returned in a single response
+const MAX_WORKSPACE_SYMBOLS: usize = 256;
+
+/// A symbol matching the query along with its rank (lower is better)
+struct Match {
+    rank: u8,
+    name: String,
+    kind: SymbolKind,
+    container: Option<String>,
+    loc: Loc,
+}
+
+/// Handles workspace symbol request of the language server
+pub fn on_workspace_symbol_request(context: &Context, request: &Request) {
+    let parameters = serde_json::from_value::<WorkspaceSymbolParams>(request.params.clone())
+        .expect("could not deserialize workspace symbol request");
+
+    let symbols_map = context.symbols.lock().unwrap();
+    let symbols_info = workspace_symbols(&symbols_map, &parameters.query);
+
+    eprintln!(
+        "about to send {} workspace symbol(s) for query '{}'",
+        symbols_info.len(),
+        parameters.query
+    );
+    let response = lsp_server::Response::new_ok(
+        request.id.clone(),
+        WorkspaceSymbolResponse::Flat(symbols_info),
+    );
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send workspace symbol response: {:?}", err);
+    }
+}
+
+/// Computes (ranked) symbols matching a given query.
+pub(crate) fn workspace_symbols(
+    symbols_map: &BTreeMap<PathBuf, Symbols>,
+    query: &str,
+) -> Vec<SymbolInformation> {
+    let query = query.to_lowercase();
+    let mut seen = BTreeSet::new();
+    let mut matches = vec![];
+    for symbols in symbols_map.values() {
+        for mod_defs in symbols.file_mods.values().flatten() {
+            let mod_ident = mod_defs.ident.to_string();
+            let members = std::iter::once((
+                mod_defs.ident.module.value(),
+                SymbolKind::MODULE,
+                mod_defs.name_loc,
+            ))
+            .chain(
+                mod_defs
+                    .structs
+                    .iter()
+                    .map(|(name, def)| (*name, SymbolKind::STRUCT, def.name_loc)),
+            )
+            .chain(
+                mod_defs
+                    .enums
+                    .iter()
+                    .map(|(name, def)| (*name, SymbolKind::ENUM, def.name_loc)),
+            )
+            .chain(
+                mod_defs
+                    .functions
+                    .iter()
+                    .map(|(name, def)| (*name, SymbolKind::FUNCTION, def.name_loc)),
+            )
+            .chain(
+                mod_defs
+                    .constants
+                    .iter()
+                    .map(|(name, def)| (*name, SymbolKind::CONSTANT, def.name_loc)),
+            );
+            for (name, kind, loc) in members {
+                // the same definition is available in all packages depending on it
+                if !seen.insert(loc) {
+                    continue;
+                }
+                let Some(rank) = match_rank(&query, &name.as_str().to_lowercase()) else {
+                    continue;
+                };
+                matches.push((
+                    symbols,
+                    Match {
+                        rank,
+                        name: name.to_string(),
+                        kind,
+                        container: (kind != SymbolKind::MODULE).then(|| mod_ident.clone()),
+                        loc,
+                    },
+                ));
+            }
+        }
+    }
+    matches.sort_by(|(_, m1), (_, m2)| {
+        (m1.rank, m1.name.len(), &m1.name).cmp(&(m2.rank, m2.name.len(), &m2.name))
+    });
+    matches
+        .into_iter()
+        .filter_map(|(symbols, m)| symbol_information(symbols, m))
+        .take(MAX_WORKSPACE_SYMBOLS)
+        .collect()
+}
+
+/// Computes rank of a (lowercase) name matching a (lowercase) query, or returns `None` if the
+/// name does not match.
+fn match_rank(query: &str, name: &str) -> Option<u8> {
+    if name == query {
+        return Some(0);
+    }
+    if name.starts_with(query) {
+        return Some(1);
+    }
+    if name.contains(query) {
+        return Some(2);
+    }
+    let mut name_chars = name.chars();
+    query
+        .chars()
+        .all(|qc| name_chars.any(|nc| nc == qc))
+        .then_some(3)
+}
+
+#[allow(deprecated)]
+fn symbol_information(symbols: &Symbols, m: Match) -> Option<SymbolInformation> {
+    let path = symbols.files.file_name_mapping().get(&m.loc.file_hash())?;
+    let deprecated = symbols
+        .file_mods
+        .get(path)
+        .into_iter()
+        .flatten()
+        .any(|mod_defs| mod_defs.deprecated.contains(&m.loc));
+    Some(SymbolInformation {
+        name: m.name,
+        kind: m.kind,
+        tags: deprecated.then(|| vec![SymbolTag::DEPRECATED]),
+        deprecated: None,
+        location: Location {
+            uri: Url::from_file_path(path).ok()?,
+            range: symbols.files.lsp_range_opt(&m.loc)?,
+        },
+        container_name: m.container,
+    })
+}
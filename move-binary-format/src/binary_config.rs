@@ -6,7 +6,7 @@ use crate::file_format_common::{VERSION_1, VERSION_MAX};
 
 /// Configuration for the binary format related to table size.
 /// Maps to all tables in the binary format.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TableConfig {
     pub module_handles: u16,
     pub datatype_handles: u16,
@@ -59,7 +59,7 @@ impl TableConfig {
 
 /// Configuration information for deserializing a binary.
 /// Controls multiple aspects of the deserialization process.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct BinaryConfig {
     pub max_binary_format_version: u32,
     pub min_binary_format_version: u32,
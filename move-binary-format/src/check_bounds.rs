@@ -12,8 +12,8 @@ use crate::{
         AbilitySet, Bytecode, CodeOffset, CodeUnit, CompiledModule, Constant, DatatypeHandle,
         EnumDefInstantiation, EnumDefinition, FieldHandle, FieldInstantiation, FunctionDefinition,
         FunctionDefinitionIndex, FunctionHandle, FunctionInstantiation, JumpTableInner, LocalIndex,
-        ModuleHandle, Signature, SignatureToken, StructDefInstantiation, StructDefinition,
-        StructFieldInformation, TableIndex, VariantDefinition, VariantHandle,
+        ModuleHandle, Signature, SignatureIndex, SignatureToken, StructDefInstantiation,
+        StructDefinition, StructFieldInformation, TableIndex, VariantDefinition, VariantHandle,
         VariantInstantiationHandle, VariantJumpTable,
     },
     internals::ModuleIndex,
@@ -496,7 +496,22 @@ impl<'a> BoundsChecker<'a> {
                     *idx,
                     bytecode_offset,
                 )?,
-                CallGeneric(idx) => {
+                PackClosure(idx, sig_idx) => {
+                    self.check_code_unit_bounds_impl(
+                        self.module.function_handles(),
+                        *idx,
+                        bytecode_offset,
+                    )?;
+                    self.check_code_unit_signature(*sig_idx, type_param_count, bytecode_offset)?;
+                }
+                CallGeneric(idx) | PackClosureGeneric(idx, _) => {
+                    if let PackClosureGeneric(_, sig_idx) = bytecode {
+                        self.check_code_unit_signature(
+                            *sig_idx,
+                            type_param_count,
+                            bytecode_offset,
+                        )?;
+                    }
                     self.check_code_unit_bounds_impl(
                         self.module.function_instantiations(),
                         *idx,
@@ -591,26 +606,19 @@ impl<'a> BoundsChecker<'a> {
                 | VecPushBack(idx)
                 | VecPopBack(idx)
                 | VecUnpack(idx, _)
-                | VecSwap(idx) => {
-                    self.check_code_unit_bounds_impl(
-                        self.module.signatures(),
-                        *idx,
-                        bytecode_offset,
-                    )?;
-                    if let Some(sig) = self.module.signatures().get(idx.into_index()) {
-                        for ty in &sig.0 {
-                            self.check_type_parameter(ty, type_param_count)?;
-                        }
-                    }
+                | VecSwap(idx)
+                | CallClosure(idx) => {
+                    self.check_code_unit_signature(*idx, type_param_count, bytecode_offset)?
                 }
 
                 // List out the other options explicitly so there's a compile error if a new
                 // bytecode gets added.
                 FreezeRef | Pop | Ret | LdU8(_) | LdU16(_) | LdU32(_) | LdU64(_) | LdU256(_)
                 | LdU128(_) | CastU8 | CastU16 | CastU32 | CastU64 | CastU128 | CastU256
-                | LdTrue | LdFalse | ReadRef | WriteRef | Add | Sub | Mul | Mod | Div | BitOr
-                | BitAnd | Xor | Shl | Shr | Or | And | Not | Eq | Neq | Lt | Gt | Le | Ge
-                | Abort | Nop => (),
+                | LdI8(_) | LdI16(_) | LdI32(_) | LdI64(_) | LdI128(_) | LdI256(_) | CastI8
+                | CastI16 | CastI32 | CastI64 | CastI128 | CastI256 | LdTrue | LdFalse
+                | ReadRef | WriteRef | Add | Sub | Mul | Mod | Div | BitOr | BitAnd | Xor | Shl
+                | Shr | Or | And | Not | Eq | Neq | Lt | Gt | Le | Ge | Abort | Nop => (),
                 PackVariant(v_handle)
                 | UnpackVariant(v_handle)
                 | UnpackVariantImmRef(v_handle)
@@ -684,13 +692,31 @@ impl<'a> BoundsChecker<'a> {
         Ok(())
     }
 
+    /// Check that the signature an instruction refers to exists and that its type parameters are
+    /// bound to the function type parameters.
+    fn check_code_unit_signature(
+        &self,
+        idx: SignatureIndex,
+        type_param_count: usize,
+        bytecode_offset: usize,
+    ) -> PartialVMResult<()> {
+        self.check_code_unit_bounds_impl(self.module.signatures(), idx, bytecode_offset)?;
+        if let Some(sig) = self.module.signatures().get(idx.into_index()) {
+            for ty in &sig.0 {
+                self.check_type_parameter(ty, type_param_count)?;
+            }
+        }
+        Ok(())
+    }
+
     fn check_type(&self, ty: &SignatureToken) -> PartialVMResult<()> {
         use self::SignatureToken::*;
 
         for ty in ty.preorder_traversal() {
             match ty {
-                Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address | Signer | TypeParameter(_)
-                | Reference(_) | MutableReference(_) | Vector(_) => (),
+                Bool | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256
+                | Address | Signer | TypeParameter(_) | Reference(_) | MutableReference(_)
+                | Vector(_) | Function(_) => (),
                 Datatype(idx) => {
                     check_bounds_impl(self.module.datatype_handles(), *idx)?;
                     if let Some(sh) = self.module.datatype_handles().get(idx.into_index()) {
@@ -753,13 +779,20 @@ impl<'a> BoundsChecker<'a> {
                 | U64
                 | U128
                 | U256
+                | I8
+                | I16
+                | I32
+                | I64
+                | I128
+                | I256
                 | Address
                 | Signer
                 | Datatype(_)
                 | Reference(_)
                 | MutableReference(_)
                 | Vector(_)
-                | DatatypeInstantiation(_) => (),
+                | DatatypeInstantiation(_)
+                | Function(_) => (),
             }
         }
         Ok(())
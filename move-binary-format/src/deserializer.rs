@@ -1048,6 +1048,12 @@ fn load_signature_token(cursor: &mut VersionedCursor) -> BinaryLoaderResult<Sign
             arity: usize,
             ty_args: Vec<SignatureToken>,
         },
+        Function {
+            params_len: usize,
+            returns_len: usize,
+            abilities: AbilitySet,
+            tys: Vec<SignatureToken>,
+        },
     }
 
     impl TypeBuilder {
@@ -1076,10 +1082,41 @@ fn load_signature_token(cursor: &mut VersionedCursor) -> BinaryLoaderResult<Sign
                         }
                     }
                 }
+                T::Function {
+                    params_len,
+                    returns_len,
+                    abilities,
+                    mut tys,
+                } => {
+                    tys.push(tok);
+                    T::function(params_len, returns_len, abilities, tys)
+                }
                 _ => unreachable!("invalid type constructor application"),
             }
         }
 
+        /// The parameter types of a function type come first, followed by its return types.
+        fn function(
+            params_len: usize,
+            returns_len: usize,
+            abilities: AbilitySet,
+            mut tys: Vec<SignatureToken>,
+        ) -> Self {
+            if tys.len() >= params_len + returns_len {
+                let returns = tys.split_off(params_len);
+                T::Saturated(SignatureToken::Function(Box::new((
+                    tys, returns, abilities,
+                ))))
+            } else {
+                T::Function {
+                    params_len,
+                    returns_len,
+                    abilities,
+                    tys,
+                }
+            }
+        }
+
         fn is_saturated(&self) -> bool {
             matches!(self, T::Saturated(_))
         }
@@ -1105,6 +1142,24 @@ fn load_signature_token(cursor: &mut VersionedCursor) -> BinaryLoaderResult<Sign
                         )),
                     );
                 }
+                S::FUNCTION if (cursor.version() < VERSION_8) => {
+                    return Err(
+                        PartialVMError::new(StatusCode::MALFORMED).with_message(format!(
+                            "function types not supported in bytecode version {}",
+                            cursor.version()
+                        )),
+                    );
+                }
+                S::I8 | S::I16 | S::I32 | S::I64 | S::I128 | S::I256
+                    if (cursor.version() < VERSION_9) =>
+                {
+                    return Err(
+                        PartialVMError::new(StatusCode::MALFORMED).with_message(format!(
+                            "signed integers not supported in bytecode version {}",
+                            cursor.version()
+                        )),
+                    );
+                }
                 _ => (),
             };
 
@@ -1116,6 +1171,12 @@ fn load_signature_token(cursor: &mut VersionedCursor) -> BinaryLoaderResult<Sign
                 S::U64 => T::Saturated(SignatureToken::U64),
                 S::U128 => T::Saturated(SignatureToken::U128),
                 S::U256 => T::Saturated(SignatureToken::U256),
+                S::I8 => T::Saturated(SignatureToken::I8),
+                S::I16 => T::Saturated(SignatureToken::I16),
+                S::I32 => T::Saturated(SignatureToken::I32),
+                S::I64 => T::Saturated(SignatureToken::I64),
+                S::I128 => T::Saturated(SignatureToken::I128),
+                S::I256 => T::Saturated(SignatureToken::I256),
                 S::ADDRESS => T::Saturated(SignatureToken::Address),
                 S::SIGNER => T::Saturated(SignatureToken::Signer),
                 S::VECTOR => T::Vector,
@@ -1142,6 +1203,15 @@ fn load_signature_token(cursor: &mut VersionedCursor) -> BinaryLoaderResult<Sign
                     let idx = load_type_parameter_index(cursor)?;
                     T::Saturated(SignatureToken::TypeParameter(idx))
                 }
+                S::FUNCTION => {
+                    let params_len = load_signature_size(cursor)? as usize;
+                    let returns_len = load_signature_size(cursor)? as usize;
+                    let u = read_uleb_internal(cursor, AbilitySet::ALL.into_u8() as u64)?;
+                    let Some(abilities) = AbilitySet::from_u8(u) else {
+                        return Err(PartialVMError::new(StatusCode::UNKNOWN_ABILITY));
+                    };
+                    T::function(params_len, returns_len, abilities, vec![])
+                }
             })
         } else {
             Err(PartialVMError::new(StatusCode::MALFORMED)
@@ -1638,6 +1708,19 @@ fn check_cursor_version_enum_compatible(cursor_version: u32) -> BinaryLoaderResu
     }
 }
 
+fn check_cursor_version_closure_compatible(cursor_version: u32) -> BinaryLoaderResult<()> {
+    if cursor_version < VERSION_8 {
+        Err(
+            PartialVMError::new(StatusCode::MALFORMED).with_message(format!(
+                "closures not supported in bytecode version {}",
+                cursor_version
+            )),
+        )
+    } else {
+        Ok(())
+    }
+}
+
 /// Deserializes a code stream (`Bytecode`s).
 fn load_code(cursor: &mut VersionedCursor, code: &mut Vec<Bytecode>) -> BinaryLoaderResult<()> {
     let bytecode_count = load_bytecode_count(cursor)?;
@@ -1685,6 +1768,27 @@ fn load_code(cursor: &mut VersionedCursor, code: &mut Vec<Bytecode>) -> BinaryLo
                     )),
                 );
             }
+            Opcodes::LD_I8
+            | Opcodes::LD_I16
+            | Opcodes::LD_I32
+            | Opcodes::LD_I64
+            | Opcodes::LD_I128
+            | Opcodes::LD_I256
+            | Opcodes::CAST_I8
+            | Opcodes::CAST_I16
+            | Opcodes::CAST_I32
+            | Opcodes::CAST_I64
+            | Opcodes::CAST_I128
+            | Opcodes::CAST_I256
+                if (cursor.version() < VERSION_9) =>
+            {
+                return Err(
+                    PartialVMError::new(StatusCode::MALFORMED).with_message(format!(
+                        "Loading or casting signed integers not supported in bytecode version {}",
+                        cursor.version()
+                    )),
+                );
+            }
             _ => (),
         };
 
@@ -1831,6 +1935,55 @@ fn load_code(cursor: &mut VersionedCursor, code: &mut Vec<Bytecode>) -> BinaryLo
                 let jti = load_jump_table_index(cursor)?;
                 Bytecode::VariantSwitch(VariantJumpTableIndex(jti))
             }
+            Opcodes::PACK_CLOSURE => {
+                check_cursor_version_closure_compatible(cursor.version())?;
+                let fh_idx = load_function_handle_index(cursor)?;
+                Bytecode::PackClosure(fh_idx, load_signature_index(cursor)?)
+            }
+            Opcodes::PACK_CLOSURE_GENERIC => {
+                check_cursor_version_closure_compatible(cursor.version())?;
+                let fi_idx = load_function_inst_index(cursor)?;
+                Bytecode::PackClosureGeneric(fi_idx, load_signature_index(cursor)?)
+            }
+            Opcodes::CALL_CLOSURE => {
+                check_cursor_version_closure_compatible(cursor.version())?;
+                Bytecode::CallClosure(load_signature_index(cursor)?)
+            }
+            Opcodes::LD_I8 => {
+                let value = cursor.read_u8().map_err(|_| {
+                    PartialVMError::new(StatusCode::MALFORMED)
+                        .with_message("Unexpected EOF".to_string())
+                })?;
+                Bytecode::LdI8(value as i8)
+            }
+            Opcodes::LD_I16 => {
+                let value = read_u16_internal(cursor)?;
+                Bytecode::LdI16(value as i16)
+            }
+            Opcodes::LD_I32 => {
+                let value = read_u32_internal(cursor)?;
+                Bytecode::LdI32(value as i32)
+            }
+            Opcodes::LD_I64 => {
+                let value = read_u64_internal(cursor)?;
+                Bytecode::LdI64(value as i64)
+            }
+            Opcodes::LD_I128 => {
+                let value = read_u128_internal(cursor)?;
+                Bytecode::LdI128(Box::new(value as i128))
+            }
+            Opcodes::LD_I256 => {
+                let value = read_u256_internal(cursor)?;
+                Bytecode::LdI256(Box::new(move_core_types::i256::I256::from_le_bytes(
+                    &value.to_le_bytes(),
+                )))
+            }
+            Opcodes::CAST_I8 => Bytecode::CastI8,
+            Opcodes::CAST_I16 => Bytecode::CastI16,
+            Opcodes::CAST_I32 => Bytecode::CastI32,
+            Opcodes::CAST_I64 => Bytecode::CastI64,
+            Opcodes::CAST_I128 => Bytecode::CastI128,
+            Opcodes::CAST_I256 => Bytecode::CastI256,
             // ******** DEPRECATED BYTECODES ********
             Opcodes::EXISTS_DEPRECATED => {
                 Bytecode::ExistsDeprecated(load_struct_def_index(cursor)?)
@@ -1913,6 +2066,13 @@ impl SerializedType {
             0xD => Ok(SerializedType::U16),
             0xE => Ok(SerializedType::U32),
             0xF => Ok(SerializedType::U256),
+            0x10 => Ok(SerializedType::FUNCTION),
+            0x11 => Ok(SerializedType::I8),
+            0x12 => Ok(SerializedType::I16),
+            0x13 => Ok(SerializedType::I32),
+            0x14 => Ok(SerializedType::I64),
+            0x15 => Ok(SerializedType::I128),
+            0x16 => Ok(SerializedType::I256),
             _ => Err(PartialVMError::new(StatusCode::UNKNOWN_SERIALIZED_TYPE)),
         }
     }
@@ -2074,6 +2234,21 @@ impl Opcodes {
             0x54 => Ok(Opcodes::UNPACK_VARIANT_GENERIC_IMM_REF),
             0x55 => Ok(Opcodes::UNPACK_VARIANT_GENERIC_MUT_REF),
             0x56 => Ok(Opcodes::VARIANT_SWITCH),
+            0x57 => Ok(Opcodes::PACK_CLOSURE),
+            0x58 => Ok(Opcodes::PACK_CLOSURE_GENERIC),
+            0x59 => Ok(Opcodes::CALL_CLOSURE),
+            0x5A => Ok(Opcodes::LD_I8),
+            0x5B => Ok(Opcodes::LD_I16),
+            0x5C => Ok(Opcodes::LD_I32),
+            0x5D => Ok(Opcodes::LD_I64),
+            0x5E => Ok(Opcodes::LD_I128),
+            0x5F => Ok(Opcodes::LD_I256),
+            0x60 => Ok(Opcodes::CAST_I8),
+            0x61 => Ok(Opcodes::CAST_I16),
+            0x62 => Ok(Opcodes::CAST_I32),
+            0x63 => Ok(Opcodes::CAST_I64),
+            0x64 => Ok(Opcodes::CAST_I128),
+            0x65 => Ok(Opcodes::CAST_I256),
             _ => Err(PartialVMError::new(StatusCode::UNKNOWN_OPCODE)),
         }
     }
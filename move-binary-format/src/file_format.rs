@@ -812,7 +812,7 @@ pub struct AbilitySet(u8);
 impl AbilitySet {
     /// The empty ability set
     pub const EMPTY: Self = Self(0);
-    /// Abilities for `Bool`, `U8`, `U16`, `U32`, `U64`, `U128`, `U256`, and `Address`
+    /// Abilities for `Bool`, the integer types, and `Address`
     pub const PRIMITIVES: AbilitySet =
         Self((Ability::Copy as u8) | (Ability::Drop as u8) | (Ability::Store as u8));
     /// Abilities for `Reference` and `MutableReference`
@@ -822,6 +822,8 @@ impl AbilitySet {
     /// Abilities for `Vector`, note they are predicated on the type argument
     pub const VECTOR: AbilitySet =
         Self((Ability::Copy as u8) | (Ability::Drop as u8) | (Ability::Store as u8));
+    /// Abilities a `Function` type can declare
+    pub const FUNCTIONS: AbilitySet = Self((Ability::Copy as u8) | (Ability::Drop as u8));
 
     /// Ability set containing all abilities
     pub const ALL: Self = Self(
@@ -1054,6 +1056,21 @@ pub enum SignatureToken {
     U32,
     /// Unsigned integers, 256 bits length.
     U256,
+    /// Function value, with the types of its parameters, the types of its return values, and its
+    /// abilities.
+    Function(Box<(Vec<SignatureToken>, Vec<SignatureToken>, AbilitySet)>),
+    /// Signed integers, 8 bits length.
+    I8,
+    /// Signed integers, 16 bits length.
+    I16,
+    /// Signed integers, 32 bits length.
+    I32,
+    /// Signed integers, 64 bits length.
+    I64,
+    /// Signed integers, 128 bits length.
+    I128,
+    /// Signed integers, 256 bits length.
+    I256,
 }
 
 /// An iterator to help traverse the `SignatureToken` in a non-recursive fashion to avoid
@@ -1082,8 +1099,13 @@ impl<'a> Iterator for SignatureTokenPreorderTraversalIter<'a> {
                         self.stack.extend(inner_toks.iter().rev())
                     }
 
-                    Signer | Bool | Address | U8 | U16 | U32 | U64 | U128 | U256 | Datatype(_)
-                    | TypeParameter(_) => (),
+                    Function(func) => {
+                        let (params, returns, _) = &**func;
+                        self.stack.extend(params.iter().chain(returns).rev())
+                    }
+
+                    Signer | Bool | Address | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16
+                    | I32 | I64 | I128 | I256 | Datatype(_) | TypeParameter(_) => (),
                 }
                 Some(tok)
             }
@@ -1117,8 +1139,19 @@ impl<'a> Iterator for SignatureTokenPreorderTraversalIterWithDepth<'a> {
                             .extend(inner_toks.iter().map(|tok| (tok, depth + 1)).rev())
                     }
 
-                    Signer | Bool | Address | U8 | U16 | U32 | U64 | U128 | U256 | Datatype(_)
-                    | TypeParameter(_) => (),
+                    Function(func) => {
+                        let (params, returns, _) = &**func;
+                        self.stack.extend(
+                            params
+                                .iter()
+                                .chain(returns)
+                                .map(|tok| (tok, depth + 1))
+                                .rev(),
+                        )
+                    }
+
+                    Signer | Bool | Address | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16
+                    | I32 | I64 | I128 | I256 | Datatype(_) | TypeParameter(_) => (),
                 }
                 Some((tok, depth))
             }
@@ -1144,6 +1177,12 @@ impl Arbitrary for SignatureToken {
             Just(U64),
             Just(U128),
             Just(U256),
+            Just(I8),
+            Just(I16),
+            Just(I32),
+            Just(I64),
+            Just(I128),
+            Just(I256),
             Just(Address),
             any::<DatatypeHandleIndex>().prop_map(Datatype),
             any::<TypeParameterIndex>().prop_map(TypeParameter),
@@ -1174,6 +1213,12 @@ impl std::fmt::Debug for SignatureToken {
             SignatureToken::U64 => write!(f, "U64"),
             SignatureToken::U128 => write!(f, "U128"),
             SignatureToken::U256 => write!(f, "U256"),
+            SignatureToken::I8 => write!(f, "I8"),
+            SignatureToken::I16 => write!(f, "I16"),
+            SignatureToken::I32 => write!(f, "I32"),
+            SignatureToken::I64 => write!(f, "I64"),
+            SignatureToken::I128 => write!(f, "I128"),
+            SignatureToken::I256 => write!(f, "I256"),
             SignatureToken::Address => write!(f, "Address"),
             SignatureToken::Signer => write!(f, "Signer"),
             SignatureToken::Vector(boxed) => write!(f, "Vector({:?})", boxed),
@@ -1185,6 +1230,10 @@ impl std::fmt::Debug for SignatureToken {
             SignatureToken::Reference(boxed) => write!(f, "Reference({:?})", boxed),
             SignatureToken::MutableReference(boxed) => write!(f, "MutableReference({:?})", boxed),
             SignatureToken::TypeParameter(idx) => write!(f, "TypeParameter({:?})", idx),
+            SignatureToken::Function(func) => {
+                let (params, returns, abilities) = &**func;
+                write!(f, "Function({:?}, {:?}, {:?})", params, returns, abilities)
+            }
         }
     }
 }
@@ -1207,11 +1256,18 @@ impl SignatureToken {
             | U64
             | U128
             | U256
+            | I8
+            | I16
+            | I32
+            | I64
+            | I128
+            | I256
             | Address
             | Signer
             | Datatype(_)
             | DatatypeInstantiation(_)
-            | Vector(_) => SignatureTokenKind::Value,
+            | Vector(_)
+            | Function(_) => SignatureTokenKind::Value,
             // TODO: This is a temporary hack to please the verifier. SignatureTokenKind will soon
             // be completely removed. `SignatureTokenView::kind()` should be used instead.
             TypeParameter(_) => SignatureTokenKind::Value,
@@ -1222,7 +1278,7 @@ impl SignatureToken {
     pub fn is_integer(&self) -> bool {
         use SignatureToken::*;
         match self {
-            U8 | U16 | U32 | U64 | U128 | U256 => true,
+            U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256 => true,
             Bool
             | Address
             | Signer
@@ -1231,10 +1287,17 @@ impl SignatureToken {
             | DatatypeInstantiation(_)
             | Reference(_)
             | MutableReference(_)
-            | TypeParameter(_) => false,
+            | TypeParameter(_)
+            | Function(_) => false,
         }
     }
 
+    // Returns `true` if the `SignatureToken` is a signed integer type.
+    pub fn is_signed_integer(&self) -> bool {
+        use SignatureToken::*;
+        matches!(self, I8 | I16 | I32 | I64 | I128 | I256)
+    }
+
     /// Returns true if the `SignatureToken` is any kind of reference (mutable and immutable).
     pub fn is_reference(&self) -> bool {
         use SignatureToken::*;
@@ -1262,14 +1325,16 @@ impl SignatureToken {
         use SignatureToken::*;
 
         match self {
-            Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address => true,
+            Bool | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256
+            | Address => true,
             Vector(inner) => inner.is_valid_for_constant(),
             Signer
             | Datatype(_)
             | DatatypeInstantiation(_)
             | Reference(_)
             | MutableReference(_)
-            | TypeParameter(_) => false,
+            | TypeParameter(_)
+            | Function(_) => false,
         }
     }
 
@@ -1824,6 +1889,97 @@ pub enum Bytecode {
     /// Stack transition:
     /// ```..., enum_value_ref -> ...```
     VariantSwitch(VariantJumpTableIndex),
+    /// Create a closure of the function specified via `FunctionHandleIndex` and push it on the
+    /// stack. The closure captures the values on the stack as the first arguments of the function,
+    /// and the signature at `SignatureIndex` holds its type, as a single `Function` token whose
+    /// parameters are the remaining parameters of the function.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., captured(1), ..., captured(k) -> ..., closure_value```
+    PackClosure(FunctionHandleIndex, SignatureIndex),
+    PackClosureGeneric(FunctionInstantiationIndex, SignatureIndex),
+    /// Call the closure at the top of the stack, whose type is the `Function` token in the
+    /// signature at `SignatureIndex`, with the arguments below it. The arguments are passed to
+    /// the function of the closure after the values it captured.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., arg(1), ..., arg(n), closure_value -> ..., return_value(1), ...,
+    /// return_value(k)```
+    CallClosure(SignatureIndex),
+    /// Push an I8 constant onto the stack.
+    ///
+    /// Stack transition:
+    ///
+    /// ```... -> ..., i8_value```
+    LdI8(i8),
+    /// Push an I16 constant onto the stack.
+    ///
+    /// Stack transition:
+    ///
+    /// ```... -> ..., i16_value```
+    LdI16(i16),
+    /// Push an I32 constant onto the stack.
+    ///
+    /// Stack transition:
+    ///
+    /// ```... -> ..., i32_value```
+    LdI32(i32),
+    /// Push an I64 constant onto the stack.
+    ///
+    /// Stack transition:
+    ///
+    /// ```... -> ..., i64_value```
+    LdI64(i64),
+    /// Push an I128 constant onto the stack.
+    ///
+    /// Stack transition:
+    ///
+    /// ```... -> ..., i128_value```
+    LdI128(Box<i128>),
+    /// Push an I256 constant onto the stack.
+    ///
+    /// Stack transition:
+    ///
+    /// ```... -> ..., i256_value```
+    LdI256(Box<move_core_types::i256::I256>),
+    /// Convert the value at the top of the stack into i8. Aborts if the value does not fit.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., integer_value -> ..., i8_value```
+    CastI8,
+    /// Convert the value at the top of the stack into i16. Aborts if the value does not fit.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., integer_value -> ..., i16_value```
+    CastI16,
+    /// Convert the value at the top of the stack into i32. Aborts if the value does not fit.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., integer_value -> ..., i32_value```
+    CastI32,
+    /// Convert the value at the top of the stack into i64. Aborts if the value does not fit.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., integer_value -> ..., i64_value```
+    CastI64,
+    /// Convert the value at the top of the stack into i128. Aborts if the value does not fit.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., integer_value -> ..., i128_value```
+    CastI128,
+    /// Convert the value at the top of the stack into i256. Aborts if the value does not fit.
+    ///
+    /// Stack transition:
+    ///
+    /// ```..., integer_value -> ..., i256_value```
+    CastI256,
 
     // ******** DEPRECATED BYTECODES ********
     ExistsDeprecated(StructDefinitionIndex),
@@ -1944,6 +2100,23 @@ impl ::std::fmt::Debug for Bytecode {
                 write!(f, "UnpackVariantGenericMutRef({:?})", handle)
             }
             Bytecode::VariantSwitch(jt) => write!(f, "VariantSwitch({:?})", jt),
+            Bytecode::PackClosure(idx, sig) => write!(f, "PackClosure({}, {})", idx, sig),
+            Bytecode::PackClosureGeneric(idx, sig) => {
+                write!(f, "PackClosureGeneric({}, {})", idx, sig)
+            }
+            Bytecode::CallClosure(sig) => write!(f, "CallClosure({})", sig),
+            Bytecode::LdI8(a) => write!(f, "LdI8({})", a),
+            Bytecode::LdI16(a) => write!(f, "LdI16({})", a),
+            Bytecode::LdI32(a) => write!(f, "LdI32({})", a),
+            Bytecode::LdI64(a) => write!(f, "LdI64({})", a),
+            Bytecode::LdI128(a) => write!(f, "LdI128({})", a),
+            Bytecode::LdI256(a) => write!(f, "LdI256({})", a),
+            Bytecode::CastI8 => write!(f, "CastI8"),
+            Bytecode::CastI16 => write!(f, "CastI16"),
+            Bytecode::CastI32 => write!(f, "CastI32"),
+            Bytecode::CastI64 => write!(f, "CastI64"),
+            Bytecode::CastI128 => write!(f, "CastI128"),
+            Bytecode::CastI256 => write!(f, "CastI256"),
         }
     }
 }
@@ -2028,6 +2201,21 @@ impl Bytecode {
             | Bytecode::UnpackVariantGeneric(_)
             | Bytecode::UnpackVariantGenericImmRef(_)
             | Bytecode::UnpackVariantGenericMutRef(_)
+            | Bytecode::PackClosure(_, _)
+            | Bytecode::PackClosureGeneric(_, _)
+            | Bytecode::CallClosure(_)
+            | Bytecode::LdI8(_)
+            | Bytecode::LdI16(_)
+            | Bytecode::LdI32(_)
+            | Bytecode::LdI64(_)
+            | Bytecode::LdI128(_)
+            | Bytecode::LdI256(_)
+            | Bytecode::CastI8
+            | Bytecode::CastI16
+            | Bytecode::CastI32
+            | Bytecode::CastI64
+            | Bytecode::CastI128
+            | Bytecode::CastI256
             | Bytecode::ExistsDeprecated(_)
             | Bytecode::ExistsGenericDeprecated(_)
             | Bytecode::MoveFromDeprecated(_)
@@ -2122,6 +2310,21 @@ impl Bytecode {
             | Bytecode::UnpackVariantGeneric(_)
             | Bytecode::UnpackVariantGenericImmRef(_)
             | Bytecode::UnpackVariantGenericMutRef(_)
+            | Bytecode::PackClosure(_, _)
+            | Bytecode::PackClosureGeneric(_, _)
+            | Bytecode::CallClosure(_)
+            | Bytecode::LdI8(_)
+            | Bytecode::LdI16(_)
+            | Bytecode::LdI32(_)
+            | Bytecode::LdI64(_)
+            | Bytecode::LdI128(_)
+            | Bytecode::LdI256(_)
+            | Bytecode::CastI8
+            | Bytecode::CastI16
+            | Bytecode::CastI32
+            | Bytecode::CastI64
+            | Bytecode::CastI128
+            | Bytecode::CastI256
             | Bytecode::ExistsDeprecated(_)
             | Bytecode::ExistsGenericDeprecated(_)
             | Bytecode::MoveFromDeprecated(_)
@@ -2232,6 +2435,21 @@ impl Bytecode {
             | Bytecode::UnpackVariantGeneric(_)
             | Bytecode::UnpackVariantGenericImmRef(_)
             | Bytecode::UnpackVariantGenericMutRef(_)
+            | Bytecode::PackClosure(_, _)
+            | Bytecode::PackClosureGeneric(_, _)
+            | Bytecode::CallClosure(_)
+            | Bytecode::LdI8(_)
+            | Bytecode::LdI16(_)
+            | Bytecode::LdI32(_)
+            | Bytecode::LdI64(_)
+            | Bytecode::LdI128(_)
+            | Bytecode::LdI256(_)
+            | Bytecode::CastI8
+            | Bytecode::CastI16
+            | Bytecode::CastI32
+            | Bytecode::CastI64
+            | Bytecode::CastI128
+            | Bytecode::CastI256
             | Bytecode::ExistsDeprecated(_)
             | Bytecode::ExistsGenericDeprecated(_)
             | Bytecode::MoveFromDeprecated(_)
@@ -2745,11 +2963,15 @@ impl CompiledModule {
         use SignatureToken::*;
 
         match ty {
-            Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address => Ok(AbilitySet::PRIMITIVES),
+            Bool | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256
+            | Address => Ok(AbilitySet::PRIMITIVES),
 
             Reference(_) | MutableReference(_) => Ok(AbilitySet::REFERENCES),
             Signer => Ok(AbilitySet::SIGNER),
             TypeParameter(idx) => Ok(constraints[*idx as usize]),
+            // The abilities of a function type are declared, and the values a closure captures
+            // are checked against them when it is packed
+            Function(func) => Ok(func.2),
             Vector(ty) => AbilitySet::polymorphic_abilities(
                 AbilitySet::VECTOR,
                 vec![false],
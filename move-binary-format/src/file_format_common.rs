@@ -208,6 +208,13 @@ pub enum SerializedType {
     U16                     = 0xD,
     U32                     = 0xE,
     U256                    = 0xF,
+    FUNCTION                = 0x10,
+    I8                      = 0x11,
+    I16                     = 0x12,
+    I32                     = 0x13,
+    I64                     = 0x14,
+    I128                    = 0x15,
+    I256                    = 0x16,
 }
 
 #[rustfmt::skip]
@@ -319,6 +326,21 @@ pub enum Opcodes {
     UNPACK_VARIANT_GENERIC_IMM_REF = 0x54,
     UNPACK_VARIANT_GENERIC_MUT_REF = 0x55,
     VARIANT_SWITCH                 = 0x56,
+    PACK_CLOSURE                   = 0x57,
+    PACK_CLOSURE_GENERIC           = 0x58,
+    CALL_CLOSURE                   = 0x59,
+    LD_I8                          = 0x5A,
+    LD_I16                         = 0x5B,
+    LD_I32                         = 0x5C,
+    LD_I64                         = 0x5D,
+    LD_I128                        = 0x5E,
+    LD_I256                        = 0x5F,
+    CAST_I8                        = 0x60,
+    CAST_I16                       = 0x61,
+    CAST_I32                       = 0x62,
+    CAST_I64                       = 0x63,
+    CAST_I128                      = 0x64,
+    CAST_I256                      = 0x65,
 
     // ******** DEPRECATED BYTECODES ********
     // global storage opcodes are unused and deprecated
@@ -523,8 +545,16 @@ pub const VERSION_6: u32 = 6;
 ///  + enums
 pub const VERSION_7: u32 = 7;
 
+/// Version 8: changes compared with version 7
+///  + function types and closures
+pub const VERSION_8: u32 = 8;
+
+/// Version 9: changes compared with version 8
+///  + i8, i16, i32, i64, i128, i256 integers and corresponding Ld, Cast bytecodes
+pub const VERSION_9: u32 = 9;
+
 // Mark which version is the latest version
-pub const VERSION_MAX: u32 = VERSION_7;
+pub const VERSION_MAX: u32 = VERSION_9;
 
 // Mark which oldest version is supported.
 // TODO(#145): finish v4 compatibility; as of now, only metadata is implemented
@@ -610,6 +640,21 @@ pub fn instruction_opcode(instruction: &Bytecode) -> Opcodes {
         UnpackVariantGenericImmRef(_) => Opcodes::UNPACK_VARIANT_GENERIC_IMM_REF,
         UnpackVariantGenericMutRef(_) => Opcodes::UNPACK_VARIANT_GENERIC_MUT_REF,
         VariantSwitch(_) => Opcodes::VARIANT_SWITCH,
+        PackClosure(_, _) => Opcodes::PACK_CLOSURE,
+        PackClosureGeneric(_, _) => Opcodes::PACK_CLOSURE_GENERIC,
+        CallClosure(_) => Opcodes::CALL_CLOSURE,
+        LdI8(_) => Opcodes::LD_I8,
+        LdI16(_) => Opcodes::LD_I16,
+        LdI32(_) => Opcodes::LD_I32,
+        LdI64(_) => Opcodes::LD_I64,
+        LdI128(_) => Opcodes::LD_I128,
+        LdI256(_) => Opcodes::LD_I256,
+        CastI8 => Opcodes::CAST_I8,
+        CastI16 => Opcodes::CAST_I16,
+        CastI32 => Opcodes::CAST_I32,
+        CastI64 => Opcodes::CAST_I64,
+        CastI128 => Opcodes::CAST_I128,
+        CastI256 => Opcodes::CAST_I256,
         // ******** DEPRECATED BYTECODES ********
         ExistsDeprecated(_) => Opcodes::EXISTS_DEPRECATED,
         ExistsGenericDeprecated(_) => Opcodes::EXISTS_GENERIC_DEPRECATED,
@@ -64,6 +64,25 @@ pub enum Type {
     U32,
     #[serde(rename = "u256")]
     U256,
+    // NOTE: Added in bytecode version v8, do not reorder!
+    Function {
+        parameters: Vec<Type>,
+        return_: Vec<Type>,
+        abilities: AbilitySet,
+    },
+    // NOTE: Added in bytecode version v9, do not reorder!
+    #[serde(rename = "i8")]
+    I8,
+    #[serde(rename = "i16")]
+    I16,
+    #[serde(rename = "i32")]
+    I32,
+    #[serde(rename = "i64")]
+    I64,
+    #[serde(rename = "i128")]
+    I128,
+    #[serde(rename = "i256")]
+    I256,
 }
 
 /// Normalized version of a `FieldDefinition`. The `name` is included even though it is
@@ -237,6 +256,21 @@ pub enum Bytecode {
     UnpackVariantGenericImmRef(VariantHandle),
     UnpackVariantGenericMutRef(VariantHandle),
     VariantSwitch(VariantJumpTable),
+    PackClosure((FunctionRef, Type)),
+    PackClosureGeneric((FunctionRef, Vec<Type>, Type)),
+    CallClosure(Type),
+    LdI8(i8),
+    LdI16(i16),
+    LdI32(i32),
+    LdI64(i64),
+    LdI128(i128),
+    LdI256(move_core_types::i256::I256),
+    CastI8,
+    CastI16,
+    CastI32,
+    CastI64,
+    CastI128,
+    CastI256,
     // ******** DEPRECATED BYTECODES ********
     MutBorrowGlobalDeprecated(Identifier),
     MutBorrowGlobalGenericDeprecated((Identifier, Vec<Type>)),
@@ -345,12 +379,26 @@ impl Type {
             U64 => Type::U64,
             U128 => Type::U128,
             U256 => Type::U256,
+            I8 => Type::I8,
+            I16 => Type::I16,
+            I32 => Type::I32,
+            I64 => Type::I64,
+            I128 => Type::I128,
+            I256 => Type::I256,
             Address => Type::Address,
             Signer => Type::Signer,
             Vector(t) => Type::Vector(Box::new(Type::new(m, t))),
             TypeParameter(i) => Type::TypeParameter(*i),
             Reference(t) => Type::Reference(Box::new(Type::new(m, t))),
             MutableReference(t) => Type::MutableReference(Box::new(Type::new(m, t))),
+            Function(func) => {
+                let (params, returns, abilities) = &**func;
+                Type::Function {
+                    parameters: params.iter().map(|t| Type::new(m, t)).collect(),
+                    return_: returns.iter().map(|t| Type::new(m, t)).collect(),
+                    abilities: *abilities,
+                }
+            }
         }
     }
 
@@ -366,10 +414,21 @@ impl Type {
             U64 => true,
             U128 => true,
             U256 => true,
+            I8 => true,
+            I16 => true,
+            I32 => true,
+            I64 => true,
+            I128 => true,
+            I256 => true,
             Address => true,
             Signer => true,
             Struct { type_arguments, .. } => type_arguments.iter().all(|t| t.is_closed()),
             Vector(t) | Reference(t) | MutableReference(t) => t.is_closed(),
+            Function {
+                parameters,
+                return_,
+                ..
+            } => parameters.iter().chain(return_).all(|t| t.is_closed()),
         }
     }
 
@@ -377,7 +436,7 @@ impl Type {
         use Type::*;
         Some(if self.is_closed() {
             match self {
-                Reference(_) | MutableReference(_) => return None,
+                Reference(_) | MutableReference(_) | Function { .. } => return None,
                 Bool => TypeTag::Bool,
                 U8 => TypeTag::U8,
                 U16 => TypeTag::U16,
@@ -385,6 +444,12 @@ impl Type {
                 U64 => TypeTag::U64,
                 U128 => TypeTag::U128,
                 U256 => TypeTag::U256,
+                I8 => TypeTag::I8,
+                I16 => TypeTag::I16,
+                I32 => TypeTag::I32,
+                I64 => TypeTag::I64,
+                I128 => TypeTag::I128,
+                I256 => TypeTag::I256,
                 Address => TypeTag::Address,
                 Signer => TypeTag::Signer,
                 Vector(t) => TypeTag::Vector(Box::new(
@@ -426,7 +491,8 @@ impl Type {
     pub fn subst(&self, type_args: &[Type]) -> Self {
         use Type::*;
         match self {
-            Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address | Signer => self.clone(),
+            Bool | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256
+            | Address | Signer => self.clone(),
             Reference(ty) => Reference(Box::new(ty.subst(type_args))),
             MutableReference(ty) => MutableReference(Box::new(ty.subst(type_args))),
             Vector(t) => Vector(Box::new(t.subst(type_args))),
@@ -448,6 +514,15 @@ impl Type {
                 .get(*i as usize)
                 .expect("Type parameter index out of bound")
                 .clone(),
+            Function {
+                parameters,
+                return_,
+                abilities,
+            } => Function {
+                parameters: parameters.iter().map(|t| t.subst(type_args)).collect(),
+                return_: return_.iter().map(|t| t.subst(type_args)).collect(),
+                abilities: *abilities,
+            },
         }
     }
 }
@@ -552,6 +627,12 @@ impl From<TypeTag> for Type {
             TypeTag::U64 => U64,
             TypeTag::U128 => U128,
             TypeTag::U256 => U256,
+            TypeTag::I8 => I8,
+            TypeTag::I16 => I16,
+            TypeTag::I32 => I32,
+            TypeTag::I64 => I64,
+            TypeTag::I128 => I128,
+            TypeTag::I256 => I256,
             TypeTag::Address => Address,
             TypeTag::Signer => Signer,
             TypeTag::Vector(ty) => Vector(Box::new(Type::from(*ty))),
@@ -700,6 +781,35 @@ impl Bytecode {
                     type_params.0.iter().map(|tok| Type::new(m, tok)).collect(),
                 ))
             }
+            FB::PackClosure(fh_idx, sig_idx) => B::PackClosure((
+                FunctionRef::from_idx(m, fh_idx),
+                signature_to_single_type(m, sig_idx),
+            )),
+            FB::PackClosureGeneric(fhi_idx, sig_idx) => {
+                let FunctionInstantiation {
+                    handle,
+                    type_parameters,
+                } = m.function_instantiation_at(*fhi_idx);
+                let type_params = m.signature_at(*type_parameters);
+                B::PackClosureGeneric((
+                    FunctionRef::from_idx(m, handle),
+                    type_params.0.iter().map(|tok| Type::new(m, tok)).collect(),
+                    signature_to_single_type(m, sig_idx),
+                ))
+            }
+            FB::CallClosure(sig_idx) => B::CallClosure(signature_to_single_type(m, sig_idx)),
+            FB::LdI8(x) => B::LdI8(*x),
+            FB::LdI16(x) => B::LdI16(*x),
+            FB::LdI32(x) => B::LdI32(*x),
+            FB::LdI64(x) => B::LdI64(*x),
+            FB::LdI128(x) => B::LdI128(**x),
+            FB::LdI256(x) => B::LdI256(**x),
+            FB::CastI8 => B::CastI8,
+            FB::CastI16 => B::CastI16,
+            FB::CastI32 => B::CastI32,
+            FB::CastI64 => B::CastI64,
+            FB::CastI128 => B::CastI128,
+            FB::CastI256 => B::CastI256,
             FB::Pack(s_idx) => B::Pack(m.struct_name(*s_idx).to_owned()),
             FB::PackGeneric(s_idx) => B::PackGeneric(struct_instantiation(m, s_idx)),
             FB::Unpack(s_idx) => B::Unpack(m.struct_name(*s_idx).to_owned()),
@@ -858,12 +968,50 @@ impl std::fmt::Display for Type {
             Type::U64 => write!(f, "u64"),
             Type::U128 => write!(f, "u128"),
             Type::U256 => write!(f, "u256"),
+            Type::I8 => write!(f, "i8"),
+            Type::I16 => write!(f, "i16"),
+            Type::I32 => write!(f, "i32"),
+            Type::I64 => write!(f, "i64"),
+            Type::I128 => write!(f, "i128"),
+            Type::I256 => write!(f, "i256"),
             Type::Address => write!(f, "address"),
             Type::Signer => write!(f, "signer"),
             Type::Bool => write!(f, "bool"),
             Type::Reference(r) => write!(f, "&{}", r),
             Type::MutableReference(r) => write!(f, "&mut {}", r),
             Type::TypeParameter(i) => write!(f, "T{:?}", i),
+            Type::Function {
+                parameters,
+                return_,
+                abilities,
+            } => {
+                write!(f, "|")?;
+                for (i, ty) in parameters.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", ty)?;
+                }
+                write!(f, "|")?;
+                match return_.as_slice() {
+                    [] => (),
+                    [ty] => write!(f, " -> {}", ty)?,
+                    tys => {
+                        write!(f, " -> (")?;
+                        for (i, ty) in tys.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            write!(f, "{}", ty)?;
+                        }
+                        write!(f, ")")?;
+                    }
+                }
+                for (i, ability) in abilities.into_iter().enumerate() {
+                    write!(f, "{}{}", if i == 0 { " has " } else { " + " }, ability)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -1096,8 +1096,8 @@ impl BytecodeGen {
     fn check_signature_token(token: &SignatureToken) -> bool {
         use SignatureToken::*;
         match token {
-            U8 | U16 | U32 | U64 | U128 | U256 | Bool | Address | Signer | Datatype(_)
-            | TypeParameter(_) => true,
+            U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256 | Bool
+            | Address | Signer | Datatype(_) | TypeParameter(_) | Function(_) => true,
             Vector(element_token) => BytecodeGen::check_signature_token(element_token),
             DatatypeInstantiation(inst) => {
                 let (_, type_arguments) = &**inst;
@@ -664,6 +664,12 @@ fn serialize_signature_token_single_node_impl(
         SignatureToken::U64 => binary.push(SerializedType::U64 as u8)?,
         SignatureToken::U128 => binary.push(SerializedType::U128 as u8)?,
         SignatureToken::U256 => binary.push(SerializedType::U256 as u8)?,
+        SignatureToken::I8 => binary.push(SerializedType::I8 as u8)?,
+        SignatureToken::I16 => binary.push(SerializedType::I16 as u8)?,
+        SignatureToken::I32 => binary.push(SerializedType::I32 as u8)?,
+        SignatureToken::I64 => binary.push(SerializedType::I64 as u8)?,
+        SignatureToken::I128 => binary.push(SerializedType::I128 as u8)?,
+        SignatureToken::I256 => binary.push(SerializedType::I256 as u8)?,
         SignatureToken::Address => binary.push(SerializedType::ADDRESS as u8)?,
         SignatureToken::Signer => binary.push(SerializedType::SIGNER as u8)?,
         SignatureToken::Vector(_) => {
@@ -689,6 +695,49 @@ fn serialize_signature_token_single_node_impl(
             binary.push(SerializedType::TYPE_PARAMETER as u8)?;
             serialize_type_parameter_index(binary, *idx)?;
         }
+        SignatureToken::Function(func) => {
+            let (params, returns, abilities) = &**func;
+            binary.push(SerializedType::FUNCTION as u8)?;
+            serialize_signature_size(binary, params.len())?;
+            serialize_signature_size(binary, returns.len())?;
+            serialize_ability_set(binary, *abilities)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_function_type_version(major_version: u32, token: &SignatureToken) -> Result<()> {
+    if major_version < VERSION_8
+        && token
+            .preorder_traversal()
+            .any(|tok| matches!(tok, SignatureToken::Function(_)))
+    {
+        bail!(
+            "Function types not supported in bytecode version {}",
+            major_version
+        );
+    }
+    Ok(())
+}
+
+fn check_signed_integer_type_version(major_version: u32, token: &SignatureToken) -> Result<()> {
+    if major_version < VERSION_9
+        && token
+            .preorder_traversal()
+            .any(|tok| tok.is_signed_integer())
+    {
+        bail!(
+            "Signed integers not supported in bytecode version {}",
+            major_version
+        );
+    }
+    Ok(())
+}
+
+fn check_fields_version(major_version: u32, fields: &[FieldDefinition]) -> Result<()> {
+    for field in fields {
+        check_function_type_version(major_version, &field.signature.0)?;
+        check_signed_integer_type_version(major_version, &field.signature.0)?;
     }
     Ok(())
 }
@@ -782,6 +831,26 @@ fn serialize_jump_table(binary: &mut BinaryData, jump_table: &VariantJumpTable)
     Ok(())
 }
 
+fn check_closure_opcode_version(major_version: u32) -> Result<()> {
+    if major_version < VERSION_8 {
+        bail!(
+            "Closure instructions not supported in bytecode version {}",
+            major_version
+        );
+    }
+    Ok(())
+}
+
+fn check_signed_integer_opcode_version(major_version: u32) -> Result<()> {
+    if major_version < VERSION_9 {
+        bail!(
+            "Loading or casting signed integers not supported in bytecode version {}",
+            major_version
+        );
+    }
+    Ok(())
+}
+
 fn check_enum_opcode_version(major_version: u32) -> Result<()> {
     if major_version < VERSION_7 {
         bail!(
@@ -1069,6 +1138,77 @@ fn serialize_instruction_inner(
             binary.push(Opcodes::VARIANT_SWITCH as u8)?;
             serialize_jump_table_index(binary, jti.0)
         }
+        Bytecode::PackClosure(fh_idx, sig_idx) => {
+            check_closure_opcode_version(major_version)?;
+            binary.push(Opcodes::PACK_CLOSURE as u8)?;
+            serialize_function_handle_index(binary, fh_idx)?;
+            serialize_signature_index(binary, sig_idx)
+        }
+        Bytecode::PackClosureGeneric(fi_idx, sig_idx) => {
+            check_closure_opcode_version(major_version)?;
+            binary.push(Opcodes::PACK_CLOSURE_GENERIC as u8)?;
+            serialize_function_inst_index(binary, fi_idx)?;
+            serialize_signature_index(binary, sig_idx)
+        }
+        Bytecode::CallClosure(sig_idx) => {
+            check_closure_opcode_version(major_version)?;
+            binary.push(Opcodes::CALL_CLOSURE as u8)?;
+            serialize_signature_index(binary, sig_idx)
+        }
+        Bytecode::LdI8(value) => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::LD_I8 as u8)?;
+            binary.push(*value as u8)
+        }
+        Bytecode::LdI16(value) => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::LD_I16 as u8)?;
+            write_u16(binary, *value as u16)
+        }
+        Bytecode::LdI32(value) => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::LD_I32 as u8)?;
+            write_u32(binary, *value as u32)
+        }
+        Bytecode::LdI64(value) => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::LD_I64 as u8)?;
+            write_u64(binary, *value as u64)
+        }
+        Bytecode::LdI128(value) => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::LD_I128 as u8)?;
+            write_u128(binary, **value as u128)
+        }
+        Bytecode::LdI256(value) => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::LD_I256 as u8)?;
+            binary.extend(&value.to_le_bytes())
+        }
+        Bytecode::CastI8 => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::CAST_I8 as u8)
+        }
+        Bytecode::CastI16 => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::CAST_I16 as u8)
+        }
+        Bytecode::CastI32 => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::CAST_I32 as u8)
+        }
+        Bytecode::CastI64 => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::CAST_I64 as u8)
+        }
+        Bytecode::CastI128 => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::CAST_I128 as u8)
+        }
+        Bytecode::CastI256 => {
+            check_signed_integer_opcode_version(major_version)?;
+            binary.push(Opcodes::CAST_I256 as u8)
+        }
     };
     res?;
     Ok(())
@@ -1345,6 +1485,10 @@ impl CommonSerializer {
             self.table_count += 1;
             self.signatures.0 = check_index_in_binary(binary.len())?;
             for signature in signatures {
+                for token in &signature.0 {
+                    check_function_type_version(self.major_version, token)?;
+                    check_signed_integer_type_version(self.major_version, token)?;
+                }
                 serialize_signature(binary, signature)?;
             }
             self.signatures.1 = checked_calculate_table_size(binary, self.signatures.0)?;
@@ -1465,6 +1609,11 @@ impl ModuleSerializer {
             self.common.table_count = self.common.table_count.wrapping_add(1); // the count will bound to a small number
             self.struct_defs.0 = check_index_in_binary(binary.len())?;
             for struct_definition in struct_definitions {
+                if let StructFieldInformation::Declared(fields) =
+                    &struct_definition.field_information
+                {
+                    check_fields_version(self.common.major_version, fields)?;
+                }
                 serialize_struct_definition(binary, struct_definition)?;
             }
             self.struct_defs.1 = checked_calculate_table_size(binary, self.struct_defs.0)?;
@@ -1488,6 +1637,9 @@ impl ModuleSerializer {
             self.common.table_count = self.common.table_count.wrapping_add(1); // the count will bound to a small number
             self.enum_defs.0 = check_index_in_binary(binary.len())?;
             for enum_definition in enum_definitions {
+                for variant in &enum_definition.variants {
+                    check_fields_version(self.common.major_version, &variant.fields)?;
+                }
                 serialize_enum_definition(binary, enum_definition)?;
             }
             self.enum_defs.1 = checked_calculate_table_size(binary, self.enum_defs.0)?;
@@ -6,8 +6,9 @@
 use crate::{
     binary_config::BinaryConfig,
     file_format::{
-        basic_test_module, basic_test_module_with_enum, Bytecode, CodeUnit, CompiledModule,
-        SignatureIndex, VariantJumpTableIndex,
+        basic_test_module, basic_test_module_with_enum, Ability, AbilitySet, Bytecode, CodeUnit,
+        CompiledModule, FunctionHandleIndex, FunctionInstantiation, FunctionInstantiationIndex,
+        Signature, SignatureIndex, SignatureToken, StructFieldInformation, VariantJumpTableIndex,
     },
     file_format_common::*,
 };
@@ -16,7 +17,7 @@ use move_core_types::{metadata::Metadata, vm_status::StatusCode};
 fn malformed_simple_versioned_test(version: u32) {
     // bad uleb (more than allowed for table count)
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(150); // table count (high bit 1)
     binary.push(150); // table count (high bit 1)
     binary.push(1);
@@ -28,7 +29,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // bad uleb (too big)
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(150); // table count (high bit 1)
     binary.push(150); // table count again (high bit 1)
     binary.push(150); // table count again (high bit 1)
@@ -49,7 +50,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // no tables
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(0); // table count
     let res = CompiledModule::deserialize_with_defaults(&binary);
     assert_eq!(
@@ -59,7 +60,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // missing tables
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(10); // table count
     let res = CompiledModule::deserialize_with_defaults(&binary);
     assert_eq!(
@@ -69,7 +70,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // missing table content
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(1); // table count
     binary.push(1); // table type
     binary.push(0); // table offset
@@ -82,7 +83,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // bad table header (bad offset)
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(1); // table count
     binary.push(1); // table type
     binary.push(100); // bad table offset
@@ -95,7 +96,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // bad table header (bad offset)
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(2); // table count
     binary.push(1); // table type
     binary.push(0); // table offset
@@ -112,7 +113,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // incomplete table
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(1); // table count
     binary.push(1); // table type
     binary.push(0); // table offset
@@ -126,7 +127,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // unknown table
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(1); // table count
     binary.push(100); // table type
     binary.push(0); // table offset
@@ -140,7 +141,7 @@ fn malformed_simple_versioned_test(version: u32) {
 
     // duplicate table
     let mut binary = BinaryConstants::MOVE_MAGIC.to_vec();
-    binary.extend(version.to_le_bytes()); // version
+    binary.extend(BinaryFlavor::encode_version(version).to_le_bytes()); // version
     binary.push(3); // table count
     binary.push(1); // table type
     binary.push(0); // table offset
@@ -448,9 +449,126 @@ fn serialize_deserialize_v7_with_no_flavor() {
 
 #[test]
 fn serialize_deserialize_v7_with_flavor() {
-    let module = basic_test_module_with_enum();
+    let mut module = basic_test_module_with_enum();
+    module.version = VERSION_7;
     let mut bin = vec![];
     module.serialize_with_version(VERSION_7, &mut bin).unwrap();
     let x = CompiledModule::deserialize_with_defaults(&bin).unwrap();
     assert_eq!(x, module);
 }
+
+fn function_type(
+    params: Vec<SignatureToken>,
+    returns: Vec<SignatureToken>,
+    abilities: AbilitySet,
+) -> SignatureToken {
+    SignatureToken::Function(Box::new((params, returns, abilities)))
+}
+
+/// `basic_test_module` with a function typed field, and closure instructions in `foo`
+fn basic_test_module_with_closures() -> CompiledModule {
+    let mut m = basic_test_module();
+    let StructFieldInformation::Declared(fields) = &mut m.struct_defs[0].field_information else {
+        unreachable!()
+    };
+    fields[0].signature.0 = function_type(
+        vec![SignatureToken::U64],
+        vec![],
+        AbilitySet::singleton(Ability::Drop),
+    );
+
+    // Function types nest, and can be empty
+    let thunk = function_type(vec![], vec![SignatureToken::Bool], AbilitySet::EMPTY);
+    m.signatures.push(Signature(vec![function_type(
+        vec![SignatureToken::U64, SignatureToken::Vector(Box::new(thunk))],
+        vec![SignatureToken::U64, SignatureToken::Bool],
+        AbilitySet::FUNCTIONS,
+    )]));
+    m.signatures.push(Signature(vec![SignatureToken::U64]));
+    m.function_instantiations.push(FunctionInstantiation {
+        handle: FunctionHandleIndex::new(0),
+        type_parameters: SignatureIndex::new(2),
+    });
+    m.function_defs[0].code = Some(CodeUnit {
+        locals: SignatureIndex::new(0),
+        code: vec![
+            Bytecode::PackClosure(FunctionHandleIndex::new(0), SignatureIndex::new(1)),
+            Bytecode::PackClosureGeneric(
+                FunctionInstantiationIndex::new(0),
+                SignatureIndex::new(1),
+            ),
+            Bytecode::CallClosure(SignatureIndex::new(1)),
+            Bytecode::Ret,
+        ],
+        jump_tables: vec![],
+    });
+    m
+}
+
+#[test]
+fn serialize_deserialize_v8_closures() {
+    let mut module = basic_test_module_with_closures();
+    module.version = VERSION_8;
+    let mut bin = vec![];
+    module.serialize_with_version(VERSION_8, &mut bin).unwrap();
+    let x = CompiledModule::deserialize_with_defaults(&bin).unwrap();
+    assert_eq!(x, module);
+}
+
+#[test]
+fn serialize_closures_below_v8() {
+    let module = basic_test_module_with_closures();
+    let mut bin = vec![];
+    module
+        .serialize_with_version(VERSION_7, &mut bin)
+        .unwrap_err();
+
+    // Function types and closure instructions are rejected separately
+    let mut module = basic_test_module_with_closures();
+    module.function_defs[0].code = basic_test_module().function_defs[0].code.clone();
+    module.signatures.truncate(1);
+    module.function_instantiations.clear();
+    let mut bin = vec![];
+    module
+        .serialize_with_version(VERSION_7, &mut bin)
+        .unwrap_err();
+
+    let mut module = basic_test_module_with_closures();
+    module.struct_defs = basic_test_module().struct_defs;
+    module.signatures.truncate(1);
+    module.function_instantiations.clear();
+    module.function_defs[0].code.as_mut().unwrap().code =
+        vec![Bytecode::CallClosure(SignatureIndex::new(0)), Bytecode::Ret];
+    let mut bin = vec![];
+    module
+        .serialize_with_version(VERSION_7, &mut bin)
+        .unwrap_err();
+}
+
+#[test]
+fn closure_version_lie() {
+    let test = |module: CompiledModule| {
+        let mut bin = vec![];
+        module.serialize_with_version(VERSION_8, &mut bin).unwrap();
+        // Manually manipulate the version in the binary to the wrong version
+        let v7_bytes = BinaryFlavor::encode_version(VERSION_7).to_le_bytes();
+        for (i, b) in v7_bytes.iter().enumerate() {
+            bin[i + BinaryConstants::MOVE_MAGIC_SIZE] = *b;
+        }
+        let status_code = CompiledModule::deserialize_with_defaults(&bin)
+            .unwrap_err()
+            .major_status();
+        assert_eq!(status_code, StatusCode::MALFORMED);
+    };
+
+    // With a function type
+    let mut module = basic_test_module_with_closures();
+    module.function_defs[0].code = basic_test_module().function_defs[0].code.clone();
+    test(module);
+
+    // With a closure instruction
+    let mut module = basic_test_module();
+    module.function_defs[0].code.as_mut().unwrap().code =
+        vec![Bytecode::CallClosure(SignatureIndex::new(0)), Bytecode::Ret];
+    test(module);
+}
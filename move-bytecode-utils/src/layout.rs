@@ -31,6 +31,9 @@ const SIGNER: &str = "Signer";
 /// Name of the Move `u256` type in the serde registry
 const U256_SERDE_NAME: &str = "u256";
 
+/// Name of the Move `i256` type in the serde registry
+const I256_SERDE_NAME: &str = "i256";
+
 /// The maximal value depth that we allow creating a layout for.
 const MAX_VALUE_DEPTH: u64 = 128;
 
@@ -168,6 +171,12 @@ impl<'a, T: GetModule> SerdeLayoutBuilder<'a, T> {
             U64 => Format::U64,
             U128 => Format::U128,
             U256 => Format::TypeName(U256_SERDE_NAME.to_string()),
+            I8 => Format::I8,
+            I16 => Format::I16,
+            I32 => Format::I32,
+            I64 => Format::I64,
+            I128 => Format::I128,
+            I256 => Format::TypeName(I256_SERDE_NAME.to_string()),
             Address => Format::TypeName(ADDRESS.to_string()),
             Signer => Format::TypeName(SIGNER.to_string()),
             Struct {
@@ -197,6 +206,7 @@ impl<'a, T: GetModule> SerdeLayoutBuilder<'a, T> {
             }
             TypeParameter(i) => input_type_args[*i as usize].clone(),
             Reference(_) | MutableReference(_) => unreachable!(), // structs cannot store references
+            Function { .. } => bail!("Type layouts cannot contain function types"),
         })
     }
 
@@ -426,6 +436,12 @@ impl TypeLayoutBuilder {
             U64 => A::MoveTypeLayout::U64,
             U128 => A::MoveTypeLayout::U128,
             U256 => A::MoveTypeLayout::U256,
+            I8 => A::MoveTypeLayout::I8,
+            I16 => A::MoveTypeLayout::I16,
+            I32 => A::MoveTypeLayout::I32,
+            I64 => A::MoveTypeLayout::I64,
+            I128 => A::MoveTypeLayout::I128,
+            I256 => A::MoveTypeLayout::I256,
             Address => A::MoveTypeLayout::Address,
             Signer => bail!("Type layouts cannot contain signer"),
             Vector(elem_t) => {
@@ -481,9 +497,16 @@ impl TypeLayoutBuilder {
             U64 => A::MoveTypeLayout::U64,
             U128 => A::MoveTypeLayout::U128,
             U256 => A::MoveTypeLayout::U256,
+            I8 => A::MoveTypeLayout::I8,
+            I16 => A::MoveTypeLayout::I16,
+            I32 => A::MoveTypeLayout::I32,
+            I64 => A::MoveTypeLayout::I64,
+            I128 => A::MoveTypeLayout::I128,
+            I256 => A::MoveTypeLayout::I256,
             Address => A::MoveTypeLayout::Address,
             Signer => bail!("Type layouts cannot contain signer"),
             Reference(_) | MutableReference(_) => bail!("Type layouts cannot contain references"),
+            Function(_) => bail!("Type layouts cannot contain function types"),
         })
     }
 }
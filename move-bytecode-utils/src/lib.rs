@@ -5,6 +5,7 @@
 
 pub mod layout;
 pub mod module_cache;
+pub mod rust_bindings;
 
 use move_binary_format::file_format::{CompiledModule, DatatypeHandleIndex, SignatureToken};
 use move_core_types::{
@@ -149,6 +150,12 @@ pub fn format_signature_token(module: &CompiledModule, t: &SignatureToken) -> St
         SignatureToken::U64 => "u64".to_string(),
         SignatureToken::U128 => "u128".to_string(),
         SignatureToken::U256 => "u256".to_string(),
+        SignatureToken::I8 => "i8".to_string(),
+        SignatureToken::I16 => "i16".to_string(),
+        SignatureToken::I32 => "i32".to_string(),
+        SignatureToken::I64 => "i64".to_string(),
+        SignatureToken::I128 => "i128".to_string(),
+        SignatureToken::I256 => "i256".to_string(),
         SignatureToken::Address => "address".to_string(),
         SignatureToken::Signer => "signer".to_string(),
         SignatureToken::Vector(inner) => {
@@ -165,6 +172,20 @@ pub fn format_signature_token(module: &CompiledModule, t: &SignatureToken) -> St
             let (idx, ty_args) = &**inst;
             format_signature_token_struct(module, *idx, ty_args)
         }
+        SignatureToken::Function(func) => {
+            let (params, returns, _) = &**func;
+            let params = params
+                .iter()
+                .map(|t| format_signature_token(module, t))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let returns = returns
+                .iter()
+                .map(|t| format_signature_token(module, t))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("|{}| -> ({})", params, returns)
+        }
     }
 }
 
@@ -0,0 +1,743 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! Generates Rust code that mirrors the datatypes of a set of Move modules, so that their values
+//! can be (de)serialized with BCS, along with functions that build calls to their entry functions.
+//!
+//! Every Move module that bindings are generated for becomes a Rust module. Structs and enums
+//! become Rust types deriving `serde`'s traits, with the same BCS representation:
+//! - Phantom type parameters are dropped, as they have no effect on the representation.
+//! - Structs whose fields are named `pos0`, `pos1`, ... are assumed to be positional and become
+//!   tuple structs, and likewise for enum variants.
+//! - `std::string::String` and `std::ascii::String` become Rust `String`s, and `std::option::Option`
+//!   becomes a Rust `Option`.
+//! - Function types have no serialized form and become `()`.
+//!
+//! Each entry function gets a function that serializes its arguments into an `EntryFunctionCall`.
+//! Signer arguments are left out, as they are supplied by whoever executes the call.
+//!
+//! The generated code depends on the `bcs`, `serde`, and `move-core-types` crates.
+
+use crate::Modules;
+use anyhow::Result;
+use move_binary_format::{
+    file_format::DatatypeTyParameter,
+    normalized::{self, Field, Type},
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::{IdentStr, Identifier},
+    language_storage::ModuleId,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+const ACCOUNT_ADDRESS: &str = "::move_core_types::account_address::AccountAddress";
+const TYPE_TAG: &str = "::move_core_types::language_storage::TypeTag";
+
+/// Generates Rust bindings for modules, resolving the datatypes they refer to in `modules`.
+pub struct RustBindingsGenerator<'a> {
+    modules: &'a Modules<'a>,
+    /// The source names of the parameters of functions, keyed by module and function name.
+    parameter_names: BTreeMap<(ModuleId, Identifier), Vec<String>>,
+}
+
+/// A datatype in the generated bindings.
+type DatatypeId = (ModuleId, Identifier);
+
+impl<'a> RustBindingsGenerator<'a> {
+    pub fn new(modules: &'a Modules<'a>) -> Self {
+        Self {
+            modules,
+            parameter_names: BTreeMap::new(),
+        }
+    }
+
+    /// Name the parameters of `function` in `module` with `names` in the generated entry function
+    /// builders, rather than numbering them.
+    pub fn add_parameter_names(
+        &mut self,
+        module: ModuleId,
+        function: Identifier,
+        names: Vec<String>,
+    ) {
+        self.parameter_names.insert((module, function), names);
+    }
+
+    /// Generate bindings for all the datatypes and entry functions of the modules in `roots`, and
+    /// for the datatypes from other modules that they refer to.
+    pub fn generate(&self, roots: &[ModuleId]) -> Result<String> {
+        let mut normalized = BTreeMap::new();
+        for id in roots {
+            normalized.insert(
+                id.clone(),
+                normalized::Module::new(self.modules.get_module(id)?),
+            );
+        }
+
+        // Find the datatypes the bindings need, starting from the ones in `roots`
+        let mut datatypes = BTreeSet::new();
+        let mut worklist = vec![];
+        for (id, module) in &normalized {
+            for name in module.structs.keys().chain(module.enums.keys()) {
+                worklist.push((id.clone(), name.clone()));
+            }
+            for function in module.functions.values().filter(|f| f.is_entry) {
+                for ty in &function.parameters {
+                    collect_datatypes(ty, &mut worklist);
+                }
+            }
+        }
+        while let Some(datatype) = worklist.pop() {
+            if is_builtin(&datatype) || !datatypes.insert(datatype.clone()) {
+                continue;
+            }
+            let (id, name) = &datatype;
+            if !normalized.contains_key(id) {
+                let module = normalized::Module::new(self.modules.get_module(id)?);
+                normalized.insert(id.clone(), module);
+            }
+            for field in datatype_fields(&normalized[id], name) {
+                collect_datatypes(&field.type_, &mut worklist);
+            }
+        }
+
+        let mut module_names = BTreeMap::new();
+        for id in normalized.keys() {
+            let name = rust_ident(id.name().as_str());
+            let clashes = normalized
+                .keys()
+                .any(|other| other != id && other.name() == id.name());
+            let name = if clashes {
+                format!("{}_{}", name, id.address().short_str_lossless())
+            } else {
+                name
+            };
+            module_names.insert(id.clone(), name);
+        }
+
+        let ctx = Context {
+            normalized: &normalized,
+            module_names: &module_names,
+        };
+        let mut out = String::new();
+        writeln!(
+            out,
+            "// Generated by `move generate rust-bindings`, do not edit.\n"
+        )?;
+        out.push_str(ENTRY_FUNCTION_CALL);
+        for (id, module) in &normalized {
+            let is_root = roots.contains(id);
+            let mut body = String::new();
+            for (name, s) in &module.structs {
+                if is_root || datatypes.contains(&(id.clone(), name.clone())) {
+                    ctx.write_struct(&mut body, name, s)?;
+                }
+            }
+            for (name, e) in &module.enums {
+                if is_root || datatypes.contains(&(id.clone(), name.clone())) {
+                    ctx.write_enum(&mut body, name, e)?;
+                }
+            }
+            if is_root {
+                for (name, function) in module.functions.iter().filter(|(_, f)| f.is_entry) {
+                    let names = self.parameter_names.get(&(id.clone(), name.clone()));
+                    ctx.write_entry_function(&mut body, id, name, function, names)?;
+                }
+            }
+            if body.is_empty() {
+                continue;
+            }
+
+            writeln!(out)?;
+            writeln!(out, "/// Bindings for `{}`.", id.short_str_lossless())?;
+            writeln!(
+                out,
+                "#[allow(dead_code, non_camel_case_types, non_snake_case, clippy::all)]"
+            )?;
+            writeln!(out, "pub mod {} {{", module_names[id])?;
+            out.push_str(body.strip_prefix('\n').unwrap_or(&body));
+            writeln!(out, "}}")?;
+        }
+        Ok(out)
+    }
+}
+
+const ENTRY_FUNCTION_CALL: &str = "\
+/// A call to a Move entry function, with its arguments serialized with BCS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryFunctionCall {
+    pub module: ::move_core_types::language_storage::ModuleId,
+    pub function: ::move_core_types::identifier::Identifier,
+    pub type_arguments: ::std::vec::Vec<::move_core_types::language_storage::TypeTag>,
+    pub arguments: ::std::vec::Vec<::std::vec::Vec<u8>>,
+}
+";
+
+struct Context<'a> {
+    normalized: &'a BTreeMap<ModuleId, normalized::Module>,
+    module_names: &'a BTreeMap<ModuleId, String>,
+}
+
+impl Context<'_> {
+    fn write_struct(
+        &self,
+        out: &mut String,
+        name: &Identifier,
+        s: &normalized::Struct,
+    ) -> Result<()> {
+        let name = rust_ident(name.as_str());
+        let params = type_params(s.type_parameters.iter().map(|p| p.is_phantom));
+        writeln!(out)?;
+        writeln!(out, "    {}", DERIVES)?;
+        if is_positional(&s.fields) {
+            writeln!(
+                out,
+                "    pub struct {}{}({});",
+                name,
+                params,
+                self.tuple_fields(&s.fields, "pub ")
+            )?;
+        } else {
+            writeln!(out, "    pub struct {}{} {{", name, params)?;
+            for field in &s.fields {
+                writeln!(
+                    out,
+                    "        pub {}: {},",
+                    rust_ident(field.name.as_str()),
+                    self.rust_type(&field.type_)
+                )?;
+            }
+            writeln!(out, "    }}")?;
+        }
+        Ok(())
+    }
+
+    fn write_enum(&self, out: &mut String, name: &Identifier, e: &normalized::Enum) -> Result<()> {
+        let params = type_params(e.type_parameters.iter().map(|p| p.is_phantom));
+        writeln!(out)?;
+        writeln!(out, "    {}", DERIVES)?;
+        writeln!(
+            out,
+            "    pub enum {}{} {{",
+            rust_ident(name.as_str()),
+            params
+        )?;
+        for variant in &e.variants {
+            let variant_name = rust_ident(variant.name.as_str());
+            if variant.fields.is_empty() {
+                writeln!(out, "        {},", variant_name)?;
+            } else if is_positional(&variant.fields) {
+                writeln!(
+                    out,
+                    "        {}({}),",
+                    variant_name,
+                    self.tuple_fields(&variant.fields, "")
+                )?;
+            } else {
+                let fields = variant
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        format!(
+                            "{}: {}",
+                            rust_ident(f.name.as_str()),
+                            self.rust_type(&f.type_)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                writeln!(out, "        {} {{ {} }},", variant_name, fields.join(", "))?;
+            }
+        }
+        writeln!(out, "    }}")?;
+        Ok(())
+    }
+
+    fn write_entry_function(
+        &self,
+        out: &mut String,
+        module: &ModuleId,
+        name: &Identifier,
+        function: &normalized::Function,
+        names: Option<&Vec<String>>,
+    ) -> Result<()> {
+        let mut args = vec![];
+        let mut generics = BTreeSet::new();
+        for (i, ty) in function.parameters.iter().enumerate() {
+            if is_signer(ty) {
+                continue;
+            }
+            collect_type_params(ty, &mut generics);
+            // Parameters that are unused in Move are conventionally prefixed with `_`, but they are
+            // used by the builder
+            let arg_name = names
+                .and_then(|names| names.get(i))
+                .map(|name| name.trim_start_matches('_'))
+                .filter(|name| IdentStr::is_valid(name))
+                .map(rust_ident)
+                .filter(|name| args.iter().all(|(other, _)| other != name))
+                .unwrap_or_else(|| format!("arg{i}"));
+            args.push((arg_name, self.rust_type(ty)));
+        }
+
+        let generics = if generics.is_empty() {
+            String::new()
+        } else {
+            let params = generics
+                .iter()
+                .map(|i| format!("T{i}: ::serde::Serialize"))
+                .collect::<Vec<_>>();
+            format!("<{}>", params.join(", "))
+        };
+        let params = (0..function.type_parameters.len())
+            .map(|i| format!("type_arg{i}: {TYPE_TAG}"))
+            .chain(args.iter().map(|(name, ty)| format!("{name}: {ty}")))
+            .collect::<Vec<_>>();
+        let type_arguments = (0..function.type_parameters.len())
+            .map(|i| format!("type_arg{i}"))
+            .collect::<Vec<_>>();
+        let arguments = args
+            .iter()
+            .map(|(name, _)| format!("::bcs::to_bytes(&{name})?"))
+            .collect::<Vec<_>>();
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    /// Build a call to `{}::{}`.",
+            module.short_str_lossless(),
+            name
+        )?;
+        writeln!(
+            out,
+            "    pub fn {}{}({}) -> ::bcs::Result<super::EntryFunctionCall> {{",
+            rust_ident(name.as_str()),
+            generics,
+            params.join(", ")
+        )?;
+        writeln!(out, "        Ok(super::EntryFunctionCall {{")?;
+        writeln!(
+            out,
+            "            module: ::move_core_types::language_storage::ModuleId::new({}, {}),",
+            address_expr(module.address()),
+            identifier_expr(module.name())
+        )?;
+        writeln!(out, "            function: {},", identifier_expr(name))?;
+        writeln!(
+            out,
+            "            type_arguments: ::std::vec![{}],",
+            type_arguments.join(", ")
+        )?;
+        writeln!(
+            out,
+            "            arguments: ::std::vec![{}],",
+            arguments.join(", ")
+        )?;
+        writeln!(out, "        }})")?;
+        writeln!(out, "    }}")?;
+        Ok(())
+    }
+
+    fn tuple_fields(&self, fields: &[Field], visibility: &str) -> String {
+        fields
+            .iter()
+            .map(|f| format!("{}{}", visibility, self.rust_type(&f.type_)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The Rust type that `ty` is bound to in the bindings. References are bound to the type they
+    /// refer to, as that is how they are passed to entry functions.
+    fn rust_type(&self, ty: &Type) -> String {
+        match ty {
+            Type::Bool => "bool".to_string(),
+            Type::U8 => "u8".to_string(),
+            Type::U16 => "u16".to_string(),
+            Type::U32 => "u32".to_string(),
+            Type::U64 => "u64".to_string(),
+            Type::U128 => "u128".to_string(),
+            Type::U256 => "::move_core_types::u256::U256".to_string(),
+            Type::I8 => "i8".to_string(),
+            Type::I16 => "i16".to_string(),
+            Type::I32 => "i32".to_string(),
+            Type::I64 => "i64".to_string(),
+            Type::I128 => "i128".to_string(),
+            Type::I256 => "::move_core_types::i256::I256".to_string(),
+            Type::Address | Type::Signer => ACCOUNT_ADDRESS.to_string(),
+            Type::Vector(ty) => format!("::std::vec::Vec<{}>", self.rust_type(ty)),
+            Type::TypeParameter(i) => format!("T{i}"),
+            Type::Reference(ty) | Type::MutableReference(ty) => self.rust_type(ty),
+            Type::Function { .. } => "()".to_string(),
+            Type::Struct {
+                address,
+                module,
+                name,
+                type_arguments,
+            } => {
+                let id = ModuleId::new(*address, module.clone());
+                let datatype = (id, name.clone());
+                if let Some(builtin) = builtin_type(&datatype) {
+                    return match type_arguments.as_slice() {
+                        [] => builtin.to_string(),
+                        args => format!("{}<{}>", builtin, self.type_arguments(args)),
+                    };
+                }
+
+                let (id, name) = datatype;
+                let module = &self.normalized[&id];
+                let phantoms = datatype_type_params(module, &name)
+                    .iter()
+                    .map(|p| p.is_phantom)
+                    .collect::<Vec<_>>();
+                let args = type_arguments
+                    .iter()
+                    .zip(phantoms)
+                    .filter(|(_, is_phantom)| !is_phantom)
+                    .map(|(ty, _)| ty.clone())
+                    .collect::<Vec<_>>();
+                let path = format!(
+                    "super::{}::{}",
+                    self.module_names[&id],
+                    rust_ident(name.as_str())
+                );
+                if args.is_empty() {
+                    path
+                } else {
+                    format!("{}<{}>", path, self.type_arguments(&args))
+                }
+            }
+        }
+    }
+
+    fn type_arguments(&self, types: &[Type]) -> String {
+        types
+            .iter()
+            .map(|ty| self.rust_type(ty))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+const DERIVES: &str =
+    "#[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]";
+
+/// The Rust type that a Move datatype from the standard library maps to directly, if any.
+fn builtin_type((id, name): &DatatypeId) -> Option<&'static str> {
+    if *id.address() != AccountAddress::ONE {
+        return None;
+    }
+    match (id.name().as_str(), name.as_str()) {
+        ("string", "String") | ("ascii", "String") => Some("::std::string::String"),
+        ("option", "Option") => Some("::std::option::Option"),
+        _ => None,
+    }
+}
+
+fn is_builtin(datatype: &DatatypeId) -> bool {
+    builtin_type(datatype).is_some()
+}
+
+fn is_signer(ty: &Type) -> bool {
+    match ty {
+        Type::Signer => true,
+        Type::Reference(ty) | Type::MutableReference(ty) => is_signer(ty),
+        _ => false,
+    }
+}
+
+fn is_positional(fields: &[Field]) -> bool {
+    !fields.is_empty()
+        && fields
+            .iter()
+            .enumerate()
+            .all(|(i, f)| f.name.as_str() == format!("pos{i}"))
+}
+
+fn collect_datatypes(ty: &Type, datatypes: &mut Vec<DatatypeId>) {
+    match ty {
+        Type::Struct {
+            address,
+            module,
+            name,
+            type_arguments,
+        } => {
+            datatypes.push((ModuleId::new(*address, module.clone()), name.clone()));
+            for ty in type_arguments {
+                collect_datatypes(ty, datatypes);
+            }
+        }
+        Type::Vector(ty) | Type::Reference(ty) | Type::MutableReference(ty) => {
+            collect_datatypes(ty, datatypes)
+        }
+        _ => (),
+    }
+}
+
+fn collect_type_params(ty: &Type, params: &mut BTreeSet<u16>) {
+    match ty {
+        Type::TypeParameter(i) => {
+            params.insert(*i);
+        }
+        Type::Struct { type_arguments, .. } => {
+            for ty in type_arguments {
+                collect_type_params(ty, params);
+            }
+        }
+        Type::Vector(ty) | Type::Reference(ty) | Type::MutableReference(ty) => {
+            collect_type_params(ty, params)
+        }
+        _ => (),
+    }
+}
+
+fn datatype_fields<'m>(module: &'m normalized::Module, name: &Identifier) -> Vec<&'m Field> {
+    if let Some(s) = module.structs.get(name) {
+        s.fields.iter().collect()
+    } else if let Some(e) = module.enums.get(name) {
+        e.variants.iter().flat_map(|v| &v.fields).collect()
+    } else {
+        vec![]
+    }
+}
+
+fn datatype_type_params<'m>(
+    module: &'m normalized::Module,
+    name: &Identifier,
+) -> &'m [DatatypeTyParameter] {
+    if let Some(s) = module.structs.get(name) {
+        &s.type_parameters
+    } else if let Some(e) = module.enums.get(name) {
+        &e.type_parameters
+    } else {
+        &[]
+    }
+}
+
+/// The type parameter list of a datatype, leaving out phantom type parameters.
+fn type_params(is_phantom: impl Iterator<Item = bool>) -> String {
+    let params = is_phantom
+        .enumerate()
+        .filter(|(_, is_phantom)| !is_phantom)
+        .map(|(i, _)| format!("T{i}"))
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", params.join(", "))
+    }
+}
+
+/// `name` as a Rust identifier, escaping it if it is a Rust keyword.
+fn rust_ident(name: &str) -> String {
+    match name {
+        "self" | "Self" | "super" | "crate" => format!("{name}_"),
+        "as" | "async" | "await" | "break" | "const" | "continue" | "dyn" | "else" | "enum"
+        | "extern" | "false" | "fn" | "for" | "gen" | "if" | "impl" | "in" | "let" | "loop"
+        | "match" | "mod" | "move" | "mut" | "pub" | "ref" | "return" | "static" | "struct"
+        | "trait" | "true" | "try" | "type" | "unsafe" | "use" | "where" | "while" | "abstract"
+        | "become" | "box" | "do" | "final" | "macro" | "override" | "priv" | "typeof"
+        | "unsized" | "virtual" | "yield" => format!("r#{name}"),
+        _ => name.to_string(),
+    }
+}
+
+fn address_expr(address: &AccountAddress) -> String {
+    format!(
+        "{ACCOUNT_ADDRESS}::from_hex_literal({:?}).unwrap()",
+        address.to_hex_literal()
+    )
+}
+
+fn identifier_expr(name: &IdentStr) -> String {
+    format!(
+        "::move_core_types::identifier::Identifier::new({:?}).unwrap()",
+        name.as_str()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RustBindingsGenerator;
+    use crate::Modules;
+    use move_binary_format::CompiledModule;
+    use move_core_types::{
+        identifier::Identifier,
+        language_storage::TypeTag,
+        parsing::address::NumericalAddress,
+        runtime_value::{MoveStruct, MoveValue, MoveVariant},
+    };
+    use serde::{de::DeserializeOwned, Serialize};
+    use std::{collections::BTreeMap, fmt::Debug};
+
+    /// The bindings generated for `SOURCE`, which `generated_bindings` checks are up to date.
+    mod generated {
+        include!("rust_bindings/testdata/bindings.rs");
+    }
+
+    const SOURCE: &str = r#"
+        #[allow(unused_field)]
+        module 0x2::shapes {
+            public struct Point has copy, drop, store { x: u64, y: u64 }
+            public struct Wrapper(u8, vector<u8>) has copy, drop;
+            public struct Coin<phantom T> has store { value: u64 }
+            public struct Pair<T, phantom U> has copy, drop { first: T, fn: u8 }
+            public struct Keyword has copy, drop { shape: Shape }
+            public enum Shape has copy, drop, store {
+                Circle { radius: u64 },
+                Square(u64),
+                Empty,
+                Loop,
+            }
+
+            entry fun create<T: drop>(_s: &signer, _point: Point, _type: u8, _t: T, _fn: u8) {}
+        }
+
+        #[allow(unused_field)]
+        module 0x3::uses {
+            public struct Holder has copy, drop {
+                pair: 0x2::shapes::Pair<0x2::shapes::Point, u8>,
+                coin: vector<0x2::shapes::Wrapper>,
+            }
+        }
+    "#;
+
+    /// Compiles `SOURCE`, renaming `Keyword` to `match` and `Loop` to `loop` in the bytecode, as
+    /// Move does not allow datatypes or variants to be named like this.
+    fn compile() -> Vec<CompiledModule> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shapes.move");
+        std::fs::write(&path, SOURCE).unwrap();
+        let (_, units) = move_compiler::Compiler::from_files(
+            None,
+            vec![path.to_str().unwrap()],
+            vec![],
+            BTreeMap::<&str, NumericalAddress>::new(),
+        )
+        .build_and_report()
+        .unwrap();
+        units
+            .into_iter()
+            .map(|unit| {
+                let mut module = unit.named_module.module;
+                for ident in &mut module.identifiers {
+                    match ident.as_str() {
+                        "Keyword" => *ident = Identifier::new("match").unwrap(),
+                        "Loop" => *ident = Identifier::new("loop").unwrap(),
+                        _ => (),
+                    }
+                }
+                module
+            })
+            .collect()
+    }
+
+    #[test]
+    fn generated_bindings() {
+        let modules = compile();
+        let modules = Modules::new(&modules);
+        let shapes = "0x2::shapes".parse().unwrap();
+        let mut generator = RustBindingsGenerator::new(&modules);
+        generator.add_parameter_names(
+            shapes,
+            Identifier::new("create").unwrap(),
+            vec!["s", "point", "_type", "t", "fn"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        );
+        let bindings = generator
+            .generate(&["0x3::uses".parse().unwrap(), "0x2::shapes".parse().unwrap()])
+            .unwrap();
+        expect_test::expect_file!["rust_bindings/testdata/bindings.rs"].assert_eq(&bindings);
+    }
+
+    /// Checks that `value` has the same BCS representation as `move_value`, and that it is read
+    /// back from it.
+    fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(
+        value: T,
+        move_value: MoveValue,
+    ) {
+        let bytes = bcs::to_bytes(&value).unwrap();
+        assert_eq!(Some(bytes.clone()), move_value.simple_serialize());
+        assert_eq!(bcs::from_bytes::<T>(&bytes).unwrap(), value);
+    }
+
+    fn move_struct(fields: Vec<MoveValue>) -> MoveValue {
+        MoveValue::Struct(MoveStruct(fields))
+    }
+
+    fn move_variant(tag: u16, fields: Vec<MoveValue>) -> MoveValue {
+        MoveValue::Variant(MoveVariant { tag, fields })
+    }
+
+    #[test]
+    fn bcs_round_trip() {
+        use generated::{shapes, uses};
+
+        round_trip(
+            uses::Holder {
+                pair: shapes::Pair {
+                    first: shapes::Point { x: 1, y: 2 },
+                    r#fn: 3,
+                },
+                coin: vec![shapes::Wrapper(4, vec![5, 6])],
+            },
+            move_struct(vec![
+                move_struct(vec![
+                    move_struct(vec![MoveValue::U64(1), MoveValue::U64(2)]),
+                    MoveValue::U8(3),
+                ]),
+                MoveValue::Vector(vec![move_struct(vec![
+                    MoveValue::U8(4),
+                    MoveValue::vector_u8(vec![5, 6]),
+                ])]),
+            ]),
+        );
+        round_trip(
+            shapes::Coin { value: 7 },
+            move_struct(vec![MoveValue::U64(7)]),
+        );
+        round_trip(
+            shapes::r#match {
+                shape: shapes::Shape::Circle { radius: 8 },
+            },
+            move_struct(vec![move_variant(0, vec![MoveValue::U64(8)])]),
+        );
+        round_trip(
+            shapes::Shape::Square(9),
+            move_variant(1, vec![MoveValue::U64(9)]),
+        );
+        round_trip(shapes::Shape::Empty, move_variant(2, vec![]));
+        round_trip(shapes::Shape::r#loop, move_variant(3, vec![]));
+    }
+
+    #[test]
+    fn entry_function_call() {
+        let call = generated::shapes::create(
+            TypeTag::U16,
+            generated::shapes::Point { x: 1, y: 2 },
+            3,
+            4u16,
+            5,
+        )
+        .unwrap();
+        assert_eq!(call.module, "0x2::shapes".parse().unwrap());
+        assert_eq!(call.function.as_str(), "create");
+        assert_eq!(call.type_arguments, vec![TypeTag::U16]);
+        // The signer is left out
+        assert_eq!(
+            call.arguments,
+            vec![
+                move_struct(vec![MoveValue::U64(1), MoveValue::U64(2)])
+                    .simple_serialize()
+                    .unwrap(),
+                vec![3],
+                vec![4, 0],
+                vec![5],
+            ]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+// Generated by `move generate rust-bindings`, do not edit.
+
+/// A call to a Move entry function, with its arguments serialized with BCS.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryFunctionCall {
+    pub module: ::move_core_types::language_storage::ModuleId,
+    pub function: ::move_core_types::identifier::Identifier,
+    pub type_arguments: ::std::vec::Vec<::move_core_types::language_storage::TypeTag>,
+    pub arguments: ::std::vec::Vec<::std::vec::Vec<u8>>,
+}
+
+/// Bindings for `0x2::shapes`.
+#[allow(dead_code, non_camel_case_types, non_snake_case, clippy::all)]
+pub mod shapes {
+    #[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Coin {
+        pub value: u64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Pair<T0> {
+        pub first: T0,
+        pub r#fn: u8,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Point {
+        pub x: u64,
+        pub y: u64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Wrapper(pub u8, pub ::std::vec::Vec<u8>);
+
+    #[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct r#match {
+        pub shape: super::shapes::Shape,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub enum Shape {
+        Circle { radius: u64 },
+        Square(u64),
+        Empty,
+        r#loop,
+    }
+
+    /// Build a call to `0x2::shapes::create`.
+    pub fn create<T0: ::serde::Serialize>(type_arg0: ::move_core_types::language_storage::TypeTag, point: super::shapes::Point, r#type: u8, t: T0, r#fn: u8) -> ::bcs::Result<super::EntryFunctionCall> {
+        Ok(super::EntryFunctionCall {
+            module: ::move_core_types::language_storage::ModuleId::new(::move_core_types::account_address::AccountAddress::from_hex_literal("0x2").unwrap(), ::move_core_types::identifier::Identifier::new("shapes").unwrap()),
+            function: ::move_core_types::identifier::Identifier::new("create").unwrap(),
+            type_arguments: ::std::vec![type_arg0],
+            arguments: ::std::vec![::bcs::to_bytes(&point)?, ::bcs::to_bytes(&r#type)?, ::bcs::to_bytes(&t)?, ::bcs::to_bytes(&r#fn)?],
+        })
+    }
+}
+
+/// Bindings for `0x3::uses`.
+#[allow(dead_code, non_camel_case_types, non_snake_case, clippy::all)]
+pub mod uses {
+    #[derive(Debug, Clone, PartialEq, Eq, ::serde::Serialize, ::serde::Deserialize)]
+    pub struct Holder {
+        pub pair: super::shapes::Pair<super::shapes::Point>,
+        pub coin: ::std::vec::Vec<super::shapes::Wrapper>,
+    }
+}
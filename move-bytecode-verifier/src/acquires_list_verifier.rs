@@ -126,6 +126,12 @@ impl<'a> AcquiresVerifier<'a> {
             | Bytecode::LdU64(_)
             | Bytecode::LdU128(_)
             | Bytecode::LdU256(_)
+            | Bytecode::LdI8(_)
+            | Bytecode::LdI16(_)
+            | Bytecode::LdI32(_)
+            | Bytecode::LdI64(_)
+            | Bytecode::LdI128(_)
+            | Bytecode::LdI256(_)
             | Bytecode::LdConst(_)
             | Bytecode::LdTrue
             | Bytecode::LdFalse
@@ -141,6 +147,12 @@ impl<'a> AcquiresVerifier<'a> {
             | Bytecode::CastU64
             | Bytecode::CastU128
             | Bytecode::CastU256
+            | Bytecode::CastI8
+            | Bytecode::CastI16
+            | Bytecode::CastI32
+            | Bytecode::CastI64
+            | Bytecode::CastI128
+            | Bytecode::CastI256
             | Bytecode::Add
             | Bytecode::Sub
             | Bytecode::Mul
@@ -172,6 +184,9 @@ impl<'a> AcquiresVerifier<'a> {
             | Bytecode::VecPopBack(_)
             | Bytecode::VecUnpack(..)
             | Bytecode::VecSwap(_)
+            | Bytecode::PackClosure(..)
+            | Bytecode::PackClosureGeneric(..)
+            | Bytecode::CallClosure(_)
             | Bytecode::PackVariant(_)
             | Bytecode::PackVariantGeneric(_)
             | Bytecode::UnpackVariant(_)
@@ -175,6 +175,12 @@ impl<'a> DataDefGraphBuilder<'a> {
             | T::U64
             | T::U128
             | T::U256
+            | T::I8
+            | T::I16
+            | T::I32
+            | T::I64
+            | T::I128
+            | T::I256
             | T::Address
             | T::Signer
             | T::TypeParameter(_) => (),
@@ -199,6 +205,17 @@ impl<'a> DataDefGraphBuilder<'a> {
                     self.add_signature_token(neighbors, cur_idx, t)?
                 }
             }
+            T::Function(func) => {
+                let (params, returns, _) = &**func;
+                for t in params.iter().chain(returns) {
+                    // References are allowed at the top level of a function type
+                    let t = match t {
+                        T::Reference(inner) | T::MutableReference(inner) => inner,
+                        _ => t,
+                    };
+                    self.add_signature_token(neighbors, cur_idx, t)?
+                }
+            }
         };
         Ok(())
     }
@@ -423,6 +423,12 @@ fn compare_types(
         | (SignatureToken::U64, SignatureToken::U64)
         | (SignatureToken::U128, SignatureToken::U128)
         | (SignatureToken::U256, SignatureToken::U256)
+        | (SignatureToken::I8, SignatureToken::I8)
+        | (SignatureToken::I16, SignatureToken::I16)
+        | (SignatureToken::I32, SignatureToken::I32)
+        | (SignatureToken::I64, SignatureToken::I64)
+        | (SignatureToken::I128, SignatureToken::I128)
+        | (SignatureToken::I256, SignatureToken::I256)
         | (SignatureToken::Address, SignatureToken::Address)
         | (SignatureToken::Signer, SignatureToken::Signer) => Ok(()),
         (SignatureToken::Vector(ty1), SignatureToken::Vector(ty2)) => {
@@ -444,6 +450,15 @@ fn compare_types(
         | (SignatureToken::MutableReference(ty1), SignatureToken::MutableReference(ty2)) => {
             compare_types(context, ty1, ty2, def_module)
         }
+        (SignatureToken::Function(func1), SignatureToken::Function(func2)) => {
+            let (params1, returns1, abilities1) = &**func1;
+            let (params2, returns2, abilities2) = &**func2;
+            if abilities1 != abilities2 {
+                return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH));
+            }
+            compare_cross_module_signatures(context, params1, params2, def_module)?;
+            compare_cross_module_signatures(context, returns1, returns2, def_module)
+        }
         (SignatureToken::TypeParameter(idx1), SignatureToken::TypeParameter(idx2)) => {
             if idx1 != idx2 {
                 Err(PartialVMError::new(StatusCode::TYPE_MISMATCH))
@@ -465,7 +480,14 @@ fn compare_types(
         | (SignatureToken::TypeParameter(_), _)
         | (SignatureToken::U16, _)
         | (SignatureToken::U32, _)
-        | (SignatureToken::U256, _) => Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)),
+        | (SignatureToken::U256, _)
+        | (SignatureToken::I8, _)
+        | (SignatureToken::I16, _)
+        | (SignatureToken::I32, _)
+        | (SignatureToken::I64, _)
+        | (SignatureToken::I128, _)
+        | (SignatureToken::I256, _)
+        | (SignatureToken::Function(_), _) => Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)),
     }
 }
 
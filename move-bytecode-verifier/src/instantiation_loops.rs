@@ -143,7 +143,8 @@ impl<'a> InstantiationLoopChecker<'a> {
 
         fn rec(type_params: &mut HashSet<TypeParameterIndex>, ty: &SignatureToken) {
             match ty {
-                Bool | Address | U8 | U16 | U32 | U64 | U128 | U256 | Signer | Datatype(_) => {}
+                Bool | Address | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64
+                | I128 | I256 | Signer | Datatype(_) => {}
                 TypeParameter(idx) => {
                     type_params.insert(*idx);
                 }
@@ -155,6 +156,12 @@ impl<'a> InstantiationLoopChecker<'a> {
                         rec(type_params, ty);
                     }
                 }
+                Function(func) => {
+                    let (params, returns, _) = &**func;
+                    for ty in params.iter().chain(returns) {
+                        rec(type_params, ty);
+                    }
+                }
             }
         }
 
@@ -210,7 +217,9 @@ impl<'a> InstantiationLoopChecker<'a> {
     ) {
         if let Some(code) = &caller_def.code {
             for instr in &code.code {
-                if let Bytecode::CallGeneric(callee_inst_idx) = instr {
+                if let Bytecode::CallGeneric(callee_inst_idx)
+                | Bytecode::PackClosureGeneric(callee_inst_idx, _) = instr
+                {
                     // Get the id of the definition of the function being called.
                     // Skip if the function is not defined in the current module, as we do not
                     // have mutual recursions across module boundaries.
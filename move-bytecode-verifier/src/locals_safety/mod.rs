@@ -106,11 +106,20 @@ fn execute_inner(
         | Bytecode::LdU64(_)
         | Bytecode::LdU128(_)
         | Bytecode::LdU256(_)
+        | Bytecode::LdI8(_)
+        | Bytecode::LdI16(_)
+        | Bytecode::LdI32(_)
+        | Bytecode::LdI64(_)
+        | Bytecode::LdI128(_)
+        | Bytecode::LdI256(_)
         | Bytecode::LdConst(_)
         | Bytecode::LdTrue
         | Bytecode::LdFalse
         | Bytecode::Call(_)
         | Bytecode::CallGeneric(_)
+        | Bytecode::PackClosure(..)
+        | Bytecode::PackClosureGeneric(..)
+        | Bytecode::CallClosure(_)
         | Bytecode::Pack(_)
         | Bytecode::PackGeneric(_)
         | Bytecode::Unpack(_)
@@ -123,6 +132,12 @@ fn execute_inner(
         | Bytecode::CastU64
         | Bytecode::CastU128
         | Bytecode::CastU256
+        | Bytecode::CastI8
+        | Bytecode::CastI16
+        | Bytecode::CastI32
+        | Bytecode::CastI64
+        | Bytecode::CastI128
+        | Bytecode::CastI256
         | Bytecode::Add
         | Bytecode::Sub
         | Bytecode::Mul
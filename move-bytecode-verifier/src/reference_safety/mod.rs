@@ -11,14 +11,14 @@
 
 mod abstract_state;
 
-use crate::reference_safety::abstract_state::STEP_BASE_COST;
+use crate::{reference_safety::abstract_state::STEP_BASE_COST, signature::closure_type};
 use abstract_state::{AbstractState, AbstractValue};
 use move_abstract_interpreter::absint::{AbstractInterpreter, FunctionContext, TransferFunctions};
 use move_abstract_stack::AbstractStack;
 use move_binary_format::{
     errors::{PartialVMError, PartialVMResult},
     file_format::{
-        Bytecode, CodeOffset, FunctionDefinitionIndex, FunctionHandle, IdentifierIndex,
+        Bytecode, CodeOffset, FunctionDefinitionIndex, FunctionHandle, IdentifierIndex, Signature,
         SignatureIndex, SignatureToken, StructDefinition, StructFieldInformation,
         VariantDefinition,
     },
@@ -110,6 +110,47 @@ fn call(
     Ok(())
 }
 
+fn pack_closure(
+    verifier: &mut ReferenceSafetyAnalysis,
+    function_handle: &FunctionHandle,
+    sig_idx: SignatureIndex,
+) -> PartialVMResult<()> {
+    let (params, _, _) = closure_type(verifier.module, sig_idx)?;
+    let num_params = verifier
+        .module
+        .signature_at(function_handle.parameters)
+        .len();
+    for _ in 0..safe_unwrap!(num_params.checked_sub(params.len())) {
+        safe_assert!(safe_unwrap_err!(verifier.stack.pop()).is_value())
+    }
+    verifier.push(AbstractValue::NonReference)?;
+    Ok(())
+}
+
+fn call_closure(
+    verifier: &mut ReferenceSafetyAnalysis,
+    state: &mut AbstractState,
+    offset: CodeOffset,
+    sig_idx: SignatureIndex,
+    meter: &mut (impl Meter + ?Sized),
+) -> PartialVMResult<()> {
+    let (params, returns, _) = closure_type(verifier.module, sig_idx)?;
+    safe_assert!(safe_unwrap_err!(verifier.stack.pop()).is_value());
+    let arguments = params
+        .iter()
+        .map(|_| verifier.stack.pop().unwrap())
+        .rev()
+        .collect();
+
+    // Calling a closure is like calling its function, whose captured arguments are values
+    let return_ = Signature(returns.clone());
+    let values = state.call(offset, arguments, &BTreeSet::new(), &return_, meter)?;
+    for value in values {
+        verifier.push(value)?
+    }
+    Ok(())
+}
+
 fn num_fields(struct_def: &StructDefinition) -> usize {
     match &struct_def.field_information {
         StructFieldInformation::Native => 0,
@@ -298,6 +339,17 @@ fn execute_inner(
             call(verifier, state, offset, function_handle, meter)?
         }
 
+        Bytecode::PackClosure(idx, sig_idx) => {
+            let function_handle = verifier.module.function_handle_at(*idx);
+            pack_closure(verifier, function_handle, *sig_idx)?
+        }
+        Bytecode::PackClosureGeneric(idx, sig_idx) => {
+            let func_inst = verifier.module.function_instantiation_at(*idx);
+            let function_handle = verifier.module.function_handle_at(func_inst.handle);
+            pack_closure(verifier, function_handle, *sig_idx)?
+        }
+        Bytecode::CallClosure(sig_idx) => call_closure(verifier, state, offset, *sig_idx, meter)?,
+
         Bytecode::Ret => {
             let mut return_values = vec![];
             for _ in 0..verifier.function_context.return_().len() {
@@ -316,6 +368,12 @@ fn execute_inner(
         | Bytecode::CastU64
         | Bytecode::CastU128
         | Bytecode::CastU256
+        | Bytecode::CastI8
+        | Bytecode::CastI16
+        | Bytecode::CastI32
+        | Bytecode::CastI64
+        | Bytecode::CastI128
+        | Bytecode::CastI256
         | Bytecode::Not
         | Bytecode::ExistsDeprecated(_)
         | Bytecode::ExistsGenericDeprecated(_) => (),
@@ -339,6 +397,12 @@ fn execute_inner(
         Bytecode::LdU64(_) => verifier.push(state.value_for(&SignatureToken::U64))?,
         Bytecode::LdU128(_) => verifier.push(state.value_for(&SignatureToken::U128))?,
         Bytecode::LdU256(_) => verifier.push(state.value_for(&SignatureToken::U256))?,
+        Bytecode::LdI8(_) => verifier.push(state.value_for(&SignatureToken::I8))?,
+        Bytecode::LdI16(_) => verifier.push(state.value_for(&SignatureToken::I16))?,
+        Bytecode::LdI32(_) => verifier.push(state.value_for(&SignatureToken::I32))?,
+        Bytecode::LdI64(_) => verifier.push(state.value_for(&SignatureToken::I64))?,
+        Bytecode::LdI128(_) => verifier.push(state.value_for(&SignatureToken::I128))?,
+        Bytecode::LdI256(_) => verifier.push(state.value_for(&SignatureToken::I256))?,
         Bytecode::LdConst(idx) => {
             let signature = &verifier.module.constant_at(*idx).type_;
             verifier.push(state.value_for(signature))?
@@ -0,0 +1,408 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_binary_format::{
+    file_format::{
+        empty_module, Ability, AbilitySet,
+        Bytecode::{self, *},
+        CodeUnit, DatatypeHandle, DatatypeHandleIndex, FieldDefinition, FunctionDefinition,
+        FunctionHandle, FunctionHandleIndex, FunctionInstantiation, FunctionInstantiationIndex,
+        IdentifierIndex, ModuleHandleIndex, Signature, SignatureIndex,
+        SignatureToken::{self, *},
+        StructDefinition, StructDefinitionIndex, StructFieldInformation, TypeSignature, Visibility,
+    },
+    CompiledModule,
+};
+use move_core_types::{identifier::Identifier, vm_status::StatusCode};
+
+use crate::type_safety::is_assignable;
+
+const ADD: FunctionHandleIndex = FunctionHandleIndex(0);
+const TAKE: FunctionHandleIndex = FunctionHandleIndex(1);
+const READ: FunctionHandleIndex = FunctionHandleIndex(2);
+const FIRST_U64: FunctionInstantiationIndex = FunctionInstantiationIndex(0);
+
+fn function(
+    params: Vec<SignatureToken>,
+    returns: Vec<SignatureToken>,
+    abilities: AbilitySet,
+) -> SignatureToken {
+    Function(Box::new((params, returns, abilities)))
+}
+
+/// The index of the signature `tys`, which is added to the module if it is not there yet
+fn signature(module: &mut CompiledModule, tys: Vec<SignatureToken>) -> SignatureIndex {
+    let sig = Signature(tys);
+    match module.signatures.iter().position(|s| s == &sig) {
+        Some(idx) => SignatureIndex::new(idx as u16),
+        None => {
+            module.signatures.push(sig);
+            SignatureIndex::new(module.signatures.len() as u16 - 1)
+        }
+    }
+}
+
+fn function_type(
+    module: &mut CompiledModule,
+    params: Vec<SignatureToken>,
+    returns: Vec<SignatureToken>,
+    abilities: AbilitySet,
+) -> SignatureIndex {
+    signature(module, vec![function(params, returns, abilities)])
+}
+
+/// Verifies the module
+///
+///   struct R { flag: bool }
+///   fun add(x: u64, y: u64): u64
+///   fun take(r: R, x: u64): u64
+///   fun read(r: &u64, x: u64): u64
+///   fun first<T: drop>(t: T, x: u64): u64
+///   fun test(<params>): <returns> { <code> }
+///
+/// where `code` adds the signatures it uses to the module.
+fn verify(
+    params: Vec<SignatureToken>,
+    returns: Vec<SignatureToken>,
+    code: impl FnOnce(&mut CompiledModule) -> Vec<Bytecode>,
+) -> Result<(), StatusCode> {
+    let mut module = empty_module();
+    module.identifiers.extend(
+        ["R", "flag", "add", "take", "read", "first", "test"]
+            .map(|name| Identifier::new(name).unwrap()),
+    );
+    module.datatype_handles.push(DatatypeHandle {
+        module: ModuleHandleIndex(0),
+        name: IdentifierIndex(1),
+        abilities: AbilitySet::EMPTY,
+        type_parameters: vec![],
+    });
+    module.struct_defs.push(StructDefinition {
+        struct_handle: DatatypeHandleIndex(0),
+        field_information: StructFieldInformation::Declared(vec![FieldDefinition {
+            name: IdentifierIndex(2),
+            signature: TypeSignature(Bool),
+        }]),
+    });
+
+    let r = Datatype(DatatypeHandleIndex(0));
+    let mut functions = vec![
+        (
+            vec![U64, U64],
+            vec![U64],
+            vec![],
+            vec![MoveLoc(0), MoveLoc(1), Add, Ret],
+        ),
+        (
+            vec![r, U64],
+            vec![U64],
+            vec![],
+            vec![
+                MoveLoc(0),
+                Unpack(StructDefinitionIndex(0)),
+                Pop,
+                MoveLoc(1),
+                Ret,
+            ],
+        ),
+        (
+            vec![Reference(Box::new(U64)), U64],
+            vec![U64],
+            vec![],
+            vec![MoveLoc(0), ReadRef, MoveLoc(1), Add, Ret],
+        ),
+        (
+            vec![TypeParameter(0), U64],
+            vec![U64],
+            vec![AbilitySet::singleton(Ability::Drop)],
+            vec![MoveLoc(1), Ret],
+        ),
+    ];
+    let test_code = code(&mut module);
+    functions.push((params, returns, vec![], test_code));
+
+    for (idx, (params, returns, type_parameters, code)) in functions.into_iter().enumerate() {
+        let parameters = signature(&mut module, params);
+        let return_ = signature(&mut module, returns);
+        let locals = signature(&mut module, vec![]);
+        module.function_handles.push(FunctionHandle {
+            module: ModuleHandleIndex(0),
+            name: IdentifierIndex(idx as u16 + 3),
+            parameters,
+            return_,
+            type_parameters,
+        });
+        module.function_defs.push(FunctionDefinition {
+            function: FunctionHandleIndex(idx as u16),
+            visibility: Visibility::Public,
+            is_entry: false,
+            acquires_global_resources: vec![],
+            code: Some(CodeUnit {
+                locals,
+                code,
+                jump_tables: vec![],
+            }),
+        });
+    }
+    let type_parameters = signature(&mut module, vec![U64]);
+    module.function_instantiations.push(FunctionInstantiation {
+        handle: FunctionHandleIndex(3),
+        type_parameters,
+    });
+
+    crate::verify_module_unmetered(&module).map_err(|e| e.major_status())
+}
+
+#[test]
+fn function_type_assignability() {
+    let copy_drop = function(vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+    let drop = function(vec![U64], vec![U64], AbilitySet::singleton(Ability::Drop));
+
+    // A function value with more abilities can be used where fewer are expected
+    assert!(is_assignable(&copy_drop, &copy_drop));
+    assert!(is_assignable(&copy_drop, &drop));
+    assert!(!is_assignable(&drop, &copy_drop));
+
+    // Parameter and return types must otherwise match
+    let two_params = function(vec![U64, U64], vec![U64], AbilitySet::FUNCTIONS);
+    let bool_param = function(vec![Bool], vec![U64], AbilitySet::FUNCTIONS);
+    let no_return = function(vec![U64], vec![], AbilitySet::FUNCTIONS);
+    for other in [&two_params, &bool_param, &no_return] {
+        assert!(!is_assignable(other, &copy_drop));
+        assert!(!is_assignable(&copy_drop, other));
+    }
+    assert!(!is_assignable(&U64, &copy_drop));
+    assert!(!is_assignable(&copy_drop, &U64));
+
+    // Parameters are contravariant and returns are covariant
+    let takes = |f: &SignatureToken| function(vec![f.clone()], vec![], AbilitySet::EMPTY);
+    let gives = |f: &SignatureToken| function(vec![], vec![f.clone()], AbilitySet::EMPTY);
+    assert!(is_assignable(&takes(&drop), &takes(&copy_drop)));
+    assert!(!is_assignable(&takes(&copy_drop), &takes(&drop)));
+    assert!(is_assignable(&gives(&copy_drop), &gives(&drop)));
+    assert!(!is_assignable(&gives(&drop), &gives(&copy_drop)));
+
+    // Assignability extends through vectors and immutable references, but not mutable ones
+    let vector = |f: &SignatureToken| Vector(Box::new(f.clone()));
+    let reference = |f: &SignatureToken| Reference(Box::new(f.clone()));
+    let mut_reference = |f: &SignatureToken| MutableReference(Box::new(f.clone()));
+    assert!(is_assignable(&vector(&copy_drop), &vector(&drop)));
+    assert!(!is_assignable(&vector(&drop), &vector(&copy_drop)));
+    assert!(is_assignable(&reference(&copy_drop), &reference(&drop)));
+    assert!(!is_assignable(
+        &mut_reference(&copy_drop),
+        &mut_reference(&drop)
+    ));
+    assert!(is_assignable(&mut_reference(&drop), &mut_reference(&drop)));
+}
+
+#[test]
+fn pack_and_call_closure() {
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+        vec![
+            LdU64(1),
+            MoveLoc(0),
+            PackClosure(ADD, f),
+            CallClosure(f),
+            Ret,
+        ]
+    });
+    assert_eq!(result, Ok(()));
+
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+        vec![
+            LdU64(1),
+            MoveLoc(0),
+            PackClosureGeneric(FIRST_U64, f),
+            CallClosure(f),
+            Ret,
+        ]
+    });
+    assert_eq!(result, Ok(()));
+
+    // Nothing captured
+    let result = verify(vec![], vec![U64], |m| {
+        let f = function_type(m, vec![U64, U64], vec![U64], AbilitySet::FUNCTIONS);
+        vec![LdU64(1), LdU64(2), PackClosure(ADD, f), CallClosure(f), Ret]
+    });
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn call_closure_abilities() {
+    // A closure can be called through a function type with fewer abilities
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+        let g = function_type(m, vec![U64], vec![U64], AbilitySet::EMPTY);
+        vec![
+            LdU64(1),
+            MoveLoc(0),
+            PackClosure(ADD, f),
+            CallClosure(g),
+            Ret,
+        ]
+    });
+    assert_eq!(result, Ok(()));
+
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::EMPTY);
+        let g = function_type(m, vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+        vec![
+            LdU64(1),
+            MoveLoc(0),
+            PackClosure(ADD, f),
+            CallClosure(g),
+            Ret,
+        ]
+    });
+    assert_eq!(result, Err(StatusCode::CALL_CLOSURE_TYPE_MISMATCH_ERROR));
+}
+
+#[test]
+fn call_closure_type_mismatch() {
+    // Wrong argument
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+        vec![LdTrue, MoveLoc(0), PackClosure(ADD, f), CallClosure(f), Ret]
+    });
+    assert_eq!(result, Err(StatusCode::CALL_CLOSURE_TYPE_MISMATCH_ERROR));
+
+    // Not a closure
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+        vec![LdU64(1), MoveLoc(0), CallClosure(f), Ret]
+    });
+    assert_eq!(result, Err(StatusCode::CALL_CLOSURE_TYPE_MISMATCH_ERROR));
+
+    // A closure of a different type
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::FUNCTIONS);
+        let g = function_type(m, vec![Bool], vec![U64], AbilitySet::FUNCTIONS);
+        vec![LdTrue, MoveLoc(0), PackClosure(ADD, f), CallClosure(g), Ret]
+    });
+    assert_eq!(result, Err(StatusCode::CALL_CLOSURE_TYPE_MISMATCH_ERROR));
+}
+
+#[test]
+fn pack_closure_type_mismatch() {
+    let pack = |closure_params, closure_returns, captured| {
+        verify(vec![], vec![], |m| {
+            let f = function_type(m, closure_params, closure_returns, AbilitySet::FUNCTIONS);
+            vec![captured, PackClosure(ADD, f), Pop, Ret]
+        })
+    };
+    assert_eq!(pack(vec![U64], vec![U64], LdU64(1)), Ok(()));
+    // Wrong parameter type
+    assert_eq!(
+        pack(vec![Bool], vec![U64], LdU64(1)),
+        Err(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR)
+    );
+    // Wrong return type
+    assert_eq!(
+        pack(vec![U64], vec![Bool], LdU64(1)),
+        Err(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR)
+    );
+    // Wrong number of return values
+    assert_eq!(
+        pack(vec![U64], vec![], LdU64(1)),
+        Err(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR)
+    );
+    // Wrong captured value
+    assert_eq!(
+        pack(vec![U64], vec![U64], LdTrue),
+        Err(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR)
+    );
+}
+
+#[test]
+fn pack_closure_captures() {
+    // References cannot be captured
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = function_type(m, vec![U64], vec![U64], AbilitySet::EMPTY);
+        vec![
+            LdU64(1),
+            ImmBorrowLoc(0),
+            PackClosure(READ, f),
+            CallClosure(f),
+            Ret,
+        ]
+    });
+    assert_eq!(result, Err(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR));
+
+    // Captured values must have the abilities of the closure
+    let take = |abilities| {
+        verify(vec![Datatype(DatatypeHandleIndex(0))], vec![U64], |m| {
+            let f = function_type(m, vec![U64], vec![U64], abilities);
+            vec![
+                LdU64(1),
+                MoveLoc(0),
+                PackClosure(TAKE, f),
+                CallClosure(f),
+                Ret,
+            ]
+        })
+    };
+    assert_eq!(take(AbilitySet::EMPTY), Ok(()));
+    assert_eq!(
+        take(AbilitySet::singleton(Ability::Drop)),
+        Err(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR)
+    );
+    assert_eq!(
+        take(AbilitySet::singleton(Ability::Copy)),
+        Err(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR)
+    );
+}
+
+#[test]
+fn closure_signatures() {
+    // Function types can only have 'copy' and 'drop'
+    let result = verify(vec![U64], vec![U64], |m| {
+        let abilities = AbilitySet::FUNCTIONS | Ability::Store;
+        let f = function_type(m, vec![U64], vec![U64], abilities);
+        vec![
+            LdU64(1),
+            MoveLoc(0),
+            PackClosure(ADD, f),
+            CallClosure(f),
+            Ret,
+        ]
+    });
+    assert_eq!(result, Err(StatusCode::INVALID_SIGNATURE_TOKEN));
+
+    // A closure cannot take more parameters than its function
+    let result = verify(vec![], vec![], |m| {
+        let f = function_type(m, vec![U64, U64, U64], vec![U64], AbilitySet::FUNCTIONS);
+        vec![PackClosure(ADD, f), Pop, Ret]
+    });
+    assert_eq!(result, Err(StatusCode::INVALID_CLOSURE_SIGNATURE));
+
+    // Closure operations need a single function type
+    let result = verify(vec![U64], vec![U64], |m| {
+        let f = signature(m, vec![U64]);
+        vec![
+            LdU64(1),
+            MoveLoc(0),
+            PackClosure(ADD, f),
+            CallClosure(f),
+            Ret,
+        ]
+    });
+    assert_eq!(result, Err(StatusCode::INVALID_CLOSURE_SIGNATURE));
+}
+
+#[test]
+fn return_closure_abilities() {
+    let returns = |param_abilities, return_abilities| {
+        let param = function(vec![U64], vec![U64], param_abilities);
+        let return_ = function(vec![U64], vec![U64], return_abilities);
+        verify(vec![param], vec![return_], |_| vec![MoveLoc(0), Ret])
+    };
+    assert_eq!(returns(AbilitySet::FUNCTIONS, AbilitySet::EMPTY), Ok(()));
+    assert_eq!(
+        returns(AbilitySet::EMPTY, AbilitySet::FUNCTIONS),
+        Err(StatusCode::RET_TYPE_MISMATCH_ERROR)
+    );
+}
@@ -2,5 +2,7 @@
 // Modifications Copyright (c) 2024 Digc Labs
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(test)]
+mod closures;
 #[cfg(test)]
 mod reference_analysis;
@@ -22,6 +22,25 @@ use std::collections::{HashMap, HashSet};
 
 use crate::ability_cache::AbilityCache;
 
+/// The parameter types, return types and abilities of the function type that the signature of a
+/// closure operation holds. Its shape is checked by the `SignatureChecker`.
+pub(crate) fn closure_type(
+    module: &CompiledModule,
+    idx: SignatureIndex,
+) -> PartialVMResult<&(Vec<SignatureToken>, Vec<SignatureToken>, AbilitySet)> {
+    match module.signature_at(idx).0.as_slice() {
+        [SignatureToken::Function(func)] => Ok(func),
+        tys => Err(
+            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR).with_message(
+                format!(
+                    "expected a single function type for closure operations, got {:?}",
+                    tys
+                ),
+            ),
+        ),
+    }
+}
+
 pub struct SignatureChecker<'env, 'a, 'b, M: Meter + ?Sized> {
     module: &'env CompiledModule,
     module_ability_cache: &'a mut AbilityCache<'env>,
@@ -214,6 +233,25 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
                         type_parameters,
                     )
                 }
+                PackClosure(fh_idx, idx) => {
+                    let func_handle = self.module.function_handle_at(*fh_idx);
+                    let num_params = self.module.signature_at(func_handle.parameters).len();
+                    self.check_closure_signature(*idx, Some(num_params), type_parameters)
+                }
+                PackClosureGeneric(fi_idx, idx) => {
+                    let func_inst = self.module.function_instantiation_at(*fi_idx);
+                    let func_handle = self.module.function_handle_at(func_inst.handle);
+                    let type_arguments = &self.module.signature_at(func_inst.type_parameters).0;
+                    self.check_signature_tokens(type_arguments)?;
+                    self.check_generic_instance(
+                        type_arguments,
+                        func_handle.type_parameters.iter().copied(),
+                        type_parameters,
+                    )?;
+                    let num_params = self.module.signature_at(func_handle.parameters).len();
+                    self.check_closure_signature(*idx, Some(num_params), type_parameters)
+                }
+                CallClosure(idx) => self.check_closure_signature(*idx, None, type_parameters),
                 VecPack(idx, _)
                 | VecLen(idx)
                 | VecImmBorrow(idx)
@@ -265,6 +303,12 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
                 | LdU64(_)
                 | LdU128(_)
                 | LdU256(_)
+                | LdI8(_)
+                | LdI16(_)
+                | LdI32(_)
+                | LdI64(_)
+                | LdI128(_)
+                | LdI256(_)
                 | LdConst(_)
                 | CastU8
                 | CastU16
@@ -272,6 +316,12 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
                 | CastU64
                 | CastU128
                 | CastU256
+                | CastI8
+                | CastI16
+                | CastI32
+                | CastI64
+                | CastI128
+                | CastI256
                 | LdTrue
                 | LdFalse
                 | Call(_)
@@ -357,6 +407,18 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
                 }
             }
 
+            SignatureToken::Function(func) => {
+                let (params, returns, _) = &**func;
+                for ty in params.iter().chain(returns) {
+                    let ty = match ty {
+                        SignatureToken::Reference(inner)
+                        | SignatureToken::MutableReference(inner) => inner,
+                        _ => ty,
+                    };
+                    self.check_phantom_params(ty, false, type_parameters)?;
+                }
+            }
+
             SignatureToken::Datatype(_)
             | SignatureToken::Reference(_)
             | SignatureToken::MutableReference(_)
@@ -367,6 +429,12 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
             | SignatureToken::U64
             | SignatureToken::U128
             | SignatureToken::U256
+            | SignatureToken::I8
+            | SignatureToken::I16
+            | SignatureToken::I32
+            | SignatureToken::I64
+            | SignatureToken::I128
+            | SignatureToken::I256
             | SignatureToken::Address
             | SignatureToken::Signer => {}
         }
@@ -401,8 +469,8 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
     fn check_signature_token(&self, ty: &SignatureToken) -> PartialVMResult<()> {
         use SignatureToken::*;
         match ty {
-            U8 | U16 | U32 | U64 | U128 | U256 | Bool | Address | Signer | Datatype(_)
-            | TypeParameter(_) => Ok(()),
+            U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256 | Bool
+            | Address | Signer | Datatype(_) | TypeParameter(_) => Ok(()),
             Reference(_) | MutableReference(_) => {
                 // TODO: Prop tests expect us to NOT check the inner types.
                 // Revisit this once we rework prop tests.
@@ -414,7 +482,61 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
                 let (_, type_arguments) = &**inst;
                 self.check_signature_tokens(type_arguments)
             }
+            Function(func) => {
+                let (params, returns, abilities) = &**func;
+                if !abilities.is_subset(AbilitySet::FUNCTIONS) {
+                    return Err(PartialVMError::new(StatusCode::INVALID_SIGNATURE_TOKEN)
+                        .with_message(format!(
+                            "function types can only have the abilities {:?}, got {:?}",
+                            AbilitySet::FUNCTIONS,
+                            abilities
+                        )));
+                }
+                // Like in function signatures, references are permitted at the top level of the
+                // parameter and return types
+                for ty in params.iter().chain(returns) {
+                    match ty {
+                        Reference(inner) | MutableReference(inner) => {
+                            self.check_signature_token(inner)?
+                        }
+                        _ => self.check_signature_token(ty)?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks that the signature of a closure operation holds a single, well defined, function
+    /// type. When packing a closure, `function_params` is the number of parameters of the function
+    /// it is packed from, of which the closure can only leave out the captured ones.
+    fn check_closure_signature(
+        &mut self,
+        idx: SignatureIndex,
+        function_params: Option<usize>,
+        type_parameters: &[AbilitySet],
+    ) -> PartialVMResult<()> {
+        let tys = &self.module.signature_at(idx).0;
+        let [ty @ SignatureToken::Function(func)] = tys.as_slice() else {
+            return Err(
+                PartialVMError::new(StatusCode::INVALID_CLOSURE_SIGNATURE).with_message(format!(
+                    "expected a single function type for closure operations, got {:?}",
+                    tys
+                )),
+            );
+        };
+        if let Some(function_params) = function_params {
+            if func.0.len() > function_params {
+                return Err(PartialVMError::new(StatusCode::INVALID_CLOSURE_SIGNATURE)
+                    .with_message(format!(
+                        "closure has {} parameters, but its function only has {}",
+                        func.0.len(),
+                        function_params
+                    )));
+            }
         }
+        self.check_signature_token(ty)?;
+        self.check_instantiation(idx, type_parameters)
     }
 
     fn check_instantiation(
@@ -482,8 +604,15 @@ impl<'env, 'a, 'b, M: Meter + ?Sized> SignatureChecker<'env, 'a, 'b, M> {
             | SignatureToken::U64
             | SignatureToken::U128
             | SignatureToken::U256
+            | SignatureToken::I8
+            | SignatureToken::I16
+            | SignatureToken::I32
+            | SignatureToken::I64
+            | SignatureToken::I128
+            | SignatureToken::I256
             | SignatureToken::Address
-            | SignatureToken::Signer => Ok(()),
+            | SignatureToken::Signer
+            | SignatureToken::Function(_) => Ok(()),
         }
     }
 
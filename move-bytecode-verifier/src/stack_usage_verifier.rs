@@ -16,13 +16,18 @@ use move_abstract_interpreter::{
 };
 use move_binary_format::{
     errors::{PartialVMError, PartialVMResult},
-    file_format::{Bytecode, CodeUnit, FunctionDefinitionIndex, Signature, StructFieldInformation},
+    file_format::{
+        Bytecode, CodeUnit, FunctionDefinitionIndex, FunctionHandle, Signature, SignatureIndex,
+        StructFieldInformation,
+    },
     CompiledModule,
 };
 use move_bytecode_verifier_meter::Meter;
 use move_core_types::vm_status::StatusCode;
 use move_vm_config::verifier::VerifierConfig;
 
+use crate::signature::closure_type;
+
 pub(crate) struct StackUsageVerifier<'a> {
     module: &'a CompiledModule,
     current_function: Option<FunctionDefinitionIndex>,
@@ -135,6 +140,12 @@ impl<'a> StackUsageVerifier<'a> {
             | Bytecode::LdU64(_)
             | Bytecode::LdU128(_)
             | Bytecode::LdU256(_)
+            | Bytecode::LdI8(_)
+            | Bytecode::LdI16(_)
+            | Bytecode::LdI32(_)
+            | Bytecode::LdI64(_)
+            | Bytecode::LdI128(_)
+            | Bytecode::LdI256(_)
             | Bytecode::LdTrue
             | Bytecode::LdFalse
             | Bytecode::LdConst(_)
@@ -165,6 +176,12 @@ impl<'a> StackUsageVerifier<'a> {
             | Bytecode::CastU64
             | Bytecode::CastU128
             | Bytecode::CastU256
+            | Bytecode::CastI8
+            | Bytecode::CastI16
+            | Bytecode::CastI32
+            | Bytecode::CastI64
+            | Bytecode::CastI128
+            | Bytecode::CastI256
             | Bytecode::VecLen(_)
             | Bytecode::VecPopBack(_) => (1, 1),
 
@@ -228,6 +245,23 @@ impl<'a> StackUsageVerifier<'a> {
                 (arg_count, return_count)
             }
 
+            // PackClosure pops the captured values and pushes the closure
+            Bytecode::PackClosure(idx, sig_idx) => {
+                let function_handle = self.module.function_handle_at(*idx);
+                (self.captured_count(function_handle, *sig_idx)?, 1)
+            }
+            Bytecode::PackClosureGeneric(idx, sig_idx) => {
+                let func_inst = self.module.function_instantiation_at(*idx);
+                let function_handle = self.module.function_handle_at(func_inst.handle);
+                (self.captured_count(function_handle, *sig_idx)?, 1)
+            }
+
+            // CallClosure pops the closure and its arguments and pushes its return values
+            Bytecode::CallClosure(sig_idx) => {
+                let (params, returns, _) = closure_type(self.module, *sig_idx)?;
+                (params.len() as u64 + 1, returns.len() as u64)
+            }
+
             // Pack performs `num_fields` pops and one push
             Bytecode::Pack(idx) => {
                 let struct_definition = self.module.struct_def_at(*idx);
@@ -312,6 +346,23 @@ impl<'a> StackUsageVerifier<'a> {
         })
     }
 
+    /// The number of values captured when packing a closure of type `sig_idx` from `handle`
+    fn captured_count(
+        &self,
+        handle: &FunctionHandle,
+        sig_idx: SignatureIndex,
+    ) -> PartialVMResult<u64> {
+        let (params, _, _) = closure_type(self.module, sig_idx)?;
+        let num_params = self.module.signature_at(handle.parameters).len();
+        match num_params.checked_sub(params.len()) {
+            Some(captured) => Ok(captured as u64),
+            None => Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("closure has more parameters than its function".to_string()),
+            ),
+        }
+    }
+
     fn current_function(&self) -> FunctionDefinitionIndex {
         self.current_function.unwrap_or(FunctionDefinitionIndex(0))
     }
@@ -15,15 +15,15 @@ use move_binary_format::{
     file_format::{
         AbilitySet, Bytecode, CodeOffset, DatatypeHandleIndex, EnumDefinition, FieldHandleIndex,
         FunctionDefinitionIndex, FunctionHandle, JumpTableInner, LocalIndex, Signature,
-        SignatureToken, SignatureToken as ST, StructDefinition, StructDefinitionIndex,
-        StructFieldInformation, VariantDefinition, VariantJumpTable,
+        SignatureIndex, SignatureToken, SignatureToken as ST, StructDefinition,
+        StructDefinitionIndex, StructFieldInformation, VariantDefinition, VariantJumpTable,
     },
-    safe_unwrap_err, CompiledModule,
+    safe_unwrap, safe_unwrap_err, CompiledModule,
 };
 use move_bytecode_verifier_meter::{Meter, Scope};
 use move_core_types::vm_status::StatusCode;
 
-use crate::ability_cache::AbilityCache;
+use crate::{ability_cache::AbilityCache, signature::closure_type};
 
 struct Locals<'a> {
     param_count: usize,
@@ -325,8 +325,9 @@ fn call(
     let parameters = verifier.module.signature_at(function_handle.parameters);
     for parameter in parameters.0.iter().rev() {
         let arg = safe_unwrap_err!(verifier.stack.pop());
-        if (type_actuals.is_empty() && &arg != parameter)
-            || (!type_actuals.is_empty() && arg != instantiate(meter, parameter, type_actuals)?)
+        if (type_actuals.is_empty() && !is_assignable(&arg, parameter))
+            || (!type_actuals.is_empty()
+                && !is_assignable(&arg, &instantiate(meter, parameter, type_actuals)?))
         {
             return Err(verifier.error(StatusCode::CALL_TYPE_MISMATCH_ERROR, offset));
         }
@@ -338,6 +339,76 @@ fn call(
     Ok(())
 }
 
+fn pack_closure(
+    verifier: &mut TypeSafetyChecker,
+    meter: &mut (impl Meter + ?Sized),
+    offset: CodeOffset,
+    function_handle: &FunctionHandle,
+    type_actuals: &Signature,
+    sig_idx: SignatureIndex,
+) -> PartialVMResult<()> {
+    let func = closure_type(verifier.module, sig_idx)?;
+    let (closure_params, closure_returns, abilities) = func;
+    let parameters = verifier.module.signature_at(function_handle.parameters);
+    let returns = verifier.module.signature_at(function_handle.return_);
+    let captured_count = safe_unwrap!(parameters.len().checked_sub(closure_params.len()));
+
+    // The closure takes the parameters that are not captured, and returns what the function does
+    for (parameter, closure_param) in parameters.0[captured_count..].iter().zip(closure_params) {
+        if &instantiate(meter, parameter, type_actuals)? != closure_param {
+            return Err(verifier.error(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR, offset));
+        }
+    }
+    if returns.len() != closure_returns.len() {
+        return Err(verifier.error(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR, offset));
+    }
+    for (return_type, closure_return) in returns.0.iter().zip(closure_returns) {
+        if &instantiate(meter, return_type, type_actuals)? != closure_return {
+            return Err(verifier.error(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR, offset));
+        }
+    }
+
+    // The captured values cannot be references, and must have the abilities of the closure
+    for parameter in parameters.0[..captured_count].iter().rev() {
+        let arg = safe_unwrap_err!(verifier.stack.pop());
+        if arg.is_reference()
+            || !is_assignable(&arg, &instantiate(meter, parameter, type_actuals)?)
+            || !abilities.is_subset(verifier.abilities(meter, &arg)?)
+        {
+            return Err(verifier.error(StatusCode::PACK_CLOSURE_TYPE_MISMATCH_ERROR, offset));
+        }
+    }
+    let closure = ST::Function(Box::new(func.clone()));
+    charge_ty(meter, &closure)?;
+    verifier.push(meter, closure)?;
+    Ok(())
+}
+
+fn call_closure(
+    verifier: &mut TypeSafetyChecker,
+    meter: &mut (impl Meter + ?Sized),
+    offset: CodeOffset,
+    sig_idx: SignatureIndex,
+) -> PartialVMResult<()> {
+    let func = closure_type(verifier.module, sig_idx)?;
+    let closure = safe_unwrap_err!(verifier.stack.pop());
+    if !is_assignable(&closure, &ST::Function(Box::new(func.clone()))) {
+        return Err(verifier.error(StatusCode::CALL_CLOSURE_TYPE_MISMATCH_ERROR, offset));
+    }
+    let (params, returns, _) = func;
+    for param in params.iter().rev() {
+        let arg = safe_unwrap_err!(verifier.stack.pop());
+        if !is_assignable(&arg, param) {
+            return Err(verifier.error(StatusCode::CALL_CLOSURE_TYPE_MISMATCH_ERROR, offset));
+        }
+    }
+    for return_type in returns {
+        let return_type = charge_clone!(meter, return_type);
+        verifier.push(meter, return_type)?
+    }
+    Ok(())
+}
+
 fn type_fields_signature(
     verifier: &mut TypeSafetyChecker,
     meter: &mut (impl Meter + ?Sized),
@@ -370,7 +441,7 @@ fn pack_struct(
     let field_sig = type_fields_signature(verifier, meter, offset, struct_def, type_args)?;
     for sig in field_sig.0.iter().rev() {
         let arg = safe_unwrap_err!(verifier.stack.pop());
-        if &arg != sig {
+        if !is_assignable(&arg, sig) {
             return Err(verifier.error(StatusCode::PACK_TYPE_MISMATCH_ERROR, offset));
         }
     }
@@ -413,7 +484,7 @@ fn pack_enum_variant(
     for field_def in variant_def.fields.iter().rev() {
         let sig = instantiate(meter, &field_def.signature.0, type_args)?;
         let arg = safe_unwrap_err!(verifier.stack.pop());
-        if arg != sig {
+        if !is_assignable(&arg, &sig) {
             return Err(verifier.error(StatusCode::PACK_TYPE_MISMATCH_ERROR, offset));
         }
     }
@@ -615,7 +686,7 @@ fn verify_instr(
 
         Bytecode::StLoc(idx) => {
             let operand = safe_unwrap_err!(verifier.stack.pop());
-            if &operand != verifier.local_at(*idx) {
+            if !is_assignable(&operand, verifier.local_at(*idx)) {
                 return Err(verifier.error(StatusCode::STLOC_TYPE_MISMATCH_ERROR, offset));
             }
         }
@@ -631,7 +702,7 @@ fn verify_instr(
             let return_ = &verifier.function_context.return_().0;
             for return_type in return_.iter().rev() {
                 let operand = safe_unwrap_err!(verifier.stack.pop());
-                if &operand != return_type {
+                if !is_assignable(&operand, return_type) {
                     return Err(verifier.error(StatusCode::RET_TYPE_MISMATCH_ERROR, offset));
                 }
             }
@@ -701,6 +772,30 @@ fn verify_instr(
             verifier.push(meter, ST::U256)?;
         }
 
+        Bytecode::LdI8(_) => {
+            verifier.push(meter, ST::I8)?;
+        }
+
+        Bytecode::LdI16(_) => {
+            verifier.push(meter, ST::I16)?;
+        }
+
+        Bytecode::LdI32(_) => {
+            verifier.push(meter, ST::I32)?;
+        }
+
+        Bytecode::LdI64(_) => {
+            verifier.push(meter, ST::I64)?;
+        }
+
+        Bytecode::LdI128(_) => {
+            verifier.push(meter, ST::I128)?;
+        }
+
+        Bytecode::LdI256(_) => {
+            verifier.push(meter, ST::I256)?;
+        }
+
         Bytecode::LdConst(idx) => {
             let signature = charge_clone!(meter, &verifier.module.constant_at(*idx).type_);
             verifier.push(meter, signature)?;
@@ -739,6 +834,28 @@ fn verify_instr(
             call(verifier, meter, offset, func_handle, type_args)?
         }
 
+        Bytecode::PackClosure(idx, sig_idx) => {
+            let function_handle = verifier.module.function_handle_at(*idx);
+            let type_args = &Signature(vec![]);
+            pack_closure(
+                verifier,
+                meter,
+                offset,
+                function_handle,
+                type_args,
+                *sig_idx,
+            )?
+        }
+
+        Bytecode::PackClosureGeneric(idx, sig_idx) => {
+            let func_inst = verifier.module.function_instantiation_at(*idx);
+            let func_handle = verifier.module.function_handle_at(func_inst.handle);
+            let type_args = &verifier.module.signature_at(func_inst.type_parameters);
+            pack_closure(verifier, meter, offset, func_handle, type_args, *sig_idx)?
+        }
+
+        Bytecode::CallClosure(sig_idx) => call_closure(verifier, meter, offset, *sig_idx)?,
+
         Bytecode::Pack(idx) => {
             let struct_definition = verifier.module.struct_def_at(*idx);
             pack_struct(
@@ -805,7 +922,7 @@ fn verify_instr(
                 return Err(verifier.error(StatusCode::WRITEREF_WITHOUT_DROP_ABILITY, offset));
             }
 
-            if val_operand != ref_inner_signature {
+            if !is_assignable(&val_operand, &ref_inner_signature) {
                 return Err(verifier.error(StatusCode::WRITEREF_TYPE_MISMATCH_ERROR, offset));
             }
         }
@@ -832,14 +949,7 @@ fn verify_instr(
             verifier.push(meter, ST::U128)?;
         }
 
-        Bytecode::Add
-        | Bytecode::Sub
-        | Bytecode::Mul
-        | Bytecode::Mod
-        | Bytecode::Div
-        | Bytecode::BitOr
-        | Bytecode::BitAnd
-        | Bytecode::Xor => {
+        Bytecode::Add | Bytecode::Sub | Bytecode::Mul | Bytecode::Mod | Bytecode::Div => {
             let operand1 = safe_unwrap_err!(verifier.stack.pop());
             let operand2 = safe_unwrap_err!(verifier.stack.pop());
             if operand1.is_integer() && operand1 == operand2 {
@@ -849,10 +959,21 @@ fn verify_instr(
             }
         }
 
+        // Bitwise operations are only defined for unsigned integers
+        Bytecode::BitOr | Bytecode::BitAnd | Bytecode::Xor => {
+            let operand1 = safe_unwrap_err!(verifier.stack.pop());
+            let operand2 = safe_unwrap_err!(verifier.stack.pop());
+            if operand1.is_integer() && !operand1.is_signed_integer() && operand1 == operand2 {
+                verifier.push(meter, operand1)?;
+            } else {
+                return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
+            }
+        }
+
         Bytecode::Shl | Bytecode::Shr => {
             let operand1 = safe_unwrap_err!(verifier.stack.pop());
             let operand2 = safe_unwrap_err!(verifier.stack.pop());
-            if operand2.is_integer() && operand1 == ST::U8 {
+            if operand2.is_integer() && !operand2.is_signed_integer() && operand1 == ST::U8 {
                 verifier.push(meter, operand2)?;
             } else {
                 return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
@@ -960,7 +1081,7 @@ fn verify_instr(
                 let is_mismatched = verifier
                     .stack
                     .pop_eq_n(num_to_pop)
-                    .map(|t| element_type != &t)
+                    .map(|t| !is_assignable(&t, element_type))
                     .unwrap_or(true);
                 if is_mismatched {
                     return Err(verifier.error(StatusCode::TYPE_MISMATCH, offset));
@@ -994,7 +1115,7 @@ fn verify_instr(
             let operand_elem = safe_unwrap_err!(verifier.stack.pop());
             let operand_vec = safe_unwrap_err!(verifier.stack.pop());
             let declared_element_type = &verifier.module.signature_at(*idx).0[0];
-            if declared_element_type != &operand_elem {
+            if !is_assignable(&operand_elem, declared_element_type) {
                 return Err(verifier.error(StatusCode::TYPE_MISMATCH, offset));
             }
             match get_vector_element_type(operand_vec, true) {
@@ -1060,6 +1181,48 @@ fn verify_instr(
             }
             verifier.push(meter, ST::U256)?;
         }
+        Bytecode::CastI8 => {
+            let operand = safe_unwrap_err!(verifier.stack.pop());
+            if !operand.is_integer() {
+                return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
+            }
+            verifier.push(meter, ST::I8)?;
+        }
+        Bytecode::CastI16 => {
+            let operand = safe_unwrap_err!(verifier.stack.pop());
+            if !operand.is_integer() {
+                return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
+            }
+            verifier.push(meter, ST::I16)?;
+        }
+        Bytecode::CastI32 => {
+            let operand = safe_unwrap_err!(verifier.stack.pop());
+            if !operand.is_integer() {
+                return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
+            }
+            verifier.push(meter, ST::I32)?;
+        }
+        Bytecode::CastI64 => {
+            let operand = safe_unwrap_err!(verifier.stack.pop());
+            if !operand.is_integer() {
+                return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
+            }
+            verifier.push(meter, ST::I64)?;
+        }
+        Bytecode::CastI128 => {
+            let operand = safe_unwrap_err!(verifier.stack.pop());
+            if !operand.is_integer() {
+                return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
+            }
+            verifier.push(meter, ST::I128)?;
+        }
+        Bytecode::CastI256 => {
+            let operand = safe_unwrap_err!(verifier.stack.pop());
+            if !operand.is_integer() {
+                return Err(verifier.error(StatusCode::INTEGER_OP_TYPE_MISMATCH_ERROR, offset));
+            }
+            verifier.push(meter, ST::I256)?;
+        }
         Bytecode::PackVariant(vidx) => {
             let handle = verifier.module.variant_handle_at(*vidx);
             let enum_def = verifier.module.enum_def_at(handle.enum_def);
@@ -1208,6 +1371,12 @@ fn instantiate(
             U64 => U64,
             U128 => U128,
             U256 => U256,
+            I8 => I8,
+            I16 => I16,
+            I32 => I32,
+            I64 => I64,
+            I128 => I128,
+            I256 => I256,
             Address => Address,
             Signer => Signer,
             Vector(ty) => Vector(Box::new(rec(ty, subst))),
@@ -1221,6 +1390,14 @@ fn instantiate(
             }
             Reference(ty) => Reference(Box::new(rec(ty, subst))),
             MutableReference(ty) => MutableReference(Box::new(rec(ty, subst))),
+            Function(func) => {
+                let (params, returns, abilities) = &**func;
+                Function(Box::new((
+                    params.iter().map(|ty| rec(ty, subst)).collect(),
+                    returns.iter().map(|ty| rec(ty, subst)).collect(),
+                    *abilities,
+                )))
+            }
             TypeParameter(idx) => {
                 // Assume that the caller has previously parsed and verified the structure of the
                 // file and that this guarantees that type parameter indices are always in bounds.
@@ -1234,6 +1411,45 @@ fn instantiate(
     Ok(ty)
 }
 
+/// Checks that a value of type `actual` can be used where a value of type `expected` is required.
+/// This is type equality, except that a function value with more abilities can be used where
+/// fewer are expected. Since values are never aliased, this extends through vectors, datatype
+/// instantiations, and immutable references, but not through mutable references.
+pub(crate) fn is_assignable(actual: &SignatureToken, expected: &SignatureToken) -> bool {
+    use SignatureToken::*;
+    match (actual, expected) {
+        (Function(actual), Function(expected)) => {
+            let (actual_params, actual_returns, actual_abilities) = &**actual;
+            let (expected_params, expected_returns, expected_abilities) = &**expected;
+            actual_params.len() == expected_params.len()
+                && actual_returns.len() == expected_returns.len()
+                && expected_abilities.is_subset(*actual_abilities)
+                && expected_params
+                    .iter()
+                    .zip(actual_params)
+                    .all(|(expected, actual)| is_assignable(expected, actual))
+                && actual_returns
+                    .iter()
+                    .zip(expected_returns)
+                    .all(|(actual, expected)| is_assignable(actual, expected))
+        }
+        (Vector(actual), Vector(expected)) | (Reference(actual), Reference(expected)) => {
+            is_assignable(actual, expected)
+        }
+        (DatatypeInstantiation(actual), DatatypeInstantiation(expected)) => {
+            let (actual_idx, actual_args) = &**actual;
+            let (expected_idx, expected_args) = &**expected;
+            actual_idx == expected_idx
+                && actual_args.len() == expected_args.len()
+                && actual_args
+                    .iter()
+                    .zip(expected_args)
+                    .all(|(actual, expected)| is_assignable(actual, expected))
+        }
+        (actual, expected) => actual == expected,
+    }
+}
+
 fn get_vector_element_type(
     vector_ref_ty: SignatureToken,
     mut_ref_only: bool,
@@ -0,0 +1,856 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::reroot_path;
+use anyhow::{bail, Context, Result};
+use clap::*;
+use colored::Colorize;
+use move_binary_format::{
+    compatibility::{Compatibility, InclusionCheck},
+    compatibility_mode::CompatibilityMode,
+    file_format::Visibility,
+    inclusion_mode::InclusionCheckMode,
+    normalized::{Enum, Function, Module, Struct},
+    CompiledModule,
+};
+use move_command_line_common::files::{extension_equals, MOVE_COMPILED_EXTENSION};
+use move_compiler::compiled_unit::NamedCompiledModule;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::{IdentStr, Identifier},
+    language_storage::ModuleId,
+};
+use move_package::{
+    compilation::{
+        module_abi::{fields_text, EnumAbi, FunctionAbi, StructAbi, VariantAbi},
+        package_layout::CompiledPackageLayout,
+    },
+    BuildConfig,
+};
+use serde_json::json;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Check that the package can be published as an upgrade of a previous build of it, reporting every
+/// incompatibility between the modules of the two builds.
+#[derive(Parser)]
+#[clap(name = "check-upgrade")]
+pub struct CheckUpgrade {
+    /// The previous build to check against: either the build directory of the package (or of the
+    /// package within it), or a directory of compiled modules (`.mv` files)
+    #[clap(long = "against")]
+    pub against: PathBuf,
+    /// The upgrade policy to check: `compatible` allows any change that does not break existing
+    /// code or data, `additive` only allows adding new items to modules, and `dep-only` allows no
+    /// changes to modules
+    #[clap(long = "policy", default_value = "compatible")]
+    pub policy: UpgradePolicy,
+    /// Print the report as JSON
+    #[clap(long = "json")]
+    pub json: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum UpgradePolicy {
+    Compatible,
+    Additive,
+    DepOnly,
+}
+
+/// The rules that the modules of an upgrade must follow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Rule {
+    /// Code that depends on the old module must still link against the new one.
+    PublicLinking,
+    /// Values of existing datatypes must still be readable by the new module.
+    DatatypeLayout,
+    /// Existing entry functions must still be callable.
+    EntryLinking,
+    /// Existing enums cannot gain variants.
+    NoNewVariants,
+    /// The old module must be included in the new one, as it is or with additions, depending on
+    /// the policy.
+    Inclusion,
+}
+
+/// An incompatible change to an item in a module, and the rule it breaks.
+struct Incompatibility {
+    item: String,
+    rule: Rule,
+    message: String,
+}
+
+/// Accumulates every incompatibility found by a compatibility or inclusion check.
+#[derive(Default)]
+struct Report {
+    incompatibilities: Vec<Incompatibility>,
+    /// Items added to the module, which are only incompatible if it must stay unchanged.
+    additions: Vec<Incompatibility>,
+}
+
+impl CheckUpgrade {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        let package = config.compile_package(&rerooted_path, &mut std::io::stderr())?;
+        let package_name = package.compiled_package_info.package_name;
+
+        let old_dir = old_modules_dir(&self.against, package_name.as_str());
+        let old_modules = read_modules(&old_dir)?;
+        let new_modules = package
+            .root_modules()
+            .map(|unit| {
+                let NamedCompiledModule { module, .. } = &unit.unit;
+                (module.self_id(), Module::new(module))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut incompatibilities = vec![];
+        for (id, old) in &old_modules {
+            let found = match new_modules.get(id) {
+                None => vec![Incompatibility {
+                    item: "module".to_string(),
+                    rule: Rule::PublicLinking,
+                    message: "module was removed".to_string(),
+                }],
+                Some(new) => self.policy.check(old, new),
+            };
+            incompatibilities.extend(found.into_iter().map(|i| (id.clone(), i)));
+        }
+        if let UpgradePolicy::DepOnly = self.policy {
+            for id in new_modules
+                .keys()
+                .filter(|id| !old_modules.contains_key(*id))
+            {
+                incompatibilities.push((
+                    id.clone(),
+                    Incompatibility {
+                        item: "module".to_string(),
+                        rule: Rule::Inclusion,
+                        message: "module was added".to_string(),
+                    },
+                ));
+            }
+        }
+
+        if self.json {
+            let incompatibilities = incompatibilities
+                .iter()
+                .map(|(id, i)| {
+                    json!({
+                        "module": id.short_str_lossless(),
+                        "item": i.item,
+                        "rule": i.rule.name(),
+                        "message": i.message,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let report = json!({
+                "package": package_name.as_str(),
+                "against": old_dir.display().to_string(),
+                "policy": self.policy.to_string(),
+                "compatible": incompatibilities.is_empty(),
+                "incompatibilities": incompatibilities,
+            });
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            for (id, i) in &incompatibilities {
+                println!(
+                    "{}: {}, {}: {} [{}: {}]",
+                    "error".bold().red(),
+                    id.short_str_lossless(),
+                    i.item,
+                    i.message,
+                    i.rule.name(),
+                    i.rule.description(),
+                );
+            }
+            if incompatibilities.is_empty() {
+                println!(
+                    "{} is an upgrade of '{}' under the {} policy",
+                    package_name,
+                    old_dir.display(),
+                    self.policy
+                );
+            } else {
+                println!(
+                    "{} incompatibilities found upgrading {} from '{}' under the {} policy",
+                    incompatibilities.len(),
+                    package_name,
+                    old_dir.display(),
+                    self.policy
+                );
+            }
+        }
+
+        // Return a non-zero exit code if the upgrade is incompatible
+        if !incompatibilities.is_empty() {
+            std::process::exit(1)
+        }
+        Ok(())
+    }
+}
+
+impl UpgradePolicy {
+    fn check(&self, old: &Module, new: &Module) -> Vec<Incompatibility> {
+        let result = match self {
+            Self::Compatible => Compatibility::upgrade_check().check_with_mode::<Report>(old, new),
+            Self::Additive => InclusionCheck::Subset.check_with_mode::<Report>(old, new),
+            Self::DepOnly => InclusionCheck::Equal.check_with_mode::<Report>(old, new),
+        };
+        result.err().unwrap_or_default()
+    }
+}
+
+impl FromStr for UpgradePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "compatible" => Self::Compatible,
+            "additive" => Self::Additive,
+            "dep-only" => Self::DepOnly,
+            _ => bail!(
+                "Unknown upgrade policy \"{s}\". Expected one of: \"compatible\", \"additive\", \
+                 \"dep-only\""
+            ),
+        })
+    }
+}
+
+impl fmt::Display for UpgradePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Compatible => "compatible",
+            Self::Additive => "additive",
+            Self::DepOnly => "dep-only",
+        })
+    }
+}
+
+impl Rule {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::PublicLinking => "public_linking",
+            Self::DatatypeLayout => "datatype_layout",
+            Self::EntryLinking => "entry_linking",
+            Self::NoNewVariants => "no_new_variants",
+            Self::Inclusion => "inclusion",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Self::PublicLinking => "code depending on the module must still link against it",
+            Self::DatatypeLayout => "existing values of its datatypes must remain readable",
+            Self::EntryLinking => "its entry functions must remain callable",
+            Self::NoNewVariants => "its enums cannot gain variants",
+            Self::Inclusion => "the previous module must be included in it unchanged",
+        }
+    }
+}
+
+impl Report {
+    fn push(&mut self, item: String, rule: Rule, message: String) {
+        self.incompatibilities.push(Incompatibility {
+            item,
+            rule,
+            message,
+        })
+    }
+
+    fn push_addition(&mut self, item: String, message: String) {
+        self.additions.push(Incompatibility {
+            item,
+            rule: Rule::Inclusion,
+            message,
+        })
+    }
+}
+
+impl CompatibilityMode for Report {
+    type Error = Vec<Incompatibility>;
+
+    fn module_id_mismatch(
+        &mut self,
+        old_addr: &AccountAddress,
+        old_name: &IdentStr,
+        new_addr: &AccountAddress,
+        new_name: &IdentStr,
+    ) {
+        self.push(
+            "module".to_string(),
+            Rule::PublicLinking,
+            module_id_message(old_addr, old_name, new_addr, new_name),
+        );
+    }
+
+    fn struct_missing(&mut self, name: &Identifier, _old_struct: &Struct) {
+        self.push(
+            format!("struct {name}"),
+            Rule::PublicLinking,
+            "struct was removed".to_string(),
+        );
+    }
+
+    fn struct_ability_mismatch(
+        &mut self,
+        name: &Identifier,
+        old_struct: &Struct,
+        new_struct: &Struct,
+    ) {
+        self.push(
+            format!("struct {name}"),
+            Rule::PublicLinking,
+            abilities_message(
+                &StructAbi::new(name, old_struct).abilities,
+                &StructAbi::new(name, new_struct).abilities,
+            ),
+        );
+    }
+
+    fn struct_type_param_mismatch(
+        &mut self,
+        name: &Identifier,
+        _old_struct: &Struct,
+        _new_struct: &Struct,
+    ) {
+        self.push(
+            format!("struct {name}"),
+            Rule::PublicLinking,
+            "type parameters or their constraints changed".to_string(),
+        );
+    }
+
+    fn struct_field_mismatch(
+        &mut self,
+        name: &Identifier,
+        old_struct: &Struct,
+        new_struct: &Struct,
+    ) {
+        self.push(
+            format!("struct {name}"),
+            Rule::DatatypeLayout,
+            format!(
+                "fields changed from `{}` to `{}`",
+                fields_text(&StructAbi::new(name, old_struct).fields),
+                fields_text(&StructAbi::new(name, new_struct).fields)
+            ),
+        );
+    }
+
+    fn enum_missing(&mut self, name: &Identifier, _old_enum: &Enum) {
+        self.push(
+            format!("enum {name}"),
+            Rule::PublicLinking,
+            "enum was removed".to_string(),
+        );
+    }
+
+    fn enum_ability_mismatch(&mut self, name: &Identifier, old_enum: &Enum, new_enum: &Enum) {
+        self.push(
+            format!("enum {name}"),
+            Rule::PublicLinking,
+            abilities_message(
+                &EnumAbi::new(name, old_enum).abilities,
+                &EnumAbi::new(name, new_enum).abilities,
+            ),
+        );
+    }
+
+    fn enum_type_param_mismatch(&mut self, name: &Identifier, _old_enum: &Enum, _new_enum: &Enum) {
+        self.push(
+            format!("enum {name}"),
+            Rule::PublicLinking,
+            "type parameters or their constraints changed".to_string(),
+        );
+    }
+
+    fn enum_new_variant(&mut self, name: &Identifier, old_enum: &Enum, new_enum: &Enum) {
+        let added = new_enum.variants[old_enum.variants.len()..]
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect::<Vec<_>>();
+        self.push(
+            format!("enum {name}"),
+            Rule::NoNewVariants,
+            format!("variants were added: {}", added.join(", ")),
+        );
+    }
+
+    fn enum_variant_missing(&mut self, name: &Identifier, old_enum: &Enum, tag: usize) {
+        self.push(
+            format!("enum {name}"),
+            Rule::DatatypeLayout,
+            format!("variant `{}` was removed", old_enum.variants[tag].name),
+        );
+    }
+
+    fn enum_variant_mismatch(
+        &mut self,
+        name: &Identifier,
+        old_enum: &Enum,
+        new_enum: &Enum,
+        tag: usize,
+    ) {
+        self.push(
+            format!("enum {name}"),
+            Rule::DatatypeLayout,
+            format!(
+                "variant {} changed from `{}` to `{}`",
+                tag,
+                VariantAbi::new(&old_enum.variants[tag]),
+                VariantAbi::new(&new_enum.variants[tag])
+            ),
+        );
+    }
+
+    fn function_missing_public(&mut self, name: &Identifier, _old_func: &Function) {
+        self.push(
+            format!("function {name}"),
+            Rule::PublicLinking,
+            "public function was removed".to_string(),
+        );
+    }
+
+    fn function_missing_entry(&mut self, name: &Identifier, _old_func: &Function) {
+        self.push(
+            format!("function {name}"),
+            Rule::EntryLinking,
+            "entry function was removed".to_string(),
+        );
+    }
+
+    fn function_signature_mismatch(
+        &mut self,
+        name: &Identifier,
+        old_func: &Function,
+        new_func: &Function,
+    ) {
+        // Only the signatures of functions that can be called from outside the module matter
+        let rule = if old_func.visibility == Visibility::Public {
+            Rule::PublicLinking
+        } else if old_func.is_entry {
+            Rule::EntryLinking
+        } else {
+            return;
+        };
+        self.push(
+            format!("function {name}"),
+            rule,
+            format!(
+                "signature changed from `{}` to `{}`",
+                FunctionAbi::new(name, old_func).signature(),
+                FunctionAbi::new(name, new_func).signature()
+            ),
+        );
+    }
+
+    fn function_lost_public_visibility(&mut self, name: &Identifier, _old_func: &Function) {
+        self.push(
+            format!("function {name}"),
+            Rule::PublicLinking,
+            "function is no longer public".to_string(),
+        );
+    }
+
+    fn function_entry_compatibility(
+        &mut self,
+        name: &Identifier,
+        old_func: &Function,
+        new_func: &Function,
+    ) {
+        let message = if old_func.is_entry && !new_func.is_entry {
+            "function is no longer entry"
+        } else {
+            "function changed whether it is entry"
+        };
+        self.push(
+            format!("function {name}"),
+            Rule::EntryLinking,
+            message.to_string(),
+        );
+    }
+
+    /// Only report incompatibilities that break the rules `compatibility` checks for.
+    fn finish(self, compatibility: &Compatibility) -> Result<(), Self::Error> {
+        let incompatibilities = self
+            .incompatibilities
+            .into_iter()
+            .filter(|i| match i.rule {
+                Rule::PublicLinking | Rule::Inclusion => true,
+                Rule::DatatypeLayout | Rule::NoNewVariants => compatibility.check_datatype_layout,
+                Rule::EntryLinking => compatibility.check_private_entry_linking,
+            })
+            .collect::<Vec<_>>();
+        if incompatibilities.is_empty() {
+            Ok(())
+        } else {
+            Err(incompatibilities)
+        }
+    }
+}
+
+impl InclusionCheckMode for Report {
+    type Error = Vec<Incompatibility>;
+
+    fn module_id_mismatch(
+        &mut self,
+        old_address: &AccountAddress,
+        old_name: &IdentStr,
+        new_address: &AccountAddress,
+        new_name: &IdentStr,
+    ) {
+        self.push(
+            "module".to_string(),
+            Rule::Inclusion,
+            module_id_message(old_address, old_name, new_address, new_name),
+        );
+    }
+
+    fn file_format_version_downgrade(&mut self, old_version: u32, new_version: u32) {
+        self.push(
+            "module".to_string(),
+            Rule::Inclusion,
+            format!("file format version was downgraded from {old_version} to {new_version}"),
+        );
+    }
+
+    fn struct_new(&mut self, name: &Identifier, _new_struct: &Struct) {
+        self.push_addition(format!("struct {name}"), "struct was added".to_string());
+    }
+
+    fn struct_change(&mut self, name: &Identifier, _old_struct: &Struct, _new_struct: &Struct) {
+        self.push(
+            format!("struct {name}"),
+            Rule::Inclusion,
+            "struct was changed".to_string(),
+        );
+    }
+
+    fn struct_missing(&mut self, name: &Identifier, _old_struct: &Struct) {
+        self.push(
+            format!("struct {name}"),
+            Rule::Inclusion,
+            "struct was removed".to_string(),
+        );
+    }
+
+    fn enum_new(&mut self, name: &Identifier, _new_enum: &Enum) {
+        self.push_addition(format!("enum {name}"), "enum was added".to_string());
+    }
+
+    fn enum_change(&mut self, name: &Identifier, _new_enum: &Enum) {
+        self.push(
+            format!("enum {name}"),
+            Rule::Inclusion,
+            "enum was changed".to_string(),
+        );
+    }
+
+    fn enum_missing(&mut self, name: &Identifier, _old_enum: &Enum) {
+        self.push(
+            format!("enum {name}"),
+            Rule::Inclusion,
+            "enum was removed".to_string(),
+        );
+    }
+
+    fn function_new(&mut self, name: &Identifier, _new_func: &Function) {
+        self.push_addition(format!("function {name}"), "function was added".to_string());
+    }
+
+    fn function_change(&mut self, name: &Identifier, _old_func: &Function, _new_func: &Function) {
+        self.push(
+            format!("function {name}"),
+            Rule::Inclusion,
+            "function was changed".to_string(),
+        );
+    }
+
+    fn function_missing(&mut self, name: &Identifier, _old_func: &Function) {
+        self.push(
+            format!("function {name}"),
+            Rule::Inclusion,
+            "function was removed".to_string(),
+        );
+    }
+
+    fn friend_mismatch(&mut self, old_count: usize, new_count: usize) {
+        self.push_addition(
+            "module".to_string(),
+            format!("number of friends changed from {old_count} to {new_count}"),
+        );
+    }
+
+    fn finish(self, inclusion: &InclusionCheck) -> Result<(), Self::Error> {
+        let mut incompatibilities = self.incompatibilities;
+        if *inclusion == InclusionCheck::Equal {
+            incompatibilities.extend(self.additions);
+        }
+        if incompatibilities.is_empty() {
+            Ok(())
+        } else {
+            Err(incompatibilities)
+        }
+    }
+}
+
+/// The directory holding the modules of the previous build at `against`, which is either the
+/// directory itself or the bytecode directory of the package named `package_name` within it.
+fn old_modules_dir(against: &Path, package_name: &str) -> PathBuf {
+    let bytecode_modules = CompiledPackageLayout::CompiledModules.path();
+    [
+        against.join(bytecode_modules),
+        against.join(package_name).join(bytecode_modules),
+    ]
+    .into_iter()
+    .find(|dir| dir.is_dir())
+    .unwrap_or_else(|| against.to_path_buf())
+}
+
+/// Read the compiled modules directly within `dir`, keyed by their ids.
+fn read_modules(dir: &Path) -> Result<BTreeMap<ModuleId, Module>> {
+    let mut modules = BTreeMap::new();
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Reading modules in '{}'", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if !path.is_file() || !extension_equals(&path, MOVE_COMPILED_EXTENSION) {
+            continue;
+        }
+        let bytes = std::fs::read(&path)?;
+        let module = CompiledModule::deserialize_with_defaults(&bytes)
+            .with_context(|| format!("Deserializing module '{}'", path.display()))?;
+        modules.insert(module.self_id(), Module::new(&module));
+    }
+    if modules.is_empty() {
+        bail!("No compiled modules found in '{}'", dir.display())
+    }
+    Ok(modules)
+}
+
+fn module_id_message(
+    old_addr: &AccountAddress,
+    old_name: &IdentStr,
+    new_addr: &AccountAddress,
+    new_name: &IdentStr,
+) -> String {
+    format!(
+        "module id changed from {}::{} to {}::{}",
+        old_addr.to_hex_literal(),
+        old_name,
+        new_addr.to_hex_literal(),
+        new_name
+    )
+}
+
+fn abilities_message(old: &[String], new: &[String]) -> String {
+    format!(
+        "abilities changed from `{}` to `{}`",
+        old.join(", "),
+        new.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Rule, UpgradePolicy};
+    use move_binary_format::normalized::Module;
+    use move_core_types::parsing::address::NumericalAddress;
+    use std::collections::BTreeMap;
+
+    const OLD: &str = r#"
+        module 0x2::m {
+            public struct S has store { x: u64 }
+            public enum E has copy, drop { A { x: u8 }, B }
+            public fun f<T: copy>(x: u64, t: T): (u64, T) { (x, t) }
+            entry fun g() { h(); }
+            fun h(): u64 { 0 }
+            public fun x(s: &S): u64 { s.x }
+        }
+    "#;
+
+    fn compile(source: &str) -> Module {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("m.move");
+        std::fs::write(&path, source).unwrap();
+        let (_, units) = move_compiler::Compiler::from_files(
+            None,
+            vec![path.to_str().unwrap()],
+            vec![],
+            BTreeMap::<&str, NumericalAddress>::new(),
+        )
+        .build_and_report()
+        .unwrap();
+        let [unit] = &units[..] else {
+            panic!("expected a single module")
+        };
+        Module::new(&unit.named_module.module)
+    }
+
+    /// The incompatibilities found upgrading `OLD` to `new` under `policy`.
+    fn check(policy: UpgradePolicy, new: &str) -> Vec<(String, Rule, String)> {
+        policy
+            .check(&compile(OLD), &compile(new))
+            .into_iter()
+            .map(|i| (i.item, i.rule, i.message))
+            .collect()
+    }
+
+    /// `OLD` with `from` replaced by `to`.
+    fn change(from: &str, to: &str) -> String {
+        assert!(OLD.contains(from));
+        OLD.replace(from, to)
+    }
+
+    fn incompatibility(item: &str, rule: Rule, message: &str) -> (String, Rule, String) {
+        (item.to_string(), rule, message.to_string())
+    }
+
+    #[test]
+    fn unchanged_module() {
+        for policy in [
+            UpgradePolicy::Compatible,
+            UpgradePolicy::Additive,
+            UpgradePolicy::DepOnly,
+        ] {
+            assert_eq!(check(policy, OLD), vec![], "{policy}");
+        }
+    }
+
+    #[test]
+    fn additions() {
+        let new = change(
+            "fun h(): u64 { 0 }",
+            "fun h(): u64 { 0 }\n public struct T {}\n public fun k() {}",
+        );
+        assert_eq!(check(UpgradePolicy::Compatible, &new), vec![]);
+        assert_eq!(check(UpgradePolicy::Additive, &new), vec![]);
+        assert_eq!(
+            check(UpgradePolicy::DepOnly, &new),
+            vec![
+                incompatibility("struct T", Rule::Inclusion, "struct was added"),
+                incompatibility("function k", Rule::Inclusion, "function was added"),
+            ]
+        );
+    }
+
+    #[test]
+    fn private_changes() {
+        let new = change("fun h(): u64 { 0 }", "fun h(): bool { false }");
+        assert_eq!(check(UpgradePolicy::Compatible, &new), vec![]);
+        assert_eq!(
+            check(UpgradePolicy::Additive, &new),
+            vec![incompatibility(
+                "function h",
+                Rule::Inclusion,
+                "function was changed"
+            )]
+        );
+    }
+
+    #[test]
+    fn incompatible_structs() {
+        let new = change("has store { x: u64 }", "has store, drop { x: u64 }");
+        assert_eq!(
+            check(UpgradePolicy::Compatible, &new),
+            vec![incompatibility(
+                "struct S",
+                Rule::PublicLinking,
+                "abilities changed from `store` to `drop, store`"
+            )]
+        );
+
+        let new = change("{ x: u64 }", "{ x: u64, y: bool }")
+            .replace("{ s.x }", "{ if (s.y) s.x else 0 }");
+        assert_eq!(
+            check(UpgradePolicy::Compatible, &new),
+            vec![incompatibility(
+                "struct S",
+                Rule::DatatypeLayout,
+                "fields changed from `x: u64` to `x: u64, y: bool`"
+            )]
+        );
+        assert_eq!(
+            check(UpgradePolicy::Additive, &new),
+            vec![
+                incompatibility("struct S", Rule::Inclusion, "struct was changed"),
+                incompatibility("function x", Rule::Inclusion, "function was changed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn incompatible_enums() {
+        let new = change("A { x: u8 }, B }", "A { x: u8 }, B, C }");
+        assert_eq!(
+            check(UpgradePolicy::Compatible, &new),
+            vec![incompatibility(
+                "enum E",
+                Rule::NoNewVariants,
+                "variants were added: C"
+            )]
+        );
+
+        let new = change("A { x: u8 }", "A { x: u16 }");
+        assert_eq!(
+            check(UpgradePolicy::Compatible, &new),
+            vec![incompatibility(
+                "enum E",
+                Rule::DatatypeLayout,
+                "variant 0 changed from `A { x: u8 }` to `A { x: u16 }`"
+            )]
+        );
+        assert_eq!(
+            check(UpgradePolicy::Additive, &new),
+            vec![incompatibility(
+                "enum E",
+                Rule::Inclusion,
+                "enum was changed"
+            )]
+        );
+    }
+
+    #[test]
+    fn incompatible_functions() {
+        let new = change(
+            "f<T: copy>(x: u64, t: T): (u64, T) { (x, t) }",
+            "f<T: copy + drop>(x: u64, _t: T): u64 { x }",
+        );
+        assert_eq!(
+            check(UpgradePolicy::Compatible, &new),
+            vec![incompatibility(
+                "function f",
+                Rule::PublicLinking,
+                "signature changed from `<T0: copy>(u64, T0): (u64, T0)` to \
+                 `<T0: copy + drop>(u64, T0): u64`"
+            )]
+        );
+
+        let new = change("public fun f", "public(package) fun f");
+        assert_eq!(
+            check(UpgradePolicy::Compatible, &new),
+            vec![incompatibility(
+                "function f",
+                Rule::PublicLinking,
+                "function is no longer public"
+            )]
+        );
+
+        // Private entry functions are not linked against by other modules
+        let new = change("entry fun g() { h(); }", "").replace("fun h(): u64 { 0 }", "");
+        assert_eq!(check(UpgradePolicy::Compatible, &new), vec![]);
+        assert_eq!(
+            check(UpgradePolicy::Additive, &new),
+            vec![
+                incompatibility("function g", Rule::Inclusion, "function was removed"),
+                incompatibility("function h", Rule::Inclusion, "function was removed"),
+            ]
+        );
+    }
+}
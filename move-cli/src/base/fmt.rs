@@ -0,0 +1,74 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::reroot_path;
+use anyhow::bail;
+use clap::*;
+use move_command_line_common::files::find_move_filenames;
+use move_compiler::formatter::format_source;
+use move_package::{
+    source_package::{layout::SourcePackageLayout, manifest_parser::parse_move_manifest_from_file},
+    BuildConfig,
+};
+use std::path::Path;
+
+/// Format Move source files of the package at `path`. If no path is provided defaults to current
+/// directory. Formatting options are read from the `[format]` section of the package manifest.
+#[derive(Parser)]
+#[clap(name = "fmt")]
+pub struct Fmt {
+    /// Do not modify any files, instead list the ones that are not formatted and fail if there
+    /// are any
+    #[clap(long = "check")]
+    pub check: bool,
+}
+
+impl Fmt {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        let manifest = parse_move_manifest_from_file(&rerooted_path)?;
+        let edition = manifest
+            .package
+            .edition
+            .or(config.default_edition)
+            .unwrap_or_default();
+        let format_config = manifest.format.unwrap_or_default();
+
+        let dirs = [
+            SourcePackageLayout::Sources,
+            SourcePackageLayout::Tests,
+            SourcePackageLayout::Examples,
+            SourcePackageLayout::Scripts,
+        ]
+        .iter()
+        .map(|dir| rerooted_path.join(dir.path()))
+        .filter(|dir| dir.is_dir())
+        .collect::<Vec<_>>();
+        let mut files = find_move_filenames(&dirs, false)?;
+        files.sort();
+
+        let mut unformatted = vec![];
+        for file in files {
+            let source = std::fs::read_to_string(&file)?;
+            let formatted = format_source(&file, &source, edition, &format_config)?;
+            if formatted == source {
+                continue;
+            }
+            if self.check {
+                println!("{file}");
+            } else {
+                std::fs::write(&file, formatted)?;
+            }
+            unformatted.push(file);
+        }
+
+        if self.check && !unformatted.is_empty() {
+            bail!(
+                "{} file(s) are not formatted. Run 'move fmt' to format them",
+                unformatted.len()
+            );
+        }
+        Ok(())
+    }
+}
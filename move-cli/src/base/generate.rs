@@ -0,0 +1,76 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::reroot_path;
+use clap::*;
+use move_binary_format::file_format::FunctionDefinitionIndex;
+use move_bytecode_utils::rust_bindings::RustBindingsGenerator;
+use move_package::BuildConfig;
+use std::path::{Path, PathBuf};
+
+/// Generate code for using the package from other languages
+#[derive(Parser)]
+#[clap(name = "generate")]
+pub struct Generate {
+    #[clap(subcommand)]
+    pub cmd: GenerateCommand,
+}
+
+#[derive(Parser)]
+pub enum GenerateCommand {
+    /// Generate Rust types mirroring the package's structs and enums, which serialize the same way
+    /// with BCS, and functions that build calls to its entry functions.
+    #[clap(name = "rust-bindings")]
+    RustBindings {
+        /// The file to write the bindings to. If unset, the bindings are printed.
+        #[clap(long = "output", short = 'o')]
+        output: Option<PathBuf>,
+    },
+}
+
+impl Generate {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        // Progress goes to stderr to keep stdout for the generated code
+        let package = config.compile_package(&rerooted_path, &mut std::io::stderr())?;
+        match self.cmd {
+            GenerateCommand::RustBindings { output } => {
+                let modules = package.all_modules_map();
+                let mut generator = RustBindingsGenerator::new(&modules);
+                let mut roots = vec![];
+                for unit in package.root_modules() {
+                    let module = &unit.unit.module;
+                    roots.push(module.self_id());
+                    for (idx, def) in module.function_defs().iter().enumerate() {
+                        let Ok(function_map) = unit
+                            .unit
+                            .source_map
+                            .get_function_source_map(FunctionDefinitionIndex(idx as u16))
+                        else {
+                            continue;
+                        };
+                        let handle = module.function_handle_at(def.function);
+                        generator.add_parameter_names(
+                            module.self_id(),
+                            module.identifier_at(handle.name).to_owned(),
+                            function_map
+                                .parameters
+                                .iter()
+                                // Source maps qualify the names of locals with `#`
+                                .map(|(name, _)| name.split('#').next().unwrap().to_string())
+                                .collect(),
+                        );
+                    }
+                }
+
+                let bindings = generator.generate(&roots)?;
+                match output {
+                    Some(output) => std::fs::write(output, bindings)?,
+                    None => print!("{}", bindings),
+                }
+            }
+        }
+        Ok(())
+    }
+}
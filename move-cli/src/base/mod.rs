@@ -3,13 +3,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod build;
+pub mod check_upgrade;
 pub mod coverage;
 pub mod disassemble;
 pub mod docgen;
+pub mod fmt;
+pub mod generate;
 pub mod info;
 pub mod migrate;
 pub mod new;
 pub mod test;
+pub mod trace;
+pub mod tree;
+pub mod vendor;
+pub mod why;
 
 use move_package::source_package::layout::SourcePackageLayout;
 use std::path::{Path, PathBuf};
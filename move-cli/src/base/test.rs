@@ -73,6 +73,21 @@ pub struct Test {
     // Enable tracing for tests
     #[clap(long = "trace-execution", value_name = "PATH")]
     pub trace_execution: Option<Option<String>>,
+
+    /// Save execution traces in the JSON format rather than in the (more compact) binary one.
+    #[clap(long = "trace-json", requires = "trace_execution")]
+    pub trace_json: bool,
+
+    /// Profile the gas consumed by each test, saving a speedscope profile per test to the given
+    /// directory along with a flamegraph-ready `gas.folded` file of the gas consumed by each stack
+    /// of functions over all tests. Requires the `tracing` feature.
+    #[clap(long = "profile-gas", value_name = "DIR")]
+    pub profile_gas: Option<String>,
+
+    /// Debug the first test selected by the filter, serving the Debug Adapter Protocol on stdin and
+    /// stdout so that an editor can attach to the debugger. Requires the `tracing` feature.
+    #[clap(long = "debug")]
+    pub debug: bool,
 }
 
 impl Test {
@@ -87,6 +102,12 @@ impl Test {
         let compute_coverage = self.compute_coverage;
         // save disassembly if trace execution is enabled
         let save_disassembly = self.trace_execution.is_some();
+        // stdout is reserved for the debug adapter protocol when debugging
+        let mut writer: Box<dyn Write + Send> = if self.debug {
+            Box::new(std::io::stderr())
+        } else {
+            Box::new(std::io::stdout())
+        };
         let result = run_move_unit_tests(
             &rerooted_path,
             config,
@@ -95,7 +116,7 @@ impl Test {
             cost_table,
             compute_coverage,
             save_disassembly,
-            &mut std::io::stdout(),
+            &mut writer,
         )?;
 
         // Return a non-zero exit code if any test failed
@@ -117,6 +138,9 @@ impl Test {
             seed,
             rand_num_iters,
             trace_execution,
+            trace_json,
+            profile_gas,
+            debug,
         } = self;
         UnitTestingConfig {
             gas_limit,
@@ -128,6 +152,9 @@ impl Test {
             seed,
             rand_num_iters,
             trace_execution,
+            trace_json,
+            profile_gas,
+            debug,
             ..UnitTestingConfig::default_with_bound(None)
         }
     }
@@ -0,0 +1,516 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::reroot_path;
+use anyhow::{anyhow, bail, Context};
+use clap::*;
+use move_core_types::annotated_value::MoveValue;
+use move_package::BuildConfig;
+use move_trace_format::{
+    binary,
+    format::{Effect, Location, MoveTrace, Read, TraceEvent, TraceIndex, Write as WriteEffect},
+};
+use move_unit_test::debugger::session::{
+    string_value, DebugSession, SourcePosition, StepKind, StepResult, StopReason,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+const HELP: &str = "\
+Commands:
+  step, s                   step to the next source line, entering called functions
+  next, n                   step to the next source line of the current function
+  out, o                    run until the current function returns
+  continue, c               run until the next breakpoint
+  reverse-step, rs          step back to the previous source line of the current function
+  reverse-continue, rc      run backwards until the previous breakpoint
+  goto, g EVENT             move to the instruction which produced an event of the trace
+  break, br FILE:LINE       set a breakpoint
+  delete, d FILE:LINE       remove a breakpoint
+  stack, bt                 show the call stack, with the ids of frames
+  locals, l [N]             show locals of the N-th frame of the call stack (0 is the current one)
+  changes, ch LOCATION      list the events changing a location (e.g., l3@17, l0@5[2], g42),
+                            marking those after the current instruction with '>'
+  last-change, lc LOCATION  show the last event changing a location before the current instruction
+  help, h                   show this message
+  quit, q                   exit";
+
+#[derive(Parser)]
+pub enum TraceOptions {
+    /// Replay a trace saved by `move test --trace-execution`, stepping forwards and backwards
+    /// through the execution mapped to the source code of this package. The package must not
+    /// have changed since the trace was saved.
+    #[clap(name = "replay")]
+    Replay {
+        /// Path to the trace file (in the JSON or the binary format)
+        #[clap(name = "trace")]
+        trace: PathBuf,
+    },
+    /// Convert a trace between the JSON and the binary formats. The format of the input is
+    /// detected, and the output is written in the other format.
+    #[clap(name = "convert")]
+    Convert {
+        /// Path to the trace file to convert
+        #[clap(name = "input")]
+        input: PathBuf,
+        /// Path to write the converted trace to
+        #[clap(name = "output")]
+        output: PathBuf,
+    },
+}
+
+/// Inspect execution traces of unit tests in this package.
+#[derive(Parser)]
+#[clap(name = "trace")]
+pub struct Trace {
+    #[clap(subcommand)]
+    pub options: TraceOptions,
+}
+
+impl Trace {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let trace = match self.options {
+            TraceOptions::Replay { trace } => trace,
+            TraceOptions::Convert { input, output } => return convert(&input, &output),
+        };
+        // the trace path is relative to the current directory, which is changed to the package root
+        let trace = read_trace(&trace)?;
+        let path = reroot_path(path)?;
+        let mut config = config;
+        config.test_mode = true;
+        config.dev_mode = true;
+        let package = config.compile_package(&path, &mut Vec::new())?;
+        let module_info = package
+            .all_modules()
+            .map(|unit| (unit.unit.module.self_id(), unit.unit.clone()))
+            .collect();
+        let session = DebugSession::new(trace, module_info, package.file_map.clone());
+        Replay {
+            session,
+            breakpoints: BTreeMap::new(),
+        }
+        .run()
+    }
+}
+
+struct Replay {
+    session: DebugSession,
+    breakpoints: BTreeMap<PathBuf, BTreeSet<usize>>,
+}
+
+impl Replay {
+    fn run(&mut self) -> anyhow::Result<()> {
+        println!("Type 'help' for a list of commands.");
+        let result = self.session.start(/* stop_on_entry */ true);
+        self.report(result);
+        let mut stdin = std::io::stdin().lock();
+        loop {
+            print!("(trace) ");
+            std::io::stdout().flush()?;
+            let mut line = String::new();
+            if stdin.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            let mut words = line.split_whitespace();
+            let Some(command) = words.next() else {
+                continue;
+            };
+            let arg = words.next();
+            match self.execute(command, arg) {
+                Ok(true) => (),
+                Ok(false) => return Ok(()),
+                Err(err) => println!("{err}"),
+            }
+        }
+    }
+
+    /// Executes a command, returning whether to continue the replay.
+    fn execute(&mut self, command: &str, arg: Option<&str>) -> anyhow::Result<bool> {
+        let result = match command {
+            "step" | "s" => self.session.step(StepKind::In),
+            "next" | "n" => self.session.step(StepKind::Over),
+            "out" | "o" => self.session.step(StepKind::Out),
+            "continue" | "c" => self.session.step(StepKind::Continue),
+            "reverse-step" | "rs" => self.session.step_back(),
+            "reverse-continue" | "rc" => self.session.reverse_continue(),
+            "goto" | "g" => {
+                let event = required(arg, "EVENT")?;
+                let event = event
+                    .parse::<TraceIndex>()
+                    .ok()
+                    .filter(|event| *event < self.session.events().len())
+                    .ok_or_else(|| anyhow!("Invalid event '{event}'"))?;
+                self.session.goto(event)
+            }
+            "break" | "br" | "delete" | "d" => {
+                let (path, line) = breakpoint(required(arg, "FILE:LINE")?)?;
+                let lines = self.breakpoints.entry(path.clone()).or_default();
+                if command.starts_with('b') {
+                    lines.insert(line);
+                } else if !lines.remove(&line) {
+                    bail!("No breakpoint at {}:{line}", path.display());
+                }
+                let lines = lines.iter().copied().collect::<Vec<_>>();
+                let verified = self.session.set_breakpoints(&path, &lines);
+                if lines
+                    .iter()
+                    .zip(verified)
+                    .any(|(l, verified)| *l == line && !verified)
+                {
+                    println!("Warning: no code at {}:{line}", path.display());
+                }
+                return Ok(true);
+            }
+            "stack" | "bt" => {
+                self.print_stack();
+                return Ok(true);
+            }
+            "locals" | "l" => {
+                let idx = arg.map_or(Ok(0), |n| {
+                    n.parse::<usize>()
+                        .map_err(|_| anyhow!("Invalid frame '{n}'"))
+                })?;
+                self.print_locals(idx)?;
+                return Ok(true);
+            }
+            "changes" | "ch" => {
+                let location = location(required(arg, "LOCATION")?)?;
+                let changes = self.session.changes(&location);
+                if changes.is_empty() {
+                    println!("No change to {location}");
+                }
+                for event in changes {
+                    let marker = if event < self.session.cursor() {
+                        " "
+                    } else {
+                        ">"
+                    };
+                    println!("{marker} {}", self.describe_event(event));
+                }
+                return Ok(true);
+            }
+            "last-change" | "lc" => {
+                let location = location(required(arg, "LOCATION")?)?;
+                match self.session.last_change(&location) {
+                    Some(event) => println!("{}", self.describe_event(event)),
+                    None => println!("No change to {location} so far"),
+                }
+                return Ok(true);
+            }
+            "help" | "h" => {
+                println!("{HELP}");
+                return Ok(true);
+            }
+            "quit" | "q" => return Ok(false),
+            _ => bail!("Unknown command '{command}', type 'help' for a list of commands"),
+        };
+        self.report(result);
+        Ok(true)
+    }
+
+    /// Reports where the replayed execution stopped.
+    fn report(&mut self, result: StepResult) {
+        for output in self.session.take_output() {
+            println!("[debug] {output}");
+        }
+        let reason = match result {
+            StepResult::Stopped(reason) => reason,
+            StepResult::Terminated => {
+                println!("Execution terminated");
+                return;
+            }
+        };
+        match reason {
+            StopReason::Entry | StopReason::Step => (),
+            StopReason::Breakpoint => println!("Breakpoint hit"),
+            StopReason::Exception(msg) => println!("Execution error: {msg}"),
+        }
+        let Some(frame) = self.session.stack().last() else {
+            return;
+        };
+        println!(
+            "[event {}] {}",
+            self.session.cursor(),
+            self.session.frame_name(frame)
+        );
+        if let Some(position) = self.session.frame_position(frame) {
+            println!("{}", format_position(&position));
+            if let Some(text) = source_line(&position) {
+                println!("{:>5} | {}", position.line, text.trim_end());
+            }
+        }
+    }
+
+    fn print_stack(&self) {
+        let stack = self.session.stack();
+        for (idx, frame) in stack.iter().rev().enumerate() {
+            let position = self
+                .session
+                .frame_position(frame)
+                .map(|position| format!(" at {}", format_position(&position)))
+                .unwrap_or_default();
+            println!(
+                "#{idx} [frame {}] {}{position}",
+                frame.frame_id,
+                self.session.frame_name(frame)
+            );
+        }
+    }
+
+    fn print_locals(&self, idx: usize) -> anyhow::Result<()> {
+        let stack = self.session.stack();
+        let frame = stack
+            .len()
+            .checked_sub(idx + 1)
+            .and_then(|idx| stack.get(idx))
+            .ok_or_else(|| anyhow!("No frame #{idx} in the call stack"))?;
+        for local in self.session.locals(frame) {
+            println!(
+                "l{} {}: {} = {}",
+                local.index,
+                local.name,
+                local.type_,
+                format_value(&local.value)
+            );
+        }
+        Ok(())
+    }
+
+    fn describe_event(&self, event: TraceIndex) -> String {
+        let what = match &self.session.events()[event] {
+            TraceEvent::OpenFrame { frame, .. } => {
+                format!("arguments passed to {}", frame.function_name)
+            }
+            TraceEvent::Effect(effect) => match &**effect {
+                Effect::Write(WriteEffect {
+                    location,
+                    root_value_after_write,
+                }) => format!(
+                    "{location} <-- {}",
+                    format_value(root_value_after_write.snapshot())
+                ),
+                Effect::Read(Read { location, .. }) => format!("{location} moved"),
+                effect => effect.to_string(),
+            },
+            _ => String::new(),
+        };
+        let origin = match self.session.event_origin(event) {
+            Some(origin) => {
+                let position = origin
+                    .position
+                    .map(|position| format!(", {}", format_position(&position)))
+                    .unwrap_or_default();
+                format!(
+                    " ({} pc {}: {}{position})",
+                    origin.function_name, origin.pc, origin.instruction
+                )
+            }
+            None => String::new(),
+        };
+        format!("[event {event}] {what}{origin}")
+    }
+}
+
+/// Reads a trace saved in either the JSON or the binary format.
+fn read_trace(path: &Path) -> anyhow::Result<MoveTrace> {
+    let file = File::open(path).with_context(|| format!("Unable to open {}", path.display()))?;
+    MoveTrace::read(file).with_context(|| format!("Invalid trace {}", path.display()))
+}
+
+fn convert(input: &Path, output: &Path) -> anyhow::Result<()> {
+    let mut reader = BufReader::new(
+        File::open(input).with_context(|| format!("Unable to open {}", input.display()))?,
+    );
+    let writer = BufWriter::new(
+        File::create(output).with_context(|| format!("Unable to create {}", output.display()))?,
+    );
+    let (result, format) = if binary::is_binary(&mut reader)? {
+        (binary::binary_to_json(reader, writer), "JSON")
+    } else {
+        (binary::json_to_binary(reader, writer), "binary")
+    };
+    result.with_context(|| format!("Unable to convert {}", input.display()))?;
+    println!("Wrote {} trace to {}", format, output.display());
+    Ok(())
+}
+
+fn required<'a>(arg: Option<&'a str>, name: &str) -> anyhow::Result<&'a str> {
+    arg.ok_or_else(|| anyhow!("Missing argument {name}"))
+}
+
+fn location(arg: &str) -> anyhow::Result<Location> {
+    arg.parse::<Location>().map_err(|err| anyhow!(err))
+}
+
+fn breakpoint(arg: &str) -> anyhow::Result<(PathBuf, usize)> {
+    let (path, line) = arg
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow!("Invalid breakpoint '{arg}', expected FILE:LINE"))?;
+    let line = line
+        .parse::<usize>()
+        .map_err(|_| anyhow!("Invalid line '{line}'"))?;
+    Ok((PathBuf::from(path), line))
+}
+
+/// Formats a position relative to the package root (the current directory).
+fn format_position(position: &SourcePosition) -> String {
+    let path = std::env::current_dir()
+        .ok()
+        .and_then(|dir| position.path.strip_prefix(dir.canonicalize().ok()?).ok())
+        .unwrap_or(&position.path);
+    format!("{}:{}:{}", path.display(), position.line, position.column)
+}
+
+fn source_line(position: &SourcePosition) -> Option<String> {
+    let text = std::fs::read_to_string(&position.path).ok()?;
+    text.lines()
+        .nth(position.line.checked_sub(1)?)
+        .map(str::to_string)
+}
+
+/// Renders a value in full. Integer widths are not shown as they are not recorded in JSON traces.
+fn format_value(value: &MoveValue) -> String {
+    let fields = |fields: &[(_, MoveValue)]| {
+        fields
+            .iter()
+            .map(|(name, value)| format!("{name}: {}", format_value(value)))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match value {
+        MoveValue::U8(n) => n.to_string(),
+        MoveValue::U16(n) => n.to_string(),
+        MoveValue::U32(n) => n.to_string(),
+        MoveValue::U64(n) => n.to_string(),
+        MoveValue::U128(n) => n.to_string(),
+        MoveValue::U256(n) => n.to_string(),
+        MoveValue::I8(n) => n.to_string(),
+        MoveValue::I16(n) => n.to_string(),
+        MoveValue::I32(n) => n.to_string(),
+        MoveValue::I64(n) => n.to_string(),
+        MoveValue::I128(n) => n.to_string(),
+        MoveValue::I256(n) => n.to_string(),
+        MoveValue::Bool(b) => b.to_string(),
+        MoveValue::Address(a) => a.to_hex_literal(),
+        MoveValue::Signer(a) => format!("signer({})", a.to_hex_literal()),
+        MoveValue::Vector(elems) => format!(
+            "[{}]",
+            elems
+                .iter()
+                .map(format_value)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        MoveValue::Struct(s) => match string_value(value) {
+            Some(text) => format!("{text:?}"),
+            None => format!("{} {{ {} }}", s.type_.name, fields(&s.fields)),
+        },
+        MoveValue::Variant(v) => format!(
+            "{}::{} {{ {} }}",
+            v.type_.name,
+            v.variant_name,
+            fields(&v.fields)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, read_trace, Replay};
+    use move_binary_format::file_format::{Bytecode, FunctionDefinitionIndex};
+    use move_compiler::shared::files::MappedFiles;
+    use move_core_types::{
+        account_address::AccountAddress,
+        annotated_value::MoveValue,
+        identifier::Identifier,
+        language_storage::{ModuleId, TypeTag},
+    };
+    use move_trace_format::{
+        binary,
+        format::{Effect, Location, MoveTraceBuilder, TraceValue, TypeTagWithRefs, Write},
+    };
+    use move_unit_test::debugger::session::DebugSession;
+    use std::{
+        collections::BTreeMap,
+        fs::File,
+        io::{BufReader, BufWriter},
+    };
+
+    /// Streams the trace of a function writing a `u8` to its only local to a binary trace file.
+    fn write_binary_trace(file: File) {
+        let mut builder = MoveTraceBuilder::new_streaming(Box::new(BufWriter::new(file))).unwrap();
+        let frame_id = builder.current_trace_offset();
+        builder.open_frame(
+            frame_id,
+            FunctionDefinitionIndex(0),
+            "f".to_string(),
+            ModuleId::new(AccountAddress::ONE, Identifier::new("m").unwrap()),
+            vec![],
+            vec![],
+            vec![],
+            vec![TypeTagWithRefs {
+                type_: TypeTag::U8,
+                ref_type: None,
+            }],
+            false,
+            10,
+        );
+        builder.instruction(
+            &Bytecode::StLoc(0),
+            vec![],
+            vec![Effect::Write(Write {
+                location: Location::Local(frame_id, 0),
+                root_value_after_write: TraceValue::RuntimeValue {
+                    value: MoveValue::U8(42),
+                },
+            })],
+            9,
+            0,
+        );
+        builder.instruction(&Bytecode::Ret, vec![], vec![], 8, 1);
+        builder.close_frame(frame_id, vec![], 8);
+        builder.finish().unwrap();
+    }
+
+    #[test]
+    fn replay_binary_traces() {
+        let dir = tempfile::tempdir().unwrap();
+        let binary_path = dir.path().join("test.trace");
+        write_binary_trace(File::create(&binary_path).unwrap());
+        let trace = read_trace(&binary_path).unwrap();
+        assert_eq!(trace.events.len(), 5);
+
+        let mut replay = Replay {
+            session: DebugSession::new(trace.clone(), BTreeMap::new(), MappedFiles::empty()),
+            breakpoints: BTreeMap::new(),
+        };
+        assert!(replay.execute("goto", Some("2")).unwrap());
+        assert_eq!(replay.session.cursor(), 1);
+        assert!(replay.execute("changes", Some("l0@0")).unwrap());
+        assert!(replay.execute("goto", Some("5")).is_err());
+        replay.session.goto(4);
+        let frame = replay.session.stack()[0].clone();
+        let locals = replay.session.locals(&frame);
+        // integer widths are preserved in binary traces
+        assert_eq!(locals[0].value, MoveValue::U8(42));
+        assert!(!replay.execute("quit", None).unwrap());
+
+        // converting the trace to JSON and back preserves its events (but for integer widths)
+        let json_path = dir.path().join("test.json");
+        convert(&binary_path, &json_path).unwrap();
+        let mut json = BufReader::new(File::open(&json_path).unwrap());
+        assert!(!binary::is_binary(&mut json).unwrap());
+        let json_trace = read_trace(&json_path).unwrap();
+        assert_eq!(json_trace.events.len(), trace.events.len());
+        let converted_path = dir.path().join("converted.trace");
+        convert(&json_path, &converted_path).unwrap();
+        assert_eq!(read_trace(&converted_path).unwrap(), json_trace);
+
+        assert!(read_trace(&dir.path().join("missing.trace")).is_err());
+    }
+}
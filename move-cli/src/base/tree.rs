@@ -0,0 +1,39 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::reroot_path;
+use clap::*;
+use move_package::{resolution::dependency_tree::DependencyTree, BuildConfig};
+use std::path::Path;
+
+/// Print the package's dependency graph, with where each dependency comes from and whether its
+/// declaration was overridden.
+#[derive(Parser)]
+#[clap(name = "tree")]
+pub struct Tree {
+    /// Print the graph as JSON
+    #[clap(long = "json", conflicts_with = "dot")]
+    pub json: bool,
+    /// Print the graph in the DOT language
+    #[clap(long = "dot")]
+    pub dot: bool,
+}
+
+impl Tree {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        // Progress goes to stderr to keep stdout parseable
+        let resolved_graph =
+            config.resolution_graph_for_package(&rerooted_path, None, &mut std::io::stderr())?;
+        let tree = DependencyTree::new(&resolved_graph)?;
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&tree)?);
+        } else if self.dot {
+            print!("{}", tree.to_dot());
+        } else {
+            println!("{}", tree.render());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::reroot_path;
+use clap::*;
+use move_package::BuildConfig;
+use std::path::Path;
+
+/// Copy the package's remote dependencies into its `vendor` directory and record their digests in
+/// `Move.lock`. Later builds use the vendored copies rather than fetching the dependencies, and
+/// fail if the copies do not match the recorded digests.
+#[derive(Parser)]
+#[clap(name = "vendor")]
+pub struct Vendor;
+
+impl Vendor {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        config.vendor_deps_for_package(&rerooted_path, &mut std::io::stdout())
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use super::reroot_path;
+use clap::*;
+use move_package::{resolution::dependency_tree::DependencyTree, BuildConfig};
+use move_symbol_pool::Symbol;
+use std::path::Path;
+
+/// Explain why a package is in the dependency graph: every path of dependencies that leads to it,
+/// and the override that decided where it comes from.
+#[derive(Parser)]
+#[clap(name = "why")]
+pub struct Why {
+    /// The package to explain, by its identifier or its name
+    pub package: String,
+    /// Print the paths as JSON
+    #[clap(long = "json", conflicts_with = "dot")]
+    pub json: bool,
+    /// Print the paths as a graph in the DOT language
+    #[clap(long = "dot")]
+    pub dot: bool,
+}
+
+impl Why {
+    pub fn execute(self, path: Option<&Path>, config: BuildConfig) -> anyhow::Result<()> {
+        let rerooted_path = reroot_path(path)?;
+        // Progress goes to stderr to keep stdout parseable
+        let resolved_graph =
+            config.resolution_graph_for_package(&rerooted_path, None, &mut std::io::stderr())?;
+        let tree = DependencyTree::new(&resolved_graph)?;
+        let paths = tree.why(Symbol::from(self.package.as_str()))?;
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&paths)?);
+        } else if self.dot {
+            print!("{}", tree.paths_to_dot(&paths));
+        } else {
+            print!("{}", tree.render_paths(&paths));
+        }
+        Ok(())
+    }
+}
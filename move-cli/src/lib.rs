@@ -4,8 +4,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base::{
-    build::Build, coverage::Coverage, disassemble::Disassemble, docgen::Docgen, info::Info,
-    migrate::Migrate, new::New, test::Test,
+    build::Build, check_upgrade::CheckUpgrade, coverage::Coverage, disassemble::Disassemble,
+    docgen::Docgen, fmt::Fmt, generate::Generate, info::Info, migrate::Migrate, new::New,
+    test::Test, trace::Trace, tree::Tree, vendor::Vendor, why::Why,
 };
 use move_package::BuildConfig;
 
@@ -58,13 +59,20 @@ pub struct MoveCLI {
 #[derive(Parser)]
 pub enum Command {
     Build(Build),
+    CheckUpgrade(CheckUpgrade),
     Coverage(Coverage),
     Disassemble(Disassemble),
     Docgen(Docgen),
+    Fmt(Fmt),
+    Generate(Generate),
     Info(Info),
     Migrate(Migrate),
     New(New),
     Test(Test),
+    Trace(Trace),
+    Tree(Tree),
+    Vendor(Vendor),
+    Why(Why),
     /// Execute a sandbox command.
     #[clap(name = "sandbox")]
     Sandbox {
@@ -88,6 +96,9 @@ pub fn run_cli(
     //         2. The CostTable only affects sandbox runs, but not unit tests, which use a unit cost table.
     match cmd {
         Command::Build(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
+        Command::CheckUpgrade(c) => {
+            c.execute(move_args.package_path.as_deref(), move_args.build_config)
+        }
         Command::Coverage(c) => {
             c.execute(move_args.package_path.as_deref(), move_args.build_config)
         }
@@ -95,6 +106,10 @@ pub fn run_cli(
             c.execute(move_args.package_path.as_deref(), move_args.build_config)
         }
         Command::Docgen(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
+        Command::Fmt(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
+        Command::Generate(c) => {
+            c.execute(move_args.package_path.as_deref(), move_args.build_config)
+        }
         Command::Info(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
         Command::Migrate(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
         Command::New(c) => c.execute_with_defaults(move_args.package_path.as_deref()),
@@ -104,6 +119,10 @@ pub fn run_cli(
             natives,
             Some(cost_table.clone()),
         ),
+        Command::Trace(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
+        Command::Tree(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
+        Command::Vendor(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
+        Command::Why(c) => c.execute(move_args.package_path.as_deref(), move_args.build_config),
         Command::Sandbox { storage_dir, cmd } => {
             cmd.handle_command(natives, cost_table, &move_args, &storage_dir)
         }
@@ -6,25 +6,21 @@
 use crate::{
     sandbox::{
         self,
-        utils::{on_disk_state_view::OnDiskStateView, PackageContext},
+        utils::{
+            on_disk_state_view::OnDiskStateView, ArgumentValue, FunctionArguments, PackageContext,
+        },
     },
     Move, NativeFunctionRecord, DEFAULT_BUILD_DIR,
 };
 use anyhow::Result;
 use clap::Parser;
-use move_core_types::parsing::values::ParsedValue;
-use move_core_types::{language_storage::TypeTag, transaction_argument::TransactionArgument};
+use move_core_types::language_storage::TypeTag;
 use move_package::compilation::package_layout::CompiledPackageLayout;
 use move_vm_test_utils::gas_schedule::CostTable;
 use std::{
     fs,
     path::{Path, PathBuf},
 };
-fn parse_transaction_argument(s: &str) -> Result<TransactionArgument> {
-    let x: ParsedValue<()> = ParsedValue::parse(s)?;
-    let move_value = x.into_concrete_value(&|_| None)?;
-    TransactionArgument::try_from(move_value)
-}
 
 #[derive(Parser)]
 pub enum SandboxCommand {
@@ -72,19 +68,27 @@ pub enum SandboxCommand {
         signers: Vec<String>,
         /// Possibly-empty list of arguments passed to the transaction (e.g., `i` in
         /// `main(i: u64)`). Must match the arguments types expected by `script_file`.
-        /// Supported argument types are
+        /// Supported arguments are
         /// bool literals (true, false),
-        /// u64 literals (e.g., 10, 58),
-        /// address literals (e.g., 0x12, 0x0000000000000000000000000000000f),
-        /// hexadecimal strings (e.g., x"0012" will parse as the vector<u8> value [00, 12]), and
-        /// ASCII strings (e.g., 'b"hi" will parse as the vector<u8> value [68, 69]).
+        /// integer literals (e.g., 10, 58u8, 0x2a),
+        /// address literals (e.g., @0x12, @std),
+        /// hexadecimal strings (e.g., x"0012" will parse as the vector<u8> value [00, 12]),
+        /// ASCII strings (e.g., b"hi" will parse as the vector<u8> value [68, 69]),
+        /// strings (e.g., "hi", also for `std::string::String` and `std::ascii::String`),
+        /// vectors (e.g., vector[1, 2]), options (e.g., none, some(1)), and
+        /// structs given their fields in order (e.g., struct(1, true)).
         #[clap(
             long = "args",
-            value_parser = parse_transaction_argument,
+            value_parser = ArgumentValue::parse,
             num_args(1..),
             action = clap::ArgAction::Append,
         )]
-        args: Vec<TransactionArgument>,
+        args: Vec<ArgumentValue>,
+        /// Path to a JSON file holding an array of the arguments passed to the transaction, instead
+        /// of `--args`. Integers may be given as strings, options as `null` or their value, and
+        /// structs as objects keyed by field names.
+        #[clap(long = "args-file", conflicts_with = "args")]
+        args_file: Option<PathBuf>,
         /// Possibly-empty list of type arguments passed to the transaction (e.g., `T` in
         /// `main<T>()`). Must match the type arguments kinds expected by `script_file`.
         #[clap(
@@ -98,8 +102,8 @@ pub enum SandboxCommand {
         /// By default, no `gas-budget` is specified and gas metering is disabled.
         #[clap(long = "gas-budget", short = 'g')]
         gas_budget: Option<u64>,
-        /// If set, the effects of executing `script_file` (i.e., published, updated, and
-        /// deleted resources) will NOT be committed to disk.
+        /// If set, the effects of executing `script_file` (i.e., created, modified, and
+        /// deleted data) are explained but NOT committed to disk.
         #[clap(long = "dry-run", short = 'n')]
         dry_run: bool,
     },
@@ -121,6 +125,13 @@ pub enum SandboxCommand {
         /// Path to a resource, events file, or module stored on disk.
         #[clap(name = "file")]
         file: PathBuf,
+        /// Print the summary of a module given by `--abi` as JSON.
+        #[clap(long = "json", requires = "abi")]
+        json: bool,
+        /// Summarize a module's datatypes and public, package, and entry functions instead of
+        /// disassembling it.
+        #[clap(long = "abi")]
+        abi: bool,
     },
     /// Delete all resources, events, and modules stored on disk under `storage-dir`.
     /// Does *not* delete anything in `src`.
@@ -220,10 +231,15 @@ impl SandboxCommand {
                 function_name,
                 signers,
                 args,
+                args_file,
                 type_args,
                 gas_budget,
                 dry_run,
             } => {
+                let args = match args_file {
+                    Some(path) => FunctionArguments::from_json_file(path)?,
+                    None => FunctionArguments::Parsed(args.clone()),
+                };
                 let context =
                     PackageContext::new(&move_args.package_path, &move_args.build_config)?;
                 let state = context.prepare_state(storage_dir)?;
@@ -254,10 +270,10 @@ impl SandboxCommand {
                 *use_temp_dir,
                 *track_cov,
             ),
-            SandboxCommand::View { file } => {
+            SandboxCommand::View { file, json, abi } => {
                 let state = PackageContext::new(&move_args.package_path, &move_args.build_config)?
                     .prepare_state(storage_dir)?;
-                sandbox::commands::view(&state, file)
+                sandbox::commands::view(&state, file, *json, *abi)
             }
             SandboxCommand::Clean {} => {
                 // delete storage
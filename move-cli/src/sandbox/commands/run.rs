@@ -5,8 +5,9 @@
 
 use crate::{
     sandbox::utils::{
-        contains_module, explain_execution_error, get_gas_status, is_bytecode_file,
-        on_disk_state_view::OnDiskStateView,
+        contains_module, explain_execution_effects, explain_execution_error, get_gas_status,
+        is_bytecode_file, maybe_commit_effects, on_disk_state_view::OnDiskStateView,
+        FunctionArguments,
     },
     NativeFunctionRecord,
 };
@@ -14,30 +15,29 @@ use anyhow::{anyhow, bail, Result};
 use move_binary_format::file_format::CompiledModule;
 use move_command_line_common::files::try_exists;
 use move_core_types::{
-    account_address::AccountAddress,
-    identifier::IdentStr,
-    language_storage::TypeTag,
+    account_address::AccountAddress, identifier::IdentStr, language_storage::TypeTag,
     runtime_value::MoveValue,
-    transaction_argument::{convert_txn_args, TransactionArgument},
 };
 use move_package::compilation::compiled_package::CompiledPackage;
+use move_symbol_pool::Symbol;
 use move_vm_runtime::move_vm::MoveVM;
 use move_vm_test_utils::gas_schedule::CostTable;
+use move_vm_types::loaded_data::runtime_types::Type;
 use std::{fs, path::Path};
 
 pub fn run(
     natives: impl IntoIterator<Item = NativeFunctionRecord>,
     cost_table: &CostTable,
     state: &OnDiskStateView,
-    _package: &CompiledPackage,
+    package: &CompiledPackage,
     module_file: &Path,
     function_name: &str,
     signers: &[String],
-    txn_args: &[TransactionArgument],
+    args: FunctionArguments,
     vm_type_tags: Vec<TypeTag>,
     gas_budget: Option<u64>,
-    _dry_run: bool,
-    _verbose: bool,
+    dry_run: bool,
+    verbose: bool,
 ) -> Result<()> {
     if !try_exists(module_file)? {
         bail!("Module file {:?} does not exist", module_file)
@@ -50,13 +50,15 @@ pub fn run(
         module_file
     );
     let bytecode = fs::read(module_file)?;
+    // script fun. parse module, extract script ID to pass to VM
+    let module = CompiledModule::deserialize_with_defaults(&bytecode)
+        .map_err(|e| anyhow!("Error deserializing module: {:?}", e))?;
+    let function_name = IdentStr::new(function_name)?;
 
     let signer_addresses = signers
         .iter()
         .map(|s| AccountAddress::from_hex_literal(s))
         .collect::<Result<Vec<AccountAddress>, _>>()?;
-    // TODO: parse Value's directly instead of going through the indirection of TransactionArgument?
-    let vm_args: Vec<Vec<u8>> = convert_txn_args(txn_args);
 
     let vm = MoveVM::new(natives).unwrap();
     let mut gas_status = get_gas_status(cost_table, gas_budget)?;
@@ -70,20 +72,60 @@ pub fn run(
         .map(|tag| session.load_type(tag))
         .collect::<Result<Vec<_>, _>>()?;
 
-    // TODO rethink move-cli arguments for executing functions
+    // check the signers and arguments against the signature of the function
+    let function =
+        match session.load_function(&module.self_id(), function_name, &script_type_arguments) {
+            Ok(function) => function,
+            Err(err) => {
+                return explain_execution_error(
+                    err,
+                    state,
+                    &script_type_parameters,
+                    &script_parameters,
+                    &vm_type_tags,
+                    &signer_addresses,
+                    &[],
+                )
+            }
+        };
+    let num_signers = function
+        .parameters
+        .iter()
+        .take_while(|ty| match ty {
+            Type::Reference(inner) => **inner == Type::Signer,
+            ty => **ty == Type::Signer,
+        })
+        .count();
+    if num_signers != signer_addresses.len() {
+        bail!(
+            "Function expected {} signer(s), but found {}",
+            num_signers,
+            signer_addresses.len()
+        )
+    }
+    let layouts = function.parameters[num_signers..]
+        .iter()
+        .map(|ty| match ty {
+            Type::Reference(ty) | Type::MutableReference(ty) => ty,
+            ty => ty,
+        })
+        .map(|ty| session.type_to_fully_annotated_layout(ty))
+        .collect::<Result<Vec<_>, _>>()?;
+    let named_addresses = &package.compiled_package_info.address_alias_instantiation;
+    let args = args.into_move_values(&layouts, &|name| {
+        named_addresses.get(&Symbol::from(name)).copied()
+    })?;
+
     let vm_args = signer_addresses
         .iter()
-        .map(|a| {
-            MoveValue::Signer(*a)
-                .simple_serialize()
+        .map(|a| MoveValue::Signer(*a))
+        .chain(args.iter().cloned())
+        .map(|v| {
+            v.simple_serialize()
                 .expect("transaction arguments must serialize")
         })
-        .chain(vm_args)
         .collect();
     let res = {
-        // script fun. parse module, extract script ID to pass to VM
-        let module = CompiledModule::deserialize_with_defaults(&bytecode)
-            .map_err(|e| anyhow!("Error deserializing module: {:?}", e))?;
         move_vm_profiler::tracing_feature_enabled! {
             use move_vm_profiler::GasProfiler;
             use move_vm_types::gas::GasMeter;
@@ -91,14 +133,14 @@ pub fn run(
             let gas_rem: u64 = gas_status.remaining_gas().into();
             gas_status.set_profiler(GasProfiler::init(
                 &session.vm_config().profiler_config,
-                function_name.to_owned(),
+                function_name.to_string(),
                 gas_rem,
             ));
         }
 
         session.execute_entry_function(
             &module.self_id(),
-            IdentStr::new(function_name)?,
+            function_name,
             script_type_arguments,
             vm_args,
             &mut gas_status,
@@ -113,10 +155,11 @@ pub fn run(
             &script_parameters,
             &vm_type_tags,
             &signer_addresses,
-            txn_args,
+            &args,
         )
     } else {
-        let _changeset = session.finish().0?;
-        Ok(())
+        let changeset = session.finish().0?;
+        explain_execution_effects(&changeset, gas_budget, &gas_status, verbose);
+        maybe_commit_effects(changeset, state, dry_run)
     }
 }
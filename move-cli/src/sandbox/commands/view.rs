@@ -7,13 +7,25 @@ use crate::sandbox::utils::{is_bytecode_file, on_disk_state_view::OnDiskStateVie
 
 use anyhow::{bail, Result};
 use std::path::Path;
-/// Print a module or resource stored in `file`
-pub fn view(_state: &OnDiskStateView, path: &Path) -> Result<()> {
+/// Print a module or resource stored in `file`. If `abi` is set, modules are summarized by their
+/// interface rather than disassembled, as JSON if `json` is set.
+pub fn view(_state: &OnDiskStateView, path: &Path, json: bool, abi: bool) -> Result<()> {
+    if json && !abi {
+        bail!("`--json` is only supported with `--abi`")
+    }
     if is_bytecode_file(path) {
-        let bytecode_opt = OnDiskStateView::view_module(path)?;
-        match bytecode_opt {
-            Some(bytecode) => println!("{}", bytecode),
-            None => println!("Bytecode not found."),
+        if abi {
+            match OnDiskStateView::view_module_abi(path)? {
+                Some(module) if json => println!("{}", serde_json::to_string_pretty(&module)?),
+                Some(module) => print!("{}", module),
+                None => println!("Bytecode not found."),
+            }
+        } else {
+            let bytecode_opt = OnDiskStateView::view_module(path)?;
+            match bytecode_opt {
+                Some(bytecode) => println!("{}", bytecode),
+                None => println!("Bytecode not found."),
+            }
         }
     } else {
         bail!("`move view <file>` must point to a valid file under storage")
@@ -0,0 +1,756 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, bail, Context, Result};
+use move_core_types::{
+    account_address::AccountAddress,
+    annotated_value::{MoveStructLayout, MoveTypeLayout},
+    i256::I256,
+    language_storage::TypeTag,
+    parsing::{
+        address::ParsedAddress,
+        parser::{parse_i256, parse_u256, Parser},
+        values::{ParsableValue, ParsedValue, ValueToken},
+    },
+    runtime_value::{MoveStruct, MoveValue},
+    u256::U256,
+};
+use std::path::Path;
+
+/// A value passed to a function with `--args`: any value accepted by `ParsedValue`, or `none` and
+/// `some(<value>)` for values of type `std::option::Option`.
+pub type ArgumentValue = ParsedValue<OptionValue>;
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum OptionValue {
+    None,
+    Some(Box<ArgumentValue>),
+}
+
+/// The arguments passed to a function, which are only given types when checked against its
+/// signature.
+#[derive(Debug, Clone)]
+pub enum FunctionArguments {
+    /// Arguments passed with `--args`
+    Parsed(Vec<ArgumentValue>),
+    /// Arguments passed with `--args-file`, as a JSON array
+    Json(Vec<serde_json::Value>),
+}
+
+impl ParsableValue for OptionValue {
+    type ConcreteValue = MoveValue;
+
+    fn parse_value<'a, I: Iterator<Item = (ValueToken, &'a str)>>(
+        parser: &mut Parser<'a, ValueToken, I>,
+    ) -> Option<Result<Self>> {
+        match parser.peek()? {
+            (ValueToken::Ident, "none") => {
+                parser.advance_any().unwrap();
+                Some(Ok(OptionValue::None))
+            }
+            (ValueToken::Ident, "some") => {
+                parser.advance_any().unwrap();
+                let value = (|| {
+                    parser.advance(ValueToken::LParen)?;
+                    let value = parser.parse_value()?;
+                    parser.advance(ValueToken::RParen)?;
+                    Ok(OptionValue::Some(Box::new(value)))
+                })();
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    fn move_value_into_concrete(v: MoveValue) -> Result<Self::ConcreteValue> {
+        Ok(v)
+    }
+
+    fn concrete_vector(elems: Vec<Self::ConcreteValue>) -> Result<Self::ConcreteValue> {
+        Ok(MoveValue::Vector(elems))
+    }
+
+    fn concrete_struct(values: Vec<Self::ConcreteValue>) -> Result<Self::ConcreteValue> {
+        Ok(MoveValue::Struct(MoveStruct(values)))
+    }
+
+    fn into_concrete_value(
+        self,
+        mapping: &impl Fn(&str) -> Option<AccountAddress>,
+    ) -> Result<Self::ConcreteValue> {
+        let elems = match self {
+            OptionValue::None => vec![],
+            OptionValue::Some(value) => vec![value.into_concrete_value(mapping)?],
+        };
+        Ok(MoveValue::Struct(MoveStruct(vec![MoveValue::Vector(
+            elems,
+        )])))
+    }
+}
+
+impl FunctionArguments {
+    /// Reads arguments from a file holding a JSON array of values.
+    pub fn from_json_file(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Unable to read arguments from {}", path.display()))?;
+        match serde_json::from_str(&json)? {
+            serde_json::Value::Array(values) => Ok(FunctionArguments::Json(values)),
+            _ => bail!("Expected a JSON array of arguments in {}", path.display()),
+        }
+    }
+
+    /// Converts the arguments to values of the types of the parameters they are passed for, given
+    /// by their layouts. Named addresses are resolved with `mapping`.
+    pub fn into_move_values(
+        self,
+        layouts: &[MoveTypeLayout],
+        mapping: &impl Fn(&str) -> Option<AccountAddress>,
+    ) -> Result<Vec<MoveValue>> {
+        let num_args = match &self {
+            FunctionArguments::Parsed(values) => values.len(),
+            FunctionArguments::Json(values) => values.len(),
+        };
+        if num_args != layouts.len() {
+            bail!(
+                "Function expected {} argument(s), but found {}",
+                layouts.len(),
+                num_args
+            )
+        }
+        let values: Vec<Result<MoveValue>> = match self {
+            FunctionArguments::Parsed(values) => values
+                .into_iter()
+                .zip(layouts)
+                .map(|(value, layout)| parsed_to_move_value(value, layout, mapping))
+                .collect(),
+            FunctionArguments::Json(values) => values
+                .iter()
+                .zip(layouts)
+                .map(|(value, layout)| json_to_move_value(value, layout, mapping))
+                .collect(),
+        };
+        values
+            .into_iter()
+            .enumerate()
+            .map(|(idx, value)| value.with_context(|| format!("Invalid argument #{}", idx + 1)))
+            .collect()
+    }
+}
+
+/// Kinds of structs that are passed differently from other structs.
+enum SpecialStruct {
+    Utf8String,
+    AsciiString,
+    Option,
+}
+
+fn special_struct(layout: &MoveStructLayout) -> Option<SpecialStruct> {
+    let tag = &layout.type_;
+    if tag.address != AccountAddress::ONE {
+        return None;
+    }
+    match (tag.module.as_str(), tag.name.as_str()) {
+        ("string", "String") => Some(SpecialStruct::Utf8String),
+        ("ascii", "String") => Some(SpecialStruct::AsciiString),
+        ("option", "Option") => Some(SpecialStruct::Option),
+        _ => None,
+    }
+}
+
+/// Returns the layout of the elements of an `Option` (stored in its only field, a vector).
+fn option_element_layout(layout: &MoveStructLayout) -> Result<&MoveTypeLayout> {
+    match layout.fields.as_slice() {
+        [field] => match &field.layout {
+            MoveTypeLayout::Vector(elem) => Ok(elem),
+            _ => bail!("Unexpected layout for {}", layout.type_),
+        },
+        _ => bail!("Unexpected layout for {}", layout.type_),
+    }
+}
+
+fn string_value(kind: SpecialStruct, bytes: Vec<u8>) -> Result<MoveValue> {
+    match kind {
+        SpecialStruct::Utf8String if std::str::from_utf8(&bytes).is_err() => {
+            bail!("Invalid UTF-8 string")
+        }
+        SpecialStruct::AsciiString if !bytes.is_ascii() => bail!("Invalid ASCII string"),
+        _ => Ok(MoveValue::Struct(MoveStruct(vec![MoveValue::Vector(
+            bytes.into_iter().map(MoveValue::U8).collect(),
+        )]))),
+    }
+}
+
+fn option_value(elem: Option<MoveValue>) -> MoveValue {
+    MoveValue::Struct(MoveStruct(vec![MoveValue::Vector(
+        elem.into_iter().collect(),
+    )]))
+}
+
+/// Converts an integer to a value of the integer type given by `layout`, if it fits. `suffix` is
+/// the type of the integer if it was given one (e.g. `1u8`), which must be the same.
+fn integer_value(
+    value: U256,
+    suffix: Option<MoveTypeLayout>,
+    layout: &MoveTypeLayout,
+) -> Result<MoveValue> {
+    if let Some(suffix) = suffix {
+        if &suffix != layout {
+            bail!(
+                "Expected a value of type {}, found a {}",
+                TypeTag::from(layout),
+                suffix
+            )
+        }
+    }
+    let out_of_range = || {
+        anyhow!(
+            "Integer {} does not fit in a {}",
+            value,
+            TypeTag::from(layout)
+        )
+    };
+    Ok(match layout {
+        MoveTypeLayout::U8 => MoveValue::U8(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::U16 => MoveValue::U16(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::U32 => MoveValue::U32(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::U64 => MoveValue::U64(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::U128 => MoveValue::U128(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::U256 => MoveValue::U256(value),
+        MoveTypeLayout::I8
+        | MoveTypeLayout::I16
+        | MoveTypeLayout::I32
+        | MoveTypeLayout::I64
+        | MoveTypeLayout::I128
+        | MoveTypeLayout::I256 => {
+            let value = I256::try_from(value).map_err(|_| out_of_range())?;
+            signed_integer_value(value, layout)?
+        }
+        _ => bail!(
+            "Expected a value of type {}, found an integer",
+            TypeTag::from(layout)
+        ),
+    })
+}
+
+/// Converts a signed integer to a value of the signed integer type given by `layout`, if it fits.
+fn signed_integer_value(value: I256, layout: &MoveTypeLayout) -> Result<MoveValue> {
+    let out_of_range = || {
+        anyhow!(
+            "Integer {} does not fit in a {}",
+            value,
+            TypeTag::from(layout)
+        )
+    };
+    Ok(match layout {
+        MoveTypeLayout::I8 => MoveValue::I8(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::I16 => MoveValue::I16(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::I32 => MoveValue::I32(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::I64 => MoveValue::I64(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::I128 => MoveValue::I128(value.try_into().map_err(|_| out_of_range())?),
+        MoveTypeLayout::I256 => MoveValue::I256(value),
+        _ => bail!(
+            "Expected a value of type {}, found a signed integer",
+            TypeTag::from(layout)
+        ),
+    })
+}
+
+fn parsed_to_move_value(
+    value: ArgumentValue,
+    layout: &MoveTypeLayout,
+    mapping: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<MoveValue> {
+    use MoveTypeLayout as L;
+    use ParsedValue as V;
+    Ok(match (value, layout) {
+        (V::InferredNum(n), _) => integer_value(n, None, layout)?,
+        (V::U8(n), _) => integer_value(n.into(), Some(L::U8), layout)?,
+        (V::U16(n), _) => integer_value(n.into(), Some(L::U16), layout)?,
+        (V::U32(n), _) => integer_value(n.into(), Some(L::U32), layout)?,
+        (V::U64(n), _) => integer_value(n.into(), Some(L::U64), layout)?,
+        (V::U128(n), _) => integer_value(n.into(), Some(L::U128), layout)?,
+        (V::U256(n), _) => integer_value(n, Some(L::U256), layout)?,
+        (V::I8(n), L::I8) => MoveValue::I8(n),
+        (V::I16(n), L::I16) => MoveValue::I16(n),
+        (V::I32(n), L::I32) => MoveValue::I32(n),
+        (V::I64(n), L::I64) => MoveValue::I64(n),
+        (V::I128(n), L::I128) => MoveValue::I128(n),
+        (V::I256(n), L::I256) => MoveValue::I256(n),
+        (V::Bool(b), L::Bool) => MoveValue::Bool(b),
+        (V::Address(addr), L::Address) => MoveValue::Address(addr.into_account_address(mapping)?),
+        (V::Vector(elems), L::Vector(elem_layout)) => MoveValue::Vector(
+            elems
+                .into_iter()
+                .map(|elem| parsed_to_move_value(elem, elem_layout, mapping))
+                .collect::<Result<_>>()?,
+        ),
+        (value, L::Struct(struct_layout)) => match (special_struct(struct_layout), value) {
+            (
+                Some(kind @ (SpecialStruct::Utf8String | SpecialStruct::AsciiString)),
+                V::Vector(bytes),
+            ) => {
+                let bytes = bytes
+                    .into_iter()
+                    .map(|byte| match byte {
+                        V::U8(byte) => Ok(byte),
+                        _ => Err(anyhow!("Expected a string for a {}", struct_layout.type_)),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                string_value(kind, bytes)?
+            }
+            (Some(SpecialStruct::Option), V::Custom(option)) => {
+                let elem_layout = option_element_layout(struct_layout)?;
+                option_value(match option {
+                    OptionValue::None => None,
+                    OptionValue::Some(value) => {
+                        Some(parsed_to_move_value(*value, elem_layout, mapping)?)
+                    }
+                })
+            }
+            (_, V::Struct(fields)) => {
+                if fields.len() != struct_layout.fields.len() {
+                    bail!(
+                        "Expected {} field(s) for a {}, found {}",
+                        struct_layout.fields.len(),
+                        struct_layout.type_,
+                        fields.len()
+                    )
+                }
+                MoveValue::Struct(MoveStruct(
+                    fields
+                        .into_iter()
+                        .zip(&struct_layout.fields)
+                        .map(|(value, field)| {
+                            parsed_to_move_value(value, &field.layout, mapping)
+                                .with_context(|| format!("Invalid field {}", field.name))
+                        })
+                        .collect::<Result<_>>()?,
+                ))
+            }
+            _ => bail!("Expected a value of type {}", struct_layout.type_),
+        },
+        (V::Custom(_), _) => bail!(
+            "Expected a value of type {}, found an option",
+            TypeTag::from(layout)
+        ),
+        _ => bail!("Expected a value of type {}", TypeTag::from(layout)),
+    })
+}
+
+fn json_to_move_value(
+    value: &serde_json::Value,
+    layout: &MoveTypeLayout,
+    mapping: &impl Fn(&str) -> Option<AccountAddress>,
+) -> Result<MoveValue> {
+    use serde_json::Value as J;
+    use MoveTypeLayout as L;
+    Ok(match (value, layout) {
+        (J::Number(_) | J::String(_), L::U8 | L::U16 | L::U32 | L::U64 | L::U128 | L::U256) => {
+            // large integers may not be represented exactly as JSON numbers, so they are also
+            // accepted as strings
+            let digits = match value {
+                J::String(s) => s.clone(),
+                _ => value.to_string(),
+            };
+            let (n, _) = parse_u256(&digits).map_err(|_| anyhow!("Invalid integer {}", value))?;
+            integer_value(n, None, layout)?
+        }
+        (J::Number(_) | J::String(_), L::I8 | L::I16 | L::I32 | L::I64 | L::I128 | L::I256) => {
+            let digits = match value {
+                J::String(s) => s.clone(),
+                _ => value.to_string(),
+            };
+            let (n, _) = parse_i256(&digits).map_err(|_| anyhow!("Invalid integer {}", value))?;
+            signed_integer_value(n, layout)?
+        }
+        (J::Bool(b), L::Bool) => MoveValue::Bool(*b),
+        (J::String(s), L::Address) => {
+            MoveValue::Address(ParsedAddress::parse(s)?.into_account_address(mapping)?)
+        }
+        (J::Array(elems), L::Vector(elem_layout)) => MoveValue::Vector(
+            elems
+                .iter()
+                .map(|elem| json_to_move_value(elem, elem_layout, mapping))
+                .collect::<Result<_>>()?,
+        ),
+        (value, L::Struct(struct_layout)) => match (special_struct(struct_layout), value) {
+            (
+                Some(kind @ (SpecialStruct::Utf8String | SpecialStruct::AsciiString)),
+                J::String(s),
+            ) => string_value(kind, s.as_bytes().to_vec())?,
+            (Some(SpecialStruct::Option), value) => {
+                let elem_layout = option_element_layout(struct_layout)?;
+                option_value(match value {
+                    J::Null => None,
+                    value => Some(json_to_move_value(value, elem_layout, mapping)?),
+                })
+            }
+            (_, J::Object(fields)) => {
+                if let Some(name) = fields.keys().find(|name| {
+                    !struct_layout
+                        .fields
+                        .iter()
+                        .any(|f| f.name.as_str() == *name)
+                }) {
+                    bail!("Unknown field {} for a {}", name, struct_layout.type_)
+                }
+                MoveValue::Struct(MoveStruct(
+                    struct_layout
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let value = fields.get(field.name.as_str()).ok_or_else(|| {
+                                anyhow!(
+                                    "Missing field {} for a {}",
+                                    field.name,
+                                    struct_layout.type_
+                                )
+                            })?;
+                            json_to_move_value(value, &field.layout, mapping)
+                                .with_context(|| format!("Invalid field {}", field.name))
+                        })
+                        .collect::<Result<_>>()?,
+                ))
+            }
+            _ => bail!("Expected a value of type {}", struct_layout.type_),
+        },
+        _ => bail!("Expected a value of type {}", TypeTag::from(layout)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgumentValue, FunctionArguments};
+    use move_core_types::{
+        account_address::AccountAddress,
+        annotated_value::{MoveFieldLayout, MoveStructLayout, MoveTypeLayout as L},
+        identifier::Identifier,
+        language_storage::StructTag,
+        runtime_value::{MoveStruct, MoveValue as V},
+        u256::U256,
+    };
+    use serde_json::json;
+
+    const ALICE: AccountAddress = AccountAddress::TWO;
+
+    fn mapping(name: &str) -> Option<AccountAddress> {
+        (name == "alice").then_some(ALICE)
+    }
+
+    fn struct_layout(
+        address: AccountAddress,
+        module: &str,
+        name: &str,
+        fields: Vec<(&str, L)>,
+    ) -> L {
+        let tag = StructTag {
+            address,
+            module: Identifier::new(module).unwrap(),
+            name: Identifier::new(name).unwrap(),
+            type_params: vec![],
+        };
+        let fields = fields
+            .into_iter()
+            .map(|(name, layout)| MoveFieldLayout::new(Identifier::new(name).unwrap(), layout))
+            .collect();
+        L::Struct(Box::new(MoveStructLayout::new(tag, fields)))
+    }
+
+    fn utf8_string() -> L {
+        struct_layout(
+            AccountAddress::ONE,
+            "string",
+            "String",
+            vec![("bytes", L::Vector(Box::new(L::U8)))],
+        )
+    }
+
+    fn ascii_string() -> L {
+        struct_layout(
+            AccountAddress::ONE,
+            "ascii",
+            "String",
+            vec![("bytes", L::Vector(Box::new(L::U8)))],
+        )
+    }
+
+    fn option(elem: L) -> L {
+        struct_layout(
+            AccountAddress::ONE,
+            "option",
+            "Option",
+            vec![("vec", L::Vector(Box::new(elem)))],
+        )
+    }
+
+    fn point() -> L {
+        struct_layout(
+            AccountAddress::TWO,
+            "m",
+            "Point",
+            vec![("x", L::U64), ("y", L::I8)],
+        )
+    }
+
+    fn bytes(s: &str) -> V {
+        V::Struct(MoveStruct(vec![V::Vector(s.bytes().map(V::U8).collect())]))
+    }
+
+    fn from_args(args: &[&str], layouts: &[L]) -> anyhow::Result<Vec<V>> {
+        let args = args
+            .iter()
+            .map(|arg| ArgumentValue::parse(arg))
+            .collect::<anyhow::Result<_>>()?;
+        FunctionArguments::Parsed(args).into_move_values(layouts, &mapping)
+    }
+
+    fn from_json(args: serde_json::Value, layouts: &[L]) -> anyhow::Result<Vec<V>> {
+        let serde_json::Value::Array(args) = args else {
+            panic!("expected an array of arguments")
+        };
+        FunctionArguments::Json(args).into_move_values(layouts, &mapping)
+    }
+
+    fn error(result: anyhow::Result<Vec<V>>) -> String {
+        format!("{:#}", result.unwrap_err())
+    }
+
+    #[test]
+    fn parsed_integers() {
+        let values = from_args(
+            &["1", "2u16", "300", "-5i8", "7", "-7i128"],
+            &[L::U8, L::U16, L::U256, L::I8, L::I64, L::I128],
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                V::U8(1),
+                V::U16(2),
+                V::U256(U256::from(300u64)),
+                V::I8(-5),
+                V::I64(7),
+                V::I128(-7),
+            ]
+        );
+
+        assert_eq!(
+            error(from_args(&["256"], &[L::U8])),
+            "Invalid argument #1: Integer 256 does not fit in a u8"
+        );
+        assert_eq!(
+            error(from_args(&["128"], &[L::I8])),
+            "Invalid argument #1: Integer 128 does not fit in a i8"
+        );
+        assert_eq!(
+            error(from_args(&["true", "1u8"], &[L::Bool, L::U64])),
+            "Invalid argument #2: Expected a value of type u64, found a u8"
+        );
+        assert_eq!(
+            error(from_args(&["1"], &[L::Bool])),
+            "Invalid argument #1: Expected a value of type bool, found an integer"
+        );
+        // negative numbers must be given a signed type
+        assert!(ArgumentValue::parse("-7").is_err());
+    }
+
+    #[test]
+    fn parsed_addresses() {
+        let values = from_args(&["@alice", "@0x1"], &[L::Address, L::Address]).unwrap();
+        assert_eq!(
+            values,
+            vec![V::Address(ALICE), V::Address(AccountAddress::ONE)]
+        );
+        assert!(from_args(&["@bob"], &[L::Address]).is_err());
+    }
+
+    #[test]
+    fn parsed_vectors() {
+        let values = from_args(
+            &[
+                "vector[1, 2, 3]",
+                "vector[vector[true], vector[]]",
+                "b\"hi\"",
+            ],
+            &[
+                L::Vector(Box::new(L::U32)),
+                L::Vector(Box::new(L::Vector(Box::new(L::Bool)))),
+                L::Vector(Box::new(L::U8)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                V::Vector(vec![V::U32(1), V::U32(2), V::U32(3)]),
+                V::Vector(vec![V::Vector(vec![V::Bool(true)]), V::Vector(vec![])]),
+                V::Vector(vec![V::U8(b'h'), V::U8(b'i')]),
+            ]
+        );
+        assert_eq!(
+            error(from_args(
+                &["vector[1, 1000]"],
+                &[L::Vector(Box::new(L::U8))]
+            )),
+            "Invalid argument #1: Integer 1000 does not fit in a u8"
+        );
+    }
+
+    #[test]
+    fn parsed_structs() {
+        let values = from_args(
+            &[
+                "struct(1, -1i8)",
+                "\"hello\"",
+                "\"ascii\"",
+                "none",
+                "some(@alice)",
+            ],
+            &[
+                point(),
+                utf8_string(),
+                ascii_string(),
+                option(L::U64),
+                option(L::Address),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                V::Struct(MoveStruct(vec![V::U64(1), V::I8(-1)])),
+                bytes("hello"),
+                bytes("ascii"),
+                V::Struct(MoveStruct(vec![V::Vector(vec![])])),
+                V::Struct(MoveStruct(vec![V::Vector(vec![V::Address(ALICE)])])),
+            ]
+        );
+
+        assert_eq!(
+            error(from_args(&["struct(1)"], &[point()])),
+            "Invalid argument #1: Expected 2 field(s) for a 0x2::m::Point, found 1"
+        );
+        assert_eq!(
+            error(from_args(&["struct(1, 1u8)"], &[point()])),
+            "Invalid argument #1: Invalid field y: Expected a value of type i8, found a u8"
+        );
+        assert_eq!(
+            error(from_args(&["\"\u{e9}\""], &[ascii_string()])),
+            "Invalid argument #1: Invalid ASCII string"
+        );
+        assert_eq!(
+            error(from_args(&["x\"ff\""], &[utf8_string()])),
+            "Invalid argument #1: Invalid UTF-8 string"
+        );
+        assert!(from_args(&["some(1)"], &[L::U64]).is_err());
+    }
+
+    #[test]
+    fn argument_count() {
+        assert_eq!(
+            error(from_args(&["1"], &[L::U64, L::U64])),
+            "Function expected 2 argument(s), but found 1"
+        );
+        assert_eq!(
+            error(from_json(json!([1, 2]), &[L::U64])),
+            "Function expected 1 argument(s), but found 2"
+        );
+    }
+
+    #[test]
+    fn json_values() {
+        let values = from_json(
+            json!([
+                255,
+                "340282366920938463463374607431768211455",
+                -3,
+                "0x2",
+                "alice",
+                [true, false],
+                {"y": "-128", "x": 5},
+                "hello",
+                null,
+                7,
+            ]),
+            &[
+                L::U8,
+                L::U128,
+                L::I16,
+                L::Address,
+                L::Address,
+                L::Vector(Box::new(L::Bool)),
+                point(),
+                utf8_string(),
+                option(L::U64),
+                option(L::U64),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                V::U8(255),
+                V::U128(u128::MAX),
+                V::I16(-3),
+                V::Address(AccountAddress::TWO),
+                V::Address(ALICE),
+                V::Vector(vec![V::Bool(true), V::Bool(false)]),
+                V::Struct(MoveStruct(vec![V::U64(5), V::I8(-128)])),
+                bytes("hello"),
+                V::Struct(MoveStruct(vec![V::Vector(vec![])])),
+                V::Struct(MoveStruct(vec![V::Vector(vec![V::U64(7)])])),
+            ]
+        );
+    }
+
+    #[test]
+    fn json_errors() {
+        assert_eq!(
+            error(from_json(json!([256]), &[L::U8])),
+            "Invalid argument #1: Integer 256 does not fit in a u8"
+        );
+        assert_eq!(
+            error(from_json(json!([-129]), &[L::I8])),
+            "Invalid argument #1: Integer -129 does not fit in a i8"
+        );
+        assert_eq!(
+            error(from_json(json!([1.5]), &[L::U64])),
+            "Invalid argument #1: Invalid integer 1.5"
+        );
+        assert_eq!(
+            error(from_json(json!([{"x": 1, "y": 2, "z": 3}]), &[point()])),
+            "Invalid argument #1: Unknown field z for a 0x2::m::Point"
+        );
+        assert_eq!(
+            error(from_json(json!([{"x": 1}]), &[point()])),
+            "Invalid argument #1: Missing field y for a 0x2::m::Point"
+        );
+        assert_eq!(
+            error(from_json(json!([{"x": 1, "y": 200}]), &[point()])),
+            "Invalid argument #1: Invalid field y: Integer 200 does not fit in a i8"
+        );
+        assert_eq!(
+            error(from_json(json!(["\u{e9}"]), &[ascii_string()])),
+            "Invalid argument #1: Invalid ASCII string"
+        );
+        assert!(from_json(json!(["bob"]), &[L::Address]).is_err());
+    }
+
+    #[test]
+    fn json_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("args.json");
+
+        std::fs::write(&path, "[1, \"two\"]").unwrap();
+        let FunctionArguments::Json(values) = FunctionArguments::from_json_file(&path).unwrap()
+        else {
+            panic!("expected JSON arguments")
+        };
+        assert_eq!(values, vec![json!(1), json!("two")]);
+
+        std::fs::write(&path, "{\"x\": 1}").unwrap();
+        assert!(FunctionArguments::from_json_file(&path).is_err());
+        assert!(FunctionArguments::from_json_file(&dir.path().join("missing.json")).is_err());
+    }
+}
@@ -23,7 +23,7 @@ use move_core_types::{
     account_address::AccountAddress,
     effects::{ChangeSet, Op},
     language_storage::{ModuleId, TypeTag},
-    transaction_argument::TransactionArgument,
+    runtime_value::MoveValue,
     vm_status::{StatusCode, StatusType},
 };
 use move_ir_types::location::Loc;
@@ -36,9 +36,11 @@ use std::{
     sync::Arc,
 };
 
+pub mod arguments;
 pub mod on_disk_state_view;
 pub mod package_context;
 
+pub use arguments::*;
 use move_bytecode_utils::module_cache::GetModule;
 use move_vm_test_utils::gas_schedule::{CostTable, GasStatus};
 pub use on_disk_state_view::*;
@@ -93,10 +95,90 @@ pub(crate) fn explain_publish_changeset(changeset: &ChangeSet) {
     )
 }
 
+/// Explain the effects of executing a function: the modules it created, modified and deleted
+/// (each of them if `verbose` is set), and the gas it used out of `gas_budget` (if any).
+pub(crate) fn explain_execution_effects(
+    changeset: &ChangeSet,
+    gas_budget: Option<u64>,
+    gas_status: &GasStatus,
+    verbose: bool,
+) {
+    let (mut created, mut modified, mut deleted) = (0, 0, 0);
+    for (addr, name, op) in changeset.modules() {
+        let module_id = ModuleId::new(addr, name.clone());
+        match op {
+            Op::New(module_bytes) => {
+                created += 1;
+                if verbose {
+                    println!(
+                        "Created module {} ({:?} bytes)",
+                        module_id,
+                        module_bytes.len()
+                    );
+                }
+            }
+            Op::Modify(module_bytes) => {
+                modified += 1;
+                if verbose {
+                    println!(
+                        "Modified module {} ({:?} bytes)",
+                        module_id,
+                        module_bytes.len()
+                    );
+                }
+            }
+            Op::Delete => {
+                deleted += 1;
+                if verbose {
+                    println!("Deleted module {}", module_id);
+                }
+            }
+        }
+    }
+    println!(
+        "Effects: {} created, {} modified, {} deleted",
+        created, modified, deleted
+    );
+    match gas_budget {
+        Some(gas_budget) => {
+            let gas_left: u64 = gas_status.remaining_gas().into();
+            println!(
+                "Gas used: {} (budget: {})",
+                gas_budget.saturating_sub(gas_left),
+                gas_budget
+            )
+        }
+        None => println!("Gas used: unmetered (no gas budget set)"),
+    }
+}
+
+/// Write the effects of executing a function to disk, unless `dry_run` is set.
+pub(crate) fn maybe_commit_effects(
+    changeset: ChangeSet,
+    state: &OnDiskStateView,
+    dry_run: bool,
+) -> Result<()> {
+    if dry_run {
+        if !changeset.accounts().is_empty() {
+            println!("Discarding changes; re-run without --dry-run to commit them.");
+        }
+        return Ok(());
+    }
+    for (module_id, op) in changeset.into_modules() {
+        match op {
+            Op::New(module_bytes) | Op::Modify(module_bytes) => {
+                state.save_module(&module_id, &module_bytes)?
+            }
+            Op::Delete => state.delete_module(&module_id)?,
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn explain_type_error(
     script_params: &[SignatureToken],
     signers: &[AccountAddress],
-    txn_args: &[TransactionArgument],
+    args: &[MoveValue],
 ) {
     use SignatureToken::*;
     let expected_num_signers = script_params
@@ -118,12 +200,12 @@ pub(crate) fn explain_type_error(
 
     // TODO: printing type(s) of missing arguments could be useful
     let expected_num_args = script_params.len() - signers.len();
-    if expected_num_args != txn_args.len() {
+    if expected_num_args != args.len() {
         println!(
             "Execution failed with incorrect number of arguments: script expected {:?}, but found \
              {:?}",
             expected_num_args,
-            txn_args.len()
+            args.len()
         );
         return;
     }
@@ -280,7 +362,7 @@ pub(crate) fn explain_execution_error(
     script_parameters: &[SignatureToken],
     vm_type_args: &[TypeTag],
     signers: &[AccountAddress],
-    txn_args: &[TransactionArgument],
+    args: &[MoveValue],
 ) -> Result<()> {
     use StatusCode::*;
     match (error.location(), error.major_status(), error.sub_status()) {
@@ -339,7 +421,7 @@ pub(crate) fn explain_execution_error(
             script_type_parameters.len(),
             vm_type_args.len()
         ),
-        (_, TYPE_MISMATCH, _) => explain_type_error(script_parameters, signers, txn_args),
+        (_, TYPE_MISMATCH, _) => explain_type_error(script_parameters, signers, args),
         (_, LINKER_ERROR, _) => {
             // TODO: is this the only reason we can see LINKER_ERROR?
             // Can we also see it if someone manually deletes modules in storage?
@@ -370,3 +452,52 @@ pub(crate) fn contains_module(path: &Path) -> bool {
             Err(_) => false,
         }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{maybe_commit_effects, on_disk_state_view::OnDiskStateView};
+    use move_core_types::{
+        account_address::AccountAddress,
+        effects::{ChangeSet, Op},
+        identifier::Identifier,
+        language_storage::ModuleId,
+    };
+
+    fn changeset(module_id: &ModuleId, op: Op<Vec<u8>>) -> ChangeSet {
+        let mut changeset = ChangeSet::new();
+        changeset.add_module_op(module_id.clone(), op).unwrap();
+        changeset
+    }
+
+    /// Returns the bytes of the modules saved on disk.
+    fn saved_modules(state: &OnDiskStateView) -> Vec<Vec<u8>> {
+        state
+            .module_paths()
+            .map(|path| std::fs::read(path).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn effects_committed_unless_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let state =
+            OnDiskStateView::create(dir.path().join("build"), dir.path().join("storage")).unwrap();
+        let module_id = ModuleId::new(AccountAddress::TWO, Identifier::new("m").unwrap());
+
+        for (op, dry_run_modules, modules) in [
+            (Op::New(vec![1]), vec![], vec![vec![1]]),
+            (Op::Modify(vec![2]), vec![vec![1]], vec![vec![2]]),
+            (Op::Delete, vec![vec![2]], vec![]),
+        ] {
+            maybe_commit_effects(changeset(&module_id, op.clone()), &state, true).unwrap();
+            assert_eq!(saved_modules(&state), dry_run_modules, "dry run of {op:?}");
+            maybe_commit_effects(changeset(&module_id, op.clone()), &state, false).unwrap();
+            assert_eq!(saved_modules(&state), modules, "commit of {op:?}");
+            assert_eq!(state.has_module(&module_id), !modules.is_empty());
+        }
+
+        // empty changesets leave the storage as it is
+        maybe_commit_effects(ChangeSet::new(), &state, false).unwrap();
+        assert!(saved_modules(&state).is_empty());
+    }
+}
@@ -16,6 +16,7 @@ use move_core_types::{
 };
 use move_disassembler::disassembler::Disassembler;
 use move_ir_types::location::Spanned;
+use move_package::compilation::module_abi::ModuleAbi;
 use std::{
     fs,
     path::{Path, PathBuf},
@@ -141,15 +142,22 @@ impl OnDiskStateView {
         })
     }
 
-    fn view_bytecode(path: &Path) -> Result<Option<String>> {
+    fn read_module(path: &Path) -> Result<Option<CompiledModule>> {
         if path.is_dir() {
             bail!("Bad bytecode path {:?}. Needed file, found directory", path)
         }
 
-        Ok(match Self::get_bytes(path)? {
-            Some(bytes) => {
-                let module = CompiledModule::deserialize_with_defaults(&bytes)
-                    .map_err(|e| anyhow!("Failure deserializing module: {:?}", e))?;
+        Self::get_bytes(path)?
+            .map(|bytes| {
+                CompiledModule::deserialize_with_defaults(&bytes)
+                    .map_err(|e| anyhow!("Failure deserializing module: {:?}", e))
+            })
+            .transpose()
+    }
+
+    fn view_bytecode(path: &Path) -> Result<Option<String>> {
+        Ok(match Self::read_module(path)? {
+            Some(module) => {
                 // TODO: find or create source map and pass it to disassembler
                 let d: Disassembler =
                     Disassembler::from_module(&module, Spanned::unsafe_no_loc(()).loc)?;
@@ -163,6 +171,11 @@ impl OnDiskStateView {
         Self::view_bytecode(module_path)
     }
 
+    /// Read the module at `module_path` and summarize its interface
+    pub fn view_module_abi(module_path: &Path) -> Result<Option<ModuleAbi>> {
+        Ok(Self::read_module(module_path)?.map(|module| ModuleAbi::from_compiled(&module)))
+    }
+
     /// Save `module` on disk under the path `module.address()`/`module.name()`
     pub fn save_module(&self, module_id: &ModuleId, module_bytes: &[u8]) -> Result<()> {
         let path = self.get_module_path(module_id);
@@ -262,3 +275,74 @@ impl Default for OnDiskStateView {
             .expect("Failure creating OnDiskStateView")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::OnDiskStateView;
+    use move_binary_format::CompiledModule;
+    use move_core_types::parsing::address::NumericalAddress;
+    use std::collections::BTreeMap;
+
+    const SOURCE: &str = r#"
+        module 0x2::m {
+            public struct Coin<phantom T> has store { value: u64 }
+            public enum Shape has copy, drop { Circle { r: u8 }, Square { side: u8 } }
+            public fun value<T>(coin: &Coin<T>): u64 { coin.value }
+            public(package) fun split(x: u64): (u64, u64) { (x / 2, x - x / 2) }
+            entry fun run() {}
+            fun hidden() {}
+        }
+    "#;
+
+    fn compile(dir: &std::path::Path, source: &str) -> CompiledModule {
+        let path = dir.join("m.move");
+        std::fs::write(&path, source).unwrap();
+        let (_, units) = move_compiler::Compiler::from_files(
+            None,
+            vec![path.to_str().unwrap()],
+            vec![],
+            BTreeMap::<&str, NumericalAddress>::new(),
+        )
+        .build_and_report()
+        .unwrap();
+        let [unit] = &units[..] else {
+            panic!("expected a single module")
+        };
+        unit.named_module.module.clone()
+    }
+
+    #[test]
+    fn view_module_abi() {
+        let dir = tempfile::tempdir().unwrap();
+        let state =
+            OnDiskStateView::create(dir.path().join("build"), dir.path().join("storage")).unwrap();
+        let module = compile(dir.path(), SOURCE);
+        let mut bytes = vec![];
+        module
+            .serialize_with_version(module.version, &mut bytes)
+            .unwrap();
+        state.save_module(&module.self_id(), &bytes).unwrap();
+
+        let path = state.get_module_path(&module.self_id());
+        let abi = OnDiskStateView::view_module_abi(&path).unwrap().unwrap();
+        assert_eq!(
+            abi.to_string(),
+            "module 0x2::m
+    struct Coin<phantom T0> has store { value: u64 }
+    enum Shape has copy, drop { Circle { r: u8 }, Square { side: u8 } }
+    entry fun run()
+    public(package) fun split(u64): (u64, u64)
+    public fun value<T0>(&0x2::m::Coin<T0>): u64
+"
+        );
+        // parameter names are only known from source
+        let value = abi.functions.iter().find(|f| f.name == "value").unwrap();
+        assert_eq!(value.parameters[0].name, "arg0");
+        assert!(abi.constants.is_empty());
+
+        let missing = state.get_module_path(&"0x2::missing".parse().unwrap());
+        assert!(OnDiskStateView::view_module_abi(&missing)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_binary_format::file_format::{Constant, SignatureToken};
-use move_core_types::{account_address::AccountAddress, u256::U256};
+use move_core_types::{account_address::AccountAddress, i256::I256, u256::U256};
 
 pub enum RenderResult {
     AsString(String),
@@ -44,6 +44,30 @@ pub fn try_render_constant(constant: &Constant) -> RenderResult {
             .ok()
             .map(|x| x.to_string())
             .map_or(RenderResult::NotRendered, RenderResult::AsValue),
+        SignatureToken::I8 => bcs::from_bytes::<i8>(bytes)
+            .ok()
+            .map(|x| x.to_string())
+            .map_or(RenderResult::NotRendered, RenderResult::AsValue),
+        SignatureToken::I16 => bcs::from_bytes::<i16>(bytes)
+            .ok()
+            .map(|x| x.to_string())
+            .map_or(RenderResult::NotRendered, RenderResult::AsValue),
+        SignatureToken::I32 => bcs::from_bytes::<i32>(bytes)
+            .ok()
+            .map(|x| x.to_string())
+            .map_or(RenderResult::NotRendered, RenderResult::AsValue),
+        SignatureToken::I64 => bcs::from_bytes::<i64>(bytes)
+            .ok()
+            .map(|x| x.to_string())
+            .map_or(RenderResult::NotRendered, RenderResult::AsValue),
+        SignatureToken::I128 => bcs::from_bytes::<i128>(bytes)
+            .ok()
+            .map(|x| x.to_string())
+            .map_or(RenderResult::NotRendered, RenderResult::AsValue),
+        SignatureToken::I256 => bcs::from_bytes::<I256>(bytes)
+            .ok()
+            .map(|x| x.to_string())
+            .map_or(RenderResult::NotRendered, RenderResult::AsValue),
         SignatureToken::Address => bcs::from_bytes::<AccountAddress>(bytes)
             .ok()
             .map(|x| x.to_canonical_string(true))
@@ -59,6 +83,7 @@ pub fn try_render_constant(constant: &Constant) -> RenderResult {
         | SignatureToken::DatatypeInstantiation(_)
         | SignatureToken::Reference(_)
         | SignatureToken::MutableReference(_)
-        | SignatureToken::TypeParameter(_) => RenderResult::NotRendered,
+        | SignatureToken::TypeParameter(_)
+        | SignatureToken::Function(_) => RenderResult::NotRendered,
     }
 }
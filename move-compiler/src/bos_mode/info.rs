@@ -125,7 +125,7 @@ fn all_uid_holders(info: &TypingProgramInfo) -> BTreeMap<(ModuleIdent, DatatypeN
             N::Type_::Unit
             | N::Type_::Param(_)
             | N::Type_::Var(_)
-            | N::Type_::Fun(_, _)
+            | N::Type_::Fun(_, _, _)
             | N::Type_::Anything
             | N::Type_::UnresolvedError => None,
 
@@ -66,7 +66,7 @@ fn is_field_coin_type(sp!(_, t): &N::Type) -> bool {
             let sp!(_, tname) = tname;
             tname.is(&BOS_ADDR_VALUE, COIN_MOD_NAME, COIN_STRUCT_NAME)
         }
-        T::Unit | T::Param(_) | T::Var(_) | T::Anything | T::UnresolvedError | T::Fun(_, _) => {
+        T::Unit | T::Param(_) | T::Var(_) | T::Anything | T::UnresolvedError | T::Fun(_, _, _) => {
             false
         }
     }
@@ -195,7 +195,7 @@ impl Context<'_> {
             | T::Var(_)
             | T::Anything
             | T::UnresolvedError
-            | T::Fun(_, _) => None,
+            | T::Fun(_, _, _) => None,
         }
     }
 
@@ -80,6 +80,8 @@ fn is_random_or_random_generator(sp!(_, t): &N::Type) -> Option<&str> {
                 None
             }
         }
-        T::Unit | T::Param(_) | T::Var(_) | T::Anything | T::UnresolvedError | T::Fun(_, _) => None,
+        T::Unit | T::Param(_) | T::Var(_) | T::Anything | T::UnresolvedError | T::Fun(_, _, _) => {
+            None
+        }
     }
 }
@@ -268,8 +268,9 @@ impl<'a> ShareOwnedVerifierAI<'a> {
                     .filter(|(is_phantom, _)| !*is_phantom)
                     .any(|(_, t)| self.can_hold_obj_base(t))
             }
-            // any user defined type or type parameter is pessimistically assumed to hold an object
-            BaseType_::Param(_) => true,
+            // any user defined type or type parameter is pessimistically assumed to hold an object,
+            // as is any function value since it might capture one
+            BaseType_::Param(_) | BaseType_::Fun(_, _, _) => true,
             BaseType_::Unreachable | BaseType_::UnresolvedError => false,
         }
     }
@@ -729,7 +729,7 @@ fn is_mut_clock(param_ty: &Type) -> bool {
         | Type_::Var(_)
         | Type_::Anything
         | Type_::UnresolvedError
-        | Type_::Fun(_, _) => false,
+        | Type_::Fun(_, _, _) => false,
     }
 }
 
@@ -747,7 +747,7 @@ fn is_mut_random(param_ty: &Type) -> bool {
         | Type_::Var(_)
         | Type_::Anything
         | Type_::UnresolvedError
-        | Type_::Fun(_, _) => false,
+        | Type_::Fun(_, _, _) => false,
     }
 }
 
@@ -810,7 +810,7 @@ fn is_entry_primitive_ty(param_ty: &Type) -> bool {
         Type_::Unit => false,
 
         // Error case nothing to do
-        Type_::UnresolvedError | Type_::Anything | Type_::Var(_) | Type_::Fun(_, _) => true,
+        Type_::UnresolvedError | Type_::Anything | Type_::Var(_) | Type_::Fun(_, _, _) => true,
     }
 }
 
@@ -844,7 +844,7 @@ fn is_entry_object_ty_inner(param_ty: &Type) -> bool {
         | Type_::Anything
         | Type_::Var(_)
         | Type_::Unit
-        | Type_::Fun(_, _) => true,
+        | Type_::Fun(_, _, _) => true,
         // Unreachable cases
         Type_::Apply(None, _, _) => unreachable!("ICE abilities should have been expanded"),
     }
@@ -905,7 +905,7 @@ fn entry_return(
             }
         }
         // Error case nothing to do
-        Type_::UnresolvedError | Type_::Anything | Type_::Var(_) | Type_::Fun(_, _) => (),
+        Type_::UnresolvedError | Type_::Anything | Type_::Var(_) | Type_::Fun(_, _, _) => (),
         // Unreachable cases
         Type_::Apply(None, _, _) => unreachable!("ICE abilities should have been expanded"),
     }
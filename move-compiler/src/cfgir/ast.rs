@@ -289,6 +289,12 @@ impl AstDebug for MoveValue {
             V::U64(u) => w.write(format!("{}", u)),
             V::U128(u) => w.write(format!("{}", u)),
             V::U256(u) => w.write(format!("{}", u)),
+            V::I8(i) => w.write(format!("{}", i)),
+            V::I16(i) => w.write(format!("{}", i)),
+            V::I32(i) => w.write(format!("{}", i)),
+            V::I64(i) => w.write(format!("{}", i)),
+            V::I128(i) => w.write(format!("{}", i)),
+            V::I256(i) => w.write(format!("{}", i)),
             V::Bool(b) => w.write(format!("{}", b)),
             V::Address(a) => w.write(format!("{}", a)),
             V::Vector(vs) => {
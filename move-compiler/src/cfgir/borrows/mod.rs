@@ -319,6 +319,22 @@ fn exp(context: &mut Context, parent_e: &Exp) -> Values {
             context.add_diags(diags);
             values
         }
+        E::PackClosure(mcall) => {
+            mcall.arguments.iter().for_each(|arg| {
+                let arg = exp(context, arg);
+                assert!(!assert_single_value(arg).is_ref());
+            });
+            svalue()
+        }
+        E::CallClosure(closure, args) => {
+            let evalues: Values = args.iter().flat_map(|arg| exp(context, arg)).collect();
+            let closure = exp(context, closure);
+            assert!(!assert_single_value(closure).is_ref());
+            let ret_ty = &parent_e.ty;
+            let (diags, values) = context.borrow_state.call(*eloc, evalues, ret_ty);
+            context.add_diags(diags);
+            values
+        }
 
         E::Unit { .. } => vec![],
         E::Value(_) | E::Constant(_) | E::UnresolvedError | E::ErrorConstant { .. } => svalue(),
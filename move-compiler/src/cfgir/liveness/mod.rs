@@ -137,7 +137,13 @@ fn exp(state: &mut LivenessState, parent_e: &Exp) {
             state.0.insert(*var);
         }
 
-        E::ModuleCall(mcall) => mcall.arguments.iter().for_each(|e| exp(state, e)),
+        E::ModuleCall(mcall) | E::PackClosure(mcall) => {
+            mcall.arguments.iter().for_each(|e| exp(state, e))
+        }
+        E::CallClosure(closure, args) => {
+            args.iter().for_each(|e| exp(state, e));
+            exp(state, closure)
+        }
         E::Vector(_, _, _, args) => args.iter().for_each(|e| exp(state, e)),
         E::Freeze(e)
         | E::Dereference(e)
@@ -336,11 +342,15 @@ mod last_usage {
                 }
             }
 
-            E::ModuleCall(mcall) => mcall
+            E::ModuleCall(mcall) | E::PackClosure(mcall) => mcall
                 .arguments
                 .iter_mut()
                 .rev()
                 .for_each(|arg| exp(context, arg)),
+            E::CallClosure(closure, args) => {
+                exp(context, closure);
+                args.iter_mut().rev().for_each(|arg| exp(context, arg))
+            }
             E::Vector(_, _, _, args) => args.iter_mut().rev().for_each(|arg| exp(context, arg)),
             E::Freeze(e)
             | E::Dereference(e)
@@ -394,7 +394,13 @@ fn exp(context: &mut Context, parent_e: &Exp) {
             )
         }
 
-        E::ModuleCall(mcall) => mcall.arguments.iter().map(|e| exp(context, e)).collect(),
+        E::ModuleCall(mcall) | E::PackClosure(mcall) => {
+            mcall.arguments.iter().for_each(|e| exp(context, e))
+        }
+        E::CallClosure(closure, args) => {
+            args.iter().for_each(|e| exp(context, e));
+            exp(context, closure)
+        }
         E::Vector(_, _, _, args) => args.iter().map(|e| exp(context, e)).collect(),
         E::Freeze(e)
         | E::Dereference(e)
@@ -549,6 +555,7 @@ fn add_drop_ability_tip(context: &Context, diag: &mut Diagnostic, st: SingleType
             let declared_abilities = context.datatype_declared_abilities(m, s);
             (Some(decl_loc), declared_abilities, ty_args.clone())
         }
+        T::Fun(_, _, abilities) => (None, abilities, vec![]),
         t => panic!(
             "ICE either the type did not have 'drop' when it should have or it was converted \
              incorrectly {:?}",
@@ -567,10 +574,10 @@ fn add_drop_ability_tip(context: &Context, diag: &mut Diagnostic, st: SingleType
                 T::Unit => AbilitySet::collection(ty_arg.loc),
                 T::Ref(_, _) => AbilitySet::references(ty_arg.loc),
                 T::UnresolvedError | T::Anything => AbilitySet::all(ty_arg.loc),
-                T::Param(TParam { abilities, .. }) | T::Apply(Some(abilities), _, _) => {
-                    abilities.clone()
-                }
-                T::Var(_) | T::Apply(None, _, _) | T::Fun(_, _) => panic!("ICE expansion failed"),
+                T::Param(TParam { abilities, .. })
+                | T::Apply(Some(abilities), _, _)
+                | T::Fun(_, _, abilities) => abilities.clone(),
+                T::Var(_) | T::Apply(None, _, _) => panic!("ICE expansion failed"),
             };
             (ty_arg, abilities)
         }),
@@ -586,15 +593,15 @@ fn single_type_to_naming_type_(st_: SingleType_) -> N::Type_ {
     use N::Type_ as T;
     match st_ {
         S::Ref(mut_, b) => T::Ref(mut_, Box::new(base_type_to_naming_type(b))),
-        S::Base(sp!(_, b_)) => base_type_to_naming_type_(b_),
+        S::Base(sp!(loc, b_)) => base_type_to_naming_type_(loc, b_),
     }
 }
 
 fn base_type_to_naming_type(sp!(loc, bt_): BaseType) -> N::Type {
-    sp(loc, base_type_to_naming_type_(bt_))
+    sp(loc, base_type_to_naming_type_(loc, bt_))
 }
 
-fn base_type_to_naming_type_(bt_: BaseType_) -> N::Type_ {
+fn base_type_to_naming_type_(loc: Loc, bt_: BaseType_) -> N::Type_ {
     use BaseType_ as B;
     use N::Type_ as T;
     match bt_ {
@@ -606,6 +613,17 @@ fn base_type_to_naming_type_(bt_: BaseType_) -> N::Type_ {
             type_name_to_naming_type_name(tn),
             ty_args.into_iter().map(base_type_to_naming_type).collect(),
         ),
+        B::Fun(params, returns, abilities) => T::Fun(
+            params.into_iter().map(single_type_to_naming_type).collect(),
+            Box::new(T::multiple(
+                loc,
+                returns
+                    .into_iter()
+                    .map(single_type_to_naming_type)
+                    .collect(),
+            )),
+            abilities,
+        ),
     }
 }
 
@@ -15,6 +15,7 @@ use crate::{
     parser::ast::{BinOp, BinOp_, ConstantName, UnaryOp, UnaryOp_},
     shared::unique_map::UniqueMap,
 };
+use move_core_types::i256::I256;
 use move_ir_types::location::*;
 use move_proc_macros::growing_stack;
 use std::convert::TryFrom;
@@ -121,7 +122,10 @@ fn optimize_exp(context: &Context, e: &mut Exp) -> bool {
             }
         }
 
-        E::ModuleCall(mcall) => mcall.arguments.iter_mut().any(optimize_exp),
+        E::ModuleCall(mcall) | E::PackClosure(mcall) => {
+            mcall.arguments.iter_mut().any(optimize_exp)
+        }
+        E::CallClosure(closure, args) => args.iter_mut().any(optimize_exp) || optimize_exp(closure),
 
         E::Freeze(e) | E::Dereference(e) | E::Borrow(_, e, _, _) => optimize_exp(e),
 
@@ -222,7 +226,9 @@ fn is_valid_const_type(sp!(_, ty_): &BaseType) -> bool {
         T::Apply(_, tn, ty_args) if is_valid_const_type_name(tn) => {
             ty_args.iter().all(is_valid_const_type)
         }
-        T::Apply(_, _, _) | T::Param(_) | T::Unreachable | T::UnresolvedError => false,
+        T::Apply(_, _, _) | T::Param(_) | T::Fun(_, _, _) | T::Unreachable | T::UnresolvedError => {
+            false
+        }
     }
 }
 
@@ -237,9 +243,21 @@ fn is_valid_const_type_name(sp!(_, tn_): &TypeName) -> bool {
 fn is_valid_const_builtin_type(sp!(_, bt_): &BuiltinTypeName) -> bool {
     use BuiltinTypeName_ as N;
     match bt_ {
-        N::Address | N::U8 | N::U16 | N::U32 | N::U64 | N::U128 | N::U256 | N::Vector | N::Bool => {
-            true
-        }
+        N::Address
+        | N::U8
+        | N::U16
+        | N::U32
+        | N::U64
+        | N::U128
+        | N::U256
+        | N::I8
+        | N::I16
+        | N::I32
+        | N::I64
+        | N::I128
+        | N::I256
+        | N::Vector
+        | N::Bool => true,
         N::Signer => false,
     }
 }
@@ -305,6 +323,41 @@ fn fold_binary_op(
         (B::Div, V::U128(u1), V::U128(u2)) => V::U128(u1.checked_div(u2)?),
         (B::Div, V::U256(u1), V::U256(u2)) => V::U256(u1.checked_div(u2)?),
 
+        (B::Add, V::I8(i1), V::I8(i2)) => V::I8(i1.checked_add(i2)?),
+        (B::Add, V::I16(i1), V::I16(i2)) => V::I16(i1.checked_add(i2)?),
+        (B::Add, V::I32(i1), V::I32(i2)) => V::I32(i1.checked_add(i2)?),
+        (B::Add, V::I64(i1), V::I64(i2)) => V::I64(i1.checked_add(i2)?),
+        (B::Add, V::I128(i1), V::I128(i2)) => V::I128(i1.checked_add(i2)?),
+        (B::Add, V::I256(i1), V::I256(i2)) => V::I256(i1.checked_add(i2)?),
+
+        (B::Sub, V::I8(i1), V::I8(i2)) => V::I8(i1.checked_sub(i2)?),
+        (B::Sub, V::I16(i1), V::I16(i2)) => V::I16(i1.checked_sub(i2)?),
+        (B::Sub, V::I32(i1), V::I32(i2)) => V::I32(i1.checked_sub(i2)?),
+        (B::Sub, V::I64(i1), V::I64(i2)) => V::I64(i1.checked_sub(i2)?),
+        (B::Sub, V::I128(i1), V::I128(i2)) => V::I128(i1.checked_sub(i2)?),
+        (B::Sub, V::I256(i1), V::I256(i2)) => V::I256(i1.checked_sub(i2)?),
+
+        (B::Mul, V::I8(i1), V::I8(i2)) => V::I8(i1.checked_mul(i2)?),
+        (B::Mul, V::I16(i1), V::I16(i2)) => V::I16(i1.checked_mul(i2)?),
+        (B::Mul, V::I32(i1), V::I32(i2)) => V::I32(i1.checked_mul(i2)?),
+        (B::Mul, V::I64(i1), V::I64(i2)) => V::I64(i1.checked_mul(i2)?),
+        (B::Mul, V::I128(i1), V::I128(i2)) => V::I128(i1.checked_mul(i2)?),
+        (B::Mul, V::I256(i1), V::I256(i2)) => V::I256(i1.checked_mul(i2)?),
+
+        (B::Mod, V::I8(i1), V::I8(i2)) => V::I8(i1.checked_rem(i2)?),
+        (B::Mod, V::I16(i1), V::I16(i2)) => V::I16(i1.checked_rem(i2)?),
+        (B::Mod, V::I32(i1), V::I32(i2)) => V::I32(i1.checked_rem(i2)?),
+        (B::Mod, V::I64(i1), V::I64(i2)) => V::I64(i1.checked_rem(i2)?),
+        (B::Mod, V::I128(i1), V::I128(i2)) => V::I128(i1.checked_rem(i2)?),
+        (B::Mod, V::I256(i1), V::I256(i2)) => V::I256(i1.checked_rem(i2)?),
+
+        (B::Div, V::I8(i1), V::I8(i2)) => V::I8(i1.checked_div(i2)?),
+        (B::Div, V::I16(i1), V::I16(i2)) => V::I16(i1.checked_div(i2)?),
+        (B::Div, V::I32(i1), V::I32(i2)) => V::I32(i1.checked_div(i2)?),
+        (B::Div, V::I64(i1), V::I64(i2)) => V::I64(i1.checked_div(i2)?),
+        (B::Div, V::I128(i1), V::I128(i2)) => V::I128(i1.checked_div(i2)?),
+        (B::Div, V::I256(i1), V::I256(i2)) => V::I256(i1.checked_div(i2)?),
+
         (B::Shl, V::U8(u1), V::U8(u2)) => V::U8(u1.checked_shl(u2 as u32)?),
         (B::Shl, V::U16(u1), V::U8(u2)) => V::U16(u1.checked_shl(u2 as u32)?),
         (B::Shl, V::U32(u1), V::U8(u2)) => V::U32(u1.checked_shl(u2 as u32)?),
@@ -380,6 +433,34 @@ fn fold_binary_op(
         (B::Ge, V::U128(u1), V::U128(u2)) => V::Bool(u1 >= u2),
         (B::Ge, V::U256(u1), V::U256(u2)) => V::Bool(u1 >= u2),
 
+        (B::Lt, V::I8(i1), V::I8(i2)) => V::Bool(i1 < i2),
+        (B::Lt, V::I16(i1), V::I16(i2)) => V::Bool(i1 < i2),
+        (B::Lt, V::I32(i1), V::I32(i2)) => V::Bool(i1 < i2),
+        (B::Lt, V::I64(i1), V::I64(i2)) => V::Bool(i1 < i2),
+        (B::Lt, V::I128(i1), V::I128(i2)) => V::Bool(i1 < i2),
+        (B::Lt, V::I256(i1), V::I256(i2)) => V::Bool(i1 < i2),
+
+        (B::Gt, V::I8(i1), V::I8(i2)) => V::Bool(i1 > i2),
+        (B::Gt, V::I16(i1), V::I16(i2)) => V::Bool(i1 > i2),
+        (B::Gt, V::I32(i1), V::I32(i2)) => V::Bool(i1 > i2),
+        (B::Gt, V::I64(i1), V::I64(i2)) => V::Bool(i1 > i2),
+        (B::Gt, V::I128(i1), V::I128(i2)) => V::Bool(i1 > i2),
+        (B::Gt, V::I256(i1), V::I256(i2)) => V::Bool(i1 > i2),
+
+        (B::Le, V::I8(i1), V::I8(i2)) => V::Bool(i1 <= i2),
+        (B::Le, V::I16(i1), V::I16(i2)) => V::Bool(i1 <= i2),
+        (B::Le, V::I32(i1), V::I32(i2)) => V::Bool(i1 <= i2),
+        (B::Le, V::I64(i1), V::I64(i2)) => V::Bool(i1 <= i2),
+        (B::Le, V::I128(i1), V::I128(i2)) => V::Bool(i1 <= i2),
+        (B::Le, V::I256(i1), V::I256(i2)) => V::Bool(i1 <= i2),
+
+        (B::Ge, V::I8(i1), V::I8(i2)) => V::Bool(i1 >= i2),
+        (B::Ge, V::I16(i1), V::I16(i2)) => V::Bool(i1 >= i2),
+        (B::Ge, V::I32(i1), V::I32(i2)) => V::Bool(i1 >= i2),
+        (B::Ge, V::I64(i1), V::I64(i2)) => V::Bool(i1 >= i2),
+        (B::Ge, V::I128(i1), V::I128(i2)) => V::Bool(i1 >= i2),
+        (B::Ge, V::I256(i1), V::I256(i2)) => V::Bool(i1 >= i2),
+
         (B::Eq, v1, v2) => V::Bool(v1 == v2),
         (B::Neq, v1, v2) => V::Bool(v1 != v2),
 
@@ -436,11 +517,63 @@ fn fold_cast(loc: Loc, sp!(_, bt_): &BuiltinTypeName, v: Value_) -> Option<Unann
         (BT::U256, V::U64(u)) => V::U256(u.into()),
         (BT::U256, V::U128(u)) => V::U256(u.into()),
         (BT::U256, V::U256(u)) => V::U256(u),
+
+        // Casts to or from signed integers go through i256
+        (bt_, v) if bt_.is_signed() || is_signed_value(&v) => {
+            cast_from_i256(bt_, value_to_i256(v)?)?
+        }
         (_, v) => panic!("ICE unexpected cast while folding: {:?} as {:?}", v, bt_),
     };
     Some(evalue_(loc, cast))
 }
 
+fn is_signed_value(v: &Value_) -> bool {
+    use Value_ as V;
+    matches!(
+        v,
+        V::I8(_) | V::I16(_) | V::I32(_) | V::I64(_) | V::I128(_) | V::I256(_)
+    )
+}
+
+fn value_to_i256(v: Value_) -> Option<I256> {
+    use Value_ as V;
+    Some(match v {
+        V::U8(u) => I256::from(u),
+        V::U16(u) => I256::from(u),
+        V::U32(u) => I256::from(u),
+        V::U64(u) => I256::from(u),
+        V::U128(u) => I256::from(u),
+        V::U256(u) => I256::try_from(u).ok()?,
+        V::I8(i) => I256::from(i),
+        V::I16(i) => I256::from(i),
+        V::I32(i) => I256::from(i),
+        V::I64(i) => I256::from(i),
+        V::I128(i) => I256::from(i),
+        V::I256(i) => i,
+        V::Address(_) | V::Bool(_) | V::Vector(_, _) => return None,
+    })
+}
+
+fn cast_from_i256(bt_: &BuiltinTypeName_, i: I256) -> Option<Value_> {
+    use BuiltinTypeName_ as BT;
+    use Value_ as V;
+    Some(match bt_ {
+        BT::U8 => V::U8(i.try_into().ok()?),
+        BT::U16 => V::U16(i.try_into().ok()?),
+        BT::U32 => V::U32(i.try_into().ok()?),
+        BT::U64 => V::U64(i.try_into().ok()?),
+        BT::U128 => V::U128(i.try_into().ok()?),
+        BT::U256 => V::U256(i.try_into().ok()?),
+        BT::I8 => V::I8(i.try_into().ok()?),
+        BT::I16 => V::I16(i.try_into().ok()?),
+        BT::I32 => V::I32(i.try_into().ok()?),
+        BT::I64 => V::I64(i.try_into().ok()?),
+        BT::I128 => V::I128(i.try_into().ok()?),
+        BT::I256 => V::I256(i),
+        BT::Address | BT::Signer | BT::Vector | BT::Bool => return None,
+    })
+}
+
 const fn evalue_(loc: Loc, v: Value_) -> UnannotatedExp_ {
     use UnannotatedExp_ as E;
     E::Value(sp(loc, v))
@@ -172,11 +172,17 @@ mod count {
 
             E::Copy { var, .. } | E::Move { var, .. } => context.used(var, true),
 
-            E::ModuleCall(mcall) => {
+            E::ModuleCall(mcall) | E::PackClosure(mcall) => {
                 for arg in &mcall.arguments {
                     exp(context, arg);
                 }
             }
+            E::CallClosure(closure, args) => {
+                for arg in args {
+                    exp(context, arg);
+                }
+                exp(context, closure)
+            }
             E::Vector(_, _, _, args) => {
                 for arg in args.iter() {
                     exp(context, arg);
@@ -224,6 +230,8 @@ mod count {
             | E::Freeze(_)
             | E::Dereference(_)
             | E::ModuleCall(_)
+            | E::PackClosure(_)
+            | E::CallClosure(_, _)
             | E::Move { .. }
             | E::Borrow(_, _, _, _) => false,
 
@@ -381,11 +389,17 @@ mod eliminate {
             | E::ErrorConstant { .. }
             | E::BorrowLocal(_, _) => (),
 
-            E::ModuleCall(mcall) => {
+            E::ModuleCall(mcall) | E::PackClosure(mcall) => {
                 for arg in mcall.arguments.iter_mut() {
                     exp(context, arg);
                 }
             }
+            E::CallClosure(closure, args) => {
+                for arg in args.iter_mut() {
+                    exp(context, arg);
+                }
+                exp(context, closure)
+            }
             E::Vector(_, _, _, args) => {
                 for arg in args.iter_mut() {
                     exp(context, arg);
@@ -615,6 +615,12 @@ pub(crate) fn move_value_from_value_(v_: Value_) -> MoveValue {
         V::U64(u) => MV::U64(u),
         V::U128(u) => MV::U128(u),
         V::U256(u) => MV::U256(u),
+        V::I8(i) => MV::I8(i),
+        V::I16(i) => MV::I16(i),
+        V::I32(i) => MV::I32(i),
+        V::I64(i) => MV::I64(i),
+        V::I128(i) => MV::I128(i),
+        V::I256(i) => MV::I256(i),
         V::Bool(b) => MV::Bool(b),
         V::Vector(_, vs) => MV::Vector(vs.into_iter().map(move_value_from_value).collect()),
     }
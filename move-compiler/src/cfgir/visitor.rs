@@ -266,11 +266,17 @@ pub trait CFGIRVisitorContext {
                 self.visit_exp(er);
             }
 
-            E::ModuleCall(m) => {
+            E::ModuleCall(m) | E::PackClosure(m) => {
                 for arg in &m.arguments {
                     self.visit_exp(arg)
                 }
             }
+            E::CallClosure(closure, args) => {
+                for arg in args {
+                    self.visit_exp(arg)
+                }
+                self.visit_exp(closure)
+            }
             E::Vector(_, _, _, es) | E::Multiple(es) => {
                 for e in es {
                     self.visit_exp(e)
@@ -302,6 +308,12 @@ pub trait CFGIRVisitorContext {
             | V::U64(_)
             | V::U128(_)
             | V::U256(_)
+            | V::I8(_)
+            | V::I16(_)
+            | V::I32(_)
+            | V::I64(_)
+            | V::I128(_)
+            | V::I256(_)
             | V::Bool(_) => (),
             V::Vector(_, vs) => {
                 for v in vs {
@@ -737,6 +749,19 @@ pub trait SimpleAbsInt: Sized {
 
                 default_values_for_ty(&parent_e.ty)
             }
+            E::PackClosure(mcall) => {
+                for arg in &mcall.arguments {
+                    self.exp(context, state, arg);
+                }
+                default_values(1)
+            }
+            E::CallClosure(closure, args) => {
+                for arg in args {
+                    self.exp(context, state, arg);
+                }
+                self.exp(context, state, closure);
+                default_values_for_ty(&parent_e.ty)
+            }
 
             E::Unit { .. } => vec![],
             E::Value(_) | E::Constant(_) | E::UnresolvedError | E::ErrorConstant { .. } => {
@@ -931,7 +956,12 @@ fn exp_satisfies_(e: &Exp, p: &mut impl FnMut(&Exp) -> bool) -> bool {
 
         E::BinopExp(el, _, er) => exp_satisfies_(el, p) || exp_satisfies_(er, p),
 
-        E::ModuleCall(call) => call.arguments.iter().any(|arg| exp_satisfies_(arg, p)),
+        E::ModuleCall(call) | E::PackClosure(call) => {
+            call.arguments.iter().any(|arg| exp_satisfies_(arg, p))
+        }
+        E::CallClosure(closure, args) => {
+            args.iter().any(|arg| exp_satisfies_(arg, p)) || exp_satisfies_(closure, p)
+        }
         E::Vector(_, _, _, es) | E::Multiple(es) => es.iter().any(move |e| exp_satisfies_(e, p)),
 
         E::Pack(_, _, es) | E::PackVariant(_, _, _, es) => {
@@ -985,7 +1015,7 @@ pub fn same_value_exp_(e1: &H::UnannotatedExp_, e2: &H::UnannotatedExp_) -> bool
         (E::Borrow(_, e1, f1, _), E::Borrow(_, e2, f2, _)) => f1 == f2 && same_value_exp(e1, e2),
 
         // false for anything effectful
-        (E::ModuleCall(_), _) => false,
+        (E::ModuleCall(_), _) | (E::CallClosure(_, _), _) => false,
 
         // TODO there is some potential for equality here, but a bit too brittle now
         (E::Cast(_, _), _) | (E::ErrorConstant { .. }, _) => false,
@@ -165,6 +165,7 @@ codes!(
         InvalidName: { msg: "invalid name", severity: BlockingError },
         InvalidMacro: { msg: "invalid macro invocation", severity: BlockingError },
         InvalidMatch: { msg: "invalid 'match'", severity: BlockingError },
+        InvalidCall: { msg: "invalid call", severity: NonblockingError },
     ],
     // errors for any rules around declaration items
     Declarations: [
@@ -267,6 +268,7 @@ codes!(
         InvalidErrorUsage: { msg: "invalid constant usage in error context", severity: BlockingError },
         IncompletePattern: { msg: "non-exhaustive pattern", severity: BlockingError },
         DeprecatedUsage: { msg: "deprecated usage", severity: Warning },
+        InvalidClosureCapture: { msg: "invalid closure capture", severity: BlockingError },
     ],
     // errors for ability rules. mostly typing/translate
     AbilitySafety: [
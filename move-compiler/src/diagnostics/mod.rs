@@ -37,7 +37,7 @@ use std::{
     io::Write,
     iter::FromIterator,
     ops::Range,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::RwLock,
 };
 
@@ -75,6 +75,16 @@ pub struct Diagnostic {
     primary_label: (Loc, String),
     secondary_labels: Vec<(Loc, String)>,
     notes: Vec<String>,
+    fixes: Vec<DiagnosticFix>,
+    filter_attribute: Option<String>,
+}
+
+/// A machine-applicable fix for a diagnostic. Each edit replaces the source text at a given
+/// location with the new text (edits of a single fix must not overlap).
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug, Hash)]
+pub struct DiagnosticFix {
+    pub title: String,
+    pub edits: Vec<(Loc, String)>,
 }
 
 #[derive(PartialEq, Eq, Hash, Clone, Debug, Default)]
@@ -279,6 +289,8 @@ fn render_diagnostic_text(
         primary_label,
         secondary_labels,
         mut notes,
+        fixes: _,
+        filter_attribute: _,
     } = diag;
     let mut diag = csr::diagnostic::Diagnostic::new(info.severity().into_codespan_severity());
     let (code, message) = info.render();
@@ -416,13 +428,18 @@ impl<'env> DiagnosticReporter<'env> {
             // TODO do we want a centralized place for tips like this?
             if diag.info().severity() == Severity::Warning {
                 if let Some((prefix, name)) = self.known_filter_names.get(&diag.info().id()) {
-                    let help = format!(
-                        "This warning can be suppressed with '#[{}({})]' \
-                         applied to the 'module' or module member ('const', 'fun', or 'struct')",
+                    let attr = format!(
+                        "{}({})",
                         known_attributes::DiagnosticAttribute::ALLOW,
                         format_allow_attr(*prefix, *name),
                     );
-                    diag.add_note(help)
+                    let help = format!(
+                        "This warning can be suppressed with '#[{}]' \
+                         applied to the 'module' or module member ('const', 'fun', or 'struct')",
+                        attr,
+                    );
+                    diag.add_note(help);
+                    diag.filter_attribute = Some(attr);
                 }
                 if self.flags.warnings_are_errors() {
                     diag = diag.set_severity(Severity::NonblockingError)
@@ -620,6 +637,8 @@ impl Diagnostics {
                 primary_label,
                 secondary_labels,
                 notes,
+                fixes: _,
+                filter_attribute: _,
             } = diag;
             let csr_diag = (
                 info.severity().into_codespan_severity(),
@@ -722,6 +741,8 @@ impl Diagnostic {
                 .map(|(loc, msg)| (loc, msg.to_string()))
                 .collect(),
             notes: notes.into_iter().map(|msg| msg.to_string()).collect(),
+            fixes: vec![],
+            filter_attribute: None,
         }
     }
 
@@ -765,6 +786,22 @@ impl Diagnostic {
         self.notes.push(msg.to_string())
     }
 
+    /// Attaches a fix that can be applied automatically (e.g., by the IDE) to resolve the
+    /// diagnostic
+    pub fn add_fix(
+        &mut self,
+        title: impl ToString,
+        edits: impl IntoIterator<Item = (Loc, impl ToString)>,
+    ) {
+        self.fixes.push(DiagnosticFix {
+            title: title.to_string(),
+            edits: edits
+                .into_iter()
+                .map(|(loc, text)| (loc, text.to_string()))
+                .collect(),
+        })
+    }
+
     pub fn info(&self) -> &DiagnosticInfo {
         &self.info
     }
@@ -777,6 +814,24 @@ impl Diagnostic {
         self.primary_label.0
     }
 
+    pub fn secondary_labels(&self) -> &[(Loc, String)] {
+        &self.secondary_labels
+    }
+
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    pub fn fixes(&self) -> &[DiagnosticFix] {
+        &self.fixes
+    }
+
+    /// Returns the contents of the attribute (e.g., `allow(lint(name))`) that would suppress
+    /// this diagnostic, if one exists
+    pub fn filter_attribute(&self) -> Option<&str> {
+        self.filter_attribute.as_deref()
+    }
+
     pub fn is_migration(&self) -> bool {
         const MIGRATION_CATEGORY: u8 = codes::Category::Migration as u8;
         self.info.category() == MIGRATION_CATEGORY
@@ -788,6 +843,8 @@ impl Diagnostic {
             primary_label: (ploc, _pmsg),
             secondary_labels: _,
             notes: _,
+            fixes: _,
+            filter_attribute: _,
         } = self;
 
         let bloc = mapped_files.position(ploc);
@@ -1030,6 +1087,22 @@ impl Migration {
         output.join("")
     }
 
+    /// Returns the original contents of the file at a given path along with its contents after
+    /// applying all migration changes to it, or `None` if the file does not need to be migrated.
+    pub fn migrated_file(&mut self, path: &Path) -> Option<(String, String)> {
+        let file_hash = self
+            .changes
+            .keys()
+            .find(|hash| self.mapped_files.file_path(hash) == path)
+            .cloned()?;
+        let file_id = self.mapped_files.file_hash_to_file_id(&file_hash)?;
+        let original = self.get_file_contents(file_id);
+        let file_changes = self.changes.get_mut(&file_hash).unwrap();
+        Self::ensure_unique_changes(file_changes);
+        let migrated = Self::render_changes(original.clone(), file_changes);
+        Some((original, migrated))
+    }
+
     pub fn record_diff(&mut self, path: PathBuf) -> anyhow::Result<String> {
         let output_path = path.join("migration.patch");
         let string_result = output_path.to_str().unwrap_or("invalid path").to_string();
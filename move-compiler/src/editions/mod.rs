@@ -53,6 +53,8 @@ pub enum FeatureGate {
     TypeHoles,
     Lambda,
     ModuleLabel,
+    Closures,
+    SignedIntegers,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug, PartialOrd, Ord, Default)]
@@ -143,7 +145,7 @@ pub fn valid_editions_for_feature(feature: FeatureGate) -> Vec<Edition> {
 static SUPPORTED_FEATURES: Lazy<BTreeMap<Edition, BTreeSet<FeatureGate>>> =
     Lazy::new(|| BTreeMap::from_iter(Edition::ALL.iter().map(|e| (*e, e.features()))));
 
-const E2024_ALPHA_FEATURES: &[FeatureGate] = &[];
+const E2024_ALPHA_FEATURES: &[FeatureGate] = &[FeatureGate::Closures, FeatureGate::SignedIntegers];
 
 const E2024_BETA_FEATURES: &[FeatureGate] = &[];
 
@@ -312,6 +314,8 @@ impl FeatureGate {
             FeatureGate::TypeHoles => "'_' placeholders for type inference are",
             FeatureGate::Lambda => "lambda expressions are",
             FeatureGate::ModuleLabel => "'module' label forms (ending with ';') are",
+            FeatureGate::Closures => "function values (closures) are",
+            FeatureGate::SignedIntegers => "signed integer types are",
         }
     }
 }
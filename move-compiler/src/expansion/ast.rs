@@ -292,7 +292,7 @@ pub enum Type_ {
     Multiple(Vec<Type>),
     Apply(ModuleAccess, Vec<Type>),
     Ref(bool, Box<Type>),
-    Fun(Vec<Type>, Box<Type>),
+    Fun(Vec<Type>, Box<Type>, AbilitySet),
     UnresolvedError,
 }
 pub type Type = Spanned<Type_>;
@@ -367,6 +367,18 @@ pub enum Value_ {
     U128(u128),
     // <num>u256
     U256(move_core_types::u256::U256),
+    // <num>i8
+    I8(i8),
+    // <num>i16
+    I16(i16),
+    // <num>i32
+    I32(i32),
+    // <num>i64
+    I64(i64),
+    // <num>i128
+    I128(i128),
+    // <num>i256
+    I256(move_core_types::i256::I256),
     // true
     // false
     Bool(bool),
@@ -401,6 +413,7 @@ pub enum Exp_ {
     Match(Box<Exp>, Spanned<Vec<MatchArm>>),
     While(Option<BlockLabel>, Box<Exp>, Box<Exp>),
     Loop(Option<BlockLabel>, Box<Exp>),
+    For(Option<BlockLabel>, LValue, Box<Exp>, Box<Exp>),
     Block(Option<BlockLabel>, Sequence),
     Lambda(LambdaLValues, Option<Type>, Box<Exp>),
     Quant(
@@ -695,7 +708,7 @@ impl AbilitySet {
         Ability_::Store,
         Ability_::Key,
     ];
-    /// Abilities for bool, u8, u16, u32, u64, u128, u256 and address
+    /// Abilities for bool, the integer types and address
     pub const PRIMITIVES: [Ability_; 3] = [Ability_::Copy, Ability_::Drop, Ability_::Store];
     /// Abilities for &_ and &mut _
     pub const REFERENCES: [Ability_; 2] = [Ability_::Copy, Ability_::Drop];
@@ -957,6 +970,12 @@ impl std::fmt::Display for Value_ {
             V::U64(u) => write!(f, "{}", u),
             V::U128(u) => write!(f, "{}", u),
             V::U256(u) => write!(f, "{}", u),
+            V::I8(i) => write!(f, "{}", i),
+            V::I16(i) => write!(f, "{}", i),
+            V::I32(i) => write!(f, "{}", i),
+            V::I64(i) => write!(f, "{}", i),
+            V::I128(i) => write!(f, "{}", i),
+            V::I256(i) => write!(f, "{}", i),
             V::Bool(b) => write!(f, "{}", b),
             // TODO preserve the user's original string
             V::Bytearray(v) => {
@@ -1393,11 +1412,18 @@ impl AstDebug for Type_ {
                 }
                 s.ast_debug(w)
             }
-            Type_::Fun(args, result) => {
+            Type_::Fun(args, result, abilities) => {
                 w.write("(");
                 w.comma(args, |w, ty| ty.ast_debug(w));
                 w.write("):");
                 result.ast_debug(w);
+                if !abilities.is_empty() {
+                    w.write(" has ");
+                    w.list(abilities, " + ", |w, ab| {
+                        ab.ast_debug(w);
+                        false
+                    });
+                }
             }
             Type_::UnresolvedError => w.write("_|_"),
         }
@@ -1513,6 +1539,12 @@ impl AstDebug for Value_ {
             V::U64(u) => w.write(format!("{}u64", u)),
             V::U128(u) => w.write(format!("{}u128", u)),
             V::U256(u) => w.write(format!("{}u256", u)),
+            V::I8(i) => w.write(format!("{}i8", i)),
+            V::I16(i) => w.write(format!("{}i16", i)),
+            V::I32(i) => w.write(format!("{}i32", i)),
+            V::I64(i) => w.write(format!("{}i64", i)),
+            V::I128(i) => w.write(format!("{}i128", i)),
+            V::I256(i) => w.write(format!("{}i256", i)),
             V::Bool(b) => w.write(format!("{}", b)),
             V::Bytearray(v) => w.write(format!("{:?}", v)),
         }
@@ -1624,6 +1656,15 @@ impl AstDebug for Exp_ {
                 w.write("loop ");
                 e.ast_debug(w);
             }
+            E::For(name, lv, e, eloop) => {
+                name.map(|name| w.write(format!("'{}: ", name)));
+                w.write("for (");
+                lv.ast_debug(w);
+                w.write(" in ");
+                e.ast_debug(w);
+                w.write(")");
+                eloop.ast_debug(w);
+            }
             E::Block(name, seq) => {
                 name.map(|name| w.write(format!("'{}: ", name)));
                 seq.ast_debug(w);
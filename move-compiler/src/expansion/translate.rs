@@ -49,7 +49,10 @@ use crate::{
     FullyCompiledProgram,
 };
 use move_core_types::account_address::AccountAddress;
-use move_core_types::parsing::parser::{parse_u16, parse_u256, parse_u32};
+use move_core_types::parsing::parser::{
+    parse_i128, parse_i16, parse_i256, parse_i32, parse_i64, parse_i8, parse_u16, parse_u256,
+    parse_u32,
+};
 use move_ir_types::location::*;
 use move_proc_macros::growing_stack;
 use move_symbol_pool::Symbol;
@@ -2424,10 +2427,11 @@ fn type_(context: &mut Context, sp!(loc, pt_): P::Type) -> E::Type {
             Some(access_result!(n, ptyargs, _)) => ET::Apply(n, sp_types(context, ptyargs)),
         },
         PT::Ref(mut_, inner) => ET::Ref(mut_, Box::new(type_(context, *inner))),
-        PT::Fun(args, result) => {
+        PT::Fun(args, result, abilities_vec) => {
             let args = types(context, args);
             let result = type_(context, *result);
-            ET::Fun(args, Box::new(result))
+            let abilities = function_type_abilities(context, abilities_vec);
+            ET::Fun(args, Box::new(result), abilities)
         }
         PT::UnresolvedError => {
             // Treat an unresolved error as a leading access
@@ -2438,6 +2442,24 @@ fn type_(context: &mut Context, sp!(loc, pt_): P::Type) -> E::Type {
     sp(loc, t_)
 }
 
+fn function_type_abilities(context: &mut Context, abilities_vec: Vec<Ability>) -> E::AbilitySet {
+    let abilities = ability_set(context, "modifier", abilities_vec);
+    for ability in &abilities {
+        if !matches!(ability.value, P::Ability_::Copy | P::Ability_::Drop) {
+            let msg = format!(
+                "Invalid '{}' ability for a function type. \
+                 Function values can only have the 'copy' and 'drop' abilities",
+                ability
+            );
+            context.add_diag(diag!(
+                TypeSafety::UnexpectedFunctionType,
+                (ability.loc, msg)
+            ));
+        }
+    }
+    abilities
+}
+
 fn types(context: &mut Context, pts: Vec<P::Type>) -> Vec<E::Type> {
     pts.into_iter().map(|pt| type_(context, pt)).collect()
 }
@@ -2665,6 +2687,18 @@ fn exp(context: &mut Context, pe: Box<P::Exp>) -> Box<E::Exp> {
         }
         PE::While(pb, ploop) => EE::While(None, exp(context, pb), exp(context, ploop)),
         PE::Loop(ploop) => EE::Loop(None, exp(context, ploop)),
+        PE::For(pb, pe, ploop) => {
+            let lv_opt = bind(context, pb);
+            let e = exp(context, pe);
+            let eloop = exp(context, ploop);
+            match lv_opt {
+                Some(lv) => EE::For(None, lv, e, eloop),
+                None => {
+                    assert!(context.env().has_errors());
+                    EE::UnresolvedError
+                }
+            }
+        }
         PE::Block(seq) => EE::Block(None, sequence(context, loc, seq)),
         PE::Lambda(plambda, pty_opt, pe) => {
             let elambda_opt = lambda_bind_list(context, plambda);
@@ -2717,6 +2751,19 @@ fn exp(context: &mut Context, pe: Box<P::Exp>) -> Box<E::Exp> {
         }
         PE::Continue(name) => EE::Continue(name),
         PE::Dereference(pe) => EE::Dereference(exp(context, pe)),
+        PE::UnaryExp(op @ sp!(oploc, P::UnaryOp_::Neg), pe) => match *pe {
+            // The sign is part of a signed literal, so that the minimum value of each type can be
+            // written, e.g. '-128i8'
+            sp!(_, PE::Value(sp!(_, P::Value_::Num(s)))) if is_signed_literal(&s) => {
+                let pv = sp(loc, P::Value_::Num(format!("-{}", s).into()));
+                unwrap_or_error_exp!(value(&mut context.defn_context, pv).map(EE::Value))
+            }
+            pe => {
+                let current_package = context.current_package();
+                context.check_feature(current_package, FeatureGate::SignedIntegers, oploc);
+                EE::UnaryExp(op, exp(context, Box::new(pe)))
+            }
+        },
         PE::UnaryExp(op, pe) => EE::UnaryExp(op, exp(context, pe)),
         PE::BinopExp(_pl, op, _pr) if op.value.is_spec_only() => {
             context.spec_deprecated(loc, /* is_error */ true);
@@ -2841,6 +2888,7 @@ fn exp_cast(context: &mut Context, in_parens: bool, plhs: Box<P::Exp>, pty: P::T
             PE::IfElse(_, _, _)
             | PE::While(_, _)
             | PE::Loop(_)
+            | PE::For(_, _, _)
             | PE::Labeled(_, _)
             | PE::Lambda(_, _, _)
             | PE::Quant(_, _, _, _, _)
@@ -2893,13 +2941,17 @@ fn maybe_labeled_exp(
             ensure_unique_label(context, loc, &label, label_opt);
             E::Exp_::Loop(Some(label), body)
         }
+        E::Exp_::For(label_opt, lv, e, body) => {
+            ensure_unique_label(context, loc, &label, label_opt);
+            E::Exp_::For(Some(label), lv, e, body)
+        }
         E::Exp_::Block(label_opt, seq) => {
             ensure_unique_label(context, loc, &label, label_opt);
             E::Exp_::Block(Some(label), seq)
         }
         _ => {
-            let msg = "Invalid label. Labels can only be used on 'while', 'loop', or block '{{}}' \
-                 expressions";
+            let msg = "Invalid label. Labels can only be used on 'while', 'loop', 'for', or block \
+                 '{{}}' expressions";
             context.add_diag(diag!(Syntax::InvalidLabel, (loc, msg)));
             E::Exp_::UnresolvedError
         }
@@ -3260,6 +3312,15 @@ fn match_pattern(context: &mut Context, sp!(loc, pat_): P::MatchPattern) -> E::M
 pub(super) fn value(context: &mut DefnContext, sp!(loc, pvalue_): P::Value) -> Option<E::Value> {
     use E::Value_ as EV;
     use P::Value_ as PV;
+    if matches!(&pvalue_, PV::Num(s) if is_signed_literal(s)) {
+        let current_package = context.current_package;
+        context.env.check_feature(
+            &context.reporter,
+            current_package,
+            FeatureGate::SignedIntegers,
+            loc,
+        );
+    }
     let value_ = match pvalue_ {
         PV::Address(addr) => {
             let addr = top_level_address(context, /* suggest_declaration */ true, addr);
@@ -3307,6 +3368,48 @@ pub(super) fn value(context: &mut DefnContext, sp!(loc, pvalue_): P::Value) -> O
                 return None;
             }
         },
+        PV::Num(s) if s.ends_with("i8") => match parse_i8(&s[..s.len() - 2]) {
+            Ok((i, _format)) => EV::I8(i),
+            Err(_) => {
+                context.add_diag(signed_num_out_of_range_error(loc, &s, "'i8'"));
+                return None;
+            }
+        },
+        PV::Num(s) if s.ends_with("i16") => match parse_i16(&s[..s.len() - 3]) {
+            Ok((i, _format)) => EV::I16(i),
+            Err(_) => {
+                context.add_diag(signed_num_out_of_range_error(loc, &s, "'i16'"));
+                return None;
+            }
+        },
+        PV::Num(s) if s.ends_with("i32") => match parse_i32(&s[..s.len() - 3]) {
+            Ok((i, _format)) => EV::I32(i),
+            Err(_) => {
+                context.add_diag(signed_num_out_of_range_error(loc, &s, "'i32'"));
+                return None;
+            }
+        },
+        PV::Num(s) if s.ends_with("i64") => match parse_i64(&s[..s.len() - 3]) {
+            Ok((i, _format)) => EV::I64(i),
+            Err(_) => {
+                context.add_diag(signed_num_out_of_range_error(loc, &s, "'i64'"));
+                return None;
+            }
+        },
+        PV::Num(s) if s.ends_with("i128") => match parse_i128(&s[..s.len() - 4]) {
+            Ok((i, _format)) => EV::I128(i),
+            Err(_) => {
+                context.add_diag(signed_num_out_of_range_error(loc, &s, "'i128'"));
+                return None;
+            }
+        },
+        PV::Num(s) if s.ends_with("i256") => match parse_i256(&s[..s.len() - 4]) {
+            Ok((i, _format)) => EV::I256(i),
+            Err(_) => {
+                context.add_diag(signed_num_out_of_range_error(loc, &s, "'i256'"));
+                return None;
+            }
+        },
 
         PV::Num(s) => match parse_u256(&s) {
             Ok((u, _format)) => EV::InferredNum(u),
@@ -3337,6 +3440,13 @@ pub(super) fn value(context: &mut DefnContext, sp!(loc, pvalue_): P::Value) -> O
     Some(sp(loc, value_))
 }
 
+// Returns true if the number literal has a signed integer suffix, e.g. '1i8'
+fn is_signed_literal(s: &str) -> bool {
+    ["i8", "i16", "i32", "i64", "i128", "i256"]
+        .iter()
+        .any(|suffix| s.ends_with(suffix))
+}
+
 // Create an error for an integer literal that is too big to fit in its type.
 // This assumes that the literal is the current token.
 fn num_too_big_error(loc: Loc, type_description: &'static str) -> Diagnostic {
@@ -3352,6 +3462,28 @@ fn num_too_big_error(loc: Loc, type_description: &'static str) -> Diagnostic {
     )
 }
 
+// Create an error for a signed integer literal that does not fit in its type, where negative
+// literals are too small rather than too big.
+fn signed_num_out_of_range_error(
+    loc: Loc,
+    literal: &str,
+    type_description: &'static str,
+) -> Diagnostic {
+    if !literal.starts_with('-') {
+        return num_too_big_error(loc, type_description);
+    }
+    diag!(
+        Syntax::InvalidNumber,
+        (
+            loc,
+            format!(
+                "Invalid number literal. The given literal is too small to fit into {}",
+                type_description
+            )
+        ),
+    )
+}
+
 //**************************************************************************************************
 // Fields
 //**************************************************************************************************
@@ -3483,20 +3615,10 @@ fn lvalues(context: &mut Context, e: Box<P::Exp>) -> Option<LValue> {
             let er = exp(context, pr);
             L::Mutate(er)
         }
-        pdotted_ @ PE::Dot(_, _, _) => {
+        pdotted_ @ (PE::Dot(_, _, _) | PE::Index(_, _)) => {
             let dotted = exp_dotted(context, Box::new(sp(loc, pdotted_)))?;
             L::FieldMutate(dotted)
         }
-        PE::Index(_, _) => {
-            context.add_diag(diag!(
-                Syntax::InvalidLValue,
-                (
-                    loc,
-                    "Index syntax it not yet supported in left-hand positions"
-                )
-            ));
-            return None;
-        }
         _ => L::Assigns(sp(loc, vec![assign(context, sp(loc, e_))?])),
     };
     Some(al)
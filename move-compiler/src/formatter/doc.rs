@@ -0,0 +1,192 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! A small layout engine in the style of Wadler's "prettier printer". A document describes all
+//! possible layouts of the text and groups are laid out flat (on a single line) if they fit into
+//! the remaining line width, and broken (with each line break turned into a newline) otherwise.
+
+#[derive(Debug, Clone)]
+pub enum Doc {
+    Nil,
+    // Must not contain newlines unless it is verbatim text (e.g., a block comment), in which case
+    // the enclosing group is always broken.
+    Text(String),
+    // A space if flat, a newline if broken.
+    Line,
+    // Nothing if flat, a newline if broken.
+    SoftLine,
+    // Always a newline (forces all enclosing groups to break).
+    HardLine,
+    // Forces all enclosing groups to break without emitting anything.
+    BreakParent,
+    Concat(Vec<Doc>),
+    Nest(Box<Doc>),
+    Group(Box<Doc>),
+    // (broken, flat) variants depending on the mode of the enclosing group
+    IfBreak(Box<Doc>, Box<Doc>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Flat,
+    Break,
+}
+
+impl Doc {
+    pub fn text(s: impl Into<String>) -> Doc {
+        Doc::Text(s.into())
+    }
+
+    pub fn concat(docs: impl IntoIterator<Item = Doc>) -> Doc {
+        Doc::Concat(docs.into_iter().collect())
+    }
+
+    pub fn nest(doc: Doc) -> Doc {
+        Doc::Nest(Box::new(doc))
+    }
+
+    pub fn group(doc: Doc) -> Doc {
+        Doc::Group(Box::new(doc))
+    }
+
+    pub fn if_break(broken: Doc, flat: Doc) -> Doc {
+        Doc::IfBreak(Box::new(broken), Box::new(flat))
+    }
+
+    /// Joins documents with a separator.
+    pub fn join(docs: impl IntoIterator<Item = Doc>, sep: Doc) -> Doc {
+        let mut result = vec![];
+        for (idx, doc) in docs.into_iter().enumerate() {
+            if idx > 0 {
+                result.push(sep.clone());
+            }
+            result.push(doc);
+        }
+        Doc::Concat(result)
+    }
+
+    /// Checks if this document will never be laid out on a single line.
+    pub fn forces_break(&self) -> bool {
+        match self {
+            Doc::HardLine | Doc::BreakParent => true,
+            Doc::Text(s) => s.contains('\n'),
+            Doc::Concat(docs) => docs.iter().any(Doc::forces_break),
+            Doc::Nest(doc) | Doc::Group(doc) => doc.forces_break(),
+            Doc::IfBreak(_, flat) => flat.forces_break(),
+            Doc::Nil | Doc::Line | Doc::SoftLine => false,
+        }
+    }
+
+    /// Lays out the document within a given line width, indenting nested documents by a given
+    /// number of spaces. Lines never end with trailing whitespace.
+    pub fn render(&self, width: usize, indent: usize) -> String {
+        let mut out = String::new();
+        let mut column = 0;
+        // indentation is only emitted once some text follows the newline
+        let mut pending_indent = None;
+        let mut stack: Vec<(usize, Mode, &Doc)> = vec![(0, Mode::Break, self)];
+        while let Some((ind, mode, doc)) = stack.pop() {
+            match doc {
+                Doc::Nil | Doc::BreakParent => (),
+                Doc::Text(s) => emit(&mut out, &mut column, &mut pending_indent, s),
+                Doc::Line | Doc::SoftLine if mode == Mode::Flat => {
+                    if matches!(doc, Doc::Line) {
+                        emit(&mut out, &mut column, &mut pending_indent, " ");
+                    }
+                }
+                Doc::Line | Doc::SoftLine | Doc::HardLine => {
+                    out.push('\n');
+                    column = 0;
+                    pending_indent = Some(ind);
+                }
+                Doc::Concat(docs) => {
+                    for doc in docs.iter().rev() {
+                        stack.push((ind, mode, doc));
+                    }
+                }
+                Doc::Nest(doc) => stack.push((ind + indent, mode, doc)),
+                Doc::Group(doc) => {
+                    let start = pending_indent.unwrap_or(column);
+                    let mode =
+                        if mode == Mode::Flat || fits(width.saturating_sub(start), doc, &stack) {
+                            Mode::Flat
+                        } else {
+                            Mode::Break
+                        };
+                    stack.push((ind, mode, doc));
+                }
+                Doc::IfBreak(broken, flat) => {
+                    let doc = if mode == Mode::Break { broken } else { flat };
+                    stack.push((ind, mode, doc));
+                }
+            }
+        }
+        out
+    }
+}
+
+fn emit(out: &mut String, column: &mut usize, pending_indent: &mut Option<usize>, s: &str) {
+    if s.is_empty() {
+        return;
+    }
+    if let Some(ind) = pending_indent.take() {
+        out.extend(std::iter::repeat_n(' ', ind));
+        *column = ind;
+    }
+    out.push_str(s);
+    *column = match s.rfind('\n') {
+        Some(idx) => s[idx + 1..].chars().count(),
+        None => *column + s.chars().count(),
+    };
+}
+
+/// Checks if a document laid out flat fits into the remaining width, taking into account the text
+/// following it (up to the next line break).
+fn fits(mut remaining: usize, doc: &Doc, rest: &[(usize, Mode, &Doc)]) -> bool {
+    let mut rest = rest.iter().rev();
+    let mut stack = vec![(Mode::Flat, doc)];
+    loop {
+        let Some((mode, doc)) = stack.pop() else {
+            match rest.next() {
+                Some((_, mode, doc)) => {
+                    stack.push((*mode, *doc));
+                    continue;
+                }
+                None => return true,
+            }
+        };
+        match doc {
+            Doc::Nil => (),
+            Doc::BreakParent => {
+                if mode == Mode::Flat {
+                    return false;
+                }
+            }
+            Doc::Text(s) => {
+                if s.contains('\n') {
+                    return mode == Mode::Break && s[..s.find('\n').unwrap()].len() <= remaining;
+                }
+                let len = s.chars().count();
+                if len > remaining {
+                    return false;
+                }
+                remaining -= len;
+            }
+            Doc::Line | Doc::SoftLine | Doc::HardLine if mode == Mode::Break => return true,
+            Doc::SoftLine => (),
+            Doc::Line => {
+                if remaining == 0 {
+                    return false;
+                }
+                remaining -= 1;
+            }
+            Doc::HardLine => return false,
+            Doc::Concat(docs) => stack.extend(docs.iter().rev().map(|doc| (mode, doc))),
+            Doc::Nest(doc) | Doc::Group(doc) => stack.push((mode, doc)),
+            Doc::IfBreak(broken, flat) => {
+                stack.push((mode, if mode == Mode::Break { broken } else { flat }))
+            }
+        }
+    }
+}
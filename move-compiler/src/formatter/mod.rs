@@ -0,0 +1,323 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! Source formatter for Move. A file is parsed and pretty-printed from its AST with all (regular
+//! and documentation) comments kept in place. As a safeguard against losing or changing any code,
+//! the result is checked to consist of the same tokens and comments as the original source (modulo
+//! commas and the order of use declarations) and formatting fails otherwise.
+
+mod doc;
+mod printer;
+
+use crate::{
+    diagnostics::{codes::Severity, report_diagnostics_to_buffer},
+    editions::{Edition, Flavor},
+    parser::{
+        comments::{extract_comments, verify_string},
+        lexer::{Lexer, Tok},
+        syntax::parse_file_string,
+    },
+    shared::{files::MappedFiles, CompilationEnv, Flags, PackageConfig},
+};
+use anyhow::{anyhow, bail};
+use move_command_line_common::files::FileHash;
+use move_symbol_pool::Symbol;
+use printer::Printer;
+use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+
+/// Default maximum line width
+pub const DEFAULT_MAX_WIDTH: usize = 100;
+/// Default number of spaces used for a single level of indentation
+pub const DEFAULT_INDENT: usize = 4;
+
+/// Formatter configuration (can be specified in the `[format]` section of the package manifest)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatConfig {
+    pub max_width: usize,
+    pub indent: usize,
+    pub group_imports: ImportGrouping,
+}
+
+/// How module-level use declarations are ordered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImportGrouping {
+    /// Keep the original order
+    #[default]
+    Preserve,
+    /// Sort each run of consecutive use declarations
+    Sort,
+    /// Sort each run of consecutive use declarations and split it into (blank line separated)
+    /// groups: system packages, other packages, and the module's own package
+    Group,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            max_width: DEFAULT_MAX_WIDTH,
+            indent: DEFAULT_INDENT,
+            group_imports: ImportGrouping::default(),
+        }
+    }
+}
+
+impl FromStr for ImportGrouping {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "sort" => Ok(Self::Sort),
+            "group" => Ok(Self::Group),
+            _ => bail!(
+                "Invalid import grouping '{s}'. Expected one of: 'preserve', 'sort', or 'group'"
+            ),
+        }
+    }
+}
+
+/// Formats the content of a Move source file. Files with syntax errors are not formatted (the
+/// error contains the rendered diagnostics, with `file_name` used to identify the file).
+pub fn format_source(
+    file_name: &str,
+    source: &str,
+    edition: Edition,
+    config: &FormatConfig,
+) -> anyhow::Result<String> {
+    let defs = parse(file_name, source, edition)?;
+    let comments = extract_comments(source);
+    let mut printer = Printer::new(source, comments, config, edition);
+    let formatted = printer.file(&defs).render(config.max_width, config.indent);
+
+    // make sure that formatting did not change the meaning of the code
+    parse(file_name, &formatted, edition).map_err(|_| {
+        anyhow!("Formatting '{file_name}' failed: the formatted code does not parse")
+    })?;
+    if !same_tokens(source, &formatted, edition) || !same_comments(source, &formatted) {
+        bail!("Formatting '{file_name}' failed: the formatted code does not match the original");
+    }
+    Ok(formatted)
+}
+
+fn parse(
+    file_name: &str,
+    source: &str,
+    edition: Edition,
+) -> anyhow::Result<Vec<crate::parser::ast::Definition>> {
+    let file_hash = FileHash::new(source);
+    let env = CompilationEnv::new(
+        Flags::empty(),
+        vec![],
+        vec![],
+        None,
+        BTreeMap::new(),
+        Some(PackageConfig {
+            edition,
+            flavor: Flavor::default(),
+            ..PackageConfig::default()
+        }),
+        None,
+    );
+    let result = verify_string(file_hash, source)
+        .and_then(|()| parse_file_string(&env, file_hash, source, None));
+    let diags = match result {
+        Ok(defs) => match env.check_diags_at_or_above_severity(Severity::NonblockingError) {
+            Ok(()) => return Ok(defs),
+            Err(diags) => diags,
+        },
+        Err(diags) => diags,
+    };
+    let mut files = MappedFiles::empty();
+    files.add(file_hash, Symbol::from(file_name), Arc::from(source));
+    let rendered = report_diagnostics_to_buffer(&files, diags, /* ansi_color */ false);
+    Err(anyhow!(
+        "Cannot format '{file_name}' due to syntax errors:\n{}",
+        String::from_utf8_lossy(&rendered)
+    ))
+}
+
+/// Checks that two source texts consist of the same tokens, ignoring commas (which are optional
+/// at the end of lists) and the order of use declarations (which can be sorted).
+fn same_tokens(original: &str, formatted: &str, edition: Edition) -> bool {
+    tokens(original, edition) == tokens(formatted, edition)
+}
+
+fn tokens(source: &str, edition: Edition) -> Option<(Vec<String>, Vec<Vec<String>>)> {
+    let mut lexer = Lexer::new(source, FileHash::new(source), edition);
+    lexer.advance().ok()?;
+    let mut toks: Vec<String> = vec![];
+    let mut uses = vec![];
+    // start of the current statement (or declaration)
+    let mut stmt_start = 0;
+    let mut in_use = None;
+    while lexer.peek() != Tok::EOF {
+        match lexer.peek() {
+            Tok::Comma => (),
+            Tok::Use => {
+                // attributes preceding the declaration are moved along with it
+                let attrs_only = toks[stmt_start..].first().is_none_or(|t| t == "#");
+                in_use = Some(if attrs_only { stmt_start } else { toks.len() });
+                toks.push(lexer.content().to_string());
+            }
+            Tok::Semicolon if in_use.is_some() => {
+                toks.push(lexer.content().to_string());
+                uses.push(toks.split_off(in_use.take().unwrap()));
+                stmt_start = toks.len();
+            }
+            tok => {
+                toks.push(lexer.content().to_string());
+                if matches!(tok, Tok::Semicolon | Tok::LBrace | Tok::RBrace) && in_use.is_none() {
+                    stmt_start = toks.len();
+                }
+            }
+        }
+        lexer.advance().ok()?;
+    }
+    uses.sort();
+    Some((toks, uses))
+}
+
+fn same_comments(original: &str, formatted: &str) -> bool {
+    let comments = |source: &str| {
+        let mut comments = extract_comments(source)
+            .into_iter()
+            .map(|c| {
+                // indentation of multi-line comments can change
+                let text = c.text(source).lines().map(str::trim).collect::<Vec<_>>();
+                text.join("\n")
+            })
+            .collect::<Vec<_>>();
+        comments.sort();
+        comments
+    };
+    comments(original) == comments(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(source: &str, config: &FormatConfig) -> String {
+        let formatted = format_source("test.move", source, Edition::E2024_BETA, config).unwrap();
+        let reformatted = format_source("test.move", &formatted, Edition::E2024_BETA, config);
+        assert_eq!(formatted, reformatted.unwrap(), "formatting is not idempotent");
+        formatted
+    }
+
+    #[test]
+    fn test_format_module() {
+        let source = r#"
+/// Doc comment
+module 0x42::m { use std::vector ;
+    // leading comment
+    public struct S has copy,drop { f: u64, g: vector<u8> } // trailing
+
+    public fun foo(x: u64, v: vector<u64>): u64 { let y = x+1; // add one
+        if (y > 10) { return y }; vector::length(&v) + /* inline */ y
+    }
+}
+"#;
+        let expected = r#"/// Doc comment
+module 0x42::m {
+    use std::vector;
+    // leading comment
+    public struct S has copy, drop {
+        f: u64,
+        g: vector<u8>,
+    } // trailing
+
+    public fun foo(x: u64, v: vector<u64>): u64 {
+        let y = x + 1; // add one
+        if (y > 10) {
+            return y
+        };
+        vector::length(&v) + /* inline */ y
+    }
+}
+"#;
+        assert_eq!(format(source, &FormatConfig::default()), expected);
+    }
+
+    #[test]
+    fn test_format_width_and_indent() {
+        let source = r#"module 0x42::m {
+    fun foo() { bar(first_argument, second_argument, third_argument) }
+}
+"#;
+        let expected = r#"module 0x42::m {
+  fun foo() {
+    bar(
+      first_argument,
+      second_argument,
+      third_argument,
+    )
+  }
+}
+"#;
+        let config = FormatConfig {
+            max_width: 40,
+            indent: 2,
+            ..FormatConfig::default()
+        };
+        assert_eq!(format(source, &config), expected);
+    }
+
+    #[test]
+    fn test_group_imports() {
+        let source = r#"module pkg::m {
+    use pkg::n;
+    use other::x;
+    use std::vector; // vector
+    use bos::object;
+    fun foo() {}
+}
+"#;
+        let expected = r#"module pkg::m {
+    use bos::object;
+    use std::vector; // vector
+
+    use other::x;
+
+    use pkg::n;
+    fun foo() {}
+}
+"#;
+        let config = FormatConfig {
+            group_imports: ImportGrouping::Group,
+            ..FormatConfig::default()
+        };
+        assert_eq!(format(source, &config), expected);
+    }
+
+    #[test]
+    fn test_format_for_loop() {
+        let source = r#"module 0x42::m {
+    fun sum(v: &vector<u64>): u64 { let mut s = 0; 'a: for (x   in v) { if (*x == 0) break 'a; s = s + *x }; s }
+}
+"#;
+        let expected = r#"module 0x42::m {
+    fun sum(v: &vector<u64>): u64 {
+        let mut s = 0;
+        'a: for (x in v) {
+            if (*x == 0) break 'a;
+            s = s + *x
+        };
+        s
+    }
+}
+"#;
+        assert_eq!(format(source, &FormatConfig::default()), expected);
+    }
+
+    #[test]
+    fn test_syntax_error() {
+        let result = format_source(
+            "test.move",
+            "module 0x42::m { fun }",
+            Edition::E2024_BETA,
+            &FormatConfig::default(),
+        );
+        assert!(result.is_err());
+    }
+}
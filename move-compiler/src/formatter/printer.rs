@@ -0,0 +1,1733 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! Translation of the parser AST into a layout document. The AST does not retain all details of
+//! the source text (e.g., comments or the exact spelling of literals), so the original text is
+//! consulted where needed. Comments are emitted based on their position in the source: each
+//! comment is printed right before the first syntactic element that follows it, unless it ends the
+//! line of a list element or a statement, in which case it stays at the end of that line.
+
+use crate::{
+    bos_mode::{BOS_ADDR_NAME, STD_ADDR_NAME},
+    editions::{Edition, FeatureGate},
+    formatter::{doc::Doc, FormatConfig, ImportGrouping},
+    parser::{
+        ast::*,
+        comments::{Comment, CommentKind},
+    },
+    shared::{Identifier, Name},
+};
+use move_core_types::account_address::AccountAddress;
+use move_ir_types::location::*;
+
+pub(super) struct Printer<'a> {
+    source: &'a str,
+    comments: Vec<Comment>,
+    // index of the first comment that has not been printed yet
+    next_comment: usize,
+    config: &'a FormatConfig,
+    edition: Edition,
+}
+
+// Element of a list printed one per line (module members, statements, etc.)
+struct LineItem {
+    // start of the item, including its attributes
+    start: u32,
+    end: u32,
+    doc: Doc,
+}
+
+impl<'a> Printer<'a> {
+    pub(super) fn new(
+        source: &'a str,
+        comments: Vec<Comment>,
+        config: &'a FormatConfig,
+        edition: Edition,
+    ) -> Self {
+        Self {
+            source,
+            comments,
+            next_comment: 0,
+            config,
+            edition,
+        }
+    }
+
+    //**********************************************************************************************
+    // Source text and comments
+    //**********************************************************************************************
+
+    fn src(&self, loc: Loc) -> &'a str {
+        &self.source[loc.start() as usize..loc.end() as usize]
+    }
+
+    fn name(&self, n: &Name) -> Doc {
+        // names can be synthesized by the parser (e.g., `Self` in module uses) in which case they
+        // do not correspond to the source text
+        match self
+            .source
+            .get(n.loc.start() as usize..n.loc.end() as usize)
+        {
+            Some(text) if text.trim_matches('`') == n.value.as_str() => Doc::text(text),
+            _ => Doc::text(n.value.as_str()),
+        }
+    }
+
+    /// Prints a source fragment as is (along with any comments inside it), except for the
+    /// indentation of its lines, which is adjusted to the current indentation level.
+    fn verbatim(&mut self, loc: Loc) -> Doc {
+        let leading = self.comments_before(loc.start());
+        while self
+            .comments
+            .get(self.next_comment)
+            .is_some_and(|c| c.start < loc.end())
+        {
+            self.next_comment += 1;
+        }
+        Doc::concat([
+            leading,
+            self.reindented(loc.start(), self.src(loc).trim_end()),
+        ])
+    }
+
+    /// Splits a (multi-line) source fragment starting at a given position into lines, removing the
+    /// indentation of the line the fragment starts at from all lines except for the first one.
+    fn reindented(&self, start: u32, text: &str) -> Doc {
+        let line_start = self.source[..start as usize]
+            .rfind('\n')
+            .map_or(0, |idx| idx + 1);
+        let line = &self.source[line_start..];
+        let indent = line.len() - line.trim_start_matches([' ', '\t']).len();
+        let lines = text.lines().enumerate().map(|(idx, line)| {
+            let line = line.trim_end();
+            if idx == 0 {
+                return Doc::text(line);
+            }
+            let line_indent = line.len() - line.trim_start_matches([' ', '\t']).len();
+            Doc::text(&line[line_indent.min(indent)..])
+        });
+        Doc::join(lines, Doc::HardLine)
+    }
+
+    /// Prints a header of a declaration (e.g., `public(package) entry fun `) with normalized
+    /// whitespace.
+    fn header(&mut self, loc: Loc) -> Doc {
+        let leading = self.comments_before(loc.start());
+        let text = self.src(loc);
+        if self
+            .comments
+            .get(self.next_comment)
+            .is_some_and(|c| c.start < loc.end())
+        {
+            return Doc::concat([leading, self.verbatim(loc), Doc::text(" ")]);
+        }
+        let normalized = text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .replace(" (", "(")
+            .replace("( ", "(")
+            .replace(" )", ")");
+        Doc::concat([leading, Doc::text(normalized), Doc::text(" ")])
+    }
+
+    fn next_comment_start(&self) -> Option<u32> {
+        self.comments.get(self.next_comment).map(|c| c.start)
+    }
+
+    /// Returns the position where the text preceding a given element starts, which is the start of
+    /// the first unprinted comment in front of it (if any).
+    fn leading_start(&self, pos: u32) -> u32 {
+        self.next_comment_start()
+            .filter(|start| *start < pos)
+            .unwrap_or(pos)
+    }
+
+    /// Checks if there is at least one blank line between two positions (ignoring comments).
+    fn blank_line_between(&self, start: u32, end: u32) -> bool {
+        let mut newlines = 0;
+        let mut pos = start as usize;
+        let end = end as usize;
+        while pos < end {
+            if let Some(c) = self
+                .comments
+                .iter()
+                .find(|c| c.start as usize <= pos && pos < c.end as usize)
+            {
+                newlines = 0;
+                pos = c.end as usize;
+                continue;
+            }
+            match self.source.as_bytes()[pos] {
+                b'\n' => {
+                    newlines += 1;
+                    if newlines > 1 {
+                        return true;
+                    }
+                }
+                b' ' | b'\t' | b'\r' => (),
+                _ => newlines = 0,
+            }
+            pos += 1;
+        }
+        false
+    }
+
+    fn newline_between(&self, start: u32, end: u32) -> bool {
+        start < end && self.source[start as usize..end as usize].contains('\n')
+    }
+
+    /// Prints all comments preceding a given position that have not been printed yet.
+    fn comments_before(&mut self, pos: u32) -> Doc {
+        let mut docs = vec![];
+        while let Some(c) = self.comments.get(self.next_comment).copied() {
+            if c.start >= pos {
+                break;
+            }
+            self.next_comment += 1;
+            let next = self.leading_start(pos);
+            docs.push(self.reindented(c.start, c.text(self.source)));
+            if c.kind == CommentKind::Line || self.newline_between(c.end, next) {
+                docs.push(Doc::HardLine);
+                if self.blank_line_between(c.end, next) {
+                    docs.push(Doc::HardLine);
+                }
+            } else {
+                docs.push(Doc::text(" "));
+            }
+        }
+        Doc::concat(docs)
+    }
+
+    /// Prints comments that follow a given position on the same line, possibly after a separator.
+    fn trailing_comments(&mut self, pos: u32) -> Doc {
+        let mut docs = vec![];
+        let mut pos = pos as usize;
+        while let Some(c) = self.comments.get(self.next_comment).copied() {
+            let between = self.source.get(pos..c.start as usize).unwrap_or("\n");
+            if !between
+                .chars()
+                .all(|ch| matches!(ch, ' ' | '\t' | ',' | ';'))
+            {
+                break;
+            }
+            self.next_comment += 1;
+            docs.push(Doc::text(" "));
+            docs.push(self.reindented(c.start, c.text(self.source)));
+            if c.kind == CommentKind::Line {
+                docs.push(Doc::BreakParent);
+                break;
+            }
+            pos = c.end as usize;
+        }
+        Doc::concat(docs)
+    }
+
+    /// Finds the position of the first token after a given position, skipping separators.
+    fn closing_pos(&self, pos: u32) -> u32 {
+        let mut pos = pos as usize;
+        let bytes = self.source.as_bytes();
+        while pos < bytes.len() {
+            if let Some(c) = self
+                .comments
+                .iter()
+                .find(|c| c.start as usize <= pos && pos < c.end as usize)
+            {
+                pos = c.end as usize;
+                continue;
+            }
+            if !matches!(bytes[pos], b' ' | b'\t' | b'\r' | b'\n' | b',') {
+                break;
+            }
+            pos += 1;
+        }
+        pos as u32
+    }
+
+    /// Finds the first occurrence of one of the given characters outside of comments.
+    fn find_char(&self, start: u32, end: u32, chars: &[u8]) -> Option<u32> {
+        let mut pos = start as usize;
+        while pos < end as usize {
+            if let Some(c) = self
+                .comments
+                .iter()
+                .find(|c| c.start as usize <= pos && pos < c.end as usize)
+            {
+                pos = c.end as usize;
+                continue;
+            }
+            if chars.contains(&self.source.as_bytes()[pos]) {
+                return Some(pos as u32);
+            }
+            pos += 1;
+        }
+        None
+    }
+
+    //**********************************************************************************************
+    // Layout helpers
+    //**********************************************************************************************
+
+    /// Prints items one per line, preserving (single) blank lines between them.
+    fn lines(&self, items: Vec<LineItem>) -> Doc {
+        let mut docs = vec![];
+        let mut prev_end = None;
+        for item in items {
+            if let Some(prev_end) = prev_end {
+                docs.push(Doc::HardLine);
+                if self.blank_line_between(prev_end, item.start) {
+                    docs.push(Doc::HardLine);
+                }
+            }
+            docs.push(item.doc);
+            prev_end = Some(item.end);
+        }
+        Doc::concat(docs)
+    }
+
+    /// Creates an item to be printed on its own line with leading and trailing comments.
+    fn line_item(
+        &mut self,
+        start: u32,
+        end: u32,
+        print: impl FnOnce(&mut Self) -> Doc,
+        sep: &str,
+    ) -> LineItem {
+        let item_start = self.leading_start(start);
+        let leading = self.comments_before(start);
+        let doc = print(self);
+        let trailing = self.trailing_comments(end);
+        LineItem {
+            start: item_start,
+            end,
+            doc: Doc::concat([leading, doc, Doc::text(sep), trailing]),
+        }
+    }
+
+    /// Prints a block of items (one per line) enclosed in braces.
+    fn braced_lines(&mut self, items: Vec<LineItem>, close_pos: u32) -> Doc {
+        let trailing_start = self.leading_start(close_pos);
+        let dangling = self.comments_before(close_pos);
+        let has_dangling = !matches!(&dangling, Doc::Concat(docs) if docs.is_empty());
+        if items.is_empty() && !has_dangling {
+            return Doc::text("{}");
+        }
+        let last_end = items.last().map(|item| item.end);
+        let mut inner = vec![self.lines(items)];
+        if has_dangling {
+            if let Some(last_end) = last_end {
+                inner.push(Doc::HardLine);
+                if self.blank_line_between(last_end, trailing_start) {
+                    inner.push(Doc::HardLine);
+                }
+            }
+            inner.push(strip_trailing_hardline(dangling));
+        }
+        Doc::concat([
+            Doc::text("{"),
+            Doc::nest(Doc::concat([Doc::HardLine, Doc::concat(inner)])),
+            Doc::HardLine,
+            Doc::text("}"),
+        ])
+    }
+
+    /// Prints a comma separated list of items which is laid out on a single line if it fits or
+    /// with one item per line (and a trailing comma) otherwise.
+    fn list<T>(
+        &mut self,
+        [open, close]: [&str; 2],
+        items: &[T],
+        close_pos: u32,
+        spaced: bool,
+        loc: impl Fn(&T) -> Loc,
+        mut print: impl FnMut(&mut Self, &T) -> Doc,
+    ) -> Doc {
+        let line = if spaced { Doc::Line } else { Doc::SoftLine };
+        let mut docs = vec![];
+        for (idx, item) in items.iter().enumerate() {
+            let item_loc = loc(item);
+            if idx > 0 {
+                docs.push(Doc::Line);
+            }
+            docs.push(self.comments_before(item_loc.start()));
+            docs.push(print(self, item));
+            if idx + 1 < items.len() {
+                docs.push(Doc::text(","));
+            } else {
+                docs.push(Doc::if_break(Doc::text(","), Doc::Nil));
+            }
+            docs.push(self.trailing_comments(item_loc.end()));
+        }
+        let dangling = self.comments_before(close_pos);
+        if !matches!(&dangling, Doc::Concat(d) if d.is_empty()) {
+            if !items.is_empty() {
+                docs.push(Doc::Line);
+            }
+            docs.push(strip_trailing_hardline(dangling));
+            docs.push(Doc::BreakParent);
+        } else if items.is_empty() {
+            return Doc::text(format!("{open}{close}"));
+        }
+        Doc::group(Doc::concat([
+            Doc::text(open),
+            Doc::nest(Doc::concat([line.clone(), Doc::concat(docs)])),
+            line,
+            Doc::text(close),
+        ]))
+    }
+
+    //**********************************************************************************************
+    // Definitions
+    //**********************************************************************************************
+
+    pub(super) fn file(&mut self, defs: &[Definition]) -> Doc {
+        let mut items = vec![];
+        for def in defs {
+            let (attributes, loc) = match def {
+                Definition::Module(m) => (&m.attributes, m.loc),
+                Definition::Address(a) => (&a.attributes, a.loc),
+            };
+            let start = attrs_start(attributes, loc);
+            items.push(self.line_item(
+                start,
+                loc.end(),
+                |p| {
+                    let attrs = p.attributes(attributes);
+                    let doc = match def {
+                        Definition::Module(m) => p.module(m),
+                        Definition::Address(a) => p.address(a),
+                    };
+                    Doc::concat([attrs, doc])
+                },
+                "",
+            ));
+        }
+        let trailing_start = self.leading_start(self.source.len() as u32);
+        let last_end = items.last().map(|item| item.end);
+        let mut docs = vec![self.lines(items)];
+        let rest = self.comments_before(self.source.len() as u32);
+        if !matches!(&rest, Doc::Concat(d) if d.is_empty()) {
+            if let Some(last_end) = last_end {
+                docs.push(Doc::HardLine);
+                if self.blank_line_between(last_end, trailing_start) {
+                    docs.push(Doc::HardLine);
+                }
+            }
+            docs.push(strip_trailing_hardline(rest));
+        }
+        docs.push(Doc::HardLine);
+        Doc::concat(docs)
+    }
+
+    fn attributes(&mut self, attributes: &[Attributes]) -> Doc {
+        let mut docs = vec![];
+        for attrs in attributes {
+            docs.push(self.comments_before(attrs.loc.start()));
+            let close_pos = attrs.loc.end() - 1;
+            docs.push(Doc::text("#"));
+            docs.push(self.list(
+                ["[", "]"],
+                &attrs.value,
+                close_pos,
+                false,
+                |a| a.loc,
+                |p, a| p.attribute(a),
+            ));
+            docs.push(Doc::HardLine);
+        }
+        Doc::concat(docs)
+    }
+
+    fn attribute(&mut self, attr: &Attribute) -> Doc {
+        match &attr.value {
+            Attribute_::Name(n) => self.name(n),
+            Attribute_::Assigned(n, v) => {
+                let value = match &v.value {
+                    AttributeValue_::Value(v) => Doc::text(self.src(v.loc)),
+                    AttributeValue_::ModuleAccess(chain) => self.chain(chain),
+                };
+                Doc::concat([self.name(n), Doc::text(" = "), value])
+            }
+            Attribute_::Parameterized(n, attrs) => {
+                let name = self.name(n);
+                let close_pos =
+                    self.closing_pos(attrs.value.last().map_or(n.loc.end() + 1, |a| a.loc.end()));
+                let args = self.list(
+                    ["(", ")"],
+                    &attrs.value,
+                    close_pos,
+                    false,
+                    |a| a.loc,
+                    |p, a| p.attribute(a),
+                );
+                Doc::concat([name, args])
+            }
+        }
+    }
+
+    fn address(&mut self, a: &AddressDefinition) -> Doc {
+        let addr = self.leading_name_access(&a.addr);
+        let mut items = vec![];
+        for m in &a.modules {
+            let start = attrs_start(&m.attributes, m.loc);
+            items.push(self.line_item(
+                start,
+                m.loc.end(),
+                |p| {
+                    let attrs = p.attributes(&m.attributes);
+                    Doc::concat([attrs, p.module(m)])
+                },
+                "",
+            ));
+        }
+        let body = self.braced_lines(items, a.loc.end() - 1);
+        Doc::concat([Doc::text("address "), addr, Doc::text(" "), body])
+    }
+
+    fn module(&mut self, m: &ModuleDefinition) -> Doc {
+        if m.is_spec_module {
+            return self.verbatim(m.loc);
+        }
+        let name_start = m
+            .address
+            .as_ref()
+            .map_or(m.name.loc().start(), |a| a.loc.start());
+        let mut header = vec![self.header(span(m.loc, m.loc.start(), name_start))];
+        if let Some(addr) = &m.address {
+            header.push(self.leading_name_access(addr));
+            header.push(Doc::text("::"));
+        }
+        header.push(self.name(&m.name.0));
+        let address_name = m.address.as_ref().and_then(|a| match &a.value {
+            LeadingNameAccess_::Name(n) | LeadingNameAccess_::GlobalAddress(n) => {
+                Some(n.value.as_str())
+            }
+            LeadingNameAccess_::AnonymousAddress(_) => None,
+        });
+        let items = self.members(&m.members, address_name);
+        match m.definition_mode {
+            ModuleDefinitionMode::Braces => {
+                header.push(Doc::text(" "));
+                header.push(self.braced_lines(items, m.loc.end() - 1));
+            }
+            ModuleDefinitionMode::Semicolon => {
+                header.push(Doc::text(";"));
+                if !items.is_empty() {
+                    header.push(Doc::HardLine);
+                    header.push(Doc::HardLine);
+                    header.push(self.lines(items));
+                }
+            }
+        }
+        Doc::concat(header)
+    }
+
+    fn members(&mut self, members: &[ModuleMember], address_name: Option<&str>) -> Vec<LineItem> {
+        let mut items = vec![];
+        let mut uses = vec![];
+        for member in members {
+            match member {
+                ModuleMember::Use(decl) if !matches!(decl.use_, Use::Fun { .. }) => {
+                    let start = attrs_start(&decl.attributes, decl.loc);
+                    let item = self.line_item(start, decl.loc.end(), |p| p.use_decl(decl), ";");
+                    uses.push((decl, item));
+                    continue;
+                }
+                _ => (),
+            }
+            items.extend(self.group_uses(std::mem::take(&mut uses), address_name));
+            let item = match member {
+                ModuleMember::Function(f) => {
+                    let start = attrs_start(&f.attributes, f.loc);
+                    self.line_item(start, f.loc.end(), |p| p.function(f), "")
+                }
+                ModuleMember::Struct(s) => {
+                    let start = attrs_start(&s.attributes, s.loc);
+                    self.line_item(start, s.loc.end(), |p| p.struct_def(s), "")
+                }
+                ModuleMember::Enum(e) => {
+                    let start = attrs_start(&e.attributes, e.loc);
+                    self.line_item(start, e.loc.end(), |p| p.enum_def(e), "")
+                }
+                ModuleMember::Use(decl) => {
+                    let start = attrs_start(&decl.attributes, decl.loc);
+                    self.line_item(start, decl.loc.end(), |p| p.use_decl(decl), ";")
+                }
+                ModuleMember::Friend(f) => {
+                    let start = attrs_start(&f.attributes, f.loc);
+                    self.line_item(
+                        start,
+                        f.loc.end(),
+                        |p| {
+                            let attrs = p.attributes(&f.attributes);
+                            let friend = p.chain(&f.friend);
+                            Doc::concat([attrs, Doc::text("friend "), friend])
+                        },
+                        ";",
+                    )
+                }
+                ModuleMember::Constant(c) => {
+                    let start = attrs_start(&c.attributes, c.loc);
+                    self.line_item(start, c.loc.end(), |p| p.constant(c), "")
+                }
+                ModuleMember::Spec(s) => {
+                    // the trailing semicolon (if any) is not a part of the spec's location
+                    let end = self
+                        .find_char(s.loc.end(), self.closing_pos(s.loc.end()) + 1, b";")
+                        .map_or(s.loc.end(), |pos| pos + 1);
+                    let loc = Loc::new(s.loc.file_hash(), s.loc.start(), end);
+                    self.line_item(loc.start(), loc.end(), |p| p.verbatim(loc), "")
+                }
+            };
+            items.push(item);
+        }
+        items.extend(self.group_uses(uses, address_name));
+        items
+    }
+
+    /// Reorders a run of consecutive use declarations according to the configuration.
+    fn group_uses(
+        &self,
+        uses: Vec<(&UseDecl, LineItem)>,
+        address_name: Option<&str>,
+    ) -> Vec<LineItem> {
+        if uses.is_empty() || self.config.group_imports == ImportGrouping::Preserve {
+            return uses.into_iter().map(|(_, item)| item).collect();
+        }
+        let mut groups: [Vec<(String, LineItem)>; 3] = Default::default();
+        for (decl, item) in uses {
+            let key = self.src(decl.loc).split_whitespace().collect::<String>();
+            let group = match self.config.group_imports {
+                ImportGrouping::Group => use_group(decl, address_name),
+                _ => 0,
+            };
+            groups[group].push((key, item));
+        }
+        let mut items: Vec<LineItem> = vec![];
+        for mut group in groups {
+            group.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
+            for (idx, (_, mut item)) in group.into_iter().enumerate() {
+                // groups are separated by a blank line
+                if !items.is_empty() && idx == 0 {
+                    item.doc = Doc::concat([Doc::HardLine, item.doc]);
+                }
+                items.push(item);
+            }
+        }
+        // positions are only used to preserve blank lines so the reordered items take over the
+        // span of the whole run (with no blank lines inside)
+        let run_start = items.iter().map(|item| item.start).min().unwrap();
+        let run_end = items.iter().map(|item| item.end).max().unwrap();
+        for (idx, item) in items.iter_mut().enumerate() {
+            item.start = if idx == 0 { run_start } else { run_end };
+            item.end = run_end;
+        }
+        items
+    }
+
+    fn use_decl(&mut self, decl: &UseDecl) -> Doc {
+        let attrs = self.attributes(&decl.attributes);
+        let use_ = match &decl.use_ {
+            Use::ModuleUse(mident, mu) => {
+                let mident = self.module_ident(mident);
+                let mu = self.module_use(mu, decl.loc);
+                Doc::concat([Doc::text("use "), mident, mu])
+            }
+            Use::NestedModuleUses(addr, uses) => {
+                let addr = self.leading_name_access(addr);
+                let close_pos = decl.loc.end() - 1;
+                let uses = self.list(
+                    ["{", "}"],
+                    uses,
+                    close_pos,
+                    false,
+                    |(m, _)| m.loc(),
+                    |p, (m, mu)| {
+                        let name = p.name(&m.0);
+                        let mu = p.module_use(mu, decl.loc);
+                        Doc::concat([name, mu])
+                    },
+                );
+                Doc::concat([Doc::text("use "), addr, Doc::text("::"), uses])
+            }
+            Use::Fun {
+                visibility,
+                function,
+                ty,
+                method,
+            } => {
+                let vis = match visibility {
+                    Visibility::Public(_) => "public ",
+                    Visibility::Friend(_) => "public(friend) ",
+                    Visibility::Package(_) => "public(package) ",
+                    Visibility::Internal => "",
+                };
+                Doc::concat([
+                    Doc::text(vis),
+                    Doc::text("use fun "),
+                    self.chain(function),
+                    Doc::text(" as "),
+                    self.chain(ty),
+                    Doc::text("."),
+                    self.name(method),
+                ])
+            }
+            Use::Partial { .. } => self.verbatim(decl.loc),
+        };
+        Doc::concat([attrs, use_])
+    }
+
+    fn module_use(&mut self, mu: &ModuleUse, decl_loc: Loc) -> Doc {
+        match mu {
+            ModuleUse::Module(None) => Doc::Nil,
+            ModuleUse::Module(Some(alias)) => Doc::concat([Doc::text(" as "), self.name(&alias.0)]),
+            ModuleUse::Members(members) => {
+                let first_start = members
+                    .first()
+                    .map_or(decl_loc.end(), |(n, _)| n.loc.start());
+                let braces = members.len() != 1
+                    || self
+                        .find_char(decl_loc.start(), first_start, b"{")
+                        .is_some_and(|pos| {
+                            // the brace must belong to this module use
+                            !self.source[pos as usize..first_start as usize].contains('}')
+                        });
+                if !braces {
+                    let (n, alias) = &members[0];
+                    return Doc::concat([Doc::text("::"), self.use_member(n, alias)]);
+                }
+                let last_end = members
+                    .last()
+                    .map_or(first_start, |(n, alias)| alias.unwrap_or(*n).loc.end());
+                let close_pos = self.closing_pos(last_end);
+                let list = self.list(
+                    ["{", "}"],
+                    members,
+                    close_pos,
+                    false,
+                    |(n, alias)| union(n.loc, alias.as_ref().map_or(n.loc, |a| a.loc)),
+                    |p, (n, alias)| p.use_member(n, alias),
+                );
+                Doc::concat([Doc::text("::"), list])
+            }
+            ModuleUse::Partial { .. } => Doc::Nil,
+        }
+    }
+
+    fn use_member(&mut self, n: &Name, alias: &Option<Name>) -> Doc {
+        match alias {
+            Some(alias) => Doc::concat([self.name(n), Doc::text(" as "), self.name(alias)]),
+            None => self.name(n),
+        }
+    }
+
+    fn function(&mut self, f: &Function) -> Doc {
+        let attrs = self.attributes(&f.attributes);
+        let header = self.header(span(f.loc, f.loc.start(), f.name.loc().start()));
+        let name = self.name(&f.name.0);
+        let tparams = self.type_parameters(&f.signature.type_parameters);
+        let params_start = f
+            .signature
+            .type_parameters
+            .last()
+            .map_or(f.name.loc().end(), |(n, abilities)| {
+                abilities.last().map_or(n.loc.end(), |a| a.loc.end())
+            });
+        let last_param_end = f
+            .signature
+            .parameters
+            .last()
+            .map_or(params_start, |(_, _, ty)| ty.loc.end());
+        let close_pos = self
+            .find_char(last_param_end, f.loc.end(), b")")
+            .unwrap_or(last_param_end);
+        let params = self.list(
+            ["(", ")"],
+            &f.signature.parameters,
+            close_pos,
+            false,
+            |(mut_, v, ty)| union(mut_.unwrap_or(v.loc()), ty.loc),
+            |p, (mut_, v, ty)| {
+                let mut_ = if mut_.is_some() {
+                    Doc::text("mut ")
+                } else {
+                    Doc::Nil
+                };
+                let v = p.name(&v.0);
+                let ty = p.type_(ty);
+                Doc::concat([mut_, v, Doc::text(": "), ty])
+            },
+        );
+        let ret = &f.signature.return_type;
+        let ret = if ret.loc != f.name.loc() {
+            Doc::concat([Doc::text(": "), self.type_(ret)])
+        } else {
+            Doc::Nil
+        };
+        let body = match &f.body.value {
+            FunctionBody_::Native => Doc::text(";"),
+            FunctionBody_::Defined(seq) => {
+                let close_pos = f.body.loc.end() - 1;
+                Doc::concat([Doc::text(" "), self.sequence(seq, close_pos, false)])
+            }
+        };
+        Doc::concat([
+            attrs,
+            header,
+            name,
+            tparams,
+            Doc::group(Doc::concat([params, ret])),
+            body,
+        ])
+    }
+
+    fn type_parameters(&mut self, tparams: &[(Name, Vec<Ability>)]) -> Doc {
+        if tparams.is_empty() {
+            return Doc::Nil;
+        }
+        let docs = tparams
+            .iter()
+            .map(|(n, abilities)| {
+                let name = self.name(n);
+                Doc::concat([name, self.constraints(abilities)])
+            })
+            .collect::<Vec<_>>();
+        Doc::concat([
+            Doc::text("<"),
+            Doc::join(docs, Doc::text(", ")),
+            Doc::text(">"),
+        ])
+    }
+
+    fn constraints(&mut self, abilities: &[Ability]) -> Doc {
+        if abilities.is_empty() {
+            return Doc::Nil;
+        }
+        Doc::concat([
+            Doc::text(": "),
+            Doc::join(
+                abilities.iter().map(|a| Doc::text(a.value.to_string())),
+                Doc::text(" + "),
+            ),
+        ])
+    }
+
+    fn datatype_type_parameters(&mut self, tparams: &[DatatypeTypeParameter]) -> Doc {
+        if tparams.is_empty() {
+            return Doc::Nil;
+        }
+        let docs = tparams
+            .iter()
+            .map(|tp| {
+                let phantom = if tp.is_phantom {
+                    Doc::text("phantom ")
+                } else {
+                    Doc::Nil
+                };
+                let name = self.name(&tp.name);
+                Doc::concat([phantom, name, self.constraints(&tp.constraints)])
+            })
+            .collect::<Vec<_>>();
+        Doc::concat([
+            Doc::text("<"),
+            Doc::join(docs, Doc::text(", ")),
+            Doc::text(">"),
+        ])
+    }
+
+    fn abilities(abilities: &[Ability]) -> Doc {
+        Doc::concat([
+            Doc::text("has "),
+            Doc::join(
+                abilities.iter().map(|a| Doc::text(a.value.to_string())),
+                Doc::text(", "),
+            ),
+        ])
+    }
+
+    /// Returns the location where the type parameters of a datatype end (or its name if there are
+    /// none).
+    fn datatype_header_end(&self, name: &DatatypeName, tparams: &[DatatypeTypeParameter]) -> u32 {
+        match tparams.last() {
+            Some(tp) => {
+                let end = tp
+                    .constraints
+                    .last()
+                    .map_or(tp.name.loc.end(), |a| a.loc.end());
+                self.find_char(end, self.source.len() as u32, b">")
+                    .map_or(end, |pos| pos + 1)
+            }
+            None => name.loc().end(),
+        }
+    }
+
+    fn struct_def(&mut self, s: &StructDefinition) -> Doc {
+        let attrs = self.attributes(&s.attributes);
+        let header = self.header(span(s.loc, s.loc.start(), s.name.loc().start()));
+        let name = self.name(&s.name.0);
+        let tparams = self.datatype_type_parameters(&s.type_parameters);
+        let header_end = self.datatype_header_end(&s.name, &s.type_parameters);
+        let body_start = self
+            .find_char(header_end, s.loc.end(), b"{(;")
+            .unwrap_or(s.loc.end());
+        let infix = s
+            .abilities
+            .first()
+            .is_some_and(|a| a.loc.start() < body_start);
+        let mut docs = vec![attrs, header, name, tparams];
+        if infix {
+            docs.push(Doc::text(" "));
+            docs.push(Self::abilities(&s.abilities));
+        }
+        match &s.fields {
+            StructFields::Native(_) => (),
+            StructFields::Named(fields) => {
+                let close_pos = self.closing_pos(
+                    fields
+                        .last()
+                        .map_or(body_start + 1, |(_, _, ty)| ty.loc.end()),
+                );
+                docs.push(Doc::text(" "));
+                docs.push(self.named_fields(fields, close_pos));
+            }
+            StructFields::Positional(fields) => {
+                let close_pos =
+                    self.closing_pos(fields.last().map_or(body_start + 1, |(_, ty)| ty.loc.end()));
+                docs.push(self.list(
+                    ["(", ")"],
+                    fields,
+                    close_pos,
+                    false,
+                    |(_, ty)| ty.loc,
+                    |p, (_, ty)| p.type_(ty),
+                ));
+            }
+        }
+        if !infix && !s.abilities.is_empty() {
+            docs.push(Doc::text(" "));
+            docs.push(Self::abilities(&s.abilities));
+        }
+        if self.src(s.loc).ends_with(';') {
+            docs.push(Doc::text(";"));
+        }
+        Doc::concat(docs)
+    }
+
+    fn named_fields(&mut self, fields: &[(DocComment, Field, Type)], close_pos: u32) -> Doc {
+        let mut items = vec![];
+        for (_, f, ty) in fields {
+            let loc = union(f.loc(), ty.loc);
+            items.push(self.line_item(
+                loc.start(),
+                loc.end(),
+                |p| {
+                    let name = p.name(&f.0);
+                    let ty = p.type_(ty);
+                    Doc::concat([name, Doc::text(": "), ty])
+                },
+                ",",
+            ));
+        }
+        self.braced_lines(items, close_pos)
+    }
+
+    fn enum_def(&mut self, e: &EnumDefinition) -> Doc {
+        let attrs = self.attributes(&e.attributes);
+        let header = self.header(span(e.loc, e.loc.start(), e.name.loc().start()));
+        let name = self.name(&e.name.0);
+        let tparams = self.datatype_type_parameters(&e.type_parameters);
+        let header_end = self.datatype_header_end(&e.name, &e.type_parameters);
+        let body_start = self
+            .find_char(header_end, e.loc.end(), b"{")
+            .unwrap_or(e.loc.end());
+        let infix = e
+            .abilities
+            .first()
+            .is_some_and(|a| a.loc.start() < body_start);
+        let mut docs = vec![attrs, header, name, tparams];
+        if infix {
+            docs.push(Doc::text(" "));
+            docs.push(Self::abilities(&e.abilities));
+        }
+        let mut items = vec![];
+        for v in &e.variants {
+            items.push(self.line_item(v.loc.start(), v.loc.end(), |p| p.variant(v), ","));
+        }
+        let close_pos = self.closing_pos(e.variants.last().map_or(body_start + 1, |v| v.loc.end()));
+        docs.push(Doc::text(" "));
+        docs.push(self.braced_lines(items, close_pos));
+        if !infix && !e.abilities.is_empty() {
+            docs.push(Doc::text(" "));
+            docs.push(Self::abilities(&e.abilities));
+        }
+        if self.src(e.loc).ends_with(';') {
+            docs.push(Doc::text(";"));
+        }
+        Doc::concat(docs)
+    }
+
+    fn variant(&mut self, v: &VariantDefinition) -> Doc {
+        let name = self.name(&v.name.0);
+        let fields = match &v.fields {
+            VariantFields::Empty => Doc::Nil,
+            VariantFields::Named(fields) => {
+                let close_pos = v.loc.end() - 1;
+                let fields = self.list(
+                    ["{", "}"],
+                    fields,
+                    close_pos,
+                    true,
+                    |(_, f, ty)| union(f.loc(), ty.loc),
+                    |p, (_, f, ty)| {
+                        let name = p.name(&f.0);
+                        let ty = p.type_(ty);
+                        Doc::concat([name, Doc::text(": "), ty])
+                    },
+                );
+                Doc::concat([Doc::text(" "), fields])
+            }
+            VariantFields::Positional(fields) => {
+                let close_pos = v.loc.end() - 1;
+                self.list(
+                    ["(", ")"],
+                    fields,
+                    close_pos,
+                    false,
+                    |(_, ty)| ty.loc,
+                    |p, (_, ty)| p.type_(ty),
+                )
+            }
+        };
+        Doc::concat([name, fields])
+    }
+
+    fn constant(&mut self, c: &Constant) -> Doc {
+        let attrs = self.attributes(&c.attributes);
+        let header = self.header(span(c.loc, c.loc.start(), c.name.loc().start()));
+        let name = self.name(&c.name.0);
+        let ty = self.type_(&c.signature);
+        let value = self.assigned(&c.value);
+        Doc::concat([
+            attrs,
+            header,
+            name,
+            Doc::text(": "),
+            ty,
+            value,
+            Doc::text(";"),
+        ])
+    }
+
+    //**********************************************************************************************
+    // Names and types
+    //**********************************************************************************************
+
+    fn leading_name_access(&mut self, name: &LeadingNameAccess) -> Doc {
+        let leading = self.comments_before(name.loc.start());
+        let doc = match &name.value {
+            LeadingNameAccess_::AnonymousAddress(_) => Doc::text(self.src(name.loc)),
+            LeadingNameAccess_::GlobalAddress(n) => Doc::concat([Doc::text("::"), self.name(n)]),
+            LeadingNameAccess_::Name(n) => self.name(n),
+        };
+        Doc::concat([leading, doc])
+    }
+
+    fn module_ident(&mut self, mident: &ModuleIdent) -> Doc {
+        let addr = self.leading_name_access(&mident.value.address);
+        Doc::concat([addr, Doc::text("::"), self.name(&mident.value.module.0)])
+    }
+
+    fn chain(&mut self, chain: &NameAccessChain) -> Doc {
+        let leading = self.comments_before(chain.loc.start());
+        let doc = match &chain.value {
+            NameAccessChain_::Single(entry) => self.path_entry(entry),
+            NameAccessChain_::Path(path) => {
+                let mut docs = vec![self.leading_name_access(&path.root.name)];
+                docs.push(self.entry_suffix(&path.root.tyargs, &path.root.is_macro));
+                for entry in &path.entries {
+                    docs.push(Doc::text("::"));
+                    docs.push(self.path_entry(entry));
+                }
+                Doc::concat(docs)
+            }
+        };
+        Doc::concat([leading, doc])
+    }
+
+    fn path_entry(&mut self, entry: &PathEntry) -> Doc {
+        let name = self.name(&entry.name);
+        Doc::concat([name, self.entry_suffix(&entry.tyargs, &entry.is_macro)])
+    }
+
+    fn entry_suffix(&mut self, tyargs: &Option<Spanned<Vec<Type>>>, is_macro: &Option<Loc>) -> Doc {
+        let macro_ = if is_macro.is_some() {
+            Doc::text("!")
+        } else {
+            Doc::Nil
+        };
+        let tyargs = match tyargs {
+            Some(tys) => self.type_args(&tys.value),
+            None => Doc::Nil,
+        };
+        Doc::concat([macro_, tyargs])
+    }
+
+    fn type_args(&mut self, tys: &[Type]) -> Doc {
+        let docs = tys.iter().map(|ty| self.type_(ty)).collect::<Vec<_>>();
+        Doc::concat([
+            Doc::text("<"),
+            Doc::join(docs, Doc::text(", ")),
+            Doc::text(">"),
+        ])
+    }
+
+    fn type_(&mut self, ty: &Type) -> Doc {
+        let leading = self.comments_before(ty.loc.start());
+        let doc = match &ty.value {
+            Type_::Apply(chain) => self.chain(chain),
+            Type_::Ref(mut_, inner) => {
+                let prefix = if *mut_ { "&mut " } else { "&" };
+                Doc::concat([Doc::text(prefix), self.type_(inner)])
+            }
+            Type_::Fun(args, ret, abilities) => {
+                let args = args.iter().map(|ty| self.type_(ty)).collect::<Vec<_>>();
+                let args = Doc::concat([
+                    Doc::text("|"),
+                    Doc::join(args, Doc::text(", ")),
+                    Doc::text("|"),
+                ]);
+                let ret = if !self.edition.supports(FeatureGate::Move2024Keywords) {
+                    Doc::concat([Doc::text(" "), self.type_(ret)])
+                } else if ret.loc.start() == ty.loc.start() {
+                    Doc::Nil
+                } else {
+                    Doc::concat([Doc::text(" -> "), self.type_(ret)])
+                };
+                let abilities = if abilities.is_empty() {
+                    Doc::Nil
+                } else {
+                    Doc::concat([
+                        Doc::text(" has "),
+                        Doc::join(
+                            abilities.iter().map(|a| Doc::text(a.value.to_string())),
+                            Doc::text(" + "),
+                        ),
+                    ])
+                };
+                Doc::concat([args, ret, abilities])
+            }
+            Type_::Unit => Doc::text("()"),
+            Type_::Multiple(tys) => {
+                let close_pos = ty.loc.end() - 1;
+                self.list(
+                    ["(", ")"],
+                    tys,
+                    close_pos,
+                    false,
+                    |ty| ty.loc,
+                    |p, ty| p.type_(ty),
+                )
+            }
+            Type_::UnresolvedError => self.verbatim(ty.loc),
+        };
+        Doc::concat([leading, doc])
+    }
+
+    //**********************************************************************************************
+    // Expressions
+    //**********************************************************************************************
+
+    /// Prints a sequence enclosed in braces. If allowed, a sequence consisting of a single
+    /// expression is printed on a single line if it fits.
+    fn sequence(&mut self, seq: &Sequence, close_pos: u32, allow_flat: bool) -> Doc {
+        let (uses, items, _, final_exp) = seq;
+        if allow_flat && uses.is_empty() && items.is_empty() {
+            if let Some(e) = final_exp.as_ref() {
+                let start = self.leading_start(e.loc.start());
+                let leading = self.comments_before(e.loc.start());
+                let exp = self.exp(e);
+                let trailing = self.trailing_comments(e.loc.end());
+                let dangling = self.comments_before(close_pos);
+                let has_comments = start < e.loc.start()
+                    || !matches!(&trailing, Doc::Concat(d) if d.is_empty())
+                    || !matches!(&dangling, Doc::Concat(d) if d.is_empty());
+                let inner =
+                    Doc::concat([leading, exp, trailing, strip_trailing_hardline(dangling)]);
+                return Doc::group(Doc::concat([
+                    Doc::text("{"),
+                    Doc::nest(Doc::concat([Doc::Line, inner])),
+                    Doc::Line,
+                    Doc::text("}"),
+                    if has_comments {
+                        Doc::BreakParent
+                    } else {
+                        Doc::Nil
+                    },
+                ]));
+            }
+        }
+        let mut lines = vec![];
+        for u in uses {
+            lines.push(self.line_item(u.loc.start(), u.loc.end(), |p| p.use_decl(u), ";"));
+        }
+        for item in items {
+            lines.push(self.line_item(
+                item.loc.start(),
+                item.loc.end(),
+                |p| p.sequence_item(item),
+                ";",
+            ));
+        }
+        if let Some(e) = final_exp.as_ref() {
+            lines.push(self.line_item(e.loc.start(), e.loc.end(), |p| p.exp(e), ""));
+        }
+        self.braced_lines(lines, close_pos)
+    }
+
+    fn sequence_item(&mut self, item: &SequenceItem) -> Doc {
+        match &item.value {
+            SequenceItem_::Seq(e) => self.exp(e),
+            SequenceItem_::Declare(binds, ty) => {
+                let binds = self.bind_list(binds);
+                let ty = self.type_annotation(ty);
+                Doc::concat([Doc::text("let "), binds, ty])
+            }
+            SequenceItem_::Bind(binds, ty, e) => {
+                let binds = self.bind_list(binds);
+                let ty = self.type_annotation(ty);
+                Doc::concat([Doc::text("let "), binds, ty, self.assigned(e)])
+            }
+        }
+    }
+
+    fn type_annotation(&mut self, ty: &Option<Type>) -> Doc {
+        match ty {
+            Some(ty) => Doc::concat([Doc::text(": "), self.type_(ty)]),
+            None => Doc::Nil,
+        }
+    }
+
+    fn bind_list(&mut self, binds: &BindList) -> Doc {
+        let parens = self.src(binds.loc).starts_with('(');
+        if !parens && binds.value.len() == 1 {
+            return self.bind(&binds.value[0]);
+        }
+        let close_pos = binds.loc.end() - 1;
+        self.list(
+            ["(", ")"],
+            &binds.value,
+            close_pos,
+            false,
+            |b| b.loc,
+            |p, b| p.bind(b),
+        )
+    }
+
+    fn bind(&mut self, bind: &Bind) -> Doc {
+        let leading = self.comments_before(bind.loc.start());
+        let doc = match &bind.value {
+            Bind_::Var(mut_, v) => {
+                let mut_ = if mut_.is_some() {
+                    Doc::text("mut ")
+                } else {
+                    Doc::Nil
+                };
+                Doc::concat([mut_, self.name(&v.0)])
+            }
+            Bind_::Unpack(chain, fields) => {
+                let name = self.chain(chain);
+                let close_pos = bind.loc.end() - 1;
+                let fields = match fields {
+                    FieldBindings::Named(fields) => {
+                        let list = self.list(
+                            ["{", "}"],
+                            fields,
+                            close_pos,
+                            true,
+                            |f| match f {
+                                Ellipsis::Binder((f, b)) => union(f.loc(), b.loc),
+                                Ellipsis::Ellipsis(loc) => *loc,
+                            },
+                            |p, f| match f {
+                                Ellipsis::Binder((f, b)) if f.loc() == b.loc => p.bind(b),
+                                Ellipsis::Binder((f, b)) => {
+                                    let name = p.name(&f.0);
+                                    let b = p.bind(b);
+                                    Doc::concat([name, Doc::text(": "), b])
+                                }
+                                Ellipsis::Ellipsis(_) => Doc::text(".."),
+                            },
+                        );
+                        Doc::concat([Doc::text(" "), list])
+                    }
+                    FieldBindings::Positional(binds) => self.list(
+                        ["(", ")"],
+                        binds,
+                        close_pos,
+                        false,
+                        |b| match b {
+                            Ellipsis::Binder(b) => b.loc,
+                            Ellipsis::Ellipsis(loc) => *loc,
+                        },
+                        |p, b| match b {
+                            Ellipsis::Binder(b) => p.bind(b),
+                            Ellipsis::Ellipsis(_) => Doc::text(".."),
+                        },
+                    ),
+                };
+                Doc::concat([name, fields])
+            }
+        };
+        Doc::concat([leading, doc])
+    }
+
+    fn exp_list(&mut self, delims: [&str; 2], args: &Spanned<Vec<Exp>>) -> Doc {
+        let close_pos = args.loc.end() - 1;
+        // a trailing lambda (or block) or a sole call-like argument can span multiple lines without
+        // breaking the argument list (there are no comments to be printed so the arguments can be
+        // printed more than once)
+        if let Some((last, others)) = args.value.split_last() {
+            let hug = match &last.value {
+                Exp_::Lambda(..) | Exp_::Block(_) => true,
+                Exp_::Call(..) | Exp_::DotCall(..) | Exp_::Pack(..) | Exp_::Vector(..) => {
+                    others.is_empty()
+                }
+                _ => false,
+            };
+            if hug && !self.has_comments_in(args.loc) {
+                let others = others.iter().map(|e| self.exp(e)).collect::<Vec<_>>();
+                if !others.iter().any(Doc::forces_break) {
+                    let mut docs = vec![Doc::text(delims[0])];
+                    for doc in others {
+                        docs.push(doc);
+                        docs.push(Doc::text(", "));
+                    }
+                    docs.push(self.exp(last));
+                    docs.push(Doc::text(delims[1]));
+                    return Doc::concat(docs);
+                }
+            }
+        }
+        self.list(
+            delims,
+            &args.value,
+            close_pos,
+            false,
+            |e| e.loc,
+            |p, e| p.exp(e),
+        )
+    }
+
+    fn has_comments_in(&self, loc: Loc) -> bool {
+        self.comments
+            .iter()
+            .any(|c| loc.start() <= c.start && c.start < loc.end())
+    }
+
+    fn exp(&mut self, e: &Exp) -> Doc {
+        let leading = self.comments_before(e.loc.start());
+        let doc = match &e.value {
+            Exp_::Value(_) => Doc::text(self.src(e.loc)),
+            Exp_::Quant(..) | Exp_::Spec(_) => self.verbatim(e.loc),
+            Exp_::UnresolvedError | Exp_::DotUnresolved(..) => self.verbatim(e.loc),
+            Exp_::Move(_, inner) => Doc::concat([Doc::text("move "), self.exp(inner)]),
+            Exp_::Copy(_, inner) => Doc::concat([Doc::text("copy "), self.exp(inner)]),
+            Exp_::Name(chain) => self.chain(chain),
+            Exp_::Call(chain, args) => {
+                let name = self.chain(chain);
+                Doc::concat([name, self.exp_list(["(", ")"], args)])
+            }
+            Exp_::Pack(chain, fields) => {
+                let name = self.chain(chain);
+                let close_pos = e.loc.end() - 1;
+                let fields = self.list(
+                    ["{", "}"],
+                    fields,
+                    close_pos,
+                    true,
+                    |(f, e)| union(f.loc(), e.loc),
+                    |p, (f, e)| {
+                        if f.loc() == e.loc {
+                            p.exp(e)
+                        } else {
+                            let name = p.name(&f.0);
+                            let e = p.exp(e);
+                            Doc::concat([name, Doc::text(": "), e])
+                        }
+                    },
+                );
+                Doc::concat([name, Doc::text(" "), fields])
+            }
+            Exp_::Vector(_, tys, args) => {
+                let tys = match tys {
+                    Some(tys) => self.type_args(tys),
+                    None => Doc::Nil,
+                };
+                Doc::concat([Doc::text("vector"), tys, self.exp_list(["[", "]"], args)])
+            }
+            Exp_::IfElse(cond, conseq, alt) => {
+                let cond = self.exp(cond);
+                let conseq_is_block = matches!(conseq.value, Exp_::Block(_));
+                let conseq = self.exp(conseq);
+                let mut docs = vec![Doc::text("if ("), cond, Doc::text(") "), conseq];
+                if let Some(alt) = alt {
+                    // `else` follows the closing brace of a block, and otherwise it starts a new
+                    // line if the whole expression does not fit on a single line
+                    docs.push(if conseq_is_block {
+                        Doc::text(" ")
+                    } else {
+                        Doc::Line
+                    });
+                    docs.push(Doc::text("else "));
+                    docs.push(self.exp(alt));
+                }
+                Doc::group(Doc::concat(docs))
+            }
+            Exp_::Match(subject, arms) => {
+                let subject = self.exp(subject);
+                let mut items = vec![];
+                for arm in &arms.value {
+                    items.push(self.line_item(
+                        arm.loc.start(),
+                        arm.loc.end(),
+                        |p| p.match_arm(arm),
+                        ",",
+                    ));
+                }
+                let close_pos = arms.loc.end() - 1;
+                let arms = self.braced_lines(items, close_pos);
+                Doc::concat([Doc::text("match ("), subject, Doc::text(") "), arms])
+            }
+            Exp_::While(cond, body) => {
+                let cond = self.exp(cond);
+                let body = self.exp(body);
+                Doc::concat([Doc::text("while ("), cond, Doc::text(") "), body])
+            }
+            Exp_::Loop(body) => Doc::concat([Doc::text("loop "), self.exp(body)]),
+            Exp_::For(bind, iter, body) => {
+                let bind = self.bind(bind);
+                let iter = self.exp(iter);
+                let body = self.exp(body);
+                Doc::concat([
+                    Doc::text("for ("),
+                    bind,
+                    Doc::text(" in "),
+                    iter,
+                    Doc::text(") "),
+                    body,
+                ])
+            }
+            Exp_::Labeled(label, inner) => {
+                let label = Doc::text(self.src(label.0.loc));
+                Doc::concat([label, Doc::text(": "), self.exp(inner)])
+            }
+            Exp_::Block(seq) => self.sequence(seq, e.loc.end() - 1, false),
+            Exp_::Lambda(binds, ret, body) => {
+                let binds = binds
+                    .value
+                    .iter()
+                    .map(|(binds, ty)| {
+                        let binds = self.bind_list(binds);
+                        Doc::concat([binds, self.type_annotation(ty)])
+                    })
+                    .collect::<Vec<_>>();
+                let ret = match ret {
+                    Some(ty) => Doc::concat([Doc::text(" -> "), self.type_(ty)]),
+                    None => Doc::Nil,
+                };
+                let body = match &body.value {
+                    Exp_::Block(seq) => {
+                        let leading = self.comments_before(body.loc.start());
+                        let block = self.sequence(seq, body.loc.end() - 1, true);
+                        Doc::concat([leading, block])
+                    }
+                    _ => self.exp(body),
+                };
+                Doc::concat([
+                    Doc::text("|"),
+                    Doc::join(binds, Doc::text(", ")),
+                    Doc::text("|"),
+                    ret,
+                    Doc::text(" "),
+                    body,
+                ])
+            }
+            Exp_::ExpList(es) => {
+                let close_pos = e.loc.end() - 1;
+                self.list(["(", ")"], es, close_pos, false, |e| e.loc, |p, e| p.exp(e))
+            }
+            Exp_::Unit => Doc::text("()"),
+            Exp_::Parens(inner) => {
+                let inner = self.exp(inner);
+                let dangling = self.comments_before(e.loc.end() - 1);
+                Doc::group(Doc::concat([
+                    Doc::text("("),
+                    Doc::nest(Doc::concat([Doc::SoftLine, inner, dangling])),
+                    Doc::SoftLine,
+                    Doc::text(")"),
+                ]))
+            }
+            Exp_::Assign(lhs, rhs) => {
+                let lhs = self.exp(lhs);
+                Doc::concat([lhs, self.assigned(rhs)])
+            }
+            Exp_::Abort(arg) => match arg {
+                Some(arg) => Doc::concat([Doc::text("abort "), self.exp(arg)]),
+                None => Doc::text("abort"),
+            },
+            Exp_::Return(label, arg) => self.jump("return", label, arg),
+            Exp_::Break(label, arg) => self.jump("break", label, arg),
+            Exp_::Continue(label) => self.jump("continue", label, &None),
+            Exp_::Dereference(inner) => Doc::concat([Doc::text("*"), self.exp(inner)]),
+            Exp_::UnaryExp(op, inner) => {
+                Doc::concat([Doc::text(op.value.to_string()), self.exp(inner)])
+            }
+            Exp_::BinopExp(..) => self.binop(e),
+            Exp_::Borrow(mut_, inner) => {
+                let prefix = if *mut_ { "&mut " } else { "&" };
+                Doc::concat([Doc::text(prefix), self.exp(inner)])
+            }
+            Exp_::Dot(inner, _, n) => {
+                let inner = self.exp(inner);
+                Doc::concat([inner, Doc::text("."), self.name(n)])
+            }
+            Exp_::DotCall(inner, _, n, is_macro, tys, args) => {
+                let inner = self.exp(inner);
+                let name = self.name(n);
+                let macro_ = if is_macro.is_some() {
+                    Doc::text("!")
+                } else {
+                    Doc::Nil
+                };
+                let tys = match tys {
+                    Some(tys) => self.type_args(tys),
+                    None => Doc::Nil,
+                };
+                let args = self.exp_list(["(", ")"], args);
+                Doc::concat([inner, Doc::text("."), name, macro_, tys, args])
+            }
+            Exp_::Index(inner, args) => {
+                let inner = self.exp(inner);
+                Doc::concat([inner, self.exp_list(["[", "]"], args)])
+            }
+            Exp_::Cast(inner, ty) => {
+                let inner = self.exp(inner);
+                Doc::concat([inner, Doc::text(" as "), self.type_(ty)])
+            }
+            Exp_::Annotate(inner, ty) => {
+                let inner = self.exp(inner);
+                let ty = self.type_(ty);
+                Doc::concat([Doc::text("("), inner, Doc::text(": "), ty, Doc::text(")")])
+            }
+        };
+        Doc::concat([leading, doc])
+    }
+
+    /// Prints the right-hand side of an assignment (along with `=`), which moves to the next line
+    /// if it does not fit unless it can be broken itself.
+    fn assigned(&mut self, e: &Exp) -> Doc {
+        let rhs = self.exp(e);
+        match &e.value {
+            Exp_::Value(_)
+            | Exp_::Name(_)
+            | Exp_::BinopExp(..)
+            | Exp_::Dot(..)
+            | Exp_::Cast(..)
+            | Exp_::UnaryExp(..)
+            | Exp_::Borrow(..)
+            | Exp_::Dereference(..) => Doc::group(Doc::concat([
+                Doc::text(" ="),
+                Doc::nest(Doc::concat([Doc::Line, rhs])),
+            ])),
+            _ => Doc::concat([Doc::text(" = "), rhs]),
+        }
+    }
+
+    fn jump(&mut self, keyword: &str, label: &Option<BlockLabel>, arg: &Option<Box<Exp>>) -> Doc {
+        let mut docs = vec![Doc::text(keyword)];
+        if let Some(label) = label {
+            docs.push(Doc::text(" "));
+            docs.push(Doc::text(self.src(label.0.loc)));
+        }
+        if let Some(arg) = arg {
+            docs.push(Doc::text(" "));
+            docs.push(self.exp(arg));
+        }
+        Doc::concat(docs)
+    }
+
+    /// Prints a binary operation. Chains of the same operator (e.g., `a && b && c`) are broken
+    /// all at once (after each operator) if they do not fit on a single line.
+    fn binop(&mut self, e: &Exp) -> Doc {
+        let Exp_::BinopExp(_, op, _) = &e.value else {
+            unreachable!()
+        };
+        let mut operands = vec![];
+        collect_operands(e, op.value, &mut operands);
+        let mut operands = operands.into_iter();
+        let first = self.exp(operands.next().unwrap());
+        let mut rest = vec![];
+        for operand in operands {
+            rest.push(Doc::text(format!(" {}", op.value)));
+            rest.push(Doc::Line);
+            rest.push(self.exp(operand));
+        }
+        Doc::group(Doc::concat([first, Doc::nest(Doc::concat(rest))]))
+    }
+
+    fn match_arm(&mut self, arm: &MatchArm) -> Doc {
+        let pattern = self.pattern(&arm.value.pattern);
+        let guard = match &arm.value.guard {
+            Some(guard) => {
+                let guard = self.exp(guard);
+                Doc::concat([Doc::text(" if ("), guard, Doc::text(")")])
+            }
+            None => Doc::Nil,
+        };
+        let rhs = self.exp(&arm.value.rhs);
+        Doc::concat([pattern, guard, Doc::text(" => "), rhs])
+    }
+
+    fn pattern(&mut self, pat: &MatchPattern) -> Doc {
+        let leading = self.comments_before(pat.loc.start());
+        let doc = match &pat.value {
+            MatchPattern_::PositionalConstructor(chain, pats) => {
+                let name = self.chain(chain);
+                let close_pos = pat.loc.end() - 1;
+                let pats = self.list(
+                    ["(", ")"],
+                    &pats.value,
+                    close_pos,
+                    false,
+                    |p| match p {
+                        Ellipsis::Binder(p) => p.loc,
+                        Ellipsis::Ellipsis(loc) => *loc,
+                    },
+                    |p, pat| match pat {
+                        Ellipsis::Binder(pat) => p.pattern(pat),
+                        Ellipsis::Ellipsis(_) => Doc::text(".."),
+                    },
+                );
+                Doc::concat([name, pats])
+            }
+            MatchPattern_::FieldConstructor(chain, fields) => {
+                let name = self.chain(chain);
+                let close_pos = pat.loc.end() - 1;
+                let fields = self.list(
+                    ["{", "}"],
+                    &fields.value,
+                    close_pos,
+                    true,
+                    |f| match f {
+                        Ellipsis::Binder((f, pat)) => union(f.loc(), pat.loc),
+                        Ellipsis::Ellipsis(loc) => *loc,
+                    },
+                    |p, f| match f {
+                        Ellipsis::Binder((f, pat)) if f.loc() == pat.loc => p.pattern(pat),
+                        Ellipsis::Binder((f, pat)) => {
+                            let name = p.name(&f.0);
+                            let pat = p.pattern(pat);
+                            Doc::concat([name, Doc::text(": "), pat])
+                        }
+                        Ellipsis::Ellipsis(_) => Doc::text(".."),
+                    },
+                );
+                Doc::concat([name, Doc::text(" "), fields])
+            }
+            MatchPattern_::Name(mut_, chain) => {
+                let mut_ = if mut_.is_some() {
+                    Doc::text("mut ")
+                } else {
+                    Doc::Nil
+                };
+                Doc::concat([mut_, self.chain(chain)])
+            }
+            MatchPattern_::Literal(_) => Doc::text(self.src(pat.loc)),
+            MatchPattern_::Or(lhs, rhs) => {
+                let lhs = self.pattern(lhs);
+                Doc::concat([lhs, Doc::text(" | "), self.pattern(rhs)])
+            }
+            MatchPattern_::At(v, inner) => {
+                let v = self.name(&v.0);
+                Doc::concat([v, Doc::text(" @ "), self.pattern(inner)])
+            }
+        };
+        Doc::concat([leading, doc])
+    }
+}
+
+/// Collects operands of a chain of left-associative binary operations using the same operator.
+fn collect_operands<'e>(e: &'e Exp, op: BinOp_, operands: &mut Vec<&'e Exp>) {
+    match &e.value {
+        Exp_::BinopExp(lhs, sp!(_, op_), rhs) if *op_ == op => {
+            collect_operands(lhs, op, operands);
+            operands.push(rhs);
+        }
+        _ => operands.push(e),
+    }
+}
+
+fn union(loc1: Loc, loc2: Loc) -> Loc {
+    Loc::new(
+        loc1.file_hash(),
+        loc1.start().min(loc2.start()),
+        loc1.end().max(loc2.end()),
+    )
+}
+
+fn span(loc: Loc, start: u32, end: u32) -> Loc {
+    Loc::new(loc.file_hash(), start, end)
+}
+
+fn attrs_start(attributes: &[Attributes], loc: Loc) -> u32 {
+    attributes
+        .first()
+        .map_or(loc.start(), |attrs| attrs.loc.start().min(loc.start()))
+}
+
+/// Removes the line break (or space) following the last comment of a printed comment sequence so
+/// that the sequence can be followed by a line break of the enclosing document, which is forced to
+/// be broken if there are any comments.
+fn strip_trailing_hardline(doc: Doc) -> Doc {
+    let Doc::Concat(mut docs) = doc else {
+        return doc;
+    };
+    while matches!(docs.last(), Some(Doc::HardLine))
+        || matches!(docs.last(), Some(Doc::Text(t)) if t == " ")
+    {
+        docs.pop();
+    }
+    if !docs.is_empty() {
+        docs.push(Doc::BreakParent);
+    }
+    Doc::Concat(docs)
+}
+
+/// Classifies a use declaration into one of the import groups: system packages, other packages,
+/// and the package of the module itself.
+fn use_group(decl: &UseDecl, address_name: Option<&str>) -> usize {
+    let address = match &decl.use_ {
+        Use::ModuleUse(mident, _) => &mident.value.address,
+        Use::NestedModuleUses(addr, _) => addr,
+        Use::Fun { .. } | Use::Partial { .. } => return 1,
+    };
+    match &address.value {
+        LeadingNameAccess_::Name(n) | LeadingNameAccess_::GlobalAddress(n) => {
+            if n.value == STD_ADDR_NAME || n.value == BOS_ADDR_NAME {
+                0
+            } else if Some(n.value.as_str()) == address_name {
+                2
+            } else {
+                1
+            }
+        }
+        LeadingNameAccess_::AnonymousAddress(a) => {
+            if a.into_inner() == AccountAddress::ONE || a.into_inner() == AccountAddress::TWO {
+                0
+            } else {
+                1
+            }
+        }
+    }
+}
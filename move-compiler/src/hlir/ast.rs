@@ -183,12 +183,14 @@ pub type TypeName = Spanned<TypeName_>;
 pub enum BaseType_ {
     Param(TParam),
     Apply(AbilitySet, TypeName, Vec<BaseType>),
+    // |t1, ..., tn| -> (r1, ..., rm) has a1 + ... + ak
+    Fun(Vec<SingleType>, Vec<SingleType>, AbilitySet),
     Unreachable,
     UnresolvedError,
 }
 pub type BaseType = Spanned<BaseType_>;
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub enum SingleType_ {
     Base(BaseType),
     Ref(bool, BaseType),
@@ -359,6 +361,18 @@ pub enum Value_ {
     U128(u128),
     // <num>u256
     U256(move_core_types::u256::U256),
+    // <num>i8
+    I8(i8),
+    // <num>i16
+    I16(i16),
+    // <num>i32
+    I32(i32),
+    // <num>i64
+    I64(i64),
+    // <num>i128
+    I128(i128),
+    // <num>i256
+    I256(move_core_types::i256::I256),
     // true
     // false
     Bool(bool),
@@ -399,6 +413,8 @@ pub enum UnannotatedExp_ {
     },
 
     ModuleCall(Box<ModuleCall>),
+    PackClosure(Box<ModuleCall>),
+    CallClosure(Box<Exp>, Vec<Exp>),
     Freeze(Box<Exp>),
     Vector(Loc, usize, Box<BaseType>, Vec<Exp>),
 
@@ -455,6 +471,12 @@ impl Value_ {
             Self::U64(v) => *v == 0,
             Self::U128(v) => *v == 0,
             Self::U256(v) => *v == move_core_types::u256::U256::zero(),
+            Self::I8(v) => *v == 0,
+            Self::I16(v) => *v == 0,
+            Self::I32(v) => *v == 0,
+            Self::I64(v) => *v == 0,
+            Self::I128(v) => *v == 0,
+            Self::I256(v) => *v == move_core_types::i256::I256::zero(),
             Self::Address(_) | Self::Bool(_) | Self::Vector(_, _) => false,
         }
     }
@@ -634,7 +656,8 @@ impl BaseType_ {
         use BuiltinTypeName_::*;
 
         let kind = match b_ {
-            U8 | U16 | U32 | U64 | U128 | U256 | Bool | Address => AbilitySet::primitives(loc),
+            U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256 | Bool
+            | Address => AbilitySet::primitives(loc),
             Signer => AbilitySet::signer(loc),
             Vector => {
                 let declared_abilities = AbilitySet::collection(loc);
@@ -656,9 +679,9 @@ impl BaseType_ {
 
     pub fn abilities(&self, loc: Loc) -> AbilitySet {
         match self {
-            BaseType_::Apply(abilities, _, _) | BaseType_::Param(TParam { abilities, .. }) => {
-                abilities.clone()
-            }
+            BaseType_::Apply(abilities, _, _)
+            | BaseType_::Param(TParam { abilities, .. })
+            | BaseType_::Fun(_, _, abilities) => abilities.clone(),
             BaseType_::Unreachable | BaseType_::UnresolvedError => AbilitySet::all(loc),
         }
     }
@@ -1243,6 +1266,20 @@ impl AstDebug for BaseType_ {
                     },
                 );
             }
+            BaseType_::Fun(params, returns, abilities) => {
+                w.write("|");
+                w.comma(params, |w, ty| ty.ast_debug(w));
+                w.write("| -> (");
+                w.comma(returns, |w, ty| ty.ast_debug(w));
+                w.write(")");
+                if !abilities.is_empty() {
+                    w.write(" has ");
+                    w.list(abilities, "+", |w, ab| {
+                        ab.ast_debug(w);
+                        false
+                    });
+                }
+            }
             BaseType_::Unreachable => w.write("_|_"),
             BaseType_::UnresolvedError => w.write("_"),
         }
@@ -1461,6 +1498,12 @@ impl AstDebug for Value_ {
             V::U64(u) => w.write(format!("{}u64", u)),
             V::U128(u) => w.write(format!("{}u128", u)),
             V::U256(u) => w.write(format!("{}u256", u)),
+            V::I8(i) => w.write(format!("{}i8", i)),
+            V::I16(i) => w.write(format!("{}i16", i)),
+            V::I32(i) => w.write(format!("{}i32", i)),
+            V::I64(i) => w.write(format!("{}i64", i)),
+            V::I128(i) => w.write(format!("{}i128", i)),
+            V::I256(i) => w.write(format!("{}i256", i)),
             V::Bool(b) => w.write(format!("{}", b)),
             V::Vector(ty, elems) => {
                 w.write("vector#value");
@@ -1529,6 +1572,16 @@ impl AstDebug for UnannotatedExp_ {
             E::ModuleCall(mcall) => {
                 mcall.ast_debug(w);
             }
+            E::PackClosure(mcall) => {
+                w.write("closure ");
+                mcall.ast_debug(w);
+            }
+            E::CallClosure(closure, args) => {
+                closure.ast_debug(w);
+                w.write("(");
+                args.ast_debug(w);
+                w.write(")");
+            }
             E::Vector(_loc, n, ty, elems) => {
                 w.write(format!("vector#{}", n));
                 w.write("<");
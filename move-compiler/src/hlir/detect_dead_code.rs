@@ -606,7 +606,8 @@ fn value(context: &mut Context, e: &T::Exp) -> ControlFlow {
         // -----------------------------------------------------------------------------------------
         //  calls and nested expressions
         // -----------------------------------------------------------------------------------------
-        E::ModuleCall(call) => value_report!(&call.arguments),
+        E::ModuleCall(call) | E::PackClosure(call) => value_report!(&call.arguments),
+        E::CallClosure(closure, args) => value_report!(args).combine_seq(value_report!(closure)),
 
         E::Builtin(_, args) | E::Vector(_, _, _, args) => value_report!(args),
 
@@ -811,6 +812,8 @@ fn statement(context: &mut Context, e: &T::Exp) -> ControlFlow {
         // unravelled to find any embedded, divergent operations.
         // -----------------------------------------------------------------------------------------
         E::ModuleCall(_)
+        | E::PackClosure(_)
+        | E::CallClosure(_, _)
         | E::Builtin(_, _)
         | E::Vector(_, _, _, _)
         | E::Dereference(_)
@@ -17,7 +17,7 @@ use crate::{
     naming::ast as N,
     parser::ast::{
         Ability_, BinOp, BinOp_, ConstantName, DatatypeName, Field, FunctionName, TargetKind,
-        VariantName,
+        UnaryOp_, VariantName,
     },
     shared::{
         matching::{new_match_var_name, MatchContext, MATCH_TEMP_PREFIX},
@@ -705,7 +705,16 @@ fn base_type(context: &mut Context, sp!(loc, nb_): N::Type) -> H::BaseType {
         NT::Param(tp) => HB::Param(tp),
         NT::UnresolvedError => HB::UnresolvedError,
         NT::Anything => HB::Unreachable,
-        NT::Ref(_, _) | NT::Unit | NT::Fun(_, _) => {
+        NT::Fun(params, result, abilities) => {
+            let params = single_types(context, params);
+            let returns = match type_(context, *result).value {
+                H::Type_::Unit => vec![],
+                H::Type_::Single(s) => vec![s],
+                H::Type_::Multiple(ss) => ss,
+            };
+            HB::Fun(params, returns, abilities)
+        }
+        NT::Ref(_, _) | NT::Unit => {
             context.add_diag(ice!((
                 loc,
                 format!(
@@ -1334,6 +1343,37 @@ fn value(
             };
             make_exp(HE::ModuleCall(Box::new(call)))
         }
+        E::PackClosure(call) => {
+            let T::ModuleCall {
+                module,
+                name,
+                type_arguments,
+                arguments,
+                parameter_types,
+                method_name: _,
+            } = *call;
+            let htys = base_types(context, type_arguments);
+            let expected_type = H::Type_::from_vec(eloc, single_types(context, parameter_types));
+            let arguments = value_list(context, block, Some(&expected_type), *arguments);
+            let call = H::ModuleCall {
+                module,
+                name,
+                type_arguments: htys,
+                arguments,
+            };
+            make_exp(HE::PackClosure(Box::new(call)))
+        }
+        E::CallClosure(closure, arguments) => {
+            let N::Type_::Fun(parameter_types, _, _) = closure.ty.value.clone() else {
+                context.add_diag(ice!((eloc, "ICE closure call of a non-function type")));
+                return error_exp(eloc);
+            };
+            let expected_type = H::Type_::from_vec(eloc, single_types(context, parameter_types));
+            // The arguments are evaluated before the closure, matching the order of the stack
+            let arguments = value_list(context, block, Some(&expected_type), *arguments);
+            let closure = value(context, block, None, *closure);
+            make_exp(HE::CallClosure(Box::new(closure), arguments))
+        }
         E::Builtin(bt, args) => make_exp(builtin(context, block, eloc, *bt, args)),
 
         // -----------------------------------------------------------------------------------------
@@ -1352,6 +1392,26 @@ fn value(
             let value = value(context, block, None, *ev);
             make_exp(HE::Dereference(Box::new(value)))
         }
+        E::UnaryExp(sp!(oploc, UnaryOp_::Neg), operand) => {
+            // Negation is compiled as a subtraction from zero
+            let operand = value(context, block, None, *operand);
+            let zero_ = match in_type.value.builtin_name() {
+                Some(sp!(_, bt)) if bt.is_signed() => signed_zero(bt),
+                _ => {
+                    context.add_diag(ice!((
+                        eloc,
+                        format!(
+                            "ICE typing failed for negation: {}",
+                            debug_display_verbose!(in_type)
+                        )
+                    )));
+                    return error_exp(eloc);
+                }
+            };
+            let zero = H::exp(operand.ty.clone(), sp(oploc, HE::Value(sp(oploc, zero_))));
+            let sub = sp(oploc, BinOp_::Sub);
+            make_exp(HE::BinopExp(Box::new(zero), sub, Box::new(operand)))
+        }
         E::UnaryExp(op, operand) => {
             let operand = value(context, block, None, *operand);
             make_exp(HE::UnaryExp(op, Box::new(operand)))
@@ -1563,7 +1623,13 @@ fn value(
                 | Some(bt @ sp!(_, BT::U32))
                 | Some(bt @ sp!(_, BT::U64))
                 | Some(bt @ sp!(_, BT::U128))
-                | Some(bt @ sp!(_, BT::U256)) => *bt,
+                | Some(bt @ sp!(_, BT::U256))
+                | Some(bt @ sp!(_, BT::I8))
+                | Some(bt @ sp!(_, BT::I16))
+                | Some(bt @ sp!(_, BT::I32))
+                | Some(bt @ sp!(_, BT::I64))
+                | Some(bt @ sp!(_, BT::I128))
+                | Some(bt @ sp!(_, BT::I256)) => *bt,
                 _ => {
                     context.add_diag(ice!((
                         eloc,
@@ -1965,7 +2031,7 @@ fn statement(context: &mut Context, block: &mut Block, e: T::Exp) {
         }
 
         // calls might be for effect
-        e_ @ E::ModuleCall(_) | e_ @ E::Builtin(_, _) => {
+        e_ @ (E::ModuleCall(_) | E::CallClosure(_, _) | E::Builtin(_, _)) => {
             value_statement(context, block, make_exp(e_));
         }
 
@@ -1978,7 +2044,8 @@ fn statement(context: &mut Context, block: &mut Block, e: T::Exp) {
         // FIXME(cgswords): we can't optimize because almost all of these throw. We have to do the
         // "honest" work here, even though it's thrown away. Consider emitting a warning about
         // these and/or weaking guarantees in Move 2024.
-        e_ @ (E::Vector(_, _, _, _)
+        e_ @ (E::PackClosure(_)
+        | E::Vector(_, _, _, _)
         | E::Dereference(_)
         | E::UnaryExp(_, _)
         | E::BinopExp(_, _, _, _)
@@ -2636,6 +2703,12 @@ fn process_value(context: &mut Context, sp!(loc, ev_): E::Value) -> H::Value {
         EV::U64(u) => HV::U64(u),
         EV::U128(u) => HV::U128(u),
         EV::U256(u) => HV::U256(u),
+        EV::I8(i) => HV::I8(i),
+        EV::I16(i) => HV::I16(i),
+        EV::I32(i) => HV::I32(i),
+        EV::I64(i) => HV::I64(i),
+        EV::I128(i) => HV::I128(i),
+        EV::I256(i) => HV::I256(i),
         EV::Bool(u) => HV::Bool(u),
         EV::Bytearray(bytes) => HV::Vector(
             Box::new(H::BaseType_::u8(loc)),
@@ -2645,6 +2718,20 @@ fn process_value(context: &mut Context, sp!(loc, ev_): E::Value) -> H::Value {
     sp(loc, v_)
 }
 
+fn signed_zero(bt: &N::BuiltinTypeName_) -> H::Value_ {
+    use N::BuiltinTypeName_ as BT;
+    use H::Value_ as HV;
+    match bt {
+        BT::I8 => HV::I8(0),
+        BT::I16 => HV::I16(0),
+        BT::I32 => HV::I32(0),
+        BT::I64 => HV::I64(0),
+        BT::I128 => HV::I128(0),
+        BT::I256 => HV::I256(move_core_types::i256::I256::zero()),
+        _ => panic!("ICE expected a signed integer type, found {}", bt),
+    }
+}
+
 #[derive(Debug)]
 enum BinopEntry {
     Op {
@@ -353,6 +353,12 @@ fn write_signature_token(ctx: &mut Context, t: &SignatureToken) -> String {
         SignatureToken::U64 => "u64".to_string(),
         SignatureToken::U128 => "u128".to_string(),
         SignatureToken::U256 => "u256".to_string(),
+        SignatureToken::I8 => "i8".to_string(),
+        SignatureToken::I16 => "i16".to_string(),
+        SignatureToken::I32 => "i32".to_string(),
+        SignatureToken::I64 => "i64".to_string(),
+        SignatureToken::I128 => "i128".to_string(),
+        SignatureToken::I256 => "i256".to_string(),
         SignatureToken::Address => "address".to_string(),
         SignatureToken::Signer => "signer".to_string(),
         SignatureToken::Vector(inner) => format!("vector<{}>", write_signature_token(ctx, inner)),
@@ -372,6 +378,33 @@ fn write_signature_token(ctx: &mut Context, t: &SignatureToken) -> String {
             format!("&mut {}", write_signature_token(ctx, inner))
         }
         SignatureToken::TypeParameter(idx) => write_type_parameter(*idx),
+        SignatureToken::Function(func) => {
+            let (params, returns, abilities) = &**func;
+            let params = params
+                .iter()
+                .map(|ty| write_signature_token(ctx, ty))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let returns = match returns.len() {
+                0 => "".to_string(),
+                1 => format!(" -> {}", write_signature_token(ctx, &returns[0])),
+                _ => format!(
+                    " -> ({})",
+                    returns
+                        .iter()
+                        .map(|ty| write_signature_token(ctx, ty))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+            let abilities = if *abilities == AbilitySet::EMPTY {
+                "".to_string()
+            } else {
+                let abilities = abilities.into_iter().map(write_ability);
+                format!(" has {}", abilities.collect::<Vec<_>>().join(" + "))
+            };
+            format!("|{}|{}{}", params, returns, abilities)
+        }
     }
 }
 
@@ -17,6 +17,7 @@ pub mod compiled_unit;
 pub mod diagnostics;
 pub mod editions;
 pub mod expansion;
+pub mod formatter;
 pub mod hlir;
 pub mod interface_generator;
 pub mod ir_translation;
@@ -15,6 +15,7 @@ use crate::{
         visitor::{same_value_exp, simple_visitor},
     },
 };
+use move_ir_types::location::Loc;
 
 #[derive(Debug, Clone, Copy)]
 enum Simplification {
@@ -77,10 +78,46 @@ simple_visitor!(
             Simplification::AlwaysTrue => "is always 'true'".to_string(),
             Simplification::AlwaysFalse => "is always 'false'".to_string(),
         };
-        self.reporter.add_diag(diag!(
+        let mut diag = diag!(
             StyleCodes::CombinableComparisons.diag_info(),
             (exp.exp.loc, format!("This comparison {msg}")),
-        ));
+        );
+        let loc = exp.exp.loc;
+        let (fix, edits) = match simplification {
+            // keep the left comparison, replacing its operator and dropping the right one
+            Simplification::Reducible(inner_op) => {
+                let lhs_loc = outer_l.exp.loc;
+                let edits = vec![
+                    (
+                        Loc::new(loc.file_hash(), loc.start(), lhs_loc.start()),
+                        "".to_string(),
+                    ),
+                    (op_l.loc, inner_op.to_string()),
+                    (
+                        Loc::new(loc.file_hash(), lhs_loc.end(), loc.end()),
+                        "".to_string(),
+                    ),
+                ];
+                (format!("Replace with '{inner_op}'"), edits)
+            }
+            Simplification::AlwaysTrue => (
+                "Replace with 'true'".to_string(),
+                vec![(loc, "true".to_string())],
+            ),
+            Simplification::AlwaysFalse => (
+                "Replace with 'false'".to_string(),
+                vec![(loc, "false".to_string())],
+            ),
+        };
+        if edits.iter().all(|(edit_loc, _)| {
+            edit_loc.file_hash() == loc.file_hash()
+                && loc.start() <= edit_loc.start()
+                && edit_loc.start() <= edit_loc.end()
+                && edit_loc.end() <= loc.end()
+        }) {
+            diag.add_fix(fix, edits);
+        }
+        self.reporter.add_diag(diag);
 
         false
     }
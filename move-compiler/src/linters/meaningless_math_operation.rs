@@ -11,7 +11,7 @@ use crate::{
     linters::StyleCodes,
     parser::ast::BinOp_,
 };
-use move_core_types::u256::U256;
+use move_core_types::{i256::I256, u256::U256};
 use move_ir_types::location::Loc;
 
 simple_visitor!(
@@ -84,12 +84,22 @@ fn is_zero(exp: &H::Exp) -> Option<Loc> {
             Some(*loc)
         }
         Value_::U256(u) if u == &U256::zero() => Some(*loc),
+        Value_::I8(0) | Value_::I16(0) | Value_::I32(0) | Value_::I64(0) | Value_::I128(0) => {
+            Some(*loc)
+        }
+        Value_::I256(i) if i == &I256::zero() => Some(*loc),
         Value_::U8(_)
         | Value_::U16(_)
         | Value_::U32(_)
         | Value_::U64(_)
         | Value_::U128(_)
         | Value_::U256(_)
+        | Value_::I8(_)
+        | Value_::I16(_)
+        | Value_::I32(_)
+        | Value_::I64(_)
+        | Value_::I128(_)
+        | Value_::I256(_)
         | Value_::Address(_)
         | Value_::Bool(_)
         | Value_::Vector(_, _) => None,
@@ -105,12 +115,22 @@ fn is_one(exp: &H::Exp) -> Option<Loc> {
             Some(*loc)
         }
         Value_::U256(u) if u == &U256::one() => Some(*loc),
+        Value_::I8(1) | Value_::I16(1) | Value_::I32(1) | Value_::I64(1) | Value_::I128(1) => {
+            Some(*loc)
+        }
+        Value_::I256(i) if i == &I256::one() => Some(*loc),
         Value_::U8(_)
         | Value_::U16(_)
         | Value_::U32(_)
         | Value_::U64(_)
         | Value_::U128(_)
         | Value_::U256(_)
+        | Value_::I8(_)
+        | Value_::I16(_)
+        | Value_::I32(_)
+        | Value_::I64(_)
+        | Value_::I128(_)
+        | Value_::I256(_)
         | Value_::Address(_)
         | Value_::Bool(_)
         | Value_::Vector(_, _) => None,
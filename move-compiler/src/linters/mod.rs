@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_symbol_pool::Symbol;
+use std::{fmt, sync::Arc};
 
 use crate::{
     cfgir::visitor::CFGIRVisitor,
@@ -27,7 +28,7 @@ pub mod unnecessary_unit;
 pub mod unnecessary_while_loop;
 pub mod unneeded_return;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum LintLevel {
     // No linters
     None,
@@ -220,3 +221,132 @@ pub fn linter_visitors(level: LintLevel) -> Vec<Visitor> {
         }
     }
 }
+
+/// A lint defined outside of the compiler, e.g. a project-specific rule provided by a downstream
+/// crate. The visitor reports diagnostics with `info`, which must carry an external prefix (see
+/// `codes::custom`), and `#[allow(lint(<filter_name>))]` suppresses them.
+///
+/// Lints are identified by their diagnostic info and filter name: equality and ordering ignore the
+/// visitor, which cannot be compared, and builds reject lints that share a filter name or a
+/// diagnostic code (see `duplicate_custom_lints`).
+#[derive(Clone)]
+pub struct CustomLint {
+    info: DiagnosticInfo,
+    filter_name: &'static str,
+    level: LintLevel,
+    visitor: Arc<dyn Fn() -> Visitor + Send + Sync>,
+}
+
+impl CustomLint {
+    /// Creates a lint that runs only when all linters are enabled, i.e. with `--lint`
+    pub fn new(
+        info: DiagnosticInfo,
+        filter_name: &'static str,
+        visitor: impl Fn() -> Visitor + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            info,
+            filter_name,
+            level: LintLevel::All,
+            visitor: Arc::new(visitor),
+        }
+    }
+
+    /// Runs the lint with the default linters as well, i.e. unless `--no-lint` is set
+    pub fn enabled_by_default(mut self) -> Self {
+        self.level = LintLevel::Default;
+        self
+    }
+
+    pub fn info(&self) -> &DiagnosticInfo {
+        &self.info
+    }
+
+    pub fn filter_name(&self) -> &'static str {
+        self.filter_name
+    }
+
+    pub fn is_enabled(&self, level: LintLevel) -> bool {
+        self.level <= level
+    }
+
+    pub fn warning_filter(&self) -> WarningFilter {
+        WarningFilter::code(
+            self.info.external_prefix(),
+            self.info.category(),
+            self.info.code(),
+            Some(self.filter_name),
+        )
+    }
+
+    pub fn visitor(&self) -> Visitor {
+        (self.visitor)()
+    }
+
+    fn key(&self) -> (&DiagnosticInfo, &'static str, LintLevel) {
+        (&self.info, self.filter_name, self.level)
+    }
+}
+
+impl fmt::Debug for CustomLint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomLint")
+            .field("info", &self.info)
+            .field("filter_name", &self.filter_name)
+            .field("level", &self.level)
+            .finish_non_exhaustive()
+    }
+}
+
+// Compares lints without their visitors
+impl PartialEq for CustomLint {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for CustomLint {}
+
+impl PartialOrd for CustomLint {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CustomLint {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// The first two lints in `lints` that cannot be told apart, because they have the same filter
+/// name, or the same diagnostic code so that their warnings would be filtered together
+pub fn duplicate_custom_lints(lints: &[CustomLint]) -> Option<(&CustomLint, &CustomLint)> {
+    let same_code = |a: &DiagnosticInfo, b: &DiagnosticInfo| {
+        (a.external_prefix(), a.category(), a.code())
+            == (b.external_prefix(), b.category(), b.code())
+    };
+    lints.iter().enumerate().find_map(|(i, lint)| {
+        lints[..i]
+            .iter()
+            .find(|prev| prev.filter_name == lint.filter_name || same_code(&prev.info, &lint.info))
+            .map(|prev| (prev, lint))
+    })
+}
+
+/// The filters for `lints`, all of them known under `#[allow(lint(_))]`. Every lint is known
+/// regardless of the lint level, so allowing a lint that is not run is not an error.
+pub fn custom_known_filters(lints: &[CustomLint]) -> (Option<Symbol>, Vec<WarningFilter>) {
+    (
+        Some(ALLOW_ATTR_CATEGORY.into()),
+        lints.iter().map(CustomLint::warning_filter).collect(),
+    )
+}
+
+pub fn custom_linter_visitors(lints: &[CustomLint], level: LintLevel) -> Vec<Visitor> {
+    lints
+        .iter()
+        .filter(|lint| lint.is_enabled(level))
+        .map(CustomLint::visitor)
+        .collect()
+}
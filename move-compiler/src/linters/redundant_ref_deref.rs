@@ -95,6 +95,7 @@ fn is_simple_deref_ref_exp(exp: &Exp) -> bool {
         TE::Constant(_, _) => true,
         TE::ErrorConstant { .. } => true,
         TE::ModuleCall(_) => true,
+        TE::PackClosure(_) | TE::CallClosure(_, _) => true,
         TE::Vector(_, _, _, _) => true,
         TE::Copy { .. } => true,
 
@@ -40,6 +40,8 @@ fn check_mutate(context: &mut Context, loc: Loc, lhs: &T::Exp, rhs: &T::Exp) {
             | E::Value(_)
             | E::Constant(_, _)
             | E::ModuleCall(_)
+            | E::PackClosure(_)
+            | E::CallClosure(_, _)
             | E::Vector(_, _, _, _)
             | E::IfElse(_, _, _)
             | E::Match(_, _)
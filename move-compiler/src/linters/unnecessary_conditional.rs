@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Detects and suggests simplification for `if (c) e1 else e2` can be removed
+use move_ir_types::location::Loc;
 use move_proc_macros::growing_stack;
 
 use crate::expansion::ast::Value;
@@ -19,7 +20,7 @@ use crate::{
 simple_visitor!(
     UnnecessaryConditional,
     fn visit_exp_custom(&mut self, exp: &T::Exp) -> bool {
-        let UnannotatedExp_::IfElse(_, etrue, efalse) = &exp.exp.value else {
+        let UnannotatedExp_::IfElse(econd, etrue, efalse) = &exp.exp.value else {
             return false;
         };
         let Some(vtrue) = extract_value(etrue) else {
@@ -37,10 +38,33 @@ simple_visitor!(
                     "Detected an unnecessary conditional expression 'if (cond)'. Consider using \
                     the condition directly, i.e. '{negation}cond'",
                 );
-                self.add_diag(diag!(
+                let mut diag = diag!(
                     StyleCodes::UnnecessaryConditional.diag_info(),
                     (exp.exp.loc, msg)
-                ));
+                );
+                // keep the condition, replacing everything before and after it, and parenthesize
+                // it unless it is atomic as it may be an operand of another expression
+                let (prefix, suffix) = match (*v1, is_atomic(econd)) {
+                    (true, true) => ("", ""),
+                    (true, false) => ("(", ")"),
+                    (false, true) => ("!", ""),
+                    (false, false) => ("!(", ")"),
+                };
+                let title = if *v1 {
+                    "Replace with the condition"
+                } else {
+                    "Replace with the negated condition"
+                };
+                let (loc, cond_loc) = (exp.exp.loc, econd.exp.loc);
+                if loc.file_hash() == cond_loc.file_hash()
+                    && loc.start() <= cond_loc.start()
+                    && cond_loc.end() <= loc.end()
+                {
+                    let before = Loc::new(loc.file_hash(), loc.start(), cond_loc.start());
+                    let after = Loc::new(loc.file_hash(), cond_loc.end(), loc.end());
+                    diag.add_fix(title, [(before, prefix), (after, suffix)]);
+                }
+                self.add_diag(diag);
             }
             (v1, v2) if v1 == v2 => {
                 let msg =
@@ -78,6 +102,20 @@ simple_visitor!(
     }
 );
 
+/// Indicates if the expression can be negated without wrapping it in parentheses
+fn is_atomic(exp: &T::Exp) -> bool {
+    matches!(
+        &exp.exp.value,
+        UnannotatedExp_::Value(_)
+            | UnannotatedExp_::Use(_)
+            | UnannotatedExp_::Copy { .. }
+            | UnannotatedExp_::Move { .. }
+            | UnannotatedExp_::Constant(_, _)
+            | UnannotatedExp_::ModuleCall(_)
+            | UnannotatedExp_::UnaryExp(_, _)
+    )
+}
+
 #[growing_stack]
 fn extract_value(block: &T::Exp) -> Option<&Value> {
     match &block.exp.value {
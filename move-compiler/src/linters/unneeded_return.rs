@@ -98,6 +98,8 @@ fn tail(context: &mut Context, exp: &T::Exp) {
         | T::UnannotatedExp_::Use(_)
         | T::UnannotatedExp_::Constant(_, _)
         | T::UnannotatedExp_::ModuleCall(_)
+        | T::UnannotatedExp_::PackClosure(_)
+        | T::UnannotatedExp_::CallClosure(_, _)
         | T::UnannotatedExp_::Dereference(_)
         | T::UnannotatedExp_::UnaryExp(_, _)
         | T::UnannotatedExp_::BinopExp(_, _, _, _)
@@ -150,6 +152,8 @@ fn returnable_value(context: &mut Context, exp: &T::Exp) -> bool {
         | T::UnannotatedExp_::Use(_)
         | T::UnannotatedExp_::Constant(_, _)
         | T::UnannotatedExp_::ModuleCall(_)
+        | T::UnannotatedExp_::PackClosure(_)
+        | T::UnannotatedExp_::CallClosure(_, _)
         | T::UnannotatedExp_::Builtin(_, _)
         | T::UnannotatedExp_::BorrowLocal(_, _)
         | T::UnannotatedExp_::ErrorConstant { .. }
@@ -186,11 +190,24 @@ fn returnable_value(context: &mut Context, exp: &T::Exp) -> bool {
 }
 
 fn report_unneeded_return(context: &mut Context, loc: Loc) {
-    context.add_diag(diag!(
+    let mut diag = diag!(
         StyleCodes::UnneededReturn.diag_info(),
         (
             loc,
             "Remove unnecessary 'return', the expression is already in a 'return' position"
         )
-    ));
+    );
+    // remove the `return` keyword along with the whitespace following it
+    if let Some(keyword_len) = context
+        .env
+        .mapped_files()
+        .source_of_loc_opt(&loc)
+        .and_then(|src| src.strip_prefix("return"))
+        .map(|rest| loc.usize_range().len() - rest.trim_start().len())
+    {
+        let start = loc.start();
+        let keyword_loc = Loc::new(loc.file_hash(), start, start + keyword_len as u32);
+        diag.add_fix("Remove unnecessary 'return'", [(keyword_loc, "")]);
+    }
+    context.add_diag(diag);
 }
@@ -103,10 +103,10 @@ pub struct UseFuns {
 pub enum SyntaxMethodKind_ {
     Index,
     IndexMut,
-    // ForMut,
-    // ForImm,
-    // ForVal,
-    // Assign,
+    ForImm,
+    ForMut,
+    ForVal,
+    Assign,
 }
 
 pub type SyntaxMethodKind = Spanned<SyntaxMethodKind_>;
@@ -125,11 +125,20 @@ pub struct SyntaxMethod {
 pub struct IndexSyntaxMethods {
     pub index_mut: Option<Box<SyntaxMethod>>,
     pub index: Option<Box<SyntaxMethod>>,
+    pub assign: Option<Box<SyntaxMethod>>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForSyntaxMethods {
+    pub for_imm: Option<Box<SyntaxMethod>>,
+    pub for_mut: Option<Box<SyntaxMethod>>,
+    pub for_val: Option<Box<SyntaxMethod>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
 pub struct SyntaxMethodEntry {
     pub index: Option<Box<IndexSyntaxMethods>>,
+    pub for_: Option<Box<ForSyntaxMethods>>,
 }
 
 // Mapping from type to their possible "syntax methods"
@@ -285,6 +294,18 @@ pub enum BuiltinTypeName_ {
     U128,
     // u256
     U256,
+    // i8
+    I8,
+    // i16
+    I16,
+    // i32
+    I32,
+    // i64
+    I64,
+    // i128
+    I128,
+    // i256
+    I256,
     // Vector
     Vector,
     // bool
@@ -322,7 +343,7 @@ pub enum Type_ {
     Ref(bool, Box<Type>),
     Param(TParam),
     Apply(Option<AbilitySet>, TypeName, Vec<Type>),
-    Fun(Vec<Type>, Box<Type>),
+    Fun(Vec<Type>, Box<Type>, AbilitySet),
     Var(TVar),
     Anything,
     UnresolvedError,
@@ -448,6 +469,8 @@ pub enum Exp_ {
     Match(Box<Exp>, Spanned<Vec<MatchArm>>),
     While(BlockLabel, Box<Exp>, Box<Exp>),
     Loop(BlockLabel, Box<Exp>),
+    // The body of a 'for' loop is a lambda, passed to the subject type's 'for' syntax method
+    For(BlockLabel, Box<Exp>, Lambda),
     Block(Block),
     Lambda(Lambda),
 
@@ -597,6 +620,10 @@ impl SyntaxMethodEntry {
         match kind {
             SyntaxMethodKind_::Index => &mut self.index_entry().index,
             SyntaxMethodKind_::IndexMut => &mut self.index_entry().index_mut,
+            SyntaxMethodKind_::Assign => &mut self.index_entry().assign,
+            SyntaxMethodKind_::ForImm => &mut self.for_entry().for_imm,
+            SyntaxMethodKind_::ForMut => &mut self.for_entry().for_mut,
+            SyntaxMethodKind_::ForVal => &mut self.for_entry().for_val,
         }
     }
 
@@ -605,11 +632,24 @@ impl SyntaxMethodEntry {
             let new_index_syntax_method = IndexSyntaxMethods {
                 index: None,
                 index_mut: None,
+                assign: None,
             };
             self.index = Some(Box::new(new_index_syntax_method));
         }
         self.index.as_mut().unwrap()
     }
+
+    fn for_entry(&mut self) -> &mut ForSyntaxMethods {
+        if self.for_.is_none() {
+            let new_for_syntax_method = ForSyntaxMethods {
+                for_imm: None,
+                for_mut: None,
+                for_val: None,
+            };
+            self.for_ = Some(Box::new(new_for_syntax_method));
+        }
+        self.for_.as_mut().unwrap()
+    }
 }
 
 static BUILTIN_TYPE_ALL_NAMES: Lazy<BTreeSet<Symbol>> = Lazy::new(|| {
@@ -622,6 +662,12 @@ static BUILTIN_TYPE_ALL_NAMES: Lazy<BTreeSet<Symbol>> = Lazy::new(|| {
         BuiltinTypeName_::U_64,
         BuiltinTypeName_::U_128,
         BuiltinTypeName_::U_256,
+        BuiltinTypeName_::I_8,
+        BuiltinTypeName_::I_16,
+        BuiltinTypeName_::I_32,
+        BuiltinTypeName_::I_64,
+        BuiltinTypeName_::I_128,
+        BuiltinTypeName_::I_256,
         BuiltinTypeName_::BOOL,
         BuiltinTypeName_::VECTOR,
     ]
@@ -638,16 +684,39 @@ static BUILTIN_TYPE_NUMERIC: Lazy<BTreeSet<BuiltinTypeName_>> = Lazy::new(|| {
         BuiltinTypeName_::U64,
         BuiltinTypeName_::U128,
         BuiltinTypeName_::U256,
+        BuiltinTypeName_::I8,
+        BuiltinTypeName_::I16,
+        BuiltinTypeName_::I32,
+        BuiltinTypeName_::I64,
+        BuiltinTypeName_::I128,
+        BuiltinTypeName_::I256,
     ]
     .into_iter()
     .collect()
 });
 
-static BUILTIN_TYPE_BITS: Lazy<BTreeSet<BuiltinTypeName_>> =
-    Lazy::new(|| BUILTIN_TYPE_NUMERIC.clone());
+static BUILTIN_TYPE_SIGNED: Lazy<BTreeSet<BuiltinTypeName_>> = Lazy::new(|| {
+    [
+        BuiltinTypeName_::I8,
+        BuiltinTypeName_::I16,
+        BuiltinTypeName_::I32,
+        BuiltinTypeName_::I64,
+        BuiltinTypeName_::I128,
+        BuiltinTypeName_::I256,
+    ]
+    .into_iter()
+    .collect()
+});
+
+static BUILTIN_TYPE_BITS: Lazy<BTreeSet<BuiltinTypeName_>> = Lazy::new(|| {
+    BUILTIN_TYPE_NUMERIC
+        .difference(&BUILTIN_TYPE_SIGNED)
+        .copied()
+        .collect()
+});
 
 static BUILTIN_TYPE_ORDERED: Lazy<BTreeSet<BuiltinTypeName_>> =
-    Lazy::new(|| BUILTIN_TYPE_BITS.clone());
+    Lazy::new(|| BUILTIN_TYPE_NUMERIC.clone());
 
 impl BuiltinTypeName_ {
     pub const ADDRESS: &'static str = "address";
@@ -658,6 +727,12 @@ impl BuiltinTypeName_ {
     pub const U_64: &'static str = "u64";
     pub const U_128: &'static str = "u128";
     pub const U_256: &'static str = "u256";
+    pub const I_8: &'static str = "i8";
+    pub const I_16: &'static str = "i16";
+    pub const I_32: &'static str = "i32";
+    pub const I_64: &'static str = "i64";
+    pub const I_128: &'static str = "i128";
+    pub const I_256: &'static str = "i256";
     pub const BOOL: &'static str = "bool";
     pub const VECTOR: &'static str = "vector";
 
@@ -669,6 +744,10 @@ impl BuiltinTypeName_ {
         &BUILTIN_TYPE_NUMERIC
     }
 
+    pub fn signed() -> &'static BTreeSet<BuiltinTypeName_> {
+        &BUILTIN_TYPE_SIGNED
+    }
+
     pub fn bits() -> &'static BTreeSet<BuiltinTypeName_> {
         &BUILTIN_TYPE_BITS
     }
@@ -681,6 +760,10 @@ impl BuiltinTypeName_ {
         Self::numeric().contains(self)
     }
 
+    pub fn is_signed(&self) -> bool {
+        Self::signed().contains(self)
+    }
+
     pub fn resolve(name_str: &str) -> Option<Self> {
         use BuiltinTypeName_ as BT;
         match name_str {
@@ -692,6 +775,12 @@ impl BuiltinTypeName_ {
             BT::U_64 => Some(BT::U64),
             BT::U_128 => Some(BT::U128),
             BT::U_256 => Some(BT::U256),
+            BT::I_8 => Some(BT::I8),
+            BT::I_16 => Some(BT::I16),
+            BT::I_32 => Some(BT::I32),
+            BT::I_64 => Some(BT::I64),
+            BT::I_128 => Some(BT::I128),
+            BT::I_256 => Some(BT::I256),
             BT::BOOL => Some(BT::Bool),
             BT::VECTOR => Some(BT::Vector),
             _ => None,
@@ -702,9 +791,20 @@ impl BuiltinTypeName_ {
         use BuiltinTypeName_ as B;
         // Match here to make sure this function is fixed when collections are added
         match self {
-            B::Address | B::U8 | B::U16 | B::U32 | B::U64 | B::U128 | B::U256 | B::Bool => {
-                AbilitySet::primitives(loc)
-            }
+            B::Address
+            | B::U8
+            | B::U16
+            | B::U32
+            | B::U64
+            | B::U128
+            | B::U256
+            | B::I8
+            | B::I16
+            | B::I32
+            | B::I64
+            | B::I128
+            | B::I256
+            | B::Bool => AbilitySet::primitives(loc),
             B::Signer => AbilitySet::signer(loc),
             B::Vector => AbilitySet::collection(loc),
         }
@@ -722,6 +822,12 @@ impl BuiltinTypeName_ {
             | B::U64
             | B::U128
             | B::U256
+            | B::I8
+            | B::I16
+            | B::I32
+            | B::I64
+            | B::I128
+            | B::I256
             | B::Bool => vec![],
             B::Vector => vec![AbilitySet::empty()],
         }
@@ -798,9 +904,20 @@ impl Type_ {
     pub fn builtin_(b: BuiltinTypeName, ty_args: Vec<Type>) -> Type_ {
         use BuiltinTypeName_ as B;
         let abilities = match &b.value {
-            B::Address | B::U8 | B::U16 | B::U32 | B::U64 | B::U128 | B::U256 | B::Bool => {
-                Some(AbilitySet::primitives(b.loc))
-            }
+            B::Address
+            | B::U8
+            | B::U16
+            | B::U32
+            | B::U64
+            | B::U128
+            | B::U256
+            | B::I8
+            | B::I16
+            | B::I32
+            | B::I64
+            | B::I128
+            | B::I256
+            | B::Bool => Some(AbilitySet::primitives(b.loc)),
             B::Signer => Some(AbilitySet::signer(b.loc)),
             B::Vector => None,
         };
@@ -848,6 +965,30 @@ impl Type_ {
         Self::builtin(loc, sp(loc, BuiltinTypeName_::U256), vec![])
     }
 
+    pub fn i8(loc: Loc) -> Type {
+        Self::builtin(loc, sp(loc, BuiltinTypeName_::I8), vec![])
+    }
+
+    pub fn i16(loc: Loc) -> Type {
+        Self::builtin(loc, sp(loc, BuiltinTypeName_::I16), vec![])
+    }
+
+    pub fn i32(loc: Loc) -> Type {
+        Self::builtin(loc, sp(loc, BuiltinTypeName_::I32), vec![])
+    }
+
+    pub fn i64(loc: Loc) -> Type {
+        Self::builtin(loc, sp(loc, BuiltinTypeName_::I64), vec![])
+    }
+
+    pub fn i128(loc: Loc) -> Type {
+        Self::builtin(loc, sp(loc, BuiltinTypeName_::I128), vec![])
+    }
+
+    pub fn i256(loc: Loc) -> Type {
+        Self::builtin(loc, sp(loc, BuiltinTypeName_::I256), vec![])
+    }
+
     pub fn vector(loc: Loc, elem: Type) -> Type {
         Self::builtin(loc, sp(loc, BuiltinTypeName_::Vector), vec![elem])
     }
@@ -917,7 +1058,7 @@ impl Type_ {
             Type_::Unit => Some(AbilitySet::collection(loc)),
             Type_::Ref(_, _) => Some(AbilitySet::references(loc)),
             Type_::Anything | Type_::UnresolvedError => Some(AbilitySet::all(loc)),
-            Type_::Fun(_, _) => Some(AbilitySet::functions(loc)),
+            Type_::Fun(_, _, abilities) => Some(abilities.clone()),
             Type_::Var(_) => None,
         }
     }
@@ -929,7 +1070,7 @@ impl Type_ {
             Type_::Unit => Some(AbilitySet::COLLECTION.contains(&ability)),
             Type_::Ref(_, _) => Some(AbilitySet::REFERENCES.contains(&ability)),
             Type_::Anything | Type_::UnresolvedError => Some(true),
-            Type_::Fun(_, _) => Some(AbilitySet::FUNCTIONS.contains(&ability)),
+            Type_::Fun(_, _, abilities) => Some(abilities.has_ability_(ability)),
             Type_::Var(_) => None,
         }
     }
@@ -943,7 +1084,7 @@ impl Type_ {
             Type_::Unit
             | Type_::Param(_)
             | Type_::Apply(_, _, _)
-            | Type_::Fun(_, _)
+            | Type_::Fun(_, _, _)
             | Type_::Var(_)
             | Type_::Anything
             | Type_::UnresolvedError => None,
@@ -957,7 +1098,7 @@ impl Type_ {
             Type_::Unit
             | Type_::Param(_)
             | Type_::Apply(_, _, _)
-            | Type_::Fun(_, _)
+            | Type_::Fun(_, _, _)
             | Type_::Var(_)
             | Type_::Anything
             | Type_::UnresolvedError => self.clone(),
@@ -997,6 +1138,12 @@ impl Value_ {
             U64(_) => Type_::u64(loc),
             U128(_) => Type_::u128(loc),
             U256(_) => Type_::u256(loc),
+            I8(_) => Type_::i8(loc),
+            I16(_) => Type_::i16(loc),
+            I32(_) => Type_::i32(loc),
+            I64(_) => Type_::i64(loc),
+            I128(_) => Type_::i128(loc),
+            I256(_) => Type_::i256(loc),
             Bool(_) => Type_::bool(loc),
             Bytearray(_) => Type_::vector(loc, Type_::u8(loc)),
         })
@@ -1022,6 +1169,12 @@ impl fmt::Display for BuiltinTypeName_ {
                 BT::U64 => BT::U_64,
                 BT::U128 => BT::U_128,
                 BT::U256 => BT::U_256,
+                BT::I8 => BT::I_8,
+                BT::I16 => BT::I_16,
+                BT::I32 => BT::I_32,
+                BT::I64 => BT::I_64,
+                BT::I128 => BT::I_128,
+                BT::I256 => BT::I_256,
                 BT::Bool => BT::BOOL,
                 BT::Vector => BT::VECTOR,
             }
@@ -1058,6 +1211,10 @@ impl fmt::Display for SyntaxMethodKind_ {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         let msg = match self {
             SyntaxMethodKind_::IndexMut | SyntaxMethodKind_::Index => SyntaxAttribute::INDEX,
+            SyntaxMethodKind_::ForImm | SyntaxMethodKind_::ForMut | SyntaxMethodKind_::ForVal => {
+                SyntaxAttribute::FOR
+            }
+            SyntaxMethodKind_::Assign => SyntaxAttribute::ASSIGN,
         };
         write!(f, "{}", msg)
     }
@@ -1181,15 +1338,32 @@ impl AstDebug for SyntaxMethod {
 impl AstDebug for (&TypeName, &SyntaxMethodEntry) {
     fn ast_debug(&self, w: &mut AstWriter) {
         let (_tn, methods) = *self;
-        let SyntaxMethodEntry { index } = methods;
+        let SyntaxMethodEntry { index, for_ } = methods;
         if let Some(index) = &index {
-            let IndexSyntaxMethods { index_mut, index } = &**index;
+            let IndexSyntaxMethods {
+                index_mut,
+                index,
+                assign,
+            } = &**index;
             if let Some(index) = index.as_ref() {
                 index.ast_debug(w)
             }
             if let Some(index_mut) = index_mut.as_ref() {
                 index_mut.ast_debug(w)
             }
+            if let Some(assign) = assign.as_ref() {
+                assign.ast_debug(w)
+            }
+        }
+        if let Some(for_) = &for_ {
+            let ForSyntaxMethods {
+                for_imm,
+                for_mut,
+                for_val,
+            } = &**for_;
+            for method in [for_imm, for_mut, for_val].into_iter().flatten() {
+                method.ast_debug(w)
+            }
         }
     }
 }
@@ -1576,11 +1750,18 @@ impl AstDebug for Type_ {
                     }),
                 }
             }
-            Type_::Fun(args, result) => {
+            Type_::Fun(args, result, abilities) => {
                 w.write("|");
                 w.comma(args, |w, ty| ty.ast_debug(w));
                 w.write("|");
                 result.ast_debug(w);
+                if !abilities.is_empty() {
+                    w.write(" has ");
+                    w.list(abilities, " + ", |w, ab| {
+                        ab.ast_debug(w);
+                        false
+                    });
+                }
             }
             Type_::Var(tv) => w.write(format!("#{}", tv.0)),
             Type_::Anything => w.write("_"),
@@ -1755,6 +1936,14 @@ impl AstDebug for Exp_ {
                 w.write("loop ");
                 e.ast_debug(w);
             }
+            E::For(name, subject, body) => {
+                name.ast_debug(w);
+                w.write(": ");
+                w.write("for ");
+                subject.ast_debug(w);
+                w.write(" ");
+                body.ast_debug(w);
+            }
             E::Block(seq) => seq.ast_debug(w),
             E::Lambda(l) => l.ast_debug(w),
             E::ExpList(es) => {
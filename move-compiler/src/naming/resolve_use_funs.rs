@@ -372,6 +372,10 @@ fn exp(context: &mut Context, sp!(_, e_): &mut N::Exp) {
             exp(context, econd);
             exp(context, ebody)
         }
+        N::Exp_::For(_, esubject, N::Lambda { body, .. }) => {
+            exp(context, esubject);
+            exp(context, body)
+        }
         N::Exp_::Block(N::Block {
             name: _,
             from_macro_argument: _,
@@ -65,18 +65,6 @@ pub(super) fn resolve_syntax_attributes(
         return None;
     }
 
-    // For loops may need to change this, but for now we disallow this.
-    if let Some(macro_loc) = function.macro_ {
-        let msg = "Syntax attributes may not appear on macro definitions";
-        let fn_msg = "This function is a macro";
-        context.add_diag(diag!(
-            Declarations::InvalidSyntaxMethod,
-            (attr_loc, msg),
-            (macro_loc, fn_msg)
-        ));
-        return None;
-    }
-
     let method_entry = syntax_methods.entry(type_name).or_default();
 
     for prekind in syntax_method_prekinds {
@@ -84,6 +72,10 @@ pub(super) fn resolve_syntax_attributes(
             assert!(context.env.has_errors());
             continue;
         };
+        if !valid_macro_form(context, &kind, attr_loc, function) {
+            assert!(context.env.has_errors());
+            continue;
+        }
         if !valid_return_type(
             context,
             &kind,
@@ -120,6 +112,10 @@ fn prev_syntax_defn_error(
     let kind_string = match method_kind {
         SyntaxMethodKind_::Index => format!("'{}'", SyntaxAttribute::INDEX),
         SyntaxMethodKind_::IndexMut => format!("mutable '{}'", SyntaxAttribute::INDEX),
+        SyntaxMethodKind_::ForImm => format!("immutable '{}'", SyntaxAttribute::FOR),
+        SyntaxMethodKind_::ForMut => format!("mutable '{}'", SyntaxAttribute::FOR),
+        SyntaxMethodKind_::ForVal => format!("by-value '{}'", SyntaxAttribute::FOR),
+        SyntaxMethodKind_::Assign => format!("'{}'", SyntaxAttribute::ASSIGN),
     };
     let msg = format!(
         "Redefined {} 'syntax' method for '{}'",
@@ -230,40 +226,84 @@ fn determine_valid_kind(
                 return None;
             }
         }
-        SyntaxMethodPrekind_::For => {
-            let msg = "'for' syntax attributes are not currently supported";
-            context.add_diag(diag!(Declarations::InvalidAttribute, (sloc, msg),));
-            return None;
-        }
-        // SyntaxMethodPrekind_::For => match mut_opt {
-        //     Some((loc, true)) => SK::ForMut,
-        //     Some((loc, false)) => SK::ForImm,
-        //     None => SK::ForVal,
-        // },
+        SyntaxMethodPrekind_::For => match subject_type.value.is_ref() {
+            Some(false) => SK::ForImm,
+            Some(true) => SK::ForMut,
+            None => SK::ForVal,
+        },
         SyntaxMethodPrekind_::Assign => {
-            let msg = "'assign' syntax attributes are not currently supported";
-            context.add_diag(diag!(Declarations::InvalidAttribute, (sloc, msg),));
-            return None;
-        } // SyntaxMethodPrekind_::Assign => match mut_opt {
-          //     Some((loc, true)) => SK::Assign,
-          //     _ => {
-          //         let msg = format!(
-          //         "'{}' is only valid if the first parameter's type is a mutable reference as '&mut'",
-          //         SyntaxAttribute::INDEX,
-          //     );
-          //         let ty_msg = "This type is not a reference";
-          //         context.add_diag(diag!(
-          //             Declarations::InvalidAttribute,
-          //             (sloc, msg),
-          //             (*ty_loc, msg)
-          //         ));
-          //         return None;
-          //     }
-          // },
+            if valid_mut_ref(subject_type) {
+                SK::Assign
+            } else {
+                let msg = format!(
+                    "'{}' is only valid if the first parameter's type is a mutable reference as '&mut'",
+                    SyntaxAttribute::ASSIGN,
+                );
+                let ty_msg = "This type is not a mutable reference";
+                context.add_diag(diag!(
+                    Declarations::InvalidAttribute,
+                    (sloc, msg),
+                    (subject_type.loc, ty_msg)
+                ));
+                return None;
+            }
+        }
     };
     Some(sp(sloc, sk))
 }
 
+/// 'index' and 'assign' syntax methods must be normal functions, while 'for' syntax methods must
+/// be macros taking the subject and a lambda for the loop body, as in `$f: |&$T| -> $R`.
+fn valid_macro_form(
+    context: &mut Context,
+    sp!(sloc, kind): &SyntaxMethodKind,
+    attr_loc: Loc,
+    function: &N::Function,
+) -> bool {
+    match kind {
+        SyntaxMethodKind_::Index | SyntaxMethodKind_::IndexMut | SyntaxMethodKind_::Assign => {
+            if let Some(macro_loc) = function.macro_ {
+                let msg = format!(
+                    "'{}' syntax attributes may not appear on macro definitions",
+                    kind
+                );
+                let fn_msg = "This function is a macro";
+                context.add_diag(diag!(
+                    Declarations::InvalidSyntaxMethod,
+                    (attr_loc, msg),
+                    (macro_loc, fn_msg)
+                ));
+                false
+            } else {
+                true
+            }
+        }
+        SyntaxMethodKind_::ForImm | SyntaxMethodKind_::ForMut | SyntaxMethodKind_::ForVal => {
+            if function.macro_.is_none() {
+                let msg = format!(
+                    "'{}' syntax attributes may only appear on macro definitions",
+                    SyntaxAttribute::FOR
+                );
+                context.add_diag(diag!(Declarations::InvalidSyntaxMethod, (*sloc, msg)));
+                return false;
+            }
+            let parameters = &function.signature.parameters;
+            let valid_body_param = parameters.len() == 2
+                && matches!(&parameters[1].2.value, N::Type_::Fun(args, _, _) if args.len() == 1);
+            if !valid_body_param {
+                let msg = format!(
+                    "Invalid '{}' syntax method. It must take exactly two parameters: \
+                    the subject and a lambda taking one argument for the loop body",
+                    SyntaxAttribute::FOR
+                );
+                context.add_diag(diag!(Declarations::InvalidSyntaxMethod, (*sloc, msg)));
+                return false;
+            }
+            true
+        }
+    }
+}
+
 //-------------------------------------------------------------------------------------------------
 // Types
 //-------------------------------------------------------------------------------------------------
@@ -322,7 +362,7 @@ fn determine_subject_type_name(
             assert!(context.env.has_errors());
             None
         }
-        N::Type_::Unit | N::Type_::Fun(_, _) => {
+        N::Type_::Unit | N::Type_::Fun(_, _, _) => {
             let msg = "Invalid type for syntax method definition";
             let mut diag = diag!(Declarations::InvalidSyntaxMethod, (*loc, msg));
             diag.add_note("Syntax methods may only be defined for single base types");
@@ -398,6 +438,27 @@ fn valid_return_type(
                 false
             }
         }
+
+        SyntaxMethodKind_::ForImm
+        | SyntaxMethodKind_::ForMut
+        | SyntaxMethodKind_::ForVal
+        | SyntaxMethodKind_::Assign => {
+            if matches!(ty.value, N::Type_::Unit) {
+                true
+            } else {
+                let msg = format!(
+                    "Invalid {} annotation. This syntax method must return a unit type",
+                    SyntaxAttribute::SYNTAX
+                );
+                let tmsg = "This is not a unit type";
+                context.add_diag(diag!(
+                    Declarations::InvalidSyntaxMethod,
+                    (*loc, msg),
+                    (ty.loc, tmsg)
+                ));
+                false
+            }
+        }
     }
 }
 
@@ -430,7 +491,7 @@ fn valid_index_return_type(
             ));
             false
         }
-        N::Type_::Fun(_, _) => {
+        N::Type_::Fun(_, _, _) => {
             let msg = format!(
                 "Invalid {} annotation. A syntax method cannot return a function",
                 SyntaxAttribute::SYNTAX
@@ -183,6 +183,8 @@ pub(super) enum ResolvedPatternTerm {
 enum LoopType {
     While,
     Loop,
+    // The label is that of the loop body's lambda, to which 'continue' gives
+    For(BlockLabel),
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
@@ -751,10 +753,16 @@ impl<'outer, 'env> Context<'outer, 'env> {
                     module_type.set_name_info(self.current_module, nloc);
                     ResolvedType::ModuleType(module_type)
                 }
-                ty @ (ResolvedType::BuiltinType(_)
-                | ResolvedType::TParam(_, _)
-                | ResolvedType::Hole
-                | ResolvedType::Unbound) => ty,
+                ty @ ResolvedType::BuiltinType(bt_) => {
+                    if bt_.is_signed() {
+                        let current_package = self.current_package;
+                        self.check_feature(current_package, FeatureGate::SignedIntegers, nloc);
+                    }
+                    ty
+                }
+                ty @ (ResolvedType::TParam(_, _) | ResolvedType::Hole | ResolvedType::Unbound) => {
+                    ty
+                }
             },
             EN::ModuleAccess(m, n) | EN::Variant(sp!(_, (m, n)), _) => {
                 let Some(module_type) = self.resolve_module_type(nloc, &m, &n, error_kind) else {
@@ -1406,6 +1414,32 @@ impl<'outer, 'env> Context<'outer, 'env> {
         let (_name, label, name_type) = self.nominal_blocks.pop().unwrap();
         (label, name_type)
     }
+
+    fn new_implicit_block_label(&mut self, loc: Loc) -> BlockLabel {
+        let id = self.nominal_block_id;
+        self.nominal_block_id += 1;
+        block_label(loc, None, id)
+    }
+
+    // The body of a 'for' loop is a lambda, so continuing the loop returns from that lambda
+    fn continue_exp(&self, loc: Loc, label: BlockLabel) -> N::Exp_ {
+        let for_body_label = self
+            .nominal_blocks
+            .iter()
+            .find_map(|(_, block_label, name_type)| match name_type {
+                NominalBlockType::Loop(LoopType::For(body_label)) if *block_label == label => {
+                    Some(*body_label)
+                }
+                _ => None,
+            });
+        match for_body_label {
+            Some(body_label) => {
+                let unit = sp(loc, N::Exp_::Unit { trailing: false });
+                N::Exp_::Give(NominalBlockUsage::Continue, body_label, Box::new(unit))
+            }
+            None => N::Exp_::Continue(label),
+        }
+    }
 }
 
 fn block_label(loc: Loc, name: Option<Symbol>, id: u16) -> BlockLabel {
@@ -1443,6 +1477,7 @@ impl std::fmt::Display for LoopType {
         match self {
             LoopType::While => write!(f, "while"),
             LoopType::Loop => write!(f, "loop"),
+            LoopType::For(_) => write!(f, "for"),
         }
     }
 }
@@ -2515,10 +2550,10 @@ fn type_(context: &mut Context, case: TypeAnnotation, sp!(loc, ety_): E::Type) -
                 }
             }
         }
-        ET::Fun(tys, ty) => {
+        ET::Fun(tys, ty, abilities) => {
             let tys = types(context, case, tys);
             let ty = Box::new(type_(context, case, *ty));
-            NT::Fun(tys, ty)
+            NT::Fun(tys, ty, abilities)
         }
     };
     sp(loc, ty_)
@@ -2728,6 +2763,37 @@ fn exp(context: &mut Context, e: Box<E::Exp>) -> Box<N::Exp> {
             assert_eq!(name_type, NominalBlockType::Loop(LoopType::Loop));
             NE::Loop(label, body)
         }
+        EE::For(name_opt, elv, esubject, ebody) => {
+            let subject = exp(context, esubject);
+            let body_label = context.new_implicit_block_label(eloc);
+            let loop_type = LoopType::For(body_label);
+            context.enter_nominal_block(eloc, name_opt, NominalBlockType::Loop(loop_type));
+            context.new_local_scope();
+            let lvloc = elv.loc;
+            let elambda_binds = sp(lvloc, vec![(sp(lvloc, vec![elv]), None)]);
+            let nlambda_binds_opt = lambda_bind_list(context, elambda_binds);
+            let body = exp(context, ebody);
+            context.close_local_scope();
+            let (label, name_type) = context.exit_nominal_block();
+            assert_eq!(name_type, NominalBlockType::Loop(loop_type));
+            match nlambda_binds_opt {
+                None => {
+                    assert!(context.env.has_errors());
+                    NE::UnresolvedError
+                }
+                Some(parameters) => {
+                    let lambda = N::Lambda {
+                        parameters,
+                        return_type: Some(sp(body.loc, N::Type_::Unit)),
+                        return_label: body_label,
+                        use_fun_color: 0, // used in macro expansion
+                        body,
+                        extra_annotations: vec![], // used in macro expansion
+                    };
+                    NE::For(label, subject, lambda)
+                }
+            }
+        }
         EE::Block(Some(name), eseq) => {
             context.enter_nominal_block(eloc, Some(name), NominalBlockType::Block);
             let seq = sequence(context, eseq);
@@ -2844,12 +2910,12 @@ fn exp(context: &mut Context, e: Box<E::Exp>) -> Box<N::Exp> {
             if let Some(loop_name) = name_opt {
                 context
                     .resolve_nominal_label(NominalBlockUsage::Continue, loop_name)
-                    .map(NE::Continue)
+                    .map(|name| context.continue_exp(eloc, name))
                     .unwrap_or_else(|| NE::UnresolvedError)
             } else {
                 context
                     .current_continue(eloc)
-                    .map(NE::Continue)
+                    .map(|name| context.continue_exp(eloc, name))
                     .unwrap_or_else(|| NE::UnresolvedError)
             }
         }
@@ -4095,8 +4161,15 @@ fn resolve_call(
                     ),
                 ));
             }
+            // Non-syntax locals can be invoked as function values, which is checked in typing
+            if !var.value.is_syntax_identifier()
+                && context
+                    .env
+                    .supports_feature(context.current_package, FeatureGate::Closures)
+            {
+                N::Exp_::VarCall(sp(subject_loc, var.value), args)
             // If this variable refers to a local (num > 0) or it isn't syntax, error.
-            if !var.value.is_syntax_identifier() {
+            } else if !var.value.is_syntax_identifier() {
                 let name = var.value.name;
                 let msg = format!(
                     "Unexpected invocation of parameter or local '{name}'. \
@@ -4316,6 +4389,13 @@ fn remove_unused_bindings_exp(
             remove_unused_bindings_exp(context, used, econd);
             remove_unused_bindings_exp(context, used, ebody)
         }
+        N::Exp_::For(_, esubject, lambda) => {
+            remove_unused_bindings_exp(context, used, esubject);
+            for (lvs, _) in &mut lambda.parameters.value {
+                remove_unused_bindings_lvalues(context, used, lvs)
+            }
+            remove_unused_bindings_exp(context, used, &mut lambda.body)
+        }
         N::Exp_::Block(N::Block {
             name: _,
             from_macro_argument: _,
@@ -8,8 +8,8 @@ use crate::{
     diagnostics::Diagnostic,
     ice,
     shared::{
-        ast_debug::*, format_comma, Identifier, Name, NamedAddressMap, NamedAddressMapIndex,
-        NamedAddressMaps, NumericalAddress, TName,
+        ast_debug::*, format_comma, format_delim, Identifier, Name, NamedAddressMap,
+        NamedAddressMapIndex, NamedAddressMaps, NumericalAddress, TName,
     },
 };
 use move_command_line_common::files::FileHash;
@@ -435,8 +435,8 @@ pub enum Type_ {
     // &t
     // &mut t
     Ref(bool, Box<Type>),
-    // (t1,...,tn):t
-    Fun(Vec<Type>, Box<Type>),
+    // |t1,...,tn| -> t has a1 + ... + am
+    Fun(Vec<Type>, Box<Type>, Vec<Ability>),
     // ()
     Unit,
     // (t1, t2, ... , tn)
@@ -486,7 +486,7 @@ pub type LambdaBindings = Spanned<LambdaBindings_>;
 pub enum Value_ {
     // @<num>
     Address(LeadingNameAccess),
-    // <num>(u8|u16|u32|u64|u128|u256)?
+    // <num>(u8|u16|u32|u64|u128|u256|i8|i16|i32|i64|i128|i256)?
     Num(Symbol),
     // false
     Bool(bool),
@@ -501,6 +501,8 @@ pub type Value = Spanned<Value_>;
 pub enum UnaryOp_ {
     // !
     Not,
+    // -
+    Neg,
 }
 pub type UnaryOp = Spanned<UnaryOp_>;
 
@@ -601,6 +603,8 @@ pub enum Exp_ {
     While(Box<Exp>, Box<Exp>),
     // loop eloop
     Loop(Box<Exp>),
+    // for (bind in e) eloop
+    For(Bind, Box<Exp>, Box<Exp>),
 
     // 'label: e
     Labeled(BlockLabel, Box<Exp>),
@@ -1141,11 +1145,13 @@ impl Type_ {
 
 impl UnaryOp_ {
     pub const NOT: &'static str = "!";
+    pub const NEG: &'static str = "-";
 
     pub fn symbol(&self) -> &'static str {
         use UnaryOp_ as U;
         match self {
             U::Not => U::NOT,
+            U::Neg => U::NEG,
         }
     }
 
@@ -1153,6 +1159,7 @@ impl UnaryOp_ {
         use UnaryOp_ as U;
         match self {
             U::Not => true,
+            U::Neg => false,
         }
     }
 }
@@ -1351,7 +1358,13 @@ impl fmt::Display for Type_ {
         match self {
             Apply(n) => write!(f, "{}", n),
             Ref(mut_, ty) => write!(f, "&{}{}", if *mut_ { "mut " } else { "" }, ty),
-            Fun(args, result) => write!(f, "({}):{}", format_comma(args), result),
+            Fun(args, result, abilities) => {
+                write!(f, "({}):{}", format_comma(args), result)?;
+                if !abilities.is_empty() {
+                    write!(f, " has {}", format_delim(abilities, " + "))?;
+                }
+                Ok(())
+            }
             Unit => write!(f, "()"),
             Multiple(tys) => {
                 write!(f, "(")?;
@@ -1923,11 +1936,18 @@ impl AstDebug for Type_ {
                 }
                 s.ast_debug(w)
             }
-            Type_::Fun(args, result) => {
+            Type_::Fun(args, result, abilities) => {
                 w.write("(");
                 w.comma(args, |w, ty| ty.ast_debug(w));
                 w.write("):");
                 result.ast_debug(w);
+                if !abilities.is_empty() {
+                    w.write(" has ");
+                    w.list(abilities, " + ", |w, ab| {
+                        ab.ast_debug(w);
+                        false
+                    });
+                }
             }
             Type_::UnresolvedError => w.write("_|_"),
         }
@@ -2136,6 +2156,14 @@ impl AstDebug for Exp_ {
                 w.write("loop ");
                 e.ast_debug(w);
             }
+            E::For(b, e, eloop) => {
+                w.write("for (");
+                b.ast_debug(w);
+                w.write(" in ");
+                e.ast_debug(w);
+                w.write(")");
+                eloop.ast_debug(w);
+            }
             E::Labeled(name, e) => {
                 w.write(format!("'{name}: "));
                 e.ast_debug(w)
@@ -36,3 +36,85 @@ pub fn verify_string(file_hash: FileHash, string: &str) -> Result<(), Diagnostic
         }
     }
 }
+
+/// A (regular or documentation) comment in the source text, including its delimiters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comment {
+    pub start: u32,
+    pub end: u32,
+    pub kind: CommentKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    // `// ...` (ends right before the newline)
+    Line,
+    // `/* ... */` (can be nested)
+    Block,
+}
+
+impl Comment {
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.start as usize..self.end as usize]
+    }
+}
+
+/// Collects all comments in the source text in the order of their appearance. Unlike the lexer,
+/// which only keeps documentation comments, this also returns regular comments so that tools
+/// working on the source text (e.g., the formatter) can preserve them. Comment-like text inside
+/// byte string literals is skipped. An unterminated block comment extends to the end of the text.
+pub fn extract_comments(source: &str) -> Vec<Comment> {
+    let bytes = source.as_bytes();
+    let mut comments = vec![];
+    let mut pos = 0;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'"' => {
+                pos += 1;
+                while pos < bytes.len() && bytes[pos] != b'"' {
+                    pos += if bytes[pos] == b'\\' { 2 } else { 1 };
+                }
+                pos += 1;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                let start = pos;
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+                let end = start + source[start..pos].trim_end_matches('\r').len();
+                comments.push(Comment {
+                    start: start as u32,
+                    end: end as u32,
+                    kind: CommentKind::Line,
+                });
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                let start = pos;
+                let mut depth = 0;
+                while pos < bytes.len() {
+                    if bytes[pos..].starts_with(b"/*") {
+                        depth += 1;
+                        pos += 2;
+                    } else if bytes[pos..].starts_with(b"*/") {
+                        depth -= 1;
+                        pos += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        pos += 1;
+                    }
+                }
+                let end = pos.min(bytes.len());
+                pos = end;
+                comments.push(Comment {
+                    start: start as u32,
+                    end: end as u32,
+                    kind: CommentKind::Block,
+                });
+            }
+            _ => pos += 1,
+        }
+    }
+    comments
+}
@@ -36,6 +36,9 @@ pub const KEYWORDS: &[&str] = &[
 
 pub const CONTEXTUAL_KEYWORDS: &[&str] = &["address", "mut", "phantom", "Self", "entry", "macro"];
 
-pub const PRIMITIVE_TYPES: &[&str] = &["u8", "u16", "u32", "u64", "u128", "u256", "bool", "vector"];
+pub const PRIMITIVE_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "u256", "i8", "i16", "i32", "i64", "i128", "i256", "bool",
+    "vector",
+];
 
 pub const BUILTINS: &[&str] = &["assert", "freeze"];
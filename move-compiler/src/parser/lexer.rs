@@ -907,11 +907,13 @@ fn get_hex_number(text: &str) -> (Tok, usize) {
 // portion, checks for a typed suffix.
 fn get_number_maybe_with_suffix(text: &str, num_text_len: usize) -> (Tok, usize) {
     let rest = &text[num_text_len..];
-    if rest.starts_with("u8") {
+    // Unsigned suffixes start with 'u' and signed suffixes with 'i'
+    let suffix = rest.strip_prefix(['u', 'i']).unwrap_or_default();
+    if suffix.starts_with('8') {
         (Tok::NumTypedValue, num_text_len + 2)
-    } else if rest.starts_with("u64") || rest.starts_with("u16") || rest.starts_with("u32") {
+    } else if suffix.starts_with("64") || suffix.starts_with("16") || suffix.starts_with("32") {
         (Tok::NumTypedValue, num_text_len + 3)
-    } else if rest.starts_with("u128") || rest.starts_with("u256") {
+    } else if suffix.starts_with("128") || suffix.starts_with("256") {
         (Tok::NumTypedValue, num_text_len + 4)
     } else {
         // No typed suffix
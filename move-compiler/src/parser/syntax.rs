@@ -1687,6 +1687,7 @@ fn parse_sequence(context: &mut Context) -> Result<Sequence, Box<Diagnostic>> {
 //          | "while" "(" <Exp> ")" <Exp> (SpecBlock)?
 //          | "loop" <Exp>
 //          | "loop" (<BlockLabel> ":")? "{" <Exp> "}"
+//          | "for" "(" <Bind> "in" <Exp> ")" <Exp>
 //          | "return" <BlockLabel>? "{" <Exp> "}"
 //          | "return" <BlockLabel>? <Exp>?
 //          | "abort" "{" <Exp> "}"
@@ -1844,7 +1845,7 @@ fn is_control_exp(context: &mut Context, tok: Tok) -> bool {
             | Tok::Return
             | Tok::Abort
             | Tok::BlockLabel
-    ) || (matches!(tok, Tok::Match)
+    ) || (matches!(tok, Tok::Match | Tok::For)
         && context
             .env
             .supports_feature(context.current_package, FeatureGate::Move2024Keywords)
@@ -1960,6 +1961,16 @@ fn parse_control_exp(context: &mut Context) -> Result<(Exp, bool), Box<Diagnosti
             let (eloop, ends_in_block) = parse_exp_or_sequence(context)?;
             (Exp_::Loop(Box::new(eloop)), ends_in_block)
         }
+        Tok::For => {
+            context.tokens.advance()?;
+            consume_token(context.tokens, Tok::LParen)?;
+            let b = parse_bind(context)?;
+            consume_identifier(context.tokens, "in")?;
+            let e = parse_exp(context)?;
+            consume_token(context.tokens, Tok::RParen)?;
+            let (eloop, ends_in_block) = parse_exp_or_sequence(context)?;
+            (Exp_::For(b, Box::new(e), Box::new(eloop)), ends_in_block)
+        }
         Tok::Return => {
             context.tokens.advance()?;
             let label = match context.tokens.peek() {
@@ -2378,6 +2389,7 @@ fn at_start_of_exp(context: &mut Context) -> bool {
             | Tok::AmpMut
             | Tok::Star
             | Tok::Exclaim
+            | Tok::Minus
             | Tok::LParen
             | Tok::LBrace
             | Tok::Abort
@@ -2386,6 +2398,7 @@ fn at_start_of_exp(context: &mut Context) -> bool {
             | Tok::If
             | Tok::Match
             | Tok::Loop
+            | Tok::For
             | Tok::Return
             | Tok::While
             | Tok::BlockLabel
@@ -2581,6 +2594,7 @@ fn parse_binop_exp(context: &mut Context, lhs: Exp, min_prec: u32) -> Result<Exp
 // Parse a unary expression:
 //      UnaryExp =
 //          "!" <UnaryExp>
+//          | "-" <UnaryExp>
 //          | "&mut" <UnaryExp>
 //          | "&" "mut" <UnaryExp>
 //          | "&" <UnaryExp>
@@ -2603,6 +2617,18 @@ fn parse_unary_exp(context: &mut Context) -> Result<Exp, Box<Diagnostic>> {
             let e = parse_unary_exp(context)?;
             Exp_::UnaryExp(op, Box::new(e))
         }
+        Tok::Minus => {
+            context.tokens.advance()?;
+            let op_end_loc = context.tokens.previous_end_loc();
+            let op = spanned(
+                context.tokens.file_hash(),
+                start_loc,
+                op_end_loc,
+                UnaryOp_::Neg,
+            );
+            let e = parse_unary_exp(context)?;
+            Exp_::UnaryExp(op, Box::new(e))
+        }
         Tok::AmpMut => {
             context.tokens.advance()?;
             let e = parse_unary_exp(context)?;
@@ -2653,6 +2679,7 @@ fn parse_unary_exp(context: &mut Context) -> Result<Exp, Box<Diagnostic>> {
 //          | <DotOrIndexChain> "." <Number>
 //          | <DotOrIndexChain> "[" <Exp> "]"                      spec only
 //          | <DotOrIndexChain> <OptionalTypeArgs> "(" Comma<Exp> ")"
+//          | <DotOrIndexChain> "(" Comma<Exp> ")"                 invalid
 //          | <Term>
 fn parse_dot_or_index_chain(context: &mut Context) -> Result<Exp, Box<Diagnostic>> {
     let start_loc = context.tokens.start_loc();
@@ -2743,6 +2770,23 @@ fn parse_dot_or_index_chain(context: &mut Context) -> Result<Exp, Box<Diagnostic
 
                 Exp_::Index(Box::new(lhs), index_args)
             }
+            // Function values can only be called through locals, e.g. 'f(x)', which are parsed
+            // as calls in 'parse_term'
+            Tok::LParen => {
+                let args = parse_call_args(context);
+                let msg = "Invalid call. Only function values in local variables can be called";
+                let mut diag = diag!(
+                    Syntax::InvalidCall,
+                    (args.loc, msg),
+                    (lhs.loc, "This expression is not a local variable")
+                );
+                diag.add_note(
+                    "Assign the function value to a local variable to call it, e.g. \
+                     'let f = s.f; f(x)'",
+                );
+                context.add_diag(diag);
+                Exp_::UnresolvedError
+            }
             _ => break,
         };
         let end_loc = context.tokens.previous_end_loc();
@@ -2944,7 +2988,7 @@ fn make_builtin_call(loc: Loc, name: Symbol, args: Vec<Exp>) -> Exp {
 //          | "&" <Type>
 //          | "&mut" <Type>
 //          | "&" "mut" <Type>
-//          | "|" Comma<Type> "|" Type   (spec only)
+//          | "|" Comma<Type> "|" ("->" Type)? ("has" <Ability> ("+" <Ability>)*)?
 //          | "(" Comma<Type> ")"
 fn parse_type(context: &mut Context) -> Result<Type, Box<Diagnostic>> {
     parse_type_(context, /* whitespace_sensitive_ty_args */ false)
@@ -3022,11 +3066,20 @@ fn parse_type_(
                 // legacy spec syntax
                 parse_type(context)?
             };
+            let abilities =
+                if context.tokens.peek() == Tok::Identifier && context.tokens.content() == "has" {
+                    let has_loc = current_token_loc(context.tokens);
+                    context.check_feature(context.current_package, FeatureGate::Closures, has_loc);
+                    context.tokens.advance()?;
+                    parse_function_type_abilities(context)?
+                } else {
+                    vec![]
+                };
             return Ok(spanned(
                 context.tokens.file_hash(),
                 start_loc,
                 context.tokens.previous_end_loc(),
-                Type_::Fun(args, Box::new(result)),
+                Type_::Fun(args, Box::new(result), abilities),
             ));
         }
         _ => {
@@ -3109,6 +3162,16 @@ fn parse_ability(context: &mut Context) -> Result<Ability, Box<Diagnostic>> {
     }
 }
 
+// Parse the abilities declared on a function type:
+//      FunctionTypeAbilities = <Ability> ("+" <Ability>)*
+fn parse_function_type_abilities(context: &mut Context) -> Result<Vec<Ability>, Box<Diagnostic>> {
+    let mut abilities = vec![parse_ability(context)?];
+    while match_token(context.tokens, Tok::Plus)? {
+        abilities.push(parse_ability(context)?);
+    }
+    Ok(abilities)
+}
+
 // Parse a type parameter:
 //      TypeParameter =
 //          <SyntaxIdentifier> <Constraint>?
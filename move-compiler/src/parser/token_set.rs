@@ -85,6 +85,7 @@ const EXP_STARTS: &[Tok] = &[
     Tok::AmpMut,
     Tok::Star,
     Tok::Exclaim,
+    Tok::Minus,
     Tok::LParen,
     Tok::LBrace,
     Tok::Abort,
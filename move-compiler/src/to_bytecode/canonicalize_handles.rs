@@ -300,6 +300,12 @@ fn remap_signature_token(token: &mut SignatureToken, datatypes: &[TableIndex]) {
         | T::U64
         | T::U128
         | T::U256
+        | T::I8
+        | T::I16
+        | T::I32
+        | T::I64
+        | T::I128
+        | T::I256
         | T::Address
         | T::Signer
         | T::TypeParameter(_) => (),
@@ -317,6 +323,13 @@ fn remap_signature_token(token: &mut SignatureToken, datatypes: &[TableIndex]) {
                 remap_signature_token(token, datatypes)
             }
         }
+
+        T::Function(func) => {
+            let (params, returns, _) = Box::as_mut(func);
+            for token in params.iter_mut().chain(returns) {
+                remap_signature_token(token, datatypes)
+            }
+        }
     }
 }
 
@@ -324,7 +337,7 @@ fn remap_signature_token(token: &mut SignatureToken, datatypes: &[TableIndex]) {
 /// `functions`.
 fn remap_code(code: &mut CodeUnit, functions: &[TableIndex]) {
     for instr in &mut code.code {
-        if let Bytecode::Call(function) = instr {
+        if let Bytecode::Call(function) | Bytecode::PackClosure(function, _) = instr {
             remap!(FunctionHandleIndex, *function, functions);
         }
     }
@@ -683,6 +683,10 @@ fn seen_datatypes_base_type(
             }
             tys.iter().for_each(|st| seen_datatypes_base_type(seen, st))
         }
+        B::Fun(params, returns, _) => params
+            .iter()
+            .chain(returns)
+            .for_each(|st| seen_datatypes_single_type(seen, st)),
         B::Param(TParam { .. }) => (),
     }
 }
@@ -848,6 +852,12 @@ fn base_type(context: &mut Context, sp!(bt_loc, bt_): H::BaseType) -> IR::Type {
         B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::U64))), _) => IRT::U64,
         B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::U128))), _) => IRT::U128,
         B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::U256))), _) => IRT::U256,
+        B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::I8))), _) => IRT::I8,
+        B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::I16))), _) => IRT::I16,
+        B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::I32))), _) => IRT::I32,
+        B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::I64))), _) => IRT::I64,
+        B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::I128))), _) => IRT::I128,
+        B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::I256))), _) => IRT::I256,
 
         B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::Bool))), _) => IRT::Bool,
         B::Apply(_, sp!(_, TN::Builtin(sp!(_, BT::Vector))), mut args) => {
@@ -862,6 +872,17 @@ fn base_type(context: &mut Context, sp!(bt_loc, bt_): H::BaseType) -> IR::Type {
             let tys = base_types(context, tys);
             IRT::Datatype(n, tys)
         }
+        B::Fun(params, returns, abs) => IRT::Function(
+            params
+                .into_iter()
+                .map(|st| single_type(context, st))
+                .collect(),
+            returns
+                .into_iter()
+                .map(|st| single_type(context, st))
+                .collect(),
+            abilities(&abs),
+        ),
         B::Param(TParam {
             user_specified_name,
             ..
@@ -882,6 +903,13 @@ fn single_type(context: &mut Context, sp!(st_loc, st_): H::SingleType) -> IR::Ty
     }
 }
 
+fn closure_type(context: &mut Context, sp!(_, t_): H::Type) -> IR::Type {
+    match t_ {
+        H::Type_::Single(st) => single_type(context, st),
+        _ => panic!("ICE closure must have a single function type"),
+    }
+}
+
 fn types(context: &mut Context, sp!(_, t_): H::Type) -> Vec<IR::Type> {
     use H::Type_ as T;
     match t_ {
@@ -1057,6 +1085,12 @@ fn exp(context: &mut Context, code: &mut IR::BytecodeBlock, e: H::Exp) {
                 V::U64(u) => B::LdU64(u),
                 V::U128(u) => B::LdU128(u),
                 V::U256(u) => B::LdU256(u),
+                V::I8(i) => B::LdI8(i),
+                V::I16(i) => B::LdI16(i),
+                V::I32(i) => B::LdI32(i),
+                V::I64(i) => B::LdI64(i),
+                V::I128(i) => B::LdI128(i),
+                V::I256(i) => B::LdI256(i),
                 V::Bool(b) => {
                     if b {
                         B::LdTrue
@@ -1118,6 +1152,23 @@ fn exp(context: &mut Context, code: &mut IR::BytecodeBlock, e: H::Exp) {
                 mcall.type_arguments,
             );
         }
+        E::PackClosure(mcall) => {
+            for arg in mcall.arguments {
+                exp(context, code, arg);
+            }
+            let (m, n) = context.qualified_function_name(&mcall.module, mcall.name);
+            let tys = base_types(context, mcall.type_arguments);
+            let closure_ty = closure_type(context, e.ty);
+            code.push(sp(loc, B::PackClosure(m, n, tys, closure_ty)))
+        }
+        E::CallClosure(closure, args) => {
+            for arg in args {
+                exp(context, code, arg);
+            }
+            let closure_ty = closure_type(context, closure.ty.clone());
+            exp(context, code, *closure);
+            code.push(sp(loc, B::CallClosure(closure_ty)))
+        }
 
         E::Freeze(er) => {
             exp(context, code, *er);
@@ -1219,6 +1270,12 @@ fn exp(context: &mut Context, code: &mut IR::BytecodeBlock, e: H::Exp) {
                 BT::U64 => B::CastU64,
                 BT::U128 => B::CastU128,
                 BT::U256 => B::CastU256,
+                BT::I8 => B::CastI8,
+                BT::I16 => B::CastI16,
+                BT::I32 => B::CastI32,
+                BT::I64 => B::CastI64,
+                BT::I128 => B::CastI128,
+                BT::I256 => B::CastI256,
                 BT::Address | BT::Signer | BT::Vector | BT::Bool => {
                     panic!("ICE type checking failed. unexpected cast")
                 }
@@ -1253,6 +1310,7 @@ fn unary_op(code: &mut IR::BytecodeBlock, sp!(loc, op_): UnaryOp) {
         loc,
         match op_ {
             O::Not => B::Not,
+            O::Neg => panic!("ICE negation should have been compiled to a subtraction"),
         },
     ));
 }
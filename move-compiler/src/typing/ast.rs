@@ -196,6 +196,9 @@ pub enum UnannotatedExp_ {
     Constant(ModuleIdent, ConstantName),
 
     ModuleCall(Box<ModuleCall>),
+    // A closure, lifted into the function being called, with the captured values as arguments
+    PackClosure(Box<ModuleCall>),
+    CallClosure(Box<Exp>, Box<Exp>),
     Builtin(Box<BuiltinFunction>, Box<Exp>),
     Vector(Loc, usize, Box<Type>, Box<Exp>),
 
@@ -669,6 +672,16 @@ impl AstDebug for UnannotatedExp_ {
             E::ModuleCall(mcall) => {
                 mcall.ast_debug(w);
             }
+            E::PackClosure(mcall) => {
+                w.write("closure ");
+                mcall.ast_debug(w);
+            }
+            E::CallClosure(closure, args) => {
+                closure.ast_debug(w);
+                w.write("(");
+                args.ast_debug(w);
+                w.write(")");
+            }
             E::Builtin(bf, rhs) => {
                 bf.ast_debug(w);
                 w.write("(");
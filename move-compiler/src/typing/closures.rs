@@ -0,0 +1,185 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    diag,
+    diagnostics::warning_filters::WarningFilters,
+    expansion::ast::{Mutability, Visibility},
+    naming::ast::{self as N, BlockLabel, Var},
+    parser::ast::{DocComment, FunctionName},
+    shared::unique_map::UniqueMap,
+    typing::{
+        ast as T,
+        core::{Closure, Context},
+        expand, match_analysis,
+        visitor::{LValueKind, TypingVisitorContext},
+    },
+};
+use move_ir_types::location::*;
+use std::collections::{BTreeSet, VecDeque};
+
+//**************************************************************************************************
+// Description
+//**************************************************************************************************
+// A lambda used as a value is typed along with the function that contains it. Its captures are
+// the locals of that function used in the lambda body. Once the function has been typed, each
+// lambda is lifted into a private function of the module, taking the captured values followed by
+// the lambda parameters. The closure is then packed with the captured values as its arguments.
+
+//**************************************************************************************************
+// Captures
+//**************************************************************************************************
+
+struct CaptureCollector<'ctx, 'outer, 'env> {
+    context: &'ctx mut Context<'outer, 'env>,
+    outer_locals: &'ctx BTreeSet<Var>,
+    labels: BTreeSet<BlockLabel>,
+    captures: BTreeSet<Var>,
+}
+
+/// Returns the locals of the enclosing function used in the body of a lambda. Captured locals are
+/// copied or moved into the closure, so assigning to them or borrowing them mutably is an error,
+/// as is jumping out of the lambda body.
+pub fn captures(
+    context: &mut Context,
+    outer_locals: &BTreeSet<Var>,
+    return_label: BlockLabel,
+    body: &T::Exp,
+) -> BTreeSet<Var> {
+    let mut collector = CaptureCollector {
+        context,
+        outer_locals,
+        labels: BTreeSet::from([return_label]),
+        captures: BTreeSet::new(),
+    };
+    collector.visit_exp(body);
+    collector.captures
+}
+
+impl CaptureCollector<'_, '_, '_> {
+    fn invalid_capture(&mut self, loc: Loc, msg: String) {
+        let note = "Captured locals are copied or moved into the closure when it is created";
+        let mut diag = diag!(TypeSafety::InvalidClosureCapture, (loc, msg));
+        diag.add_note(note);
+        self.context.add_diag(diag);
+    }
+}
+
+impl TypingVisitorContext for CaptureCollector<'_, '_, '_> {
+    const VISIT_LVALUES: bool = true;
+
+    fn visit_exp_custom(&mut self, exp: &T::Exp) -> bool {
+        use T::UnannotatedExp_ as E;
+        match &exp.exp.value {
+            E::Use(var)
+            | E::Copy { var, .. }
+            | E::Move { var, .. }
+            | E::BorrowLocal(false, var)
+                if self.outer_locals.contains(var) =>
+            {
+                self.captures.insert(*var);
+            }
+            E::BorrowLocal(true, var) if self.outer_locals.contains(var) => {
+                let msg = format!(
+                    "Invalid mutable borrow of '{}'. Captured locals cannot be modified",
+                    var.value.name
+                );
+                self.invalid_capture(exp.exp.loc, msg);
+            }
+            E::While(name, _, _) | E::Loop { name, .. } | E::NamedBlock(name, _) => {
+                self.labels.insert(*name);
+            }
+            E::Give(label, _) | E::Continue(label) if !self.labels.contains(label) => {
+                let msg = "Invalid control flow. \
+                    A closure cannot break, continue, or return to a label outside of its body";
+                let diag = diag!(TypeSafety::InvalidClosureCapture, (exp.exp.loc, msg));
+                self.context.add_diag(diag);
+            }
+            _ => (),
+        }
+        false
+    }
+
+    fn visit_lvalue_custom(&mut self, kind: &LValueKind, lvalue: &T::LValue) -> bool {
+        if let (LValueKind::Assign, T::LValue_::Var { var, .. }) = (kind, &lvalue.value) {
+            if self.outer_locals.contains(var) {
+                let msg = format!(
+                    "Invalid assignment to '{}'. Captured locals cannot be modified",
+                    var.value.name
+                );
+                self.invalid_capture(lvalue.loc, msg);
+            }
+        }
+        false
+    }
+
+    fn push_warning_filter_scope(&mut self, filter: WarningFilters) {
+        self.context.push_warning_filter_scope(filter);
+    }
+
+    fn pop_warning_filter_scope(&mut self) {
+        self.context.pop_warning_filter_scope();
+    }
+}
+
+//**************************************************************************************************
+// Lifting
+//**************************************************************************************************
+
+/// Lifts the closures of the current function, after its constraints have been solved, into
+/// private functions sharing its type parameters.
+pub fn lift(
+    context: &mut Context,
+    type_parameters: &[N::TParam],
+    warning_filter: WarningFilters,
+    index: usize,
+) -> Vec<(FunctionName, T::Function)> {
+    std::mem::take(&mut context.closures)
+        .into_iter()
+        .map(|closure| {
+            let Closure {
+                name,
+                loc,
+                captures,
+                parameters,
+                mut return_type,
+                mut body,
+            } = closure;
+            let parameters = captures
+                .into_iter()
+                .map(|(var, ty)| (Mutability::Either, var, ty))
+                .chain(parameters)
+                .map(|(mut_, var, mut ty)| {
+                    expand::type_(context, &mut ty);
+                    (mut_, var, ty)
+                })
+                .collect();
+            expand::type_(context, &mut return_type);
+            expand::exp(context, &mut body);
+            let body_loc = body.exp.loc;
+            let seq = VecDeque::from([sp(body_loc, T::SequenceItem_::Seq(body))]);
+            let mut body_ = T::FunctionBody_::Defined((N::UseFuns::new(0), seq));
+            match_analysis::function_body_(context, &mut body_);
+            let signature = N::FunctionSignature {
+                type_parameters: type_parameters.to_vec(),
+                parameters,
+                return_type,
+            };
+            let function = T::Function {
+                doc: DocComment::empty(),
+                warning_filter,
+                index,
+                attributes: UniqueMap::new(),
+                loc,
+                visibility: Visibility::Internal,
+                compiled_visibility: Visibility::Internal,
+                entry: None,
+                macro_: None,
+                signature,
+                body: sp(body_loc, body_),
+            };
+            (name, function)
+        })
+        .collect()
+}
@@ -15,8 +15,8 @@ use crate::{
     ice,
     naming::ast::{
         self as N, BlockLabel, BuiltinTypeName_, Color, DatatypeTypeParameter, EnumDefinition,
-        IndexSyntaxMethods, ResolvedUseFuns, StructDefinition, TParam, TParamID, TVar, Type,
-        TypeName, TypeName_, Type_, UseFun, UseFunKind, Var,
+        ForSyntaxMethods, IndexSyntaxMethods, ResolvedUseFuns, StructDefinition, TParam, TParamID,
+        TVar, Type, TypeName, TypeName_, Type_, UseFun, UseFunKind, Var,
     },
     parser::ast::{
         Ability_, ConstantName, DatatypeName, DocComment, Field, FunctionName, VariantName,
@@ -31,7 +31,7 @@ use crate::{
         unique_map::UniqueMap,
         *,
     },
-    typing::deprecation_warnings::Deprecations,
+    typing::{ast as T, deprecation_warnings::Deprecations},
 };
 use known_attributes::AttributePosition;
 use move_ir_types::location::*;
@@ -72,6 +72,7 @@ pub enum Constraint {
         constraints: AbilitySet,
     },
     NumericConstraint(Loc, &'static str, Type),
+    SignedConstraint(Loc, &'static str, Type),
     BitsConstraint(Loc, &'static str, Type),
     OrderedConstraint(Loc, &'static str, Type),
     BaseTypeConstraint(Loc, String, Type),
@@ -95,6 +96,18 @@ pub enum MacroExpansion {
     Argument { scope_color: Color },
 }
 
+/// A lambda used as a function value. Its body is typed along with the current function, after
+/// which it is lifted into a function of its own that takes the captured locals first.
+#[derive(Debug)]
+pub struct Closure {
+    pub name: FunctionName,
+    pub loc: Loc,
+    pub captures: Vec<(Var, Type)>,
+    pub parameters: Vec<(Mutability, Var, Type)>,
+    pub return_type: Type,
+    pub body: Box<T::Exp>,
+}
+
 pub(super) struct TypingDebugFlags {
     #[allow(dead_code)]
     pub(super) match_counterexample: bool,
@@ -165,6 +178,8 @@ pub struct Context<'env, 'outer> {
     /// This is to prevent accidentally thinking we are in a recursive call if a macro is used
     /// inside a lambda body
     pub lambda_expansion: Vec<Vec<MacroExpansion>>,
+    /// Lambdas used as values in the current function, lifted once the function has been typed
+    pub closures: Vec<Closure>,
     /// IDE Info for the current module member. We hold onto this during typing so we can elaborate
     /// it at the end.
     pub ide_info: IDEInfo,
@@ -541,6 +556,7 @@ impl<'env> ModuleContext<'env> {
             named_block_map: BTreeMap::new(),
             macro_expansion: vec![],
             lambda_expansion: vec![],
+            closures: vec![],
             ide_info: IDEInfo::new(),
         }
     }
@@ -1029,6 +1045,11 @@ impl<'env, 'outer> Context<'env, 'outer> {
             .push(Constraint::NumericConstraint(loc, op, t))
     }
 
+    pub fn add_signed_constraint(&mut self, loc: Loc, op: &'static str, t: Type) {
+        self.constraints
+            .push(Constraint::SignedConstraint(loc, op, t))
+    }
+
     pub fn add_bits_constraint(&mut self, loc: Loc, op: &'static str, t: Type) {
         self.constraints
             .push(Constraint::BitsConstraint(loc, op, t))
@@ -1056,6 +1077,10 @@ impl<'env, 'outer> Context<'env, 'outer> {
         self.locals.get(var).unwrap().clone()
     }
 
+    pub fn declared_locals(&self) -> BTreeSet<Var> {
+        self.locals.key_cloned_iter().map(|(var, _)| var).collect()
+    }
+
     pub fn is_current_function(&self, m: &ModuleIdent, f: &FunctionName) -> bool {
         self.is_current_module(m) && matches!(&self.current_function, Some(curf) if curf == f)
     }
@@ -1379,11 +1404,17 @@ fn error_format_impl_(b_: &Type_, subst: &Subst, nested: bool) -> String {
             };
             format!("{}{}", n, tys_str)
         }
-        Fun(args, result) => {
+        Fun(args, result, abilities) => {
+            let abilities_str = if !abilities.is_empty() {
+                format!(" has {}", format_delim(abilities, " + "))
+            } else {
+                "".to_string()
+            };
             format!(
-                "|{}| -> {}",
+                "|{}| -> {}{}",
                 format_comma(args.iter().map(|t| error_format_nested(t, subst))),
-                error_format_nested(result, subst)
+                error_format_nested(result, subst),
+                abilities_str
             )
         }
         Param(tp) => tp.user_specified_name.value.to_string(),
@@ -1456,7 +1487,7 @@ pub fn infer_abilities<const INFO_PASS: bool>(
             }))
             .unwrap()
         }
-        T::Fun(_, _) => AbilitySet::functions(loc),
+        T::Fun(_, _, abilities) => abilities.clone(),
     }
 }
 
@@ -1503,7 +1534,7 @@ fn debug_abilities_info(context: &mut Context, ty: &Type) -> (Option<Loc>, Abili
                 ),
             }
         }
-        T::Fun(_, _) => (None, AbilitySet::functions(loc), vec![]),
+        T::Fun(_, _, abilities) => (None, abilities.clone(), vec![]),
     }
 }
 
@@ -1657,6 +1688,18 @@ pub fn find_index_funs(context: &mut Context, type_name: &TypeName) -> Option<In
     Some(*index)
 }
 
+pub fn find_for_funs(context: &mut Context, type_name: &TypeName) -> Option<ForSyntaxMethods> {
+    let module_ident = match &type_name.value {
+        TypeName_::Multiple(_) => return None,
+        TypeName_::Builtin(builtin_name) => context.env().primitive_definer(builtin_name.value)?,
+        TypeName_::ModuleType(m, _) => m,
+    };
+    let module_defn = context.module_info(module_ident);
+    let entry = module_defn.syntax_methods.get(type_name)?;
+    let for_ = entry.for_.clone()?;
+    Some(*for_)
+}
+
 //**************************************************************************************************
 // Enums
 //**************************************************************************************************
@@ -2205,6 +2248,9 @@ pub fn solve_constraints(context: &mut Context) {
             Constraint::NumericConstraint(loc, op, t) => {
                 solve_builtin_type_constraint(context, BT::numeric(), loc, op, t)
             }
+            Constraint::SignedConstraint(loc, op, t) => {
+                solve_builtin_type_constraint(context, BT::signed(), loc, op, t)
+            }
             Constraint::BitsConstraint(loc, op, t) => {
                 solve_builtin_type_constraint(context, BT::bits(), loc, op, t)
             }
@@ -2386,7 +2432,7 @@ fn solve_base_type_constraint(context: &mut Context, loc: Loc, msg: String, ty:
                 (tyloc, tmsg)
             ))
         }
-        UnresolvedError | Anything | Param(_) | Apply(_, _, _) | Fun(_, _) => (),
+        UnresolvedError | Anything | Param(_) | Apply(_, _, _) | Fun(_, _, _) => (),
     }
 }
 
@@ -2407,7 +2453,7 @@ fn solve_single_type_constraint(context: &mut Context, loc: Loc, msg: String, ty
                 (tyloc, tmsg)
             ))
         }
-        UnresolvedError | Anything | Ref(_, _) | Param(_) | Apply(_, _, _) | Fun(_, _) => (),
+        UnresolvedError | Anything | Ref(_, _) | Param(_) | Apply(_, _, _) | Fun(_, _, _) => (),
     }
 }
 
@@ -2446,7 +2492,7 @@ pub fn unfold_type_recur(subst: &Subst, sp!(_loc, t_): &mut Type) {
         Type_::Unit | Type_::Param(_) | Type_::Anything | Type_::UnresolvedError => (),
         Type_::Ref(_, inner) => unfold_type_recur(subst, inner),
         Type_::Apply(_, _, args) => args.iter_mut().for_each(|ty| unfold_type_recur(subst, ty)),
-        Type_::Fun(args, ret) => {
+        Type_::Fun(args, ret, _) => {
             args.iter_mut().for_each(|ty| unfold_type_recur(subst, ty));
             unfold_type_recur(subst, ret);
         }
@@ -2505,10 +2551,10 @@ pub fn subst_tparams(subst: &TParamSubst, sp!(loc, t_): Type) -> Type {
                 .collect();
             sp(loc, Apply(k, n, ftys))
         }
-        Fun(args, result) => {
+        Fun(args, result, abilities) => {
             let ftys = args.into_iter().map(|t| subst_tparams(subst, t)).collect();
             let fres = Box::new(subst_tparams(subst, *result));
-            sp(loc, Fun(ftys, fres))
+            sp(loc, Fun(ftys, fres, abilities))
         }
     }
 }
@@ -2527,7 +2573,7 @@ pub fn all_tparams(sp!(_, t_): Type) -> BTreeSet<TParam> {
             }
             tparams
         }
-        Fun(args, result) => {
+        Fun(args, result, _) => {
             let mut tparams = all_tparams(*result);
             for arg in args {
                 tparams.append(&mut all_tparams(arg));
@@ -2546,10 +2592,10 @@ pub fn ready_tvars(subst: &Subst, sp!(loc, t_): Type) -> Type {
             let tys = tys.into_iter().map(|t| ready_tvars(subst, t)).collect();
             sp(loc, Apply(k, n, tys))
         }
-        Fun(args, result) => {
+        Fun(args, result, abilities) => {
             let args = args.into_iter().map(|t| ready_tvars(subst, t)).collect();
             let result = Box::new(ready_tvars(subst, *result));
-            sp(loc, Fun(args, result))
+            sp(loc, Fun(args, result, abilities))
         }
         Var(i) => {
             let last_var = forward_tvar(subst, i);
@@ -2626,11 +2672,12 @@ fn instantiate_impl(context: &mut Context, keep_tanything: bool, sp!(loc, t_): T
         Apply(abilities_opt, n, ty_args) => {
             instantiate_apply_impl(context, keep_tanything, loc, abilities_opt, n, ty_args)
         }
-        Fun(args, result) => Fun(
+        Fun(args, result, abilities) => Fun(
             args.into_iter()
                 .map(|t| instantiate_impl(context, keep_tanything, t))
                 .collect(),
             Box::new(instantiate_impl(context, keep_tanything, *result)),
+            abilities,
         ),
         x @ Param(_) => x,
         // instantiating a var really shouldn't happen... but it does because of macro expansion
@@ -2814,7 +2861,7 @@ pub fn give_tparams_all_abilities(sp!(_, ty_): &mut Type) {
                 give_tparams_all_abilities(ty_arg)
             }
         }
-        Type_::Fun(args, ret) => {
+        Type_::Fun(args, ret, _) => {
             for arg in args {
                 give_tparams_all_abilities(arg)
             }
@@ -2944,7 +2991,7 @@ fn join_impl(
             let (subst, tys) = join_impl_types(counter, subst, case, tys1, tys2)?;
             Ok((subst, sp(*loc, Apply(k2.clone(), *n2, tys))))
         }
-        (sp!(_, Fun(a1, _)), sp!(_, Fun(a2, _))) if a1.len() != a2.len() => {
+        (sp!(_, Fun(a1, _, _)), sp!(_, Fun(a2, _, _))) if a1.len() != a2.len() => {
             Err(TypingError::FunArityMismatch(
                 a1.len(),
                 Box::new(lhs.clone()),
@@ -2952,7 +2999,26 @@ fn join_impl(
                 Box::new(rhs.clone()),
             ))
         }
-        (sp!(_, Fun(a1, r1)), sp!(loc, Fun(a2, r2))) => {
+        (sp!(_, Fun(a1, r1, abs1)), sp!(loc, Fun(a2, r2, abs2))) => {
+            // Function values with more abilities can be used where fewer are expected, similar
+            // to the mutability of references above
+            let abilities = match case {
+                Join => abs1.intersect(abs2),
+                Invariant if abs1 == abs2 => abs2.clone(),
+                Subtype if abs2.is_subset(abs1) => abs2.clone(),
+                Invariant => {
+                    return Err(TypingError::InvariantError(
+                        Box::new(lhs.clone()),
+                        Box::new(rhs.clone()),
+                    ))
+                }
+                Subtype => {
+                    return Err(TypingError::SubtypeError(
+                        Box::new(lhs.clone()),
+                        Box::new(rhs.clone()),
+                    ))
+                }
+            };
             // TODO this is going to likely lead to some strange error locations/messages
             // since the RHS in subtyping is currently assumed to be an annotation
             let (subst, args) = match case {
@@ -2960,7 +3026,7 @@ fn join_impl(
                 Subtype => join_impl_types(counter, subst, case, a2, a1)?,
             };
             let (subst, result) = join_impl(counter, subst, case, r1, r2)?;
-            Ok((subst, sp(*loc, Fun(args, Box::new(result)))))
+            Ok((subst, sp(*loc, Fun(args, Box::new(result), abilities))))
         }
         (sp!(loc1, Var(id1)), sp!(loc2, Var(id2))) => {
             if *id1 == *id2 {
@@ -3110,7 +3176,7 @@ fn join_bind_tvar(subst: &mut Subst, loc: Loc, tvar: TVar, ty: Type) -> Result<b
                 .iter()
                 .rev()
                 .for_each(|inner| used_tvars(used, inner)),
-            T::Fun(inner_args, inner_ret) => {
+            T::Fun(inner_args, inner_ret, _) => {
                 inner_args
                     .iter()
                     .rev()
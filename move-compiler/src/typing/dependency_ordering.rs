@@ -320,7 +320,7 @@ fn type_(context: &mut Context, sp!(_, ty_): &N::Type) {
             types(context, tys);
         }
         T::Ref(_, t) => type_(context, t),
-        T::Fun(tys, t) => {
+        T::Fun(tys, t, _) => {
             types(context, tys);
             type_(context, t);
         }
@@ -395,7 +395,7 @@ fn exp(context: &mut Context, e: &T::Exp) {
     match &e.exp.value {
         E::Value(sp!(_, Value_::Address(a))) => context.add_address_usage(*a),
 
-        E::ModuleCall(c) => {
+        E::ModuleCall(c) | E::PackClosure(c) => {
             let T::ModuleCall {
                 module,
                 type_arguments,
@@ -406,6 +406,10 @@ fn exp(context: &mut Context, e: &T::Exp) {
             types(context, type_arguments);
             exp(context, arguments);
         }
+        E::CallClosure(e1, e2) => {
+            exp(context, e1);
+            exp(context, e2);
+        }
         E::Builtin(_, e) => exp(context, e),
         E::Vector(_, _, ty, e) => {
             type_(context, ty);
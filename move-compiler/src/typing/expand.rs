@@ -9,16 +9,17 @@ use crate::{
     expansion::ast::Value_,
     ice,
     naming::ast::{BuiltinTypeName_, FunctionSignature, Type, TypeName_, Type_},
-    parser::ast::Ability_,
+    parser::ast::{Ability_, UnaryOp_},
     shared::{ide::IDEAnnotation, string_utils::debug_print},
     typing::{
         ast::{self as T},
         core::{self, Context},
     },
 };
-use move_core_types::u256::U256;
+use move_core_types::{i256::I256, u256::U256};
 use move_ir_types::location::*;
 use move_proc_macros::growing_stack;
+use std::str::FromStr;
 
 //**************************************************************************************************
 // Functions
@@ -78,7 +79,7 @@ pub fn type_(context: &mut Context, ty: &mut Type) {
                     context.add_diag(diag!(TypeSafety::UninferredType, (ty.loc, msg)));
                     sp(loc, UnresolvedError)
                 }
-                sp!(loc, Fun(_, _)) if !context.in_macro_function => {
+                sp!(loc, Fun(_, _, _)) if !function_types_allowed(context) => {
                     // catch this here for better location infomration (the tvar instead of the fun)
                     unexpected_lambda_type(context, ty.loc);
                     sp(loc, UnresolvedError)
@@ -112,8 +113,8 @@ pub fn type_(context: &mut Context, ty: &mut Type) {
                 }
             }
         }
-        Fun(args, result) => {
-            if context.in_macro_function {
+        Fun(args, result, _) => {
+            if function_types_allowed(context) {
                 types(context, args);
                 type_(context, result);
             } else {
@@ -124,6 +125,14 @@ pub fn type_(context: &mut Context, ty: &mut Type) {
     }
 }
 
+// Function types are always allowed in macros, and otherwise only when closures are supported
+fn function_types_allowed(context: &Context) -> bool {
+    context.in_macro_function
+        || context
+            .env()
+            .supports_feature(context.current_package(), FeatureGate::Closures)
+}
+
 fn unexpected_lambda_type(context: &mut Context, loc: Loc) {
     if context.check_feature(context.current_package(), FeatureGate::MacroFuns, loc) {
         let msg = "Unexpected lambda type. \
@@ -207,6 +216,20 @@ pub fn exp(context: &mut Context, e: &mut T::Exp) {
                 e.exp.value = E::UnresolvedError
             }
         }
+        E::UnaryExp(sp!(_, UnaryOp_::Neg), base_exp) if is_signed(&e.ty) => {
+            if let E::Value(sp!(_, Value_::InferredNum(v))) = &base_exp.exp.value {
+                // The sign is folded into the literal, so that the minimum value of each type can
+                // be written without a suffix
+                let eloc = e.exp.loc;
+                let v = *v;
+                e.exp.value = match inferred_signed_value(context, eloc, v, true, &e.ty) {
+                    Some(value) => E::Value(sp(eloc, value)),
+                    None => E::UnresolvedError,
+                };
+            } else {
+                exp(context, base_exp)
+            }
+        }
 
         E::Unit { .. }
         | E::Value(_)
@@ -218,7 +241,11 @@ pub fn exp(context: &mut Context, e: &mut T::Exp) {
         | E::ErrorConstant { .. }
         | E::UnresolvedError => (),
 
-        E::ModuleCall(call) => module_call(context, call),
+        E::ModuleCall(call) | E::PackClosure(call) => module_call(context, call),
+        E::CallClosure(closure, args) => {
+            exp(context, closure);
+            exp(context, args);
+        }
         E::Builtin(b, args) => {
             builtin_function(context, b);
             exp(context, args);
@@ -311,6 +338,9 @@ fn inferred_numerical_value(
 ) -> Option<Value_> {
     use BuiltinTypeName_ as BT;
     let bt = match ty.value.builtin_name() {
+        Some(sp!(_, bt)) if bt.is_signed() => {
+            return inferred_signed_value(context, eloc, value, false, ty)
+        }
         Some(sp!(_, bt)) if bt.is_numeric() => bt,
         _ => panic!("ICE inferred num failed {:?}", &ty.value),
     };
@@ -327,7 +357,16 @@ fn inferred_numerical_value(
         BT::U64 => u64_max,
         BT::U128 => u128_max,
         BT::U256 => u256_max,
-        BT::Address | BT::Signer | BT::Vector | BT::Bool => unreachable!(),
+        BT::I8
+        | BT::I16
+        | BT::I32
+        | BT::I64
+        | BT::I128
+        | BT::I256
+        | BT::Address
+        | BT::Signer
+        | BT::Vector
+        | BT::Bool => unreachable!(),
     };
     if value > max {
         let msg = format!(
@@ -366,12 +405,93 @@ fn inferred_numerical_value(
             BT::U64 => Value_::U64(value.down_cast_lossy()),
             BT::U128 => Value_::U128(value.down_cast_lossy()),
             BT::U256 => Value_::U256(value),
-            BT::Address | BT::Signer | BT::Vector | BT::Bool => unreachable!(),
+            BT::I8
+            | BT::I16
+            | BT::I32
+            | BT::I64
+            | BT::I128
+            | BT::I256
+            | BT::Address
+            | BT::Signer
+            | BT::Vector
+            | BT::Bool => unreachable!(),
         };
         Some(value_)
     }
 }
 
+fn is_signed(ty: &Type) -> bool {
+    ty.value
+        .builtin_name()
+        .is_some_and(|sp!(_, bt)| bt.is_signed())
+}
+
+// Resolves a literal of a signed integer type, where `negative` is set for a negated literal
+fn inferred_signed_value(
+    context: &mut Context,
+    eloc: Loc,
+    value: U256,
+    negative: bool,
+    ty: &Type,
+) -> Option<Value_> {
+    let bt = match ty.value.builtin_name() {
+        Some(sp!(_, bt)) if bt.is_signed() => *bt,
+        _ => panic!("ICE inferred signed num failed {:?}", &ty.value),
+    };
+    let sign = if negative { "-" } else { "" };
+    let signed_value = I256::from_str(&format!("{sign}{value}")).ok();
+    if let Some(value_) = signed_value.and_then(|i| signed_value_of_type(bt, i)) {
+        return Some(value_);
+    }
+
+    let msg = format!(
+        "Expected a literal of type '{}', but the value is too {}.",
+        bt,
+        if negative { "small" } else { "large" },
+    );
+    let mut diag = diag!(
+        TypeSafety::InvalidNum,
+        (eloc, "Invalid numerical literal"),
+        (ty.loc, msg),
+    );
+    let fix_bt = signed_value.and_then(|i| {
+        BuiltinTypeName_::signed()
+            .iter()
+            .find(|fix_bt| signed_value_of_type(**fix_bt, i).is_some())
+    });
+    if let Some(fix_bt) = fix_bt {
+        let fix = format!(
+            "Annotating the literal might help inference: '{sign}{value}{type}'",
+            type=fix_bt,
+        );
+        diag.add_secondary_label((eloc, fix));
+    }
+    context.add_diag(diag);
+    None
+}
+
+fn signed_value_of_type(bt: BuiltinTypeName_, i: I256) -> Option<Value_> {
+    use BuiltinTypeName_ as BT;
+    Some(match bt {
+        BT::I8 => Value_::I8(i.try_into().ok()?),
+        BT::I16 => Value_::I16(i.try_into().ok()?),
+        BT::I32 => Value_::I32(i.try_into().ok()?),
+        BT::I64 => Value_::I64(i.try_into().ok()?),
+        BT::I128 => Value_::I128(i.try_into().ok()?),
+        BT::I256 => Value_::I256(i),
+        BT::U8
+        | BT::U16
+        | BT::U32
+        | BT::U64
+        | BT::U128
+        | BT::U256
+        | BT::Address
+        | BT::Signer
+        | BT::Vector
+        | BT::Bool => unreachable!(),
+    })
+}
+
 fn match_arm(context: &mut Context, sp!(_, arm_): &mut T::MatchArm) {
     pat(context, &mut arm_.pattern);
     for (_, ty) in arm_.binders.iter_mut() {
@@ -407,7 +527,7 @@ fn pat(context: &mut Context, p: &mut T::MatchPattern) {
                 Type_::Unit
                 | Type_::Param(_)
                 | Type_::Apply(_, _, _)
-                | Type_::Fun(_, _)
+                | Type_::Fun(_, _, _)
                 | Type_::Var(_)
                 | Type_::Anything
                 | Type_::UnresolvedError => &p.ty,
@@ -96,7 +96,7 @@ impl<'a> Context<'a> {
                 tys.iter()
                     .for_each(|t| Self::add_tparam_edges(acc, tparam, info.clone(), t))
             }
-            Fun(tys, t) => {
+            Fun(tys, t, _) => {
                 let info = EdgeInfo {
                     edge: Edge::Nested,
                     ..info
@@ -236,10 +236,14 @@ fn exp(context: &mut Context, e: &T::Exp) {
         | E::ErrorConstant { .. }
         | E::UnresolvedError => (),
 
-        E::ModuleCall(call) => {
+        E::ModuleCall(call) | E::PackClosure(call) => {
             context.add_usage(e.exp.loc, &call.module, &call.name, &call.type_arguments);
             exp(context, &call.arguments)
         }
+        E::CallClosure(closure, args) => {
+            exp(context, args);
+            exp(context, closure)
+        }
 
         E::IfElse(eb, et, ef_opt) => {
             exp(context, eb);
@@ -136,7 +136,7 @@ pub(crate) fn call(
             Arg::ByName((e, ty)) => (EvalStrategy::ByName(e.loc), ty.clone()),
         };
         let unfolded = core::unfold_type(&context.subst, arg_ty);
-        if let sp!(tfunloc, Type_::Fun(param_tys, result_ty)) = unfolded {
+        if let sp!(tfunloc, Type_::Fun(param_tys, result_ty, _)) = unfolded {
             let arg_exp = match arg {
                 Arg::ByValue(_) => {
                     assert!(
@@ -615,6 +615,12 @@ fn recolor_exp(ctx: &mut Recolor, sp!(_, e_): &mut N::Exp) {
             recolor_exp(ctx, econd);
             recolor_exp(ctx, ebody)
         }
+        N::Exp_::For(name, esubject, lambda) => {
+            ctx.add_block_label(*name);
+            recolor_block_label(ctx, name);
+            recolor_exp(ctx, esubject);
+            recolor_lambda(ctx, lambda)
+        }
         N::Exp_::Block(N::Block {
             name,
             from_macro_argument: _,
@@ -665,29 +671,32 @@ fn recolor_exp(ctx: &mut Recolor, sp!(_, e_): &mut N::Exp) {
             }
         }
 
-        N::Exp_::Lambda(N::Lambda {
-            parameters: sp!(_, parameters),
-            return_type: _,
-            return_label,
-            use_fun_color,
-            body,
-            extra_annotations: _,
-        }) => {
-            ctx.add_block_label(*return_label);
-            for (lvs, _) in &*parameters {
-                ctx.add_lvalues(lvs);
-            }
-            recolor_use_funs_(ctx, use_fun_color);
-            for (lvs, _) in parameters {
-                recolor_lvalues(ctx, lvs);
-            }
-            recolor_block_label(ctx, return_label);
-            recolor_exp(ctx, body)
-        }
+        N::Exp_::Lambda(lambda) => recolor_lambda(ctx, lambda),
         N::Exp_::ExpDotted(_dotted_usage, ed) => recolor_exp_dotted(ctx, ed),
     }
 }
 
+fn recolor_lambda(ctx: &mut Recolor, lambda: &mut N::Lambda) {
+    let N::Lambda {
+        parameters: sp!(_, parameters),
+        return_type: _,
+        return_label,
+        use_fun_color,
+        body,
+        extra_annotations: _,
+    } = lambda;
+    ctx.add_block_label(*return_label);
+    for (lvs, _) in &*parameters {
+        ctx.add_lvalues(lvs);
+    }
+    recolor_use_funs_(ctx, use_fun_color);
+    for (lvs, _) in parameters {
+        recolor_lvalues(ctx, lvs);
+    }
+    recolor_block_label(ctx, return_label);
+    recolor_exp(ctx, body)
+}
+
 fn recolor_exp_dotted(ctx: &mut Recolor, sp!(_, ed_): &mut N::ExpDotted) {
     match ed_ {
         N::ExpDotted_::Exp(e) => recolor_exp(ctx, e),
@@ -907,6 +916,10 @@ fn exp(context: &mut Context, sp!(eloc, e_): &mut N::Exp) {
             exp(context, econd);
             exp(context, ebody)
         }
+        N::Exp_::For(_name, esubject, lambda) => {
+            exp(context, esubject);
+            lambda_(context, lambda)
+        }
         N::Exp_::Block(N::Block {
             name: _,
             from_macro_argument: _,
@@ -960,19 +973,7 @@ fn exp(context: &mut Context, sp!(eloc, e_): &mut N::Exp) {
             exps(context, es)
         }
         N::Exp_::ExpList(es) => exps(context, es),
-        N::Exp_::Lambda(N::Lambda {
-            parameters: sp!(_, parameters),
-            body: e,
-            ..
-        }) => {
-            for (lvs, ty_opt) in parameters {
-                lvalues(context, lvs);
-                if let Some(ty) = ty_opt {
-                    type_(context, ty)
-                }
-            }
-            exp(context, e)
-        }
+        N::Exp_::Lambda(lambda) => lambda_(context, lambda),
         N::Exp_::ExpDotted(_usage, ed) => exp_dotted(context, ed),
 
         ///////
@@ -1203,6 +1204,21 @@ fn exp(context: &mut Context, sp!(eloc, e_): &mut N::Exp) {
     }
 }
 
+fn lambda_(context: &mut Context, lambda: &mut N::Lambda) {
+    let N::Lambda {
+        parameters: sp!(_, parameters),
+        body: e,
+        ..
+    } = lambda;
+    for (lvs, ty_opt) in parameters {
+        lvalues(context, lvs);
+        if let Some(ty) = ty_opt {
+            type_(context, ty)
+        }
+    }
+    exp(context, e)
+}
+
 fn builtin_function(context: &mut Context, sp!(_, bf_): &mut N::BuiltinFunction) {
     match bf_ {
         N::BuiltinFunction_::Freeze(ty_opt) => {
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod ast;
+mod closures;
 pub mod core;
 mod dependency_ordering;
 mod deprecation_warnings;
@@ -128,7 +128,7 @@ fn type_(context: &mut Context, sp!(loc, ty_): &N::Type) {
             }
             tys.iter().for_each(|t| type_(context, t))
         }
-        Fun(ts, t) => {
+        Fun(ts, t, _) => {
             ts.iter().for_each(|t| type_(context, t));
             type_(context, t)
         }
@@ -13,6 +13,7 @@ use crate::{
     expansion::ast::ModuleIdent,
     ice,
     naming::ast::{self as N, IndexSyntaxMethods, SyntaxMethod},
+    shared::known_attributes::SyntaxAttribute,
     typing::core::{self, Context},
 };
 use move_ir_types::location::*;
@@ -30,7 +31,11 @@ pub fn validate_syntax_methods(
     let methods = &mut module.syntax_methods;
     for (_, entry) in methods.iter_mut() {
         if let Some(index) = &mut entry.index {
-            let IndexSyntaxMethods { index, index_mut } = &mut **index;
+            let IndexSyntaxMethods {
+                index,
+                index_mut,
+                assign,
+            } = &mut **index;
             if let (Some(index_defn), Some(index_mut_defn)) = (index.as_ref(), index_mut.as_ref()) {
                 if !validate_index_syntax_methods(context, index_defn, index_mut_defn) {
                     // If we didn't validate they wre comptaible, we remove the mut one to avoid more
@@ -39,6 +44,18 @@ pub fn validate_syntax_methods(
                     *index_mut = None;
                 }
             }
+            if let Some(assign_defn) = assign.as_ref() {
+                // We check against the mutable index function when there is one, since its subject
+                // must match the assign one exactly.
+                if !validate_assign_syntax_method(
+                    context,
+                    index_mut.as_deref().or(index.as_deref()),
+                    assign_defn,
+                ) {
+                    assert!(context.env().has_errors());
+                    *assign = None;
+                }
+            }
         }
     }
 }
@@ -97,57 +114,13 @@ fn validate_index_syntax_methods(
         return false;
     }
 
-    let mut valid = true;
-
     // Check that the type parameters have the same abilities.
-    for (index_tparam, mut_tparam) in index_finfo
-        .signature
-        .type_parameters
-        .iter()
-        .zip(mut_finfo.signature.type_parameters.iter())
-    {
-        for ability in index_tparam.abilities.iter() {
-            if !mut_tparam.abilities.has_ability(&ability) {
-                let index_msg = format!(
-                    "This index function type parameter has the '{}' ability",
-                    ability
-                );
-                let index_mut_msg = "This mutable index function type parameter does not";
-                let mut diag = diag!(
-                    TypeSafety::IncompatibleSyntaxMethods,
-                    (index_tparam.user_specified_name.loc, index_msg),
-                    (ability.loc, "Ability defined here"),
-                    (mut_tparam.user_specified_name.loc, index_mut_msg),
-                );
-                diag.add_note(
-                    "Index operations on use the same abilities for their type parameters",
-                );
-                context.add_diag(diag);
-                valid = false;
-            }
-        }
-
-        for ability in mut_tparam.abilities.iter() {
-            if !index_tparam.abilities.has_ability(&ability) {
-                let index_mut_msg = format!(
-                    "This mutable index function type parameter has the '{}' ability",
-                    ability
-                );
-                let index_msg = "This index function type parameter does not";
-                let mut diag = diag!(
-                    TypeSafety::IncompatibleSyntaxMethods,
-                    (mut_tparam.user_specified_name.loc, index_mut_msg),
-                    (ability.loc, "Ability defined here"),
-                    (index_tparam.user_specified_name.loc, index_msg),
-                );
-                diag.add_note(
-                    "Index operations on use the same abilities for their type parameters",
-                );
-                context.add_diag(diag);
-                valid = false;
-            }
-        }
-    }
+    let mut valid = same_type_parameter_abilities(
+        context,
+        ("index", &index_finfo.signature.type_parameters),
+        ("mutable index", &mut_finfo.signature.type_parameters),
+        "Index operations on use the same abilities for their type parameters",
+    );
 
     // Now we simply want to make the types the same w/r/t type parameters. To do this, we
     // instantiate the type parameters of the index one and ground them to the type parameters of
@@ -324,6 +297,236 @@ fn validate_index_syntax_methods(
     valid
 }
 
+/// An assign syntax method `assign(&mut T, args..., value)` must agree with the index syntax
+/// methods for `T`: it takes the same type parameters and index arguments, and its value has the
+/// type the index functions return a reference to.
+fn validate_assign_syntax_method(
+    context: &mut Context,
+    index_opt: Option<&SyntaxMethod>,
+    assign: &SyntaxMethod,
+) -> bool {
+    let Some(index) = index_opt else {
+        let msg = format!(
+            "'{}' syntax methods must be defined alongside an '{}' syntax method",
+            SyntaxAttribute::ASSIGN,
+            SyntaxAttribute::INDEX,
+        );
+        let mut diag = diag!(Declarations::MissingSyntaxMethod, (assign.loc, msg));
+        diag.add_note(format!(
+            "The '{}' syntax method determines the type of the assigned value",
+            SyntaxAttribute::INDEX
+        ));
+        context.add_diag(diag);
+        return false;
+    };
+
+    let index_ann_loc = index.kind.loc;
+    let (index_module, index_fn) = &index.target_function;
+    let (assign_module, assign_fn) = &assign.target_function;
+
+    let index_finfo = context.function_info(index_module, index_fn).clone();
+    let assign_finfo = context.function_info(assign_module, assign_fn).clone();
+
+    if index_finfo.signature.type_parameters.len() != assign_finfo.signature.type_parameters.len() {
+        let index_msg = format!(
+            "This index function expects {} type arguments",
+            index_finfo.signature.type_parameters.len()
+        );
+        let assign_msg = format!(
+            "This assign function expects {} type arguments",
+            assign_finfo.signature.type_parameters.len()
+        );
+        let mut diag = diag!(
+            TypeSafety::IncompatibleSyntaxMethods,
+            (index.loc, index_msg),
+            (assign.loc, assign_msg),
+        );
+        diag.add_note(
+            "Index and assign operations on the same type must take the same number of type \
+             arguments",
+        );
+        context.add_diag(diag);
+        return false;
+    }
+
+    if index_finfo.signature.parameters.len() + 1 != assign_finfo.signature.parameters.len() {
+        let index_msg = format!(
+            "This index function expects {} parameters",
+            index_finfo.signature.parameters.len()
+        );
+        let assign_msg = format!(
+            "This assign function expects {} parameters, but it should expect {}",
+            assign_finfo.signature.parameters.len(),
+            index_finfo.signature.parameters.len() + 1
+        );
+        let mut diag = diag!(
+            TypeSafety::IncompatibleSyntaxMethods,
+            (index.loc, index_msg),
+            (assign.loc, assign_msg),
+        );
+        diag.add_note(
+            "Assign operations take the parameters of the index operation, followed by the \
+             assigned value",
+        );
+        context.add_diag(diag);
+        return false;
+    }
+
+    // Check that the type parameters have the same abilities.
+    let mut valid = same_type_parameter_abilities(
+        context,
+        ("index", &index_finfo.signature.type_parameters),
+        ("assign", &assign_finfo.signature.type_parameters),
+        "Index and assign operations must use the same abilities for their type parameters",
+    );
+
+    // As for the index functions, we instantiate the index function with the type parameters of
+    // the assign one, and then make sure their types agree.
+    let prev_constraints = std::mem::take(&mut context.constraints);
+    let prev_subst = std::mem::replace(&mut context.subst, core::Subst::empty());
+
+    let assign_tparam_types = assign_finfo
+        .signature
+        .type_parameters
+        .iter()
+        .map(|tp| sp(tp.user_specified_name.loc, N::Type_::Param(tp.clone())))
+        .collect::<Vec<_>>();
+
+    let index_ty = core::make_function_type_no_visibility_check(
+        context,
+        index_ann_loc,
+        index_module,
+        index_fn,
+        Some(assign_tparam_types),
+    );
+
+    let mut subst = std::mem::replace(&mut context.subst, core::Subst::empty());
+
+    // The value is assigned where the index functions return a reference to, so it must have the
+    // type under that reference. We already ensured that they return references in naming.
+    let value_type = match core::ready_tvars(&subst, index_ty.return_.clone()) {
+        sp!(_, N::Type_::Ref(_, inner)) => *inner,
+        _ => {
+            context.add_diag(ice!((
+                index_finfo.signature.return_type.loc,
+                "This index function got to type verification with an invalid type"
+            )));
+            let _ = std::mem::replace(&mut context.subst, prev_subst);
+            let _ = std::mem::replace(&mut context.constraints, prev_constraints);
+            return false;
+        }
+    };
+
+    let mut expected_types = index_ty
+        .params
+        .iter()
+        .map(|(_, ty)| ty.clone())
+        .chain(std::iter::once(value_type))
+        .collect::<Vec<_>>();
+    // The assign subject must be a `&mut`, while the index one may be either reference, so we
+    // expect the mutable form of the index subject.
+    if let sp!(loc, N::Type_::Ref(_, inner)) = core::ready_tvars(&subst, expected_types[0].clone())
+    {
+        expected_types[0] = sp(loc, N::Type_::Ref(true, inner));
+    }
+
+    for (ndx, expected_type) in expected_types.into_iter().enumerate() {
+        let (_, _, assign_type) = &assign_finfo.signature.parameters[ndx];
+        if let Ok((subst_, _)) = core::invariant(
+            &mut context.tvar_counter,
+            subst.clone(),
+            &expected_type,
+            assign_type,
+        ) {
+            subst = subst_;
+        } else {
+            let index_type =
+                if let Some((_, _, index_type)) = index_finfo.signature.parameters.get(ndx) {
+                    index_type
+                } else {
+                    &index_finfo.signature.return_type
+                };
+            let index_msg = if ndx < index_finfo.signature.parameters.len() {
+                format!(
+                    "This index function parameter has type {}",
+                    ty_str(index_type)
+                )
+            } else {
+                format!("This index function returns type {}", ty_str(index_type))
+            };
+            let assign_msg = format!(
+                "Expected this assign function parameter to have type {}",
+                ty_str(&core::ready_tvars(&subst, expected_type))
+            );
+            let assign_msg_2 = format!("It has type {}", ty_str(assign_type));
+            let mut diag = diag!(
+                TypeSafety::IncompatibleSyntaxMethods,
+                (index_type.loc, index_msg),
+                (assign_type.loc, assign_msg),
+                (assign_type.loc, assign_msg_2)
+            );
+            add_type_param_info(
+                &mut diag,
+                index_type,
+                &index_finfo.signature.type_parameters,
+                assign_type,
+                &assign_finfo.signature.type_parameters,
+            );
+            diag.add_note(
+                "Assign operations must take a mutable subject and the index parameters, \
+                 followed by a value of the type the index operation returns a reference to",
+            );
+            context.add_diag(diag);
+            valid = false;
+        }
+    }
+
+    let _ = std::mem::replace(&mut context.subst, prev_subst);
+    let _ = std::mem::replace(&mut context.constraints, prev_constraints);
+
+    valid
+}
+
+/// Checks that the type parameters of two syntax methods for the same type have the same
+/// abilities, reporting each ability that only one of a pair of type parameters has. `fn_kind` and
+/// `other_fn_kind` describe the two functions in the errors.
+fn same_type_parameter_abilities(
+    context: &mut Context,
+    (fn_kind, tparams): (&str, &[N::TParam]),
+    (other_fn_kind, other_tparams): (&str, &[N::TParam]),
+    note: &'static str,
+) -> bool {
+    let mut valid = true;
+    for (tparam, other_tparam) in tparams.iter().zip(other_tparams) {
+        let checks = [
+            (tparam, fn_kind, other_tparam, other_fn_kind),
+            (other_tparam, other_fn_kind, tparam, fn_kind),
+        ];
+        for (tparam, fn_kind, other_tparam, other_fn_kind) in checks {
+            for ability in tparam.abilities.iter() {
+                if !other_tparam.abilities.has_ability(&ability) {
+                    let msg = format!(
+                        "This {} function type parameter has the '{}' ability",
+                        fn_kind, ability
+                    );
+                    let other_msg =
+                        format!("This {} function type parameter does not", other_fn_kind);
+                    let mut diag = diag!(
+                        TypeSafety::IncompatibleSyntaxMethods,
+                        (tparam.user_specified_name.loc, msg),
+                        (ability.loc, "Ability defined here"),
+                        (other_tparam.user_specified_name.loc, &other_msg),
+                    );
+                    diag.add_note(note);
+                    context.add_diag(diag);
+                    valid = false;
+                }
+            }
+        }
+    }
+    valid
+}
+
 // Error printing helpers
 
 fn add_type_param_info(
@@ -23,6 +23,7 @@ use crate::{
     shared::{
         ide::{DotAutocompleteInfo, IDEAnnotation, MacroCallInfo},
         known_attributes::{ErrorAttribute, SyntaxAttribute, TestingAttribute},
+        matching::MatchContext,
         process_binops,
         program_info::{ConstantInfo, DatatypeKind, NamingProgramInfo, TypingProgramInfo},
         string_utils::{debug_print, make_ascii_titlecase},
@@ -32,9 +33,10 @@ use crate::{
     bos_mode,
     typing::{
         ast::{self as T},
+        closures,
         core::{
-            self, global_use_funs, public_testing_visibility, report_visibility_error, Context,
-            ModuleContext, PublicForTesting, ResolvedFunctionType, Subst,
+            self, global_use_funs, public_testing_visibility, report_visibility_error, Closure,
+            Context, ModuleContext, PublicForTesting, ResolvedFunctionType, Subst,
         },
         dependency_ordering, expand, infinite_instantiations, macro_expand, match_analysis,
         recursive_datatypes,
@@ -305,8 +307,21 @@ fn module<'env>(
                     constants.lock().unwrap().add(name, c).unwrap();
                 }
                 Member::Function((name, f)) => {
-                    let f = function(&mut context, name, f);
-                    functions.lock().unwrap().add(name, f).unwrap();
+                    let (f, lifted) = function(&mut context, name, f);
+                    let mut functions = functions.lock().unwrap();
+                    functions.add(name, f).unwrap();
+                    for (lifted_name, lifted_f) in lifted {
+                        if let Err((_, prev_loc)) = functions.add(lifted_name, lifted_f) {
+                            let msg = format!(
+                                "Function '{lifted_name}' conflicts with a closure of '{name}'"
+                            );
+                            context.add_diag(diag!(
+                                Declarations::DuplicateItem,
+                                (lifted_name.loc(), msg),
+                                (prev_loc, "Previously declared here")
+                            ));
+                        }
+                    }
                 }
             };
             let (cur_new_friends, cur_used_members, cur_used_methods) = context.finish();
@@ -366,7 +381,12 @@ fn finalize_ide_info(context: &mut Context) {
 // Functions
 //**************************************************************************************************
 
-fn function(context: &mut Context, name: FunctionName, f: N::Function) -> T::Function {
+/// Returns the typed function along with the closures lifted out of its body
+fn function(
+    context: &mut Context,
+    name: FunctionName,
+    f: N::Function,
+) -> (T::Function, Vec<(FunctionName, T::Function)>) {
     let N::Function {
         doc,
         warning_filter,
@@ -396,11 +416,12 @@ fn function(context: &mut Context, name: FunctionName, f: N::Function) -> T::Fun
     } else {
         function_body(context, n_body)
     };
+    let lifted = closures::lift(context, &signature.type_parameters, warning_filter, index);
     finalize_ide_info(context);
     context.current_function = None;
     context.in_macro_function = false;
     context.pop_warning_filter_scope();
-    T::Function {
+    let function = T::Function {
         doc,
         warning_filter,
         index,
@@ -412,7 +433,8 @@ fn function(context: &mut Context, name: FunctionName, f: N::Function) -> T::Fun
         macro_,
         signature,
         body,
-    }
+    };
+    (function, lifted)
 }
 
 fn function_signature(context: &mut Context, macro_: Option<Loc>, sig: &N::FunctionSignature) {
@@ -534,6 +556,7 @@ mod check_valid_constant {
     use crate::{
         diag,
         diagnostics::codes::DiagnosticCode,
+        editions::FeatureGate,
         ice,
         naming::ast::{Type, Type_},
         shared::*,
@@ -564,6 +587,19 @@ mod check_valid_constant {
             Type_::bool(loc),
             Type_::address(loc),
         ];
+        if context
+            .env()
+            .supports_feature(context.current_package(), FeatureGate::SignedIntegers)
+        {
+            acceptable_types.extend([
+                Type_::i8(loc),
+                Type_::i16(loc),
+                Type_::i32(loc),
+                Type_::i64(loc),
+                Type_::i128(loc),
+                Type_::i256(loc),
+            ]);
+        }
         let ty_is_an_acceptable_type = acceptable_types.iter().any(|acceptable_type| {
             let old_subst = context.subst.clone();
             let result = subtype_no_report(context, ty.clone(), acceptable_type.clone());
@@ -656,6 +692,15 @@ mod check_valid_constant {
                 exp(context, &call.arguments);
                 "Module calls are"
             }
+            E::PackClosure(call) => {
+                exp(context, &call.arguments);
+                "Function values are"
+            }
+            E::CallClosure(closure, args) => {
+                exp(context, closure);
+                exp(context, args);
+                "Function value calls are"
+            }
             E::Builtin(b, args) => {
                 exp(context, args);
                 s = format!("'{}' is", b);
@@ -1090,7 +1135,7 @@ fn visit_type_params(
                 }
             }
         },
-        Type_::Fun(args, result) => {
+        Type_::Fun(args, result, _) => {
             for ty in args {
                 visit_type_params(context, ty, ParamPos::NonPhantom(NonPhantomPos::TypeArg), f)
             }
@@ -1157,7 +1202,7 @@ fn has_unresolved_error_type(ty: &Type) -> bool {
         Type_::UnresolvedError => true,
         Type_::Ref(_, ty) => has_unresolved_error_type(ty),
         Type_::Apply(_, _, ty_args) => ty_args.iter().any(has_unresolved_error_type),
-        Type_::Fun(args, result) => {
+        Type_::Fun(args, result, _) => {
             args.iter().any(has_unresolved_error_type) || has_unresolved_error_type(result)
         }
         Type_::Param(_) | Type_::Var(_) | Type_::Anything | Type_::Unit => false,
@@ -1669,6 +1714,10 @@ fn exp(context: &mut Context, ne: Box<N::Exp>) -> Box<T::Exp> {
                 nargs_,
             )
         }
+        NE::VarCall(var, sp!(argloc, nargs_)) if !var.value.is_syntax_identifier() => {
+            let args = exp_vec(context, nargs_);
+            closure_call(context, eloc, var, argloc, args)
+        }
         NE::VarCall(_, sp!(_, nargs_)) => {
             exp_vec(context, nargs_);
             assert!(
@@ -1757,6 +1806,7 @@ fn exp(context: &mut Context, ne: Box<N::Exp>) -> Box<T::Exp> {
             };
             (sp(eloc, ty.value), eloop)
         }
+        NE::For(name, nsubject, nlambda) => for_loop(context, eloc, name, nsubject, nlambda),
         NE::Block(N::Block {
             name,
             from_macro_argument,
@@ -1784,6 +1834,14 @@ fn exp(context: &mut Context, ne: Box<N::Exp>) -> Box<T::Exp> {
             context.maybe_exit_macro_argument(eloc, from_macro_argument);
             res
         }
+        NE::Lambda(lambda)
+            if context.current_function.is_some()
+                && context
+                    .env()
+                    .supports_feature(context.current_package(), FeatureGate::Closures) =>
+        {
+            closure(context, eloc, lambda)
+        }
         NE::Lambda(_) => {
             if context.check_feature(context.current_package(), FeatureGate::Lambda, eloc) {
                 let msg = "Lambdas can only be used directly as arguments to 'macro' functions";
@@ -1808,15 +1866,7 @@ fn exp(context: &mut Context, ne: Box<N::Exp>) -> Box<T::Exp> {
 
         NE::FieldMutate(ndotted, nr) => {
             let er = exp(context, nr);
-            let eborrow = exp_dotted_expression(
-                context,
-                DottedUsage::Borrow(true),
-                Some("mutation"),
-                ndotted.loc,
-                ndotted,
-            );
-            check_mutation(context, eborrow.exp.loc, eborrow.ty.clone(), &er.ty);
-            (sp(eloc, Type_::Unit), TE::Mutate(eborrow, er))
+            exp_dotted_mutate(context, eloc, ndotted, er)
         }
 
         NE::Return(nret) => {
@@ -1878,6 +1928,10 @@ fn exp(context: &mut Context, ne: Box<N::Exp>) -> Box<T::Exp> {
                     subtype(context, rloc, msg, er.ty.clone(), Type_::bool(rloc));
                     Type_::bool(eloc)
                 }
+                Neg => {
+                    context.add_signed_constraint(er.exp.loc, uop.value.symbol(), er.ty.clone());
+                    er.ty.clone()
+                }
             };
             (ty, TE::UnaryExp(uop, er))
         }
@@ -3062,6 +3116,116 @@ fn add_variant_field_types<T>(
     })
 }
 
+//--------------------------------------------------------------------------------------------------
+// For Loops
+//--------------------------------------------------------------------------------------------------
+
+// A 'for' loop is expanded as a call to the subject type's 'for' syntax method, with the loop
+// body as the lambda argument, inside of a named block that 'break' gives to.
+fn for_loop(
+    context: &mut Context,
+    eloc: Loc,
+    name: BlockLabel,
+    nsubject: Box<N::Exp>,
+    nlambda: N::Lambda,
+) -> (Type, T::UnannotatedExp_) {
+    use macro_expand::EvalStrategy;
+    use T::{SequenceItem_ as TS, UnannotatedExp_ as TE};
+
+    let for_loop_type = context.named_block_type(name, eloc);
+    // for loop breaks must break with unit
+    subtype(
+        context,
+        eloc,
+        || "Cannot use 'break' with a non-'()' value in 'for'",
+        for_loop_type,
+        sp(eloc, Type_::Unit),
+    );
+
+    let esubject = exp(context, nsubject);
+    let subject_loc = esubject.exp.loc;
+    let subject_ty = core::ready_tvars(&context.subst, esubject.ty.clone());
+    let Some((m, f)) = find_for_fun(context, subject_loc, &subject_ty) else {
+        ice_assert!(
+            context.reporter,
+            context.env().has_errors(),
+            subject_loc,
+            "No 'for' syntax method found but no error reported"
+        );
+        return (context.error_type(eloc), TE::UnresolvedError);
+    };
+    let fty = core::make_function_type(context, eloc, &m, &f, None, None);
+    let body_loc = nlambda.body.loc;
+    let nbody = sp(body_loc, N::Exp_::Lambda(nlambda));
+    let args = vec![
+        EvalStrategy::ByValue(*esubject),
+        EvalStrategy::ByName(convert_macro_arg_to_block(context, nbody)),
+    ];
+    let (type_arguments, args, return_ty) =
+        macro_call_impl(context, eloc, m, f, eloc, fty, eloc, args);
+    let (ty, eloop_) = expand_macro(context, eloc, m, f, None, type_arguments, args, return_ty);
+    let eloop = Box::new(T::exp(ty, sp(eloc, eloop_)));
+    let use_funs = N::UseFuns::new(context.current_call_color());
+    let seq = (use_funs, VecDeque::from([sp(eloc, TS::Seq(eloop))]));
+    (sp(eloc, Type_::Unit), TE::NamedBlock(name, seq))
+}
+
+// Assumes tvars have already been readied
+fn find_for_fun(context: &mut Context, loc: Loc, ty: &Type) -> Option<(ModuleIdent, FunctionName)> {
+    use Type_ as T;
+    const UNINFERRED_MSG: &str =
+        "Could not infer the type of the 'for' loop subject. Try annotating here";
+    let ty_str = core::error_format(ty, &context.subst);
+    let msg = || {
+        format!(
+            "No valid '{}({})' method found for {}",
+            SyntaxAttribute::SYNTAX,
+            SyntaxAttribute::FOR,
+            ty_str
+        )
+    };
+
+    let (mut_opt, base_ty) = match ty {
+        sp!(_, T::Ref(mut_, inner)) => (Some(*mut_), &**inner),
+        _ => (None, ty),
+    };
+    match base_ty {
+        sp!(_, T::UnresolvedError) => None,
+        sp!(tloc, T::Anything | T::Var(_)) => {
+            context.add_diag(diag!(
+                TypeSafety::UninferredType,
+                (loc, msg()),
+                (*tloc, UNINFERRED_MSG),
+            ));
+            None
+        }
+        sp!(_, T::Apply(_, type_name, _)) => {
+            let methods_opt = core::find_for_funs(context, type_name);
+            let method_opt = methods_opt.and_then(|methods| match mut_opt {
+                Some(false) => methods.for_imm,
+                Some(true) => methods.for_mut,
+                None => methods.for_val,
+            });
+            if method_opt.is_none() {
+                context.add_diag(diag!(Declarations::MissingSyntaxMethod, (loc, msg())));
+            }
+            method_opt.map(|method| method.target_function)
+        }
+        sp!(_, T::Unit | T::Ref(_, _) | T::Param(_) | T::Fun(_, _, _)) => {
+            let smsg = format!(
+                "Expected a struct or builtin type but got: {}",
+                core::error_format(ty, &context.subst)
+            );
+            context.add_diag(diag!(
+                TypeSafety::ExpectedSpecificType,
+                (loc, msg()),
+                (ty.loc, smsg),
+            ));
+            None
+        }
+    }
+}
+
 //--------------------------------------------------------------------------------------------------
 // Index Type Resolution
 //--------------------------------------------------------------------------------------------------
@@ -3106,7 +3270,7 @@ fn find_index_funs(context: &mut Context, loc: Loc, ty: &Type) -> Option<IndexSy
             }
             index_opt
         }
-        sp!(_, T::Unit | T::Ref(_, _) | T::Param(_) | T::Fun(_, _)) => {
+        sp!(_, T::Unit | T::Ref(_, _) | T::Param(_) | T::Fun(_, _, _)) => {
             let smsg = format!(
                 "Expected a struct or builtin type but got: {}",
                 core::error_format(ty, &context.subst)
@@ -3361,18 +3525,90 @@ fn exp_dotted_usage(
     resolve_exp_dotted(context, usage, exp_loc, edotted, None)
 }
 
-fn exp_dotted_expression(
+// Mutates a dotted expression, as in `E = er`. If `E` is an index access `E'[args]` and the type
+// of `E'` has an 'assign' syntax method, this calls it as `assign(&mut E', args..., er)`.
+// Otherwise, it borrows `E` mutably and mutates through that reference.
+fn exp_dotted_mutate(
     context: &mut Context,
-    usage: DottedUsage,
-    constraint_verb: Option<&str>,
-    error_loc: Loc,
+    eloc: Loc,
     ndotted: N::ExpDotted,
-) -> Box<T::Exp> {
-    let edotted = process_exp_dotted(context, constraint_verb, ndotted);
-    if matches!(usage, DottedUsage::Borrow(_)) && edotted.accessors.is_empty() {
-        context.add_base_type_constraint(error_loc, "Invalid borrow", edotted.base.ty.clone());
+    er: Box<T::Exp>,
+) -> (Type, T::UnannotatedExp_) {
+    use T::UnannotatedExp_ as TE;
+    let error_loc = ndotted.loc;
+    let mut edotted = process_exp_dotted(context, Some("mutation"), ndotted);
+    match edotted.accessors.pop() {
+        Some(ExpDottedAccess::Index {
+            syntax_methods:
+                Some(IndexSyntaxMethods {
+                    assign: Some(assign),
+                    ..
+                }),
+            args: sp!(argloc, mut args),
+            ..
+        }) => {
+            use T::SequenceItem_ as TS;
+            // The value is bound first so that, as when mutating through a reference, it is
+            // evaluated before the subject, allowing assignments like `m[k] = m[k] + 1`.
+            let vloc = er.exp.loc;
+            let value_ty = er.ty.clone();
+            let var = sp(
+                vloc,
+                N::Var_ {
+                    name: Symbol::from("%value"),
+                    id: 0,
+                    color: context.next_variable_color(),
+                },
+            );
+            let lvalue = N::LValue_::Var {
+                mut_: Some(Mutability::Imm),
+                var,
+                unused_binding: false,
+            };
+            let b = bind_list(
+                context,
+                sp(vloc, vec![sp(vloc, lvalue)]),
+                Some(value_ty.clone()),
+            );
+            let lvalue_ty = lvalues_expected_types(context, &b);
+            let bind = sp(vloc, TS::Bind(b, lvalue_ty, er));
+            let evalue = T::exp(
+                value_ty,
+                sp(
+                    vloc,
+                    TE::Move {
+                        from_user: false,
+                        var,
+                    },
+                ),
+            );
+
+            let (m, f) = assign.target_function;
+            let esubject =
+                resolve_exp_dotted(context, DottedUsage::Borrow(true), error_loc, edotted, None);
+            args.insert(0, *esubject);
+            args.push(evalue);
+            let (ty, e_) = module_call(context, error_loc, m, f, None, argloc, args);
+            let ecall = T::exp(ty.clone(), sp(eloc, e_));
+            let seq = VecDeque::from([bind, sp(eloc, TS::Seq(Box::new(ecall)))]);
+            let use_funs = N::UseFuns::new(context.current_call_color());
+            (ty, TE::Block((use_funs, seq)))
+        }
+        accessor_opt => {
+            edotted.accessors.extend(accessor_opt);
+            if edotted.accessors.is_empty() {
+                context.add_base_type_constraint(
+                    error_loc,
+                    "Invalid borrow",
+                    edotted.base.ty.clone(),
+                );
+            }
+            let eborrow =
+                resolve_exp_dotted(context, DottedUsage::Borrow(true), error_loc, edotted, None);
+            check_mutation(context, eborrow.exp.loc, eborrow.ty.clone(), &er.ty);
+            (sp(eloc, Type_::Unit), TE::Mutate(eborrow, er))
+        }
     }
-    resolve_exp_dotted(context, usage, error_loc, edotted, None)
 }
 
 // This comment servees to document the function below. Depending on the shape of the dotted
@@ -3950,7 +4186,7 @@ fn type_to_type_name_(
                 Ty::Anything => {
                     format!("Unable to infer type for {error_msg}. Try annotating this type")
                 }
-                Ty::Unit | Ty::Apply(_, sp!(_, TN::Multiple(_)), _) | Ty::Fun(_, _) => {
+                Ty::Unit | Ty::Apply(_, sp!(_, TN::Multiple(_)), _) | Ty::Fun(_, _, _) => {
                     let titlecase_msg = make_ascii_titlecase(&error_msg);
                     let tsubst = core::error_format_(t, &context.subst);
                     format!(
@@ -4059,6 +4295,179 @@ fn module_call_impl(
     (call, return_)
 }
 
+fn closure(context: &mut Context, loc: Loc, lambda: N::Lambda) -> (Type, T::UnannotatedExp_) {
+    use T::UnannotatedExp_ as TE;
+    let N::Lambda {
+        parameters: sp!(_, lambda_params),
+        return_type: return_annotation,
+        return_label,
+        use_fun_color,
+        body,
+        extra_annotations: _,
+    } = lambda;
+    let outer_locals = context.declared_locals();
+    // Parameters that are not a single variable are bound at the start of the body
+    let mut parameters = vec![];
+    let mut seq = VecDeque::new();
+    for (sp!(lvs_loc, lvs_), ty_opt) in lambda_params {
+        let ty = match ty_opt {
+            Some(ty) => core::instantiate(context, ty),
+            None => core::make_tvar(context, lvs_loc),
+        };
+        let msg = "Invalid closure parameter";
+        context.add_single_type_constraint(lvs_loc, msg, ty.clone());
+        let (mut_, var) = match &lvs_[..] {
+            [sp!(_, N::LValue_::Var { mut_, var, .. })] => {
+                (mut_.unwrap_or(Mutability::Either), *var)
+            }
+            _ => {
+                let var = context.new_match_var("closure_param".to_owned(), lvs_loc);
+                let arg = Box::new(sp(lvs_loc, N::Exp_::Var(var)));
+                seq.push_back(sp(lvs_loc, N::SequenceItem_::Bind(sp(lvs_loc, lvs_), arg)));
+                (Mutability::Either, var)
+            }
+        };
+        context.declare_local(mut_, var, ty.clone());
+        parameters.push((mut_, var, ty));
+    }
+    let body_loc = body.loc;
+    seq.push_back(sp(body_loc, N::SequenceItem_::Seq(body)));
+    let block = N::Exp_::Block(N::Block {
+        name: Some(return_label),
+        from_macro_argument: None,
+        seq: (N::UseFuns::new(use_fun_color), seq),
+    });
+    let body = exp(context, Box::new(sp(body_loc, block)));
+    let return_type = match return_annotation {
+        Some(annot) => {
+            let annot = core::instantiate(context, annot);
+            let msg = || "Invalid closure body";
+            subtype(context, body_loc, msg, body.ty.clone(), annot.clone());
+            annot
+        }
+        None => body.ty.clone(),
+    };
+
+    // The closure has the abilities shared by all of its captured values
+    let captures = closures::captures(context, &outer_locals, return_label, &body)
+        .into_iter()
+        .map(|var| (var, context.get_local_type(&var)))
+        .collect::<Vec<_>>();
+    let mut abilities = AbilitySet::from_abilities_(loc, [Ability_::Copy, Ability_::Drop]).unwrap();
+    for (_, ty) in &captures {
+        let ty_abilities = core::infer_abilities(context.info(), &context.subst, ty.clone());
+        abilities = abilities.intersect(&ty_abilities);
+    }
+    for (var, ty) in &captures {
+        let name = var.value.name;
+        let msg = format!("Invalid capture of '{name}'. References cannot be captured");
+        context.add_base_type_constraint(var.loc, msg, ty.clone());
+        let msg = format!("Invalid capture of '{name}'");
+        context.add_ability_set_constraint(var.loc, Some(msg), ty.clone(), abilities.clone());
+    }
+
+    let mident = *context.current_module().unwrap();
+    let fname = context.current_function.unwrap();
+    let name = FunctionName(sp(
+        loc,
+        format!("{fname}__lambda__{}", context.closures.len()).into(),
+    ));
+    let type_arguments = context
+        .function_info(&mident, &fname)
+        .signature
+        .type_parameters
+        .iter()
+        .map(|tp| sp(loc, Type_::Param(tp.clone())))
+        .collect();
+    let args = captures
+        .iter()
+        .map(|(var, ty)| T::exp(ty.clone(), sp(var.loc, TE::Use(*var))))
+        .collect();
+    let (arguments, parameter_types) = call_args(
+        context,
+        loc,
+        || "Invalid closure",
+        captures.len(),
+        loc,
+        args,
+    );
+    context
+        .used_module_members
+        .entry(mident.value)
+        .or_default()
+        .insert(name.value());
+    let param_tys = parameters.iter().map(|(_, _, ty)| ty.clone()).collect();
+    let ty = sp(
+        loc,
+        Type_::Fun(param_tys, Box::new(return_type.clone()), abilities),
+    );
+    context.closures.push(Closure {
+        name,
+        loc,
+        captures,
+        parameters,
+        return_type,
+        body,
+    });
+    let call = T::ModuleCall {
+        module: mident,
+        name,
+        type_arguments,
+        arguments,
+        parameter_types,
+        method_name: None,
+    };
+    (ty, TE::PackClosure(Box::new(call)))
+}
+
+fn closure_call(
+    context: &mut Context,
+    loc: Loc,
+    var: N::Var,
+    argloc: Loc,
+    args: Vec<T::Exp>,
+) -> (Type, T::UnannotatedExp_) {
+    use T::UnannotatedExp_ as TE;
+    let name = var.value.name;
+    let var_ty = context.get_local_type(&var);
+    let (params, return_) = match core::unfold_type(&context.subst, var_ty.clone()).value {
+        Type_::Fun(params, return_, _) => (params, *return_),
+        Type_::UnresolvedError => return (context.error_type(loc), TE::UnresolvedError),
+        Type_::Anything => {
+            let msg = format!(
+                "Could not infer the type of '{name}'. \
+                Try annotating it with a function type, e.g. '|u64| -> u64'"
+            );
+            context.add_diag(diag!(TypeSafety::UninferredType, (var.loc, msg)));
+            return (context.error_type(loc), TE::UnresolvedError);
+        }
+        _ => {
+            let msg = format!("Invalid call of '{name}'. Expected a function value");
+            let ty_msg = format!("Given: {}", core::error_format(&var_ty, &context.subst));
+            context.add_diag(diag!(
+                TypeSafety::InvalidCallTarget,
+                (loc, msg),
+                (var_ty.loc, ty_msg)
+            ));
+            return (context.error_type(loc), TE::UnresolvedError);
+        }
+    };
+    let (arguments, arg_tys) = call_args(
+        context,
+        loc,
+        || format!("Invalid call of '{name}'"),
+        params.len(),
+        argloc,
+        args,
+    );
+    for (idx, (arg_ty, param_ty)) in arg_tys.into_iter().zip(params).enumerate() {
+        let msg = || format!("Invalid call of '{name}'. Invalid argument {}", idx + 1);
+        subtype(context, loc, msg, arg_ty, param_ty);
+    }
+    let closure = T::exp(var_ty, sp(var.loc, TE::Use(var)));
+    (return_, TE::CallClosure(Box::new(closure), arguments))
+}
+
 /// If the constant that we are referencing has an `error` attribute, we need to change the type of
 /// the constant to a u64 since this will be compiled into a u64 error code.
 fn annotated_error_const(context: &mut Context, e: &mut T::Exp, abort_or_assert_str: &str) {
@@ -4181,6 +4590,12 @@ fn error_constant_attr_params(
                     | Value_::U64(_)
                     | Value_::U128(_)
                     | Value_::U256(_)
+                    | Value_::I8(_)
+                    | Value_::I16(_)
+                    | Value_::I32(_)
+                    | Value_::I64(_)
+                    | Value_::I128(_)
+                    | Value_::I256(_)
                     | Value_::Bool(_)
                     | Value_::Bytearray(_) => {
                         context.add_diag(diag!(
@@ -4645,7 +5060,12 @@ fn expected_by_name_arg_type(
     } else {
         sp(lambda.body.loc, Type_::Anything)
     };
-    let tfun = sp(eloc, Type_::Fun(param_tys, Box::new(ret_ty)));
+    // Lambda arguments are substituted into the macro body, so they are not restricted by the
+    // abilities of the function type
+    let tfun = sp(
+        eloc,
+        Type_::Fun(param_tys, Box::new(ret_ty), AbilitySet::all(eloc)),
+    );
     let msg = || {
         format!(
             "Invalid call of '{}::{}'. Invalid argument for parameter '{}'",
@@ -4773,7 +5193,14 @@ fn convert_macro_arg_to_block(context: &mut Context, sp!(loc, ne_): N::Exp) -> N
                     .map(|(sp!(loc, _), _)| core::make_tvar(context, *loc))
                     .collect::<Vec<_>>();
                 let res_ty = core::make_tvar(context, lambda.body.loc);
-                let tfun = sp(loc, Type_::Fun(param_tys.clone(), Box::new(res_ty.clone())));
+                let tfun = sp(
+                    loc,
+                    Type_::Fun(
+                        param_tys.clone(),
+                        Box::new(res_ty.clone()),
+                        AbilitySet::all(loc),
+                    ),
+                );
                 for annot in extra_annotations {
                     let annot_loc = annot.loc;
                     subtype(
@@ -270,7 +270,7 @@ pub trait TypingVisitorContext {
             N::Type_::Ref(_, inner) => self.visit_type(exp_loc, inner),
             N::Type_::Param(_) => (),
             N::Type_::Apply(_, _, args) => args.iter().for_each(|ty| self.visit_type(exp_loc, ty)),
-            N::Type_::Fun(args, ret) => {
+            N::Type_::Fun(args, ret, _) => {
                 args.iter().for_each(|ty| self.visit_type(exp_loc, ty));
                 self.visit_type(exp_loc, ret);
             }
@@ -411,7 +411,7 @@ pub trait TypingVisitorContext {
         let sp!(exp_loc, uexp) = &exp.exp;
         let exp_loc = *exp_loc;
         match uexp {
-            E::ModuleCall(c) => {
+            E::ModuleCall(c) | E::PackClosure(c) => {
                 if Self::VISIT_TYPES {
                     c.type_arguments
                         .iter()
@@ -422,6 +422,11 @@ pub trait TypingVisitorContext {
                 }
                 self.visit_exp(&c.arguments)
             }
+            E::CallClosure(closure, args) => {
+                // visit the arguments first to match the evaluation order
+                self.visit_exp(args);
+                self.visit_exp(closure)
+            }
             E::Builtin(bf, e) => {
                 // visit the argument first to better match control flow
                 self.visit_exp(e);
@@ -877,7 +882,7 @@ pub trait TypingMutVisitorContext {
             N::Type_::Apply(_, _, args) => {
                 args.iter_mut().for_each(|ty| self.visit_type(exp_loc, ty))
             }
-            N::Type_::Fun(args, ret) => {
+            N::Type_::Fun(args, ret, _) => {
                 args.iter_mut().for_each(|ty| self.visit_type(exp_loc, ty));
                 self.visit_type(exp_loc, ret);
             }
@@ -1010,7 +1015,7 @@ pub trait TypingMutVisitorContext {
         let sp!(exp_loc, uexp) = &mut exp.exp;
         let exp_loc = *exp_loc;
         match uexp {
-            E::ModuleCall(c) => {
+            E::ModuleCall(c) | E::PackClosure(c) => {
                 if Self::VISIT_TYPES {
                     c.type_arguments
                         .iter_mut()
@@ -1021,6 +1026,11 @@ pub trait TypingMutVisitorContext {
                 }
                 self.visit_exp(&mut c.arguments)
             }
+            E::CallClosure(closure, args) => {
+                // visit the arguments first to match the evaluation order
+                self.visit_exp(args);
+                self.visit_exp(closure)
+            }
             E::Builtin(bf, e) => {
                 // visit the argument first to better match control flow
                 self.visit_exp(e);
@@ -1227,12 +1237,13 @@ where
         E::While(_, e1, e2) | E::Mutate(e1, e2) | E::BinopExp(e1, _, _, e2) => {
             exp_satisfies_(e1, p) || exp_satisfies_(e2, p)
         }
+        E::CallClosure(closure, args) => exp_satisfies_(args, p) || exp_satisfies_(closure, p),
         E::IfElse(e1, e2, e3_opt) => {
             exp_satisfies_(e1, p)
                 || exp_satisfies_(e2, p)
                 || e3_opt.iter().any(|e3| exp_satisfies_(e3, p))
         }
-        E::ModuleCall(c) => exp_satisfies_(&c.arguments, p),
+        E::ModuleCall(c) | E::PackClosure(c) => exp_satisfies_(&c.arguments, p),
         E::Match(esubject, arms) => {
             exp_satisfies_(esubject, p)
                 || arms
@@ -817,6 +817,12 @@ fn convert_builtin_type_to_typetag(s_type: &HA::SingleType_) -> Option<TypeTag>
                     NA::BuiltinTypeName_::U256 => TypeTag::U256,
                     NA::BuiltinTypeName_::U16 => TypeTag::U16,
                     NA::BuiltinTypeName_::U32 => TypeTag::U32,
+                    NA::BuiltinTypeName_::I8 => TypeTag::I8,
+                    NA::BuiltinTypeName_::I16 => TypeTag::I16,
+                    NA::BuiltinTypeName_::I32 => TypeTag::I32,
+                    NA::BuiltinTypeName_::I64 => TypeTag::I64,
+                    NA::BuiltinTypeName_::I128 => TypeTag::I128,
+                    NA::BuiltinTypeName_::I256 => TypeTag::I256,
                     NA::BuiltinTypeName_::Vector => {
                         if tts.len() != 1 {
                             return None;
@@ -829,6 +835,7 @@ fn convert_builtin_type_to_typetag(s_type: &HA::SingleType_) -> Option<TypeTag>
             }
             HA::BaseType_::Apply(_, _, _) => None,
             HA::BaseType_::Param(_)
+            | HA::BaseType_::Fun(_, _, _)
             | HA::BaseType_::Unreachable
             | HA::BaseType_::UnresolvedError => None,
         }
@@ -0,0 +1,11 @@
+// function values are not supported in the 2024 edition, only in 2024 alpha
+module 0x42::m;
+
+public struct Strategy has drop {
+    apply: |u64| -> u64 has drop,
+}
+
+public fun run(x: u64): u64 {
+    let f = |y: u64| y + x;
+    f(1)
+}
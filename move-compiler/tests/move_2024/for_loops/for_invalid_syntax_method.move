@@ -0,0 +1,19 @@
+// invalid '#[syntax(for)]' declarations
+module 0x42::m;
+
+public struct S has drop {}
+
+#[syntax(for)]
+public fun not_a_macro(_s: &S, _f: |&u64|) {}
+
+#[syntax(for)]
+public macro fun no_body($s: &S) {
+    let _ = $s;
+}
+
+#[syntax(for)]
+public macro fun returns_value($s: &S, $f: |&u64|): u64 {
+    let _ = $s;
+    $f(&0);
+    0
+}
@@ -0,0 +1,32 @@
+// break and continue in for loops, including labeled ones, and early returns from their bodies
+module 0x42::m;
+
+public fun first_even(v: &vector<u64>): Option<u64> {
+    for (x in v) {
+        if (*x % 2 == 0) return option::some(*x);
+    };
+    option::none()
+}
+
+public fun sum_until_zero(v: &vector<u64>): u64 {
+    let mut sum = 0;
+    for (x in v) {
+        if (*x == 0) break;
+        if (*x % 2 == 1) continue;
+        sum = sum + *x;
+    };
+    sum
+}
+
+public fun count_pairs(v: &vector<u64>, w: &vector<u64>): u64 {
+    let mut count = 0;
+    'outer: for (x in v) {
+        'inner: for (y in w) {
+            if (*y == 0) continue 'outer;
+            if (*x == *y) break 'inner;
+            if (*x > 100) break 'outer;
+            count = count + 1;
+        };
+    };
+    count
+}
@@ -0,0 +1,30 @@
+// for loops over types without a matching '#[syntax(for)]' method
+module 0x42::m;
+
+public struct Bag has drop { items: vector<u64> }
+
+#[syntax(for)]
+public macro fun do_ref($bag: &Bag, $f: |&u64|) {
+    let bag = $bag;
+    bag.items.do_ref!(|x| $f(x))
+}
+
+public struct NoLoops has drop {}
+
+public fun no_syntax_method(n: NoLoops) {
+    for (_x in n) {};
+}
+
+public fun no_by_value_method(bag: Bag) {
+    for (_x in bag) {};
+}
+
+public fun not_a_datatype<T: drop>(t: T) {
+    for (_x in t) {};
+}
+
+public fun break_with_value(v: vector<u64>) {
+    'a: for (_x in v) {
+        break 'a 0
+    };
+}
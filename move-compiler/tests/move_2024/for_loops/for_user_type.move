@@ -0,0 +1,28 @@
+// for loops over a user type with '#[syntax(for)]' macros
+module 0x42::m;
+
+public struct Bag has drop { items: vector<u64> }
+
+#[syntax(for)]
+public macro fun do_ref($bag: &Bag, $f: |&u64|) {
+    let bag = $bag;
+    bag.items.do_ref!(|x| $f(x))
+}
+
+#[syntax(for)]
+public macro fun do_mut($bag: &mut Bag, $f: |&mut u64|) {
+    let bag = $bag;
+    bag.items.do_mut!(|x| $f(x))
+}
+
+public fun total(bag: &mut Bag): u64 {
+    for (x in bag) {
+        *x = *x + 1;
+    };
+    let bag: &Bag = bag;
+    let mut total = 0;
+    for (x in bag) {
+        total = total + *x;
+    };
+    total
+}
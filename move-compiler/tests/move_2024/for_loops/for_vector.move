@@ -0,0 +1,37 @@
+// for loops over vectors by value, by immutable reference and by mutable reference
+module 0x42::m;
+
+public fun sum(v: vector<u64>): u64 {
+    let mut sum = 0;
+    for (x in v) {
+        sum = sum + x;
+    };
+    sum
+}
+
+public fun sum_ref(v: &vector<u64>): u64 {
+    let mut sum = 0;
+    for (x in v) {
+        sum = sum + *x;
+    };
+    sum
+}
+
+public fun double(v: &mut vector<u64>) {
+    for (x in v) {
+        *x = *x * 2;
+    }
+}
+
+public fun sum_borrowed(): u64 {
+    let mut v = vector[1, 2, 3];
+    double(&mut v);
+    let mut sum = 0;
+    for (x in &v) {
+        sum = sum + *x;
+    };
+    for (x in &mut v) {
+        *x = 0;
+    };
+    sum
+}
@@ -0,0 +1,6 @@
+// signed integers are not supported in the 2024 edition, only in 2024 alpha
+module 0x42::m;
+
+public fun negate(x: i64): i64 {
+    -x + -1i64
+}
@@ -0,0 +1,20 @@
+// syntax methods for the same type must use the same abilities for their type parameters
+module 0x42::a {
+    public struct S<phantom T> has drop { values: vector<u64> }
+
+    #[syntax(index)]
+    public fun get<T: copy>(s: &S<T>, i: u64): &u64 { &s.values[i] }
+
+    #[syntax(index)]
+    public fun get_mut<T: drop>(s: &mut S<T>, i: u64): &mut u64 { &mut s.values[i] }
+}
+
+module 0x42::b {
+    public struct S<phantom T> has drop { values: vector<u64> }
+
+    #[syntax(index)]
+    public fun get<T: copy>(s: &S<T>, i: u64): &u64 { &s.values[i] }
+
+    #[syntax(assign)]
+    public fun set<T: copy + store>(_s: &mut S<T>, _i: u64, _value: u64) {}
+}
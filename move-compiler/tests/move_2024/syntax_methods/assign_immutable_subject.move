@@ -0,0 +1,10 @@
+// '#[syntax(assign)]' methods must take their subject by mutable reference
+module 0x42::m;
+
+public struct S has drop { value: u64 }
+
+#[syntax(index)]
+public fun value(s: &S): &u64 { &s.value }
+
+#[syntax(assign)]
+public fun set_value(_s: &S, _value: u64) {}
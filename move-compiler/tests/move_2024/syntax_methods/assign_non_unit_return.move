@@ -0,0 +1,14 @@
+// '#[syntax(assign)]' methods must return '()'
+module 0x42::m;
+
+public struct S has drop { value: u64 }
+
+#[syntax(index)]
+public fun value(s: &S): &u64 { &s.value }
+
+#[syntax(assign)]
+public fun set_value(s: &mut S, value: u64): u64 {
+    let old = s.value;
+    s.value = value;
+    old
+}
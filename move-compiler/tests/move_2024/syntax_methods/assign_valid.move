@@ -0,0 +1,38 @@
+// index assignment through '#[syntax(assign)]' methods
+module 0x42::m;
+
+public struct Grid has drop { cells: vector<u64>, width: u64 }
+
+#[syntax(index)]
+public fun cell(grid: &Grid, x: u64, y: u64): &u64 {
+    &grid.cells[y * grid.width + x]
+}
+
+#[syntax(index)]
+public fun cell_mut(grid: &mut Grid, x: u64, y: u64): &mut u64 {
+    &mut grid.cells[y * grid.width + x]
+}
+
+#[syntax(assign)]
+public fun set_cell(grid: &mut Grid, x: u64, y: u64, value: u64) {
+    let width = grid.width;
+    *&mut grid.cells[y * width + x] = value;
+}
+
+public struct Map<phantom K: copy + drop, V> has drop { values: vector<V> }
+
+#[syntax(index)]
+public fun get<K: copy + drop, V: drop>(map: &Map<K, V>, _key: K): &V {
+    &map.values[0]
+}
+
+#[syntax(assign)]
+public fun insert<K: copy + drop, V: drop>(map: &mut Map<K, V>, _key: K, value: V) {
+    *&mut map.values[0] = value;
+}
+
+public fun assign(grid: &mut Grid, map: &mut Map<u8, bool>): u64 {
+    grid[1, 2] = 3;
+    map[0] = true;
+    grid[1, 2]
+}
@@ -0,0 +1,52 @@
+// '#[syntax(assign)]' methods must take the index parameters followed by the value
+module 0x42::a {
+    public struct S has drop { values: vector<u64> }
+
+    #[syntax(index)]
+    public fun get(s: &S, i: u64): &u64 { &s.values[i] }
+
+    // missing the index parameter
+    #[syntax(assign)]
+    public fun set(_s: &mut S, _value: u64) {}
+}
+
+module 0x42::b {
+    public struct S has drop { values: vector<u64> }
+
+    #[syntax(index)]
+    public fun get(s: &S, i: u64): &u64 { &s.values[i] }
+
+    // the index parameter has a different type
+    #[syntax(assign)]
+    public fun set(_s: &mut S, _i: u8, _value: u64) {}
+}
+
+module 0x42::c {
+    public struct S has drop { values: vector<u64> }
+
+    #[syntax(index)]
+    public fun get(s: &S, i: u64): &u64 { &s.values[i] }
+
+    // the value has a different type than the index function returns a reference to
+    #[syntax(assign)]
+    public fun set(_s: &mut S, _i: u64, _value: bool) {}
+}
+
+module 0x42::d {
+    public struct S<phantom T> has drop { values: vector<u64> }
+
+    #[syntax(index)]
+    public fun get<T>(s: &S<T>, i: u64): &u64 { &s.values[i] }
+
+    // a different number of type parameters
+    #[syntax(assign)]
+    public fun set<T, U>(_s: &mut S<T>, _i: u64, _value: u64) {}
+}
+
+module 0x42::e {
+    public struct S has drop { values: vector<u64> }
+
+    // no index function
+    #[syntax(assign)]
+    public fun set(_s: &mut S, _i: u64, _value: u64) {}
+}
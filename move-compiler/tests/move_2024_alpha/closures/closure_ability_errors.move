@@ -0,0 +1,14 @@
+// ability errors for closures and function types
+module 0x42::m;
+
+public struct Coin { value: u64 }
+
+public struct Store has store { f: |u64| -> u64 has store }
+
+public fun capture_without_copy(coin: Coin): |u64| -> bool has copy {
+    |x| { let Coin { value } = coin; value == x }
+}
+
+public fun capture_without_drop(coin: Coin): |u64| -> bool has drop {
+    |x| { let Coin { value } = coin; value == x }
+}
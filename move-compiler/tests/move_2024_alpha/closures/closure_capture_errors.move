@@ -0,0 +1,33 @@
+// closures copy or move their captures, so they cannot be modified, borrowed, or jumped out of
+module 0x42::m;
+
+public fun capture_reference(v: &vector<u64>): || -> u64 {
+    || v.length()
+}
+
+public fun capture_local_reference(): u64 {
+    let x = 1;
+    let r = &x;
+    let f = || *r;
+    f()
+}
+
+public fun assign_capture(): u64 {
+    let mut x = 1;
+    let f = || { x = 2; x };
+    f()
+}
+
+public fun borrow_capture_mutably(): u64 {
+    let mut v = vector[1];
+    let f = || { v.push_back(2); v.length() };
+    f()
+}
+
+public fun break_out(): u64 {
+    'outer: loop {
+        let f = || { break 'outer };
+        f();
+    };
+    0
+}
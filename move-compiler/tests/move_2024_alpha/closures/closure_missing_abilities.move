@@ -0,0 +1,15 @@
+// closures without 'copy' cannot be used twice, and closures without 'drop' must be consumed
+module 0x42::m;
+
+public fun missing_copy(f: |u64| -> u64 has drop): u64 {
+    let g = f;
+    f(1) + g(1)
+}
+
+public fun missing_drop(_f: |u64| -> u64) {
+}
+
+public fun explicit_copy(f: |u64| -> u64 has drop): u64 {
+    let g = copy f;
+    g(1)
+}
@@ -0,0 +1,34 @@
+// type errors for closures and their calls
+module 0x42::m;
+
+public fun not_a_function(): u64 {
+    let x: u64 = 1;
+    x(2)
+}
+
+public fun wrong_argument(f: |u64| -> u64): u64 {
+    f(true)
+}
+
+public fun wrong_arity(f: |u64, u64| -> u64): u64 {
+    f(1)
+}
+
+public fun wrong_return(): |u64| -> bool {
+    |x| x + 1
+}
+
+public fun wrong_annotation(): |u64| -> u64 {
+    |x| -> bool { x > 0 }
+}
+
+public fun uninferred(): u64 {
+    let f = |x| x;
+    0
+}
+
+public struct S has drop { f: |u64| -> u64 has copy + drop }
+
+public fun call_field(s: &S, x: u64): u64 {
+    (s.f)(x)
+}
@@ -0,0 +1,39 @@
+// function values: closures stored in structs, passed to and returned from functions, and called
+module 0x42::m;
+
+public struct Strategy has copy, drop {
+    apply: |u64| -> u64 has copy + drop,
+}
+
+public fun scale(k: u64): Strategy {
+    Strategy { apply: |x| x * k }
+}
+
+public fun run(s: &Strategy, x: u64): u64 {
+    let f = s.apply;
+    f(x)
+}
+
+public fun apply_twice<T: copy + drop>(f: |T| -> T has copy, x: T): T {
+    f(f(x))
+}
+
+public fun constant<T: copy + drop>(x: T): || -> T has copy + drop {
+    || x
+}
+
+public struct Ticket has drop { id: u64 }
+
+// closures capturing values without 'copy' only have 'drop'
+public fun redeem(ticket: Ticket): |u64| -> bool has drop {
+    |id: u64| -> bool { ticket.id == id }
+}
+
+public fun use_all(): u64 {
+    let s = scale(3);
+    let f = constant(7u64);
+    let check = redeem(Ticket { id: 1 });
+    let add_one = |x: u64| x + 1;
+    let sum = run(&s, f()) + apply_twice(add_one, 1);
+    if (check(1)) sum else 0
+}
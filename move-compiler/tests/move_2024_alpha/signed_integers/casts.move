@@ -0,0 +1,14 @@
+// casts between signed and unsigned integers in both directions
+module 0x42::m;
+
+public fun to_unsigned(x: i8, y: i64, z: i256): (u8, u256, u64, u8) {
+    (x as u8, y as u256, z as u64, (-1i8) as u8)
+}
+
+public fun to_signed(x: u8, y: u256, z: u64): (i8, i16, i256) {
+    (x as i8, y as i16, z as i256)
+}
+
+public fun between_signed(x: i8, y: i256): (i256, i8) {
+    (x as i256, y as i8)
+}
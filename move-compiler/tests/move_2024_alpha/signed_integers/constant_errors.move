@@ -0,0 +1,12 @@
+// signed arithmetic in constants that always aborts
+module 0x42::m;
+
+const MIN_DIV: i8 = -128i8 / -1;
+const MIN_MOD: i64 = -9223372036854775808i64 % -1;
+const MIN_NEG: i16 = -(-32768i16);
+const NEG_TO_UNSIGNED: u8 = (-1i8 as u8);
+const TOO_BIG: i8 = (200u8 as i8);
+
+public fun constants(): (i8, i64, i16, u8, i8) {
+    (MIN_DIV, MIN_MOD, MIN_NEG, NEG_TO_UNSIGNED, TOO_BIG)
+}
@@ -0,0 +1,20 @@
+// signed integer literals out of the range of their type
+module 0x42::m;
+
+public fun positive(): (i8, i16, i32, i64, i128) {
+    (128i8, 32768i16, 2147483648i32, 9223372036854775808i64, 170141183460469231731687303715884105728i128)
+}
+
+public fun negative(): (i8, i16) {
+    (-129i8, -32769i16)
+}
+
+public fun inferred(): i8 {
+    let x: i8 = 200;
+    let y: i8 = -200;
+    x + y
+}
+
+public fun i256_range(): i256 {
+    57896044618658097711785492504343953926634992332820282019728792003956564819968i256
+}
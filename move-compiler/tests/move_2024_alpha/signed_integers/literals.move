@@ -0,0 +1,25 @@
+// signed integer literals, including the minimum values that are only in range when negated
+module 0x42::m;
+
+const MIN_I8: i8 = -128i8;
+const MAX_I8: i8 = 127;
+const MIN_I256: i256 = -57896044618658097711785492504343953926634992332820282019728792003956564819968i256;
+const MAX_I256: i256 = 57896044618658097711785492504343953926634992332820282019728792003956564819967;
+
+public fun literals(): (i8, i16, i32, i64, i128, i256) {
+    (-128i8, -32768i16, -2147483648i32, -9223372036854775808i64, 1i128, MIN_I256)
+}
+
+public fun inferred(): i8 {
+    let x = -128;
+    let y: i8 = 127;
+    x + y + MIN_I8 + MAX_I8
+}
+
+public fun negation(x: i64): i64 {
+    -x + -(-x) - -1
+}
+
+public fun bounds(): bool {
+    MAX_I256 > MIN_I256
+}
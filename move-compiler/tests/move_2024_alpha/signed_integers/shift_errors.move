@@ -0,0 +1,14 @@
+// signed integers cannot be shifted
+module 0x42::m;
+
+public fun shl(x: i8): i8 {
+    x << 1
+}
+
+public fun shr(x: i256): i256 {
+    x >> 2
+}
+
+public fun bitwise(x: i32, y: i32): i32 {
+    (x & y) | (x ^ y)
+}
@@ -0,0 +1,54 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use move_command_line_common::testing::insta_assert;
+use move_compiler::{
+    diagnostics::report_diagnostics_to_buffer, editions::Edition, shared::PackageConfig, Compiler,
+};
+
+/// Compiles the Move file at `path` against the standard library with `edition`, and snapshots
+/// the diagnostics it produces. Files that compile without warnings have empty snapshots.
+fn run_test(path: &Path, edition: Edition) -> datatest_stable::Result<()> {
+    let targets = vec![path.to_str().unwrap().to_owned()];
+    let (files, result) = Compiler::from_files(
+        None,
+        targets,
+        move_stdlib::move_stdlib_files(),
+        move_stdlib::move_stdlib_named_addresses(),
+    )
+    .set_default_config(PackageConfig {
+        edition,
+        ..PackageConfig::default()
+    })
+    .build()?;
+    let diags = match result {
+        Ok((_units, warnings)) => warnings,
+        Err(diags) => diags,
+    };
+    let rendered = report_diagnostics_to_buffer(&files, diags, /* ansi_color */ false);
+    insta_assert! {
+        input_path: path,
+        contents: String::from_utf8(rendered)?,
+    };
+    Ok(())
+}
+
+fn run_test_2024(path: &Path) -> datatest_stable::Result<()> {
+    run_test(path, Edition::E2024)
+}
+
+fn run_test_2024_alpha(path: &Path) -> datatest_stable::Result<()> {
+    run_test(path, Edition::E2024_ALPHA)
+}
+
+datatest_stable::harness!(
+    run_test_2024,
+    "tests/move_2024",
+    r".*\.move$",
+    run_test_2024_alpha,
+    "tests/move_2024_alpha",
+    r".*\.move$"
+);
@@ -7,6 +7,7 @@ use std::io::{Cursor, Read};
 use crate::{
     account_address::AccountAddress,
     annotated_value::{MoveEnumLayout, MoveFieldLayout, MoveStructLayout, MoveTypeLayout},
+    i256::I256,
     identifier::IdentStr,
     u256::U256,
     VARIANT_COUNT_MAX,
@@ -66,6 +67,42 @@ pub trait Visitor<'b, 'l> {
         value: U256,
     ) -> Result<Self::Value, Self::Error>;
 
+    fn visit_i8(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i8,
+    ) -> Result<Self::Value, Self::Error>;
+
+    fn visit_i16(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i16,
+    ) -> Result<Self::Value, Self::Error>;
+
+    fn visit_i32(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i32,
+    ) -> Result<Self::Value, Self::Error>;
+
+    fn visit_i64(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i64,
+    ) -> Result<Self::Value, Self::Error>;
+
+    fn visit_i128(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i128,
+    ) -> Result<Self::Value, Self::Error>;
+
+    fn visit_i256(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: I256,
+    ) -> Result<Self::Value, Self::Error>;
+
     fn visit_bool(
         &mut self,
         driver: &ValueDriver<'_, 'b, 'l>,
@@ -164,6 +201,54 @@ pub trait Traversal<'b, 'l> {
         Ok(())
     }
 
+    fn traverse_i8(
+        &mut self,
+        _driver: &ValueDriver<'_, 'b, 'l>,
+        _value: i8,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn traverse_i16(
+        &mut self,
+        _driver: &ValueDriver<'_, 'b, 'l>,
+        _value: i16,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn traverse_i32(
+        &mut self,
+        _driver: &ValueDriver<'_, 'b, 'l>,
+        _value: i32,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn traverse_i64(
+        &mut self,
+        _driver: &ValueDriver<'_, 'b, 'l>,
+        _value: i64,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn traverse_i128(
+        &mut self,
+        _driver: &ValueDriver<'_, 'b, 'l>,
+        _value: i128,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn traverse_i256(
+        &mut self,
+        _driver: &ValueDriver<'_, 'b, 'l>,
+        _value: I256,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     fn traverse_bool(
         &mut self,
         _driver: &ValueDriver<'_, 'b, 'l>,
@@ -263,6 +348,54 @@ impl<'b, 'l, T: Traversal<'b, 'l> + ?Sized> Visitor<'b, 'l> for T {
         self.traverse_u256(driver, value)
     }
 
+    fn visit_i8(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i8,
+    ) -> Result<Self::Value, Self::Error> {
+        self.traverse_i8(driver, value)
+    }
+
+    fn visit_i16(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i16,
+    ) -> Result<Self::Value, Self::Error> {
+        self.traverse_i16(driver, value)
+    }
+
+    fn visit_i32(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i32,
+    ) -> Result<Self::Value, Self::Error> {
+        self.traverse_i32(driver, value)
+    }
+
+    fn visit_i64(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i64,
+    ) -> Result<Self::Value, Self::Error> {
+        self.traverse_i64(driver, value)
+    }
+
+    fn visit_i128(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: i128,
+    ) -> Result<Self::Value, Self::Error> {
+        self.traverse_i128(driver, value)
+    }
+
+    fn visit_i256(
+        &mut self,
+        driver: &ValueDriver<'_, 'b, 'l>,
+        value: I256,
+    ) -> Result<Self::Value, Self::Error> {
+        self.traverse_i256(driver, value)
+    }
+
     fn visit_bool(
         &mut self,
         driver: &ValueDriver<'_, 'b, 'l>,
@@ -722,6 +855,36 @@ pub(crate) fn visit_value<'c, 'b, 'l, V: Visitor<'b, 'l> + ?Sized>(
             visitor.visit_u256(&driver, v)
         }
 
+        L::I8 => {
+            let v = i8::from_le_bytes(driver.read_exact()?);
+            visitor.visit_i8(&driver, v)
+        }
+
+        L::I16 => {
+            let v = i16::from_le_bytes(driver.read_exact()?);
+            visitor.visit_i16(&driver, v)
+        }
+
+        L::I32 => {
+            let v = i32::from_le_bytes(driver.read_exact()?);
+            visitor.visit_i32(&driver, v)
+        }
+
+        L::I64 => {
+            let v = i64::from_le_bytes(driver.read_exact()?);
+            visitor.visit_i64(&driver, v)
+        }
+
+        L::I128 => {
+            let v = i128::from_le_bytes(driver.read_exact()?);
+            visitor.visit_i128(&driver, v)
+        }
+
+        L::I256 => {
+            let v = I256::from_le_bytes(&driver.read_exact()?);
+            visitor.visit_i256(&driver, v)
+        }
+
         L::Address => {
             let v = AccountAddress::new(driver.read_exact()?);
             visitor.visit_address(&driver, v)
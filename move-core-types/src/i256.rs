@@ -0,0 +1,301 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::u256::U256;
+use ethnum::I256 as EthnumI256;
+use num::BigInt;
+#[cfg(any(test, feature = "fuzzing"))]
+use proptest::strategy::BoxedStrategy;
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, num::ParseIntError};
+
+const I256_NUM_BITS: usize = 256;
+pub const I256_NUM_BYTES: usize = I256_NUM_BITS / 8;
+
+#[derive(Debug)]
+pub struct I256FromStrError(ParseIntError);
+
+impl std::error::Error for I256FromStrError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl fmt::Display for I256FromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A value that does not fit in the integer type it is being cast to
+#[derive(Debug)]
+pub struct I256CastError {
+    val: String,
+    target: &'static str,
+}
+
+impl I256CastError {
+    pub fn new(val: impl fmt::Display, target: &'static str) -> Self {
+        Self {
+            val: val.to_string(),
+            target,
+        }
+    }
+}
+
+impl std::error::Error for I256CastError {}
+
+impl fmt::Display for I256CastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cast failed, {} is out of range for {}",
+            self.val, self.target
+        )
+    }
+}
+
+/// A 256-bit signed integer, in two's complement
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy, PartialOrd, Ord, Default)]
+pub struct I256(EthnumI256);
+
+impl fmt::Display for I256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::str::FromStr for I256 {
+    type Err = I256FromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl<'de> Deserialize<'de> for I256 {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(I256::from_le_bytes(
+            &(<[u8; I256_NUM_BYTES]>::deserialize(deserializer)?),
+        ))
+    }
+}
+
+impl Serialize for I256 {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.to_le_bytes().serialize(serializer)
+    }
+}
+
+impl I256 {
+    /// Zero value as I256
+    pub const fn zero() -> Self {
+        Self(EthnumI256::ZERO)
+    }
+
+    /// One value as I256
+    pub const fn one() -> Self {
+        Self(EthnumI256::ONE)
+    }
+
+    /// Min value of I256: -2^255
+    pub const fn min_value() -> Self {
+        Self(EthnumI256::MIN)
+    }
+
+    /// Max value of I256: 2^255 - 1
+    pub const fn max_value() -> Self {
+        Self(EthnumI256::MAX)
+    }
+
+    /// I256 from string with radix 10 or 16, with an optional leading '-'
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, I256FromStrError> {
+        EthnumI256::from_str_radix(src, radix)
+            .map(Self)
+            .map_err(I256FromStrError)
+    }
+
+    /// I256 from 32 little endian bytes, in two's complement
+    pub fn from_le_bytes(slice: &[u8; I256_NUM_BYTES]) -> Self {
+        Self(EthnumI256::from_le_bytes(*slice))
+    }
+
+    /// I256 to 32 little endian bytes, in two's complement
+    pub fn to_le_bytes(self) -> [u8; I256_NUM_BYTES] {
+        self.0.to_le_bytes()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// Checked integer addition. Computes self + rhs, returning None if overflow occurred.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    /// Checked integer subtraction. Computes self - rhs, returning None if overflow occurred.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Checked integer multiplication. Computes self * rhs, returning None if overflow occurred.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.0.checked_mul(rhs.0).map(Self)
+    }
+
+    /// Checked integer division, rounding towards zero. Computes self / rhs, returning None if
+    /// rhs == 0 or the division overflows (MIN / -1).
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.0.checked_div(rhs.0).map(Self)
+    }
+
+    /// Checked integer remainder, with the sign of self. Computes self % rhs, returning None if
+    /// rhs == 0 or the division overflows (MIN % -1).
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.0.checked_rem(rhs.0).map(Self)
+    }
+
+    /// Checked negation. Computes -self, returning None if self == MIN.
+    pub fn checked_neg(self) -> Option<Self> {
+        self.0.checked_neg().map(Self)
+    }
+}
+
+macro_rules! impl_from {
+    ($($t:ty),* $(,)?) => {$(
+        impl From<$t> for I256 {
+            fn from(n: $t) -> Self {
+                Self(EthnumI256::from(n))
+            }
+        }
+    )*};
+}
+
+impl_from!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+macro_rules! impl_try_into {
+    ($($t:ty),* $(,)?) => {$(
+        impl TryFrom<I256> for $t {
+            type Error = I256CastError;
+
+            fn try_from(n: I256) -> Result<Self, Self::Error> {
+                if n < I256::from(<$t>::MIN) || n > I256::from(<$t>::MAX) {
+                    Err(I256CastError::new(n, stringify!($t)))
+                } else {
+                    Ok(n.0.as_i128() as $t)
+                }
+            }
+        }
+    )*};
+}
+
+impl_try_into!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl TryFrom<I256> for u128 {
+    type Error = I256CastError;
+
+    fn try_from(n: I256) -> Result<Self, Self::Error> {
+        if n.is_negative() || n > I256::from(u128::MAX) {
+            Err(I256CastError::new(n, "u128"))
+        } else {
+            Ok(n.0.as_u128())
+        }
+    }
+}
+
+impl TryFrom<U256> for I256 {
+    type Error = I256CastError;
+
+    fn try_from(n: U256) -> Result<Self, Self::Error> {
+        let i = I256::from_le_bytes(&n.to_le_bytes());
+        if i.is_negative() {
+            Err(I256CastError::new(n, "i256"))
+        } else {
+            Ok(i)
+        }
+    }
+}
+
+impl TryFrom<I256> for U256 {
+    type Error = I256CastError;
+
+    fn try_from(n: I256) -> Result<Self, Self::Error> {
+        if n.is_negative() {
+            Err(I256CastError::new(n, "u256"))
+        } else {
+            Ok(U256::from_le_bytes(&n.to_le_bytes()))
+        }
+    }
+}
+
+impl Distribution<I256> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> I256 {
+        let mut dest = [0; I256_NUM_BYTES];
+        rng.fill_bytes(&mut dest);
+        I256::from_le_bytes(&dest)
+    }
+}
+
+impl From<&I256> for EthnumI256 {
+    fn from(n: &I256) -> EthnumI256 {
+        n.0
+    }
+}
+
+impl From<&I256> for BigInt {
+    fn from(n: &I256) -> Self {
+        BigInt::from_signed_bytes_le(&n.to_le_bytes())
+    }
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+impl proptest::prelude::Arbitrary for I256 {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+    fn arbitrary_with(_params: Self::Parameters) -> Self::Strategy {
+        use proptest::strategy::Strategy as _;
+        proptest::arbitrary::any::<[u8; I256_NUM_BYTES]>()
+            .prop_map(|q| I256::from_le_bytes(&q))
+            .boxed()
+    }
+}
+
+#[cfg(any(test, feature = "fuzzing"))]
+impl<'a> arbitrary::Arbitrary<'a> for I256 {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes = <[u8; I256_NUM_BYTES]>::arbitrary(u)?;
+        Ok(I256::from_le_bytes(&bytes))
+    }
+}
+
+#[test]
+fn casts() {
+    let minus_one = I256::from(-1i8);
+    assert!(u8::try_from(minus_one).is_err());
+    assert!(U256::try_from(minus_one).is_err());
+    assert_eq!(i8::try_from(minus_one).unwrap(), -1);
+    assert!(i8::try_from(I256::from(-129i16)).is_err());
+    assert!(I256::try_from(U256::max_value()).is_err());
+    assert_eq!(
+        I256::try_from(U256::from(u128::MAX)).unwrap(),
+        I256::from(u128::MAX)
+    );
+    assert_eq!(
+        I256::from_le_bytes(&I256::min_value().to_le_bytes()),
+        I256::min_value()
+    );
+    assert!(I256::min_value().checked_div(minus_one).is_none());
+}
@@ -57,14 +57,28 @@ pub enum TypeTag {
     U32,
     #[serde(rename = "u256", alias = "U256")]
     U256,
+
+    // NOTE: Added in bytecode version v9, do not reorder!
+    #[serde(rename = "i8", alias = "I8")]
+    I8,
+    #[serde(rename = "i16", alias = "I16")]
+    I16,
+    #[serde(rename = "i32", alias = "I32")]
+    I32,
+    #[serde(rename = "i64", alias = "I64")]
+    I64,
+    #[serde(rename = "i128", alias = "I128")]
+    I128,
+    #[serde(rename = "i256", alias = "I256")]
+    I256,
 }
 
 impl TypeTag {
     /// Return a canonical string representation of the type. All types are represented using their
     /// source syntax:
     ///
-    /// - "bool", "u8", "u16", "u32", "u64", "u128", "u256", "address", "signer", "vector" for
-    ///   ground types.
+    /// - "bool", "u8", "u16", "u32", "u64", "u128", "u256", "i8", "i16", "i32", "i64", "i128",
+    ///   "i256", "address", "signer", "vector" for ground types.
     ///
     /// - Structs are represented as fully qualified type names, with or without the prefix "0x"
     ///   depending on the `with_prefix` flag, e.g. `0x000...0001::string::String` or
@@ -97,6 +111,12 @@ impl TypeTag {
                     TypeTag::U64 => write!(f, "u64"),
                     TypeTag::U128 => write!(f, "u128"),
                     TypeTag::U256 => write!(f, "u256"),
+                    TypeTag::I8 => write!(f, "i8"),
+                    TypeTag::I16 => write!(f, "i16"),
+                    TypeTag::I32 => write!(f, "i32"),
+                    TypeTag::I64 => write!(f, "i64"),
+                    TypeTag::I128 => write!(f, "i128"),
+                    TypeTag::I256 => write!(f, "i256"),
                     TypeTag::Address => write!(f, "address"),
                     TypeTag::Signer => write!(f, "signer"),
                     TypeTag::Vector(t) => {
@@ -127,7 +147,13 @@ impl TypeTag {
                 | TypeTag::Signer
                 | TypeTag::U16
                 | TypeTag::U32
-                | TypeTag::U256 => AbstractMemorySize::new(0),
+                | TypeTag::U256
+                | TypeTag::I8
+                | TypeTag::I16
+                | TypeTag::I32
+                | TypeTag::I64
+                | TypeTag::I128
+                | TypeTag::I256 => AbstractMemorySize::new(0),
                 TypeTag::Vector(x) => x.abstract_size_for_gas_metering(),
                 TypeTag::Struct(y) => y.abstract_size_for_gas_metering(),
             }
@@ -373,6 +399,12 @@ impl Display for TypeTag {
             TypeTag::U64 => write!(f, "u64"),
             TypeTag::U128 => write!(f, "u128"),
             TypeTag::U256 => write!(f, "u256"),
+            TypeTag::I8 => write!(f, "i8"),
+            TypeTag::I16 => write!(f, "i16"),
+            TypeTag::I32 => write!(f, "i32"),
+            TypeTag::I64 => write!(f, "i64"),
+            TypeTag::I128 => write!(f, "i128"),
+            TypeTag::I256 => write!(f, "i256"),
             TypeTag::Address => write!(f, "address"),
             TypeTag::Signer => write!(f, "signer"),
             TypeTag::Bool => write!(f, "bool"),
@@ -14,6 +14,7 @@ pub mod annotated_value;
 pub mod annotated_visitor;
 pub mod effects;
 pub mod gas_algebra;
+pub mod i256;
 pub mod identifier;
 pub mod language_storage;
 pub mod metadata;
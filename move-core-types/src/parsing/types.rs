@@ -50,6 +50,12 @@ pub enum ParsedType {
     U64,
     U128,
     U256,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    I256,
     Bool,
     Address,
     Signer,
@@ -185,6 +191,12 @@ impl ParsedType {
             ParsedType::U64 => TypeTag::U64,
             ParsedType::U128 => TypeTag::U128,
             ParsedType::U256 => TypeTag::U256,
+            ParsedType::I8 => TypeTag::I8,
+            ParsedType::I16 => TypeTag::I16,
+            ParsedType::I32 => TypeTag::I32,
+            ParsedType::I64 => TypeTag::I64,
+            ParsedType::I128 => TypeTag::I128,
+            ParsedType::I256 => TypeTag::I256,
             ParsedType::Bool => TypeTag::Bool,
             ParsedType::Address => TypeTag::Address,
             ParsedType::Signer => TypeTag::Signer,
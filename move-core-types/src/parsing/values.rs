@@ -47,6 +47,12 @@ pub enum ParsedValue<Extra: ParsableValue = ()> {
     U64(u64),
     U128(u128),
     U256(crate::u256::U256),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    I256(crate::i256::I256),
     Bool(bool),
     Vector(Vec<ParsedValue<Extra>>),
     Struct(Vec<ParsedValue<Extra>>),
@@ -136,6 +142,13 @@ impl Token for ValueToken {
                 (ValueToken::NumberTyped, num_text_len + 3)
             } else if rest.starts_with("u128") || rest.starts_with("u256") {
                 (ValueToken::NumberTyped, num_text_len + 4)
+            } else if rest.starts_with("i8") {
+                (ValueToken::NumberTyped, num_text_len + 2)
+            } else if rest.starts_with("i64") || rest.starts_with("i16") || rest.starts_with("i32")
+            {
+                (ValueToken::NumberTyped, num_text_len + 3)
+            } else if rest.starts_with("i128") || rest.starts_with("i256") {
+                (ValueToken::NumberTyped, num_text_len + 4)
             } else {
                 // No typed suffix
                 (ValueToken::Number, num_text_len)
@@ -164,6 +177,15 @@ impl Token for ValueToken {
             ',' => (Self::Comma, 1),
             ':' if matches!(chars.peek(), Some(':')) => (Self::ColonColon, 2),
             ':' => (Self::Colon, 1),
+            '-' if matches!(chars.peek(), Some(c) if c.is_ascii_digit()) => {
+                // negative numbers must have a signed integer suffix
+                match Self::next_token(&s[1..])? {
+                    Some((ValueToken::NumberTyped, len)) if s[1..=len].contains('i') => {
+                        (ValueToken::NumberTyped, 1 + len)
+                    }
+                    _ => bail!("expected a signed integer suffix on negative number: {}", s),
+                }
+            }
             '0' if matches!(chars.peek(), Some('x')) => {
                 chars.next().unwrap();
                 match chars.next() {
@@ -296,6 +318,12 @@ impl<Extra: ParsableValue> ParsedValue<Extra> {
             ParsedValue::InferredNum(u) | ParsedValue::U256(u) => {
                 Extra::move_value_into_concrete(MoveValue::U256(u))
             }
+            ParsedValue::I8(i) => Extra::move_value_into_concrete(MoveValue::I8(i)),
+            ParsedValue::I16(i) => Extra::move_value_into_concrete(MoveValue::I16(i)),
+            ParsedValue::I32(i) => Extra::move_value_into_concrete(MoveValue::I32(i)),
+            ParsedValue::I64(i) => Extra::move_value_into_concrete(MoveValue::I64(i)),
+            ParsedValue::I128(i) => Extra::move_value_into_concrete(MoveValue::I128(i)),
+            ParsedValue::I256(i) => Extra::move_value_into_concrete(MoveValue::I256(i)),
             ParsedValue::Bool(b) => Extra::move_value_into_concrete(MoveValue::Bool(b)),
             ParsedValue::Vector(values) => Extra::concrete_vector(
                 values
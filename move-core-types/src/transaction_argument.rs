@@ -78,9 +78,15 @@ impl TryFrom<MoveValue> for TransactionArgument {
                     })
                     .collect::<Result<Vec<u8>>>()?,
             ),
-            MoveValue::Signer(_) | MoveValue::Struct(_) | MoveValue::Variant(_) => {
-                return Err(anyhow!("invalid transaction argument: {:?}", val))
-            }
+            MoveValue::Signer(_)
+            | MoveValue::Struct(_)
+            | MoveValue::Variant(_)
+            | MoveValue::I8(_)
+            | MoveValue::I16(_)
+            | MoveValue::I32(_)
+            | MoveValue::I64(_)
+            | MoveValue::I128(_)
+            | MoveValue::I256(_) => return Err(anyhow!("invalid transaction argument: {:?}", val)),
             MoveValue::U16(i) => TransactionArgument::U16(i),
             MoveValue::U32(i) => TransactionArgument::U32(i),
             MoveValue::U256(i) => TransactionArgument::U256(i),
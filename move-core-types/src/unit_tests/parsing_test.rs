@@ -1,5 +1,6 @@
 use crate::{
     account_address::AccountAddress,
+    i256::I256,
     identifier::Identifier,
     language_storage::{ModuleId, StructTag, TypeTag},
     parsing::{
@@ -95,6 +96,12 @@ fn tests_parse_value_positive() {
             "340282366920938463463374607431768211455u128",
             V::U128(340282366920938463463374607431768211455),
         ),
+        ("-128i8", V::I8(i8::MIN)),
+        ("127i8", V::I8(i8::MAX)),
+        ("-0x80i8", V::I8(i8::MIN)),
+        ("-1_000i32", V::I32(-1_000)),
+        ("0i64", V::I64(0)),
+        ("-1i256", V::I256(I256::from(-1i8))),
         ("true", V::Bool(true)),
         ("false", V::Bool(false)),
         (
@@ -170,6 +177,12 @@ fn tests_parse_value_negative() {
             "18446744073709551616u64",
             "340282366920938463463374607431768211456u128",
             "340282366920938463463374607431768211456340282366920938463463374607431768211456340282366920938463463374607431768211456340282366920938463463374607431768211456u256",
+            "-1u8",
+            "-1",
+            "- 1i8",
+            "128i8",
+            "-129i8",
+            "0x80i8",
             "0xg",
             "0x00g0",
             "0x",
@@ -243,6 +256,8 @@ fn test_type_type() {
         "u64",
         "u128",
         "u256",
+        "i8",
+        "i256",
         "bool",
         "vector<u8>",
         "vector<vector<u64>>",
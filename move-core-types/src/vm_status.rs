@@ -315,6 +315,10 @@ pub enum StatusCode {
     ZERO_SIZED_ENUM = 1134,
     MAX_VARIANTS_REACHED = 1135,
 
+    INVALID_CLOSURE_SIGNATURE = 1136,
+    PACK_CLOSURE_TYPE_MISMATCH_ERROR = 1137,
+    CALL_CLOSURE_TYPE_MISMATCH_ERROR = 1138,
+
     // These are errors that the VM might raise if a violation of internal
     // invariants takes place.
     // Invariant Violation Errors: 2000-2999
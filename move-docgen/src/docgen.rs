@@ -944,6 +944,12 @@ impl<'env> Docgen<'env> {
                 MoveValue::U64(u) => format!("{u}"),
                 MoveValue::U128(u) => format!("{u}"),
                 MoveValue::U256(u) => format!("{u}"),
+                MoveValue::I8(i) => format!("{i}"),
+                MoveValue::I16(i) => format!("{i}"),
+                MoveValue::I32(i) => format!("{i}"),
+                MoveValue::I64(i) => format!("{i}"),
+                MoveValue::I128(i) => format!("{i}"),
+                MoveValue::I256(i) => format!("{i}"),
                 MoveValue::Bool(false) => "false".to_owned(),
                 MoveValue::Bool(true) => "true".to_owned(),
                 MoveValue::Address(a) => a.to_hex_literal().to_string(),
@@ -29,6 +29,12 @@
 //!   | u64       // unsigned 64 bit integer
 //!   | u128      // unsigned 128 bit integer
 //!   | u256      // unsigned 256 bit integer
+//!   | i8        // signed 8 bit integer
+//!   | i16       // signed 16 bit integer
+//!   | i32       // signed 32 bit integer
+//!   | i64       // signed 64 bit integer
+//!   | i128      // signed 128 bit integer
+//!   | i256      // signed 256 bit integer
 //!   | address   // 32 byte account address
 //!   | bytearray // immutable, arbitrarily sized array of bytes
 //!
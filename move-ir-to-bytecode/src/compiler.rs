@@ -491,26 +491,77 @@ fn set_module_version(module: &mut CompiledModule, version: Option<u32>) {
     // Leave this const, and the const assertion here as a reminder to update this code if the
     // version changes
     #[allow(clippy::assertions_on_constants)]
-    const PRE_MAX_VERSION: u32 = {
+    const PRE_SIGNED_VERSION: u32 = {
         assert!(
-            VERSION_MAX == 7,
+            VERSION_MAX == 9,
             "Need to update this code if the version changes"
         );
         VERSION_MAX - 1
     };
-    let version = if module.enum_defs.is_empty()
-        && module.enum_def_instantiations.is_empty()
-        && module.variant_handles.is_empty()
-        && module.variant_instantiation_handles.is_empty()
-        && module.function_defs.iter().all(|f| {
-            f.code
-                .as_ref()
-                .map(|c| c.jump_tables.is_empty())
-                .unwrap_or(true)
-        }) {
-        PRE_MAX_VERSION
-    } else {
+    const PRE_CLOSURE_VERSION: u32 = PRE_SIGNED_VERSION - 1;
+    const PRE_ENUM_VERSION: u32 = PRE_CLOSURE_VERSION - 1;
+    let tokens = || {
+        module
+            .signatures
+            .iter()
+            .flat_map(|s| &s.0)
+            .chain(
+                module
+                    .struct_defs
+                    .iter()
+                    .flat_map(|s| s.fields().into_iter().flatten().map(|f| &f.signature.0)),
+            )
+            .chain(
+                module
+                    .enum_defs
+                    .iter()
+                    .flat_map(|e| &e.variants)
+                    .flat_map(|v| &v.fields)
+                    .map(|f| &f.signature.0),
+            )
+            .chain(module.constant_pool.iter().map(|c| &c.type_))
+            .flat_map(|tok| tok.preorder_traversal())
+    };
+    let uses_signed_integers = tokens().any(|t| t.is_signed_integer())
+        || module
+            .function_defs
+            .iter()
+            .filter_map(|f| f.code.as_ref())
+            .flat_map(|c| &c.code)
+            .any(|instr| {
+                matches!(
+                    instr,
+                    Bytecode::LdI8(_)
+                        | Bytecode::LdI16(_)
+                        | Bytecode::LdI32(_)
+                        | Bytecode::LdI64(_)
+                        | Bytecode::LdI128(_)
+                        | Bytecode::LdI256(_)
+                        | Bytecode::CastI8
+                        | Bytecode::CastI16
+                        | Bytecode::CastI32
+                        | Bytecode::CastI64
+                        | Bytecode::CastI128
+                        | Bytecode::CastI256
+                )
+            });
+    let uses_function_types = tokens().any(|t| matches!(t, SignatureToken::Function(_)));
+    let uses_enums = !module.enum_defs.is_empty()
+        || !module.enum_def_instantiations.is_empty()
+        || !module.variant_handles.is_empty()
+        || !module.variant_instantiation_handles.is_empty()
+        || module
+            .function_defs
+            .iter()
+            .any(|f| f.code.as_ref().is_some_and(|c| !c.jump_tables.is_empty()));
+    let version = if uses_signed_integers {
         VERSION_MAX
+    } else if uses_function_types {
+        PRE_SIGNED_VERSION
+    } else if uses_enums {
+        PRE_CLOSURE_VERSION
+    } else {
+        PRE_ENUM_VERSION
     };
     module.version = version;
 }
@@ -692,6 +743,12 @@ fn compile_type(
         Type_::U64 => SignatureToken::U64,
         Type_::U128 => SignatureToken::U128,
         Type_::U256 => SignatureToken::U256,
+        Type_::I8 => SignatureToken::I8,
+        Type_::I16 => SignatureToken::I16,
+        Type_::I32 => SignatureToken::I32,
+        Type_::I64 => SignatureToken::I64,
+        Type_::I128 => SignatureToken::I128,
+        Type_::I256 => SignatureToken::I256,
         Type_::Bool => SignatureToken::Bool,
         Type_::Vector(inner_type) => SignatureToken::Vector(Box::new(compile_type(
             context,
@@ -723,6 +780,11 @@ fn compile_type(
             };
             SignatureToken::TypeParameter(idx)
         }
+        Type_::Function(params, returns, abs) => {
+            let params = compile_types(context, type_parameters, params)?;
+            let returns = compile_types(context, type_parameters, returns)?;
+            SignatureToken::Function(Box::new((params, returns, abilities(abs))))
+        }
     })
 }
 
@@ -1317,6 +1379,30 @@ fn compile_expression(
                 push_instr!(exp.loc, Bytecode::LdU256(Box::new(i)));
                 function_frame.push()?;
             }
+            CopyableVal_::I8(i) => {
+                push_instr!(exp.loc, Bytecode::LdI8(i));
+                function_frame.push()?;
+            }
+            CopyableVal_::I16(i) => {
+                push_instr!(exp.loc, Bytecode::LdI16(i));
+                function_frame.push()?;
+            }
+            CopyableVal_::I32(i) => {
+                push_instr!(exp.loc, Bytecode::LdI32(i));
+                function_frame.push()?;
+            }
+            CopyableVal_::I64(i) => {
+                push_instr!(exp.loc, Bytecode::LdI64(i));
+                function_frame.push()?;
+            }
+            CopyableVal_::I128(i) => {
+                push_instr!(exp.loc, Bytecode::LdI128(Box::new(i)));
+                function_frame.push()?;
+            }
+            CopyableVal_::I256(i) => {
+                push_instr!(exp.loc, Bytecode::LdI256(Box::new(i)));
+                function_frame.push()?;
+            }
             CopyableVal_::ByteArray(buf) => {
                 let vec_value = MoveValue::vector_u8(buf);
                 let ty = MoveTypeLayout::Vector(Box::new(MoveTypeLayout::U8));
@@ -1661,6 +1747,36 @@ fn compile_call(
                     function_frame.pop()?;
                     function_frame.push()?;
                 }
+                Builtin::ToI8 => {
+                    push_instr!(call.loc, Bytecode::CastI8);
+                    function_frame.pop()?;
+                    function_frame.push()?;
+                }
+                Builtin::ToI16 => {
+                    push_instr!(call.loc, Bytecode::CastI16);
+                    function_frame.pop()?;
+                    function_frame.push()?;
+                }
+                Builtin::ToI32 => {
+                    push_instr!(call.loc, Bytecode::CastI32);
+                    function_frame.pop()?;
+                    function_frame.push()?;
+                }
+                Builtin::ToI64 => {
+                    push_instr!(call.loc, Bytecode::CastI64);
+                    function_frame.pop()?;
+                    function_frame.push()?;
+                }
+                Builtin::ToI128 => {
+                    push_instr!(call.loc, Bytecode::CastI128);
+                    function_frame.pop()?;
+                    function_frame.push()?;
+                }
+                Builtin::ToI256 => {
+                    push_instr!(call.loc, Bytecode::CastI256);
+                    function_frame.pop()?;
+                    function_frame.push()?;
+                }
             }
         }
         FunctionCall_::ModuleFunctionCall {
@@ -1700,6 +1816,12 @@ fn type_to_constant_type_layout(ty: Type) -> Result<MoveTypeLayout> {
         Type_::U64 => MoveTypeLayout::U64,
         Type_::U128 => MoveTypeLayout::U128,
         Type_::U256 => MoveTypeLayout::U256,
+        Type_::I8 => MoveTypeLayout::I8,
+        Type_::I16 => MoveTypeLayout::I16,
+        Type_::I32 => MoveTypeLayout::I32,
+        Type_::I64 => MoveTypeLayout::I64,
+        Type_::I128 => MoveTypeLayout::I128,
+        Type_::I256 => MoveTypeLayout::I256,
         Type_::Bool => MoveTypeLayout::Bool,
         Type_::Vector(inner_type) => {
             MoveTypeLayout::Vector(Box::new(type_to_constant_type_layout(*inner_type)?))
@@ -1710,6 +1832,9 @@ fn type_to_constant_type_layout(ty: Type) -> Result<MoveTypeLayout> {
         Type_::TypeParameter(_) => {
             bail!("Type parameters are not supported in constant type layouts")
         }
+        Type_::Function(..) => {
+            bail!("Function types are not supported in constant type layouts")
+        }
         Type_::Datatype(_ident, _tys) => {
             bail!("TODO Structs are not *yet* supported in constant type layouts")
         }
@@ -1817,12 +1942,24 @@ fn compile_bytecode(
         IRBytecode_::LdU64(u) => Bytecode::LdU64(u),
         IRBytecode_::LdU128(u) => Bytecode::LdU128(Box::new(u)),
         IRBytecode_::LdU256(u) => Bytecode::LdU256(Box::new(u)),
+        IRBytecode_::LdI8(i) => Bytecode::LdI8(i),
+        IRBytecode_::LdI16(i) => Bytecode::LdI16(i),
+        IRBytecode_::LdI32(i) => Bytecode::LdI32(i),
+        IRBytecode_::LdI64(i) => Bytecode::LdI64(i),
+        IRBytecode_::LdI128(i) => Bytecode::LdI128(Box::new(i)),
+        IRBytecode_::LdI256(i) => Bytecode::LdI256(Box::new(i)),
         IRBytecode_::CastU8 => Bytecode::CastU8,
         IRBytecode_::CastU16 => Bytecode::CastU16,
         IRBytecode_::CastU32 => Bytecode::CastU32,
         IRBytecode_::CastU64 => Bytecode::CastU64,
         IRBytecode_::CastU128 => Bytecode::CastU128,
         IRBytecode_::CastU256 => Bytecode::CastU256,
+        IRBytecode_::CastI8 => Bytecode::CastI8,
+        IRBytecode_::CastI16 => Bytecode::CastI16,
+        IRBytecode_::CastI32 => Bytecode::CastI32,
+        IRBytecode_::CastI64 => Bytecode::CastI64,
+        IRBytecode_::CastI128 => Bytecode::CastI128,
+        IRBytecode_::CastI256 => Bytecode::CastI256,
         IRBytecode_::LdTrue => Bytecode::LdTrue,
         IRBytecode_::LdFalse => Bytecode::LdFalse,
         IRBytecode_::LdConst(ty, v) => {
@@ -2081,6 +2218,27 @@ fn compile_bytecode(
             let table_idx = compile_jump_table(context, jump_tables, def_idx, eh_idx, lbls)?;
             Bytecode::VariantSwitch(table_idx)
         }
+        IRBytecode_::PackClosure(m, n, tys, ty) => {
+            let tokens = Signature(compile_types(
+                context,
+                function_frame.type_parameters(),
+                &tys,
+            )?);
+            let type_actuals_id = context.signature_index(tokens)?;
+            let fh_idx = context.function_handle(m, n)?.1;
+            let closure = compile_type(context, function_frame.type_parameters(), &ty)?;
+            let sig_idx = context.signature_index(Signature(vec![closure]))?;
+            if tys.is_empty() {
+                Bytecode::PackClosure(fh_idx, sig_idx)
+            } else {
+                let fi_idx = context.function_instantiation_index(fh_idx, type_actuals_id)?;
+                Bytecode::PackClosureGeneric(fi_idx, sig_idx)
+            }
+        }
+        IRBytecode_::CallClosure(ty) => {
+            let closure = compile_type(context, function_frame.type_parameters(), &ty)?;
+            Bytecode::CallClosure(context.signature_index(Signature(vec![closure]))?)
+        }
     };
     push_instr!(loc, ff_instr);
     Ok(())
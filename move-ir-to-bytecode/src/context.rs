@@ -885,6 +885,12 @@ impl<'a> Context<'a> {
             | x @ SignatureToken::U64
             | x @ SignatureToken::U128
             | x @ SignatureToken::U256
+            | x @ SignatureToken::I8
+            | x @ SignatureToken::I16
+            | x @ SignatureToken::I32
+            | x @ SignatureToken::I64
+            | x @ SignatureToken::I128
+            | x @ SignatureToken::I256
             | x @ SignatureToken::Address
             | x @ SignatureToken::Signer
             | x @ SignatureToken::TypeParameter(_) => x,
@@ -931,6 +937,18 @@ impl<'a> Context<'a> {
                     .collect::<Result<_>>()?;
                 SignatureToken::DatatypeInstantiation(Box::new((correct_sh_idx, correct_inners)))
             }
+            SignatureToken::Function(func) => {
+                let (params, returns, abilities) = *func;
+                let correct_params = params
+                    .into_iter()
+                    .map(|t| self.reindex_signature_token(dep, t))
+                    .collect::<Result<_>>()?;
+                let correct_returns = returns
+                    .into_iter()
+                    .map(|t| self.reindex_signature_token(dep, t))
+                    .collect::<Result<_>>()?;
+                SignatureToken::Function(Box::new((correct_params, correct_returns, abilities)))
+            }
         })
     }
 
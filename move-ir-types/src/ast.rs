@@ -158,6 +158,18 @@ pub enum Type_ {
     U128,
     /// `u256`
     U256,
+    /// `i8`
+    I8,
+    /// `i16`
+    I16,
+    /// `i32`
+    I32,
+    /// `i64`
+    I64,
+    /// `i128`
+    I128,
+    /// `i256`
+    I256,
     /// `bool`
     Bool,
     /// `vector`
@@ -168,6 +180,8 @@ pub enum Type_ {
     Reference(bool, Box<Type>),
     /// A type parameter
     TypeParameter(TypeVar_),
+    /// A function type, with its parameter types, return types, and declared abilities
+    Function(Vec<Type>, Vec<Type>, BTreeSet<Ability>),
 }
 
 //**************************************************************************************************
@@ -425,6 +439,18 @@ pub enum Builtin {
     ToU128,
     /// Cast an integer into u256.
     ToU256,
+    /// Cast an integer into i8.
+    ToI8,
+    /// Cast an integer into i16.
+    ToI16,
+    /// Cast an integer into i32.
+    ToI32,
+    /// Cast an integer into i64.
+    ToI64,
+    /// Cast an integer into i128.
+    ToI128,
+    /// Cast an integer into i256.
+    ToI256,
 }
 
 /// Enum for different function calls
@@ -532,6 +558,18 @@ pub enum CopyableVal_ {
     U128(u128),
     /// An unsigned 256-bit integer
     U256(move_core_types::u256::U256),
+    /// A signed 8-bit integer
+    I8(i8),
+    /// A signed 16-bit integer
+    I16(i16),
+    /// A signed 32-bit integer
+    I32(i32),
+    /// A signed 64-bit integer
+    I64(i64),
+    /// A signed 128-bit integer
+    I128(i128),
+    /// A signed 256-bit integer
+    I256(move_core_types::i256::I256),
     /// true or false
     Bool(bool),
     /// `b"<bytes>"`
@@ -671,12 +709,24 @@ pub enum Bytecode_ {
     LdU64(u64),
     LdU128(u128),
     LdU256(move_core_types::u256::U256),
+    LdI8(i8),
+    LdI16(i16),
+    LdI32(i32),
+    LdI64(i64),
+    LdI128(i128),
+    LdI256(move_core_types::i256::I256),
     CastU8,
     CastU16,
     CastU32,
     CastU64,
     CastU128,
     CastU256,
+    CastI8,
+    CastI16,
+    CastI32,
+    CastI64,
+    CastI128,
+    CastI256,
     LdTrue,
     LdFalse,
     LdConst(Type, MoveValue),
@@ -730,6 +780,8 @@ pub enum Bytecode_ {
     PackVariant(DatatypeName, VariantName, Vec<Type>),
     UnpackVariant(DatatypeName, VariantName, Vec<Type>, UnpackType),
     VariantSwitch(DatatypeName, Vec<(VariantName, BlockLabel)>),
+    PackClosure(ModuleName, FunctionName, Vec<Type>, Type),
+    CallClosure(Type),
 }
 pub type Bytecode = Spanned<Bytecode_>;
 
@@ -1431,6 +1483,12 @@ impl fmt::Display for Type_ {
             Type_::U64 => write!(f, "u64"),
             Type_::U128 => write!(f, "u128"),
             Type_::U256 => write!(f, "u256"),
+            Type_::I8 => write!(f, "i8"),
+            Type_::I16 => write!(f, "i16"),
+            Type_::I32 => write!(f, "i32"),
+            Type_::I64 => write!(f, "i64"),
+            Type_::I128 => write!(f, "i128"),
+            Type_::I256 => write!(f, "i256"),
             Type_::Bool => write!(f, "bool"),
             Type_::Address => write!(f, "address"),
             Type_::Signer => write!(f, "signer"),
@@ -1440,6 +1498,24 @@ impl fmt::Display for Type_ {
                 write!(f, "&{}{}", if *is_mutable { "mut " } else { "" }, t)
             }
             Type_::TypeParameter(s) => write!(f, "{}", s),
+            Type_::Function(params, returns, abilities) => {
+                let join = |tys: &[Type]| {
+                    tys.iter()
+                        .map(|t| t.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                write!(f, "|{}|", join(params))?;
+                match returns.as_slice() {
+                    [] => (),
+                    [ty] => write!(f, " -> {}", ty)?,
+                    tys => write!(f, " -> ({})", join(tys))?,
+                }
+                if !abilities.is_empty() {
+                    write!(f, " has {}", format_constraints(abilities))?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -1470,6 +1546,12 @@ impl fmt::Display for Builtin {
             Builtin::ToU64 => write!(f, "to_u64"),
             Builtin::ToU128 => write!(f, "to_u128"),
             Builtin::ToU256 => write!(f, "to_u256"),
+            Builtin::ToI8 => write!(f, "to_i8"),
+            Builtin::ToI16 => write!(f, "to_i16"),
+            Builtin::ToI32 => write!(f, "to_i32"),
+            Builtin::ToI64 => write!(f, "to_i64"),
+            Builtin::ToI128 => write!(f, "to_i128"),
+            Builtin::ToI256 => write!(f, "to_i256"),
         }
     }
 }
@@ -1599,6 +1681,12 @@ impl fmt::Display for CopyableVal_ {
             CopyableVal_::U64(v) => write!(f, "{}", v),
             CopyableVal_::U128(v) => write!(f, "{}u128", v),
             CopyableVal_::U256(v) => write!(f, "{}u256", v),
+            CopyableVal_::I8(v) => write!(f, "{}i8", v),
+            CopyableVal_::I16(v) => write!(f, "{}i16", v),
+            CopyableVal_::I32(v) => write!(f, "{}i32", v),
+            CopyableVal_::I64(v) => write!(f, "{}i64", v),
+            CopyableVal_::I128(v) => write!(f, "{}i128", v),
+            CopyableVal_::I256(v) => write!(f, "{}i256", v),
             CopyableVal_::Bool(v) => write!(f, "{}", v),
             CopyableVal_::ByteArray(v) => write!(f, "0b{}", hex::encode(v)),
             CopyableVal_::Address(v) => write!(f, "0x{}", hex::encode(v)),
@@ -1726,12 +1814,24 @@ impl fmt::Display for Bytecode_ {
             Bytecode_::LdU64(u) => write!(f, "LdU64 {}", u),
             Bytecode_::LdU128(u) => write!(f, "LdU128 {}", u),
             Bytecode_::LdU256(u) => write!(f, "LdU256 {}", u),
+            Bytecode_::LdI8(i) => write!(f, "LdI8 {}", i),
+            Bytecode_::LdI16(i) => write!(f, "LdI16 {}", i),
+            Bytecode_::LdI32(i) => write!(f, "LdI32 {}", i),
+            Bytecode_::LdI64(i) => write!(f, "LdI64 {}", i),
+            Bytecode_::LdI128(i) => write!(f, "LdI128 {}", i),
+            Bytecode_::LdI256(i) => write!(f, "LdI256 {}", i),
             Bytecode_::CastU8 => write!(f, "CastU8"),
             Bytecode_::CastU16 => write!(f, "CastU16"),
             Bytecode_::CastU32 => write!(f, "CastU32"),
             Bytecode_::CastU64 => write!(f, "CastU64"),
             Bytecode_::CastU128 => write!(f, "CastU128"),
             Bytecode_::CastU256 => write!(f, "CastU256"),
+            Bytecode_::CastI8 => write!(f, "CastI8"),
+            Bytecode_::CastI16 => write!(f, "CastI16"),
+            Bytecode_::CastI32 => write!(f, "CastI32"),
+            Bytecode_::CastI64 => write!(f, "CastI64"),
+            Bytecode_::CastI128 => write!(f, "CastI128"),
+            Bytecode_::CastI256 => write!(f, "CastI256"),
             Bytecode_::LdTrue => write!(f, "LdTrue"),
             Bytecode_::LdFalse => write!(f, "LdFalse"),
             Bytecode_::LdConst(ty, v) => write!(f, "LdConst<{}> {}", ty, format_move_value(v)),
@@ -1837,6 +1937,15 @@ impl fmt::Display for Bytecode_ {
                         ))
                 )
             }
+            Bytecode_::PackClosure(m, n, tys, ty) => write!(
+                f,
+                "PackClosure {}.{}{} as {}",
+                m,
+                n,
+                format_type_actuals(tys),
+                ty
+            ),
+            Bytecode_::CallClosure(ty) => write!(f, "CallClosure {}", ty),
         }
     }
 }
@@ -1863,5 +1972,11 @@ fn format_move_value(v: &MoveValue) -> String {
         MoveValue::U16(u) => format!("{}u16", u),
         MoveValue::U32(u) => format!("{}u32", u),
         MoveValue::U256(u) => format!("{}u256", u),
+        MoveValue::I8(i) => format!("{}i8", i),
+        MoveValue::I16(i) => format!("{}i16", i),
+        MoveValue::I32(i) => format!("{}i32", i),
+        MoveValue::I64(i) => format!("{}i64", i),
+        MoveValue::I128(i) => format!("{}i128", i),
+        MoveValue::I256(i) => format!("{}i256", i),
     }
 }
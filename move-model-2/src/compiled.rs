@@ -12,8 +12,8 @@ use move_binary_format::file_format::{
     VariantInstantiationHandleIndex, VariantJumpTable, VariantTag, Visibility,
 };
 use move_core_types::{
-    account_address::AccountAddress, annotated_value, language_storage::ModuleId as CoreModuleId,
-    u256::U256,
+    account_address::AccountAddress, annotated_value, i256::I256,
+    language_storage::ModuleId as CoreModuleId, u256::U256,
 };
 use move_symbol_pool::Symbol;
 use std::{
@@ -135,6 +135,13 @@ pub enum Type {
     Datatype(Box<(QualifiedMemberId, Vec<Type>)>),
     Reference(/* is_mut */ bool, Box<Type>),
     TypeParameter(TypeParameterIndex),
+    Function(Box<(Vec<Type>, Vec<Type>, AbilitySet)>),
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    I256,
 }
 
 #[derive(Debug, Clone)]
@@ -215,6 +222,21 @@ pub enum Bytecode {
     UnpackVariantGenericImmRef(Box<(QualifiedMemberId, Symbol, Vec<Type>)>),
     UnpackVariantGenericMutRef(Box<(QualifiedMemberId, Symbol, Vec<Type>)>),
     VariantSwitch(Box<(QualifiedMemberId, Vec<(Symbol, CodeOffset)>)>),
+    PackClosure(Box<(QualifiedMemberId, Type)>),
+    PackClosureGeneric(Box<(QualifiedMemberId, Vec<Type>, Type)>),
+    CallClosure(Box<Type>),
+    LdI8(i8),
+    LdI16(i16),
+    LdI32(i32),
+    LdI64(i64),
+    LdI128(Box<i128>),
+    LdI256(Box<I256>),
+    CastI8,
+    CastI16,
+    CastI32,
+    CastI64,
+    CastI128,
+    CastI256,
 }
 
 #[derive(Debug, Clone)]
@@ -265,9 +287,15 @@ impl Constant {
             T::U128 => L::U128,
             T::U256 => L::U16,
             T::Address => L::Address,
+            T::I8 => L::I8,
+            T::I16 => L::I16,
+            T::I32 => L::I32,
+            T::I64 => L::I64,
+            T::I128 => L::I128,
+            T::I256 => L::I256,
             T::Vector(inner) => L::Vector(Box::new(Self::annotated_constant_layout(inner))),
 
-            T::Datatype(_) | T::Reference(_, _) | T::TypeParameter(_) => {
+            T::Datatype(_) | T::Reference(_, _) | T::TypeParameter(_) | T::Function(_) => {
                 unreachable!("{ty:?} is not supported in constants")
             }
         }
@@ -411,8 +439,10 @@ impl Packages {
                     .iter()
                     .flat_map(|c| c.code.iter())
                     .filter_map(|instr| match instr {
-                        file_format::Bytecode::Call(i) => Some(*i),
-                        file_format::Bytecode::CallGeneric(i) => {
+                        file_format::Bytecode::Call(i)
+                        | file_format::Bytecode::PackClosure(i, _) => Some(*i),
+                        file_format::Bytecode::CallGeneric(i)
+                        | file_format::Bytecode::PackClosureGeneric(i, _) => {
                             Some(module.function_instantiation_at(*i).handle)
                         }
                         _ => None,
@@ -695,6 +725,24 @@ fn make_type(module: &CompiledModule, token: &SignatureToken) -> Type {
         S::U16 => Type::U16,
         S::U32 => Type::U32,
         S::U256 => Type::U256,
+        S::Function(func) => {
+            let (params, returns, abilities) = &**func;
+            let params = params
+                .iter()
+                .map(|token| make_type(module, token))
+                .collect();
+            let returns = returns
+                .iter()
+                .map(|token| make_type(module, token))
+                .collect();
+            Type::Function(Box::new((params, returns, *abilities)))
+        }
+        S::I8 => Type::I8,
+        S::I16 => Type::I16,
+        S::I32 => Type::I32,
+        S::I64 => Type::I64,
+        S::I128 => Type::I128,
+        S::I256 => Type::I256,
     }
 }
 
@@ -882,6 +930,33 @@ fn make_bytecode(
             let member_id = qualified_member_from_datatype_handle(module, enum_def.enum_handle);
             Bytecode::VariantSwitch(Box::new((member_id, offsets)))
         }
+        B::PackClosure(idx, sig_idx) => {
+            let member_id = qualified_member_from_func_handle(module, *idx);
+            let closure_type = get_closure_signature_as_type(module, *sig_idx);
+            Bytecode::PackClosure(Box::new((member_id, closure_type)))
+        }
+        B::PackClosureGeneric(idx, sig_idx) => {
+            let func_inst = module.function_instantiation_at(*idx);
+            let member_id = qualified_member_from_func_handle(module, func_inst.handle);
+            let types = signature_to_types(module, func_inst.type_parameters);
+            let closure_type = get_closure_signature_as_type(module, *sig_idx);
+            Bytecode::PackClosureGeneric(Box::new((member_id, types, closure_type)))
+        }
+        B::CallClosure(sig_idx) => {
+            Bytecode::CallClosure(Box::new(get_closure_signature_as_type(module, *sig_idx)))
+        }
+        B::LdI8(val) => Bytecode::LdI8(*val),
+        B::LdI16(val) => Bytecode::LdI16(*val),
+        B::LdI32(val) => Bytecode::LdI32(*val),
+        B::LdI64(val) => Bytecode::LdI64(*val),
+        B::LdI128(val) => Bytecode::LdI128(val.clone()),
+        B::LdI256(val) => Bytecode::LdI256(val.clone()),
+        B::CastI8 => Bytecode::CastI8,
+        B::CastI16 => Bytecode::CastI16,
+        B::CastI32 => Bytecode::CastI32,
+        B::CastI64 => Bytecode::CastI64,
+        B::CastI128 => Bytecode::CastI128,
+        B::CastI256 => Bytecode::CastI256,
 
         // deprecated
         B::ExistsDeprecated(_)
@@ -997,3 +1072,11 @@ fn get_vector_signature_as_type(module: &CompiledModule, sig_idx: SignatureIndex
     }
     vec_type.pop().unwrap()
 }
+
+fn get_closure_signature_as_type(module: &CompiledModule, sig_idx: SignatureIndex) -> Type {
+    let mut closure_type = signature_to_types(module, sig_idx);
+    if closure_type.len() != 1 {
+        panic!("Bad closure signature")
+    }
+    closure_type.pop().unwrap()
+}
@@ -46,7 +46,7 @@ impl fmt::Display for Type<'_> {
                     Ok(())
                 }
             },
-            N::Type_::Fun(targs, tret) => {
+            N::Type_::Fun(targs, tret, abilities) => {
                 write!(f, "|")?;
                 for (i, t) in targs.iter().enumerate() {
                     if i > 0 {
@@ -54,7 +54,12 @@ impl fmt::Display for Type<'_> {
                     }
                     write!(f, "{}", type_(t))?;
                 }
-                write!(f, "| -> {}", type_(tret))
+                write!(f, "| -> {}", type_(tret))?;
+                if !abilities.is_empty() {
+                    let abilities = abilities.iter().map(|a| a.to_string()).collect::<Vec<_>>();
+                    write!(f, " has {}", abilities.join(" + "))?;
+                }
+                Ok(())
             }
             N::Type_::Var(_) | N::Type_::Anything | N::Type_::UnresolvedError => write!(f, "_"),
         }
@@ -312,7 +312,7 @@ impl ExpTranslator<'_, '_, '_> {
                 }
             }
             Ref(is_mut, ty) => Type::Reference(*is_mut, Box::new(self.translate_type(ty))),
-            Fun(args, result) => Type::Fun(
+            Fun(args, result, _) => Type::Fun(
                 self.translate_types(args),
                 Box::new(self.translate_type(result)),
             ),
@@ -363,6 +363,30 @@ impl ExpTranslator<'_, '_, '_> {
                 Value::Number(BigInt::from(x)),
                 Type::new_prim(PrimitiveType::U256),
             )),
+            EA::Value_::I8(x) => Some((
+                Value::Number(BigInt::from(*x)),
+                Type::new_prim(PrimitiveType::I8),
+            )),
+            EA::Value_::I16(x) => Some((
+                Value::Number(BigInt::from(*x)),
+                Type::new_prim(PrimitiveType::I16),
+            )),
+            EA::Value_::I32(x) => Some((
+                Value::Number(BigInt::from(*x)),
+                Type::new_prim(PrimitiveType::I32),
+            )),
+            EA::Value_::I64(x) => Some((
+                Value::Number(BigInt::from(*x)),
+                Type::new_prim(PrimitiveType::I64),
+            )),
+            EA::Value_::I128(x) => Some((
+                Value::Number(BigInt::from(*x)),
+                Type::new_prim(PrimitiveType::I128),
+            )),
+            EA::Value_::I256(x) => Some((
+                Value::Number(BigInt::from(x)),
+                Type::new_prim(PrimitiveType::I256),
+            )),
             EA::Value_::Bool(x) => Some((Value::Bool(*x), Type::new_prim(PrimitiveType::Bool))),
             EA::Value_::Bytearray(x) => {
                 let ty = Type::Vector(Box::new(Type::new_prim(PrimitiveType::U8)));
@@ -379,6 +403,12 @@ impl ExpTranslator<'_, '_, '_> {
             (_, MoveValue::U64(n)) => Value::Number(BigInt::from_u64(*n).unwrap()),
             (_, MoveValue::U128(n)) => Value::Number(BigInt::from_u128(*n).unwrap()),
             (_, MoveValue::U256(n)) => Value::Number(BigInt::from(n)),
+            (_, MoveValue::I8(n)) => Value::Number(BigInt::from(*n)),
+            (_, MoveValue::I16(n)) => Value::Number(BigInt::from(*n)),
+            (_, MoveValue::I32(n)) => Value::Number(BigInt::from(*n)),
+            (_, MoveValue::I64(n)) => Value::Number(BigInt::from(*n)),
+            (_, MoveValue::I128(n)) => Value::Number(BigInt::from(*n)),
+            (_, MoveValue::I256(n)) => Value::Number(BigInt::from(n)),
             (_, MoveValue::Bool(b)) => Value::Bool(*b),
             (_, MoveValue::Address(a)) => Value::Address(crate::addr_to_big_uint(a)),
             (_, MoveValue::Signer(a)) => Value::Address(crate::addr_to_big_uint(a)),
@@ -2002,6 +2002,12 @@ impl<'env> ModuleEnv<'env> {
             SignatureToken::U64 => Type::Primitive(PrimitiveType::U64),
             SignatureToken::U128 => Type::Primitive(PrimitiveType::U128),
             SignatureToken::U256 => Type::Primitive(PrimitiveType::U256),
+            SignatureToken::I8 => Type::Primitive(PrimitiveType::I8),
+            SignatureToken::I16 => Type::Primitive(PrimitiveType::I16),
+            SignatureToken::I32 => Type::Primitive(PrimitiveType::I32),
+            SignatureToken::I64 => Type::Primitive(PrimitiveType::I64),
+            SignatureToken::I128 => Type::Primitive(PrimitiveType::I128),
+            SignatureToken::I256 => Type::Primitive(PrimitiveType::I256),
             SignatureToken::Address => Type::Primitive(PrimitiveType::Address),
             SignatureToken::Signer => Type::Primitive(PrimitiveType::Signer),
             SignatureToken::Reference(t) => {
@@ -2053,6 +2059,14 @@ impl<'env> ModuleEnv<'env> {
                     self.globalize_signatures(args),
                 )
             }
+            SignatureToken::Function(func) => {
+                let (params, returns, _) = &**func;
+                let result = match self.globalize_signatures(returns) {
+                    mut returns if returns.len() == 1 => returns.pop().unwrap(),
+                    returns => Type::Tuple(returns),
+                };
+                Type::Fun(self.globalize_signatures(params), Box::new(result))
+            }
         }
     }
 
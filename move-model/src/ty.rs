@@ -55,6 +55,12 @@ pub enum PrimitiveType {
     U64,
     U128,
     U256,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    I256,
     Address,
     Signer,
     // Types only appearing in specifications
@@ -81,7 +87,8 @@ impl PrimitiveType {
     pub fn is_spec(&self) -> bool {
         use PrimitiveType::*;
         match self {
-            Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address | Signer => false,
+            Bool | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64 | I128 | I256
+            | Address | Signer => false,
             Num | Range | EventStore => true,
         }
     }
@@ -97,6 +104,12 @@ impl PrimitiveType {
             U64 => MType::U64,
             U128 => MType::U128,
             U256 => MType::U256,
+            I8 => MType::I8,
+            I16 => MType::I16,
+            I32 => MType::I32,
+            I64 => MType::I64,
+            I128 => MType::I128,
+            I256 => MType::I256,
             Address => MType::Address,
             Signer => MType::Signer,
             Num | Range | EventStore => return None,
@@ -183,6 +196,12 @@ impl Type {
                     | PrimitiveType::U64
                     | PrimitiveType::U128
                     | PrimitiveType::U256
+                    | PrimitiveType::I8
+                    | PrimitiveType::I16
+                    | PrimitiveType::I32
+                    | PrimitiveType::I64
+                    | PrimitiveType::I128
+                    | PrimitiveType::I256
                     | PrimitiveType::Num,
             )
         )
@@ -444,6 +463,12 @@ impl Type {
             TypeTag::U64 => Primitive(PrimitiveType::U64),
             TypeTag::U128 => Primitive(PrimitiveType::U128),
             TypeTag::U256 => Primitive(PrimitiveType::U8),
+            TypeTag::I8 => Primitive(PrimitiveType::I8),
+            TypeTag::I16 => Primitive(PrimitiveType::I16),
+            TypeTag::I32 => Primitive(PrimitiveType::I32),
+            TypeTag::I64 => Primitive(PrimitiveType::I64),
+            TypeTag::I128 => Primitive(PrimitiveType::I128),
+            TypeTag::I256 => Primitive(PrimitiveType::I256),
             TypeTag::Address => Primitive(PrimitiveType::Address),
             TypeTag::Signer => Primitive(PrimitiveType::Signer),
             TypeTag::Struct(s) => {
@@ -1298,6 +1323,12 @@ impl fmt::Display for PrimitiveType {
             U64 => f.write_str("u64"),
             U128 => f.write_str("u128"),
             U256 => f.write_str("u256"),
+            I8 => f.write_str("i8"),
+            I16 => f.write_str("i16"),
+            I32 => f.write_str("i32"),
+            I64 => f.write_str("i64"),
+            I128 => f.write_str("i128"),
+            I256 => f.write_str("i256"),
             Address => f.write_str("address"),
             Signer => f.write_str("signer"),
             Range => f.write_str("range"),
@@ -97,6 +97,39 @@ impl<'a> BuildPlan<'a> {
         })
     }
 
+    /// Compilation results in the process exit upon warning/failure. Modules are reused from the
+    /// previous build where neither their source nor the interfaces they depend on have changed.
+    /// When the interface of a recompiled module changes, the modules that depend on it have to be
+    /// compiled in turn, by another run of the compiler, so `modify_compiler` may be called more
+    /// than once.
+    pub fn compile_incremental<W: Write>(
+        &self,
+        writer: &mut W,
+        modify_compiler: impl FnMut(Compiler) -> Compiler,
+    ) -> Result<CompiledPackage> {
+        let CompilationDependencies {
+            root_package,
+            project_root,
+            transitive_dependencies,
+        } = self.compute_dependencies();
+
+        let compiled = CompiledPackage::build_all_incremental(
+            writer,
+            self.compiler_vfs_root.clone(),
+            &project_root,
+            root_package,
+            transitive_dependencies,
+            self.resolution_graph,
+            modify_compiler,
+        )?;
+
+        Self::clean(
+            &project_root.join(CompiledPackageLayout::Root.path()),
+            self.sorted_deps.iter().copied().collect(),
+        )?;
+        Ok(compiled)
+    }
+
     /// Compilation results in the process exit upon warning/failure
     pub fn migrate<W: Write>(&self, writer: &mut W) -> Result<Option<Migration>> {
         let CompilationDependencies {
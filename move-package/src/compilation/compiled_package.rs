@@ -4,7 +4,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    compilation::package_layout::CompiledPackageLayout,
+    compilation::{
+        incremental::{self, CompilationCache},
+        module_abi::ModuleAbi,
+        package_layout::CompiledPackageLayout,
+    },
     resolution::resolution_graph::{Package, Renaming, ResolvedGraph, ResolvedTable},
     source_package::{
         layout::{SourcePackageLayout, REFERENCE_TEMPLATE_FILENAME},
@@ -26,17 +30,19 @@ use move_command_line_common::files::{
 };
 use move_compiler::{
     compiled_unit::{AnnotatedCompiledUnit, CompiledUnit, NamedCompiledModule},
+    diagnostics::{report_warnings, unwrap_or_report_diagnostics},
     editions::Flavor,
-    linters,
+    linters::{self, LintLevel},
     shared::{
-        files::MappedFiles, NamedAddressMap, NumericalAddress, PackageConfig, PackagePaths,
-        SaveFlag, SaveHook,
+        files::MappedFiles, vfs_path_from_str, NamedAddressMap, NumericalAddress, PackageConfig,
+        PackagePaths, SaveFlag, SaveHook,
     },
     bos_mode::{self},
     Compiler,
 };
 use move_disassembler::disassembler::Disassembler;
 use move_docgen::{Docgen, DocgenFlags, DocgenOptions};
+use move_ir_types::location::Spanned;
 use move_model_2::source_model;
 use move_symbol_pool::Symbol;
 use serde::{Deserialize, Serialize};
@@ -46,7 +52,7 @@ use std::{
     io::Write,
     path::{Path, PathBuf},
 };
-use vfs::VfsPath;
+use vfs::{impls::physical::PhysicalFS, VfsPath};
 
 #[derive(Debug, Clone)]
 pub enum CompilationCachingStatus {
@@ -92,6 +98,8 @@ pub struct CompiledPackage {
     //
     /// filename -> doctext
     pub compiled_docs: Option<Vec<(String, String)>>,
+    /// The interfaces of the modules in the root package
+    pub compiled_abis: Option<Vec<ModuleAbi>>,
     /// The mapping of file hashes to file names and contents
     pub file_map: MappedFiles,
 }
@@ -215,16 +223,34 @@ impl OnDiskCompiledPackage {
             None
         };
 
+        let abis_path = self
+            .root_path
+            .join(self.package.compiled_package_info.package_name.as_str())
+            .join(CompiledPackageLayout::CompiledAbis.path());
+        let compiled_abis = if abis_path.is_dir() {
+            Some(
+                find_filenames(&[abis_path.to_string_lossy().to_string()], |path| {
+                    extension_equals(path, "json")
+                })?
+                .into_iter()
+                .map(|path| Ok(serde_json::from_str(&std::fs::read_to_string(&path)?)?))
+                .collect::<Result<_>>()?,
+            )
+        } else {
+            None
+        };
+
         Ok(CompiledPackage {
             compiled_package_info: self.package.compiled_package_info.clone(),
             root_compiled_units,
             deps_compiled_units,
             compiled_docs,
+            compiled_abis,
             file_map,
         })
     }
 
-    fn decode_unit(
+    pub(crate) fn decode_unit(
         &self,
         package_name: Symbol,
         bytecode_path_str: &str,
@@ -314,6 +340,23 @@ impl OnDiskCompiledPackage {
         })
     }
 
+    /// The path of the bytecode of module `module_name` from package `package_name` on disk
+    pub(crate) fn compiled_unit_path(&self, package_name: Symbol, module_name: &str) -> PathBuf {
+        let root_package = self.package.compiled_package_info.package_name;
+        let file_path = if root_package == package_name {
+            PathBuf::new()
+        } else {
+            CompiledPackageLayout::Dependencies
+                .path()
+                .join(package_name.as_str())
+        }
+        .join(module_name);
+        self.root_path
+            .join(CompiledPackageLayout::CompiledModules.path())
+            .join(file_path)
+            .with_extension(MOVE_COMPILED_EXTENSION)
+    }
+
     fn save_compiled_unit(
         &self,
         package_name: Symbol,
@@ -509,11 +552,13 @@ impl CompiledPackage {
             .filter(|&dep| dep.is_immediate)
             .map(|dep| dep.name)
             .collect::<Vec<_>>();
-        for dep in &transitive_dependencies {
-            writeln!(w, "{} {}", "INCLUDING DEPENDENCY".bold().green(), dep.name)?;
-        }
         let root_package_name = resolved_package.source_package.package.name;
-        writeln!(w, "{} {}", "BUILDING".bold().green(), root_package_name)?;
+        Self::write_build_header(
+            w,
+            root_package_name,
+            &transitive_dependencies,
+            &resolution_graph.build_options,
+        )?;
 
         // gather source/dep files with their address mappings
         let (sources_package_paths, deps_package_paths) = make_source_and_deps_for_compiler(
@@ -561,6 +606,13 @@ impl CompiledPackage {
         compiler = compiler
             .add_custom_known_filters(filter_attr_name, filters)
             .add_visitors(linters::linter_visitors(lint_level));
+        let custom_lints = &resolution_graph.build_options.custom_lints;
+        if !custom_lints.is_empty() {
+            let (filter_attr_name, filters) = linters::custom_known_filters(custom_lints);
+            compiler = compiler
+                .add_custom_known_filters(filter_attr_name, filters)
+                .add_visitors(linters::custom_linter_visitors(custom_lints, lint_level));
+        }
         Ok(BuildResult {
             root_package_name,
             immediate_dependencies,
@@ -568,6 +620,25 @@ impl CompiledPackage {
         })
     }
 
+    fn write_build_header<W: Write>(
+        w: &mut W,
+        root_package_name: Symbol,
+        transitive_dependencies: &[DependencyInfo],
+        build_options: &BuildConfig,
+    ) -> Result<()> {
+        for dep in transitive_dependencies {
+            writeln!(w, "{} {}", "INCLUDING DEPENDENCY".bold().green(), dep.name)?;
+        }
+        // with `--lint`, every custom lint runs
+        if build_options.lint_flag.get() == LintLevel::All {
+            for lint in &build_options.custom_lints {
+                writeln!(w, "{} {}", "CUSTOM LINT".bold().green(), lint.filter_name())?;
+            }
+        }
+        writeln!(w, "{} {}", "BUILDING".bold().green(), root_package_name)?;
+        Ok(())
+    }
+
     pub(crate) fn build_for_result<W: Write, T>(
         w: &mut W,
         vfs_root: Option<VfsPath>,
@@ -614,41 +685,18 @@ impl CompiledPackage {
         )?;
         let program_info = program_info_hook.take_typing_info();
         let (file_map, all_compiled_units) = result;
-        let mut all_compiled_units_vec = vec![];
-        let mut root_compiled_units = vec![];
-        let mut deps_compiled_units = vec![];
-        for mut annot_unit in all_compiled_units {
-            let source_path = PathBuf::from(
-                file_map
-                    .get(&annot_unit.loc().file_hash())
-                    .unwrap()
-                    .0
-                    .as_str(),
-            );
-            let package_name = annot_unit.named_module.package_name.unwrap();
-            // unwraps below are safe as the source path exists (or must have existed at some point)
-            // so it would be syntactically correct
-            let file_name = PathBuf::from(source_path.file_name().unwrap());
-            if let Ok(p) = dunce::canonicalize(source_path.parent().unwrap()) {
-                annot_unit
-                    .named_module
-                    .source_map
-                    .set_from_file_path(p.join(file_name));
-            }
-            let unit = CompiledUnitWithSource {
-                unit: annot_unit.named_module,
-                source_path,
-            };
-            if package_name == root_package_name {
-                root_compiled_units.push(unit.clone())
-            } else {
-                deps_compiled_units.push((package_name, unit.clone()))
-            }
-            all_compiled_units_vec.push((unit.source_path, unit.unit));
-        }
+        let (root_compiled_units, deps_compiled_units) =
+            Self::partition_units(root_package_name, &file_map, all_compiled_units);
 
         let mut compiled_docs = None;
-        if resolution_graph.build_options.generate_docs {
+        let mut compiled_abis = None;
+        let build_options = &resolution_graph.build_options;
+        if build_options.generate_docs || build_options.emit_abi {
+            let all_compiled_units_vec = root_compiled_units
+                .iter()
+                .chain(deps_compiled_units.iter().map(|(_, unit)| unit))
+                .map(|unit| (unit.source_path.clone(), unit.unit.clone()))
+                .collect();
             let root_named_address_map = resolved_package.resolved_table.clone();
             let model = source_model::Model::from_source(
                 file_map.clone(),
@@ -658,14 +706,28 @@ impl CompiledPackage {
                 all_compiled_units_vec,
             )?;
 
-            compiled_docs = Some(Self::build_docs(
-                DocgenFlags::default(), // TODO this should be configurable
-                resolved_package.source_package.package.name,
-                &model,
-                &resolved_package.package_path,
-                &immediate_dependencies,
-                &resolution_graph.build_options.install_dir,
-            )?);
+            if build_options.generate_docs {
+                compiled_docs = Some(Self::build_docs(
+                    DocgenFlags::default(), // TODO this should be configurable
+                    resolved_package.source_package.package.name,
+                    &model,
+                    &resolved_package.package_path,
+                    &immediate_dependencies,
+                    &build_options.install_dir,
+                )?);
+            }
+            if build_options.emit_abi {
+                compiled_abis = Some(
+                    root_compiled_units
+                        .iter()
+                        .map(|unit| {
+                            let module =
+                                model.module((unit.unit.address.into_inner(), unit.unit.name));
+                            ModuleAbi::new(module)
+                        })
+                        .collect(),
+                );
+            }
         };
 
         let compiled_package = CompiledPackage {
@@ -678,14 +740,255 @@ impl CompiledPackage {
             root_compiled_units,
             deps_compiled_units,
             compiled_docs,
+            compiled_abis,
+            file_map,
+        };
+
+        compiled_package.save_to_disk(project_root.join(CompiledPackageLayout::Root.path()))?;
+
+        Ok(compiled_package)
+    }
+
+    /// Compile the package like `build_all` does with the `build_and_report` driver, but only
+    /// compile the source files that changed since the last build, along with the files whose
+    /// modules depend on interfaces that changed as a result. The modules of all other files are
+    /// loaded from the build directory. Falls back to compiling everything when there is no usable
+    /// `CompilationCache` from the last build.
+    pub(crate) fn build_all_incremental<W: Write>(
+        w: &mut W,
+        vfs_root: Option<VfsPath>,
+        project_root: &Path,
+        resolved_package: Package,
+        transitive_dependencies: Vec<DependencyInfo>,
+        resolution_graph: &ResolvedGraph,
+        mut modify_compiler: impl FnMut(Compiler) -> Compiler,
+    ) -> Result<CompiledPackage> {
+        let build_options = &resolution_graph.build_options;
+        // Source files are only tracked on the real file system, and docs and ABIs need the whole
+        // package
+        if vfs_root.is_some() || build_options.generate_docs || build_options.emit_abi {
+            return Self::build_all(
+                w,
+                vfs_root,
+                project_root,
+                resolved_package,
+                transitive_dependencies,
+                resolution_graph,
+                |compiler| modify_compiler(compiler).build_and_report(),
+            );
+        }
+
+        let root_package_name = resolved_package.source_package.package.name;
+        let package_root = project_root
+            .join(CompiledPackageLayout::Root.path())
+            .join(root_package_name.as_str());
+        let (sources_package_paths, deps_package_paths) = make_source_and_deps_for_compiler(
+            resolution_graph,
+            &resolved_package,
+            transitive_dependencies.clone(),
+        )?;
+        let sources = incremental::source_files(&sources_package_paths, &deps_package_paths)?;
+        let config_digest =
+            incremental::config_digest(build_options, &sources_package_paths, &deps_package_paths)?;
+
+        let cached = if build_options.force_recompilation {
+            None
+        } else {
+            CompilationCache::load(&package_root, &config_digest).and_then(|cache| {
+                let changed = cache.changed_files(&sources)?;
+                let on_disk = OnDiskCompiledPackage::from_path(&package_root).ok()?;
+                Some((cache, changed, on_disk))
+            })
+        };
+        let Some((cache, mut to_compile, on_disk)) = cached else {
+            let mut warnings = BTreeMap::new();
+            let compiled_package = Self::build_all(
+                w,
+                vfs_root,
+                project_root,
+                resolved_package,
+                transitive_dependencies,
+                resolution_graph,
+                |compiler| {
+                    let (files, units_res) = modify_compiler(compiler).build()?;
+                    let (units, diags) = unwrap_or_report_diagnostics(&files, units_res);
+                    warnings = incremental::render_warnings(&files, &diags);
+                    report_warnings(&files, diags);
+                    Ok((files, units))
+                },
+            )?;
+            CompilationCache::new(config_digest, &sources, &compiled_package, &warnings)
+                .save(&package_root)?;
+            return Ok(compiled_package);
+        };
+
+        // Compile the changed files, and then any files whose modules were compiled against
+        // interfaces that changed as a result, until the interfaces of all modules are consistent
+        Self::write_build_header(
+            w,
+            root_package_name,
+            &transitive_dependencies,
+            &resolution_graph.build_options,
+        )?;
+        let mut compiled = None;
+        while !to_compile.is_empty() {
+            // the compiler knows files by their paths in its (physical) virtual file system
+            let physical_root = VfsPath::new(PhysicalFS::new("/"));
+            let files_to_compile = to_compile
+                .iter()
+                .map(|path| {
+                    Ok(PathBuf::from(
+                        vfs_path_from_str(path.clone(), &physical_root)?.as_str(),
+                    ))
+                })
+                .collect::<Result<_>>()?;
+            let BuildResult {
+                result: (file_map, units, warnings),
+                ..
+            } = Self::build_for_driver(
+                &mut std::io::sink(),
+                None,
+                resolved_package.clone(),
+                transitive_dependencies.clone(),
+                resolution_graph,
+                |compiler| {
+                    let compiler =
+                        modify_compiler(compiler.set_files_to_compile(Some(files_to_compile)));
+                    let (files, units_res) = compiler.build()?;
+                    let (units, warnings) = unwrap_or_report_diagnostics(&files, units_res);
+                    Ok((files, units, warnings))
+                },
+            )?;
+            let mut interfaces = cache.interfaces(&to_compile);
+            interfaces.extend(units.iter().map(|unit| {
+                let module = &unit.named_module.module;
+                (
+                    incremental::module_key(module),
+                    incremental::interface_digest(module),
+                )
+            }));
+            let stale = cache.stale_files(&to_compile, &interfaces);
+            compiled = Some((file_map, units, warnings));
+            if stale.is_empty() {
+                break;
+            }
+            to_compile.extend(stale);
+        }
+
+        let mut rendered_warnings = BTreeMap::new();
+        let (file_map, mut root_compiled_units, mut deps_compiled_units) = match compiled {
+            Some((file_map, units, warnings)) => {
+                rendered_warnings = incremental::render_warnings(&file_map, &warnings);
+                report_warnings(&file_map, warnings);
+                let (root_units, deps_units) =
+                    Self::partition_units(root_package_name, &file_map, units);
+                (file_map, root_units, deps_units)
+            }
+            None => {
+                let mut file_map = MappedFiles::empty();
+                for path in sources.keys() {
+                    let contents = Arc::from(std::fs::read_to_string(path)?);
+                    file_map.add(
+                        FileHash::new(&contents),
+                        FileName::from(path.as_str()),
+                        contents,
+                    );
+                }
+                (file_map, vec![], vec![])
+            }
+        };
+        for (path, file) in cache
+            .files
+            .iter()
+            .filter(|(path, _)| !to_compile.contains(*path))
+        {
+            // the warnings of reused files are not reported by the compiler again
+            eprint!("{}", file.warnings);
+            rendered_warnings.insert(path.clone(), file.warnings.clone());
+            for module in &file.modules {
+                let bytecode_path =
+                    on_disk.compiled_unit_path(file.package, module.id.name().as_str());
+                let mut unit =
+                    on_disk.decode_unit(file.package, &bytecode_path.to_string_lossy())?;
+                unit.unit.address_name = module.address_name.map(Spanned::unsafe_no_loc);
+                unit.source_path = PathBuf::from(path);
+                if file.package == root_package_name {
+                    root_compiled_units.push(unit);
+                } else {
+                    deps_compiled_units.push((file.package, unit));
+                }
+            }
+        }
+
+        let compiled_package = CompiledPackage {
+            compiled_package_info: CompiledPackageInfo {
+                package_name: root_package_name,
+                address_alias_instantiation: resolved_package.resolved_table,
+                source_digest: Some(resolved_package.source_digest),
+                build_flags: build_options.clone(),
+            },
+            root_compiled_units: incremental::sort_by_dependencies(root_compiled_units, |unit| {
+                &unit.unit.module
+            }),
+            deps_compiled_units: incremental::sort_by_dependencies(
+                deps_compiled_units,
+                |(_, unit)| &unit.unit.module,
+            ),
+            compiled_docs: None,
+            compiled_abis: None,
             file_map,
         };
 
         compiled_package.save_to_disk(project_root.join(CompiledPackageLayout::Root.path()))?;
+        CompilationCache::new(config_digest, &sources, &compiled_package, &rendered_warnings)
+            .save(&package_root)?;
 
         Ok(compiled_package)
     }
 
+    /// Split the units produced by the compiler into those of the root package and those of its
+    /// dependencies, pointing their source maps at their source files.
+    fn partition_units(
+        root_package_name: Symbol,
+        file_map: &MappedFiles,
+        units: Vec<AnnotatedCompiledUnit>,
+    ) -> (
+        Vec<CompiledUnitWithSource>,
+        Vec<(PackageName, CompiledUnitWithSource)>,
+    ) {
+        let mut root_compiled_units = vec![];
+        let mut deps_compiled_units = vec![];
+        for mut annot_unit in units {
+            let source_path = PathBuf::from(
+                file_map
+                    .get(&annot_unit.loc().file_hash())
+                    .unwrap()
+                    .0
+                    .as_str(),
+            );
+            let package_name = annot_unit.named_module.package_name.unwrap();
+            // unwraps below are safe as the source path exists (or must have existed at some point)
+            // so it would be syntactically correct
+            let file_name = PathBuf::from(source_path.file_name().unwrap());
+            if let Ok(p) = dunce::canonicalize(source_path.parent().unwrap()) {
+                annot_unit
+                    .named_module
+                    .source_map
+                    .set_from_file_path(p.join(file_name));
+            }
+            let unit = CompiledUnitWithSource {
+                unit: annot_unit.named_module,
+                source_path,
+            };
+            if package_name == root_package_name {
+                root_compiled_units.push(unit)
+            } else {
+                deps_compiled_units.push((package_name, unit))
+            }
+        }
+        (root_compiled_units, deps_compiled_units)
+    }
+
     // We take the (restrictive) view that all filesystems are case insensitive to maximize
     // portability of packages.
     fn check_filepaths_ok(&self) -> Result<()> {
@@ -786,6 +1089,18 @@ impl CompiledPackage {
             }
         }
 
+        if let Some(abis) = &self.compiled_abis {
+            for abi in abis {
+                on_disk_package.save_under(
+                    CompiledPackageLayout::CompiledAbis
+                        .path()
+                        .join(&abi.name)
+                        .with_extension("json"),
+                    serde_json::to_string_pretty(abi)?.as_bytes(),
+                )?;
+            }
+        }
+
         on_disk_package.save_under(
             CompiledPackageLayout::BuildInfo.path(),
             serde_yaml::to_string(&on_disk_package.package)?.as_bytes(),
@@ -939,3 +1254,179 @@ pub(crate) fn make_deps_for_compiler_internal(
         })
         .collect::<Result<Vec<_>>>()
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{compilation::build_plan::BuildPlan, BuildConfig};
+    use move_compiler::{
+        diag,
+        diagnostics::{
+            codes::{custom, Severity},
+            report_diagnostics_to_buffer, DiagnosticReporter,
+        },
+        expansion::ast::ModuleIdent,
+        linters::{CustomLint, LintLevel},
+        parser::ast::FunctionName,
+        shared::{CompilationEnv, Identifier},
+        typing::{
+            ast as T,
+            visitor::{TypingVisitor, TypingVisitorConstructor, TypingVisitorContext},
+        },
+    };
+    use std::path::Path;
+
+    const MANIFEST: &str = r#"
+        [package]
+        name = "P"
+        edition = "2024"
+
+        [addresses]
+        p = "0x2"
+    "#;
+
+    const LINT_NAME: &str = "no_bad";
+    const LINT_MESSAGE: &str = "function named 'bad'";
+
+    /// Reports every function named `bad`
+    struct NoBad;
+
+    struct Context<'a> {
+        reporter: DiagnosticReporter<'a>,
+    }
+
+    impl TypingVisitorConstructor for NoBad {
+        type Context<'a> = Context<'a>;
+
+        fn context<'a>(env: &'a CompilationEnv, _program: &T::Program) -> Self::Context<'a> {
+            Context {
+                reporter: env.diagnostic_reporter_at_top_level(),
+            }
+        }
+    }
+
+    impl TypingVisitorContext for Context<'_> {
+        fn push_warning_filter_scope(
+            &mut self,
+            filters: move_compiler::diagnostics::warning_filters::WarningFilters,
+        ) {
+            self.reporter.push_warning_filter_scope(filters)
+        }
+
+        fn pop_warning_filter_scope(&mut self) {
+            self.reporter.pop_warning_filter_scope()
+        }
+
+        fn visit_function_custom(
+            &mut self,
+            _module: ModuleIdent,
+            function_name: FunctionName,
+            _fdef: &T::Function,
+        ) -> bool {
+            if function_name.value().as_str() == "bad" {
+                let info = no_bad_info();
+                self.reporter
+                    .add_diag(diag!(info, (function_name.loc(), "Avoid 'bad'")));
+            }
+            false
+        }
+    }
+
+    fn no_bad_info() -> move_compiler::diagnostics::codes::DiagnosticInfo {
+        custom("Test", Severity::Warning, 1, 1, LINT_MESSAGE)
+    }
+
+    fn no_bad() -> CustomLint {
+        CustomLint::new(no_bad_info(), LINT_NAME, || NoBad.visitor())
+    }
+
+    fn new_package(source: &str) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Move.toml"), MANIFEST).unwrap();
+        std::fs::create_dir(dir.path().join("sources")).unwrap();
+        std::fs::write(dir.path().join("sources").join("m.move"), source).unwrap();
+        dir
+    }
+
+    /// Builds `root` with `config`, returning the build output and the rendered warnings
+    fn build(root: &Path, config: BuildConfig) -> (String, String) {
+        let mut output = Vec::new();
+        let mut warnings = Vec::new();
+        let resolved_graph = config
+            .resolution_graph_for_package(root, None, &mut output)
+            .unwrap();
+        BuildPlan::create(&resolved_graph)
+            .unwrap()
+            .compile_with_driver(&mut output, |compiler| {
+                let (files, units_res) = compiler.build()?;
+                let (units, diags) = units_res.map_err(|_| anyhow::anyhow!("Compilation error"))?;
+                warnings = report_diagnostics_to_buffer(&files, diags, /* ansi_color */ false);
+                Ok((files, units))
+            })
+            .unwrap();
+        (
+            String::from_utf8(output).unwrap(),
+            String::from_utf8(warnings).unwrap(),
+        )
+    }
+
+    fn lint_config(lint: CustomLint, level: LintLevel) -> BuildConfig {
+        BuildConfig {
+            custom_lints: vec![lint],
+            lint_flag: level.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn custom_lint_levels() {
+        let dir = new_package("module p::m { public fun bad() {} }");
+        let root = dir.path();
+
+        let (output, warnings) = build(root, lint_config(no_bad(), LintLevel::All));
+        assert!(
+            output.contains(&format!("CUSTOM LINT {LINT_NAME}")),
+            "{output}"
+        );
+        assert!(warnings.contains(LINT_MESSAGE), "{warnings}");
+
+        let (output, warnings) = build(root, lint_config(no_bad(), LintLevel::Default));
+        assert!(!output.contains("CUSTOM LINT"), "{output}");
+        assert!(!warnings.contains(LINT_MESSAGE), "{warnings}");
+
+        let default = no_bad().enabled_by_default();
+        let (_, warnings) = build(root, lint_config(default.clone(), LintLevel::Default));
+        assert!(warnings.contains(LINT_MESSAGE), "{warnings}");
+
+        let (_, warnings) = build(root, lint_config(default, LintLevel::None));
+        assert!(!warnings.contains(LINT_MESSAGE), "{warnings}");
+    }
+
+    #[test]
+    fn custom_lint_allowed() {
+        let source = format!("module p::m {{ #[allow(lint({LINT_NAME}))] public fun bad() {{}} }}");
+        let dir = new_package(&source);
+        let (_, warnings) = build(dir.path(), lint_config(no_bad(), LintLevel::All));
+        assert!(warnings.is_empty(), "{warnings}");
+    }
+
+    #[test]
+    fn duplicate_custom_lints() {
+        let dir = new_package("module p::m { public fun bad() {} }");
+        let same_name = CustomLint::new(
+            custom("Test", Severity::Warning, 1, 2, LINT_MESSAGE),
+            LINT_NAME,
+            || NoBad.visitor(),
+        );
+        let same_code = CustomLint::new(no_bad_info(), "other", || NoBad.visitor());
+        for duplicate in [same_name, same_code] {
+            let config = BuildConfig {
+                custom_lints: vec![no_bad(), duplicate],
+                ..Default::default()
+            };
+            let err = config
+                .resolution_graph_for_package(dir.path(), None, &mut Vec::new())
+                .unwrap_err();
+            assert!(err.to_string().contains("Custom lints"), "{err}");
+        }
+    }
+}
@@ -0,0 +1,553 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    compilation::{
+        compiled_package::{CompiledPackage, ModuleFormat},
+        package_layout::CompiledPackageLayout,
+    },
+    resolution::digest::digest_str,
+    source_package::parsed_manifest::PackageName,
+    BuildConfig,
+};
+use anyhow::Result;
+use move_binary_format::{
+    file_format::{CompiledModule, Visibility},
+    normalized,
+};
+use move_command_line_common::files::FileHash;
+use move_compiler::{
+    diagnostics::{report_diagnostics_to_buffer, Diagnostics},
+    editions::Edition,
+    parser::{
+        ast::MACRO_MODIFIER,
+        lexer::{Lexer, Tok},
+    },
+    shared::{files::MappedFiles, PackagePaths},
+};
+use move_core_types::language_storage::ModuleId;
+use move_symbol_pool::Symbol;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+/// A record of the source files compiled into a package and the modules compiled from each of
+/// them. A module can be reused from the build directory by the next build if neither its source
+/// file nor the interfaces of the modules it depends on have changed in the meantime.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompilationCache {
+    /// Digest of everything besides source files that affects compilation: the build flags, and
+    /// the compiler configuration and named addresses of every package. The cache only applies to
+    /// builds with the same configuration.
+    pub config_digest: String,
+    /// The source files that were compiled, keyed by path.
+    pub files: BTreeMap<String, CachedFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedFile {
+    /// The package the file belongs to.
+    pub package: PackageName,
+    /// Digest of the contents of the file.
+    pub source_digest: String,
+    /// Whether the file contains the `macro` token, in which case it may declare macros. Macro
+    /// bodies are inlined at their call sites and do not appear in bytecode, so a change to such a
+    /// file invalidates the whole cache.
+    pub contains_macro: bool,
+    /// The modules compiled from the file.
+    pub modules: Vec<CachedModule>,
+    /// The warnings reported for the file when it was compiled, rendered without color. They are
+    /// reported again by builds that reuse its modules.
+    pub warnings: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedModule {
+    pub id: ModuleId,
+    pub address_name: Option<Symbol>,
+    /// Digest of the module's interface, see `interface_digest`.
+    pub interface_digest: String,
+    /// The modules this module depends on, along with the digests of the interfaces it was
+    /// compiled against.
+    pub dependencies: BTreeMap<String, String>,
+}
+
+/// A source file that is about to be compiled.
+#[derive(Debug, Clone)]
+pub struct SourceFile {
+    pub package: PackageName,
+    pub source_digest: String,
+    pub contains_macro: bool,
+}
+
+/// The source files of a package and its source dependencies, keyed by the path the compiler
+/// knows them by.
+pub type SourceFiles = BTreeMap<String, SourceFile>;
+
+impl CompilationCache {
+    /// Load the cache saved under `package_root`, the build directory of a package, if there is
+    /// one and it applies to a build with the configuration digest `config_digest`.
+    pub fn load(package_root: &Path, config_digest: &str) -> Option<Self> {
+        let bytes =
+            std::fs::read(package_root.join(CompiledPackageLayout::CompilationCache.path()))
+                .ok()?;
+        let cache = serde_yaml::from_slice::<Self>(&bytes).ok()?;
+        (cache.config_digest == config_digest).then_some(cache)
+    }
+
+    /// Record the modules of `package` as compiled from `sources`, along with the `warnings`
+    /// reported for each file.
+    pub fn new(
+        config_digest: String,
+        sources: &SourceFiles,
+        package: &CompiledPackage,
+        warnings: &BTreeMap<String, String>,
+    ) -> Self {
+        let interfaces = package
+            .all_compiled_units()
+            .map(|unit| (module_key(&unit.module), interface_digest(&unit.module)))
+            .collect::<BTreeMap<_, _>>();
+        let mut files = sources
+            .iter()
+            .map(|(path, source)| {
+                let file = CachedFile {
+                    package: source.package,
+                    source_digest: source.source_digest.clone(),
+                    contains_macro: source.contains_macro,
+                    modules: vec![],
+                    warnings: warnings.get(path).cloned().unwrap_or_default(),
+                };
+                (path.clone(), file)
+            })
+            .collect::<BTreeMap<_, _>>();
+        for unit in package.all_compiled_units_with_source() {
+            let Some(file) = files.get_mut(unit.source_path.to_string_lossy().as_ref()) else {
+                continue;
+            };
+            let module = &unit.unit.module;
+            file.modules.push(CachedModule {
+                id: module.self_id(),
+                address_name: unit.unit.address_name.map(|name| name.value),
+                interface_digest: interfaces[&module_key(module)].clone(),
+                dependencies: module
+                    .immediate_dependencies()
+                    .iter()
+                    .filter_map(|id| {
+                        let key = id.short_str_lossless();
+                        interfaces.get(&key).map(|digest| (key, digest.clone()))
+                    })
+                    .collect(),
+            });
+        }
+        Self {
+            config_digest,
+            files,
+        }
+    }
+
+    pub fn save(&self, package_root: &Path) -> Result<()> {
+        std::fs::write(
+            package_root.join(CompiledPackageLayout::CompilationCache.path()),
+            serde_yaml::to_string(self)?,
+        )?;
+        Ok(())
+    }
+
+    /// The files in `sources` that need to be compiled because they are new or have changed since
+    /// the cache was recorded, or `None` if nothing can be reused from the cache.
+    pub fn changed_files(&self, sources: &SourceFiles) -> Option<BTreeSet<String>> {
+        // Modules of deleted files may still be referred to by the others
+        if self.files.keys().any(|path| !sources.contains_key(path)) {
+            return None;
+        }
+        let mut changed = BTreeSet::new();
+        for (path, source) in sources {
+            match self.files.get(path) {
+                Some(file) if file.source_digest == source.source_digest => (),
+                Some(file) if file.contains_macro || source.contains_macro => return None,
+                None if source.contains_macro => return None,
+                _ => {
+                    changed.insert(path.clone());
+                }
+            }
+        }
+        Some(changed)
+    }
+
+    /// The interface digests of the modules compiled from files outside of `compiled`
+    pub fn interfaces(&self, compiled: &BTreeSet<String>) -> BTreeMap<String, String> {
+        self.files
+            .iter()
+            .filter(|(path, _)| !compiled.contains(*path))
+            .flat_map(|(_, file)| &file.modules)
+            .map(|module| {
+                (
+                    module.id.short_str_lossless(),
+                    module.interface_digest.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// The files outside of `compiled` that need to be compiled too, because one of their modules
+    /// was compiled against an interface that differs from the one in `interfaces`.
+    pub fn stale_files(
+        &self,
+        compiled: &BTreeSet<String>,
+        interfaces: &BTreeMap<String, String>,
+    ) -> BTreeSet<String> {
+        self.files
+            .iter()
+            .filter(|(path, _)| !compiled.contains(*path))
+            .filter(|(_, file)| {
+                file.modules.iter().any(|module| {
+                    module
+                        .dependencies
+                        .iter()
+                        .any(|(dep, digest)| interfaces.get(dep) != Some(digest))
+                })
+            })
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+}
+
+/// Digest the sources of the packages to be compiled.
+pub fn source_files(
+    sources: &PackagePaths,
+    deps: &[(PackagePaths, ModuleFormat)],
+) -> Result<SourceFiles> {
+    let mut files = SourceFiles::new();
+    let source_deps = deps.iter().filter_map(|(paths, format)| match format {
+        ModuleFormat::Source => Some(paths),
+        ModuleFormat::Bytecode => None,
+    });
+    for package_paths in std::iter::once(sources).chain(source_deps) {
+        let Some((package, _)) = &package_paths.name else {
+            continue;
+        };
+        for path in &package_paths.paths {
+            let contents = std::fs::read_to_string(path.as_str())?;
+            let file = SourceFile {
+                package: *package,
+                source_digest: digest_str(contents.as_bytes()),
+                contains_macro: contains_macro(&contents),
+            };
+            files.insert(path.to_string(), file);
+        }
+    }
+    Ok(files)
+}
+
+/// Whether `contents` contains the `macro` token outside of comments and strings. Files that do
+/// are conservatively assumed to declare macros, as are files that cannot be lexed.
+fn contains_macro(contents: &str) -> bool {
+    let mut lexer = Lexer::new(contents, FileHash::new(contents), Edition::E2024);
+    loop {
+        if lexer.advance().is_err() {
+            return true;
+        }
+        match lexer.peek() {
+            Tok::EOF => return false,
+            Tok::Identifier if lexer.content() == MACRO_MODIFIER => return true,
+            _ => (),
+        }
+    }
+}
+
+/// Render `warnings` grouped by the file they are reported in, keyed by its path, to be recorded
+/// in the cache.
+pub fn render_warnings(files: &MappedFiles, warnings: &Diagnostics) -> BTreeMap<String, String> {
+    let mut by_file = BTreeMap::<String, Diagnostics>::new();
+    for diag in warnings.clone().into_vec() {
+        let Some((path, _)) = files.get(&diag.primary_loc().file_hash()) else {
+            continue;
+        };
+        by_file.entry(path.to_string()).or_default().add(diag);
+    }
+    by_file
+        .into_iter()
+        .map(|(path, diags)| {
+            let rendered = report_diagnostics_to_buffer(files, diags, /* ansi_color */ false);
+            (path, String::from_utf8_lossy(&rendered).into_owned())
+        })
+        .collect()
+}
+
+/// Digest everything besides the sources of the packages that affects their compilation.
+pub fn config_digest(
+    build_config: &BuildConfig,
+    sources: &PackagePaths,
+    deps: &[(PackagePaths, ModuleFormat)],
+) -> Result<String> {
+    // custom lints are not serialized with the rest of the configuration
+    let mut config = format!(
+        "{}\n{}\n{:?}\n",
+        env!("CARGO_PKG_VERSION"),
+        serde_yaml::to_string(build_config)?,
+        build_config.custom_lints
+    );
+    for (package_paths, format) in std::iter::once((sources, &ModuleFormat::Source))
+        .chain(deps.iter().map(|(paths, format)| (paths, format)))
+    {
+        config.push_str(&format!(
+            "{:?} {:?}\n",
+            package_paths.name, package_paths.named_address_map
+        ));
+        if let ModuleFormat::Bytecode = format {
+            for path in &package_paths.paths {
+                config.push_str(&digest_str(&std::fs::read(path.as_str())?));
+            }
+        }
+    }
+    Ok(digest_str(config.as_bytes()))
+}
+
+/// The key a module is known by in the cache.
+pub fn module_key(module: &CompiledModule) -> String {
+    module.self_id().short_str_lossless()
+}
+
+/// Digest the parts of `module` that other modules can depend on: its friends, datatypes, and the
+/// signatures of its non-private functions. Modules that depend on `module` only need to be
+/// recompiled when this changes.
+pub fn interface_digest(module: &CompiledModule) -> String {
+    let normalized::Module {
+        friends,
+        structs,
+        enums,
+        functions,
+        ..
+    } = normalized::Module::new(module);
+    let functions = functions
+        .iter()
+        .filter(|(_, function)| function.visibility != Visibility::Private)
+        .map(|(name, function)| {
+            (
+                name,
+                function.visibility,
+                function.is_entry,
+                &function.type_parameters,
+                &function.parameters,
+                &function.return_,
+            )
+        })
+        .collect::<Vec<_>>();
+    digest_str(format!("{:?}", (friends, structs, enums, functions)).as_bytes())
+}
+
+/// Order `units` so that every module comes after the modules it depends on.
+pub fn sort_by_dependencies<T>(units: Vec<T>, module: impl Fn(&T) -> &CompiledModule) -> Vec<T> {
+    fn visit(idx: usize, deps: &[Vec<usize>], visited: &mut [bool], order: &mut Vec<usize>) {
+        if visited[idx] {
+            return;
+        }
+        visited[idx] = true;
+        for dep in &deps[idx] {
+            visit(*dep, deps, visited, order);
+        }
+        order.push(idx);
+    }
+
+    let indices = units
+        .iter()
+        .enumerate()
+        .map(|(idx, unit)| (module(unit).self_id(), idx))
+        .collect::<BTreeMap<_, _>>();
+    let deps = units
+        .iter()
+        .map(|unit| {
+            module(unit)
+                .immediate_dependencies()
+                .iter()
+                .filter_map(|id| indices.get(id).copied())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut visited = vec![false; units.len()];
+    let mut order = vec![];
+    for idx in 0..units.len() {
+        visit(idx, &deps, &mut visited, &mut order);
+    }
+    let mut units = units.into_iter().map(Some).collect::<Vec<_>>();
+    order
+        .into_iter()
+        .map(|idx| units[idx].take().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contains_macro, CompilationCache};
+    use crate::{compilation::package_layout::CompiledPackageLayout, BuildConfig};
+    use move_ir_types::location::Loc;
+    use std::{collections::BTreeSet, path::Path};
+
+    const MANIFEST: &str = r#"
+        [package]
+        name = "P"
+        edition = "2024"
+
+        [addresses]
+        p = "0x2"
+    "#;
+
+    const A: &str = "module p::a { public fun f(): u64 { 1 } public fun k(x: u8): u8 { x } }";
+    const B: &str = "module p::b { public fun g(): u64 { p::a::f() } }";
+    const C: &str = "module p::c { public fun h(): u64 { 3 } }";
+
+    fn write(root: &Path, file: &str, contents: &str) {
+        std::fs::write(root.join("sources").join(file), contents).unwrap();
+    }
+
+    fn new_package(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("Move.toml"), MANIFEST).unwrap();
+        std::fs::create_dir(dir.path().join("sources")).unwrap();
+        for (file, contents) in files {
+            write(dir.path(), file, contents);
+        }
+        dir
+    }
+
+    /// The names of the modules that the build of `root` compiled, rather than reused from the
+    /// previous build. Only the address names of compiled modules have a location in source.
+    fn compiled_modules(root: &Path, config: BuildConfig) -> BTreeSet<String> {
+        let package = config.compile_package(root, &mut Vec::new()).unwrap();
+        package
+            .root_compiled_units
+            .iter()
+            .filter(|unit| {
+                unit.unit
+                    .address_name
+                    .is_some_and(|name| name.loc != Loc::invalid())
+            })
+            .map(|unit| unit.unit.name.to_string())
+            .collect()
+    }
+
+    fn modules(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    fn load_cache(root: &Path) -> CompilationCache {
+        let path = root
+            .join(CompiledPackageLayout::Root.path())
+            .join("P")
+            .join(CompiledPackageLayout::CompilationCache.path());
+        serde_yaml::from_slice(&std::fs::read(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn body_change_reuses_cache() {
+        let dir = new_package(&[("a.move", A), ("b.move", B), ("c.move", C)]);
+        let root = dir.path();
+        let all = modules(&["a", "b", "c"]);
+        assert_eq!(compiled_modules(root, BuildConfig::default()), all);
+        assert!(compiled_modules(root, BuildConfig::default()).is_empty());
+
+        write(root, "a.move", &A.replace("{ 1 }", "{ 2 }"));
+        assert_eq!(
+            compiled_modules(root, BuildConfig::default()),
+            modules(&["a"])
+        );
+
+        // a forced build compiles everything
+        let force = BuildConfig {
+            force_recompilation: true,
+            ..Default::default()
+        };
+        assert_eq!(compiled_modules(root, force), all);
+    }
+
+    #[test]
+    fn signature_change_recompiles_dependents() {
+        let dir = new_package(&[("a.move", A), ("b.move", B), ("c.move", C)]);
+        let root = dir.path();
+        compiled_modules(root, BuildConfig::default());
+
+        // `b` depends on `a`, but `c` does not
+        write(root, "a.move", &A.replace("x: u8): u8", "x: u16): u16"));
+        assert_eq!(
+            compiled_modules(root, BuildConfig::default()),
+            modules(&["a", "b"])
+        );
+    }
+
+    #[test]
+    fn deleted_file_invalidates_cache() {
+        let dir = new_package(&[("a.move", A), ("b.move", B), ("c.move", C)]);
+        let root = dir.path();
+        compiled_modules(root, BuildConfig::default());
+
+        std::fs::remove_file(root.join("sources").join("c.move")).unwrap();
+        assert_eq!(
+            compiled_modules(root, BuildConfig::default()),
+            modules(&["a", "b"])
+        );
+    }
+
+    #[test]
+    fn macro_change_invalidates_cache() {
+        let d = "module p::d { public macro fun twice($x: u64): u64 { $x * 2 } }";
+        let dir = new_package(&[("a.move", A), ("c.move", C), ("d.move", d)]);
+        let root = dir.path();
+        let all = modules(&["a", "c", "d"]);
+        assert_eq!(compiled_modules(root, BuildConfig::default()), all);
+
+        write(root, "d.move", &d.replace("$x * 2", "$x + $x"));
+        assert_eq!(compiled_modules(root, BuildConfig::default()), all);
+    }
+
+    #[test]
+    fn config_change_invalidates_cache() {
+        let dir = new_package(&[("a.move", A), ("b.move", B), ("c.move", C)]);
+        let root = dir.path();
+        compiled_modules(root, BuildConfig::default());
+
+        let dev = BuildConfig {
+            dev_mode: true,
+            ..Default::default()
+        };
+        assert_eq!(compiled_modules(root, dev), modules(&["a", "b", "c"]));
+    }
+
+    #[test]
+    fn warnings_of_reused_files_are_cached() {
+        let c = "module p::c { public fun h(): u64 { let x = 3; 3 } }";
+        let dir = new_package(&[("a.move", A), ("c.move", c)]);
+        let root = dir.path();
+        compiled_modules(root, BuildConfig::default());
+        write(root, "a.move", &A.replace("{ 1 }", "{ 2 }"));
+        assert_eq!(
+            compiled_modules(root, BuildConfig::default()),
+            modules(&["a"])
+        );
+
+        let cache = load_cache(root);
+        let warnings = |file: &str| {
+            let (_, file) = cache
+                .files
+                .iter()
+                .find(|(path, _)| path.ends_with(file))
+                .unwrap();
+            file.warnings.clone()
+        };
+        assert!(warnings("c.move").contains("unused variable"));
+        assert!(warnings("a.move").is_empty());
+    }
+
+    #[test]
+    fn macro_tokens() {
+        assert!(contains_macro("module p::m { macro fun f() {} }"));
+        assert!(contains_macro("module p::m { public macro fun f() {} }"));
+        assert!(!contains_macro("module p::m { fun f() {} }"));
+        assert!(!contains_macro("// macro fun\nmodule p::m { fun f() {} }"));
+        assert!(!contains_macro(
+            "module p::m { fun f(): vector<u8> { b\"macro\" } }"
+        ));
+    }
+}
@@ -5,5 +5,7 @@
 
 pub mod build_plan;
 pub mod compiled_package;
+pub mod incremental;
 pub mod model_builder;
+pub mod module_abi;
 pub mod package_layout;
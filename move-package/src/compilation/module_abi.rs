@@ -0,0 +1,714 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_binary_format::{
+    file_format::{AbilitySet, DatatypeTyParameter, Visibility},
+    normalized, CompiledModule,
+};
+use move_compiler::{
+    expansion::ast::Fields,
+    naming::ast::{StructFields, VariantFields},
+    parser::ast::DocComment,
+    shared::known_attributes,
+};
+use move_core_types::{annotated_value::MoveValue, identifier::IdentStr};
+use move_model_2::source_model;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The version of the ABI format. It is bumped whenever a change is made to the format that
+/// clients need to account for.
+pub const ABI_VERSION: u32 = 1;
+
+/// The interface of a module, for generating client code that calls it. Only the items that can
+/// be used from outside the module are included, that is its datatypes, constants, and its
+/// public, package, and entry functions. Types are written the way `normalized::Type` displays
+/// them, with addresses in full and type parameters written `T<index>`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ModuleAbi {
+    pub abi_version: u32,
+    pub address: String,
+    pub name: String,
+    pub doc: Option<String>,
+    pub structs: Vec<StructAbi>,
+    pub enums: Vec<EnumAbi>,
+    pub functions: Vec<FunctionAbi>,
+    pub constants: Vec<ConstantAbi>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StructAbi {
+    pub name: String,
+    pub doc: Option<String>,
+    pub abilities: Vec<String>,
+    pub type_parameters: Vec<DatatypeTypeParameterAbi>,
+    pub fields: Vec<FieldAbi>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EnumAbi {
+    pub name: String,
+    pub doc: Option<String>,
+    pub abilities: Vec<String>,
+    pub type_parameters: Vec<DatatypeTypeParameterAbi>,
+    /// The variants of the enum, in the order of their tags.
+    pub variants: Vec<VariantAbi>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VariantAbi {
+    pub name: String,
+    pub doc: Option<String>,
+    pub fields: Vec<FieldAbi>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DatatypeTypeParameterAbi {
+    pub constraints: Vec<String>,
+    pub is_phantom: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldAbi {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FunctionAbi {
+    pub name: String,
+    pub doc: Option<String>,
+    /// One of `public`, `package`, or `private`.
+    pub visibility: String,
+    pub is_entry: bool,
+    /// The constraints on each type parameter.
+    pub type_parameters: Vec<Vec<String>>,
+    pub parameters: Vec<ParameterAbi>,
+    #[serde(rename = "return")]
+    pub return_: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParameterAbi {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConstantAbi {
+    pub name: String,
+    pub doc: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The value of the constant, as it is displayed in Move source.
+    pub value: String,
+    /// The index of the constant in the module's constant pool, which is the index a clever abort
+    /// code raised with the constant refers to it by.
+    pub constant_index: u16,
+    /// Whether the constant is annotated with `#[error]`, and so can be raised as a clever error.
+    pub is_error: bool,
+    /// The message of an `#[error]` constant, if its value is a UTF8 string.
+    pub error_message: Option<String>,
+}
+
+impl ModuleAbi {
+    pub fn new(module: source_model::Module) -> Self {
+        let info = module.info();
+        let normalized = normalized::Module::new(&module.compiled().module);
+        let mut abi = Self::from_normalized(&normalized);
+        abi.doc = doc_text(&info.doc);
+
+        for s in &mut abi.structs {
+            let Some(def) = info.structs.get_(&s.name.as_str().into()) else {
+                continue;
+            };
+            s.doc = doc_text(&def.doc);
+            if let StructFields::Defined(_, fields) = &def.fields {
+                document_fields(&mut s.fields, fields);
+            }
+        }
+
+        for e in &mut abi.enums {
+            let Some(def) = info.enums.get_(&e.name.as_str().into()) else {
+                continue;
+            };
+            e.doc = doc_text(&def.doc);
+            for v in &mut e.variants {
+                let Some(variant) = def.variants.get_(&v.name.as_str().into()) else {
+                    continue;
+                };
+                v.doc = doc_text(&variant.doc);
+                if let VariantFields::Defined(_, fields) = &variant.fields {
+                    document_fields(&mut v.fields, fields);
+                }
+            }
+        }
+
+        for f in &mut abi.functions {
+            let Some(source) = info.functions.get_(&f.name.as_str().into()) else {
+                continue;
+            };
+            f.doc = doc_text(&source.doc);
+            for (param, (_, var, _)) in f.parameters.iter_mut().zip(&source.signature.parameters) {
+                param.name = var.value.name.to_string();
+            }
+        }
+
+        // Not all source constants have a compiled representation, those that don't are left out
+        abi.constants = module
+            .named_constants()
+            .filter_map(|constant| {
+                let compiled = constant.compiled()?;
+                let info = constant.info();
+                let is_error = info
+                    .attributes
+                    .contains_key_(&known_attributes::ErrorAttribute.into());
+                let error_message = match compiled.value() {
+                    MoveValue::Vector(values) if is_error => values
+                        .iter()
+                        .map(|v| match v {
+                            MoveValue::U8(b) => Some(*b),
+                            _ => None,
+                        })
+                        .collect::<Option<Vec<_>>>()
+                        .and_then(|bytes| String::from_utf8(bytes).ok()),
+                    _ => None,
+                };
+                let index = compiled.def_idx.0;
+                Some(ConstantAbi {
+                    name: constant.name().to_string(),
+                    doc: doc_text(&info.doc),
+                    type_: normalized.constants[index as usize].type_.to_string(),
+                    value: compiled.display_value(),
+                    constant_index: index,
+                    is_error,
+                    error_message,
+                })
+            })
+            .collect();
+
+        abi
+    }
+
+    /// The interface of a compiled module without its source. Doc comments, parameter names, and
+    /// the names of constants are only known from source, so there are no docs, parameters are
+    /// named `arg<index>`, and constants are left out.
+    pub fn from_compiled(module: &CompiledModule) -> Self {
+        Self::from_normalized(&normalized::Module::new(module))
+    }
+
+    fn from_normalized(normalized: &normalized::Module) -> Self {
+        ModuleAbi {
+            abi_version: ABI_VERSION,
+            address: normalized.address.to_hex_literal(),
+            name: normalized.name.to_string(),
+            doc: None,
+            structs: normalized
+                .structs
+                .iter()
+                .map(|(name, s)| StructAbi::new(name, s))
+                .collect(),
+            enums: normalized
+                .enums
+                .iter()
+                .map(|(name, e)| EnumAbi::new(name, e))
+                .collect(),
+            functions: normalized
+                .functions
+                .iter()
+                .filter(|(_, f)| f.visibility != Visibility::Private || f.is_entry)
+                .map(|(name, f)| FunctionAbi::new(name, f))
+                .collect(),
+            constants: vec![],
+        }
+    }
+}
+
+impl StructAbi {
+    pub fn new(name: &IdentStr, s: &normalized::Struct) -> Self {
+        StructAbi {
+            name: name.to_string(),
+            doc: None,
+            abilities: abilities(s.abilities),
+            type_parameters: datatype_type_parameters(&s.type_parameters),
+            fields: s.fields.iter().map(FieldAbi::new).collect(),
+        }
+    }
+}
+
+impl EnumAbi {
+    pub fn new(name: &IdentStr, e: &normalized::Enum) -> Self {
+        EnumAbi {
+            name: name.to_string(),
+            doc: None,
+            abilities: abilities(e.abilities),
+            type_parameters: datatype_type_parameters(&e.type_parameters),
+            variants: e.variants.iter().map(VariantAbi::new).collect(),
+        }
+    }
+}
+
+impl VariantAbi {
+    pub fn new(v: &normalized::Variant) -> Self {
+        VariantAbi {
+            name: v.name.to_string(),
+            doc: None,
+            fields: v.fields.iter().map(FieldAbi::new).collect(),
+        }
+    }
+}
+
+impl FieldAbi {
+    pub fn new(f: &normalized::Field) -> Self {
+        FieldAbi {
+            name: f.name.to_string(),
+            type_: f.type_.to_string(),
+            doc: None,
+        }
+    }
+}
+
+impl FunctionAbi {
+    pub fn new(name: &IdentStr, f: &normalized::Function) -> Self {
+        FunctionAbi {
+            name: name.to_string(),
+            doc: None,
+            visibility: match f.visibility {
+                Visibility::Public => "public",
+                Visibility::Friend => "package",
+                Visibility::Private => "private",
+            }
+            .to_string(),
+            is_entry: f.is_entry,
+            type_parameters: f.type_parameters.iter().map(|c| abilities(*c)).collect(),
+            parameters: f
+                .parameters
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| ParameterAbi {
+                    name: format!("arg{i}"),
+                    type_: ty.to_string(),
+                })
+                .collect(),
+            return_: f.return_.iter().map(|ty| ty.to_string()).collect(),
+        }
+    }
+
+    /// The type parameters, parameter types, and return types of the function, e.g.
+    /// `<T0: copy>(u64, T0): (bool, T0)`.
+    pub fn signature(&self) -> String {
+        let mut out = String::new();
+        if !self.type_parameters.is_empty() {
+            let type_params = self
+                .type_parameters
+                .iter()
+                .enumerate()
+                .map(|(i, constraints)| format!("T{i}{}", constraints_text(constraints)))
+                .collect::<Vec<_>>();
+            out.push_str(&format!("<{}>", type_params.join(", ")));
+        }
+        let params = self
+            .parameters
+            .iter()
+            .map(|p| p.type_.as_str())
+            .collect::<Vec<_>>();
+        out.push_str(&format!("({})", params.join(", ")));
+        match self.return_.as_slice() {
+            [] => (),
+            [ty] => out.push_str(&format!(": {ty}")),
+            tys => out.push_str(&format!(": ({})", tys.join(", "))),
+        }
+        out
+    }
+}
+
+/// Summarizes the module in Move-like syntax, one item per line.
+impl fmt::Display for ModuleAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "module {}::{}", self.address, self.name)?;
+        for s in &self.structs {
+            writeln!(f, "    {s}")?;
+        }
+        for e in &self.enums {
+            writeln!(f, "    {e}")?;
+        }
+        for c in &self.constants {
+            writeln!(f, "    const {}: {} = {}", c.name, c.type_, c.value)?;
+        }
+        for function in &self.functions {
+            writeln!(f, "    {function}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for StructAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "struct {}{}{} {{ {} }}",
+            self.name,
+            type_parameters_text(&self.type_parameters),
+            has_abilities_text(&self.abilities),
+            fields_text(&self.fields)
+        )
+    }
+}
+
+impl fmt::Display for EnumAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variants = self
+            .variants
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "enum {}{}{} {{ {} }}",
+            self.name,
+            type_parameters_text(&self.type_parameters),
+            has_abilities_text(&self.abilities),
+            variants.join(", ")
+        )
+    }
+}
+
+impl fmt::Display for VariantAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {{ {} }}", self.name, fields_text(&self.fields))
+    }
+}
+
+impl fmt::Display for FieldAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.type_)
+    }
+}
+
+impl fmt::Display for FunctionAbi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.visibility.as_str() {
+            "public" => write!(f, "public ")?,
+            "package" => write!(f, "public(package) ")?,
+            _ => (),
+        }
+        if self.is_entry {
+            write!(f, "entry ")?;
+        }
+        write!(f, "fun {}{}", self.name, self.signature())
+    }
+}
+
+/// The fields of a struct or variant, separated by commas.
+pub fn fields_text(fields: &[FieldAbi]) -> String {
+    fields
+        .iter()
+        .map(|f| f.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn has_abilities_text(abilities: &[String]) -> String {
+    if abilities.is_empty() {
+        String::new()
+    } else {
+        format!(" has {}", abilities.join(", "))
+    }
+}
+
+fn constraints_text(constraints: &[String]) -> String {
+    if constraints.is_empty() {
+        String::new()
+    } else {
+        format!(": {}", constraints.join(" + "))
+    }
+}
+
+fn type_parameters_text(params: &[DatatypeTypeParameterAbi]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+    let params = params
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let phantom = if p.is_phantom { "phantom " } else { "" };
+            format!("{phantom}T{i}{}", constraints_text(&p.constraints))
+        })
+        .collect::<Vec<_>>();
+    format!("<{}>", params.join(", "))
+}
+
+/// The text of `doc`, without the space that conventionally follows `///` on each line.
+fn doc_text(doc: &DocComment) -> Option<String> {
+    let text = doc
+        .text()
+        .lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (!text.trim().is_empty()).then_some(text)
+}
+
+fn abilities(abilities: AbilitySet) -> Vec<String> {
+    abilities.into_iter().map(|a| a.to_string()).collect()
+}
+
+fn datatype_type_parameters(params: &[DatatypeTyParameter]) -> Vec<DatatypeTypeParameterAbi> {
+    params
+        .iter()
+        .map(|p| DatatypeTypeParameterAbi {
+            constraints: abilities(p.constraints),
+            is_phantom: p.is_phantom,
+        })
+        .collect()
+}
+
+/// Documents `fields` with the doc comments of the source fields they were compiled from.
+fn document_fields<T>(fields: &mut [FieldAbi], source: &Fields<(DocComment, T)>) {
+    for (_, _, (idx, (doc, _))) in source {
+        if let Some(field) = fields.get_mut(*idx) {
+            field.doc = doc_text(doc);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ModuleAbi;
+    use crate::{compilation::package_layout::CompiledPackageLayout, BuildConfig};
+    use expect_test::expect;
+    use std::{fs, path::Path};
+
+    const SOURCE: &str = r#"
+/// A module to describe.
+module abi::m {
+    /// A coin.
+    public struct Coin<phantom T> has store {
+        /// Its value.
+        value: u64,
+    }
+
+    public enum Shape has copy, drop {
+        /// A circle.
+        Circle { radius: u8 },
+        Point,
+    }
+
+    /// The value is too large.
+    #[error]
+    const ETooLarge: vector<u8> = b"Value is too large";
+
+    const MAX: u64 = 100;
+
+    /// The value of `coin`.
+    public fun value<T>(coin: &Coin<T>): u64 { coin.value }
+
+    public(package) fun split(amount: u64): (u64, u64) {
+        assert!(amount <= MAX, ETooLarge);
+        (amount / 2, amount - amount / 2)
+    }
+
+    entry fun run<T: copy + drop>(_x: T) { hidden(); }
+
+    fun hidden(): Shape { Shape::Circle { radius: 1 } }
+}
+"#;
+
+    fn write_package(root: &Path) {
+        fs::create_dir_all(root.join("sources")).unwrap();
+        fs::write(
+            root.join("Move.toml"),
+            "[package]\nname = \"Abi\"\nedition = \"2024\"\n\n[addresses]\nabi = \"0x2\"\n",
+        )
+        .unwrap();
+        fs::write(root.join("sources").join("m.move"), SOURCE).unwrap();
+    }
+
+    fn build(root: &Path, emit_abi: bool) -> Option<Vec<ModuleAbi>> {
+        let config = BuildConfig {
+            emit_abi,
+            ..Default::default()
+        };
+        config
+            .compile_package(root, &mut Vec::new())
+            .unwrap()
+            .compiled_abis
+    }
+
+    fn abi_path(root: &Path) -> std::path::PathBuf {
+        root.join(CompiledPackageLayout::Root.path())
+            .join("Abi")
+            .join(CompiledPackageLayout::CompiledAbis.path())
+            .join("m.json")
+    }
+
+    #[test]
+    fn json_output() {
+        let dir = tempfile::tempdir().unwrap();
+        write_package(dir.path());
+        let abis = build(dir.path(), true).unwrap();
+        let [abi] = &abis[..] else {
+            panic!("expected a single module")
+        };
+
+        let json = fs::read_to_string(abi_path(dir.path())).unwrap();
+        assert_eq!(json, serde_json::to_string_pretty(abi).unwrap());
+        expect![[r#"
+            {
+              "abi_version": 1,
+              "address": "0x2",
+              "name": "m",
+              "doc": "A module to describe.",
+              "structs": [
+                {
+                  "name": "Coin",
+                  "doc": "A coin.",
+                  "abilities": [
+                    "store"
+                  ],
+                  "type_parameters": [
+                    {
+                      "constraints": [],
+                      "is_phantom": true
+                    }
+                  ],
+                  "fields": [
+                    {
+                      "name": "value",
+                      "type": "u64",
+                      "doc": "Its value."
+                    }
+                  ]
+                }
+              ],
+              "enums": [
+                {
+                  "name": "Shape",
+                  "doc": null,
+                  "abilities": [
+                    "copy",
+                    "drop"
+                  ],
+                  "type_parameters": [],
+                  "variants": [
+                    {
+                      "name": "Circle",
+                      "doc": "A circle.",
+                      "fields": [
+                        {
+                          "name": "radius",
+                          "type": "u8",
+                          "doc": null
+                        }
+                      ]
+                    },
+                    {
+                      "name": "Point",
+                      "doc": null,
+                      "fields": []
+                    }
+                  ]
+                }
+              ],
+              "functions": [
+                {
+                  "name": "run",
+                  "doc": null,
+                  "visibility": "private",
+                  "is_entry": true,
+                  "type_parameters": [
+                    [
+                      "copy",
+                      "drop"
+                    ]
+                  ],
+                  "parameters": [
+                    {
+                      "name": "_x",
+                      "type": "T0"
+                    }
+                  ],
+                  "return": []
+                },
+                {
+                  "name": "split",
+                  "doc": null,
+                  "visibility": "package",
+                  "is_entry": false,
+                  "type_parameters": [],
+                  "parameters": [
+                    {
+                      "name": "amount",
+                      "type": "u64"
+                    }
+                  ],
+                  "return": [
+                    "u64",
+                    "u64"
+                  ]
+                },
+                {
+                  "name": "value",
+                  "doc": "The value of `coin`.",
+                  "visibility": "public",
+                  "is_entry": false,
+                  "type_parameters": [
+                    []
+                  ],
+                  "parameters": [
+                    {
+                      "name": "coin",
+                      "type": "&0x2::m::Coin<T0>"
+                    }
+                  ],
+                  "return": [
+                    "u64"
+                  ]
+                }
+              ],
+              "constants": [
+                {
+                  "name": "ETooLarge",
+                  "doc": "The value is too large.",
+                  "type": "vector<u8>",
+                  "value": "\"Value is too large\"",
+                  "constant_index": 1,
+                  "is_error": true,
+                  "error_message": "Value is too large"
+                },
+                {
+                  "name": "MAX",
+                  "doc": null,
+                  "type": "u64",
+                  "value": "100u64",
+                  "constant_index": 2,
+                  "is_error": false,
+                  "error_message": null
+                }
+              ]
+            }"#]]
+        .assert_eq(&json);
+
+        let parsed: ModuleAbi = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, abi);
+    }
+
+    #[test]
+    fn json_output_is_optional() {
+        let dir = tempfile::tempdir().unwrap();
+        write_package(dir.path());
+        assert!(build(dir.path(), false).is_none());
+        assert!(!abi_path(dir.path()).exists());
+
+        // The previous build of the unchanged package is not reused, as it has no ABIs, but this
+        // one is reused by the next build, which reads the ABIs back
+        let abis = build(dir.path(), true).unwrap();
+        assert!(abi_path(dir.path()).exists());
+        assert_eq!(build(dir.path(), true).unwrap(), abis);
+    }
+}
@@ -8,6 +8,7 @@ use std::path::{Path, PathBuf};
 #[derive(Debug, Clone)]
 pub enum CompiledPackageLayout {
     BuildInfo,
+    CompilationCache,
     Root,
     Dependencies,
     Sources,
@@ -15,6 +16,7 @@ pub enum CompiledPackageLayout {
     LockFiles,
     CompiledModules,
     CompiledDocs,
+    CompiledAbis,
     Disassembly,
 }
 
@@ -22,6 +24,7 @@ impl CompiledPackageLayout {
     pub fn path(&self) -> &Path {
         let path = match self {
             Self::BuildInfo => "BuildInfo.yaml",
+            Self::CompilationCache => "CompilationCache.yaml",
             Self::Root => "build",
             Self::Dependencies => "dependencies",
             Self::Sources => "sources",
@@ -29,6 +32,7 @@ impl CompiledPackageLayout {
             Self::LockFiles => "locks",
             Self::CompiledModules => "bytecode_modules",
             Self::CompiledDocs => "docs",
+            Self::CompiledAbis => "abi",
             Self::Disassembly => "disassembly",
         };
         Path::new(path)
@@ -39,7 +39,7 @@ use crate::{
     lock_file::schema::update_compiler_toolchain,
     package_lock::PackageLock,
 };
-use move_compiler::linters::LintLevel;
+use move_compiler::linters::{self, CustomLint, LintLevel};
 
 #[derive(Debug, Parser, Clone, Serialize, Deserialize, Eq, PartialEq, PartialOrd, Default)]
 #[clap(about)]
@@ -59,6 +59,12 @@ pub struct BuildConfig {
     #[clap(name = "generate-docs", long = "doc", global = true)]
     pub generate_docs: bool,
 
+    /// Generate a JSON description of the interface of each module in the package, for generating
+    /// client code
+    #[clap(name = "emit-abi", long = "emit-abi", global = true)]
+    #[serde(default)]
+    pub emit_abi: bool,
+
     /// Save disassembly for generated bytecode along with
     /// bytecode maps (source maps for disassembeld bytecode)
     #[clap(name = "save-disassembly", long = "disassemble", global = true)]
@@ -68,7 +74,8 @@ pub struct BuildConfig {
     #[clap(long = "install-dir", global = true)]
     pub install_dir: Option<PathBuf>,
 
-    /// Force recompilation of all packages
+    /// Force recompilation of all packages, rather than reusing modules from the last build whose
+    /// sources and dependency interfaces have not changed
     #[clap(name = "force-recompilation", long = "force", global = true)]
     pub force_recompilation: bool,
 
@@ -116,6 +123,11 @@ pub struct BuildConfig {
     #[clap(flatten)]
     pub lint_flag: LintFlag,
 
+    /// Additional linters to run along with the compiler's own, selected by the same lint level
+    #[clap(skip)]
+    #[serde(skip)]
+    pub custom_lints: Vec<CustomLint>,
+
     /// Additional dependencies to be automatically included in every package
     #[clap(skip)]
     pub implicit_dependencies: Dependencies,
@@ -134,7 +146,7 @@ pub struct LintFlag {
     )]
     no_lint: bool,
 
-    /// If `true`, enables extra linters
+    /// If `true`, enables extra linters, and lists the custom linters registered by the tool
     #[clap(name = "lint", long = "lint", global = true, group = "lint-level")]
     lint: bool,
 }
@@ -183,7 +195,7 @@ impl BuildConfig {
     pub fn compile_package<W: Write>(self, path: &Path, writer: &mut W) -> Result<CompiledPackage> {
         let resolved_graph = self.resolution_graph_for_package(path, None, writer)?;
         let _mutx = PackageLock::lock(); // held until function returns
-        BuildPlan::create(&resolved_graph)?.compile(writer, |compiler| compiler)
+        BuildPlan::create(&resolved_graph)?.compile_incremental(writer, |compiler| compiler)
     }
 
     /// Compile the package at `path` or the containing Move package. Exit process on warning or
@@ -206,7 +218,7 @@ impl BuildConfig {
         // } else {
         //     build_plan.compile(writer)
         // }
-        build_plan.compile(writer, |compiler| compiler)
+        build_plan.compile_incremental(writer, |compiler| compiler)
     }
 
     /// Compile the package at `path` or the containing Move package. Do not exit process on warning
@@ -267,6 +279,23 @@ impl BuildConfig {
         Ok(())
     }
 
+    pub fn vendor_deps_for_package<W: Write>(&self, path: &Path, writer: &mut W) -> Result<()> {
+        let path = SourcePackageLayout::try_find_root(path)?;
+        let manifest_string =
+            std::fs::read_to_string(path.join(SourcePackageLayout::Manifest.path()))?;
+        let lock_string = std::fs::read_to_string(path.join(SourcePackageLayout::Lock.path())).ok();
+        let _mutx = PackageLock::lock(); // held until function returns
+
+        resolution::vendor::vendor_dependency_repos(
+            manifest_string,
+            lock_string,
+            self,
+            &path,
+            writer,
+        )?;
+        Ok(())
+    }
+
     pub fn resolution_graph_for_package<W: Write>(
         mut self,
         path: &Path,
@@ -276,6 +305,13 @@ impl BuildConfig {
         if self.test_mode {
             self.dev_mode = true;
         }
+        if let Some((lint, duplicate)) = linters::duplicate_custom_lints(&self.custom_lints) {
+            return Err(anyhow!(
+                "Custom lints '{}' and '{}' have the same filter name or diagnostic code",
+                lint.filter_name(),
+                duplicate.filter_name()
+            ));
+        }
         let path = SourcePackageLayout::try_find_root(path)?;
         let manifest_string =
             std::fs::read_to_string(path.join(SourcePackageLayout::Manifest.path()))?;
@@ -292,6 +328,9 @@ impl BuildConfig {
             install_dir.clone(),
             self.implicit_dependencies.clone(),
         );
+        dep_graph_builder
+            .dependency_cache
+            .use_vendored_dependencies(&path)?;
         let (dependency_graph, modified) = dep_graph_builder.get_graph(
             &DependencyKind::default(),
             path,
@@ -113,6 +113,18 @@ pub struct ManagedPackage {
     pub version: String,
 }
 
+/// A dependency copied into the `vendor` directory of the package by `move vendor`, which is
+/// used in place of fetching it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VendoredPackage {
+    /// Package identifier (as resolved by the package hook).
+    pub id: String,
+    /// Where the copy of the package is found, relative to the package root.
+    pub path: String,
+    /// Expected hash for the source and manifest of the copy.
+    pub digest: String,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct Header {
     pub version: u16,
@@ -188,6 +200,26 @@ impl ManagedPackage {
     }
 }
 
+impl VendoredPackage {
+    /// Read the vendored packages from the lock file, or an empty list if there are none.
+    pub fn read(lock: &mut impl Read) -> Result<Vec<VendoredPackage>> {
+        let contents = {
+            let mut buf = String::new();
+            lock.read_to_string(&mut buf).context("Reading lock file")?;
+            buf
+        };
+
+        #[derive(Deserialize)]
+        struct Vendor {
+            vendor: Option<Vec<VendoredPackage>>,
+        }
+        let Schema { move_: value } = toml::de::from_str::<Schema<Vendor>>(&contents)
+            .context("Deserializing vendored packages")?;
+
+        Ok(value.vendor.unwrap_or_default())
+    }
+}
+
 impl Header {
     /// Read lock file header after verifying that the version of the lock is not newer than the version
     /// supported by this library.
@@ -313,6 +345,40 @@ pub fn update_compiler_toolchain(
     Ok(())
 }
 
+/// Replaces the `[[move.vendor]]` entries in the lock file with `packages`.
+pub fn update_vendored_packages(file: &mut LockFile, packages: &[VendoredPackage]) -> Result<()> {
+    use toml_edit::{value, Table};
+    let mut toml_string = String::new();
+    file.read_to_string(&mut toml_string)?;
+    let mut toml = toml_string.parse::<toml_edit::Document>()?;
+    let move_table = toml
+        .entry("move")
+        .or_insert(Item::Table(toml_edit::Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| anyhow!("Could not find or create move table in Move.lock"))?;
+
+    if packages.is_empty() {
+        move_table.remove("vendor");
+    } else {
+        let mut vendor = ArrayOfTables::new();
+        for package in packages {
+            let mut table = Table::new();
+            table["id"] = value(&package.id);
+            table["path"] = value(&package.path);
+            table["digest"] = value(&package.digest);
+            vendor.push(table);
+        }
+        move_table["vendor"] = Item::ArrayOfTables(vendor);
+    }
+
+    file.set_len(0)?;
+    file.rewind()?;
+    write!(file, "{}", toml)?;
+    file.flush()?;
+    file.rewind()?;
+    Ok(())
+}
+
 fn to_toml_edit_value(value: &toml::Value) -> toml_edit::Item {
     match value {
         Value::String(v) => EItem(EValue::from(v.clone())),
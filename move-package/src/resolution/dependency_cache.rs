@@ -2,22 +2,27 @@
 // Modifications Copyright (c) 2024 Digc Labs
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use colored::Colorize;
 use std::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     ffi::OsStr,
+    fs::File,
     io::Write,
-    path::PathBuf,
+    path::{Path, PathBuf},
     process::{Command, Stdio},
 };
 
 use crate::{
+    lock_file::schema::VendoredPackage,
     package_hooks,
-    source_package::parsed_manifest::{DependencyKind, GitInfo, PackageName},
+    source_package::{
+        layout::SourcePackageLayout,
+        parsed_manifest::{DependencyKind, GitInfo, PackageName},
+    },
 };
 
-use super::repository_path;
+use super::{digest::compute_digest, local_path, repository_path, vendored_path};
 
 /// Fetches remote dependencies and caches information about those already fetched when building a
 /// given package.
@@ -29,6 +34,13 @@ pub struct DependencyCache {
     /// Should a dependency fetched when building a different package be refreshed to the newest
     /// version when building a new package
     skip_fetch_latest_git_deps: bool,
+
+    /// The root of the package that dependencies have been vendored into
+    vendor_root: PathBuf,
+
+    /// The digests recorded in the lock file for the vendored copies of dependencies, keyed by
+    /// their path relative to `vendor_root`
+    vendored: BTreeMap<PathBuf, String>,
 }
 
 impl DependencyCache {
@@ -37,15 +49,55 @@ impl DependencyCache {
         DependencyCache {
             fetched_deps,
             skip_fetch_latest_git_deps,
+            vendor_root: PathBuf::new(),
+            vendored: BTreeMap::new(),
         }
     }
 
+    /// Use the copies of dependencies vendored into the package at `root_path` (as recorded in its
+    /// lock file) in place of fetching those dependencies.
+    pub fn use_vendored_dependencies(&mut self, root_path: &Path) -> Result<()> {
+        let Ok(mut lock) = File::open(root_path.join(SourcePackageLayout::Lock.path())) else {
+            return Ok(());
+        };
+        self.vendor_root = root_path.canonicalize()?;
+        self.vendored = VendoredPackage::read(&mut lock)?
+            .into_iter()
+            .map(|package| (PathBuf::from(package.path), package.digest))
+            .collect();
+        Ok(())
+    }
+
+    /// The path that the dependency of kind `kind` is found at locally: its vendored copy if there
+    /// is one, or where it is fetched to otherwise.
+    pub fn local_path(&self, kind: &DependencyKind) -> PathBuf {
+        match self.vendored_copy(kind) {
+            Some((path, _)) => path,
+            None => local_path(kind),
+        }
+    }
+
+    /// The path of the vendored copy of the dependency of kind `kind`, and its expected digest.
+    fn vendored_copy(&self, kind: &DependencyKind) -> Option<(PathBuf, String)> {
+        let path = vendored_path(kind)?;
+        let digest = self.vendored.get(&path)?;
+        Some((self.vendor_root.join(path), digest.clone()))
+    }
+
     pub fn download_and_update_if_remote<Progress: Write>(
         &mut self,
         dep_name: PackageName,
         kind: &DependencyKind,
         progress_output: &mut Progress,
     ) -> Result<()> {
+        if let Some((path, digest)) = self.vendored_copy(kind) {
+            // check a given vendored copy only once
+            if self.fetched_deps.insert(path.clone()) {
+                check_vendored_copy(dep_name, &path, &digest)?;
+            }
+            return Ok(());
+        }
+
         match kind {
             DependencyKind::Local(_) => Ok(()),
 
@@ -250,3 +302,30 @@ impl DependencyCache {
         }
     }
 }
+
+/// Check that the vendored copy of `dep_name` at `path` is present and matches the `digest` it was
+/// recorded with.
+fn check_vendored_copy(dep_name: PackageName, path: &Path, digest: &str) -> Result<()> {
+    if !path.exists() {
+        bail!(
+            "Vendored copy of '{}' is missing from '{}', run `move vendor` to restore it",
+            dep_name,
+            path.display(),
+        );
+    }
+
+    let found = compute_digest(&[path.to_path_buf()])?;
+    if found.as_str() != digest {
+        bail!(
+            "Vendored copy of '{}' at '{}' does not match the digest recorded in {}: expected {}, \
+             found {}. Run `move vendor` to vendor it again",
+            dep_name,
+            path.display(),
+            SourcePackageLayout::Lock.location_str(),
+            digest,
+            found,
+        );
+    }
+
+    Ok(())
+}
@@ -33,7 +33,6 @@ use crate::{
 use super::{
     dependency_cache::DependencyCache,
     digest::{digest_str, hashed_files_digest},
-    local_path,
 };
 
 /// A representation of the transitive dependency graph of a Move package.  If successfully created,
@@ -188,7 +187,7 @@ pub enum DependencyMode {
 struct PackageTOML<'a>(&'a Package);
 struct PackageWithResolverTOML<'a>(&'a Package);
 struct DependencyTOML<'a>(PackageIdentifier, &'a Dependency);
-struct SubstTOML<'a>(&'a PM::Substitution);
+pub(crate) struct SubstTOML<'a>(pub(crate) &'a PM::Substitution);
 
 /// A builder for `DependencyGraph`
 pub struct DependencyGraphBuilder<Progress: Write> {
@@ -497,7 +496,7 @@ impl<Progress: Write> DependencyGraphBuilder<Progress> {
                 self.dependency_cache
                     .download_and_update_if_remote(dep_pkg_name, &d.kind, &mut self.progress_output)
                     .with_context(|| format!("Fetching '{}'", dep_pkg_name))?;
-                let pkg_path = dep_pkg_path.join(local_path(&d.kind));
+                let pkg_path = dep_pkg_path.join(self.dependency_cache.local_path(&d.kind));
                 let manifest_string =
                     std::fs::read_to_string(pkg_path.join(SourcePackageLayout::Manifest.path()))
                         .with_context(|| format!("Parsing manifest for '{}'", dep_pkg_name))?;
@@ -1617,7 +1616,13 @@ impl DependencyGraph {
 
 impl fmt::Display for Package {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.kind {
+        self.kind.fmt(f)
+    }
+}
+
+impl fmt::Display for PM::DependencyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
             PM::DependencyKind::Local(local) => {
                 write!(f, "local = ")?;
                 f.write_str(&path_escape(local)?)?;
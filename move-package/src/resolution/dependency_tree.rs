@@ -0,0 +1,510 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Result};
+use move_symbol_pool::Symbol;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+use treeline::Tree;
+
+use crate::{
+    package_hooks::PackageIdentifier,
+    source_package::parsed_manifest::{Dependency, DependencyKind, PackageName, Substitution},
+};
+
+use super::{
+    dependency_graph::{DependencyMode, SubstTOML},
+    resolution_graph::ResolvedGraph,
+};
+
+/// The dependency graph of a package as it was resolved for a build, annotated with where each
+/// package comes from, and which of the dependencies declared for it were overridden.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyTree {
+    pub root: PackageIdentifier,
+    pub packages: BTreeMap<PackageIdentifier, TreePackage>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TreePackage {
+    /// The name of the package in its own manifest.
+    pub name: PackageName,
+    /// Where the package was found, or `None` for the root package.
+    pub source: Option<DependencyKind>,
+    /// The version resolved for the package by the version resolution hook.
+    pub version: Option<Symbol>,
+    pub dependencies: Vec<TreeDependency>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeDependency {
+    pub id: PackageIdentifier,
+    /// The name the dependency is given in the depending package's manifest.
+    pub name: PackageName,
+    pub dev_only: bool,
+    pub addr_subst: Option<Substitution>,
+    /// Whether the dependency is declared as an override.
+    pub is_override: bool,
+    /// The source declared for the dependency in the depending package's manifest, if a
+    /// different source was chosen for it by an override elsewhere in the graph.
+    pub overridden_source: Option<DependencyKind>,
+}
+
+/// Every path through a `DependencyTree` that leads to one of its packages.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyPaths {
+    pub package: PackageIdentifier,
+    pub source: Option<DependencyKind>,
+    /// The package whose override of `package` decided its source, if it was overridden.
+    pub override_from: Option<PackageIdentifier>,
+    pub paths: Vec<DependencyPath>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyPath {
+    /// The packages along the path, from the root package to the package it leads to.
+    pub packages: Vec<PackageIdentifier>,
+    /// The last dependency on the path, as declared by the last package before its end.
+    pub dependency: TreeDependency,
+}
+
+impl DependencyTree {
+    pub fn new(resolved: &ResolvedGraph) -> Result<Self> {
+        let graph = &resolved.graph;
+        let mode = if resolved.build_options.dev_mode {
+            DependencyMode::DevOnly
+        } else {
+            DependencyMode::Always
+        };
+
+        let mut packages = BTreeMap::new();
+        for (id, package) in &resolved.package_table {
+            let source = graph.package_table.get(id);
+            let manifest = &package.source_package;
+            let mut dependencies = vec![];
+            for (dep_id, dep, dep_pkg) in graph.immediate_dependencies(*id, mode) {
+                let declared = match dep.mode {
+                    DependencyMode::Always => manifest.dependencies.get(&dep.dep_name),
+                    DependencyMode::DevOnly => manifest.dev_dependencies.get(&dep.dep_name),
+                };
+
+                // Dependencies are declared relative to the depending package, so they need to be
+                // re-rooted to be compared to the source they were resolved to.
+                let (is_override, overridden_source) = match declared {
+                    Some(Dependency::Internal(declared)) => {
+                        let mut kind = declared.kind.clone();
+                        kind.reroot(&source.map(|p| p.kind.clone()).unwrap_or_default())?;
+                        let overridden = kind != dep_pkg.kind;
+                        (declared.dep_override, overridden.then_some(kind))
+                    }
+                    // Externally resolved and implicit dependencies are not declared with a source.
+                    Some(Dependency::External(_)) | None => (false, None),
+                };
+
+                dependencies.push(TreeDependency {
+                    id: dep_id,
+                    name: dep.dep_name,
+                    dev_only: dep.mode == DependencyMode::DevOnly,
+                    addr_subst: dep.subst.clone(),
+                    is_override,
+                    overridden_source,
+                });
+            }
+
+            packages.insert(
+                *id,
+                TreePackage {
+                    name: manifest.package.name,
+                    source: source.map(|p| p.kind.clone()),
+                    version: source.and_then(|p| p.version),
+                    dependencies,
+                },
+            );
+        }
+
+        Ok(DependencyTree {
+            root: graph.root_package_id,
+            packages,
+        })
+    }
+
+    /// Find every path from the root package to `package`, which is either the identifier of a
+    /// package in the tree or the name in its manifest.
+    pub fn why(&self, package: Symbol) -> Result<DependencyPaths> {
+        let id = if self.packages.contains_key(&package) {
+            package
+        } else if let Some((id, _)) = self.packages.iter().find(|(_, p)| p.name == package) {
+            *id
+        } else {
+            bail!(
+                "Package '{}' is not in the dependency graph of '{}'",
+                package,
+                self.root
+            )
+        };
+
+        let mut paths = vec![];
+        self.find_paths(id, &mut vec![self.root], &mut paths);
+        // Shorter paths first, so the override closest to the root is found first
+        paths.sort_by_key(|path| path.packages.len());
+
+        let override_from = paths
+            .iter()
+            .find(|path| path.dependency.is_override && path.dependency.overridden_source.is_none())
+            .map(|path| path.packages[path.packages.len() - 2]);
+
+        Ok(DependencyPaths {
+            package: id,
+            source: self.packages[&id].source.clone(),
+            override_from,
+            paths,
+        })
+    }
+
+    fn find_paths(
+        &self,
+        to: PackageIdentifier,
+        stack: &mut Vec<PackageIdentifier>,
+        paths: &mut Vec<DependencyPath>,
+    ) {
+        let from = *stack.last().unwrap();
+        for dep in &self.packages[&from].dependencies {
+            stack.push(dep.id);
+            if dep.id == to {
+                paths.push(DependencyPath {
+                    packages: stack.clone(),
+                    dependency: dep.clone(),
+                });
+            } else {
+                self.find_paths(to, stack, paths);
+            }
+            stack.pop();
+        }
+    }
+
+    /// Render the tree as text. The dependencies of a package are only listed the first time it
+    /// appears, later appearances are marked with `(*)`.
+    pub fn render(&self) -> String {
+        let mut expanded = BTreeSet::new();
+        self.render_subtree(self.root, None, &mut expanded)
+            .to_string()
+    }
+
+    fn render_subtree(
+        &self,
+        id: PackageIdentifier,
+        dep: Option<&TreeDependency>,
+        expanded: &mut BTreeSet<PackageIdentifier>,
+    ) -> Tree<String> {
+        let package = &self.packages[&id];
+        let mut label = self.package_text(id);
+        if let Some(dep) = dep {
+            label.push_str(&dependency_notes(dep));
+        }
+
+        if !expanded.insert(id) {
+            if !package.dependencies.is_empty() {
+                label.push_str(" (*)");
+            }
+            return Tree::root(label);
+        }
+
+        let mut tree = Tree::root(label);
+        for dep in &package.dependencies {
+            tree.push(self.render_subtree(dep.id, Some(dep), expanded));
+        }
+        tree
+    }
+
+    /// Render `paths` as text, explaining how the package they lead to was pulled in.
+    pub fn render_paths(&self, paths: &DependencyPaths) -> String {
+        let mut out = String::new();
+        if paths.package == self.root {
+            writeln!(out, "{} is the root package", paths.package).unwrap();
+            return out;
+        }
+
+        writeln!(
+            out,
+            "{} is depended on through {} path(s):",
+            self.package_text(paths.package),
+            paths.paths.len()
+        )
+        .unwrap();
+        for path in &paths.paths {
+            let packages = path
+                .packages
+                .iter()
+                .map(|id| id.as_str())
+                .collect::<Vec<_>>();
+            writeln!(
+                out,
+                "  {}{}",
+                packages.join(" -> "),
+                dependency_notes(&path.dependency)
+            )
+            .unwrap();
+        }
+
+        match paths.override_from {
+            Some(id) => writeln!(out, "Its source was chosen by the override in {id}").unwrap(),
+            None => writeln!(out, "It is not overridden").unwrap(),
+        }
+        out
+    }
+
+    /// Render the tree as a graph in the DOT language.
+    pub fn to_dot(&self) -> String {
+        self.dot(
+            self.packages
+                .iter()
+                .flat_map(|(id, package)| package.dependencies.iter().map(move |dep| (*id, dep))),
+        )
+    }
+
+    /// Render the part of the tree covered by `paths` as a graph in the DOT language.
+    pub fn paths_to_dot(&self, paths: &DependencyPaths) -> String {
+        let edges = paths
+            .paths
+            .iter()
+            .flat_map(|path| path.packages.windows(2))
+            .map(|edge| (edge[0], edge[1]))
+            .collect::<BTreeSet<_>>();
+        self.dot(edges.into_iter().filter_map(|(from, to)| {
+            let dep = self.packages[&from]
+                .dependencies
+                .iter()
+                .find(|dep| dep.id == to)?;
+            Some((from, dep))
+        }))
+    }
+
+    fn dot<'a>(
+        &'a self,
+        edges: impl IntoIterator<Item = (PackageIdentifier, &'a TreeDependency)>,
+    ) -> String {
+        let mut nodes = BTreeSet::from([self.root]);
+        let mut lines = vec![];
+        for (from, dep) in edges {
+            nodes.insert(dep.id);
+            let mut attrs = vec![];
+            let mut label = vec![];
+            if dep.is_override {
+                label.push("override".to_string());
+            }
+            if let Some(source) = &dep.overridden_source {
+                label.push(format!("overridden: {{ {} }}", source));
+                attrs.push("color=red".to_string());
+            }
+            if let Some(subst) = &dep.addr_subst {
+                label.push(format!("addr_subst = {}", SubstTOML(subst)));
+            }
+            if dep.dev_only {
+                attrs.push("style=dashed".to_string());
+            }
+            if !label.is_empty() {
+                attrs.push(format!("label={:?}", label.join("\n")));
+            }
+            let attrs = if attrs.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attrs.join(", "))
+            };
+            lines.push(format!(
+                "    {:?} -> {:?}{};",
+                from.as_str(),
+                dep.id.as_str(),
+                attrs
+            ));
+        }
+
+        let mut out = String::from("digraph dependencies {\n");
+        for id in nodes {
+            let package = &self.packages[&id];
+            let mut label = id.to_string();
+            if let Some(source) = &package.source {
+                write!(label, "\n{}", source).unwrap();
+            }
+            writeln!(out, "    {:?} [label={:?}];", id.as_str(), label).unwrap();
+        }
+        for line in lines {
+            writeln!(out, "{}", line).unwrap();
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// A package's identifier, followed by its name if that is different, and where it was found.
+    fn package_text(&self, id: PackageIdentifier) -> String {
+        let package = &self.packages[&id];
+        let mut text = id.to_string();
+        if package.name != id {
+            write!(text, " ({})", package.name).unwrap();
+        }
+        if let Some(source) = &package.source {
+            write!(text, " {{ {} }}", source).unwrap();
+        }
+        if let Some(version) = &package.version {
+            write!(text, " version {}", version).unwrap();
+        }
+        text
+    }
+}
+
+/// Notes on how `dep` was declared and resolved, to follow the package it refers to.
+fn dependency_notes(dep: &TreeDependency) -> String {
+    let mut notes = vec![];
+    if dep.name != dep.id {
+        notes.push(format!("as {}", dep.name));
+    }
+    if dep.dev_only {
+        notes.push("dev".to_string());
+    }
+    if dep.is_override {
+        notes.push("override".to_string());
+    }
+    if let Some(source) = &dep.overridden_source {
+        notes.push(format!("overridden, declared as {{ {} }}", source));
+    }
+    if let Some(subst) = &dep.addr_subst {
+        notes.push(format!("addr_subst = {}", SubstTOML(subst)));
+    }
+    if notes.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", notes.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DependencyTree;
+    use crate::BuildConfig;
+    use std::{fs, path::Path};
+
+    /// Writes a package named `name` into `root`, with `deps` and `dev_deps` as the contents of its
+    /// `[dependencies]` and `[dev-dependencies]` sections.
+    fn write_package(root: &Path, name: &str, deps: &str, dev_deps: &str) {
+        let dir = root.join(name);
+        fs::create_dir_all(dir.join("sources")).unwrap();
+        fs::write(
+            dir.join("Move.toml"),
+            format!(
+                "[package]\nname = \"{name}\"\nedition = \"2024\"\n\n\
+                 [dependencies]\n{deps}\n\n[dev-dependencies]\n{dev_deps}\n"
+            ),
+        )
+        .unwrap();
+    }
+
+    /// A package `Root` depending on `A` and `B`, which both depend on a package named `C`. `A`
+    /// declares a different copy of `C` than `B`, and `Root` overrides the choice between them.
+    /// `Root` also has a dev-dependency on `D`.
+    fn dependency_tree(dev_mode: bool) -> (tempfile::TempDir, DependencyTree) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_package(
+            root,
+            "Root",
+            "A = { local = \"../A\" }\n\
+             B = { local = \"../B\" }\n\
+             C = { local = \"../C\", override = true }",
+            "D = { local = \"../D\" }",
+        );
+        write_package(root, "A", "C = { local = \"../OldC\" }", "");
+        write_package(root, "B", "C = { local = \"../C\" }", "");
+        write_package(root, "C", "", "");
+        write_package(root, "D", "", "");
+        fs::rename(root.join("C"), root.join("OldC")).unwrap();
+        write_package(root, "C", "", "");
+
+        let config = BuildConfig {
+            dev_mode,
+            ..Default::default()
+        };
+        let resolved = config
+            .resolution_graph_for_package(&root.join("Root"), None, &mut Vec::new())
+            .unwrap();
+        let tree = DependencyTree::new(&resolved).unwrap();
+        (dir, tree)
+    }
+
+    #[test]
+    fn render_tree() {
+        let (_dir, tree) = dependency_tree(false);
+        assert_eq!(
+            tree.render(),
+            "Root\n\
+             ├── A { local = \"../A\" }\n\
+             |   └── C { local = \"../C\" } [overridden, declared as { local = \"../OldC\" }]\n\
+             ├── B { local = \"../B\" }\n\
+             |   └── C { local = \"../C\" }\n\
+             └── C { local = \"../C\" } [override]\n",
+        );
+
+        let dot = tree.to_dot();
+        assert!(dot.contains(
+            "\"A\" -> \"C\" [color=red, label=\"overridden: { local = \\\"../OldC\\\" }\"];"
+        ));
+        assert!(dot.contains("\"Root\" -> \"C\" [label=\"override\"];"));
+        assert!(!dot.contains("\"D\""));
+    }
+
+    #[test]
+    fn render_dev_dependencies() {
+        let (_dir, tree) = dependency_tree(true);
+        let rendered = tree.render();
+        assert!(rendered.ends_with("└── D { local = \"../D\" } [dev]\n"));
+        assert!(tree.to_dot().contains("\"Root\" -> \"D\" [style=dashed];"));
+    }
+
+    #[test]
+    fn paths_to_overridden_dependency() {
+        let (_dir, tree) = dependency_tree(true);
+        let paths = tree.why("C".into()).unwrap();
+        assert_eq!(
+            tree.render_paths(&paths),
+            "C { local = \"../C\" } is depended on through 3 path(s):\n  \
+             Root -> C [override]\n  \
+             Root -> A -> C [overridden, declared as { local = \"../OldC\" }]\n  \
+             Root -> B -> C\n\
+             Its source was chosen by the override in Root\n",
+        );
+
+        let dot = tree.paths_to_dot(&paths);
+        assert!(dot.contains("\"B\" -> \"C\";"));
+        assert!(!dot.contains("\"D\""));
+    }
+
+    #[test]
+    fn paths_to_dependency() {
+        let (_dir, tree) = dependency_tree(true);
+        let paths = tree.why("D".into()).unwrap();
+        assert_eq!(
+            tree.render_paths(&paths),
+            "D { local = \"../D\" } is depended on through 1 path(s):\n  \
+             Root -> D [dev]\n\
+             It is not overridden\n",
+        );
+
+        let paths = tree.why("Root".into()).unwrap();
+        assert_eq!(tree.render_paths(&paths), "Root is the root package\n");
+    }
+
+    #[test]
+    fn paths_to_unknown_package() {
+        let (_dir, tree) = dependency_tree(false);
+        let err = tree.why("E".into()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Package 'E' is not in the dependency graph of 'Root'"
+        );
+
+        // Dev-dependencies are only part of the graph in dev mode.
+        assert!(tree.why("D".into()).is_err());
+    }
+}
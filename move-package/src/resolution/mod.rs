@@ -11,7 +11,10 @@ use std::{
 };
 
 use crate::{
-    source_package::parsed_manifest::{DependencyKind, GitInfo, OnChainInfo},
+    source_package::{
+        layout::SourcePackageLayout,
+        parsed_manifest::{DependencyKind, GitInfo, OnChainInfo},
+    },
     BuildConfig,
 };
 
@@ -19,9 +22,11 @@ use self::dependency_graph::DependencyGraphBuilder;
 
 pub mod dependency_cache;
 pub mod dependency_graph;
-mod digest;
+pub mod dependency_tree;
+pub(crate) mod digest;
 pub mod resolution_graph;
 pub mod resolving_table;
+pub mod vendor;
 
 pub fn download_dependency_repos<Progress: Write>(
     manifest_string: String,
@@ -41,6 +46,9 @@ pub fn download_dependency_repos<Progress: Write>(
         install_dir,
         build_options.implicit_dependencies.clone(),
     );
+    dep_graph_builder
+        .dependency_cache
+        .use_vendored_dependencies(root_path)?;
     let (graph, _) = dep_graph_builder.get_graph(
         &DependencyKind::default(),
         root_path.to_path_buf(),
@@ -115,6 +123,26 @@ fn local_path(kind: &DependencyKind) -> PathBuf {
     repo_path
 }
 
+/// The path that the dependency of kind `kind` is copied to by `move vendor`, relative to the root
+/// of the depending package, or `None` if it is a local dependency (which are never vendored).
+/// Vendored packages are laid out as they are in MOVE_HOME, so that local dependencies between
+/// packages from the same repository still resolve between their vendored copies.
+pub fn vendored_path(kind: &DependencyKind) -> Option<PathBuf> {
+    if let DependencyKind::Local(_) = kind {
+        return None;
+    }
+
+    let mut path = SourcePackageLayout::Vendor
+        .path()
+        .join(repository_path(kind).file_name()?);
+
+    if let DependencyKind::Git(GitInfo { subdir, .. }) = kind {
+        path.push(subdir);
+    }
+
+    Some(path)
+}
+
 fn url_to_file_name(url: &str) -> String {
     regex::Regex::new(r"/|:|\.|@")
         .unwrap()
@@ -37,7 +37,7 @@ use crate::{
 };
 
 use super::{
-    dependency_cache::DependencyCache, dependency_graph as DG, digest::compute_digest, local_path,
+    dependency_cache::DependencyCache, dependency_graph as DG, digest::compute_digest,
     resolving_table::ResolvingTable,
 };
 
@@ -112,7 +112,7 @@ impl ResolvedGraph {
                 dependency_cache
                     .download_and_update_if_remote(pkg_id, &pkg.kind, progress_output)
                     .with_context(|| format!("Fetching '{pkg_id}'"))?;
-                graph.root_path.join(local_path(&pkg.kind))
+                graph.root_path.join(dependency_cache.local_path(&pkg.kind))
             };
 
             let mut resolved_pkg = Package::new(package_path, &build_options)
@@ -137,7 +137,9 @@ impl ResolvedGraph {
                             )
                             .with_context(|| format!("Fetching '{dep_name}'"))?;
 
-                        let dep_path = &resolved_pkg.package_path.join(local_path(&internal.kind));
+                        let dep_path = &resolved_pkg
+                            .package_path
+                            .join(dependency_cache.local_path(&internal.kind));
                         let dep_manifest = parse_move_manifest_from_file(dep_path)?;
                         if dep_name != &dep_manifest.package.name {
                             bail!(
@@ -0,0 +1,295 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::{
+    fs,
+    io::{Seek, Write},
+    path::Path,
+};
+
+use crate::{
+    lock_file::schema::{update_vendored_packages, VendoredPackage},
+    source_package::{layout::SourcePackageLayout, parsed_manifest::DependencyKind},
+    BuildConfig,
+};
+
+use super::{
+    dependency_graph::DependencyGraphBuilder, digest::compute_digest, local_path, vendored_path,
+};
+
+/// Copy every remote package in the dependency graph of the package at `root_path` (including its
+/// dev-dependencies) into the package's `vendor` directory, replacing any previously vendored
+/// copies, and record the digests of the copies in its lock file. Builds of the package then use
+/// these copies rather than fetching the dependencies.
+pub fn vendor_dependency_repos<Progress: Write>(
+    manifest_string: String,
+    lock_string: Option<String>,
+    build_options: &BuildConfig,
+    root_path: &Path,
+    progress_output: &mut Progress,
+) -> Result<()> {
+    let install_dir = build_options
+        .install_dir
+        .as_ref()
+        .unwrap_or(&root_path.to_path_buf())
+        .to_owned();
+    // Existing vendored copies are deliberately not used here, so that dependencies are always
+    // vendored from their source.
+    let mut dep_graph_builder = DependencyGraphBuilder::new(
+        build_options.skip_fetch_latest_git_deps,
+        progress_output,
+        install_dir.clone(),
+        build_options.implicit_dependencies.clone(),
+    );
+    let (graph, _) = dep_graph_builder.get_graph(
+        &DependencyKind::default(),
+        root_path.to_path_buf(),
+        manifest_string,
+        lock_string,
+    )?;
+
+    let DependencyGraphBuilder {
+        ref mut dependency_cache,
+        ref mut progress_output,
+        ..
+    } = dep_graph_builder;
+
+    // Fetch all the dependencies before touching existing vendored copies, so that they are left
+    // intact if fetching fails.
+    let mut to_vendor = vec![];
+    for (pkg_id, package) in &graph.package_table {
+        dependency_cache
+            .download_and_update_if_remote(*pkg_id, &package.kind, progress_output)
+            .with_context(|| format!("Fetching '{pkg_id}'"))?;
+        if let Some(path) = vendored_path(&package.kind) {
+            to_vendor.push((*pkg_id, root_path.join(local_path(&package.kind)), path));
+        }
+    }
+
+    let vendor_dir = root_path.join(SourcePackageLayout::Vendor.path());
+    if vendor_dir.exists() {
+        fs::remove_dir_all(&vendor_dir)
+            .with_context(|| format!("Removing '{}'", vendor_dir.display()))?;
+    }
+
+    for (pkg_id, from, to) in &to_vendor {
+        writeln!(progress_output, "{} {}", "VENDORING".bold().green(), pkg_id)?;
+        copy_package(from, &root_path.join(to)).with_context(|| format!("Vendoring '{pkg_id}'"))?;
+    }
+
+    // Packages from the same repository can be nested inside one another, so digests are only
+    // computed once all the copies are in place.
+    let mut vendored = vec![];
+    for (pkg_id, _, path) in to_vendor {
+        let digest = compute_digest(&[root_path.join(&path)])?;
+        vendored.push(VendoredPackage {
+            id: pkg_id.to_string(),
+            path: path.to_string_lossy().to_string(),
+            digest: digest.to_string(),
+        });
+    }
+
+    let lock_path = root_path.join(SourcePackageLayout::Lock.path());
+    let mut lock = graph.write_to_lock(install_dir, Some(lock_path.clone()))?;
+    lock.rewind()?;
+    update_vendored_packages(&mut lock, &vendored)?;
+    lock.commit(lock_path)?;
+    Ok(())
+}
+
+/// Copy the manifest, lock file, and source directories of the package at `from` to `to`.
+fn copy_package(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for layout in [
+        SourcePackageLayout::Manifest,
+        SourcePackageLayout::Lock,
+        SourcePackageLayout::Sources,
+        SourcePackageLayout::Tests,
+        SourcePackageLayout::Scripts,
+        SourcePackageLayout::Examples,
+        SourcePackageLayout::Specifications,
+        SourcePackageLayout::DocTemplates,
+    ] {
+        let path = from.join(layout.path());
+        if path.is_file() {
+            fs::copy(&path, to.join(layout.path()))?;
+            continue;
+        }
+
+        for entry in walkdir::WalkDir::new(&path)
+            .follow_links(true)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            let target = to.join(entry.path().strip_prefix(from)?);
+            if entry.file_type().is_dir() {
+                fs::create_dir_all(&target)?;
+            } else {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        compilation::compiled_package::CompiledPackage,
+        lock_file::{
+            schema::{update_vendored_packages, VendoredPackage},
+            LockFile,
+        },
+        resolution::{digest::compute_digest, vendored_path},
+        source_package::{
+            layout::SourcePackageLayout,
+            parsed_manifest::{DependencyKind, GitInfo},
+        },
+        BuildConfig,
+    };
+    use std::{
+        fs::{self, File},
+        path::{Path, PathBuf},
+    };
+
+    /// A dependency that cannot be fetched, so builds only succeed with its vendored copy.
+    const DEP_GIT_URL: &str = "https://example.invalid/dep.git";
+
+    fn write_package(root: &Path, manifest: &str, module: &str) {
+        fs::create_dir_all(root.join(SourcePackageLayout::Sources.path())).unwrap();
+        fs::write(root.join(SourcePackageLayout::Manifest.path()), manifest).unwrap();
+        fs::write(root.join("sources").join("m.move"), module).unwrap();
+    }
+
+    fn write_vendored_packages(root: &Path, packages: &[VendoredPackage]) {
+        let mut lock = LockFile::new(root.to_path_buf(), String::new(), String::new()).unwrap();
+        update_vendored_packages(&mut lock, packages).unwrap();
+        lock.commit(root.join(SourcePackageLayout::Lock.path()))
+            .unwrap();
+    }
+
+    fn read_vendored_packages(root: &Path) -> Vec<VendoredPackage> {
+        let mut lock = File::open(root.join(SourcePackageLayout::Lock.path())).unwrap();
+        VendoredPackage::read(&mut lock).unwrap()
+    }
+
+    /// Creates a package with a git dependency whose copy is vendored into it, returning the
+    /// package and the path of the copy.
+    fn package_with_vendored_dep() -> (tempfile::TempDir, PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write_package(
+            root,
+            &format!(
+                r#"
+                [package]
+                name = "Root"
+                edition = "2024"
+
+                [dependencies]
+                Dep = {{ git = "{DEP_GIT_URL}", rev = "main" }}
+
+                [addresses]
+                root = "0x2"
+                "#
+            ),
+            "module root::m { public fun f(): u64 { dep::m::g() } }",
+        );
+
+        let kind = DependencyKind::Git(GitInfo {
+            git_url: DEP_GIT_URL.into(),
+            git_rev: "main".into(),
+            subdir: PathBuf::new(),
+        });
+        let path = vendored_path(&kind).unwrap();
+        let copy = root.join(&path);
+        write_package(
+            &copy,
+            r#"
+            [package]
+            name = "Dep"
+            edition = "2024"
+
+            [addresses]
+            dep = "0x3"
+            "#,
+            "module dep::m { public fun g(): u64 { 1 } }",
+        );
+        let digest = compute_digest(&[copy.clone()]).unwrap();
+        write_vendored_packages(
+            root,
+            &[VendoredPackage {
+                id: "Dep".to_string(),
+                path: path.to_string_lossy().to_string(),
+                digest: digest.to_string(),
+            }],
+        );
+        (dir, copy)
+    }
+
+    fn build(root: &Path) -> anyhow::Result<CompiledPackage> {
+        BuildConfig::default().compile_package(root, &mut Vec::new())
+    }
+
+    #[test]
+    fn vendored_packages_in_lock_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let package = VendoredPackage {
+            id: "Dep".to_string(),
+            path: "vendor/dep".to_string(),
+            digest: "ABC".to_string(),
+        };
+        write_vendored_packages(root, &[package.clone(), package]);
+        let packages = read_vendored_packages(root);
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].id, "Dep");
+        assert_eq!(packages[0].path, "vendor/dep");
+        assert_eq!(packages[0].digest, "ABC");
+
+        write_vendored_packages(root, &[]);
+        assert!(read_vendored_packages(root).is_empty());
+    }
+
+    #[test]
+    fn offline_build_from_vendored_copy() {
+        let (dir, _) = package_with_vendored_dep();
+        let root = dir.path();
+        let package = build(root).unwrap();
+        let deps = package
+            .deps_compiled_units
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(deps, vec!["Dep"]);
+        // rebuilding keeps the vendored packages recorded in the lock file
+        assert_eq!(read_vendored_packages(root).len(), 1);
+        build(root).unwrap();
+    }
+
+    #[test]
+    fn modified_vendored_copy() {
+        let (dir, copy) = package_with_vendored_dep();
+        fs::write(
+            copy.join("sources").join("m.move"),
+            "module dep::m { public fun g(): u64 { 2 } }",
+        )
+        .unwrap();
+        let err = format!("{:#}", build(dir.path()).unwrap_err());
+        assert!(
+            err.contains("Vendored copy of 'Dep'") && err.contains("does not match the digest"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn missing_vendored_copy() {
+        let (dir, copy) = package_with_vendored_dep();
+        fs::remove_dir_all(copy).unwrap();
+        let err = format!("{:#}", build(dir.path()).unwrap_err());
+        assert!(err.contains("Vendored copy of 'Dep' is missing"), "{err}");
+    }
+}
@@ -20,6 +20,7 @@ pub enum SourcePackageLayout {
     Manifest,
     Lock,
     DocTemplates,
+    Vendor,
 }
 
 impl SourcePackageLayout {
@@ -32,7 +33,8 @@ impl SourcePackageLayout {
     /// ├── scripts        (optional)
     /// ├── specifications (optional)
     /// ├── doc_templates      (optional)
-    /// └── tests          (optional, test mode)
+    /// ├── tests          (optional, test mode)
+    /// └── vendor         (optional)
     pub fn path(&self) -> &Path {
         Path::new(self.location_str())
     }
@@ -63,6 +65,7 @@ impl SourcePackageLayout {
             Self::Examples => "examples",
             Self::Specifications => "specifications",
             Self::DocTemplates => "doc_templates",
+            Self::Vendor => "vendor",
         }
     }
 
@@ -74,6 +77,7 @@ impl SourcePackageLayout {
             | Self::Examples
             | Self::Specifications
             | Self::DocTemplates
+            | Self::Vendor
             | Self::Lock => true,
         }
     }
@@ -5,7 +5,10 @@
 
 use crate::{package_hooks, source_package::parsed_manifest as PM};
 use anyhow::{anyhow, bail, format_err, Context, Result};
-use move_compiler::editions::{Edition, Flavor};
+use move_compiler::{
+    editions::{Edition, Flavor},
+    formatter::{FormatConfig, ImportGrouping},
+};
 use move_core_types::account_address::{AccountAddress, AccountAddressParseError};
 use move_symbol_pool::symbol::Symbol;
 use std::{
@@ -21,6 +24,7 @@ const EMPTY_ADDR_STR: &str = "_";
 
 pub const PACKAGE_NAME: &str = "package";
 const BUILD_NAME: &str = "build";
+const FORMAT_NAME: &str = "format";
 const ADDRESSES_NAME: &str = "addresses";
 const DEV_ADDRESSES_NAME: &str = "dev-addresses";
 const DEPENDENCY_NAME: &str = "dependencies";
@@ -31,6 +35,7 @@ const EXTERNAL_RESOLVER_PREFIX: &str = "r";
 const KNOWN_NAMES: &[&str] = &[
     PACKAGE_NAME,
     BUILD_NAME,
+    FORMAT_NAME,
     ADDRESSES_NAME,
     DEV_ADDRESSES_NAME,
     DEPENDENCY_NAME,
@@ -86,6 +91,11 @@ pub fn parse_source_manifest(tval: TV) -> Result<PM::SourceManifest> {
                 .map(parse_build_info)
                 .transpose()
                 .context("Error parsing '[build]' section of manifest")?;
+            let format = table
+                .remove(FORMAT_NAME)
+                .map(parse_format_info)
+                .transpose()
+                .context("Error parsing '[format]' section of manifest")?;
             let dependencies = table
                 .remove(DEPENDENCY_NAME)
                 .map(parse_dependencies)
@@ -103,6 +113,7 @@ pub fn parse_source_manifest(tval: TV) -> Result<PM::SourceManifest> {
                 addresses,
                 dev_address_assignments,
                 build,
+                format,
                 dependencies,
                 dev_dependencies,
             })
@@ -242,6 +253,48 @@ pub fn parse_build_info(tval: TV) -> Result<PM::BuildInfo> {
     }
 }
 
+pub fn parse_format_info(tval: TV) -> Result<FormatConfig> {
+    match tval {
+        TV::Table(mut table) => {
+            warn_if_unknown_field_names(&table, &["max_width", "indent", "group_imports"]);
+            let mut config = FormatConfig::default();
+            if let Some(max_width) = table.remove("max_width") {
+                config.max_width = parse_format_size("max_width", max_width)?;
+            }
+            if let Some(indent) = table.remove("indent") {
+                config.indent = parse_format_size("indent", indent)?;
+            }
+            if let Some(group_imports) = table.remove("group_imports") {
+                config.group_imports = match group_imports.as_str() {
+                    Some(s) => ImportGrouping::from_str(s)?,
+                    None => bail!(
+                        "Invalid 'group_imports' value {}. Expected a string but found a {}",
+                        group_imports,
+                        group_imports.type_str()
+                    ),
+                };
+            }
+            Ok(config)
+        }
+        x => bail!(
+            "Malformed section in manifest {}. Expected a table, but encountered a {}",
+            x,
+            x.type_str()
+        ),
+    }
+}
+
+fn parse_format_size(field: &str, tval: TV) -> Result<usize> {
+    match tval.as_integer() {
+        Some(n) if n > 0 => Ok(n as usize),
+        _ => bail!(
+            "Invalid '{}' value {}. Expected a positive integer",
+            field,
+            tval
+        ),
+    }
+}
+
 pub fn parse_addresses(tval: TV) -> Result<PM::AddressDeclarations> {
     match tval {
         TV::Table(table) => {
@@ -5,7 +5,10 @@
 
 use anyhow::{bail, Result};
 
-use move_compiler::editions::{Edition, Flavor};
+use move_compiler::{
+    editions::{Edition, Flavor},
+    formatter::FormatConfig,
+};
 use move_core_types::account_address::AccountAddress;
 use move_symbol_pool::symbol::Symbol;
 use serde::{Deserialize, Serialize};
@@ -32,6 +35,7 @@ pub struct SourceManifest {
     pub addresses: Option<AddressDeclarations>,
     pub dev_address_assignments: Option<DevAddressDeclarations>,
     pub build: Option<BuildInfo>,
+    pub format: Option<FormatConfig>,
     pub dependencies: Dependencies,
     pub dev_dependencies: Dependencies,
 }
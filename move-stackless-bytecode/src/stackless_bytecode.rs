@@ -4,10 +4,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::function_target::FunctionTarget;
-use ethnum::U256;
+use ethnum::{I256, U256};
 use itertools::Itertools;
 use move_binary_format::file_format::CodeOffset;
-use move_core_types::u256;
+use move_core_types::{i256, u256};
 use move_model::{
     ast::TempIndex,
     model::{DatatypeId, FunId, GlobalEnv, ModuleId, QualifiedInstId, RefType, VariantId},
@@ -98,6 +98,12 @@ pub enum Constant {
     U16(u16),
     U32(u32),
     U256(U256),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    I256(I256),
 }
 
 impl From<&u256::U256> for Constant {
@@ -106,6 +112,12 @@ impl From<&u256::U256> for Constant {
     }
 }
 
+impl From<&i256::I256> for Constant {
+    fn from(n: &i256::I256) -> Constant {
+        Constant::I256(I256::from(n))
+    }
+}
+
 /// An operation -- target of a call. This contains user functions, builtin functions, and
 /// operators.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -183,6 +195,12 @@ pub enum Operation {
     Eq,
     Neq,
     CastU256,
+    CastI8,
+    CastI16,
+    CastI32,
+    CastI64,
+    CastI128,
+    CastI256,
 
     // Variants
     PackVariant(ModuleId, DatatypeId, VariantId, Vec<Type>),
@@ -230,6 +248,12 @@ impl Operation {
             Operation::CastU64 => true,
             Operation::CastU128 => true,
             Operation::CastU256 => true,
+            Operation::CastI8 => true,
+            Operation::CastI16 => true,
+            Operation::CastI32 => true,
+            Operation::CastI64 => true,
+            Operation::CastI128 => true,
+            Operation::CastI256 => true,
             Operation::Not => false,
             Operation::Add => true,
             Operation::Sub => true,
@@ -997,6 +1021,12 @@ impl fmt::Display for OperationDisplay<'_> {
             CastU64 => write!(f, "(u64)")?,
             CastU128 => write!(f, "(u128)")?,
             CastU256 => write!(f, "(u256)")?,
+            CastI8 => write!(f, "(i8)")?,
+            CastI16 => write!(f, "(i16)")?,
+            CastI32 => write!(f, "(i32)")?,
+            CastI64 => write!(f, "(i64)")?,
+            CastI128 => write!(f, "(i128)")?,
+            CastI256 => write!(f, "(i256)")?,
             Not => write!(f, "!")?,
 
             // Binary
@@ -1110,6 +1140,12 @@ impl fmt::Display for Constant {
             U64(x) => write!(f, "{}", x)?,
             U128(x) => write!(f, "{}", x)?,
             U256(x) => write!(f, "{}", x)?,
+            I8(x) => write!(f, "{}", x)?,
+            I16(x) => write!(f, "{}", x)?,
+            I32(x) => write!(f, "{}", x)?,
+            I64(x) => write!(f, "{}", x)?,
+            I128(x) => write!(f, "{}", x)?,
+            I256(x) => write!(f, "{}", x)?,
             Address(x) => write!(f, "0x{}", x.to_str_radix(16))?,
             ByteArray(x) => write!(f, "{:?}", x)?,
             AddressArray(x) => write!(
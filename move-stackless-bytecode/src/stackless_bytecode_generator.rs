@@ -460,6 +460,126 @@ impl<'a> StacklessBytecodeGenerator<'a> {
                 self.temp_count += 1;
             }
 
+            MoveBytecode::LdI8(number) => {
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I8));
+                self.code
+                    .push(Bytecode::Load(attr_id, temp_index, Constant::I8(*number)));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::LdI16(number) => {
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I16));
+                self.code
+                    .push(Bytecode::Load(attr_id, temp_index, Constant::I16(*number)));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::LdI32(number) => {
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I32));
+                self.code
+                    .push(Bytecode::Load(attr_id, temp_index, Constant::I32(*number)));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::LdI64(number) => {
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I64));
+                self.code
+                    .push(Bytecode::Load(attr_id, temp_index, Constant::I64(*number)));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::LdI128(number) => {
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I128));
+                self.code.push(Bytecode::Load(
+                    attr_id,
+                    temp_index,
+                    Constant::I128(**number),
+                ));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::LdI256(number) => {
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I256));
+                self.code.push(Bytecode::Load(
+                    attr_id,
+                    temp_index,
+                    Constant::from(&**number),
+                ));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::CastI8 => {
+                let operand_index = self.temp_stack.pop().unwrap();
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I8));
+                self.code
+                    .push(mk_unary(Operation::CastI8, temp_index, operand_index));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::CastI16 => {
+                let operand_index = self.temp_stack.pop().unwrap();
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I16));
+                self.code
+                    .push(mk_unary(Operation::CastI16, temp_index, operand_index));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::CastI32 => {
+                let operand_index = self.temp_stack.pop().unwrap();
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I32));
+                self.code
+                    .push(mk_unary(Operation::CastI32, temp_index, operand_index));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::CastI64 => {
+                let operand_index = self.temp_stack.pop().unwrap();
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I64));
+                self.code
+                    .push(mk_unary(Operation::CastI64, temp_index, operand_index));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::CastI128 => {
+                let operand_index = self.temp_stack.pop().unwrap();
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I128));
+                self.code
+                    .push(mk_unary(Operation::CastI128, temp_index, operand_index));
+                self.temp_count += 1;
+            }
+
+            MoveBytecode::CastI256 => {
+                let operand_index = self.temp_stack.pop().unwrap();
+                let temp_index = self.temp_count;
+                self.temp_stack.push(temp_index);
+                self.local_types.push(Type::Primitive(PrimitiveType::I256));
+                self.code
+                    .push(mk_unary(Operation::CastI256, temp_index, operand_index));
+                self.temp_count += 1;
+            }
+
             MoveBytecode::LdConst(idx) => {
                 let temp_index = self.temp_count;
                 self.temp_stack.push(temp_index);
@@ -1483,6 +1603,11 @@ impl<'a> StacklessBytecodeGenerator<'a> {
                 self.code
                     .push(Bytecode::VariantSwitch(attr_id, temp_index, labels));
             }
+            MoveBytecode::PackClosure(..)
+            | MoveBytecode::PackClosureGeneric(..)
+            | MoveBytecode::CallClosure(_) => {
+                panic!("Function values are not supported in stackless bytecode")
+            }
         }
     }
 
@@ -1524,6 +1649,12 @@ impl<'a> StacklessBytecodeGenerator<'a> {
             (Type::Primitive(PrimitiveType::U64), MoveValue::U64(b)) => Constant::U64(*b),
             (Type::Primitive(PrimitiveType::U128), MoveValue::U128(b)) => Constant::U128(*b),
             (Type::Primitive(PrimitiveType::U256), MoveValue::U256(b)) => Constant::U256(b.into()),
+            (Type::Primitive(PrimitiveType::I8), MoveValue::I8(b)) => Constant::I8(*b),
+            (Type::Primitive(PrimitiveType::I16), MoveValue::I16(b)) => Constant::I16(*b),
+            (Type::Primitive(PrimitiveType::I32), MoveValue::I32(b)) => Constant::I32(*b),
+            (Type::Primitive(PrimitiveType::I64), MoveValue::I64(b)) => Constant::I64(*b),
+            (Type::Primitive(PrimitiveType::I128), MoveValue::I128(b)) => Constant::I128(*b),
+            (Type::Primitive(PrimitiveType::I256), MoveValue::I256(b)) => Constant::I256(b.into()),
             (Type::Primitive(PrimitiveType::Address), MoveValue::Address(a)) => {
                 Constant::Address(move_model::addr_to_big_uint(a))
             }
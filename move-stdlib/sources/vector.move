@@ -178,6 +178,7 @@ public macro fun destroy<$T, $R: drop>($v: vector<$T>, $f: |$T| -> $R) {
     v.destroy_empty();
 }
 
+#[syntax(for)]
 /// Destroy the vector `v` by calling `f` on each element and then destroying the vector.
 /// Preserves the order of elements in the vector.
 public macro fun do<$T, $R: drop>($v: vector<$T>, $f: |$T| -> $R) {
@@ -187,12 +188,14 @@ public macro fun do<$T, $R: drop>($v: vector<$T>, $f: |$T| -> $R) {
     v.destroy_empty();
 }
 
+#[syntax(for)]
 /// Perform an action `f` on each element of the vector `v`. The vector is not modified.
 public macro fun do_ref<$T, $R: drop>($v: &vector<$T>, $f: |&$T| -> $R) {
     let v = $v;
     v.length().do!(|i| $f(&v[i]))
 }
 
+#[syntax(for)]
 /// Perform an action `f` on each element of the vector `v`.
 /// The function `f` takes a mutable reference to the element.
 public macro fun do_mut<$T, $R: drop>($v: &mut vector<$T>, $f: |&mut $T| -> $R) {
@@ -0,0 +1,412 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! A compact binary encoding of traces, in which events are written and read one at a time so
+//! that traces never need to be held in memory as a whole.
+//!
+//! A binary trace starts with `BINARY_TRACE_MAGIC` and the version of the trace format (as a
+//! little-endian `u64`), followed by a zstd-compressed stream of events. Each event is encoded
+//! with BCS and prefixed with the length of its encoding (as a little-endian `u32`). Unlike in
+//! JSON traces, values are encoded along with their types, so they are read back exactly.
+
+use crate::format::{MoveTrace, TraceEvent, TraceVersion, TRACE_VERSION};
+use serde::{
+    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    Deserializer,
+};
+use std::{
+    fmt,
+    io::{self, BufRead, BufReader, ErrorKind, Read, Write},
+};
+
+/// Magic number identifying binary traces
+pub const BINARY_TRACE_MAGIC: [u8; 8] = *b"MVTRACE\0";
+
+/// Writes events of a trace in the binary format.
+pub struct TraceWriter<W: Write> {
+    encoder: zstd::Encoder<'static, W>,
+}
+
+/// Reads events of a trace in the binary format, as an iterator over the events.
+pub struct TraceReader<R: Read> {
+    decoder: zstd::Decoder<'static, BufReader<R>>,
+    version: TraceVersion,
+    /// Whether the end of the trace or an error was reached
+    done: bool,
+}
+
+impl<W: Write> TraceWriter<W> {
+    /// Starts a trace of the current version.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(&BINARY_TRACE_MAGIC)?;
+        writer.write_all(&TRACE_VERSION.to_le_bytes())?;
+        Ok(Self {
+            encoder: zstd::Encoder::new(writer, zstd::DEFAULT_COMPRESSION_LEVEL)?,
+        })
+    }
+
+    pub fn write_event(&mut self, event: &TraceEvent) -> io::Result<()> {
+        let bytes = bcs::to_bytes(event).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        let len = u32::try_from(bytes.len())
+            .map_err(|_| io::Error::new(ErrorKind::InvalidData, "Trace event is too large"))?;
+        self.encoder.write_all(&len.to_le_bytes())?;
+        self.encoder.write_all(&bytes)
+    }
+
+    /// Completes the trace, returning the underlying writer.
+    pub fn finish(self) -> io::Result<W> {
+        let mut writer = self.encoder.finish()?;
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+impl<R: Read> TraceReader<R> {
+    /// Reads the header of a trace, failing if it is not a binary trace or if its version is not
+    /// the current one.
+    pub fn new(reader: R) -> io::Result<Self> {
+        let mut reader = BufReader::new(reader);
+        let mut magic = [0; BINARY_TRACE_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+        if magic != BINARY_TRACE_MAGIC {
+            return Err(io::Error::new(ErrorKind::InvalidData, "Not a binary trace"));
+        }
+        let mut version = [0; std::mem::size_of::<TraceVersion>()];
+        reader.read_exact(&mut version)?;
+        let version = TraceVersion::from_le_bytes(version);
+        check_version(version)?;
+        Ok(Self {
+            decoder: zstd::Decoder::with_buffer(reader)?,
+            version,
+            done: false,
+        })
+    }
+
+    pub fn version(&self) -> TraceVersion {
+        self.version
+    }
+
+    /// Reads the next event, if any.
+    fn read_event(&mut self) -> io::Result<Option<TraceEvent>> {
+        let mut len = [0; std::mem::size_of::<u32>()];
+        let mut read = 0;
+        while read < len.len() {
+            match self.decoder.read(&mut len[read..]) {
+                Ok(0) if read == 0 => return Ok(None),
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(n) => read += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => (),
+                Err(e) => return Err(e),
+            }
+        }
+        let mut bytes = vec![0; u32::from_le_bytes(len) as usize];
+        self.decoder.read_exact(&mut bytes)?;
+        bcs::from_bytes(&bytes)
+            .map(Some)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+    }
+}
+
+impl<R: Read> Iterator for TraceReader<R> {
+    type Item = io::Result<TraceEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let event = self.read_event().transpose();
+        self.done = !matches!(event, Some(Ok(_)));
+        event
+    }
+}
+
+impl MoveTrace {
+    /// Write the trace in the binary format.
+    pub fn to_binary(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = TraceWriter::new(writer)?;
+        for event in &self.events {
+            writer.write_event(event)?;
+        }
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Read back a trace saved in the binary format.
+    pub fn from_binary(reader: impl Read) -> io::Result<Self> {
+        let reader = TraceReader::new(reader)?;
+        Ok(Self {
+            version: reader.version(),
+            events: reader.collect::<io::Result<_>>()?,
+        })
+    }
+
+    /// Read back a trace saved in either the JSON or the binary format.
+    pub fn read(reader: impl Read) -> io::Result<Self> {
+        let mut reader = BufReader::new(reader);
+        if is_binary(&mut reader)? {
+            Self::from_binary(reader)
+        } else {
+            Ok(Self::from_json(reader)?)
+        }
+    }
+}
+
+/// Returns whether a trace is in the binary format, without consuming any of it.
+pub fn is_binary(reader: &mut impl BufRead) -> io::Result<bool> {
+    Ok(reader.fill_buf()?.starts_with(&BINARY_TRACE_MAGIC))
+}
+
+/// Converts a trace in the JSON format to the binary format, one event at a time.
+pub fn json_to_binary(reader: impl Read, writer: impl Write) -> io::Result<()> {
+    let mut writer = TraceWriter::new(writer)?;
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    JsonTraceSeed(&mut writer).deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Converts a trace in the binary format to the JSON format, one event at a time.
+pub fn binary_to_json(reader: impl Read, mut writer: impl Write) -> io::Result<()> {
+    let reader = TraceReader::new(reader)?;
+    // follows the layout of `MoveTrace::to_json`
+    write!(writer, "{{\"version\":{},\"events\":[", reader.version())?;
+    for (idx, event) in reader.enumerate() {
+        if idx > 0 {
+            writer.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut writer, &event?)?;
+    }
+    writer.write_all(b"]}")?;
+    writer.flush()
+}
+
+fn check_version(version: TraceVersion) -> io::Result<()> {
+    if version != TRACE_VERSION {
+        return Err(io::Error::new(
+            ErrorKind::InvalidData,
+            format!("Unsupported trace version {version} (expected version {TRACE_VERSION})"),
+        ));
+    }
+    Ok(())
+}
+
+/// Deserializes a JSON trace, writing its events as they are read. As fields of traces written by
+/// `MoveTrace::to_json` are sorted, the version may only be checked after the events are written.
+struct JsonTraceSeed<'a, W: Write>(&'a mut TraceWriter<W>);
+
+struct JsonEventsSeed<'a, W: Write>(&'a mut TraceWriter<W>);
+
+impl<'de, W: Write> DeserializeSeed<'de> for JsonTraceSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for JsonTraceSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Move trace")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut version = false;
+        let mut events = false;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "version" if version => return Err(A::Error::duplicate_field("version")),
+                "events" if events => return Err(A::Error::duplicate_field("events")),
+                "version" => {
+                    let v = map.next_value::<TraceVersion>()?;
+                    check_version(v).map_err(A::Error::custom)?;
+                    version = true;
+                }
+                "events" => {
+                    map.next_value_seed(JsonEventsSeed(&mut *self.0))?;
+                    events = true;
+                }
+                _ => return Err(A::Error::unknown_field(&key, &["version", "events"])),
+            }
+        }
+        if !version {
+            return Err(A::Error::missing_field("version"));
+        }
+        if !events {
+            return Err(A::Error::missing_field("events"));
+        }
+        Ok(())
+    }
+}
+
+impl<'de, W: Write> DeserializeSeed<'de> for JsonEventsSeed<'_, W> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, W: Write> Visitor<'de> for JsonEventsSeed<'_, W> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of trace events")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        while let Some(event) = seq.next_element::<TraceEvent>()? {
+            self.0.write_event(&event).map_err(A::Error::custom)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{binary_to_json, is_binary, json_to_binary, BINARY_TRACE_MAGIC};
+    use crate::format::{
+        Effect, Location, MoveTrace, MoveTraceBuilder, TraceValue, TypeTagWithRefs, Write,
+        TRACE_VERSION,
+    };
+    use move_binary_format::file_format::{Bytecode, FunctionDefinitionIndex};
+    use move_core_types::{
+        account_address::AccountAddress,
+        annotated_value::{MoveStruct, MoveValue},
+        identifier::Identifier,
+        language_storage::{ModuleId, StructTag, TypeTag},
+        u256::U256,
+    };
+    use std::{cell::RefCell, io, rc::Rc, str::FromStr};
+
+    /// A buffer which can still be read after a streaming builder took ownership of it.
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl io::Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Records the same events with a given builder, with values of all kinds.
+    fn record(builder: &mut MoveTraceBuilder) {
+        let value = MoveValue::Struct(MoveStruct {
+            type_: StructTag::from_str("0x1::m::S").unwrap(),
+            fields: vec![
+                (Identifier::new("a").unwrap(), MoveValue::U8(1)),
+                (Identifier::new("b").unwrap(), MoveValue::I16(-2)),
+                (
+                    Identifier::new("c").unwrap(),
+                    MoveValue::U256(U256::max_value()),
+                ),
+                (
+                    Identifier::new("d").unwrap(),
+                    MoveValue::Signer(AccountAddress::ONE),
+                ),
+            ],
+        });
+        let frame_id = builder.current_trace_offset();
+        builder.open_frame(
+            frame_id,
+            FunctionDefinitionIndex(0),
+            "f".to_string(),
+            ModuleId::new(AccountAddress::ONE, Identifier::new("m").unwrap()),
+            vec![TraceValue::RuntimeValue {
+                value: value.clone(),
+            }],
+            vec![TypeTag::U8],
+            vec![],
+            vec![TypeTagWithRefs {
+                type_: TypeTag::U64,
+                ref_type: None,
+            }],
+            false,
+            10,
+        );
+        builder.instruction(
+            &Bytecode::StLoc(0),
+            vec![],
+            vec![Effect::Write(Write {
+                location: Location::Local(frame_id, 0),
+                root_value_after_write: TraceValue::MutRef {
+                    location: Location::Indexed(Box::new(Location::Global(3)), 1),
+                    snapshot: Box::new(value),
+                },
+            })],
+            9,
+            0,
+        );
+        builder.effect(Effect::ExecutionError("ABORTED".to_string()));
+        builder.close_frame(frame_id, vec![], 8);
+    }
+
+    fn expected_trace() -> MoveTrace {
+        let mut builder = MoveTraceBuilder::new();
+        record(&mut builder);
+        builder.into_trace()
+    }
+
+    #[test]
+    fn streamed_trace_round_trip() {
+        let buffer = SharedBuffer::default();
+        let mut builder = MoveTraceBuilder::new_streaming(Box::new(buffer.clone())).unwrap();
+        record(&mut builder);
+        // frame ids are offsets in the whole trace, including the events already streamed
+        assert_eq!(builder.current_trace_offset(), 5);
+        builder.finish().unwrap();
+
+        let bytes = buffer.0.borrow().clone();
+        assert!(is_binary(&mut bytes.as_slice()).unwrap());
+        // values are read back exactly
+        assert_eq!(
+            MoveTrace::from_binary(bytes.as_slice()).unwrap(),
+            expected_trace()
+        );
+        assert_eq!(MoveTrace::read(bytes.as_slice()).unwrap(), expected_trace());
+    }
+
+    #[test]
+    fn trace_conversions() {
+        let trace = expected_trace();
+        let mut binary = vec![];
+        trace.to_binary(&mut binary).unwrap();
+
+        let mut json = vec![];
+        binary_to_json(binary.as_slice(), &mut json).unwrap();
+        assert!(!is_binary(&mut json.as_slice()).unwrap());
+        let json_value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json_value, trace.to_json());
+
+        let mut converted = vec![];
+        json_to_binary(json.as_slice(), &mut converted).unwrap();
+        let converted = MoveTrace::read(converted.as_slice()).unwrap();
+        assert_eq!(converted.events.len(), trace.events.len());
+        // values read from JSON lose their integer widths
+        assert_eq!(converted, MoveTrace::read(json.as_slice()).unwrap());
+    }
+
+    #[test]
+    fn invalid_binary_traces() {
+        assert!(MoveTrace::from_binary(&b"MVTRACE"[..]).is_err());
+        assert!(MoveTrace::from_binary(&b"NOTATRACE_________"[..]).is_err());
+
+        let mut wrong_version = BINARY_TRACE_MAGIC.to_vec();
+        wrong_version.extend((TRACE_VERSION + 1).to_le_bytes());
+        let err = MoveTrace::from_binary(wrong_version.as_slice()).unwrap_err();
+        assert!(err.to_string().contains("Unsupported trace version"));
+
+        let mut truncated = vec![];
+        expected_trace().to_binary(&mut truncated).unwrap();
+        truncated.truncate(truncated.len() - 4);
+        assert!(MoveTrace::from_binary(truncated.as_slice()).is_err());
+    }
+}
@@ -4,7 +4,11 @@
 
 // IDEA: Post trace analysis -- report when values are dropped.
 
-use crate::interface::{NopTracer, Tracer, Writer};
+use crate::{
+    binary::TraceWriter,
+    interface::{NopTracer, Tracer, Writer},
+    value_serde,
+};
 use move_binary_format::{
     file_format::{Bytecode, FunctionDefinitionIndex as BinaryFunctionDefinitionIndex},
     file_format_common::instruction_opcode,
@@ -13,8 +17,8 @@ use move_core_types::{
     annotated_value::MoveValue,
     language_storage::{ModuleId, TypeTag},
 };
-use serde::Serialize;
-use std::fmt::Display;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
 
 /// An index into the trace. This should be used when referring to locations in the trace.
 /// Otherwise, a `usize` should be used when referring to indices that are not in the trace.
@@ -22,14 +26,14 @@ pub type TraceIndex = usize;
 pub type TraceVersion = u64;
 
 /// The current version of the trace format.
-const TRACE_VERSION: TraceVersion = 1;
+pub const TRACE_VERSION: TraceVersion = 1;
 
 /// A Location is a valid root for a reference. This can either be a local in a frame, a stack
 /// value, or a reference into another location (e.g., vec[0][2]).
 ///
 /// Note that we track aliasing through the locations so you can always trace back to the root
 /// value for the reference.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Location {
     // Local index in a frame. The frame is identified by the index in the trace where it was created.
     // The `usize` is the index into the locals of the frame.
@@ -44,7 +48,7 @@ pub enum Location {
 
 /// A Read event. This represents a read from a location, with the value read and whether the value
 /// was moved or not.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Read {
     pub location: Location,
     pub root_value_read: TraceValue,
@@ -54,7 +58,7 @@ pub struct Read {
 /// A Write event. This represents a write to a location with the value written and a snapshot of
 /// the value that was written. Note that the `root_value_after_write` is a snapshot of the
 /// _entire_ (root) value that was written after the write.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Write {
     pub location: Location,
     pub root_value_after_write: TraceValue,
@@ -63,24 +67,27 @@ pub struct Write {
 /// A TraceValue is a value in the standard MoveValue domain + references.
 /// References hold their own snapshot of the root value they point to, along with the rooted path to
 /// the value that they reference within that snapshot.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TraceValue {
     RuntimeValue {
+        #[serde(with = "value_serde::move_value")]
         value: MoveValue,
     },
     ImmRef {
         location: Location,
         // Snapshot of the root value.
+        #[serde(with = "value_serde::boxed_move_value")]
         snapshot: Box<MoveValue>,
     },
     MutRef {
         location: Location,
         // Snapshot of the root value.
+        #[serde(with = "value_serde::boxed_move_value")]
         snapshot: Box<MoveValue>,
     },
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum RefType {
     Imm,
     Mut,
@@ -89,14 +96,14 @@ pub enum RefType {
 /// Type tag with references. This is a type tag that also supports references.
 /// if ref_type is None, this is a value type. If ref_type is Some, this is a reference type of the
 /// given reference type.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct TypeTagWithRefs {
     pub type_: TypeTag,
     pub ref_type: Option<RefType>,
 }
 
 /// A `Frame` represents a stack frame in the Move VM and a given instantiation of a function.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Frame {
     // The frame id is the offset in the trace where this frame was opened.
     pub frame_id: TraceIndex,
@@ -113,7 +120,7 @@ pub struct Frame {
 
 /// An instruction effect is a single effect of an instruction. This can be a push/pop of a value
 /// or a reference to a value, or a read/write of a value.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Effect {
     // Pop a value off the stack (pre-effect only)
     Pop(TraceValue),
@@ -135,16 +142,17 @@ pub enum Effect {
 /// Represent a data load event. This is a load of a value from storage. We only record loads by
 /// reference in the trace, and we snapshot the value at the reference location at the time of load
 /// and record its global reference ID (i.e., the location in the trace at which it was loaded).
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct DataLoad {
     pub ref_type: RefType,
     pub location: Location,
+    #[serde(with = "value_serde::move_value")]
     pub snapshot: MoveValue,
 }
 
 /// A TraceEvent is a single event in the Move VM, external events can also be interleaved in the
 /// trace. MoveVM events, are well structured, and can be a frame event or an instruction event.
-#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TraceEvent {
     OpenFrame {
         frame: Box<Frame>,
@@ -162,10 +170,10 @@ pub enum TraceEvent {
         instruction: Box<String>,
     },
     Effect(Box<Effect>),
-    External(Box<serde_json::Value>),
+    External(#[serde(with = "value_serde::json_value")] Box<serde_json::Value>),
 }
 
-#[derive(Serialize, Debug, Clone, Eq, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
 pub struct MoveTrace {
     pub version: TraceVersion,
     pub events: Vec<TraceEvent>,
@@ -178,6 +186,18 @@ pub struct MoveTraceBuilder {
     pub tracer: Box<dyn Tracer>,
 
     pub trace: MoveTrace,
+
+    // Where events are streamed to rather than being kept in `trace`, if set.
+    sink: Option<TraceSink>,
+}
+
+/// Events streamed out of a `MoveTraceBuilder` in the binary format.
+struct TraceSink {
+    writer: TraceWriter<Box<dyn std::io::Write>>,
+    // The number of events streamed so far.
+    streamed: usize,
+    // The first error that occurred while streaming, after which events are dropped.
+    error: Option<std::io::Error>,
 }
 
 impl TraceValue {
@@ -216,6 +236,19 @@ impl MoveTrace {
     pub fn to_json(&self) -> serde_json::Value {
         serde_json::to_value(self).unwrap()
     }
+
+    /// Read back a trace saved in the JSON format of `to_json`. Values are read without their
+    /// layouts, so integer widths are not preserved (see `value_serde`).
+    pub fn from_json(reader: impl std::io::Read) -> serde_json::Result<Self> {
+        let trace: Self = serde_json::from_reader(reader)?;
+        if trace.version != TRACE_VERSION {
+            return Err(serde::de::Error::custom(format!(
+                "Unsupported trace version {} (expected version {TRACE_VERSION})",
+                trace.version
+            )));
+        }
+        Ok(trace)
+    }
 }
 
 impl Default for MoveTrace {
@@ -230,6 +263,7 @@ impl MoveTraceBuilder {
         Self {
             tracer: Box::new(NopTracer),
             trace: MoveTrace::new(),
+            sink: None,
         }
     }
 
@@ -238,7 +272,36 @@ impl MoveTraceBuilder {
         Self {
             tracer,
             trace: MoveTrace::new(),
+            sink: None,
+        }
+    }
+
+    /// Create a new `MoveTraceBuilder` which streams events to `writer` in the binary format as
+    /// they are emitted, rather than keeping them in memory. The trace must be completed with
+    /// `finish`.
+    pub fn new_streaming(writer: Box<dyn std::io::Write>) -> std::io::Result<Self> {
+        Ok(Self {
+            tracer: Box::new(NopTracer),
+            trace: MoveTrace::new(),
+            sink: Some(TraceSink {
+                writer: TraceWriter::new(writer)?,
+                streamed: 0,
+                error: None,
+            }),
+        })
+    }
+
+    /// Complete the trace streamed by a `MoveTraceBuilder` created with `new_streaming`, returning
+    /// the first error that occurred while streaming it. This does nothing for other builders.
+    pub fn finish(self) -> std::io::Result<()> {
+        let Some(sink) = self.sink else {
+            return Ok(());
+        };
+        if let Some(e) = sink.error {
+            return Err(e);
         }
+        sink.writer.finish()?;
+        Ok(())
     }
 
     /// Consume the `MoveTraceBuilder` and return the `MoveTrace` that has been built by it.
@@ -248,7 +311,7 @@ impl MoveTraceBuilder {
 
     /// Get the current offset in the `MoveTrace` that is being built.
     pub fn current_trace_offset(&self) -> TraceIndex {
-        self.trace.events.len()
+        self.sink.as_ref().map_or(0, |sink| sink.streamed) + self.trace.events.len()
     }
 
     /// Record an `OpenFrame` event in the trace.
@@ -314,10 +377,18 @@ impl MoveTraceBuilder {
     }
 
     // All events pushed to the trace are first pushed, and then the tracer is notified of the
-    // event.
+    // event. When streaming, the event is then written out along with any event the tracer pushed.
     fn push_event(&mut self, event: TraceEvent) {
         self.trace.events.push(event.clone());
         self.tracer.notify(&event, Writer(&mut self.trace));
+        if let Some(sink) = &mut self.sink {
+            for event in self.trace.events.drain(..) {
+                sink.streamed += 1;
+                if sink.error.is_none() {
+                    sink.error = sink.writer.write_event(&event).err();
+                }
+            }
+        }
     }
 }
 
@@ -359,6 +430,39 @@ impl Display for Location {
     }
 }
 
+/// Parses locations in the format they are displayed in, e.g., `l3@17`, `g42`, or `l0@5[2][1]`.
+impl FromStr for Location {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid location '{s}'");
+        let (root, mut path) = s
+            .split_once('[')
+            .map_or((s, ""), |(r, _)| (r, &s[r.len()..]));
+        let mut location = if let Some(local) = root.strip_prefix('l') {
+            let (idx, frame_idx) = local.split_once('@').ok_or_else(invalid)?;
+            Location::Local(
+                frame_idx.parse().map_err(|_| invalid())?,
+                idx.parse().map_err(|_| invalid())?,
+            )
+        } else if let Some(id) = root.strip_prefix('g') {
+            Location::Global(id.parse().map_err(|_| invalid())?)
+        } else {
+            return Err(invalid());
+        };
+        while !path.is_empty() {
+            let (offset, rest) = path
+                .strip_prefix('[')
+                .and_then(|p| p.split_once(']'))
+                .ok_or_else(invalid)?;
+            location =
+                Location::Indexed(Box::new(location), offset.parse().map_err(|_| invalid())?);
+            path = rest;
+        }
+        Ok(location)
+    }
+}
+
 impl Display for Effect {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -399,3 +503,37 @@ impl Display for Effect {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Location;
+
+    #[test]
+    fn location_round_trip() {
+        let nested = Location::Indexed(
+            Box::new(Location::Indexed(Box::new(Location::Local(5, 0)), 2)),
+            1,
+        );
+        for (text, location) in [
+            ("l3@17", Location::Local(17, 3)),
+            ("g42", Location::Global(42)),
+            ("l0@5[2][1]", nested),
+            ("g1[0]", Location::Indexed(Box::new(Location::Global(1)), 0)),
+        ] {
+            assert_eq!(text.parse::<Location>().unwrap(), location);
+            assert_eq!(location.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn invalid_locations() {
+        for text in [
+            "", "l3", "l@1", "lx@1", "g", "x1", "g1[", "g1[a]", "g1[0]x", "l0@1]",
+        ] {
+            assert!(
+                text.parse::<Location>().is_err(),
+                "'{text}' should be invalid"
+            );
+        }
+    }
+}
@@ -2,6 +2,8 @@
 // Modifications Copyright (c) 2024 Digc Labs
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod binary;
 pub mod format;
 pub mod interface;
 pub mod memory_tracer;
+mod value_serde;
@@ -42,7 +42,7 @@ impl TraceState {
     }
 
     /// Apply an event to the state machine and update the locals state accordingly.
-    fn apply_event(&mut self, event: &TraceEvent) {
+    pub fn apply_event(&mut self, event: &TraceEvent) {
         match event {
             TraceEvent::OpenFrame { frame, .. } => {
                 let mut locals = BTreeMap::new();
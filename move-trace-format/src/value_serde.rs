@@ -0,0 +1,465 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! (De)serialization of the `MoveValue`s embedded in traces.
+//!
+//! In human-readable formats (i.e., JSON), values are serialized the way `MoveValue` serializes
+//! them, without their layouts, so reading them back is best-effort: integers come back as `U64`
+//! (or `U128` if they do not fit), negative integers as `I64` (or `I128`), `u256`s and `i256`s as
+//! vectors of their bytes, and signers as addresses.
+//! Structs and enum variants keep their types and field order.
+//!
+//! In binary formats, values are serialized along with the kind of each value (see
+//! `TaggedValue`), so they are read back exactly.
+
+use move_core_types::{
+    account_address::AccountAddress,
+    annotated_value::{MoveStruct, MoveValue, MoveVariant},
+    i256::I256,
+    identifier::Identifier,
+    language_storage::StructTag,
+    u256::U256,
+};
+use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    ser::SerializeTupleVariant,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{fmt, str::FromStr};
+
+/// With `arbitrary_precision`, `serde_json` hands numbers to visitors as a single-entry map
+/// keyed by this token, whose value is the number as a string.
+const JSON_NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+const TYPE: &str = "type";
+const FIELDS: &str = "fields";
+const VARIANT_NAME: &str = "variant_name";
+const VARIANT_TAG: &str = "variant_tag";
+
+const TAGGED_VALUE_NAME: &str = "TaggedValue";
+
+pub(crate) mod move_value {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &MoveValue,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            value.serialize(serializer)
+        } else {
+            TaggedValueRef(value).serialize(serializer)
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<MoveValue, D::Error> {
+        if deserializer.is_human_readable() {
+            Ok(UntypedValue::deserialize(deserializer)?.0)
+        } else {
+            Ok(TaggedValue::deserialize(deserializer)?.into())
+        }
+    }
+}
+
+pub(crate) mod boxed_move_value {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &MoveValue,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        move_value::serialize(value, serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Box<MoveValue>, D::Error> {
+        move_value::deserialize(deserializer).map(Box::new)
+    }
+}
+
+/// Values of external events, which are serialized as JSON text in binary formats.
+pub(crate) mod json_value {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        value: &serde_json::Value,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            value.serialize(serializer)
+        } else {
+            serializer.serialize_str(&value.to_string())
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Box<serde_json::Value>, D::Error> {
+        if deserializer.is_human_readable() {
+            Box::<serde_json::Value>::deserialize(deserializer)
+        } else {
+            let text = String::deserialize(deserializer)?;
+            serde_json::from_str(&text).map_err(D::Error::custom)
+        }
+    }
+}
+
+struct UntypedValue(MoveValue);
+
+struct UntypedFields(Vec<(Identifier, MoveValue)>);
+
+fn number<E: Error>(n: &str) -> Result<MoveValue, E> {
+    if let Ok(n) = n.parse::<u64>() {
+        Ok(MoveValue::U64(n))
+    } else if let Ok(n) = n.parse::<u128>() {
+        Ok(MoveValue::U128(n))
+    } else if let Ok(n) = n.parse::<i64>() {
+        Ok(MoveValue::I64(n))
+    } else if let Ok(n) = n.parse::<i128>() {
+        Ok(MoveValue::I128(n))
+    } else {
+        Err(E::custom(format!("invalid Move integer '{n}'")))
+    }
+}
+
+impl<'de> Deserialize<'de> for UntypedValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(UntypedValueVisitor)
+    }
+}
+
+struct UntypedValueVisitor;
+
+impl<'de> Visitor<'de> for UntypedValueVisitor {
+    type Value = UntypedValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a Move value")
+    }
+
+    fn visit_bool<E: Error>(self, b: bool) -> Result<Self::Value, E> {
+        Ok(UntypedValue(MoveValue::Bool(b)))
+    }
+
+    fn visit_u64<E: Error>(self, n: u64) -> Result<Self::Value, E> {
+        Ok(UntypedValue(MoveValue::U64(n)))
+    }
+
+    fn visit_u128<E: Error>(self, n: u128) -> Result<Self::Value, E> {
+        Ok(UntypedValue(MoveValue::U128(n)))
+    }
+
+    fn visit_i64<E: Error>(self, n: i64) -> Result<Self::Value, E> {
+        Ok(UntypedValue(MoveValue::I64(n)))
+    }
+
+    fn visit_i128<E: Error>(self, n: i128) -> Result<Self::Value, E> {
+        Ok(UntypedValue(MoveValue::I128(n)))
+    }
+
+    fn visit_str<E: Error>(self, s: &str) -> Result<Self::Value, E> {
+        AccountAddress::from_str(s)
+            .map(|a| UntypedValue(MoveValue::Address(a)))
+            .map_err(|_| E::custom(format!("invalid Move address '{s}'")))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elems = vec![];
+        while let Some(UntypedValue(elem)) = seq.next_element()? {
+            elems.push(elem);
+        }
+        Ok(UntypedValue(MoveValue::Vector(elems)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut type_ = None;
+        let mut fields = None;
+        let mut variant_name = None;
+        let mut variant_tag = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                JSON_NUMBER_TOKEN => {
+                    let n = map.next_value::<String>()?;
+                    return number(&n).map(UntypedValue);
+                }
+                TYPE => {
+                    let tag = map.next_value::<String>()?;
+                    type_ = Some(StructTag::from_str(&tag).map_err(A::Error::custom)?);
+                }
+                FIELDS => fields = Some(map.next_value::<UntypedFields>()?.0),
+                VARIANT_NAME => variant_name = Some(map.next_value::<Identifier>()?),
+                VARIANT_TAG => variant_tag = Some(map.next_value::<u16>()?),
+                _ => return Err(A::Error::unknown_field(&key, &[TYPE, FIELDS])),
+            }
+        }
+        let type_ = type_.ok_or_else(|| A::Error::missing_field(TYPE))?;
+        let fields = fields.ok_or_else(|| A::Error::missing_field(FIELDS))?;
+        Ok(UntypedValue(match (variant_name, variant_tag) {
+            (None, None) => MoveValue::Struct(MoveStruct { type_, fields }),
+            (Some(variant_name), Some(tag)) => MoveValue::Variant(MoveVariant {
+                type_,
+                variant_name,
+                tag,
+                fields,
+            }),
+            (None, Some(_)) => return Err(A::Error::missing_field(VARIANT_NAME)),
+            (Some(_), None) => return Err(A::Error::missing_field(VARIANT_TAG)),
+        }))
+    }
+}
+
+impl<'de> Deserialize<'de> for UntypedFields {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(UntypedFieldsVisitor)
+    }
+}
+
+struct UntypedFieldsVisitor;
+
+impl<'de> Visitor<'de> for UntypedFieldsVisitor {
+    type Value = UntypedFields;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of Move fields")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = vec![];
+        while let Some((name, UntypedValue(value))) = map.next_entry()? {
+            fields.push((name, value));
+        }
+        Ok(UntypedFields(fields))
+    }
+}
+
+/// A value along with its kind. The variants must be kept in sync with `TaggedValueRef`, which
+/// serializes values without copying them.
+#[derive(Deserialize)]
+enum TaggedValue {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    U256(U256),
+    Bool(bool),
+    Address(AccountAddress),
+    Signer(AccountAddress),
+    Vector(Vec<TaggedValue>),
+    Struct(StructTag, Vec<(Identifier, TaggedValue)>),
+    Variant(StructTag, Identifier, u16, Vec<(Identifier, TaggedValue)>),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    I256(I256),
+}
+
+struct TaggedValueRef<'a>(&'a MoveValue);
+
+struct TaggedElemsRef<'a>(&'a [MoveValue]);
+
+struct TaggedFieldsRef<'a>(&'a [(Identifier, MoveValue)]);
+
+impl From<TaggedValue> for MoveValue {
+    fn from(value: TaggedValue) -> Self {
+        let fields = |fields: Vec<(Identifier, TaggedValue)>| {
+            fields
+                .into_iter()
+                .map(|(name, value)| (name, value.into()))
+                .collect()
+        };
+        match value {
+            TaggedValue::U8(n) => MoveValue::U8(n),
+            TaggedValue::U16(n) => MoveValue::U16(n),
+            TaggedValue::U32(n) => MoveValue::U32(n),
+            TaggedValue::U64(n) => MoveValue::U64(n),
+            TaggedValue::U128(n) => MoveValue::U128(n),
+            TaggedValue::U256(n) => MoveValue::U256(n),
+            TaggedValue::I8(n) => MoveValue::I8(n),
+            TaggedValue::I16(n) => MoveValue::I16(n),
+            TaggedValue::I32(n) => MoveValue::I32(n),
+            TaggedValue::I64(n) => MoveValue::I64(n),
+            TaggedValue::I128(n) => MoveValue::I128(n),
+            TaggedValue::I256(n) => MoveValue::I256(n),
+            TaggedValue::Bool(b) => MoveValue::Bool(b),
+            TaggedValue::Address(a) => MoveValue::Address(a),
+            TaggedValue::Signer(a) => MoveValue::Signer(a),
+            TaggedValue::Vector(elems) => {
+                MoveValue::Vector(elems.into_iter().map(MoveValue::from).collect())
+            }
+            TaggedValue::Struct(type_, f) => MoveValue::Struct(MoveStruct {
+                type_,
+                fields: fields(f),
+            }),
+            TaggedValue::Variant(type_, variant_name, tag, f) => MoveValue::Variant(MoveVariant {
+                type_,
+                variant_name,
+                tag,
+                fields: fields(f),
+            }),
+        }
+    }
+}
+
+impl Serialize for TaggedValueRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = TAGGED_VALUE_NAME;
+        match self.0 {
+            MoveValue::U8(n) => serializer.serialize_newtype_variant(name, 0, "U8", n),
+            MoveValue::U16(n) => serializer.serialize_newtype_variant(name, 1, "U16", n),
+            MoveValue::U32(n) => serializer.serialize_newtype_variant(name, 2, "U32", n),
+            MoveValue::U64(n) => serializer.serialize_newtype_variant(name, 3, "U64", n),
+            MoveValue::U128(n) => serializer.serialize_newtype_variant(name, 4, "U128", n),
+            MoveValue::U256(n) => serializer.serialize_newtype_variant(name, 5, "U256", n),
+            MoveValue::Bool(b) => serializer.serialize_newtype_variant(name, 6, "Bool", b),
+            MoveValue::Address(a) => serializer.serialize_newtype_variant(name, 7, "Address", a),
+            MoveValue::Signer(a) => serializer.serialize_newtype_variant(name, 8, "Signer", a),
+            MoveValue::Vector(elems) => {
+                serializer.serialize_newtype_variant(name, 9, "Vector", &TaggedElemsRef(elems))
+            }
+            MoveValue::Struct(s) => {
+                let mut t = serializer.serialize_tuple_variant(name, 10, "Struct", 2)?;
+                t.serialize_field(&s.type_)?;
+                t.serialize_field(&TaggedFieldsRef(&s.fields))?;
+                t.end()
+            }
+            MoveValue::Variant(v) => {
+                let mut t = serializer.serialize_tuple_variant(name, 11, "Variant", 4)?;
+                t.serialize_field(&v.type_)?;
+                t.serialize_field(&v.variant_name)?;
+                t.serialize_field(&v.tag)?;
+                t.serialize_field(&TaggedFieldsRef(&v.fields))?;
+                t.end()
+            }
+            MoveValue::I8(n) => serializer.serialize_newtype_variant(name, 12, "I8", n),
+            MoveValue::I16(n) => serializer.serialize_newtype_variant(name, 13, "I16", n),
+            MoveValue::I32(n) => serializer.serialize_newtype_variant(name, 14, "I32", n),
+            MoveValue::I64(n) => serializer.serialize_newtype_variant(name, 15, "I64", n),
+            MoveValue::I128(n) => serializer.serialize_newtype_variant(name, 16, "I128", n),
+            MoveValue::I256(n) => serializer.serialize_newtype_variant(name, 17, "I256", n),
+        }
+    }
+}
+
+impl Serialize for TaggedElemsRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(TaggedValueRef))
+    }
+}
+
+impl Serialize for TaggedFieldsRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.0
+                .iter()
+                .map(|(name, value)| (name, TaggedValueRef(value))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{MoveTrace, TraceEvent, TraceValue, TRACE_VERSION};
+    use move_core_types::{
+        account_address::AccountAddress,
+        annotated_value::{MoveStruct, MoveValue, MoveVariant},
+        identifier::Identifier,
+        language_storage::StructTag,
+    };
+    use std::str::FromStr;
+
+    fn ident(name: &str) -> Identifier {
+        Identifier::new(name).unwrap()
+    }
+
+    fn tag(tag: &str) -> StructTag {
+        StructTag::from_str(tag).unwrap()
+    }
+
+    /// Writes a value to JSON and reads it back.
+    fn json_round_trip(value: MoveValue) -> MoveValue {
+        let json = serde_json::to_string(&TraceValue::RuntimeValue { value }).unwrap();
+        let TraceValue::RuntimeValue { value } = serde_json::from_str(&json).unwrap() else {
+            panic!("expected a runtime value");
+        };
+        value
+    }
+
+    #[test]
+    fn json_integers() {
+        for (value, expected) in [
+            (MoveValue::U8(7), MoveValue::U64(7)),
+            (MoveValue::U32(7), MoveValue::U64(7)),
+            (MoveValue::U64(u64::MAX), MoveValue::U64(u64::MAX)),
+            (MoveValue::U128(u128::MAX), MoveValue::U128(u128::MAX)),
+            (MoveValue::I8(-7), MoveValue::I64(-7)),
+            (MoveValue::I128(i128::MIN), MoveValue::I128(i128::MIN)),
+        ] {
+            assert_eq!(json_round_trip(value), expected);
+        }
+    }
+
+    #[test]
+    fn json_structs_and_variants() {
+        let addr = AccountAddress::from_hex_literal("0x42").unwrap();
+        let value = MoveValue::Struct(MoveStruct {
+            type_: tag("0x1::m::S<u64>"),
+            fields: vec![
+                (ident("flag"), MoveValue::Bool(true)),
+                (ident("owner"), MoveValue::Signer(addr)),
+                (
+                    ident("items"),
+                    MoveValue::Vector(vec![MoveValue::U64(1), MoveValue::U64(2)]),
+                ),
+                (
+                    ident("kind"),
+                    MoveValue::Variant(MoveVariant {
+                        type_: tag("0x1::m::E"),
+                        variant_name: ident("B"),
+                        tag: 1,
+                        fields: vec![(ident("pos0"), MoveValue::Address(addr))],
+                    }),
+                ),
+            ],
+        });
+        // signers are read back as addresses, everything else is preserved
+        let MoveValue::Struct(mut expected) = value.clone() else {
+            unreachable!()
+        };
+        expected.fields[1].1 = MoveValue::Address(addr);
+        assert_eq!(json_round_trip(value), MoveValue::Struct(expected));
+    }
+
+    #[test]
+    fn json_invalid_values() {
+        for json in [
+            r#"{"RuntimeValue":{"value":"not an address"}}"#,
+            r#"{"RuntimeValue":{"value":{"fields":{}}}}"#,
+            r#"{"RuntimeValue":{"value":{"type":"0x1::m::E","fields":{},"variant_tag":0}}}"#,
+            r#"{"RuntimeValue":{"value":1.5}}"#,
+        ] {
+            assert!(serde_json::from_str::<TraceValue>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn json_trace_version() {
+        let mut trace = MoveTrace::new();
+        trace.events.push(TraceEvent::External(Box::new(
+            serde_json::json!({ "note": "external" }),
+        )));
+        let json = trace.to_json().to_string();
+        assert_eq!(MoveTrace::from_json(json.as_bytes()).unwrap(), trace);
+
+        trace.version = TRACE_VERSION + 1;
+        let json = trace.to_json().to_string();
+        let err = MoveTrace::from_json(json.as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("Unsupported trace version"));
+    }
+}
@@ -31,6 +31,7 @@ use move_vm_config::runtime::VMConfig;
 use move_vm_runtime::{
     move_vm::MoveVM,
     session::{SerializedReturnValues, Session},
+    shared_module_cache::SharedModuleCache,
 };
 use move_vm_test_utils::{gas_schedule::GasStatus, InMemoryStorage};
 use once_cell::sync::Lazy;
@@ -164,7 +165,13 @@ impl MoveTestAdapter<'_> for SimpleVMTestAdapter {
             |session, gas_status| session.publish_module_bundle(all_bytes, sender, gas_status),
             VMConfig::default(),
         ) {
-            Ok(()) => Ok((None, modules)),
+            Ok(()) => {
+                // Republished standard library modules have to be read from storage from now on
+                for m in &modules {
+                    MOVE_STDLIB_CACHE.invalidate(&m.module.self_id());
+                }
+                Ok((None, modules))
+            }
             Err(e) => Err(anyhow!(
                 "Unable to publish module '{}'. Got VMError: {}",
                 id,
@@ -265,7 +272,7 @@ impl SimpleVMTestAdapter {
         vm_config: VMConfig,
     ) -> VMResult<Ret> {
         // start session
-        let vm = MoveVM::new_with_config(
+        let vm = MoveVM::new_with_shared_module_cache(
             move_stdlib_natives::all_natives(
                 STD_ADDR,
                 // TODO: come up with a suitable gas schedule
@@ -273,6 +280,7 @@ impl SimpleVMTestAdapter {
                 /* silent */ false,
             ),
             vm_config,
+            MOVE_STDLIB_CACHE.clone(),
         )
         .unwrap();
         let (mut session, mut gas_status) = {
@@ -342,6 +350,20 @@ static MOVE_STDLIB_COMPILED: Lazy<Vec<CompiledModule>> = Lazy::new(|| {
     }
 });
 
+/// The standard library, verified once for the VMs of all tests, which publish it before anything
+/// else. Modules are only ever removed from it, when a test republishes them, which leaves the
+/// other tests to read them from their storage.
+static MOVE_STDLIB_CACHE: Lazy<Arc<SharedModuleCache>> = Lazy::new(|| {
+    let vm_config = test_vm_config();
+    let cache = SharedModuleCache::new_with_modules(
+        vm_config.verifier,
+        vm_config.binary_config,
+        MOVE_STDLIB_COMPILED.iter().cloned(),
+    )
+    .unwrap();
+    Arc::new(cache)
+});
+
 fn test_vm_config() -> VMConfig {
     VMConfig {
         enable_invariant_violation_check_in_swap_loc: false,
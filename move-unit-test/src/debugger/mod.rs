@@ -0,0 +1,491 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! A source-level debugger for unit tests, served over the Debug Adapter Protocol (DAP) so that
+//! editors can attach to it.
+//!
+//! The debugged test is first executed with tracing enabled, and the debugger then replays the
+//! resulting trace (see `session`): it supports breakpoints by source line, stepping into, over,
+//! and out of calls (as well as stepping back and continuing in reverse), and inspecting the call
+//! stack and the values of locals in each frame.
+//! Execution errors (e.g., aborts) stop the execution as exceptions. As the test has already been
+//! executed when the debugging session starts, the output of the test run is reported to the
+//! client when the replayed execution terminates, and the output of calls to debug natives is
+//! reported as they are replayed.
+
+pub mod protocol;
+pub mod session;
+
+use anyhow::Result;
+use move_core_types::annotated_value::MoveValue;
+use move_stdlib_natives::{debug, GasParameters};
+use move_vm_runtime::native_functions::NativeFunctionTable;
+use serde_json::{json, Value};
+use session::{format_value, DebugSession, StepKind, StepResult, StopReason};
+use std::{
+    io::{BufRead, Write},
+    path::Path,
+};
+
+/// Identifier of the (only) thread reported to the client
+const THREAD_ID: u64 = 1;
+
+/// Returns a native function table with debug natives which do not print anything (as the
+/// protocol is served on stdout, while their output is forwarded to the client instead).
+pub fn silence_debug_natives(natives: NativeFunctionTable) -> NativeFunctionTable {
+    natives
+        .into_iter()
+        .map(|(addr, module, name, native)| {
+            if module.as_str() != "debug" {
+                return (addr, module, name, native);
+            }
+            let silent = debug::make_all(true, GasParameters::zeros().debug, addr)
+                .find(|(silent_name, _)| silent_name.as_str() == name.as_str())
+                .map_or(native, |(_, silent)| silent);
+            (addr, module, name, silent)
+        })
+        .collect()
+}
+
+/// Serves a debugging session for a test over the Debug Adapter Protocol until the client
+/// disconnects (or closes the input). The output of the test run is reported to the client when
+/// the replayed execution terminates.
+pub fn run_debug_adapter<R: BufRead, W: Write>(
+    session: DebugSession,
+    test_name: String,
+    test_output: String,
+    passed: bool,
+    mut reader: R,
+    writer: W,
+) -> Result<()> {
+    let mut adapter = DebugAdapter {
+        session,
+        test_name,
+        test_output,
+        passed,
+        writer,
+        seq: 0,
+        lines_start_at1: true,
+        columns_start_at1: true,
+        stop_on_entry: false,
+        launched: false,
+        configured: false,
+        running: false,
+        variables: vec![],
+    };
+    while let Some(message) = protocol::read_message(&mut reader)? {
+        if message["type"] == "request" && !adapter.handle_request(&message)? {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Contents of variables which can be inspected by the client (while the execution is stopped)
+enum Variables {
+    /// Locals of the frame at a given position in the call stack
+    Locals(usize),
+    /// Fields or elements of a value
+    Value(MoveValue),
+}
+
+struct DebugAdapter<W: Write> {
+    session: DebugSession,
+    test_name: String,
+    test_output: String,
+    passed: bool,
+    writer: W,
+    /// Sequence number of the last message sent
+    seq: u64,
+    lines_start_at1: bool,
+    columns_start_at1: bool,
+    stop_on_entry: bool,
+    launched: bool,
+    configured: bool,
+    /// Whether the replayed execution started and did not terminate yet
+    running: bool,
+    /// Variables which can be inspected, the reference of a variable is its index plus one (as
+    /// zero indicates that a variable cannot be inspected)
+    variables: Vec<Variables>,
+}
+
+impl<W: Write> DebugAdapter<W> {
+    /// Handles a request, returning `false` if the session should end.
+    fn handle_request(&mut self, request: &Value) -> Result<bool> {
+        let command = request["command"].as_str().unwrap_or_default();
+        let args = &request["arguments"];
+        match command {
+            "initialize" => {
+                self.lines_start_at1 = args["linesStartAt1"].as_bool().unwrap_or(true);
+                self.columns_start_at1 = args["columnsStartAt1"].as_bool().unwrap_or(true);
+                self.respond(
+                    request,
+                    json!({
+                        "supportsConfigurationDoneRequest": true,
+                        "supportsStepBack": true,
+                    }),
+                )?;
+                self.send_event("initialized", json!({}))?;
+            }
+            "launch" => {
+                self.stop_on_entry = args["stopOnEntry"].as_bool().unwrap_or(false);
+                self.launched = true;
+                self.respond(request, Value::Null)?;
+                self.start()?;
+            }
+            "configurationDone" => {
+                self.configured = true;
+                self.respond(request, Value::Null)?;
+                self.start()?;
+            }
+            "setBreakpoints" => {
+                let path = args["source"]["path"].as_str().unwrap_or_default();
+                let lines = args["breakpoints"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|bp| bp["line"].as_u64())
+                    .map(|line| self.source_line(line))
+                    .collect::<Vec<_>>();
+                let verified = self.session.set_breakpoints(Path::new(path), &lines);
+                let breakpoints = lines
+                    .iter()
+                    .zip(verified)
+                    .map(|(line, verified)| {
+                        let mut breakpoint = json!({
+                            "verified": verified,
+                            "line": self.client_line(*line),
+                            "source": args["source"],
+                        });
+                        if !verified {
+                            breakpoint["message"] = json!("No code at this line");
+                        }
+                        breakpoint
+                    })
+                    .collect::<Vec<_>>();
+                self.respond(request, json!({ "breakpoints": breakpoints }))?;
+            }
+            "threads" => {
+                let threads = json!([{ "id": THREAD_ID, "name": self.test_name }]);
+                self.respond(request, json!({ "threads": threads }))?;
+            }
+            "stackTrace" => {
+                let frames = self.stack_frames();
+                let start = args["startFrame"].as_u64().unwrap_or(0) as usize;
+                let levels = match args["levels"].as_u64().unwrap_or(0) as usize {
+                    0 => frames.len(),
+                    levels => levels,
+                };
+                let body = json!({
+                    "totalFrames": frames.len(),
+                    "stackFrames": frames.into_iter().skip(start).take(levels).collect::<Vec<_>>(),
+                });
+                self.respond(request, body)?;
+            }
+            "scopes" => {
+                let depth = args["frameId"].as_u64().unwrap_or(0) as usize;
+                let scopes = if depth > 0 && depth <= self.session.stack().len() {
+                    let reference = self.add_variables(Variables::Locals(depth - 1));
+                    json!([{
+                        "name": "Locals",
+                        "presentationHint": "locals",
+                        "variablesReference": reference,
+                        "expensive": false,
+                    }])
+                } else {
+                    json!([])
+                };
+                self.respond(request, json!({ "scopes": scopes }))?;
+            }
+            "variables" => {
+                let variables = self.variables(args["variablesReference"].as_u64().unwrap_or(0));
+                self.respond(request, json!({ "variables": variables }))?;
+            }
+            "next" | "stepIn" | "stepOut" | "continue" => {
+                if !self.running {
+                    self.respond_error(request, "The test is not running")?;
+                    return Ok(true);
+                }
+                let kind = match command {
+                    "next" => StepKind::Over,
+                    "stepIn" => StepKind::In,
+                    "stepOut" => StepKind::Out,
+                    _ => StepKind::Continue,
+                };
+                let body = if kind == StepKind::Continue {
+                    json!({ "allThreadsContinued": true })
+                } else {
+                    Value::Null
+                };
+                self.respond(request, body)?;
+                self.variables.clear();
+                let result = self.session.step(kind);
+                self.report(result)?;
+            }
+            "stepBack" | "reverseContinue" => {
+                if !self.running {
+                    self.respond_error(request, "The test is not running")?;
+                    return Ok(true);
+                }
+                self.respond(request, Value::Null)?;
+                self.variables.clear();
+                let result = if command == "stepBack" {
+                    self.session.step_back()
+                } else {
+                    self.session.reverse_continue()
+                };
+                self.report(result)?;
+            }
+            "disconnect" => {
+                self.respond(request, Value::Null)?;
+                return Ok(false);
+            }
+            _ => self.respond_error(request, &format!("Unsupported request '{command}'"))?,
+        }
+        Ok(true)
+    }
+
+    /// Starts the replayed execution once the client is done with both launching and
+    /// configuring the session.
+    fn start(&mut self) -> Result<()> {
+        if !self.launched || !self.configured || self.running {
+            return Ok(());
+        }
+        self.running = true;
+        let result = self.session.start(self.stop_on_entry);
+        self.report(result)
+    }
+
+    /// Reports the outcome of resuming the replayed execution to the client.
+    fn report(&mut self, result: StepResult) -> Result<()> {
+        for output in self.session.take_output() {
+            self.send_output("stdout", &format!("{output}\n"))?;
+        }
+        let reason = match result {
+            StepResult::Stopped(reason) => reason,
+            StepResult::Terminated => {
+                self.running = false;
+                let test_output = std::mem::take(&mut self.test_output);
+                self.send_output("console", &test_output)?;
+                let exit_code = if self.passed { 0 } else { 1 };
+                self.send_event("exited", json!({ "exitCode": exit_code }))?;
+                return self.send_event("terminated", json!({}));
+            }
+        };
+        let mut stopped = json!({
+            "reason": "step",
+            "threadId": THREAD_ID,
+            "allThreadsStopped": true,
+        });
+        match reason {
+            StopReason::Entry => stopped["reason"] = json!("entry"),
+            StopReason::Step => (),
+            StopReason::Breakpoint => stopped["reason"] = json!("breakpoint"),
+            StopReason::Exception(msg) => {
+                stopped["reason"] = json!("exception");
+                stopped["description"] = json!("Execution error");
+                stopped["text"] = json!(msg);
+            }
+        }
+        self.send_event("stopped", stopped)
+    }
+
+    /// Returns frames of the call stack, starting from the current one, identified by their
+    /// (1-based) positions in the call stack.
+    fn stack_frames(&self) -> Vec<Value> {
+        let stack = self.session.stack();
+        stack
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, frame)| !frame.is_native)
+            .map(|(idx, frame)| {
+                let mut stack_frame = json!({
+                    "id": idx + 1,
+                    "name": self.session.frame_name(frame),
+                    "line": 0,
+                    "column": 0,
+                });
+                match self.session.frame_position(frame) {
+                    Some(position) => {
+                        stack_frame["line"] = json!(self.client_line(position.line));
+                        stack_frame["column"] = json!(self.client_column(position.column));
+                        stack_frame["source"] = json!({
+                            "name": position.path.file_name().map(|name| name.to_string_lossy()),
+                            "path": position.path,
+                        });
+                    }
+                    None => stack_frame["presentationHint"] = json!("subtle"),
+                }
+                stack_frame
+            })
+            .collect()
+    }
+
+    /// Returns variables with a given reference.
+    fn variables(&mut self, reference: u64) -> Vec<Value> {
+        let entries = match reference
+            .checked_sub(1)
+            .and_then(|idx| self.variables.get(idx as usize))
+        {
+            Some(Variables::Locals(depth)) => match self.session.stack().get(*depth) {
+                Some(frame) => self
+                    .session
+                    .locals(frame)
+                    .into_iter()
+                    .map(|local| (local.name, Some(local.type_), local.value))
+                    .collect(),
+                None => vec![],
+            },
+            Some(Variables::Value(value)) => value_entries(value),
+            None => vec![],
+        };
+        entries
+            .into_iter()
+            .map(|(name, type_, value)| {
+                let summary = format_value(&value);
+                let type_ = type_.or_else(|| value_type(&value));
+                let reference = if has_entries(&value) {
+                    self.add_variables(Variables::Value(value))
+                } else {
+                    0
+                };
+                json!({
+                    "name": name,
+                    "value": summary,
+                    "type": type_.unwrap_or_default(),
+                    "variablesReference": reference,
+                })
+            })
+            .collect()
+    }
+
+    fn add_variables(&mut self, variables: Variables) -> usize {
+        self.variables.push(variables);
+        self.variables.len()
+    }
+
+    fn source_line(&self, line: u64) -> usize {
+        if self.lines_start_at1 {
+            line as usize
+        } else {
+            line as usize + 1
+        }
+    }
+
+    fn client_line(&self, line: usize) -> usize {
+        if self.lines_start_at1 {
+            line
+        } else {
+            line - 1
+        }
+    }
+
+    fn client_column(&self, column: usize) -> usize {
+        if self.columns_start_at1 {
+            column
+        } else {
+            column - 1
+        }
+    }
+
+    fn respond(&mut self, request: &Value, body: Value) -> Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": true,
+            "body": body,
+        }))
+    }
+
+    fn respond_error(&mut self, request: &Value, message: &str) -> Result<()> {
+        self.send(json!({
+            "type": "response",
+            "request_seq": request["seq"],
+            "command": request["command"],
+            "success": false,
+            "message": message,
+        }))
+    }
+
+    fn send_event(&mut self, event: &str, body: Value) -> Result<()> {
+        self.send(json!({
+            "type": "event",
+            "event": event,
+            "body": body,
+        }))
+    }
+
+    fn send_output(&mut self, category: &str, output: &str) -> Result<()> {
+        self.send_event(
+            "output",
+            json!({
+                "category": category,
+                "output": output,
+            }),
+        )
+    }
+
+    fn send(&mut self, mut message: Value) -> Result<()> {
+        self.seq += 1;
+        message["seq"] = json!(self.seq);
+        protocol::write_message(&mut self.writer, &message)
+    }
+}
+
+/// Returns fields or elements of a value (along with their types if known).
+fn value_entries(value: &MoveValue) -> Vec<(String, Option<String>, MoveValue)> {
+    match value {
+        MoveValue::Vector(elems) => elems
+            .iter()
+            .enumerate()
+            .map(|(idx, elem)| (format!("[{idx}]"), None, elem.clone()))
+            .collect(),
+        MoveValue::Struct(s) => s
+            .fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), None, value.clone()))
+            .collect(),
+        MoveValue::Variant(v) => v
+            .fields
+            .iter()
+            .map(|(name, value)| (name.to_string(), None, value.clone()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn has_entries(value: &MoveValue) -> bool {
+    match value {
+        MoveValue::Vector(elems) => !elems.is_empty(),
+        MoveValue::Struct(s) => !s.fields.is_empty(),
+        MoveValue::Variant(v) => !v.fields.is_empty(),
+        _ => false,
+    }
+}
+
+/// Returns the type of a value, if it can be determined from the value itself.
+fn value_type(value: &MoveValue) -> Option<String> {
+    Some(match value {
+        MoveValue::U8(_) => "u8".to_string(),
+        MoveValue::U16(_) => "u16".to_string(),
+        MoveValue::U32(_) => "u32".to_string(),
+        MoveValue::U64(_) => "u64".to_string(),
+        MoveValue::U128(_) => "u128".to_string(),
+        MoveValue::U256(_) => "u256".to_string(),
+        MoveValue::I8(_) => "i8".to_string(),
+        MoveValue::I16(_) => "i16".to_string(),
+        MoveValue::I32(_) => "i32".to_string(),
+        MoveValue::I64(_) => "i64".to_string(),
+        MoveValue::I128(_) => "i128".to_string(),
+        MoveValue::I256(_) => "i256".to_string(),
+        MoveValue::Bool(_) => "bool".to_string(),
+        MoveValue::Address(_) => "address".to_string(),
+        MoveValue::Signer(_) => "signer".to_string(),
+        MoveValue::Vector(elems) => format!("vector<{}>", value_type(elems.first()?)?),
+        MoveValue::Struct(s) => s.type_.to_string(),
+        MoveValue::Variant(v) => v.type_.to_string(),
+    })
+}
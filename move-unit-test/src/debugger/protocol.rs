@@ -0,0 +1,78 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! The base protocol of the Debug Adapter Protocol: each message is a JSON object preceded by a
+//! header consisting of `Content-Length` (and possibly other) fields terminated by an empty line.
+
+use anyhow::{anyhow, bail, Result};
+use serde_json::Value;
+use std::io::{BufRead, Write};
+
+const CONTENT_LENGTH: &str = "Content-Length";
+
+/// Reads a message, returning `None` if the input is closed.
+pub fn read_message(reader: &mut impl BufRead) -> Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            if content_length.is_none() {
+                return Ok(None);
+            }
+            bail!("Unexpected end of input in message header");
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            // tolerate blank lines between messages
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| anyhow!("Invalid message header field '{line}'"))?;
+        if name.trim().eq_ignore_ascii_case(CONTENT_LENGTH) {
+            content_length = Some(value.trim().parse::<usize>()?);
+        }
+    }
+    let mut content = vec![0; content_length.unwrap()];
+    reader.read_exact(&mut content)?;
+    Ok(Some(serde_json::from_slice(&content)?))
+}
+
+/// Writes a message.
+pub fn write_message(writer: &mut impl Write, message: &Value) -> Result<()> {
+    let content = serde_json::to_string(message)?;
+    write!(
+        writer,
+        "{CONTENT_LENGTH}: {}\r\n\r\n{content}",
+        content.len()
+    )?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_message, write_message};
+    use serde_json::json;
+
+    #[test]
+    fn test_message_round_trip() {
+        let messages = [
+            json!({"seq": 1, "type": "request", "command": "initialize"}),
+            json!({"seq": 2, "type": "event", "event": "output", "body": {"output": "ü\n"}}),
+        ];
+        let mut buf = vec![];
+        for message in &messages {
+            write_message(&mut buf, message).unwrap();
+        }
+        let mut reader = buf.as_slice();
+        for message in &messages {
+            assert_eq!(read_message(&mut reader).unwrap().as_ref(), Some(message));
+        }
+        assert!(read_message(&mut reader).unwrap().is_none());
+    }
+}
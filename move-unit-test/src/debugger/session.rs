@@ -0,0 +1,911 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+//! A debugging session replaying the execution trace of a unit test. The state of the execution
+//! (the call stack and the values of locals) at a given instruction is reconstructed by replaying
+//! the events of the trace up to this instruction, and instructions are mapped to source code
+//! through the source maps of the compiled modules.
+//!
+//! As the whole trace is available upfront, the execution can also be moved backwards (by
+//! replaying it again from the start up to an earlier instruction), and the trace can be queried
+//! for the events which changed a given location.
+
+use crate::format_module_id;
+use move_binary_format::file_format::FunctionDefinitionIndex;
+use move_command_line_common::files::FileHash;
+use move_compiler::{compiled_unit::NamedCompiledModule, shared::files::MappedFiles};
+use move_core_types::{annotated_value::MoveValue, language_storage::ModuleId};
+use move_trace_format::{
+    format::{
+        Effect, Frame, Location, MoveTrace, Read, RefType, TraceEvent, TraceIndex, TraceValue,
+    },
+    memory_tracer::TraceState,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+/// A position in the source code (lines and columns are 1-based)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourcePosition {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A frame of the call stack of the replayed execution
+#[derive(Debug, Clone)]
+pub struct StackFrame {
+    pub frame_id: TraceIndex,
+    pub module: ModuleId,
+    pub function_name: String,
+    pub function_index: FunctionDefinitionIndex,
+    pub locals_types: Vec<String>,
+    pub is_native: bool,
+    /// Offset of the instruction the frame is executing (if it executed any)
+    pub pc: Option<u16>,
+}
+
+/// A local variable (or a parameter) of a frame, with references resolved to the values they
+/// point to
+#[derive(Debug, Clone)]
+pub struct Local {
+    pub index: usize,
+    pub name: String,
+    pub type_: String,
+    pub value: MoveValue,
+}
+
+/// Kinds of requests resuming the replayed execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepKind {
+    /// Stop at the next source line, including lines of called functions
+    In,
+    /// Stop at the next source line of the current function (or of its caller if it returns)
+    Over,
+    /// Stop when the current function returns to its caller
+    Out,
+    /// Stop at the next breakpoint only
+    Continue,
+}
+
+/// Reasons for stopping the replayed execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StopReason {
+    Entry,
+    Step,
+    Breakpoint,
+    Exception(String),
+}
+
+/// Outcome of resuming the replayed execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepResult {
+    Stopped(StopReason),
+    Terminated,
+}
+
+/// The instruction which produced an event of the trace
+#[derive(Debug, Clone)]
+pub struct EventOrigin {
+    pub function_name: String,
+    pub pc: u16,
+    pub instruction: String,
+    pub position: Option<SourcePosition>,
+}
+
+/// An instruction of the trace
+struct InstructionRecord {
+    event: TraceIndex,
+    frame_id: TraceIndex,
+    /// Size of the call stack when executing the instruction
+    depth: usize,
+    pc: u16,
+}
+
+enum Advance {
+    Instruction,
+    Error(String),
+    End,
+}
+
+pub struct DebugSession {
+    module_info: BTreeMap<ModuleId, NamedCompiledModule>,
+    mapped_files: MappedFiles,
+    /// Canonical paths of source files
+    paths: BTreeMap<FileHash, PathBuf>,
+    events: Vec<TraceEvent>,
+    /// Frames opened in the trace, by their ids
+    frames: BTreeMap<TraceIndex, StackFrame>,
+    /// Instructions of the trace, in execution order
+    instructions: Vec<InstructionRecord>,
+    /// Index of the next event to replay
+    cursor: usize,
+    /// Number of events replayed at least once, which is only smaller than `cursor` after the
+    /// execution was moved backwards
+    replayed: usize,
+    /// Whether the event at `cursor` is the instruction the execution is stopped at
+    at_instruction: bool,
+    state: TraceState,
+    stack: Vec<StackFrame>,
+    breakpoints: BTreeMap<PathBuf, BTreeSet<usize>>,
+    /// Output of calls to debug natives which was not yet reported
+    output: Vec<String>,
+}
+
+impl DebugSession {
+    pub fn new(
+        trace: MoveTrace,
+        module_info: BTreeMap<ModuleId, NamedCompiledModule>,
+        mapped_files: MappedFiles,
+    ) -> Self {
+        let paths = mapped_files
+            .file_name_mapping()
+            .iter()
+            .map(|(fhash, path)| (*fhash, canonical_path(path)))
+            .collect();
+        let mut frames = BTreeMap::new();
+        let mut instructions = vec![];
+        let mut open_frames = vec![];
+        for (idx, event) in trace.events.iter().enumerate() {
+            match event {
+                TraceEvent::OpenFrame { frame, .. } => {
+                    frames.insert(frame.frame_id, stack_frame(frame));
+                    open_frames.push(frame.frame_id);
+                }
+                TraceEvent::CloseFrame { .. } => {
+                    open_frames.pop();
+                }
+                TraceEvent::Instruction { pc, .. } => {
+                    if let Some(frame_id) = open_frames.last() {
+                        instructions.push(InstructionRecord {
+                            event: idx,
+                            frame_id: *frame_id,
+                            depth: open_frames.len(),
+                            pc: *pc,
+                        });
+                    }
+                }
+                TraceEvent::Effect(_) | TraceEvent::External(_) => (),
+            }
+        }
+        Self {
+            module_info,
+            mapped_files,
+            paths,
+            events: trace.events,
+            frames,
+            instructions,
+            cursor: 0,
+            replayed: 0,
+            at_instruction: false,
+            state: TraceState::new(),
+            stack: vec![],
+            breakpoints: BTreeMap::new(),
+            output: vec![],
+        }
+    }
+
+    /// Starts the replayed execution, either stopping at the first instruction mapped to source
+    /// code or running to the first breakpoint.
+    pub fn start(&mut self, stop_on_entry: bool) -> StepResult {
+        if !stop_on_entry {
+            return self.step(StepKind::Continue);
+        }
+        loop {
+            match self.advance() {
+                Advance::Instruction if self.current_position().is_some() => {
+                    return StepResult::Stopped(StopReason::Entry)
+                }
+                Advance::Instruction => (),
+                Advance::Error(msg) => return StepResult::Stopped(StopReason::Exception(msg)),
+                Advance::End => return StepResult::Terminated,
+            }
+        }
+    }
+
+    /// Resumes the replayed execution until it stops again (or terminates).
+    pub fn step(&mut self, kind: StepKind) -> StepResult {
+        let start_depth = self.stack.len();
+        let start_line = self.current_line();
+        let mut prev = start_line.clone();
+        loop {
+            match self.advance() {
+                Advance::Instruction => (),
+                Advance::Error(msg) => return StepResult::Stopped(StopReason::Exception(msg)),
+                Advance::End => return StepResult::Terminated,
+            }
+            // instructions without source (e.g., in bytecode dependencies) are stepped through
+            let Some(line) = self.current_line() else {
+                continue;
+            };
+            // breakpoints are hit when the execution reaches their line from a different one
+            let new_line = prev.as_ref() != Some(&line);
+            if new_line && self.is_breakpoint() {
+                return StepResult::Stopped(StopReason::Breakpoint);
+            }
+            let depth = self.stack.len();
+            let stop = match kind {
+                StepKind::In => new_line,
+                StepKind::Over => {
+                    depth < start_depth
+                        || (depth == start_depth && start_line.as_ref() != Some(&line))
+                }
+                StepKind::Out => depth < start_depth,
+                StepKind::Continue => false,
+            };
+            if stop {
+                return StepResult::Stopped(StopReason::Step);
+            }
+            prev = Some(line);
+        }
+    }
+
+    /// Moves the replayed execution back to the start of the previous source line of the current
+    /// function (or of its caller if the current line is the first one), i.e., the reverse of
+    /// stepping over. If the execution stopped on an error or terminated, it is moved back to the
+    /// start of the line of the last instruction executed.
+    pub fn step_back(&mut self) -> StepResult {
+        let Some(current) = self.last_instruction() else {
+            return StepResult::Terminated;
+        };
+        let start = self.line_start(current);
+        if !self.at_instruction {
+            self.seek(start);
+            return StepResult::Stopped(StopReason::Step);
+        }
+        let depth = self.instructions[current].depth;
+        let previous = (0..start).rev().find(|idx| {
+            self.instructions[*idx].depth <= depth && self.instruction_line(*idx).is_some()
+        });
+        match previous {
+            Some(idx) => {
+                let target = self.line_start(idx);
+                self.seek(target);
+                StepResult::Stopped(StopReason::Step)
+            }
+            None => self.rewind(),
+        }
+    }
+
+    /// Moves the replayed execution back to the last breakpoint hit before the current
+    /// instruction, or to the entry of the execution if there is none.
+    pub fn reverse_continue(&mut self) -> StepResult {
+        let Some(current) = self.last_instruction() else {
+            return StepResult::Terminated;
+        };
+        let end = if self.at_instruction {
+            current
+        } else {
+            current + 1
+        };
+        // breakpoints are hit when the execution reaches their line from a different one
+        let hit = (0..end).rev().find(|idx| {
+            self.instruction_line(*idx).is_some_and(|line| {
+                self.is_breakpoint_line(&line)
+                    && (0..*idx)
+                        .rev()
+                        .find_map(|prev| self.instruction_line(prev))
+                        .as_ref()
+                        != Some(&line)
+            })
+        });
+        match hit {
+            Some(idx) => {
+                self.seek(idx);
+                StepResult::Stopped(StopReason::Breakpoint)
+            }
+            None => self.rewind(),
+        }
+    }
+
+    /// Moves the replayed execution (forwards or backwards) to the instruction which produced an
+    /// event of the trace.
+    pub fn goto(&mut self, event: TraceIndex) -> StepResult {
+        match self.instruction_of(event) {
+            Some(idx) => {
+                self.seek(idx);
+                StepResult::Stopped(StopReason::Step)
+            }
+            None => self.rewind(),
+        }
+    }
+
+    /// Returns the index of the event the replayed execution is at.
+    pub fn cursor(&self) -> TraceIndex {
+        self.cursor
+    }
+
+    /// Returns the events of the trace.
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// Returns the events of the whole trace which changed the value held at a location: writes
+    /// to it (or to a location within it or containing it), values moved out of it, and for
+    /// locals of a frame, the arguments the frame was opened with.
+    pub fn changes(&self, location: &Location) -> Vec<TraceIndex> {
+        let (root, path) = location_path(location);
+        let overlaps = |location: &Location| {
+            let (other_root, other_path) = location_path(location);
+            other_root == root && (path.starts_with(&other_path) || other_path.starts_with(&path))
+        };
+        self.events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| match event {
+                TraceEvent::OpenFrame { frame, .. } => matches!(
+                    root,
+                    Location::Local(frame_id, idx)
+                        if *frame_id == frame.frame_id && *idx < frame.parameters.len()
+                ),
+                TraceEvent::Effect(effect) => match &**effect {
+                    Effect::Write(write) => overlaps(&write.location),
+                    Effect::Read(Read {
+                        location,
+                        moved: true,
+                        ..
+                    }) => overlaps(location),
+                    _ => false,
+                },
+                _ => false,
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    /// Returns the last event which changed the value held at a location before the current
+    /// instruction (see `changes`).
+    pub fn last_change(&self, location: &Location) -> Option<TraceIndex> {
+        self.changes(location)
+            .into_iter()
+            .take_while(|idx| *idx < self.cursor)
+            .last()
+    }
+
+    /// Returns the instruction which produced an event (for frame events, the call instruction).
+    pub fn event_origin(&self, event: TraceIndex) -> Option<EventOrigin> {
+        let instruction = &self.instructions[self.instruction_of(event)?];
+        let frame = self.frames.get(&instruction.frame_id)?;
+        let TraceEvent::Instruction {
+            instruction: name, ..
+        } = &self.events[instruction.event]
+        else {
+            return None;
+        };
+        Some(EventOrigin {
+            function_name: self.frame_name(frame),
+            pc: instruction.pc,
+            instruction: name.to_string(),
+            position: self.code_position(frame, instruction.pc),
+        })
+    }
+
+    /// Sets breakpoints in a file (replacing those previously set), and returns for each of them
+    /// whether there is code at the requested line.
+    pub fn set_breakpoints(&mut self, path: &Path, lines: &[usize]) -> Vec<bool> {
+        let path = canonical_path(path);
+        let code_lines = self.code_lines(&path);
+        self.breakpoints
+            .insert(path, lines.iter().copied().collect());
+        lines.iter().map(|line| code_lines.contains(line)).collect()
+    }
+
+    /// Returns the call stack (the current frame is the last one).
+    pub fn stack(&self) -> &[StackFrame] {
+        &self.stack
+    }
+
+    /// Returns the source position of the instruction a frame is executing.
+    pub fn frame_position(&self, frame: &StackFrame) -> Option<SourcePosition> {
+        self.code_position(frame, frame.pc?)
+    }
+
+    /// Returns the source position of an instruction of the function executed in a frame.
+    fn code_position(&self, frame: &StackFrame, pc: u16) -> Option<SourcePosition> {
+        let loc = self
+            .module_info
+            .get(&frame.module)?
+            .source_map
+            .get_code_location(frame.function_index, pc)
+            .ok()?;
+        let start = self.mapped_files.start_position_opt(&loc)?;
+        Some(SourcePosition {
+            path: self.paths.get(&loc.file_hash())?.clone(),
+            line: start.user_line(),
+            column: start.column_offset() + 1,
+        })
+    }
+
+    /// Returns the qualified name of the function executed in a frame.
+    pub fn frame_name(&self, frame: &StackFrame) -> String {
+        format!(
+            "{}::{}",
+            format_module_id(&self.module_info, &frame.module),
+            frame.function_name
+        )
+    }
+
+    /// Returns locals of a frame which currently hold values, in the order of their indices.
+    /// Temporaries introduced by the compiler are omitted.
+    pub fn locals(&self, frame: &StackFrame) -> Vec<Local> {
+        let Some((values, _)) = self.state.call_stack.get(&frame.frame_id) else {
+            return vec![];
+        };
+        let function_map = self.module_info.get(&frame.module).and_then(|module| {
+            module
+                .source_map
+                .get_function_source_map(frame.function_index)
+                .ok()
+        });
+        values
+            .iter()
+            .filter_map(|(idx, value)| {
+                let name = match function_map
+                    .and_then(|map| map.get_parameter_or_local_name(*idx as u64))
+                {
+                    Some((name, _)) if name.contains('%') => return None,
+                    Some((name, _)) => name.split('#').next().unwrap_or_default().to_string(),
+                    None => format!("l{idx}"),
+                };
+                Some(Local {
+                    index: *idx,
+                    name,
+                    type_: frame.locals_types.get(*idx).cloned().unwrap_or_default(),
+                    value: self.referenced_value(value).clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Takes output of calls to debug natives replayed since the last call.
+    pub fn take_output(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Replays events up to the next instruction, which is not replayed itself (i.e., the state
+    /// reflects execution right before this instruction).
+    fn advance(&mut self) -> Advance {
+        let mut skip_instruction = std::mem::take(&mut self.at_instruction);
+        while let Some(event) = self.events.get(self.cursor) {
+            if let TraceEvent::Instruction { pc, .. } = event {
+                if !skip_instruction {
+                    if let Some(frame) = self.stack.last_mut() {
+                        frame.pc = Some(*pc);
+                    }
+                    self.at_instruction = true;
+                    return Advance::Instruction;
+                }
+                skip_instruction = false;
+            }
+            // output was already reported if the event is replayed again
+            let replayed = self.cursor < self.replayed;
+            self.cursor += 1;
+            self.replayed = self.replayed.max(self.cursor);
+            self.state.apply_event(event);
+            match event {
+                TraceEvent::OpenFrame { frame, .. } => {
+                    self.stack.push(self.frames[&frame.frame_id].clone());
+                    if !replayed && frame.is_native && frame.module.name().as_str() == "debug" {
+                        let output = match frame.function_name.as_str() {
+                            "print" => frame
+                                .parameters
+                                .first()
+                                .map(|value| format_value(self.referenced_value(value))),
+                            "print_stack_trace" => Some(self.stack_trace()),
+                            _ => None,
+                        };
+                        self.output.extend(output);
+                    }
+                }
+                TraceEvent::CloseFrame { .. } => {
+                    self.stack.pop();
+                }
+                TraceEvent::Effect(effect) => {
+                    if let Effect::ExecutionError(msg) = &**effect {
+                        return Advance::Error(msg.clone());
+                    }
+                }
+                TraceEvent::Instruction { .. } | TraceEvent::External(_) => (),
+            }
+        }
+        Advance::End
+    }
+
+    /// Replays the execution up to an instruction, from the start if it was already executed.
+    fn seek(&mut self, instruction: usize) {
+        let event = self.instructions[instruction].event;
+        if event < self.cursor || (event == self.cursor && !self.at_instruction) {
+            self.cursor = 0;
+            self.at_instruction = false;
+            self.state = TraceState::new();
+            self.stack.clear();
+        }
+        while !(self.at_instruction && self.cursor == event) {
+            if let Advance::End = self.advance() {
+                break;
+            }
+        }
+    }
+
+    /// Moves the replayed execution back to its entry (the first instruction mapped to source
+    /// code).
+    fn rewind(&mut self) -> StepResult {
+        match (0..self.instructions.len()).find(|idx| self.instruction_line(*idx).is_some()) {
+            Some(idx) => {
+                self.seek(idx);
+                StepResult::Stopped(StopReason::Entry)
+            }
+            None => StepResult::Terminated,
+        }
+    }
+
+    /// Returns the index of the instruction the execution is stopped at, or if it is not stopped
+    /// at one, of the last instruction executed.
+    fn last_instruction(&self) -> Option<usize> {
+        let end = if self.at_instruction {
+            self.cursor + 1
+        } else {
+            self.cursor
+        };
+        self.instructions
+            .partition_point(|instruction| instruction.event < end)
+            .checked_sub(1)
+    }
+
+    /// Returns the index of the instruction which produced an event.
+    fn instruction_of(&self, event: TraceIndex) -> Option<usize> {
+        self.instructions
+            .partition_point(|instruction| instruction.event <= event)
+            .checked_sub(1)
+    }
+
+    /// Returns the first instruction of the run of instructions of the same frame and source line
+    /// ending with the given one (instructions of called functions are part of the run).
+    fn line_start(&self, instruction: usize) -> usize {
+        let line = self.instruction_line(instruction);
+        let depth = self.instructions[instruction].depth;
+        let mut start = instruction;
+        for idx in (0..instruction).rev() {
+            if self.instructions[idx].depth > depth {
+                continue;
+            }
+            if self.instruction_line(idx) != line {
+                break;
+            }
+            start = idx;
+        }
+        start
+    }
+
+    /// Returns the frame and the source line of an instruction.
+    fn instruction_line(&self, instruction: usize) -> Option<(TraceIndex, PathBuf, usize)> {
+        let instruction = &self.instructions[instruction];
+        let frame = self.frames.get(&instruction.frame_id)?;
+        let position = self.code_position(frame, instruction.pc)?;
+        Some((frame.frame_id, position.path, position.line))
+    }
+
+    /// Returns the frame and the source line of the current instruction.
+    fn current_line(&self) -> Option<(TraceIndex, PathBuf, usize)> {
+        let frame = self.stack.last()?;
+        let position = self.frame_position(frame)?;
+        Some((frame.frame_id, position.path, position.line))
+    }
+
+    fn current_position(&self) -> Option<SourcePosition> {
+        self.frame_position(self.stack.last()?)
+    }
+
+    fn is_breakpoint(&self) -> bool {
+        self.current_line()
+            .is_some_and(|line| self.is_breakpoint_line(&line))
+    }
+
+    fn is_breakpoint_line(&self, (_, path, line): &(TraceIndex, PathBuf, usize)) -> bool {
+        self.breakpoints
+            .get(path)
+            .is_some_and(|lines| lines.contains(line))
+    }
+
+    /// Returns the lines of a file with code (at which the execution can stop).
+    fn code_lines(&self, path: &Path) -> BTreeSet<usize> {
+        let mut lines = BTreeSet::new();
+        for module in self.module_info.values() {
+            let function_maps = (0..module.module.function_defs().len()).filter_map(|idx| {
+                module
+                    .source_map
+                    .get_function_source_map(FunctionDefinitionIndex(idx as u16))
+                    .ok()
+            });
+            for function_map in function_maps {
+                for loc in function_map.code_map.values() {
+                    if self.paths.get(&loc.file_hash()).map(|p| p.as_path()) != Some(path) {
+                        continue;
+                    }
+                    if let Some(start) = self.mapped_files.start_position_opt(loc) {
+                        lines.insert(start.user_line());
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    /// Renders the call stack (the current frame first) the way `print_stack_trace` does.
+    fn stack_trace(&self) -> String {
+        let mut trace = "stack trace".to_string();
+        for frame in self.stack.iter().rev().filter(|frame| !frame.is_native) {
+            trace.push_str(&format!("\n\t{}", self.frame_name(frame)));
+            if let Some(position) = self.frame_position(frame) {
+                trace.push_str(&format!(" ({}:{})", position.path.display(), position.line));
+            }
+        }
+        trace
+    }
+
+    /// Returns the value a reference points to (or the value itself if it is not a reference).
+    fn referenced_value<'a>(&self, value: &'a TraceValue) -> &'a MoveValue {
+        let snapshot = value.snapshot();
+        let Some(location) = value.location() else {
+            return snapshot;
+        };
+        location_path(location)
+            .1
+            .iter()
+            .try_fold(snapshot, |value, idx| match value {
+                MoveValue::Vector(elems) => elems.get(*idx),
+                MoveValue::Struct(s) => s.fields.get(*idx).map(|(_, v)| v),
+                MoveValue::Variant(v) => v.fields.get(*idx).map(|(_, v)| v),
+                _ => None,
+            })
+            .unwrap_or(snapshot)
+    }
+}
+
+fn stack_frame(frame: &Frame) -> StackFrame {
+    StackFrame {
+        frame_id: frame.frame_id,
+        module: frame.module.clone(),
+        function_name: frame.function_name.clone(),
+        function_index: FunctionDefinitionIndex(frame.binary_member_index),
+        locals_types: frame
+            .locals_types
+            .iter()
+            .map(|ty| match ty.ref_type {
+                Some(RefType::Imm) => format!("&{}", ty.type_),
+                Some(RefType::Mut) => format!("&mut {}", ty.type_),
+                None => ty.type_.to_string(),
+            })
+            .collect(),
+        is_native: frame.is_native,
+        pc: None,
+    }
+}
+
+/// Splits a location into its root (a local or a global) and the path of indices into it.
+fn location_path(location: &Location) -> (&Location, Vec<usize>) {
+    let mut path = vec![];
+    let mut location = location;
+    while let Location::Indexed(parent, idx) = location {
+        path.push(*idx);
+        location = parent;
+    }
+    path.reverse();
+    (location, path)
+}
+
+/// Renders a value in a compact form: compound values are abbreviated as their elements can be
+/// inspected separately, and strings are shown as text.
+pub fn format_value(value: &MoveValue) -> String {
+    match value {
+        MoveValue::U8(n) => n.to_string(),
+        MoveValue::U16(n) => n.to_string(),
+        MoveValue::U32(n) => n.to_string(),
+        MoveValue::U64(n) => n.to_string(),
+        MoveValue::U128(n) => n.to_string(),
+        MoveValue::U256(n) => n.to_string(),
+        MoveValue::I8(n) => n.to_string(),
+        MoveValue::I16(n) => n.to_string(),
+        MoveValue::I32(n) => n.to_string(),
+        MoveValue::I64(n) => n.to_string(),
+        MoveValue::I128(n) => n.to_string(),
+        MoveValue::I256(n) => n.to_string(),
+        MoveValue::Bool(b) => b.to_string(),
+        MoveValue::Address(a) => a.to_hex_literal(),
+        MoveValue::Signer(a) => format!("signer({})", a.to_hex_literal()),
+        MoveValue::Vector(elems) => format!("vector[{}]", elems.len()),
+        MoveValue::Struct(s) => match string_value(value) {
+            Some(text) => format!("{text:?}"),
+            None => format!("{} {{ .. }}", s.type_.name),
+        },
+        MoveValue::Variant(v) => format!("{}::{} {{ .. }}", v.type_.name, v.variant_name),
+    }
+}
+
+/// Returns text held by a `std::string::String` or `std::ascii::String` value. Bytes may be
+/// integers of any width, as widths are not preserved in saved traces.
+pub fn string_value(value: &MoveValue) -> Option<String> {
+    let MoveValue::Struct(s) = value else {
+        return None;
+    };
+    if s.type_.name.as_str() != "String" || !matches!(s.type_.module.as_str(), "string" | "ascii") {
+        return None;
+    }
+    let [(_, MoveValue::Vector(bytes))] = s.fields.as_slice() else {
+        return None;
+    };
+    let bytes = bytes
+        .iter()
+        .map(|b| match b {
+            MoveValue::U8(b) => Some(*b),
+            MoveValue::U64(b) => u8::try_from(*b).ok(),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    String::from_utf8(bytes).ok()
+}
+
+fn canonical_path(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DebugSession, StepKind, StepResult, StopReason};
+    use move_binary_format::file_format::{Bytecode, FunctionDefinitionIndex};
+    use move_compiler::shared::files::MappedFiles;
+    use move_core_types::{
+        account_address::AccountAddress,
+        annotated_value::MoveValue,
+        identifier::Identifier,
+        language_storage::{ModuleId, TypeTag},
+    };
+    use move_trace_format::format::{
+        Effect, Location, MoveTrace, MoveTraceBuilder, Read, TraceValue, TypeTagWithRefs, Write,
+    };
+    use std::collections::BTreeMap;
+
+    fn value(n: u64) -> TraceValue {
+        TraceValue::RuntimeValue {
+            value: MoveValue::U64(n),
+        }
+    }
+
+    /// Builds the trace of `fun f(x: u64): u64 { let y = x; y }`, optionally aborting before the
+    /// function returns.
+    fn trace(error: Option<&str>) -> MoveTrace {
+        let mut builder = MoveTraceBuilder::new();
+        let frame_id = builder.current_trace_offset();
+        let u64_type = TypeTagWithRefs {
+            type_: TypeTag::U64,
+            ref_type: None,
+        };
+        builder.open_frame(
+            frame_id,
+            FunctionDefinitionIndex(0),
+            "f".to_string(),
+            ModuleId::new(AccountAddress::ONE, Identifier::new("m").unwrap()),
+            vec![value(7)],
+            vec![],
+            vec![u64_type.clone()],
+            vec![u64_type.clone(), u64_type],
+            false,
+            100,
+        );
+        let x = Location::Local(frame_id, 0);
+        let y = Location::Local(frame_id, 1);
+        let read = |location: &Location, moved| {
+            Effect::Read(Read {
+                location: location.clone(),
+                root_value_read: value(7),
+                moved,
+            })
+        };
+        builder.instruction(
+            &Bytecode::CopyLoc(0),
+            vec![],
+            vec![read(&x, false), Effect::Push(value(7))],
+            99,
+            0,
+        );
+        builder.instruction(
+            &Bytecode::StLoc(1),
+            vec![],
+            vec![
+                Effect::Pop(value(7)),
+                Effect::Write(Write {
+                    location: y.clone(),
+                    root_value_after_write: value(7),
+                }),
+            ],
+            98,
+            1,
+        );
+        if let Some(error) = error {
+            builder.instruction(
+                &Bytecode::MoveLoc(1),
+                vec![],
+                vec![Effect::ExecutionError(error.to_string())],
+                97,
+                2,
+            );
+            return builder.into_trace();
+        }
+        builder.instruction(
+            &Bytecode::MoveLoc(1),
+            vec![],
+            vec![read(&y, true), Effect::Push(value(7))],
+            97,
+            2,
+        );
+        builder.instruction(&Bytecode::Ret, vec![], vec![Effect::Pop(value(7))], 96, 3);
+        builder.close_frame(frame_id, vec![value(7)], 96);
+        builder.into_trace()
+    }
+
+    fn session(trace: MoveTrace) -> DebugSession {
+        DebugSession::new(trace, BTreeMap::new(), MappedFiles::empty())
+    }
+
+    #[test]
+    fn location_changes() {
+        let session = session(trace(None));
+        // the parameter is set when the frame is opened, the local is written and moved out of
+        assert_eq!(session.changes(&Location::Local(0, 0)), vec![0]);
+        assert_eq!(session.changes(&Location::Local(0, 1)), vec![6, 8]);
+        let field = Location::Indexed(Box::new(Location::Local(0, 1)), 0);
+        assert_eq!(session.changes(&field), vec![6, 8]);
+        assert!(session.changes(&Location::Local(1, 0)).is_empty());
+    }
+
+    #[test]
+    fn goto_events() {
+        let mut session = session(trace(None));
+        let y = Location::Local(0, 1);
+
+        // the execution stops at the instruction which produced the event
+        assert_eq!(session.goto(8), StepResult::Stopped(StopReason::Step));
+        assert_eq!(session.cursor(), 7);
+        assert_eq!(session.last_change(&y), Some(6));
+        let origin = session.event_origin(8).unwrap();
+        assert_eq!((origin.pc, origin.instruction.as_str()), (2, "MOVE_LOC"));
+        let frame = session.stack()[0].clone();
+        assert_eq!(session.stack().len(), 1);
+        assert_eq!(frame.pc, Some(2));
+        let locals = session
+            .locals(&frame)
+            .into_iter()
+            .map(|local| (local.name, local.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            locals,
+            vec![
+                ("l0".to_string(), MoveValue::U64(7)),
+                ("l1".to_string(), MoveValue::U64(7)),
+            ]
+        );
+
+        // and can be moved backwards
+        session.goto(2);
+        assert_eq!(session.cursor(), 1);
+        assert_eq!(session.last_change(&y), None);
+        assert_eq!(session.locals(&frame).len(), 1);
+
+        // instructions without source are stepped through
+        assert_eq!(session.step(StepKind::In), StepResult::Terminated);
+        assert!(session.stack().is_empty());
+    }
+
+    #[test]
+    fn execution_errors() {
+        let mut session = session(trace(Some("ABORTED")));
+        assert_eq!(
+            session.start(true),
+            StepResult::Stopped(StopReason::Exception("ABORTED".to_string()))
+        );
+        assert_eq!(session.stack().len(), 1);
+    }
+}
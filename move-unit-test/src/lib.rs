@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod cargo_runner;
+pub mod debugger;
 pub mod extensions;
 pub mod test_reporter;
 pub mod test_runner;
@@ -21,7 +22,7 @@ use move_compiler::{
     unit_test::{self, TestPlan},
     Compiler, Flags, PASS_CFGIR,
 };
-use move_core_types::language_storage::ModuleId;
+use move_core_types::{account_address::AccountAddress, language_storage::ModuleId};
 use move_vm_runtime::native_functions::NativeFunctionTable;
 use move_vm_test_utils::gas_schedule::CostTable;
 use std::{collections::BTreeMap, io::Write, marker::Send, sync::Mutex};
@@ -36,6 +37,11 @@ const RAND_NUM_ITERS_FLAG: &str = "rand-num-iters";
 const SEED_FLAG: &str = "seed";
 const TRACE_FLAG: &str = "trace-execution";
 
+move_vm_profiler::tracing_feature_enabled! {
+    /// The file in which the gas consumed by all tests is saved when profiling gas.
+    const GAS_PROFILE_FILE: &str = "gas.folded";
+}
+
 #[derive(Debug, Parser, Clone)]
 #[clap(author, version, about)]
 pub struct UnitTestingConfig {
@@ -127,6 +133,21 @@ pub struct UnitTestingConfig {
     // Enable tracing for tests
     #[clap(long = TRACE_FLAG, value_name = "PATH")]
     pub trace_execution: Option<Option<String>>,
+
+    /// Save execution traces in the JSON format rather than in the (more compact) binary one
+    #[clap(long = "trace-json", requires = "trace_execution")]
+    pub trace_json: bool,
+
+    /// Profile the gas consumed by tests, saving a speedscope profile of each test to the given
+    /// directory along with the gas consumed by all tests in the folded-stack format of flamegraph
+    /// tools (in `gas.folded`)
+    #[clap(long = "profile-gas", value_name = "DIR")]
+    pub profile_gas: Option<String>,
+
+    /// Debug the first test selected by the filter, serving the Debug Adapter Protocol on stdin
+    /// and stdout
+    #[clap(long = "debug")]
+    pub debug: bool,
 }
 
 fn format_module_id(
@@ -159,6 +180,9 @@ impl UnitTestingConfig {
             seed: None,
             deterministic_generation: false,
             trace_execution: None,
+            trace_json: false,
+            profile_gas: None,
+            debug: false,
         }
     }
 
@@ -269,6 +293,12 @@ impl UnitTestingConfig {
             return Ok((shared_writer.into_inner().unwrap(), true));
         }
 
+        if self.debug {
+            let writer = shared_writer.into_inner().unwrap();
+            let ok = self.debug_unit_test(test_plan, native_function_table, cost_table)?;
+            return Ok((writer, ok));
+        }
+
         writeln!(shared_writer.lock().unwrap(), "Running Move unit tests")?;
         let trace_location = match &self.trace_execution {
             Some(None) => Some("traces".to_string()),
@@ -289,6 +319,14 @@ impl UnitTestingConfig {
         )
         .unwrap();
 
+        if self.trace_json {
+            test_runner.save_traces_as_json();
+        }
+
+        if let Some(location) = &self.profile_gas {
+            test_runner.profile_gas(location.clone())?;
+        }
+
         if let Some(filter_str) = &self.filter {
             test_runner.filter(filter_str)
         }
@@ -298,9 +336,77 @@ impl UnitTestingConfig {
             test_results.report_statistics(&shared_writer, report_type)?;
         }
 
+        move_vm_profiler::tracing_feature_enabled! {
+            if let Some(location) = &self.profile_gas {
+                test_results
+                    .save_folded_gas_profile(&format!("{}/{}", location, GAS_PROFILE_FILE))?;
+            }
+        }
+
         let ok = test_results.summarize(&shared_writer)?;
 
         let writer = shared_writer.into_inner().unwrap();
         Ok((writer, ok))
     }
+
+    /// Runs the first test selected by the filter with tracing enabled and serves a debugger
+    /// replaying its execution over the Debug Adapter Protocol on stdin and stdout (see
+    /// `debugger`). Returns `true` if the test passed.
+    fn debug_unit_test(
+        &self,
+        test_plan: TestPlan,
+        native_function_table: Option<NativeFunctionTable>,
+        cost_table: Option<CostTable>,
+    ) -> Result<bool> {
+        let module_info = test_plan.module_info.clone();
+        let mapped_files = test_plan.mapped_files.clone();
+        let native_function_table = native_function_table.unwrap_or_else(|| {
+            move_stdlib_natives::all_natives(
+                AccountAddress::from_hex_literal("0x1").unwrap(),
+                move_stdlib_natives::GasParameters::zeros(),
+                /* silent */ true,
+            )
+        });
+        let mut test_runner = TestRunner::new(
+            self.gas_limit.unwrap_or(DEFAULT_EXECUTION_BOUND),
+            /* num_threads */ 1,
+            self.report_stacktrace_on_abort,
+            self.seed,
+            /* num_iters */ 1,
+            self.deterministic_generation,
+            /* trace_location */ None,
+            test_plan,
+            Some(debugger::silence_debug_natives(native_function_table)),
+            cost_table,
+        )?;
+        test_runner.keep_traces()?;
+        if let Some(filter_str) = &self.filter {
+            test_runner.filter(filter_str)
+        }
+        let Some((module_id, test_name)) = test_runner.retain_first_test() else {
+            bail!("No unit test to debug");
+        };
+        let test_name = format!(
+            "{}::{}",
+            format_module_id(&module_info, &module_id),
+            test_name
+        );
+
+        let test_output = Mutex::new(vec![]);
+        let mut test_results = test_runner.run(&test_output)?;
+        let trace = test_results.take_trace().unwrap_or_default();
+        let ok = test_results.summarize(&test_output)?;
+        let test_output = String::from_utf8_lossy(&test_output.into_inner().unwrap()).to_string();
+
+        let session = debugger::session::DebugSession::new(trace, module_info, mapped_files);
+        debugger::run_debug_adapter(
+            session,
+            test_name,
+            test_output,
+            ok,
+            std::io::stdin().lock(),
+            std::io::stdout(),
+        )?;
+        Ok(ok)
+    }
 }
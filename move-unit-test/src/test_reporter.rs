@@ -17,6 +17,7 @@ use move_core_types::{
 };
 use move_ir_types::location::Loc;
 use move_trace_format::format::MoveTrace;
+use move_vm_profiler::GasProfiler;
 use std::{
     collections::{BTreeMap, BTreeSet},
     io::{Result, Write},
@@ -56,6 +57,7 @@ pub struct TestRunInfo {
     pub elapsed_time: Duration,
     pub instructions_executed: u64,
     pub trace: Option<MoveTrace>,
+    pub gas_profile: Option<GasProfiler>,
 }
 
 type TestRuns<T> = BTreeMap<String, Vec<T>>;
@@ -87,19 +89,80 @@ impl TestRunInfo {
         elapsed_time: Duration,
         instructions_executed: u64,
         trace: Option<MoveTrace>,
+        gas_profile: Option<GasProfiler>,
     ) -> Self {
         Self {
             elapsed_time,
             instructions_executed,
             trace,
+            gas_profile,
         }
     }
 
-    pub fn save_trace(&self, path: &str) -> Result<()> {
-        if let Some(trace) = &self.trace {
-            write_string_to_file(path, &format!("{}", trace.to_json()))
+    /// Saves the trace kept in memory (if any) in the binary format, or in the JSON one if `json`
+    /// is set.
+    pub fn save_trace(&self, path: &str, json: bool) -> Result<()> {
+        let Some(trace) = &self.trace else {
+            return Ok(());
+        };
+        if json {
+            write_string_to_file(path, &format!("{}", trace.to_json()))?;
         } else {
-            Ok(())
+            if let Some(parent) = Path::new(path).parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            trace.to_binary(std::io::BufWriter::new(std::fs::File::create(path)?))?;
+        }
+        Ok(())
+    }
+}
+
+// Gas profiles are only recorded by the VM when the `tracing` feature is enabled.
+move_vm_profiler::tracing_feature_enabled! {
+    impl TestRunInfo {
+        pub fn save_gas_profile(&self, path: &str) -> Result<()> {
+            if let Some(gas_profile) = &self.gas_profile {
+                if let Some(parent) = Path::new(path).parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                gas_profile.save(Path::new(path))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl TestResults {
+        /// Writes the gas consumed in each stack of functions over all the tests that were
+        /// profiled, in the folded-stack format of flamegraph tools.
+        pub fn save_folded_gas_profile(&self, path: &str) -> Result<()> {
+            let passed = self
+                .final_statistics
+                .passed
+                .values()
+                .flat_map(|runs| runs.values())
+                .flatten();
+            let failed = self
+                .final_statistics
+                .failed
+                .values()
+                .flat_map(|runs| runs.values())
+                .flatten()
+                .map(|failure| &failure.test_run_info);
+            let mut stacks = BTreeMap::new();
+            for gas_profile in passed
+                .chain(failed)
+                .filter_map(|info| info.gas_profile.as_ref())
+            {
+                for (stack, gas) in gas_profile.folded_stacks() {
+                    *stacks.entry(stack).or_insert(0) += gas;
+                }
+            }
+            let mut folded = String::new();
+            for (stack, gas) in stacks {
+                folded.push_str(&format!("{stack} {gas}\n"));
+            }
+            write_string_to_file(path, &folded)
         }
     }
 }
@@ -436,6 +499,25 @@ impl TestResults {
         }
     }
 
+    /// Takes the first execution trace kept for the tests that were run (traces of passed tests
+    /// come first), if any.
+    pub fn take_trace(&mut self) -> Option<MoveTrace> {
+        let passed = self
+            .final_statistics
+            .passed
+            .values_mut()
+            .flat_map(|runs| runs.values_mut())
+            .flatten();
+        let failed = self
+            .final_statistics
+            .failed
+            .values_mut()
+            .flat_map(|runs| runs.values_mut())
+            .flatten()
+            .map(|failure| &mut failure.test_run_info);
+        passed.chain(failed).find_map(|info| info.trace.take())
+    }
+
     pub fn report_statistics<W: Write>(
         &self,
         writer: &Mutex<W>,
@@ -25,6 +25,7 @@ use move_compiler::{
 use move_core_types::{
     account_address::AccountAddress,
     effects::ChangeSet,
+    i256::I256,
     identifier::IdentStr,
     language_storage::{ModuleId, TypeTag},
     runtime_value::{serialize_values, MoveValue},
@@ -32,14 +33,25 @@ use move_core_types::{
     vm_status::StatusCode,
 };
 use move_trace_format::format::MoveTraceBuilder;
-use move_vm_runtime::{move_vm::MoveVM, native_functions::NativeFunctionTable};
+use move_vm_config::runtime::VMConfig;
+use move_vm_runtime::{
+    move_vm::MoveVM, native_functions::NativeFunctionTable, shared_module_cache::SharedModuleCache,
+};
 use move_vm_test_utils::{
     gas_schedule::{unit_cost_schedule, CostTable, Gas, GasStatus},
     InMemoryStorage,
 };
+use move_vm_types::gas::GasMeter;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use rayon::prelude::*;
-use std::{collections::BTreeMap, io::Write, marker::Send, sync::Mutex, time::Instant};
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    marker::Send,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 
 use move_vm_runtime::native_extensions::NativeContextExtensions;
 
@@ -50,10 +62,16 @@ pub struct SharedTestingConfig {
     cost_table: CostTable,
     native_function_table: NativeFunctionTable,
     starting_storage_state: InMemoryStorage,
+    /// The modules in `starting_storage_state`, verified once for the VMs of all tests.
+    module_cache: Arc<SharedModuleCache>,
     prng_seed: Option<u64>,
     num_iters: u64,
     deterministic_generation: bool,
     trace_location: Option<String>,
+    /// Whether traces are saved in the JSON format rather than streamed in the binary format
+    trace_json: bool,
+    keep_traces: bool,
+    gas_profile_location: Option<String>,
 }
 
 pub struct TestRunner {
@@ -62,6 +80,15 @@ pub struct TestRunner {
     tests: TestPlan,
 }
 
+/// Creates a tracer streaming the trace to a file in the binary format.
+fn streaming_tracer(path: &str) -> std::io::Result<MoveTraceBuilder> {
+    if let Some(parent) = Path::new(path).parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    MoveTraceBuilder::new_streaming(Box::new(file))
+}
+
 /// Setup storage state with the set of modules that will be needed for all tests
 fn setup_test_storage<'a>(
     modules: impl Iterator<Item = &'a CompiledModule>,
@@ -79,6 +106,45 @@ fn setup_test_storage<'a>(
     Ok(storage)
 }
 
+/// Returns an error if execution tracing is requested but the binary was not compiled with the
+/// `tracing` feature flag set.
+fn ensure_tracing_enabled() -> Result<()> {
+    move_vm_profiler::tracing_feature_disabled! {
+        return Err(anyhow::anyhow!(
+            "Tracing is enabled but the binary was not compiled with the `tracing` \
+             feature flag set. Rebuild binary with `--features tracing`"
+        ));
+    };
+    Ok(())
+}
+
+move_vm_profiler::tracing_feature_enabled! {
+    /// Names a function given its full name (`0x<address>::<module>::<function>`) as in source,
+    /// with the name of its address if it has one. Other names are left as they are.
+    fn source_function_name(
+        module_map: &BTreeMap<ModuleId, NamedCompiledModule>,
+        full_name: &str,
+    ) -> String {
+        let mut parts = full_name.rsplitn(3, "::");
+        let (Some(function), Some(module), Some(address)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return full_name.to_string();
+        };
+        let (Ok(address), Ok(module)) = (
+            AccountAddress::from_hex_literal(address),
+            move_core_types::identifier::Identifier::new(module),
+        ) else {
+            return full_name.to_string();
+        };
+        format!(
+            "{}::{}",
+            format_module_id(module_map, &ModuleId::new(address, module)),
+            function
+        )
+    }
+}
+
 fn convert_clever_move_abort_error(
     abort_code: u64,
     location: &Location,
@@ -124,18 +190,20 @@ impl TestRunner {
     ) -> Result<Self> {
         // If we want to trace the execution, check that the tracing compilation feature is
         // enabled, otherwise we won't generate a trace.
-        move_vm_profiler::tracing_feature_disabled! {
-            if trace_location.is_some() {
-                return Err(anyhow::anyhow!(
-                    "Tracing is enabled but the binary was not compiled with the `tracing` \
-                     feature flag set. Rebuild binary with `--features tracing`"
-                ));
-            }
-        };
+        if trace_location.is_some() {
+            ensure_tracing_enabled()?;
+        }
 
         let modules = tests.module_info.values().map(|info| &info.module);
         let starting_storage_state =
-            setup_test_storage(modules, tests.bytecode_deps_modules.iter())?;
+            setup_test_storage(modules.clone(), tests.bytecode_deps_modules.iter())?;
+        let vm_config = VMConfig::default();
+        let module_cache = SharedModuleCache::new_with_modules(
+            vm_config.verifier,
+            vm_config.binary_config,
+            modules.chain(tests.bytecode_deps_modules.iter()).cloned(),
+        )
+        .map_err(|err| anyhow::anyhow!("Failed to verify test modules: {err}"))?;
         let native_function_table = native_function_table.unwrap_or_else(|| {
             move_stdlib_natives::all_natives(
                 AccountAddress::from_hex_literal("0x1").unwrap(),
@@ -147,6 +215,7 @@ impl TestRunner {
             testing_config: SharedTestingConfig {
                 report_stacktrace_on_abort,
                 starting_storage_state,
+                module_cache: Arc::new(module_cache),
                 execution_bound,
                 native_function_table,
                 // TODO: our current implementation uses a unit cost table to prevent programs from
@@ -159,6 +228,9 @@ impl TestRunner {
                 num_iters,
                 deterministic_generation,
                 trace_location,
+                trace_json: false,
+                keep_traces: false,
+                gas_profile_location: None,
             },
             num_threads,
             tests,
@@ -188,6 +260,43 @@ impl TestRunner {
             })
     }
 
+    /// Save the execution traces of the tests in the JSON format rather than in the binary one.
+    pub fn save_traces_as_json(&mut self) {
+        self.testing_config.trace_json = true;
+    }
+
+    /// Keep the execution traces of the tests in memory (in their `TestRunInfo`) whether or not
+    /// they are also saved to files.
+    pub fn keep_traces(&mut self) -> Result<()> {
+        ensure_tracing_enabled()?;
+        self.testing_config.keep_traces = true;
+        Ok(())
+    }
+
+    /// Profile the gas consumed by each test, saving one profile per test to the given location.
+    pub fn profile_gas(&mut self, location: String) -> Result<()> {
+        ensure_tracing_enabled()?;
+        self.testing_config.gas_profile_location = Some(location);
+        Ok(())
+    }
+
+    /// Removes all tests but the first one from the set of tests to run, and returns the module
+    /// and the name of the remaining test (if any).
+    pub fn retain_first_test(&mut self) -> Option<(ModuleId, String)> {
+        let mut first = None;
+        for (module_id, module_test) in self.tests.module_tests.iter_mut() {
+            if first.is_some() {
+                module_test.tests.clear();
+                continue;
+            }
+            if let Some(test_name) = module_test.tests.keys().next().cloned() {
+                module_test.tests.retain(|name, _| name == &test_name);
+                first = Some((module_id.clone(), test_name));
+            }
+        }
+        first
+    }
+
     pub fn filter(&mut self, test_name_slice: &str) {
         for (module_id, module_test) in self.tests.module_tests.iter_mut() {
             if module_id.name().as_str().contains(test_name_slice) {
@@ -255,32 +364,60 @@ impl SharedTestingConfig {
         test_plan: &ModuleTestPlan,
         function_name: &str,
         arguments: Vec<MoveValue>,
+        trace_file: Option<&str>,
     ) -> (
         VMResult<ChangeSet>,
         VMResult<NativeContextExtensions>,
         VMResult<Vec<Vec<u8>>>,
         TestRunInfo,
     ) {
-        let move_vm = MoveVM::new(self.native_function_table.clone()).unwrap();
+        let move_vm = MoveVM::new_with_shared_module_cache(
+            self.native_function_table.clone(),
+            VMConfig::default(),
+            self.module_cache.clone(),
+        )
+        .unwrap();
         let extensions = extensions::new_extensions();
 
-        let mut move_tracer = MoveTraceBuilder::new();
-        let tracer = if self.trace_location.is_some() {
-            Some(&mut move_tracer)
-        } else {
-            None
+        // Traces are streamed to their files as they are built, unless they are kept in memory
+        // anyway or are saved in the JSON format (which is written all at once).
+        let stream_trace = !self.keep_traces && !self.trace_json;
+        let (mut move_tracer, streaming) = match trace_file {
+            Some(path) if stream_trace => match streaming_tracer(path) {
+                Ok(tracer) => (Some(tracer), true),
+                Err(e) => {
+                    eprintln!("Unable to save trace to {path} -- {:?}", e);
+                    (None, true)
+                }
+            },
+            Some(_) => (Some(MoveTraceBuilder::new()), false),
+            None if self.keep_traces => (Some(MoveTraceBuilder::new()), false),
+            None => (None, false),
         };
 
         let mut session =
             move_vm.new_session_with_extensions(&self.starting_storage_state, extensions);
         let mut gas_meter = GasStatus::new(&self.cost_table, Gas::new(self.execution_bound));
         move_vm_profiler::tracing_feature_enabled! {
+            use move_vm_config::runtime::VMProfilerConfig;
             use move_vm_profiler::GasProfiler;
-            use move_vm_types::gas::GasMeter;
-            gas_meter.set_profiler(GasProfiler::init_default_cfg(
-                function_name.to_owned(),
-                self.execution_bound,
-            ));
+            // Profiles are in internal gas units, as reported to the profiler by the VM.
+            let start_gas = GasMeter::remaining_gas(&gas_meter).into();
+            let profiler = match &self.gas_profile_location {
+                // Functions are named in full here, to be named after their modules in source
+                // when the profile is saved.
+                Some(location) => GasProfiler::init(
+                    &Some(VMProfilerConfig {
+                        full_path: location.into(),
+                        track_bytecode_instructions: false,
+                        use_long_function_name: true,
+                    }),
+                    function_name.to_owned(),
+                    start_gas,
+                ),
+                None => GasProfiler::init_default_cfg(function_name.to_owned(), start_gas),
+            };
+            gas_meter.set_profiler(profiler);
         }
 
         // TODO: collect VM logs if the verbose flag (i.e, `self.verbose`) is set
@@ -291,7 +428,7 @@ impl SharedTestingConfig {
             vec![], // no ty args, at least for now
             serialize_values(arguments.iter()),
             &mut gas_meter,
-            tracer,
+            move_tracer.as_mut(),
         );
         let mut return_result = serialized_return_values_result.map(|res| {
             res.return_values
@@ -304,8 +441,28 @@ impl SharedTestingConfig {
                 err.remove_exec_state();
             }
         }
-        let trace = if self.trace_location.is_some() {
-            Some(move_tracer.into_trace())
+        let trace = match move_tracer {
+            Some(tracer) if streaming => {
+                if let Err(e) = tracer.finish() {
+                    eprintln!("Unable to save trace to {} -- {:?}", trace_file.unwrap(), e);
+                }
+                None
+            }
+            Some(tracer) => Some(tracer.into_trace()),
+            None => None,
+        };
+        // Profiles saved with the tests are closed here, so that they are not also dumped to files
+        // of their own.
+        move_vm_profiler::tracing_feature_enabled! {
+            if self.gas_profile_location.is_some() {
+                let gas_left = GasMeter::remaining_gas(&gas_meter).into();
+                if let Some(profiler) = gas_meter.get_profiler_mut() {
+                    profiler.close(gas_left);
+                }
+            }
+        }
+        let gas_profile = if self.gas_profile_location.is_some() {
+            gas_meter.get_profiler_mut().cloned()
         } else {
             None
         };
@@ -318,6 +475,7 @@ impl SharedTestingConfig {
                 .unwrap()
                 .into(),
             trace,
+            gas_profile,
         );
         match session.finish_with_extensions().0 {
             Ok((cs, extensions)) => (Ok(cs), Ok(extensions), return_result, test_run_info),
@@ -407,6 +565,12 @@ impl SharedTestingConfig {
             TypeTag::U64 => MoveValue::U64(rng.gen::<u64>()),
             TypeTag::U128 => MoveValue::U128(rng.gen::<u128>()),
             TypeTag::U256 => MoveValue::U256(rng.gen::<U256>()),
+            TypeTag::I8 => MoveValue::I8(rng.gen::<i8>()),
+            TypeTag::I16 => MoveValue::I16(rng.gen::<i16>()),
+            TypeTag::I32 => MoveValue::I32(rng.gen::<i32>()),
+            TypeTag::I64 => MoveValue::I64(rng.gen::<i64>()),
+            TypeTag::I128 => MoveValue::I128(rng.gen::<i128>()),
+            TypeTag::I256 => MoveValue::I256(rng.gen::<I256>()),
             TypeTag::Vector(ty) => {
                 let len = rng.gen_range(0..1024);
                 let values = (0..len)
@@ -434,25 +598,51 @@ impl SharedTestingConfig {
         prng_seed: Option<u64>,
         is_last_execution_of_test: bool,
     ) -> bool {
-        let (_cs_result, _ext_result, exec_result, test_run_info) =
-            self.execute_via_move_vm(test_plan, function_name, arguments);
+        let test_file_name = format!(
+            "{}__{}{}",
+            format_module_id(output.test_info, &output.test_plan.module_id).replace("::", "__"),
+            function_name,
+            if let Some(seed) = prng_seed {
+                format!("_seed_{}", seed)
+            } else {
+                "".to_string()
+            }
+        );
 
         // Save the trace -- one per test -- for each test that we have traced (and if tracing is
-        // enabled).
-        if let Some(location) = &self.trace_location {
-            let trace_file_location = format!(
-                "{}/{}__{}{}.json",
-                location,
-                format_module_id(output.test_info, &output.test_plan.module_id).replace("::", "__"),
-                function_name,
-                if let Some(seed) = prng_seed {
-                    format!("_seed_{}", seed)
-                } else {
-                    "".to_string()
+        // enabled). Binary traces are streamed to their files during execution, unless they are
+        // also kept in memory.
+        let trace_file_location = self.trace_location.as_ref().map(|location| {
+            let extension = if self.trace_json { "json" } else { "trace" };
+            format!("{location}/{test_file_name}.{extension}")
+        });
+        let (_cs_result, _ext_result, exec_result, test_run_info) = self.execute_via_move_vm(
+            test_plan,
+            function_name,
+            arguments,
+            trace_file_location.as_deref(),
+        );
+        if let Some(location) = &trace_file_location {
+            if let Err(e) = test_run_info.save_trace(location, self.trace_json) {
+                eprintln!("Unable to save trace to {location} -- {:?}", e);
+            }
+        }
+
+        // Likewise for gas profiles, after naming their functions as in source.
+        move_vm_profiler::tracing_feature_enabled! {
+            let mut test_run_info = test_run_info;
+            if let Some(location) = &self.gas_profile_location {
+                if let Some(gas_profile) = &mut test_run_info.gas_profile {
+                    gas_profile
+                        .rename_frames(|name| source_function_name(global_test_context, name));
+                }
+                let profile_file_location = format!("{}/{}.json", location, test_file_name);
+                if let Err(e) = test_run_info.save_gas_profile(&profile_file_location) {
+                    eprintln!(
+                        "Unable to save gas profile to {profile_file_location} -- {:?}",
+                        e
+                    );
                 }
-            );
-            if let Err(e) = test_run_info.save_trace(&trace_file_location) {
-                eprintln!("Unable to save trace to {trace_file_location} -- {:?}", e);
             }
         }
 
@@ -584,3 +774,29 @@ impl SharedTestingConfig {
         self.exec_module_tests_with_move_vm(test_plan, test_info, &output)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::streaming_tracer;
+    use move_trace_format::format::{Effect, MoveTrace, TraceEvent};
+
+    #[test]
+    fn traces_streamed_to_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("traces").join("m__test.trace");
+        let mut tracer = streaming_tracer(path.to_str().unwrap()).unwrap();
+        tracer.effect(Effect::ExecutionError("ABORTED".to_string()));
+        // events are written out as they are recorded
+        assert_eq!(tracer.current_trace_offset(), 1);
+        assert!(tracer.trace.events.is_empty());
+        tracer.finish().unwrap();
+
+        let trace = MoveTrace::read(std::fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(
+            trace.events,
+            vec![TraceEvent::Effect(Box::new(Effect::ExecutionError(
+                "ABORTED".to_string()
+            )))]
+        );
+    }
+}
@@ -82,7 +82,7 @@ impl Default for VMRuntimeLimitsConfig {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VMProfilerConfig {
     /// User configured full path override
     pub full_path: std::path::PathBuf,
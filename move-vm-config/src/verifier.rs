@@ -8,7 +8,7 @@ pub const DEFAULT_MAX_CONSTANT_VECTOR_LEN: u64 = 1024 * 1024;
 pub const DEFAULT_MAX_IDENTIFIER_LENGTH: u64 = 128;
 pub const DEFAULT_MAX_VARIANTS: u64 = 127;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VerifierConfig {
     pub max_loop_depth: Option<usize>,
     pub max_function_parameters: Option<usize>,
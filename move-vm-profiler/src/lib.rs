@@ -5,18 +5,20 @@
 use move_vm_config::runtime::VMProfilerConfig;
 use serde::Serialize;
 use std::collections::BTreeMap;
+#[cfg(feature = "tracing")]
+use std::{io::Write, path::Path};
 
 #[cfg(feature = "tracing")]
 use tracing::info;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct FrameName {
     name: String,
     file: String,
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Shared {
     frames: Vec<FrameName>,
 
@@ -24,7 +26,7 @@ pub struct Shared {
     frame_table: BTreeMap<String, usize>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Event {
     #[serde(rename(serialize = "type"))]
     ty: String,
@@ -32,7 +34,7 @@ pub struct Event {
     at: u64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Profile {
     #[serde(rename(serialize = "type"))]
@@ -45,7 +47,7 @@ pub struct Profile {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GasProfiler {
     exporter: String,
@@ -184,7 +186,6 @@ impl GasProfiler {
     pub fn to_file(&self) {
         use std::ffi::{OsStr, OsString};
         use std::fs::File;
-        use std::io::Write;
         use std::time::SystemTime;
 
         let Some(config) = &self.config else {
@@ -220,16 +221,101 @@ impl GasProfiler {
         info!("Gas profile written to file: {}", p.display());
     }
 
+    /// Closes the frames left open (e.g. when execution aborted) and the top-level frame with the
+    /// given gas left, without writing the profile to a file (which `finish` then won't do either).
     #[cfg(feature = "tracing")]
-    pub fn finish(&mut self) {
+    pub fn close(&mut self, gas_end: u64) {
         if self.finished {
             return;
         }
         self.finished = true;
+        if self.config.is_none() || self.start_gas == 0 {
+            return;
+        }
+        let at = self.start_gas() - gas_end;
+        let mut open_frames = vec![];
+        for event in &self.profiles[0].events {
+            match event.ty.as_str() {
+                Self::OPEN_FRAME_IDENT => open_frames.push(event.frame),
+                Self::CLOSE_FRAME_IDENT => {
+                    open_frames.pop();
+                }
+                _ => (),
+            }
+        }
+        for frame in open_frames.into_iter().rev() {
+            self.profiles[0].events.push(Event {
+                ty: Self::CLOSE_FRAME_IDENT.to_string(),
+                frame,
+                at,
+            });
+        }
+        self.profiles[0].end_value = at;
+    }
+
+    #[cfg(feature = "tracing")]
+    pub fn finish(&mut self) {
+        if self.finished {
+            return;
+        }
         let end_gas = self.start_gas() - self.profiles[0].end_value;
-        let mut q = Some(self);
-        profile_close_frame_impl!(&mut q, Self::TOP_LEVEL_FRAME_NAME.to_string(), end_gas);
-        profile_dump_file!(q.unwrap());
+        self.close(end_gas);
+        profile_dump_file!(self);
+    }
+
+    /// Renames the frames of the profile, given their full names.
+    #[cfg(feature = "tracing")]
+    pub fn rename_frames(&mut self, rename: impl Fn(&str) -> String) {
+        for frame in &mut self.shared.frames {
+            frame.name = rename(&frame.file);
+        }
+    }
+
+    /// Returns the gas consumed in each stack of frames, keyed by the names of the frames of the
+    /// stack separated by `;` as in the folded-stack format of flamegraph tools. Gas is only
+    /// attributed to the innermost frame of a stack, and the top-level frame is left out of stacks
+    /// other than its own.
+    #[cfg(feature = "tracing")]
+    pub fn folded_stacks(&self) -> BTreeMap<String, u64> {
+        let mut stacks = BTreeMap::new();
+        let mut stack: Vec<u64> = vec![];
+        let mut last_at = 0;
+        // profilers are created with a single profile, but there are no stacks without one
+        let Some(profile) = self.profiles.first() else {
+            return stacks;
+        };
+        for event in &profile.events {
+            let gas = event.at.saturating_sub(last_at);
+            if gas > 0 && !stack.is_empty() {
+                let frames = if stack.len() > 1 {
+                    &stack[1..]
+                } else {
+                    &stack[..]
+                };
+                let key = frames
+                    .iter()
+                    .map(|idx| self.shared.frames[*idx as usize].name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(";");
+                *stacks.entry(key).or_insert(0) += gas;
+            }
+            last_at = event.at;
+            match event.ty.as_str() {
+                Self::OPEN_FRAME_IDENT => stack.push(event.frame),
+                Self::CLOSE_FRAME_IDENT => {
+                    stack.pop();
+                }
+                _ => (),
+            }
+        }
+        stacks
+    }
+
+    /// Writes the profile to the given file, in the speedscope format.
+    #[cfg(feature = "tracing")]
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(&self)?;
+        std::fs::File::create(path)?.write_all(json.as_bytes())
     }
 }
 
@@ -388,3 +474,68 @@ pub fn ensure_move_vm_profiler_disabled() {
         panic!("Cannot run with Move VM tracing feature enabled");
     }
 }
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::GasProfiler;
+    use move_vm_config::runtime::VMProfilerConfig;
+    use std::collections::BTreeMap;
+
+    /// Profiles a call from the top-level frame to `a`, which calls `b` and then aborts.
+    fn profile() -> GasProfiler {
+        let config = VMProfilerConfig {
+            full_path: "gas_profile.json".into(),
+            track_bytecode_instructions: false,
+            use_long_function_name: false,
+        };
+        let mut profiler = GasProfiler::init(&Some(config), "test".to_string(), 100);
+        profiler.open_frame("a".to_string(), "0x1::m::a".to_string(), 90);
+        profiler.open_frame("b".to_string(), "0x1::m::b".to_string(), 80);
+        profiler.close_frame("b".to_string(), "0x1::m::b".to_string(), 70);
+        profiler.close(50);
+        profiler
+    }
+
+    fn stacks(stacks: &[(&str, u64)]) -> BTreeMap<String, u64> {
+        stacks
+            .iter()
+            .map(|(stack, gas)| (stack.to_string(), *gas))
+            .collect()
+    }
+
+    #[test]
+    fn folded_stacks() {
+        let profiler = profile();
+        // gas is attributed to innermost frames, and `a` is closed along with the top-level frame
+        assert_eq!(
+            profiler.folded_stacks(),
+            stacks(&[("root", 10), ("a", 30), ("a;b", 10)])
+        );
+    }
+
+    #[test]
+    fn folded_stacks_of_renamed_frames() {
+        let mut profiler = profile();
+        profiler.rename_frames(|name| name.replace("0x1", "std"));
+        assert_eq!(
+            profiler.folded_stacks(),
+            stacks(&[("std::m::a", 30), ("std::m::a;std::m::b", 10)])
+                .into_iter()
+                .chain([("root".to_string(), 10)])
+                .collect()
+        );
+    }
+
+    #[test]
+    fn folded_stacks_without_profiles() {
+        let mut profiler = profile();
+        profiler.profiles.clear();
+        assert!(profiler.folded_stacks().is_empty());
+
+        // nothing is recorded without a profiler config
+        let mut profiler = GasProfiler::init(&None, "test".to_string(), 100);
+        profiler.open_frame("a".to_string(), "0x1::m::a".to_string(), 90);
+        profiler.close(50);
+        assert!(profiler.folded_stacks().is_empty());
+    }
+}
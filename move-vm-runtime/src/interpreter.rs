@@ -13,7 +13,9 @@ use crate::{
 use fail::fail_point;
 use move_binary_format::{
     errors::*,
-    file_format::{Bytecode, FunctionHandleIndex, FunctionInstantiationIndex, JumpTableInner},
+    file_format::{
+        Bytecode, FunctionHandleIndex, FunctionInstantiationIndex, JumpTableInner, SignatureIndex,
+    },
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -27,8 +29,8 @@ use move_vm_types::{
     gas::{GasMeter, SimpleInstruction},
     loaded_data::runtime_types::Type,
     values::{
-        self, IntegerValue, Locals, Reference, Struct, StructRef, VMValueCast, Value, Variant,
-        VariantRef, Vector, VectorRef,
+        self, Closure, IntegerValue, Locals, Reference, Struct, StructRef, VMValueCast, Value,
+        Variant, VariantRef, Vector, VectorRef,
     },
     views::TypeView,
 };
@@ -292,12 +294,20 @@ impl Interpreter {
                     // Note: the caller will find the callee's return values at the top of the shared operand stack
                     current_frame = frame;
                 }
-                ExitCode::CallGeneric(idx) => {
-                    // TODO(Gas): We should charge gas as we do type substitution...
-                    let ty_args = resolver
-                        .instantiate_generic_function(idx, current_frame.ty_args())
-                        .map_err(|e| set_err_info!(current_frame, e))?;
-                    let func = resolver.function_from_instantiation(idx);
+                ExitCode::CallGeneric(_) | ExitCode::CallClosure(..) => {
+                    let (func, ty_args) = match exit_code {
+                        ExitCode::CallGeneric(idx) => {
+                            // TODO(Gas): We should charge gas as we do type substitution...
+                            let ty_args = resolver
+                                .instantiate_generic_function(idx, current_frame.ty_args())
+                                .map_err(|e| set_err_info!(current_frame, e))?;
+                            (resolver.function_from_instantiation(idx), ty_args)
+                        }
+                        ExitCode::CallClosure(function, ty_args) => {
+                            (loader.function_at(function), ty_args)
+                        }
+                        ExitCode::Return | ExitCode::Call(_) => unreachable!(),
+                    };
                     open_frame!(
                         tracer,
                         &ty_args,
@@ -533,6 +543,12 @@ impl Interpreter {
                 IntegerValue::U64(x) => Value::u64(x),
                 IntegerValue::U128(x) => Value::u128(x),
                 IntegerValue::U256(x) => Value::u256(x),
+                IntegerValue::I8(x) => Value::i8(x),
+                IntegerValue::I16(x) => Value::i16(x),
+                IntegerValue::I32(x) => Value::i32(x),
+                IntegerValue::I64(x) => Value::i64(x),
+                IntegerValue::I128(x) => Value::i128(x),
+                IntegerValue::I256(x) => Value::i256(x),
             })
         })
     }
@@ -851,6 +867,9 @@ enum ExitCode {
     Return,
     Call(FunctionHandleIndex),
     CallGeneric(FunctionInstantiationIndex),
+    /// A call through a closure, to the function with the given loader index and type arguments.
+    /// The closure's captured values and arguments are already on the operand stack.
+    CallClosure(usize, Vec<Type>),
 }
 
 impl Frame {
@@ -947,6 +966,30 @@ impl Frame {
                 gas_meter.charge_simple_instr(S::LdU256)?;
                 interpreter.operand_stack.push(Value::u256(**int_const))?;
             }
+            Bytecode::LdI8(int_const) => {
+                gas_meter.charge_simple_instr(S::LdI8)?;
+                interpreter.operand_stack.push(Value::i8(*int_const))?;
+            }
+            Bytecode::LdI16(int_const) => {
+                gas_meter.charge_simple_instr(S::LdI16)?;
+                interpreter.operand_stack.push(Value::i16(*int_const))?;
+            }
+            Bytecode::LdI32(int_const) => {
+                gas_meter.charge_simple_instr(S::LdI32)?;
+                interpreter.operand_stack.push(Value::i32(*int_const))?;
+            }
+            Bytecode::LdI64(int_const) => {
+                gas_meter.charge_simple_instr(S::LdI64)?;
+                interpreter.operand_stack.push(Value::i64(*int_const))?;
+            }
+            Bytecode::LdI128(int_const) => {
+                gas_meter.charge_simple_instr(S::LdI128)?;
+                interpreter.operand_stack.push(Value::i128(**int_const))?;
+            }
+            Bytecode::LdI256(int_const) => {
+                gas_meter.charge_simple_instr(S::LdI256)?;
+                interpreter.operand_stack.push(Value::i256(**int_const))?;
+            }
             Bytecode::LdConst(idx) => {
                 let constant = resolver.constant_at(*idx);
                 gas_meter.charge_ld_const(NumBytes::new(constant.data.len() as u64))?;
@@ -1005,6 +1048,34 @@ impl Frame {
             Bytecode::CallGeneric(idx) => {
                 return Ok(InstrRet::ExitCode(ExitCode::CallGeneric(*idx)));
             }
+            Bytecode::PackClosure(fh_idx, sig_idx) => {
+                let function = resolver.function_index_from_handle(*fh_idx);
+                Self::pack_closure(resolver, interpreter, gas_meter, function, vec![], *sig_idx)?;
+            }
+            Bytecode::PackClosureGeneric(fi_idx, sig_idx) => {
+                let function = resolver.function_index_from_instantiation(*fi_idx);
+                let ty_args = resolver.instantiate_generic_function(*fi_idx, ty_args)?;
+                Self::pack_closure(
+                    resolver,
+                    interpreter,
+                    gas_meter,
+                    function,
+                    ty_args,
+                    *sig_idx,
+                )?;
+            }
+            Bytecode::CallClosure(sig_idx) => {
+                let param_count = resolver.closure_param_count(*sig_idx)?;
+                let (function, ty_args, captured) =
+                    interpreter.operand_stack.pop_as::<Closure>()?.unpack();
+                // The captured values are the leading arguments of the function, so they go
+                // on the stack below the arguments the closure was called with.
+                let args = interpreter.operand_stack.popn(param_count as u16)?;
+                for value in captured.chain(args) {
+                    interpreter.operand_stack.push(value)?;
+                }
+                return Ok(InstrRet::ExitCode(ExitCode::CallClosure(function, ty_args)));
+            }
             Bytecode::MutBorrowLoc(idx) | Bytecode::ImmBorrowLoc(idx) => {
                 let instr = match instruction {
                     Bytecode::MutBorrowLoc(_) => S::MutBorrowLoc,
@@ -1142,6 +1213,48 @@ impl Frame {
                     .operand_stack
                     .push(Value::u256(integer_value.cast_u256()?))?;
             }
+            Bytecode::CastI8 => {
+                gas_meter.charge_simple_instr(S::CastI8)?;
+                let integer_value = interpreter.operand_stack.pop_as::<IntegerValue>()?;
+                interpreter
+                    .operand_stack
+                    .push(Value::i8(integer_value.cast_i8()?))?;
+            }
+            Bytecode::CastI16 => {
+                gas_meter.charge_simple_instr(S::CastI16)?;
+                let integer_value = interpreter.operand_stack.pop_as::<IntegerValue>()?;
+                interpreter
+                    .operand_stack
+                    .push(Value::i16(integer_value.cast_i16()?))?;
+            }
+            Bytecode::CastI32 => {
+                gas_meter.charge_simple_instr(S::CastI32)?;
+                let integer_value = interpreter.operand_stack.pop_as::<IntegerValue>()?;
+                interpreter
+                    .operand_stack
+                    .push(Value::i32(integer_value.cast_i32()?))?;
+            }
+            Bytecode::CastI64 => {
+                gas_meter.charge_simple_instr(S::CastI64)?;
+                let integer_value = interpreter.operand_stack.pop_as::<IntegerValue>()?;
+                interpreter
+                    .operand_stack
+                    .push(Value::i64(integer_value.cast_i64()?))?;
+            }
+            Bytecode::CastI128 => {
+                gas_meter.charge_simple_instr(S::CastI128)?;
+                let integer_value = interpreter.operand_stack.pop_as::<IntegerValue>()?;
+                interpreter
+                    .operand_stack
+                    .push(Value::i128(integer_value.cast_i128()?))?;
+            }
+            Bytecode::CastI256 => {
+                gas_meter.charge_simple_instr(S::CastI256)?;
+                let integer_value = interpreter.operand_stack.pop_as::<IntegerValue>()?;
+                interpreter
+                    .operand_stack
+                    .push(Value::i256(integer_value.cast_i256()?))?;
+            }
             // Arithmetic Operations
             Bytecode::Add => {
                 gas_meter.charge_simple_instr(S::Add)?;
@@ -1446,6 +1559,22 @@ impl Frame {
                     )
                 });
 
+                // Function values have no type layout, so execution is not traced past the
+                // first closure instruction.
+                if crate::tracing2::TRACING_ENABLED
+                    && tracer.is_some()
+                    && matches!(
+                        instruction,
+                        Bytecode::PackClosure(..)
+                            | Bytecode::PackClosureGeneric(..)
+                            | Bytecode::CallClosure(_)
+                    )
+                {
+                    if let Some(tracer) = tracer.take() {
+                        tracer.stop("Function values cannot be traced");
+                    }
+                }
+
                 open_instruction!(
                     tracer,
                     instruction,
@@ -1515,6 +1644,44 @@ impl Frame {
         Location::Module(self.function.module_id().clone())
     }
 
+    /// Packs a closure over the function with the given loader index, capturing the values at
+    /// the top of the stack that are not among the parameters of the closure type.
+    fn pack_closure(
+        resolver: &Resolver,
+        interpreter: &mut Interpreter,
+        gas_meter: &mut impl GasMeter,
+        function: usize,
+        ty_args: Vec<Type>,
+        sig_idx: SignatureIndex,
+    ) -> PartialVMResult<()> {
+        let arg_count = resolver.loader().function_at(function).arg_count();
+        let captured_count = arg_count
+            .checked_sub(resolver.closure_param_count(sig_idx)?)
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("Closure has more parameters than its function".to_string())
+            })?;
+        gas_meter.charge_pack(
+            !ty_args.is_empty(),
+            interpreter.operand_stack.last_n(captured_count)?,
+        )?;
+        let captured = interpreter.operand_stack.popn(captured_count as u16)?;
+        let closure = Closure::pack(function, ty_args, captured);
+        // Function types do not bound the depth of the values they capture, so the depth is
+        // checked on the value instead.
+        if let Some(max_depth) = resolver
+            .loader()
+            .vm_config()
+            .runtime_limits_config
+            .max_value_nest_depth
+        {
+            if closure.depth() > max_depth {
+                return Err(PartialVMError::new(StatusCode::VM_MAX_VALUE_DEPTH_REACHED));
+            }
+        }
+        interpreter.operand_stack.push(Value::closure(closure))
+    }
+
     fn check_depth_of_type(resolver: &Resolver, ty: &Type) -> PartialVMResult<u64> {
         let Some(max_depth) = resolver
             .loader()
@@ -1552,8 +1719,16 @@ impl Frame {
             | Type::U64
             | Type::U128
             | Type::U256
+            | Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::I64
+            | Type::I128
+            | Type::I256
             | Type::Address
-            | Type::Signer => check_depth!(1),
+            | Type::Signer
+            // The depth of closure values is checked when they are packed
+            | Type::Function(_) => check_depth!(1),
             // Even though this is recursive this is OK since the depth of this recursion is
             // bounded by the depth of the type arguments, which we have already checked.
             Type::Reference(ty) | Type::MutableReference(ty) | Type::Vector(ty) => {
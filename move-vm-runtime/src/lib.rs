@@ -20,6 +20,7 @@ pub mod native_extensions;
 pub mod native_functions;
 pub mod runtime;
 pub mod session;
+pub mod shared_module_cache;
 #[macro_use]
 mod tracing;
 mod tracing2;
@@ -7,6 +7,7 @@ use crate::{
     logging::expect_no_verification_errors,
     native_functions::{NativeFunction, NativeFunctions, UnboxedNativeFunction},
     session::LoadedFunctionInstantiation,
+    shared_module_cache::SharedModuleCache,
 };
 use move_binary_format::{
     errors::{verification_error, Location, PartialVMError, PartialVMResult, VMResult},
@@ -73,12 +74,12 @@ where
         }
     }
 
-    fn insert(&mut self, key: K, binary: V) -> PartialVMResult<&Arc<V>> {
+    fn insert(&mut self, key: K, binary: impl Into<Arc<V>>) -> PartialVMResult<&Arc<V>> {
         let idx = self.binaries.len();
         // Last write wins in the binary cache -- it's up to the callee to not make conflicting
         // writes.
         self.id_map.insert(key, idx);
-        self.binaries.push(Arc::new(binary));
+        self.binaries.push(binary.into());
         Ok(&self.binaries[idx])
     }
 
@@ -425,6 +426,12 @@ impl ModuleCache {
             SignatureToken::U64 => Type::U64,
             SignatureToken::U128 => Type::U128,
             SignatureToken::U256 => Type::U256,
+            SignatureToken::I8 => Type::I8,
+            SignatureToken::I16 => Type::I16,
+            SignatureToken::I32 => Type::I32,
+            SignatureToken::I64 => Type::I64,
+            SignatureToken::I128 => Type::I128,
+            SignatureToken::I256 => Type::I256,
             SignatureToken::Address => Type::Address,
             SignatureToken::Signer => Type::Signer,
             SignatureToken::TypeParameter(idx) => Type::TyParam(*idx),
@@ -464,6 +471,18 @@ impl ModuleCache {
                 let def_idx = self.resolve_type_by_name(datatype_name, &runtime_id)?.0;
                 Type::DatatypeInstantiation(Box::new((def_idx, type_parameters)))
             }
+            SignatureToken::Function(func) => {
+                let (params, returns, abilities) = &**func;
+                let params = params
+                    .iter()
+                    .map(|tok| self.make_type(module, tok))
+                    .collect::<PartialVMResult<_>>()?;
+                let returns = returns
+                    .iter()
+                    .map(|tok| self.make_type(module, tok))
+                    .collect::<PartialVMResult<_>>()?;
+                Type::Function(Box::new((params, returns, *abilities)))
+            }
         };
         Ok(res)
     }
@@ -526,7 +545,15 @@ impl ModuleCache {
             | Type::Signer
             | Type::U16
             | Type::U32
-            | Type::U256 => DepthFormula::constant(1),
+            | Type::U256
+            | Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::I64
+            | Type::I128
+            | Type::I256
+            // function values are opaque, what they capture does not add to the value depth
+            | Type::Function(_) => DepthFormula::constant(1),
             // we should not see the reference here, we could instead give an invariant violation
             Type::Vector(ty) | Type::Reference(ty) | Type::MutableReference(ty) => {
                 let mut inner = self.calculate_depth_of_type(ty, depth_cache)?;
@@ -807,15 +834,22 @@ impl<'a> ModuleLoader<'a> {
 pub(crate) struct Loader {
     module_cache: RwLock<ModuleCache>,
     type_cache: RwLock<TypeCache>,
+    /// Verified modules shared with other loaders, consulted before reading modules from storage.
+    shared_module_cache: Option<Arc<SharedModuleCache>>,
     natives: NativeFunctions,
     vm_config: VMConfig,
 }
 
 impl Loader {
-    pub(crate) fn new(natives: NativeFunctions, vm_config: VMConfig) -> Self {
+    pub(crate) fn new(
+        natives: NativeFunctions,
+        vm_config: VMConfig,
+        shared_module_cache: Option<Arc<SharedModuleCache>>,
+    ) -> Self {
         Self {
             module_cache: RwLock::new(ModuleCache::new()),
             type_cache: RwLock::new(TypeCache::new()),
+            shared_module_cache,
             natives,
             vm_config,
         }
@@ -1051,6 +1085,12 @@ impl Loader {
             TypeTag::U64 => Type::U64,
             TypeTag::U128 => Type::U128,
             TypeTag::U256 => Type::U256,
+            TypeTag::I8 => Type::I8,
+            TypeTag::I16 => Type::I16,
+            TypeTag::I32 => Type::I32,
+            TypeTag::I64 => Type::I64,
+            TypeTag::I128 => Type::I128,
+            TypeTag::I256 => Type::I256,
             TypeTag::Address => Type::Address,
             TypeTag::Signer => Type::Signer,
             TypeTag::Vector(tt) => Type::Vector(Box::new(self.load_type(tt, data_store)?)),
@@ -1183,8 +1223,9 @@ impl Loader {
         Ok(module)
     }
 
-    /// Deserialize and check the module with the bytecode verifier, without linking.  Cache the
-    /// `CompiledModule` on success, and return a reference to it.
+    /// Deserialize and check the module with the bytecode verifier, without linking, unless it is
+    /// found in the shared module cache.  Cache the `CompiledModule` on success, and return a
+    /// reference to it.
     fn verify_module(
         &self,
         runtime_id: &ModuleId,
@@ -1198,12 +1239,26 @@ impl Loader {
             return Ok((storage_id, cached));
         }
 
-        let module = self.read_module_from_store(
-            runtime_id,
-            &storage_id,
-            data_store,
-            allow_loading_failure,
-        )?;
+        // Modules in the shared cache have been checked against the binary config and verified
+        // already (with the same configs as this loader's, which is checked when the VM is
+        // created), but the natives they declare still need to be checked against this loader's.
+        let shared = self
+            .shared_module_cache
+            .as_ref()
+            .and_then(|cache| cache.get(&storage_id));
+        let module = match shared {
+            Some(module) => {
+                self.check_natives(&module)
+                    .map_err(expect_no_verification_errors)?;
+                module
+            }
+            None => Arc::new(self.read_module_from_store(
+                runtime_id,
+                &storage_id,
+                data_store,
+                allow_loading_failure,
+            )?),
+        };
 
         let cached = self
             .module_cache
@@ -1391,7 +1446,7 @@ impl Loader {
     // Internal helpers
     //
 
-    fn function_at(&self, idx: usize) -> Arc<Function> {
+    pub(crate) fn function_at(&self, idx: usize) -> Arc<Function> {
         self.module_cache.read().function_at(idx)
     }
 
@@ -1428,11 +1483,18 @@ impl Loader {
             | Type::U64
             | Type::U128
             | Type::U256
+            | Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::I64
+            | Type::I128
+            | Type::I256
             | Type::Address => Ok(AbilitySet::PRIMITIVES),
 
             // Technically unreachable but, no point in erroring if we don't have to
             Type::Reference(_) | Type::MutableReference(_) => Ok(AbilitySet::REFERENCES),
             Type::Signer => Ok(AbilitySet::SIGNER),
+            Type::Function(func) => Ok(func.2),
 
             Type::TyParam(_) => Err(PartialVMError::new(StatusCode::UNREACHABLE).with_message(
                 "Unexpected TyParam type after translating from TypeTag to Type".to_string(),
@@ -1518,6 +1580,30 @@ impl<'a> Resolver<'a> {
         self.loader.function_at(func_inst.handle)
     }
 
+    // The index of a function in the loader, which is how closures refer to the function they
+    // call.
+    pub(crate) fn function_index_from_handle(&self, idx: FunctionHandleIndex) -> usize {
+        self.binary.loaded.function_at(idx.0)
+    }
+
+    pub(crate) fn function_index_from_instantiation(
+        &self,
+        idx: FunctionInstantiationIndex,
+    ) -> usize {
+        self.binary.loaded.function_instantiation_at(idx.0).handle
+    }
+
+    // The number of parameters of the closure type held by the signature of a closure instruction
+    pub(crate) fn closure_param_count(&self, idx: SignatureIndex) -> PartialVMResult<usize> {
+        match self.binary.compiled.signature_at(idx).0.as_slice() {
+            [SignatureToken::Function(func)] => Ok(func.0.len()),
+            _ => Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("Expected a closure signature".to_string()),
+            ),
+        }
+    }
+
     pub(crate) fn instantiate_generic_function(
         &self,
         idx: FunctionInstantiationIndex,
@@ -2451,6 +2537,12 @@ impl Loader {
             Type::U64 => TypeTag::U64,
             Type::U128 => TypeTag::U128,
             Type::U256 => TypeTag::U256,
+            Type::I8 => TypeTag::I8,
+            Type::I16 => TypeTag::I16,
+            Type::I32 => TypeTag::I32,
+            Type::I64 => TypeTag::I64,
+            Type::I128 => TypeTag::I128,
+            Type::I256 => TypeTag::I256,
             Type::Address => TypeTag::Address,
             Type::Signer => TypeTag::Signer,
             Type::Vector(ty) => {
@@ -2467,7 +2559,10 @@ impl Loader {
                     self.datatype_gidx_to_type_tag(*gidx, ty_args, tag_type)?,
                 ))
             }
-            Type::Reference(_) | Type::MutableReference(_) | Type::TyParam(_) => {
+            Type::Reference(_)
+            | Type::MutableReference(_)
+            | Type::TyParam(_)
+            | Type::Function(_) => {
                 return Err(
                     PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
                         .with_message(format!("no type tag for {:?}", ty)),
@@ -2587,6 +2682,12 @@ impl Loader {
             Type::U64 => R::MoveTypeLayout::U64,
             Type::U128 => R::MoveTypeLayout::U128,
             Type::U256 => R::MoveTypeLayout::U256,
+            Type::I8 => R::MoveTypeLayout::I8,
+            Type::I16 => R::MoveTypeLayout::I16,
+            Type::I32 => R::MoveTypeLayout::I32,
+            Type::I64 => R::MoveTypeLayout::I64,
+            Type::I128 => R::MoveTypeLayout::I128,
+            Type::I256 => R::MoveTypeLayout::I256,
             Type::Address => R::MoveTypeLayout::Address,
             Type::Signer => R::MoveTypeLayout::Signer,
             Type::Vector(ty) => R::MoveTypeLayout::Vector(Box::new(
@@ -2600,7 +2701,10 @@ impl Loader {
                 self.type_gidx_to_type_layout(*gidx, ty_args, count, depth)?
                     .into_layout()
             }
-            Type::Reference(_) | Type::MutableReference(_) | Type::TyParam(_) => {
+            Type::Reference(_)
+            | Type::MutableReference(_)
+            | Type::TyParam(_)
+            | Type::Function(_) => {
                 return Err(
                     PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
                         .with_message(format!("no type layout for {:?}", ty)),
@@ -2731,6 +2835,12 @@ impl Loader {
             Type::U64 => A::MoveTypeLayout::U64,
             Type::U128 => A::MoveTypeLayout::U128,
             Type::U256 => A::MoveTypeLayout::U256,
+            Type::I8 => A::MoveTypeLayout::I8,
+            Type::I16 => A::MoveTypeLayout::I16,
+            Type::I32 => A::MoveTypeLayout::I32,
+            Type::I64 => A::MoveTypeLayout::I64,
+            Type::I128 => A::MoveTypeLayout::I128,
+            Type::I256 => A::MoveTypeLayout::I256,
             Type::Address => A::MoveTypeLayout::Address,
             Type::Signer => A::MoveTypeLayout::Signer,
             Type::Vector(ty) => A::MoveTypeLayout::Vector(Box::new(
@@ -2744,7 +2854,10 @@ impl Loader {
                 self.datatype_gidx_to_fully_annotated_layout(*gidx, ty_args, count, depth)?
                     .into_layout()
             }
-            Type::Reference(_) | Type::MutableReference(_) | Type::TyParam(_) => {
+            Type::Reference(_)
+            | Type::MutableReference(_)
+            | Type::TyParam(_)
+            | Type::Function(_) => {
                 return Err(
                     PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
                         .with_message(format!("no type layout for {:?}", ty)),
@@ -8,6 +8,7 @@ use std::sync::Arc;
 use crate::{
     data_cache::TransactionDataCache, native_extensions::NativeContextExtensions,
     native_functions::NativeFunction, runtime::VMRuntime, session::Session,
+    shared_module_cache::SharedModuleCache,
 };
 use move_binary_format::{
     errors::{Location, VMResult},
@@ -35,7 +36,21 @@ impl MoveVM {
         vm_config: VMConfig,
     ) -> VMResult<Self> {
         Ok(Self {
-            runtime: VMRuntime::new(natives, vm_config)
+            runtime: VMRuntime::new(natives, vm_config, None)
+                .map_err(|err| err.finish(Location::Undefined))?,
+        })
+    }
+
+    /// Create a VM that takes the modules it loads from `shared_module_cache` when they are found
+    /// there, instead of reading and verifying them again. Fails if `vm_config` does not use the
+    /// verifier and binary configs the cache was created with.
+    pub fn new_with_shared_module_cache(
+        natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
+        vm_config: VMConfig,
+        shared_module_cache: Arc<SharedModuleCache>,
+    ) -> VMResult<Self> {
+        Ok(Self {
+            runtime: VMRuntime::new(natives, vm_config, Some(shared_module_cache))
                 .map_err(|err| err.finish(Location::Undefined))?,
         })
     }
@@ -10,6 +10,7 @@ use crate::{
     native_extensions::NativeContextExtensions,
     native_functions::{NativeFunction, NativeFunctions},
     session::{LoadedFunctionInstantiation, SerializedReturnValues, Session},
+    shared_module_cache::SharedModuleCache,
     tracing2::tracer::VMTracer,
 };
 use move_binary_format::{
@@ -47,9 +48,34 @@ impl VMRuntime {
     pub(crate) fn new(
         natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
         vm_config: VMConfig,
+        shared_module_cache: Option<Arc<SharedModuleCache>>,
     ) -> PartialVMResult<Self> {
+        // modules found in the shared cache are not checked again, so they must have been checked
+        // with the same configs as those this VM checks the modules it reads from storage with
+        if let Some(cache) = &shared_module_cache {
+            if cache.verifier_config() != &vm_config.verifier {
+                return Err(
+                    PartialVMError::new(StatusCode::VM_STARTUP_FAILURE).with_message(
+                        "Shared module cache was created with a different verifier config"
+                            .to_string(),
+                    ),
+                );
+            }
+            if cache.binary_config() != &vm_config.binary_config {
+                return Err(
+                    PartialVMError::new(StatusCode::VM_STARTUP_FAILURE).with_message(
+                        "Shared module cache was created with a different binary config"
+                            .to_string(),
+                    ),
+                );
+            }
+        }
         Ok(VMRuntime {
-            loader: Loader::new(NativeFunctions::new(natives)?, vm_config),
+            loader: Loader::new(
+                NativeFunctions::new(natives)?,
+                vm_config,
+                shared_module_cache,
+            ),
         })
     }
 
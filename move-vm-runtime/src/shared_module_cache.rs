@@ -0,0 +1,126 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use move_binary_format::{
+    binary_config::BinaryConfig,
+    errors::{Location, PartialVMError, VMResult},
+    file_format::CompiledModule,
+};
+use move_core_types::{language_storage::ModuleId, vm_status::StatusCode};
+use move_vm_config::verifier::VerifierConfig;
+use parking_lot::RwLock;
+use std::{collections::BTreeMap, sync::Arc};
+
+/// A cache of verified modules that can be shared, behind an `Arc`, by many `MoveVM`s, so that
+/// modules every VM loads (like those of the standard library, or of a package's dependencies) are
+/// deserialized and verified once, rather than once per VM.
+///
+/// Modules are added to the cache explicitly, and never by the VMs that use it. They are checked
+/// against the binary config limits and verified on their own when they are added, and a VM that
+/// finds a module in the cache uses it instead of reading the module from its storage. What a
+/// module links against is still checked by every VM that loads it, as that depends on the modules
+/// it finds in its own storage.
+///
+/// It is up to the owner of the cache to keep it coherent with the storage of the VMs that use it:
+///   - A module is cached under its self ID, and must be stored at that ID with the same contents
+///     in the storage of every VM that uses the cache.
+///   - VMs that use the cache must be configured with the verifier and binary configs the cache was
+///     created with, as modules found in it are not checked again (creating a VM with different
+///     configs fails).
+///   - When a module is republished, it must be invalidated before the next VM is created, and VMs
+///     created before that should no longer be used.
+pub struct SharedModuleCache {
+    verifier_config: VerifierConfig,
+    binary_config: BinaryConfig,
+    modules: RwLock<BTreeMap<ModuleId, Arc<CompiledModule>>>,
+}
+
+impl SharedModuleCache {
+    pub fn new(verifier_config: VerifierConfig, binary_config: BinaryConfig) -> Self {
+        Self {
+            verifier_config,
+            binary_config,
+            modules: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Create a cache holding `modules`, checked against `binary_config` and verified with
+    /// `verifier_config`.
+    pub fn new_with_modules(
+        verifier_config: VerifierConfig,
+        binary_config: BinaryConfig,
+        modules: impl IntoIterator<Item = CompiledModule>,
+    ) -> VMResult<Self> {
+        let cache = Self::new(verifier_config, binary_config);
+        cache.add_modules(modules)?;
+        Ok(cache)
+    }
+
+    pub fn verifier_config(&self) -> &VerifierConfig {
+        &self.verifier_config
+    }
+
+    pub fn binary_config(&self) -> &BinaryConfig {
+        &self.binary_config
+    }
+
+    /// Check `modules` against the binary config, verify them and add them to the cache, replacing
+    /// any cached module with the same ID. If any of them fails these checks, none of them are
+    /// added.
+    pub fn add_modules(&self, modules: impl IntoIterator<Item = CompiledModule>) -> VMResult<()> {
+        let mut verified = vec![];
+        for module in modules {
+            let module = self.check_binary_config(&module)?;
+            move_bytecode_verifier::verify_module_with_config_unmetered(
+                &self.verifier_config,
+                &module,
+            )?;
+            verified.push(module);
+        }
+
+        let mut cached = self.modules.write();
+        for module in verified {
+            cached.insert(module.self_id(), Arc::new(module));
+        }
+        Ok(())
+    }
+
+    /// Remove the module with ID `id` from the cache, so that VMs created from now on read it from
+    /// their storage instead. Returns whether the module was cached.
+    pub fn invalidate(&self, id: &ModuleId) -> bool {
+        self.modules.write().remove(id).is_some()
+    }
+
+    pub fn contains(&self, id: &ModuleId) -> bool {
+        self.modules.read().contains_key(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.read().is_empty()
+    }
+
+    pub(crate) fn get(&self, id: &ModuleId) -> Option<Arc<CompiledModule>> {
+        self.modules.read().get(id).cloned()
+    }
+
+    /// Round-trip `module` through its serialized form, so that it is subject to the same limits
+    /// as modules that VMs deserialize from their storage.
+    fn check_binary_config(&self, module: &CompiledModule) -> VMResult<CompiledModule> {
+        let location = || Location::Module(module.self_id());
+        let mut bytes = vec![];
+        module
+            .serialize_with_version(module.version, &mut bytes)
+            .map_err(|err| {
+                PartialVMError::new(StatusCode::CODE_DESERIALIZATION_ERROR)
+                    .with_message(format!("Serialization error: {:?}", err))
+                    .finish(location())
+            })?;
+        CompiledModule::deserialize_with_config(&bytes, &self.binary_config)
+            .map_err(|err| err.finish(location()))
+    }
+}
@@ -174,6 +174,13 @@ impl RootedType {
 
 impl VMTracer<'_> {
     /// Emit an error event to the trace if `true`
+    /// Ends tracing, recording why the rest of the execution cannot be traced.
+    pub(crate) fn stop(self, reason: &str) {
+        self.trace.effect(EF::ExecutionError(format!(
+            "!! TRACING STOPPED !! {reason}"
+        )));
+    }
+
     fn emit_trace_error_if_err(&mut self, is_err: bool) {
         if is_err {
             self.trace.effect(EF::ExecutionError(
@@ -775,6 +782,12 @@ impl VMTracer<'_> {
             | B::LdU64(_)
             | B::LdU128(_)
             | B::LdU256(_)
+            | B::LdI8(_)
+            | B::LdI16(_)
+            | B::LdI32(_)
+            | B::LdI64(_)
+            | B::LdI128(_)
+            | B::LdI256(_)
             | B::LdFalse
             | B::LdTrue
             | B::LdConst(_) => {
@@ -795,6 +808,12 @@ impl VMTracer<'_> {
             | B::CastU64
             | B::CastU128
             | B::CastU256
+            | B::CastI8
+            | B::CastI16
+            | B::CastI32
+            | B::CastI64
+            | B::CastI128
+            | B::CastI256
             | B::Pop
             | B::BrTrue(_)
             | B::BrFalse(_)
@@ -861,6 +880,8 @@ impl VMTracer<'_> {
             }
             // Handled by open frame
             B::Call(_) | B::CallGeneric(_) => {}
+            // The interpreter stops tracing before any closure instruction is executed
+            B::PackClosure(..) | B::PackClosureGeneric(..) | B::CallClosure(_) => return None,
             B::Pack(sidx) => {
                 let resolver = frame.function.get_resolver(self.link_context(), loader);
                 let field_count = resolver.field_count(*sidx) as usize;
@@ -949,6 +970,12 @@ impl VMTracer<'_> {
             | B::LdU64(_)
             | B::LdU128(_)
             | B::LdU256(_)
+            | B::LdI8(_)
+            | B::LdI16(_)
+            | B::LdI32(_)
+            | B::LdI64(_)
+            | B::LdI128(_)
+            | B::LdI256(_)
             | B::LdFalse
             | B::LdTrue
             | B::LdConst(_)) => {
@@ -959,6 +986,12 @@ impl VMTracer<'_> {
                     B::LdU64(_) => MoveTypeLayout::U64,
                     B::LdU128(_) => MoveTypeLayout::U128,
                     B::LdU256(_) => MoveTypeLayout::U256,
+                    B::LdI8(_) => MoveTypeLayout::I8,
+                    B::LdI16(_) => MoveTypeLayout::I16,
+                    B::LdI32(_) => MoveTypeLayout::I32,
+                    B::LdI64(_) => MoveTypeLayout::I64,
+                    B::LdI128(_) => MoveTypeLayout::I128,
+                    B::LdI256(_) => MoveTypeLayout::I256,
                     B::LdTrue => MoveTypeLayout::Bool,
                     B::LdFalse => MoveTypeLayout::Bool,
                     B::LdConst(const_idx) => get_constant_type_layout(
@@ -997,7 +1030,18 @@ impl VMTracer<'_> {
                 self.trace
                     .instruction(instruction, vec![], effects, remaining_gas, pc);
             }
-            i @ (B::CastU8 | B::CastU16 | B::CastU32 | B::CastU64 | B::CastU128 | B::CastU256) => {
+            i @ (B::CastU8
+            | B::CastU16
+            | B::CastU32
+            | B::CastU64
+            | B::CastU128
+            | B::CastU256
+            | B::CastI8
+            | B::CastI16
+            | B::CastI32
+            | B::CastI64
+            | B::CastI128
+            | B::CastI256) => {
                 let layout = match i {
                     B::CastU8 => MoveTypeLayout::U8,
                     B::CastU16 => MoveTypeLayout::U16,
@@ -1005,6 +1049,12 @@ impl VMTracer<'_> {
                     B::CastU64 => MoveTypeLayout::U64,
                     B::CastU128 => MoveTypeLayout::U128,
                     B::CastU256 => MoveTypeLayout::U256,
+                    B::CastI8 => MoveTypeLayout::I8,
+                    B::CastI16 => MoveTypeLayout::I16,
+                    B::CastI32 => MoveTypeLayout::I32,
+                    B::CastI64 => MoveTypeLayout::I64,
+                    B::CastI128 => MoveTypeLayout::I128,
+                    B::CastI256 => MoveTypeLayout::I256,
                     _ => unreachable!(),
                 };
                 let annot_layout = RootedType {
@@ -1066,6 +1116,7 @@ impl VMTracer<'_> {
                 self.trace
                     .instruction(instruction, vec![], effects, remaining_gas, pc);
             }
+            B::PackClosure(..) | B::PackClosureGeneric(..) | B::CallClosure(_) => return None,
             B::Call(_) | B::CallGeneric(_) => {
                 // NB: We don't register effects for calls as they will be handled by
                 // open_frame.
@@ -0,0 +1,140 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, path::Path};
+
+use crate::{move_vm::MoveVM, unit_tests::vm_arguments_tests::RemoteStore};
+use move_binary_format::errors::VMResult;
+use move_compiler::{editions::Edition, shared::PackageConfig, Compiler};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    parsing::address::NumericalAddress,
+    runtime_value::MoveValue,
+    vm_status::StatusCode,
+};
+use move_vm_types::gas::UnmeteredGasMeter;
+
+/// Compiles `src/unit_tests/<module>.move` with the edition that has function values and signed
+/// integers, and adds it to a store
+fn compiled_store(module: &str) -> RemoteStore {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/unit_tests")
+        .join(format!("{}.move", module));
+    let (_, units) = Compiler::from_files(
+        None,
+        vec![path.to_str().unwrap()],
+        vec![],
+        BTreeMap::<&str, NumericalAddress>::new(),
+    )
+    .set_default_config(PackageConfig {
+        edition: Edition::E2024_ALPHA,
+        ..PackageConfig::default()
+    })
+    .build_and_report()
+    .unwrap();
+    let mut store = RemoteStore::new();
+    for unit in units {
+        store.add_module(unit.named_module.module);
+    }
+    store
+}
+
+/// Calls `0x42::<module>::<name>` from `src/unit_tests/<module>.move` with `ty_args` and `args`,
+/// and returns its results
+pub(crate) fn call(
+    module: &str,
+    name: &str,
+    ty_args: Vec<TypeTag>,
+    args: Vec<MoveValue>,
+) -> VMResult<Vec<MoveValue>> {
+    let store = compiled_store(module);
+    let vm = MoveVM::new(vec![]).unwrap();
+    let mut session = vm.new_session(&store);
+    let module_id = ModuleId::new(
+        AccountAddress::from_hex_literal("0x42").unwrap(),
+        Identifier::new(module).unwrap(),
+    );
+    let ty_args = ty_args
+        .into_iter()
+        .map(|tag| session.load_type(&tag))
+        .collect::<VMResult<_>>()?;
+    let args: Vec<_> = args
+        .iter()
+        .map(|arg| arg.simple_serialize().unwrap())
+        .collect();
+    let results = session.execute_function_bypass_visibility(
+        &module_id,
+        &Identifier::new(name).unwrap(),
+        ty_args,
+        args,
+        &mut UnmeteredGasMeter,
+        None,
+    )?;
+    Ok(results
+        .return_values
+        .into_iter()
+        .map(|(bytes, layout)| MoveValue::simple_deserialize(&bytes, &layout).unwrap())
+        .collect())
+}
+
+fn call_u64(name: &str, args: &[u64]) -> VMResult<u64> {
+    let args = args.iter().map(|arg| MoveValue::U64(*arg)).collect();
+    match call("closures", name, vec![], args)?.as_slice() {
+        [MoveValue::U64(result)] => Ok(*result),
+        results => panic!("Unexpected results {:?}", results),
+    }
+}
+
+#[test]
+fn call_closures() {
+    assert_eq!(call_u64("capture", &[3, 4]).unwrap(), 7);
+    assert_eq!(call_u64("capture_nothing", &[21]).unwrap(), 42);
+    assert_eq!(call_u64("multiple_returns", &[6]).unwrap(), 42);
+    assert_eq!(call_u64("stored", &[3, 2]).unwrap(), 18);
+    // twice(|y| twice(|y| y + 1, y) + 1, 1)
+    assert_eq!(call_u64("higher_order", &[1, 1]).unwrap(), 7);
+    assert_eq!(call_u64("returned", &[2, 44]).unwrap(), 42);
+}
+
+#[test]
+fn call_generic_closure() {
+    let results = call(
+        "closures",
+        "generic",
+        vec![TypeTag::Bool],
+        vec![MoveValue::Bool(true)],
+    )
+    .unwrap();
+    assert_eq!(
+        results,
+        vec![MoveValue::Vector(vec![
+            MoveValue::Bool(true),
+            MoveValue::Bool(true)
+        ])]
+    );
+
+    let results = call(
+        "closures",
+        "generic",
+        vec![TypeTag::U8],
+        vec![MoveValue::U8(7)],
+    )
+    .unwrap();
+    assert_eq!(
+        results,
+        vec![MoveValue::Vector(vec![MoveValue::U8(7), MoveValue::U8(7)])]
+    );
+}
+
+#[test]
+fn closure_aborts() {
+    let err = call_u64("aborts", &[42]).unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::ABORTED);
+    assert_eq!(err.sub_status(), Some(42));
+
+    let err = call_u64("returned", &[2, 1]).unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::ARITHMETIC_ERROR);
+}
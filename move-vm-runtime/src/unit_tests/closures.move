@@ -0,0 +1,64 @@
+module 0x42::closures;
+
+public struct Counter has copy, drop {
+    step: |u64| -> u64 has copy + drop,
+}
+
+fun add(x: u64, y: u64): u64 {
+    x + y
+}
+
+public fun capture(x: u64, y: u64): u64 {
+    let f = |z: u64| add(x, z);
+    f(y)
+}
+
+public fun capture_nothing(x: u64): u64 {
+    let f = |y: u64| y * 2;
+    f(x)
+}
+
+public fun generic<T: copy + drop>(x: T): vector<T> {
+    let f = || vector[x, x];
+    f()
+}
+
+public fun multiple_returns(x: u64): u64 {
+    let f = |y: u64| (y, y + 1);
+    let (a, b) = f(x);
+    a * b
+}
+
+public fun stored(k: u64, x: u64): u64 {
+    let counter = Counter { step: |y| y * k };
+    let f = counter.step;
+    let g = counter.step;
+    f(g(x))
+}
+
+fun twice(f: |u64| -> u64 has copy + drop, x: u64): u64 {
+    f(f(x))
+}
+
+public fun higher_order(k: u64, x: u64): u64 {
+    let f = |y| y + k;
+    let g = |y| twice(f, y) + 1;
+    twice(g, x)
+}
+
+fun subtract(k: u64): |u64| -> u64 has copy + drop {
+    |x| x - k
+}
+
+public fun returned(k: u64, x: u64): u64 {
+    let f = subtract(k);
+    f(x)
+}
+
+public fun aborts(code: u64): u64 {
+    let f = |x: u64| {
+        if (x > 0) abort code;
+        x
+    };
+    f(1)
+}
@@ -3,4 +3,7 @@
 // Modifications Copyright (c) 2024 Digc Labs
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod closure_tests;
+pub mod shared_module_cache_tests;
+pub mod signed_integer_tests;
 pub mod vm_arguments_tests;
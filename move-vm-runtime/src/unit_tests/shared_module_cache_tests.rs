@@ -0,0 +1,143 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use crate::{
+    move_vm::MoveVM, shared_module_cache::SharedModuleCache,
+    unit_tests::vm_arguments_tests::RemoteStore,
+};
+use move_binary_format::{
+    binary_config::BinaryConfig,
+    file_format::{
+        empty_module, Bytecode, CodeUnit, CompiledModule, FunctionDefinition, FunctionHandle,
+        FunctionHandleIndex, IdentifierIndex, ModuleHandleIndex, SignatureIndex, Visibility,
+    },
+};
+use move_core_types::{identifier::Identifier, vm_status::StatusCode};
+use move_vm_config::{runtime::VMConfig, verifier::VerifierConfig};
+use move_vm_types::gas::UnmeteredGasMeter;
+
+fn new_cache() -> SharedModuleCache {
+    let vm_config = VMConfig::default();
+    SharedModuleCache::new(vm_config.verifier, vm_config.binary_config)
+}
+
+// module <SELF> { public fun foo() { abort <abort_code> } }
+fn make_module(abort_code: u64) -> CompiledModule {
+    let mut m = empty_module();
+    m.identifiers.push(Identifier::new("foo").unwrap());
+    m.function_handles.push(FunctionHandle {
+        module: ModuleHandleIndex(0),
+        name: IdentifierIndex(1),
+        parameters: SignatureIndex(0),
+        return_: SignatureIndex(0),
+        type_parameters: vec![],
+    });
+    m.function_defs.push(FunctionDefinition {
+        function: FunctionHandleIndex(0),
+        visibility: Visibility::Public,
+        is_entry: false,
+        acquires_global_resources: vec![],
+        code: Some(CodeUnit {
+            locals: SignatureIndex(0),
+            code: vec![Bytecode::LdU64(abort_code), Bytecode::Abort],
+            jump_tables: vec![],
+        }),
+    });
+    m
+}
+
+// Call `foo` in a fresh VM using `cache`, and return the code it aborted with.
+fn call_foo(cache: &Arc<SharedModuleCache>, store: &RemoteStore) -> Option<u64> {
+    let vm =
+        MoveVM::new_with_shared_module_cache(vec![], VMConfig::default(), cache.clone()).unwrap();
+    let mut session = vm.new_session(store);
+    let err = session
+        .execute_function_bypass_visibility(
+            &empty_module().self_id(),
+            &Identifier::new("foo").unwrap(),
+            vec![],
+            Vec::<Vec<u8>>::new(),
+            &mut UnmeteredGasMeter,
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::ABORTED);
+    err.sub_status()
+}
+
+#[test]
+fn modules_are_taken_from_shared_cache_until_invalidated() {
+    let mut store = RemoteStore::new();
+    store.add_module(make_module(0));
+
+    // The cached module differs from the stored one, to tell which of them a VM loaded.
+    let cache = new_cache();
+    cache.add_modules([make_module(1)]).unwrap();
+    let cache = Arc::new(cache);
+    assert_eq!(call_foo(&cache, &store), Some(1));
+    assert_eq!(call_foo(&cache, &store), Some(1));
+
+    let id = empty_module().self_id();
+    assert!(cache.invalidate(&id));
+    assert!(!cache.invalidate(&id));
+    assert_eq!(call_foo(&cache, &store), Some(0));
+}
+
+#[test]
+fn modules_failing_verification_are_not_cached() {
+    let mut module = make_module(0);
+    // Aborting without an abort code fails verification
+    module.function_defs[0].code.as_mut().unwrap().code = vec![Bytecode::Abort];
+
+    let cache = new_cache();
+    assert!(cache.add_modules([make_module(1), module]).is_err());
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn modules_exceeding_binary_config_are_not_cached() {
+    let module = make_module(0);
+    let binary_config = BinaryConfig {
+        max_binary_format_version: module.version - 1,
+        ..VMConfig::default().binary_config
+    };
+    let cache = SharedModuleCache::new(VerifierConfig::default(), binary_config);
+    let err = cache.add_modules([module]).unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::UNKNOWN_VERSION);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn vm_config_must_match_shared_cache() {
+    let cache = Arc::new(new_cache());
+    let startup_error = |vm_config| {
+        MoveVM::new_with_shared_module_cache(vec![], vm_config, cache.clone())
+            .err()
+            .map(|err| err.major_status())
+    };
+    assert_eq!(startup_error(VMConfig::default()), None);
+
+    let vm_config = VMConfig {
+        verifier: VerifierConfig {
+            max_loop_depth: Some(5),
+            ..VerifierConfig::default()
+        },
+        ..VMConfig::default()
+    };
+    assert_eq!(
+        startup_error(vm_config),
+        Some(StatusCode::VM_STARTUP_FAILURE)
+    );
+
+    let vm_config = VMConfig {
+        binary_config: BinaryConfig::with_extraneous_bytes_check(true),
+        ..VMConfig::default()
+    };
+    assert_eq!(
+        startup_error(vm_config),
+        Some(StatusCode::VM_STARTUP_FAILURE)
+    );
+}
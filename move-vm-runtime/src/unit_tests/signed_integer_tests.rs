@@ -0,0 +1,121 @@
+// Copyright (c) The Move Contributors
+// Modifications Copyright (c) 2024 Digc Labs
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::unit_tests::closure_tests::call;
+use move_core_types::{i256::I256, runtime_value::MoveValue, u256::U256, vm_status::StatusCode};
+
+fn call_signed(name: &str, args: Vec<MoveValue>) -> Result<Vec<MoveValue>, StatusCode> {
+    call("signed_integers", name, vec![], args).map_err(|err| err.major_status())
+}
+
+#[test]
+fn signed_arithmetic() {
+    let args = vec![MoveValue::I32(-7), MoveValue::I32(2)];
+    assert_eq!(
+        call_signed("arithmetic", args.clone()),
+        Ok(vec![
+            MoveValue::I32(-5),
+            MoveValue::I32(-9),
+            MoveValue::I32(-14),
+            MoveValue::I32(-3),
+            MoveValue::I32(-1),
+        ])
+    );
+    assert_eq!(
+        call_signed("comparisons", args),
+        Ok(vec![
+            MoveValue::Bool(true),
+            MoveValue::Bool(true),
+            MoveValue::Bool(false),
+            MoveValue::Bool(false),
+        ])
+    );
+    assert_eq!(
+        call_signed("neg", vec![MoveValue::I16(i16::MAX)]),
+        Ok(vec![MoveValue::I16(-i16::MAX)])
+    );
+    assert_eq!(
+        call_signed("literals", vec![]),
+        Ok(vec![
+            MoveValue::I8(i8::MIN),
+            MoveValue::I256(I256::min_value())
+        ])
+    );
+}
+
+#[test]
+fn signed_arithmetic_overflow() {
+    assert_eq!(
+        call_signed("div", vec![MoveValue::I8(i8::MIN), MoveValue::I8(-1)]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+    assert_eq!(
+        call_signed("div", vec![MoveValue::I8(1), MoveValue::I8(0)]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+    assert_eq!(
+        call_signed("rem", vec![MoveValue::I64(i64::MIN), MoveValue::I64(-1)]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+    assert_eq!(
+        call_signed("neg", vec![MoveValue::I16(i16::MIN)]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+}
+
+#[test]
+fn signed_to_unsigned_casts() {
+    assert_eq!(
+        call_signed("i8_to_u8", vec![MoveValue::I8(i8::MAX)]),
+        Ok(vec![MoveValue::U8(127)])
+    );
+    assert_eq!(
+        call_signed("i8_to_u8", vec![MoveValue::I8(-1)]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+    assert_eq!(
+        call_signed("i128_to_u256", vec![MoveValue::I128(i128::MAX)]),
+        Ok(vec![MoveValue::U256(U256::from(i128::MAX as u128))])
+    );
+    assert_eq!(
+        call_signed("i128_to_u256", vec![MoveValue::I128(i128::MIN)]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+}
+
+#[test]
+fn unsigned_to_signed_casts() {
+    assert_eq!(
+        call_signed("u8_to_i8", vec![MoveValue::U8(127)]),
+        Ok(vec![MoveValue::I8(i8::MAX)])
+    );
+    assert_eq!(
+        call_signed("u8_to_i8", vec![MoveValue::U8(128)]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+    assert_eq!(
+        call_signed("u256_to_i256", vec![MoveValue::U256(U256::from(5u8))]),
+        Ok(vec![MoveValue::I256(I256::from(5u8))])
+    );
+    assert_eq!(
+        call_signed("u256_to_i256", vec![MoveValue::U256(U256::max_value())]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+}
+
+#[test]
+fn signed_to_signed_casts() {
+    assert_eq!(
+        call_signed("i256_to_i8", vec![MoveValue::I256(I256::from(-128i16))]),
+        Ok(vec![MoveValue::I8(i8::MIN)])
+    );
+    assert_eq!(
+        call_signed("i256_to_i8", vec![MoveValue::I256(I256::from(-129i16))]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+    assert_eq!(
+        call_signed("i256_to_i8", vec![MoveValue::I256(I256::max_value())]),
+        Err(StatusCode::ARITHMETIC_ERROR)
+    );
+}
@@ -0,0 +1,45 @@
+module 0x42::signed_integers;
+
+public fun arithmetic(x: i32, y: i32): (i32, i32, i32, i32, i32) {
+    (x + y, x - y, x * y, x / y, x % y)
+}
+
+public fun comparisons(x: i32, y: i32): (bool, bool, bool, bool) {
+    (x < y, x <= y, x > y, x >= y)
+}
+
+public fun div(x: i8, y: i8): i8 {
+    x / y
+}
+
+public fun rem(x: i64, y: i64): i64 {
+    x % y
+}
+
+public fun neg(x: i16): i16 {
+    -x
+}
+
+public fun i8_to_u8(x: i8): u8 {
+    x as u8
+}
+
+public fun i128_to_u256(x: i128): u256 {
+    x as u256
+}
+
+public fun u8_to_i8(x: u8): i8 {
+    x as i8
+}
+
+public fun u256_to_i256(x: u256): i256 {
+    x as i256
+}
+
+public fun i256_to_i8(x: i256): i8 {
+    x as i8
+}
+
+public fun literals(): (i8, i256) {
+    (-128i8, -57896044618658097711785492504343953926634992332820282019728792003956564819968i256)
+}
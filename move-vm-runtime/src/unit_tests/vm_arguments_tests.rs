@@ -129,18 +129,18 @@ fn make_script_function(signature: Signature) -> (CompiledModule, Identifier) {
     )
 }
 
-struct RemoteStore {
+pub(crate) struct RemoteStore {
     modules: HashMap<ModuleId, Vec<u8>>,
 }
 
 impl RemoteStore {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             modules: HashMap::new(),
         }
     }
 
-    fn add_module(&mut self, compiled_module: CompiledModule) {
+    pub(crate) fn add_module(&mut self, compiled_module: CompiledModule) {
         let id = compiled_module.self_id();
         let mut bytes = vec![];
         compiled_module.serialize(&mut bytes).unwrap();
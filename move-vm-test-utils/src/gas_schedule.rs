@@ -23,6 +23,7 @@ use move_core_types::{
         AbstractMemorySize, GasQuantity, InternalGas, InternalGasPerAbstractMemoryUnit,
         InternalGasUnit, NumArgs, NumBytes, ToUnit, ToUnitFractional,
     },
+    i256,
     language_storage::ModuleId,
     u256,
     vm_status::StatusCode,
@@ -259,6 +260,18 @@ fn get_simple_instruction_opcode(instr: SimpleInstruction) -> Opcodes {
         CastU16 => CAST_U16,
         CastU32 => CAST_U32,
         CastU256 => CAST_U256,
+        LdI8 => LD_I8,
+        LdI16 => LD_I16,
+        LdI32 => LD_I32,
+        LdI64 => LD_I64,
+        LdI128 => LD_I128,
+        LdI256 => LD_I256,
+        CastI8 => CAST_I8,
+        CastI16 => CAST_I16,
+        CastI32 => CAST_I32,
+        CastI64 => CAST_I64,
+        CastI128 => CAST_I128,
+        CastI256 => CAST_I256,
     }
 }
 
@@ -663,6 +676,27 @@ pub fn zero_cost_instruction_table() -> Vec<(Bytecode, GasCost)> {
             VariantSwitch(VariantJumpTableIndex::new(0)),
             GasCost::new(0, 0),
         ),
+        (
+            PackClosure(FunctionHandleIndex::new(0), SignatureIndex::new(0)),
+            GasCost::new(0, 0),
+        ),
+        (
+            PackClosureGeneric(FunctionInstantiationIndex::new(0), SignatureIndex::new(0)),
+            GasCost::new(0, 0),
+        ),
+        (CallClosure(SignatureIndex::new(0)), GasCost::new(0, 0)),
+        (LdI8(0), GasCost::new(0, 0)),
+        (LdI16(0), GasCost::new(0, 0)),
+        (LdI32(0), GasCost::new(0, 0)),
+        (LdI64(0), GasCost::new(0, 0)),
+        (LdI128(Box::new(0)), GasCost::new(0, 0)),
+        (LdI256(Box::new(i256::I256::zero())), GasCost::new(0, 0)),
+        (CastI8, GasCost::new(0, 0)),
+        (CastI16, GasCost::new(0, 0)),
+        (CastI32, GasCost::new(0, 0)),
+        (CastI64, GasCost::new(0, 0)),
+        (CastI128, GasCost::new(0, 0)),
+        (CastI256, GasCost::new(0, 0)),
     ]
 }
 
@@ -844,6 +878,27 @@ pub fn bytecode_instruction_costs() -> Vec<(Bytecode, GasCost)> {
             VariantSwitch(VariantJumpTableIndex::new(0)),
             GasCost::new(2, 1),
         ),
+        (
+            PackClosure(FunctionHandleIndex::new(0), SignatureIndex::new(0)),
+            GasCost::new(2, 1),
+        ),
+        (
+            PackClosureGeneric(FunctionInstantiationIndex::new(0), SignatureIndex::new(0)),
+            GasCost::new(2, 1),
+        ),
+        (CallClosure(SignatureIndex::new(0)), GasCost::new(1132, 1)),
+        (LdI8(0), GasCost::new(1, 1)),
+        (LdI16(0), GasCost::new(1, 1)),
+        (LdI32(0), GasCost::new(1, 1)),
+        (LdI64(0), GasCost::new(1, 1)),
+        (LdI128(Box::new(0)), GasCost::new(1, 1)),
+        (LdI256(Box::new(i256::I256::zero())), GasCost::new(1, 1)),
+        (CastI8, GasCost::new(2, 1)),
+        (CastI16, GasCost::new(2, 1)),
+        (CastI32, GasCost::new(2, 1)),
+        (CastI64, GasCost::new(2, 1)),
+        (CastI128, GasCost::new(2, 1)),
+        (CastI256, GasCost::new(2, 1)),
     ]
 }
 
@@ -407,6 +407,12 @@ fn get_simple_instruction_stack_change(
         LdU64 => (0, 1, 0.into(), Type::U64.size()),
         LdU128 => (0, 1, 0.into(), Type::U128.size()),
         LdU256 => (0, 1, 0.into(), Type::U256.size()),
+        LdI8 => (0, 1, 0.into(), Type::I8.size()),
+        LdI16 => (0, 1, 0.into(), Type::I16.size()),
+        LdI32 => (0, 1, 0.into(), Type::I32.size()),
+        LdI64 => (0, 1, 0.into(), Type::I64.size()),
+        LdI128 => (0, 1, 0.into(), Type::I128.size()),
+        LdI256 => (0, 1, 0.into(), Type::I256.size()),
         LdTrue | LdFalse => (0, 1, 0.into(), Type::Bool.size()),
         FreezeRef => (1, 1, REFERENCE_SIZE, REFERENCE_SIZE),
         ImmBorrowLoc | MutBorrowLoc => (0, 1, 0.into(), REFERENCE_SIZE),
@@ -421,6 +427,12 @@ fn get_simple_instruction_stack_change(
         CastU64 => (1, 1, Type::U8.size(), Type::U64.size()),
         CastU128 => (1, 1, Type::U8.size(), Type::U128.size()),
         CastU256 => (1, 1, Type::U8.size(), Type::U256.size()),
+        CastI8 => (1, 1, Type::U8.size(), Type::I8.size()),
+        CastI16 => (1, 1, Type::U8.size(), Type::I16.size()),
+        CastI32 => (1, 1, Type::U8.size(), Type::I32.size()),
+        CastI64 => (1, 1, Type::U8.size(), Type::I64.size()),
+        CastI128 => (1, 1, Type::U8.size(), Type::I128.size()),
+        CastI256 => (1, 1, Type::U8.size(), Type::I256.size()),
         // NB: We don't know the size of what integers we're dealing with, so we conservatively
         // over-approximate by popping the smallest integers, and push the largest.
         Add | Sub | Mul | Mod | Div => (2, 1, Type::U8.size() + Type::U8.size(), Type::U256.size()),
@@ -215,6 +215,13 @@ pub enum Type {
     U16,
     U32,
     U256,
+    Function(Box<(Vec<Type>, Vec<Type>, AbilitySet)>),
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    I256,
 }
 
 impl Type {
@@ -238,6 +245,12 @@ impl Type {
             Type::U64 => Type::U64,
             Type::U128 => Type::U128,
             Type::U256 => Type::U256,
+            Type::I8 => Type::I8,
+            Type::I16 => Type::I16,
+            Type::I32 => Type::I32,
+            Type::I64 => Type::I64,
+            Type::I128 => Type::I128,
+            Type::I256 => Type::I256,
             Type::Address => Type::Address,
             Type::Signer => Type::Signer,
             Type::Vector(ty) => Type::Vector(Box::new(ty.apply_subst(subst, depth + 1)?)),
@@ -254,6 +267,18 @@ impl Type {
                 }
                 Type::DatatypeInstantiation(Box::new((*def_idx, inst)))
             }
+            Type::Function(func) => {
+                let (params, returns, abilities) = &**func;
+                let params = params
+                    .iter()
+                    .map(|ty| ty.apply_subst(subst, depth + 1))
+                    .collect::<PartialVMResult<_>>()?;
+                let returns = returns
+                    .iter()
+                    .map(|ty| ty.apply_subst(subst, depth + 1))
+                    .collect::<PartialVMResult<_>>()?;
+                Type::Function(Box::new((params, returns, *abilities)))
+            }
         };
         Ok(res)
     }
@@ -286,9 +311,8 @@ impl Type {
         use Type::*;
 
         match self {
-            TyParam(_) | Bool | U8 | U16 | U32 | U64 | U128 | U256 | Address | Signer => {
-                Self::LEGACY_BASE_MEMORY_SIZE
-            }
+            TyParam(_) | Bool | U8 | U16 | U32 | U64 | U128 | U256 | I8 | I16 | I32 | I64
+            | I128 | I256 | Address | Signer => Self::LEGACY_BASE_MEMORY_SIZE,
             Vector(ty) | Reference(ty) | MutableReference(ty) => {
                 Self::LEGACY_BASE_MEMORY_SIZE + ty.size()
             }
@@ -298,6 +322,13 @@ impl Type {
                 tys.iter()
                     .fold(Self::LEGACY_BASE_MEMORY_SIZE, |acc, ty| acc + ty.size())
             }
+            Function(func) => {
+                let (params, returns, _) = &**func;
+                params
+                    .iter()
+                    .chain(returns)
+                    .fold(Self::LEGACY_BASE_MEMORY_SIZE, |acc, ty| acc + ty.size())
+            }
         }
     }
 
@@ -313,6 +344,12 @@ impl Type {
             S::U64 => L::U64,
             S::U128 => L::U128,
             S::U256 => L::U256,
+            S::I8 => L::I8,
+            S::I16 => L::I16,
+            S::I32 => L::I32,
+            S::I64 => L::I64,
+            S::I128 => L::I128,
+            S::I256 => L::I256,
             S::Address => L::Address,
             S::Vector(inner) => L::Vector(Box::new(Self::from_const_signature(inner)?)),
             // Not yet supported
@@ -323,7 +360,11 @@ impl Type {
                 )
             }
             // Not allowed/Not meaningful
-            S::TypeParameter(_) | S::Reference(_) | S::MutableReference(_) | S::Signer => {
+            S::TypeParameter(_)
+            | S::Reference(_)
+            | S::MutableReference(_)
+            | S::Signer
+            | S::Function(_) => {
                 return Err(
                     PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
                         .with_message("Unable to load const type signature".to_string()),
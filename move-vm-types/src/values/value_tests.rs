@@ -5,7 +5,9 @@
 
 use crate::{loaded_data::runtime_types::Type, values::*, views::*};
 use move_binary_format::errors::*;
-use move_core_types::{account_address::AccountAddress, u256::U256};
+use move_core_types::{
+    account_address::AccountAddress, i256::I256, u256::U256, vm_status::StatusCode,
+};
 
 #[test]
 fn locals() -> PartialVMResult<()> {
@@ -232,3 +234,51 @@ fn test_vm_value_vector_u64_casting() {
 fn assert_sizes() {
     assert_eq!(size_of::<Value>(), 16);
 }
+
+#[test]
+fn signed_integer_arithmetic() -> PartialVMResult<()> {
+    let int = |v: Value| v.value_as::<IntegerValue>();
+
+    let x = int(Value::i8(-5))?.sub_checked(int(Value::i8(3))?)?;
+    assert_eq!(x.value_as::<i8>()?, -8);
+    assert!(int(Value::i8(i8::MIN))?
+        .sub_checked(int(Value::i8(1))?)
+        .is_err());
+    assert!(int(Value::i64(i64::MIN))?
+        .div_checked(int(Value::i64(-1))?)
+        .is_err());
+    assert!(int(Value::i16(-1))?.lt(int(Value::i16(0))?)?);
+
+    assert!(int(Value::i32(-1))?.cast_u8().is_err());
+    assert_eq!(int(Value::u64(7))?.cast_i8()?, 7);
+    assert!(int(Value::i128(-129))?.cast_i8().is_err());
+    Ok(())
+}
+
+#[test]
+fn signed_integer_shifts() -> PartialVMResult<()> {
+    let signed = [
+        Value::i8(-1),
+        Value::i16(-1),
+        Value::i32(-1),
+        Value::i64(-1),
+        Value::i128(-1),
+        Value::i256(I256::from(-1i8)),
+    ];
+    for value in signed {
+        let shl = value
+            .copy_value()?
+            .value_as::<IntegerValue>()?
+            .shl_checked(1);
+        assert_eq!(
+            shl.unwrap_err().major_status(),
+            StatusCode::INTERNAL_TYPE_ERROR
+        );
+        let shr = value.value_as::<IntegerValue>()?.shr_checked(1);
+        assert_eq!(
+            shr.unwrap_err().major_status(),
+            StatusCode::INTERNAL_TYPE_ERROR
+        );
+    }
+    Ok(())
+}
@@ -16,6 +16,7 @@ use move_core_types::{
     account_address::AccountAddress,
     effects::Op,
     gas_algebra::AbstractMemorySize,
+    i256,
     runtime_value::{MoveEnumLayout, MoveStructLayout, MoveTypeLayout},
     u256,
     vm_status::{sub_status::NFE_VECTOR_ERROR_BASE, StatusCode},
@@ -53,10 +54,19 @@ enum ValueImpl {
     Bool(bool),
     Address(Box<AccountAddress>),
 
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(Box<i128>),
+    I256(Box<i256::I256>),
+
     Container(Container),
 
     ContainerRef(Box<ContainerRef>),
     IndexedRef(Box<IndexedRef>),
+
+    Closure(Box<Closure>),
 }
 
 /// A container is a collection of values. It is used to represent data structures like a
@@ -64,7 +74,7 @@ enum ValueImpl {
 ///
 /// There is one general container that can be used to store an array of any values, same
 /// type or not, and a few specialized flavors to offer compact memory layout for small
-/// primitive types.
+/// primitive types. Vectors of signed integers use the general container.
 ///
 /// Except when not owned by the VM stack, a container always lives inside an Rc<RefCell<>>,
 /// making it possible to be shared by references.
@@ -149,6 +159,12 @@ pub enum IntegerValue {
     U64(u64),
     U128(u128),
     U256(u256::U256),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    I256(i256::I256),
 }
 
 /// A Move struct.
@@ -223,6 +239,18 @@ pub struct Variant {
 #[derive(Debug)]
 pub struct VariantRef(ContainerRef);
 
+/// A Move function value: a function together with its type arguments and the values captured
+/// when the closure was packed. The function is identified by its index in the loader's
+/// function cache, which is only meaningful to the runtime that created the closure.
+#[derive(Debug)]
+pub struct Closure {
+    function: usize,
+    ty_args: Vec<Type>,
+    captured: Vec<ValueImpl>,
+    /// The nesting depth of the closure value, cached as function types do not bound it.
+    depth: u64,
+}
+
 /***************************************************************************************
  *
  * Misc
@@ -334,6 +362,12 @@ impl_vm_value_ref!(u128, U128);
 impl_vm_value_ref!(u256::U256, U256);
 impl_vm_value_ref!(bool, Bool);
 impl_vm_value_ref!(AccountAddress, Address);
+impl_vm_value_ref!(i8, I8);
+impl_vm_value_ref!(i16, I16);
+impl_vm_value_ref!(i32, I32);
+impl_vm_value_ref!(i64, I64);
+impl_vm_value_ref!(i128, I128);
+impl_vm_value_ref!(i256::I256, I256);
 
 impl ValueImpl {
     fn as_value_ref<T>(&self) -> PartialVMResult<&T>
@@ -368,6 +402,12 @@ impl ValueImpl {
             U256(x) => U256(x.clone()),
             Bool(x) => Bool(*x),
             Address(x) => Address(x.clone()),
+            I8(x) => I8(*x),
+            I16(x) => I16(*x),
+            I32(x) => I32(*x),
+            I64(x) => I64(*x),
+            I128(x) => I128(x.clone()),
+            I256(x) => I256(x.clone()),
 
             ContainerRef(r) => ContainerRef(Box::new(r.copy_value())),
             IndexedRef(r) => IndexedRef(Box::new(r.copy_value())),
@@ -375,6 +415,23 @@ impl ValueImpl {
             // When cloning a container, we need to make sure we make a deep
             // copy of the data instead of a shallow copy of the Rc.
             Container(c) => Container(c.copy_value()?),
+
+            Closure(c) => Closure(Box::new(c.copy_value()?)),
+        })
+    }
+}
+
+impl Closure {
+    fn copy_value(&self) -> PartialVMResult<Self> {
+        Ok(Self {
+            function: self.function,
+            ty_args: self.ty_args.clone(),
+            captured: self
+                .captured
+                .iter()
+                .map(|v| v.copy_value())
+                .collect::<PartialVMResult<_>>()?,
+            depth: self.depth,
         })
     }
 }
@@ -495,11 +552,19 @@ impl ValueImpl {
             (U256(l), U256(r)) => l == r,
             (Bool(l), Bool(r)) => l == r,
             (Address(l), Address(r)) => l == r,
+            (I8(l), I8(r)) => l == r,
+            (I16(l), I16(r)) => l == r,
+            (I32(l), I32(r)) => l == r,
+            (I64(l), I64(r)) => l == r,
+            (I128(l), I128(r)) => l == r,
+            (I256(l), I256(r)) => l == r,
 
             (Container(l), Container(r)) => l.equals(r)?,
 
             (ContainerRef(l), ContainerRef(r)) => l.equals(r)?,
             (IndexedRef(l), IndexedRef(r)) => l.equals(r)?,
+
+            (Closure(l), Closure(r)) => l.equals(r)?,
             (Invalid, _)
             | (U8(_), _)
             | (U16(_), _)
@@ -509,9 +574,16 @@ impl ValueImpl {
             | (U256(_), _)
             | (Bool(_), _)
             | (Address(_), _)
+            | (I8(_), _)
+            | (I16(_), _)
+            | (I32(_), _)
+            | (I64(_), _)
+            | (I128(_), _)
+            | (I256(_), _)
             | (Container(_), _)
             | (ContainerRef(_), _)
-            | (IndexedRef(_), _) => {
+            | (IndexedRef(_), _)
+            | (Closure(_), _) => {
                 return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
                     .with_message(format!("cannot compare values: {:?}, {:?}", self, other)))
             }
@@ -521,6 +593,23 @@ impl ValueImpl {
     }
 }
 
+impl Closure {
+    fn equals(&self, other: &Self) -> PartialVMResult<bool> {
+        if self.function != other.function
+            || self.ty_args != other.ty_args
+            || self.captured.len() != other.captured.len()
+        {
+            return Ok(false);
+        }
+        for (v1, v2) in self.captured.iter().zip(other.captured.iter()) {
+            if !v1.equals(v2)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
 impl Container {
     fn equals(&self, other: &Self) -> PartialVMResult<bool> {
         use Container::*;
@@ -1111,7 +1200,14 @@ impl VariantRef {
                         | ValueImpl::U128(_)
                         | ValueImpl::U256(_)
                         | ValueImpl::Bool(_)
-                        | ValueImpl::Address(_) => ValueImpl::IndexedRef(Box::new(IndexedRef {
+                        | ValueImpl::Address(_)
+                        | ValueImpl::I8(_)
+                        | ValueImpl::I16(_)
+                        | ValueImpl::I32(_)
+                        | ValueImpl::I64(_)
+                        | ValueImpl::I128(_)
+                        | ValueImpl::I256(_)
+                        | ValueImpl::Closure(_) => ValueImpl::IndexedRef(Box::new(IndexedRef {
                             idx,
                             container_ref: self.0.copy_value(),
                         })),
@@ -1180,7 +1276,14 @@ impl Locals {
             | ValueImpl::U128(_)
             | ValueImpl::U256(_)
             | ValueImpl::Bool(_)
-            | ValueImpl::Address(_) => Ok(Value(ValueImpl::IndexedRef(Box::new(IndexedRef {
+            | ValueImpl::Address(_)
+            | ValueImpl::I8(_)
+            | ValueImpl::I16(_)
+            | ValueImpl::I32(_)
+            | ValueImpl::I64(_)
+            | ValueImpl::I128(_)
+            | ValueImpl::I256(_)
+            | ValueImpl::Closure(_) => Ok(Value(ValueImpl::IndexedRef(Box::new(IndexedRef {
                 container_ref: ContainerRef::Local(Container::Locals(Rc::clone(&self.0))),
                 idx,
             })))),
@@ -1347,6 +1450,30 @@ impl Value {
         Self(ValueImpl::Bool(x))
     }
 
+    pub fn i8(x: i8) -> Self {
+        Self(ValueImpl::I8(x))
+    }
+
+    pub fn i16(x: i16) -> Self {
+        Self(ValueImpl::I16(x))
+    }
+
+    pub fn i32(x: i32) -> Self {
+        Self(ValueImpl::I32(x))
+    }
+
+    pub fn i64(x: i64) -> Self {
+        Self(ValueImpl::I64(x))
+    }
+
+    pub fn i128(x: i128) -> Self {
+        Self(ValueImpl::I128(Box::new(x)))
+    }
+
+    pub fn i256(x: i256::I256) -> Self {
+        Self(ValueImpl::I256(Box::new(x)))
+    }
+
     pub fn address(x: AccountAddress) -> Self {
         Self(ValueImpl::Address(Box::new(x)))
     }
@@ -1375,6 +1502,10 @@ impl Value {
         ))))
     }
 
+    pub fn closure(c: Closure) -> Self {
+        Self(ValueImpl::Closure(Box::new(c)))
+    }
+
     // TODO: consider whether we want to replace these with fn vector(v: Vec<Value>).
     pub fn vector_u8(it: impl IntoIterator<Item = u8>) -> Self {
         Self(ValueImpl::Container(Container::VecU8(Rc::new(
@@ -1485,6 +1616,12 @@ impl_vm_value_cast_boxed!(u128, U128);
 impl_vm_value_cast_boxed!(u256::U256, U256);
 impl_vm_value_cast!(bool, Bool);
 impl_vm_value_cast_boxed!(AccountAddress, Address);
+impl_vm_value_cast!(i8, I8);
+impl_vm_value_cast!(i16, I16);
+impl_vm_value_cast!(i32, I32);
+impl_vm_value_cast!(i64, I64);
+impl_vm_value_cast_boxed!(i128, I128);
+impl_vm_value_cast_boxed!(i256::I256, I256);
 impl_vm_value_cast_boxed!(ContainerRef, ContainerRef);
 impl_vm_value_cast_boxed!(IndexedRef, IndexedRef);
 
@@ -1497,6 +1634,12 @@ impl VMValueCast<IntegerValue> for Value {
             ValueImpl::U64(x) => Ok(IntegerValue::U64(x)),
             ValueImpl::U128(x) => Ok(IntegerValue::U128(*x)),
             ValueImpl::U256(x) => Ok(IntegerValue::U256(*x)),
+            ValueImpl::I8(x) => Ok(IntegerValue::I8(x)),
+            ValueImpl::I16(x) => Ok(IntegerValue::I16(x)),
+            ValueImpl::I32(x) => Ok(IntegerValue::I32(x)),
+            ValueImpl::I64(x) => Ok(IntegerValue::I64(x)),
+            ValueImpl::I128(x) => Ok(IntegerValue::I128(*x)),
+            ValueImpl::I256(x) => Ok(IntegerValue::I256(*x)),
             v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
                 .with_message(format!("cannot cast {:?} to integer", v,))),
         }
@@ -1549,6 +1692,16 @@ impl VMValueCast<Variant> for Value {
     }
 }
 
+impl VMValueCast<Closure> for Value {
+    fn cast(self) -> PartialVMResult<Closure> {
+        match self.0 {
+            ValueImpl::Closure(c) => Ok(*c),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to closure", v,))),
+        }
+    }
+}
+
 impl VMValueCast<StructRef> for Value {
     fn cast(self) -> PartialVMResult<StructRef> {
         Ok(StructRef(VMValueCast::cast(self)?))
@@ -1717,6 +1870,66 @@ impl VMValueCast<u256::U256> for IntegerValue {
     }
 }
 
+impl VMValueCast<i8> for IntegerValue {
+    fn cast(self) -> PartialVMResult<i8> {
+        match self {
+            Self::I8(x) => Ok(x),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to i8", v,))),
+        }
+    }
+}
+
+impl VMValueCast<i16> for IntegerValue {
+    fn cast(self) -> PartialVMResult<i16> {
+        match self {
+            Self::I16(x) => Ok(x),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to i16", v,))),
+        }
+    }
+}
+
+impl VMValueCast<i32> for IntegerValue {
+    fn cast(self) -> PartialVMResult<i32> {
+        match self {
+            Self::I32(x) => Ok(x),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to i32", v,))),
+        }
+    }
+}
+
+impl VMValueCast<i64> for IntegerValue {
+    fn cast(self) -> PartialVMResult<i64> {
+        match self {
+            Self::I64(x) => Ok(x),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to i64", v,))),
+        }
+    }
+}
+
+impl VMValueCast<i128> for IntegerValue {
+    fn cast(self) -> PartialVMResult<i128> {
+        match self {
+            Self::I128(x) => Ok(x),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to i128", v,))),
+        }
+    }
+}
+
+impl VMValueCast<i256::I256> for IntegerValue {
+    fn cast(self) -> PartialVMResult<i256::I256> {
+        match self {
+            Self::I256(x) => Ok(x),
+            v => Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                .with_message(format!("cannot cast {:?} to i256", v,))),
+        }
+    }
+}
+
 impl IntegerValue {
     pub fn value_as<T>(self) -> PartialVMResult<T>
     where
@@ -1743,6 +1956,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => u64::checked_add(l, r).map(IntegerValue::U64),
             (U128(l), U128(r)) => u128::checked_add(l, r).map(IntegerValue::U128),
             (U256(l), U256(r)) => u256::U256::checked_add(l, r).map(IntegerValue::U256),
+            (I8(l), I8(r)) => i8::checked_add(l, r).map(IntegerValue::I8),
+            (I16(l), I16(r)) => i16::checked_add(l, r).map(IntegerValue::I16),
+            (I32(l), I32(r)) => i32::checked_add(l, r).map(IntegerValue::I32),
+            (I64(l), I64(r)) => i64::checked_add(l, r).map(IntegerValue::I64),
+            (I128(l), I128(r)) => i128::checked_add(l, r).map(IntegerValue::I128),
+            (I256(l), I256(r)) => i256::I256::checked_add(l, r).map(IntegerValue::I256),
             (l, r) => {
                 let msg = format!("Cannot add {:?} and {:?}", l, r);
                 return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(msg));
@@ -1760,6 +1979,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => u64::checked_sub(l, r).map(IntegerValue::U64),
             (U128(l), U128(r)) => u128::checked_sub(l, r).map(IntegerValue::U128),
             (U256(l), U256(r)) => u256::U256::checked_sub(l, r).map(IntegerValue::U256),
+            (I8(l), I8(r)) => i8::checked_sub(l, r).map(IntegerValue::I8),
+            (I16(l), I16(r)) => i16::checked_sub(l, r).map(IntegerValue::I16),
+            (I32(l), I32(r)) => i32::checked_sub(l, r).map(IntegerValue::I32),
+            (I64(l), I64(r)) => i64::checked_sub(l, r).map(IntegerValue::I64),
+            (I128(l), I128(r)) => i128::checked_sub(l, r).map(IntegerValue::I128),
+            (I256(l), I256(r)) => i256::I256::checked_sub(l, r).map(IntegerValue::I256),
             (l, r) => {
                 let msg = format!("Cannot sub {:?} from {:?}", r, l);
                 return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(msg));
@@ -1777,6 +2002,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => u64::checked_mul(l, r).map(IntegerValue::U64),
             (U128(l), U128(r)) => u128::checked_mul(l, r).map(IntegerValue::U128),
             (U256(l), U256(r)) => u256::U256::checked_mul(l, r).map(IntegerValue::U256),
+            (I8(l), I8(r)) => i8::checked_mul(l, r).map(IntegerValue::I8),
+            (I16(l), I16(r)) => i16::checked_mul(l, r).map(IntegerValue::I16),
+            (I32(l), I32(r)) => i32::checked_mul(l, r).map(IntegerValue::I32),
+            (I64(l), I64(r)) => i64::checked_mul(l, r).map(IntegerValue::I64),
+            (I128(l), I128(r)) => i128::checked_mul(l, r).map(IntegerValue::I128),
+            (I256(l), I256(r)) => i256::I256::checked_mul(l, r).map(IntegerValue::I256),
             (l, r) => {
                 let msg = format!("Cannot mul {:?} and {:?}", l, r);
                 return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(msg));
@@ -1794,6 +2025,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => u64::checked_div(l, r).map(IntegerValue::U64),
             (U128(l), U128(r)) => u128::checked_div(l, r).map(IntegerValue::U128),
             (U256(l), U256(r)) => u256::U256::checked_div(l, r).map(IntegerValue::U256),
+            (I8(l), I8(r)) => i8::checked_div(l, r).map(IntegerValue::I8),
+            (I16(l), I16(r)) => i16::checked_div(l, r).map(IntegerValue::I16),
+            (I32(l), I32(r)) => i32::checked_div(l, r).map(IntegerValue::I32),
+            (I64(l), I64(r)) => i64::checked_div(l, r).map(IntegerValue::I64),
+            (I128(l), I128(r)) => i128::checked_div(l, r).map(IntegerValue::I128),
+            (I256(l), I256(r)) => i256::I256::checked_div(l, r).map(IntegerValue::I256),
             (l, r) => {
                 let msg = format!("Cannot div {:?} by {:?}", l, r);
                 return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(msg));
@@ -1811,6 +2048,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => u64::checked_rem(l, r).map(IntegerValue::U64),
             (U128(l), U128(r)) => u128::checked_rem(l, r).map(IntegerValue::U128),
             (U256(l), U256(r)) => u256::U256::checked_rem(l, r).map(IntegerValue::U256),
+            (I8(l), I8(r)) => i8::checked_rem(l, r).map(IntegerValue::I8),
+            (I16(l), I16(r)) => i16::checked_rem(l, r).map(IntegerValue::I16),
+            (I32(l), I32(r)) => i32::checked_rem(l, r).map(IntegerValue::I32),
+            (I64(l), I64(r)) => i64::checked_rem(l, r).map(IntegerValue::I64),
+            (I128(l), I128(r)) => i128::checked_rem(l, r).map(IntegerValue::I128),
+            (I256(l), I256(r)) => i256::I256::checked_rem(l, r).map(IntegerValue::I256),
             (l, r) => {
                 let msg = format!("Cannot rem {:?} by {:?}", l, r);
                 return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(msg));
@@ -1902,6 +2145,10 @@ impl IntegerValue {
                 IntegerValue::U128(x << n_bits)
             }
             U256(x) => IntegerValue::U256(x << n_bits),
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => {
+                let msg = format!("Cannot shift signed integer {:?}", x);
+                return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(msg));
+            }
         })
     }
 
@@ -1940,6 +2187,10 @@ impl IntegerValue {
                 IntegerValue::U128(x >> n_bits)
             }
             U256(x) => IntegerValue::U256(x >> n_bits),
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => {
+                let msg = format!("Cannot shift signed integer {:?}", x);
+                return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(msg));
+            }
         })
     }
 
@@ -1953,6 +2204,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => l < r,
             (U128(l), U128(r)) => l < r,
             (U256(l), U256(r)) => l < r,
+            (I8(l), I8(r)) => l < r,
+            (I16(l), I16(r)) => l < r,
+            (I32(l), I32(r)) => l < r,
+            (I64(l), I64(r)) => l < r,
+            (I128(l), I128(r)) => l < r,
+            (I256(l), I256(r)) => l < r,
             (l, r) => {
                 let msg = format!(
                     "Cannot compare {:?} and {:?}: incompatible integer types",
@@ -1973,6 +2230,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => l <= r,
             (U128(l), U128(r)) => l <= r,
             (U256(l), U256(r)) => l <= r,
+            (I8(l), I8(r)) => l <= r,
+            (I16(l), I16(r)) => l <= r,
+            (I32(l), I32(r)) => l <= r,
+            (I64(l), I64(r)) => l <= r,
+            (I128(l), I128(r)) => l <= r,
+            (I256(l), I256(r)) => l <= r,
             (l, r) => {
                 let msg = format!(
                     "Cannot compare {:?} and {:?}: incompatible integer types",
@@ -1993,6 +2256,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => l > r,
             (U128(l), U128(r)) => l > r,
             (U256(l), U256(r)) => l > r,
+            (I8(l), I8(r)) => l > r,
+            (I16(l), I16(r)) => l > r,
+            (I32(l), I32(r)) => l > r,
+            (I64(l), I64(r)) => l > r,
+            (I128(l), I128(r)) => l > r,
+            (I256(l), I256(r)) => l > r,
             (l, r) => {
                 let msg = format!(
                     "Cannot compare {:?} and {:?}: incompatible integer types",
@@ -2013,6 +2282,12 @@ impl IntegerValue {
             (U64(l), U64(r)) => l >= r,
             (U128(l), U128(r)) => l >= r,
             (U256(l), U256(r)) => l >= r,
+            (I8(l), I8(r)) => l >= r,
+            (I16(l), I16(r)) => l >= r,
+            (I32(l), I32(r)) => l >= r,
+            (I64(l), I64(r)) => l >= r,
+            (I128(l), I128(r)) => l >= r,
+            (I256(l), I256(r)) => l >= r,
             (l, r) => {
                 let msg = format!(
                     "Cannot compare {:?} and {:?}: incompatible integer types",
@@ -2033,6 +2308,12 @@ impl IntegerValue {
             U64(x) => Value::u64(x),
             U128(x) => Value::u128(x),
             U256(x) => Value::u256(x),
+            I8(x) => Value::i8(x),
+            I16(x) => Value::i16(x),
+            I32(x) => Value::i32(x),
+            I64(x) => Value::i64(x),
+            I128(x) => Value::i128(x),
+            I256(x) => Value::i256(x),
         }
     }
 }
@@ -2083,6 +2364,7 @@ impl IntegerValue {
                     Ok(x.unchecked_as_u8())
                 }
             }
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => x.cast_through_i256("u8"),
         }
     }
 
@@ -2124,6 +2406,9 @@ impl IntegerValue {
                     Ok(x.unchecked_as_u16())
                 }
             }
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => {
+                x.cast_through_i256("u16")
+            }
         }
     }
 
@@ -2158,6 +2443,9 @@ impl IntegerValue {
                     Ok(x.unchecked_as_u32())
                 }
             }
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => {
+                x.cast_through_i256("u32")
+            }
         }
     }
 
@@ -2185,6 +2473,9 @@ impl IntegerValue {
                     Ok(x.unchecked_as_u64())
                 }
             }
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => {
+                x.cast_through_i256("u64")
+            }
         }
     }
 
@@ -2205,6 +2496,9 @@ impl IntegerValue {
                     Ok(x.unchecked_as_u128())
                 }
             }
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => {
+                x.cast_through_i256("u128")
+            }
         }
     }
 
@@ -2218,6 +2512,58 @@ impl IntegerValue {
             U64(x) => u256::U256::from(x),
             U128(x) => u256::U256::from(x),
             U256(x) => x,
+            x @ (I8(_) | I16(_) | I32(_) | I64(_) | I128(_) | I256(_)) => {
+                return x.cast_through_i256("u256")
+            }
+        })
+    }
+
+    pub fn cast_i8(self) -> PartialVMResult<i8> {
+        self.cast_through_i256("i8")
+    }
+
+    pub fn cast_i16(self) -> PartialVMResult<i16> {
+        self.cast_through_i256("i16")
+    }
+
+    pub fn cast_i32(self) -> PartialVMResult<i32> {
+        self.cast_through_i256("i32")
+    }
+
+    pub fn cast_i64(self) -> PartialVMResult<i64> {
+        self.cast_through_i256("i64")
+    }
+
+    pub fn cast_i128(self) -> PartialVMResult<i128> {
+        self.cast_through_i256("i128")
+    }
+
+    pub fn cast_i256(self) -> PartialVMResult<i256::I256> {
+        self.cast_through_i256("i256")
+    }
+
+    /// Casts by going through `I256`, which holds every integer value that fits in a signed
+    /// integer type or in an unsigned type narrower than `u256`.
+    fn cast_through_i256<T: TryFrom<i256::I256>>(self, target: &str) -> PartialVMResult<T> {
+        use IntegerValue::*;
+
+        let value = match &self {
+            U8(x) => Some(i256::I256::from(*x)),
+            U16(x) => Some(i256::I256::from(*x)),
+            U32(x) => Some(i256::I256::from(*x)),
+            U64(x) => Some(i256::I256::from(*x)),
+            U128(x) => Some(i256::I256::from(*x)),
+            U256(x) => i256::I256::try_from(*x).ok(),
+            I8(x) => Some(i256::I256::from(*x)),
+            I16(x) => Some(i256::I256::from(*x)),
+            I32(x) => Some(i256::I256::from(*x)),
+            I64(x) => Some(i256::I256::from(*x)),
+            I128(x) => Some(i256::I256::from(*x)),
+            I256(x) => Some(*x),
+        };
+        value.and_then(|x| T::try_from(x).ok()).ok_or_else(|| {
+            PartialVMError::new(StatusCode::ARITHMETIC_ERROR)
+                .with_message(format!("Cannot cast {:?} to {}", self, target))
         })
     }
 }
@@ -2251,7 +2597,15 @@ fn check_elem_layout(ty: &Type, v: &Container) -> PartialVMResult<()> {
 
         (Type::Datatype(_), Container::Vec(_))
         | (Type::Signer, Container::Vec(_))
-        | (Type::DatatypeInstantiation(_), Container::Vec(_)) => Ok(()),
+        | (Type::DatatypeInstantiation(_), Container::Vec(_))
+        | (Type::Function(_), Container::Vec(_)) => Ok(()),
+
+        (Type::I8, Container::Vec(_))
+        | (Type::I16, Container::Vec(_))
+        | (Type::I32, Container::Vec(_))
+        | (Type::I64, Container::Vec(_))
+        | (Type::I128, Container::Vec(_))
+        | (Type::I256, Container::Vec(_)) => Ok(()),
 
         (Type::Reference(_), _) | (Type::MutableReference(_), _) | (Type::TyParam(_), _) => Err(
             PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
@@ -2264,12 +2618,19 @@ fn check_elem_layout(ty: &Type, v: &Container) -> PartialVMResult<()> {
         | (Type::U32, _)
         | (Type::U128, _)
         | (Type::U256, _)
+        | (Type::I8, _)
+        | (Type::I16, _)
+        | (Type::I32, _)
+        | (Type::I64, _)
+        | (Type::I128, _)
+        | (Type::I256, _)
         | (Type::Bool, _)
         | (Type::Address, _)
         | (Type::Signer, _)
         | (Type::Vector(_), _)
         | (Type::Datatype(_), _)
-        | (Type::DatatypeInstantiation(_), _) => Err(PartialVMError::new(
+        | (Type::DatatypeInstantiation(_), _)
+        | (Type::Function(_), _) => Err(PartialVMError::new(
             StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
         )
         .with_message(format!(
@@ -2507,11 +2868,19 @@ impl Vector {
                     .collect::<PartialVMResult<Vec<_>>>()?,
             ),
 
-            Type::Signer | Type::Vector(_) | Type::Datatype(_) | Type::DatatypeInstantiation(_) => {
-                Value(ValueImpl::Container(Container::Vec(Rc::new(RefCell::new(
-                    elements.into_iter().map(|v| v.0).collect(),
-                )))))
-            }
+            Type::Signer
+            | Type::Vector(_)
+            | Type::Datatype(_)
+            | Type::DatatypeInstantiation(_)
+            | Type::Function(_)
+            | Type::I8
+            | Type::I16
+            | Type::I32
+            | Type::I64
+            | Type::I128
+            | Type::I256 => Value(ValueImpl::Container(Container::Vec(Rc::new(RefCell::new(
+                elements.into_iter().map(|v| v.0).collect(),
+            ))))),
 
             Type::Reference(_) | Type::MutableReference(_) | Type::TyParam(_) => {
                 return Err(
@@ -2667,18 +3036,62 @@ impl ValueImpl {
         use ValueImpl::*;
 
         match self {
-            Invalid | U8(_) | U16(_) | U32(_) | U64(_) | U128(_) | U256(_) | Bool(_) => {
-                LEGACY_CONST_SIZE
-            }
+            Invalid | U8(_) | U16(_) | U32(_) | U64(_) | U128(_) | U256(_) | I8(_) | I16(_)
+            | I32(_) | I64(_) | I128(_) | I256(_) | Bool(_) => LEGACY_CONST_SIZE,
             Address(_) => AbstractMemorySize::new(AccountAddress::LENGTH as u64),
             ContainerRef(r) => r.legacy_size(),
             IndexedRef(r) => r.legacy_size(),
             // TODO: in case the borrow fails the VM will panic.
             Container(c) => c.legacy_size(),
+            Closure(c) => c.legacy_size(),
+        }
+    }
+}
+
+impl ValueImpl {
+    /// The nesting depth of a value. The depth of values of any type other than a function type
+    /// is bounded by the depth of that type, so this only recurses as deep as the value's type.
+    fn depth(&self) -> u64 {
+        match self {
+            Self::Container(Container::Vec(r))
+            | Self::Container(Container::Struct(r))
+            | Self::Container(Container::Locals(r)) => {
+                1 + r.borrow().iter().map(|v| v.depth()).max().unwrap_or(0)
+            }
+            Self::Container(Container::Variant(r)) => {
+                1 + r.borrow().1.iter().map(|v| v.depth()).max().unwrap_or(0)
+            }
+            Self::Container(_) => 2,
+            Self::Closure(c) => c.depth,
+            Self::Invalid
+            | Self::U8(_)
+            | Self::U16(_)
+            | Self::U32(_)
+            | Self::U64(_)
+            | Self::U128(_)
+            | Self::U256(_)
+            | Self::I8(_)
+            | Self::I16(_)
+            | Self::I32(_)
+            | Self::I64(_)
+            | Self::I128(_)
+            | Self::I256(_)
+            | Self::Bool(_)
+            | Self::Address(_)
+            | Self::ContainerRef(_)
+            | Self::IndexedRef(_) => 1,
         }
     }
 }
 
+impl Closure {
+    fn legacy_size(&self) -> AbstractMemorySize {
+        self.captured
+            .iter()
+            .fold(LEGACY_STRUCT_SIZE, |acc, v| acc + v.legacy_size())
+    }
+}
+
 impl Variant {
     const TAG_SIZE: AbstractMemorySize = AbstractMemorySize::new(std::mem::size_of::<u16>() as u64);
 
@@ -2775,6 +3188,44 @@ impl Variant {
     }
 }
 
+/***************************************************************************************
+ *
+ * Closure Operations
+ *
+ *   Public APIs for function values.
+ *
+ **************************************************************************************/
+impl Closure {
+    pub fn pack<I: IntoIterator<Item = Value>>(
+        function: usize,
+        ty_args: Vec<Type>,
+        captured: I,
+    ) -> Self {
+        let captured: Vec<_> = captured.into_iter().map(|v| v.0).collect();
+        let depth = 1 + captured.iter().map(|v| v.depth()).max().unwrap_or(0);
+        Self {
+            function,
+            ty_args,
+            captured,
+            depth,
+        }
+    }
+
+    /// The nesting depth of the closure, counting the closure itself and its captured values.
+    pub fn depth(&self) -> u64 {
+        self.depth
+    }
+
+    /// Returns the function index, its type arguments, and the captured values.
+    pub fn unpack(self) -> (usize, Vec<Type>, impl Iterator<Item = Value>) {
+        (
+            self.function,
+            self.ty_args,
+            self.captured.into_iter().map(Value),
+        )
+    }
+}
+
 /***************************************************************************************
  *
  * Global Value Operations
@@ -2975,11 +3426,23 @@ impl Display for ValueImpl {
             Self::U256(x) => write!(f, "U256({})", x),
             Self::Bool(x) => write!(f, "{}", x),
             Self::Address(addr) => write!(f, "Address({})", addr.short_str_lossless()),
+            Self::I8(x) => write!(f, "I8({})", x),
+            Self::I16(x) => write!(f, "I16({})", x),
+            Self::I32(x) => write!(f, "I32({})", x),
+            Self::I64(x) => write!(f, "I64({})", x),
+            Self::I128(x) => write!(f, "I128({})", x),
+            Self::I256(x) => write!(f, "I256({})", x),
 
             Self::Container(r) => write!(f, "{}", r),
 
             Self::ContainerRef(r) => write!(f, "{}", r),
             Self::IndexedRef(r) => write!(f, "{}", r),
+
+            Self::Closure(c) => {
+                write!(f, "Closure(#{}, ", c.function)?;
+                display_list_of_items(&c.captured, f)?;
+                write!(f, ")")
+            }
         }
     }
 }
@@ -3129,6 +3592,30 @@ pub mod debug {
         debug_write!(buf, "{}", x)
     }
 
+    fn print_i8<B: Write>(buf: &mut B, x: &i8) -> PartialVMResult<()> {
+        debug_write!(buf, "{}", x)
+    }
+
+    fn print_i16<B: Write>(buf: &mut B, x: &i16) -> PartialVMResult<()> {
+        debug_write!(buf, "{}", x)
+    }
+
+    fn print_i32<B: Write>(buf: &mut B, x: &i32) -> PartialVMResult<()> {
+        debug_write!(buf, "{}", x)
+    }
+
+    fn print_i64<B: Write>(buf: &mut B, x: &i64) -> PartialVMResult<()> {
+        debug_write!(buf, "{}", x)
+    }
+
+    fn print_i128<B: Write>(buf: &mut B, x: &i128) -> PartialVMResult<()> {
+        debug_write!(buf, "{}", x)
+    }
+
+    fn print_i256<B: Write>(buf: &mut B, x: &i256::I256) -> PartialVMResult<()> {
+        debug_write!(buf, "{}", x)
+    }
+
     fn print_bool<B: Write>(buf: &mut B, x: &bool) -> PartialVMResult<()> {
         debug_write!(buf, "{}", x)
     }
@@ -3149,11 +3636,22 @@ pub mod debug {
             ValueImpl::U256(x) => print_u256(buf, x),
             ValueImpl::Bool(x) => print_bool(buf, x),
             ValueImpl::Address(x) => print_address(buf, x),
+            ValueImpl::I8(x) => print_i8(buf, x),
+            ValueImpl::I16(x) => print_i16(buf, x),
+            ValueImpl::I32(x) => print_i32(buf, x),
+            ValueImpl::I64(x) => print_i64(buf, x),
+            ValueImpl::I128(x) => print_i128(buf, x),
+            ValueImpl::I256(x) => print_i256(buf, x),
 
             ValueImpl::Container(c) => print_container(buf, c),
 
             ValueImpl::ContainerRef(r) => print_container_ref(buf, r),
             ValueImpl::IndexedRef(r) => print_indexed_ref(buf, r),
+
+            ValueImpl::Closure(c) => {
+                debug_write!(buf, "closure#{}", c.function)?;
+                print_list(buf, "(", c.captured.iter(), print_value_impl, ")")
+            }
         }
     }
 
@@ -3344,6 +3842,12 @@ impl serde::Serialize for AnnotatedValue<'_, '_, MoveTypeLayout, ValueImpl> {
             (MoveTypeLayout::U256, ValueImpl::U256(x)) => x.serialize(serializer),
             (MoveTypeLayout::Bool, ValueImpl::Bool(x)) => serializer.serialize_bool(*x),
             (MoveTypeLayout::Address, ValueImpl::Address(x)) => x.serialize(serializer),
+            (MoveTypeLayout::I8, ValueImpl::I8(x)) => serializer.serialize_i8(*x),
+            (MoveTypeLayout::I16, ValueImpl::I16(x)) => serializer.serialize_i16(*x),
+            (MoveTypeLayout::I32, ValueImpl::I32(x)) => serializer.serialize_i32(*x),
+            (MoveTypeLayout::I64, ValueImpl::I64(x)) => serializer.serialize_i64(*x),
+            (MoveTypeLayout::I128, ValueImpl::I128(x)) => serializer.serialize_i128(**x),
+            (MoveTypeLayout::I256, ValueImpl::I256(x)) => x.serialize(serializer),
 
             (MoveTypeLayout::Struct(struct_layout), ValueImpl::Container(Container::Struct(r))) => {
                 (AnnotatedValue {
@@ -3520,6 +4024,12 @@ impl<'d> serde::de::DeserializeSeed<'d> for SeedWrapper<&MoveTypeLayout> {
             L::U256 => u256::U256::deserialize(deserializer).map(Value::u256),
             L::Address => AccountAddress::deserialize(deserializer).map(Value::address),
             L::Signer => AccountAddress::deserialize(deserializer).map(Value::signer),
+            L::I8 => i8::deserialize(deserializer).map(Value::i8),
+            L::I16 => i16::deserialize(deserializer).map(Value::i16),
+            L::I32 => i32::deserialize(deserializer).map(Value::i32),
+            L::I64 => i64::deserialize(deserializer).map(Value::i64),
+            L::I128 => i128::deserialize(deserializer).map(Value::i128),
+            L::I256 => i256::I256::deserialize(deserializer).map(Value::i256),
 
             L::Struct(struct_layout) => Ok(SeedWrapper {
                 layout: &**struct_layout,
@@ -3715,12 +4225,20 @@ impl Value {
             S::U128 => L::U128,
             S::U256 => L::U256,
             S::Address => L::Address,
+            S::I8 => L::I8,
+            S::I16 => L::I16,
+            S::I32 => L::I32,
+            S::I64 => L::I64,
+            S::I128 => L::I128,
+            S::I256 => L::I256,
             S::Signer => return None,
             S::Vector(inner) => L::Vector(Box::new(Self::constant_sig_token_to_layout(inner)?)),
             // Not yet supported
             S::Datatype(_) | S::DatatypeInstantiation(_) => return None,
             // Not allowed/Not meaningful
-            S::TypeParameter(_) | S::Reference(_) | S::MutableReference(_) => return None,
+            S::TypeParameter(_) | S::Reference(_) | S::MutableReference(_) | S::Function(_) => {
+                return None
+            }
         })
     }
 
@@ -3852,11 +4370,26 @@ impl ValueImpl {
             U256(val) => visitor.visit_u256(depth, **val),
             Bool(val) => visitor.visit_bool(depth, *val),
             Address(val) => visitor.visit_address(depth, **val),
+            I8(val) => visitor.visit_i8(depth, *val),
+            I16(val) => visitor.visit_i16(depth, *val),
+            I32(val) => visitor.visit_i32(depth, *val),
+            I64(val) => visitor.visit_i64(depth, *val),
+            I128(val) => visitor.visit_i128(depth, **val),
+            I256(val) => visitor.visit_i256(depth, **val),
 
             Container(c) => c.visit_impl(visitor, depth),
 
             ContainerRef(r) => r.visit_impl(visitor, depth),
             IndexedRef(r) => r.visit_impl(visitor, depth),
+
+            // A closure is visited like a struct holding its captured values.
+            Closure(c) => {
+                if visitor.visit_struct(depth, c.captured.len()) {
+                    for val in c.captured.iter() {
+                        val.visit_impl(visitor, depth + 1);
+                    }
+                }
+            }
         }
     }
 }
@@ -3900,6 +4433,12 @@ impl ValueView for IntegerValue {
             U64(val) => visitor.visit_u64(0, *val),
             U128(val) => visitor.visit_u128(0, *val),
             U256(val) => visitor.visit_u256(0, *val),
+            I8(val) => visitor.visit_i8(0, *val),
+            I16(val) => visitor.visit_i16(0, *val),
+            I32(val) => visitor.visit_i32(0, *val),
+            I64(val) => visitor.visit_i64(0, *val),
+            I128(val) => visitor.visit_i128(0, *val),
+            I256(val) => visitor.visit_i256(0, *val),
         }
     }
 }
@@ -4192,6 +4731,12 @@ impl ValueImpl {
             (L::U64, ValueImpl::U64(x)) => MoveValue::U64(*x),
             (L::U128, ValueImpl::U128(x)) => MoveValue::U128(**x),
             (L::U256, ValueImpl::U256(x)) => MoveValue::U256(**x),
+            (L::I8, ValueImpl::I8(x)) => MoveValue::I8(*x),
+            (L::I16, ValueImpl::I16(x)) => MoveValue::I16(*x),
+            (L::I32, ValueImpl::I32(x)) => MoveValue::I32(*x),
+            (L::I64, ValueImpl::I64(x)) => MoveValue::I64(*x),
+            (L::I128, ValueImpl::I128(x)) => MoveValue::I128(**x),
+            (L::I256, ValueImpl::I256(x)) => MoveValue::I256(**x),
             (L::Bool, ValueImpl::Bool(x)) => MoveValue::Bool(*x),
             (L::Address, ValueImpl::Address(x)) => MoveValue::Address(**x),
 
@@ -4282,6 +4827,12 @@ impl ValueImpl {
             (L::U64, ValueImpl::U64(x)) => MoveValue::U64(*x),
             (L::U128, ValueImpl::U128(x)) => MoveValue::U128(**x),
             (L::U256, ValueImpl::U256(x)) => MoveValue::U256(**x),
+            (L::I8, ValueImpl::I8(x)) => MoveValue::I8(*x),
+            (L::I16, ValueImpl::I16(x)) => MoveValue::I16(*x),
+            (L::I32, ValueImpl::I32(x)) => MoveValue::I32(*x),
+            (L::I64, ValueImpl::I64(x)) => MoveValue::I64(*x),
+            (L::I128, ValueImpl::I128(x)) => MoveValue::I128(**x),
+            (L::I256, ValueImpl::I256(x)) => MoveValue::I256(**x),
             (L::Bool, ValueImpl::Bool(x)) => MoveValue::Bool(*x),
             (L::Address, ValueImpl::Address(x)) => MoveValue::Address(**x),
             (l, ValueImpl::Container(c)) => return c.as_annotated_move_value(l),
@@ -56,6 +56,30 @@ pub trait ValueView {
                 self.0 += LEGACY_CONST_SIZE;
             }
 
+            fn visit_i8(&mut self, _depth: usize, _val: i8) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i16(&mut self, _depth: usize, _val: i16) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i32(&mut self, _depth: usize, _val: i32) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i64(&mut self, _depth: usize, _val: i64) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i128(&mut self, _depth: usize, _val: i128) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i256(&mut self, _depth: usize, _val: move_core_types::i256::I256) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
             fn visit_bool(&mut self, _depth: usize, _val: bool) {
                 self.0 += LEGACY_CONST_SIZE;
             }
@@ -154,6 +178,30 @@ pub trait ValueView {
                 self.0 += LEGACY_CONST_SIZE;
             }
 
+            fn visit_i8(&mut self, _depth: usize, _val: i8) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i16(&mut self, _depth: usize, _val: i16) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i32(&mut self, _depth: usize, _val: i32) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i64(&mut self, _depth: usize, _val: i64) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i128(&mut self, _depth: usize, _val: i128) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
+            fn visit_i256(&mut self, _depth: usize, _val: move_core_types::i256::I256) {
+                self.0 += LEGACY_CONST_SIZE;
+            }
+
             fn visit_bool(&mut self, _depth: usize, _val: bool) {
                 self.0 += LEGACY_CONST_SIZE;
             }
@@ -240,6 +288,12 @@ pub trait ValueVisitor {
     fn visit_u256(&mut self, depth: usize, val: move_core_types::u256::U256);
     fn visit_bool(&mut self, depth: usize, val: bool);
     fn visit_address(&mut self, depth: usize, val: AccountAddress);
+    fn visit_i8(&mut self, depth: usize, val: i8);
+    fn visit_i16(&mut self, depth: usize, val: i16);
+    fn visit_i32(&mut self, depth: usize, val: i32);
+    fn visit_i64(&mut self, depth: usize, val: i64);
+    fn visit_i128(&mut self, depth: usize, val: i128);
+    fn visit_i256(&mut self, depth: usize, val: move_core_types::i256::I256);
 
     fn visit_struct(&mut self, depth: usize, len: usize) -> bool;
     fn visit_variant(&mut self, depth: usize, len: usize) -> bool;